@@ -0,0 +1,413 @@
+//! In-process SSH/SFTP server for tests.
+//!
+//! Serves a local directory over SFTP on `127.0.0.1`, accepting a single
+//! username/password pair ([`USERNAME`] / [`PASSWORD`]). Supports listing,
+//! reading, writing, renaming and removing files, and the
+//! `posix-rename@openssh.com` extension unless started with
+//! [`spawn_without_posix_rename`]. Counts SSH connections and extension
+//! renames so tests can assert on session reuse and the rename path.
+
+#![allow(clippy::unwrap_used)]
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use russh::keys::PrivateKey;
+use russh::keys::ssh_key::private::Ed25519Keypair;
+use russh::server::{self, Auth, Msg, Session};
+use russh::{Channel, ChannelId};
+use russh_sftp::protocol::{
+    Attrs, Data, File, FileAttributes, Handle, Name, OpenFlags, Packet, Status, StatusCode, Version,
+};
+
+use crate::connection::POSIX_RENAME;
+use tokio::net::TcpListener;
+
+/// The only accepted username.
+pub const USERNAME: &str = "tester";
+/// The only accepted password.
+pub const PASSWORD: &str = "s3cret";
+
+/// A running test server.
+#[derive(Debug)]
+pub struct TestServer {
+    /// Port the server listens on.
+    pub port: u16,
+    connections: Arc<AtomicUsize>,
+    posix_renames: Arc<AtomicUsize>,
+}
+
+impl TestServer {
+    /// Number of SSH connections accepted so far.
+    pub fn connections(&self) -> usize {
+        self.connections.load(Ordering::SeqCst)
+    }
+
+    /// Number of `posix-rename@openssh.com` requests served so far.
+    pub fn posix_renames(&self) -> usize {
+        self.posix_renames.load(Ordering::SeqCst)
+    }
+}
+
+/// Start a server exposing `root` as `/`.
+pub async fn spawn(root: &Path) -> TestServer {
+    spawn_with(root, true).await
+}
+
+/// Start a server exposing `root` as `/` that, like many non-OpenSSH
+/// servers, does not support `posix-rename@openssh.com`.
+pub async fn spawn_without_posix_rename(root: &Path) -> TestServer {
+    spawn_with(root, false).await
+}
+
+async fn spawn_with(root: &Path, posix_rename: bool) -> TestServer {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let connections = Arc::new(AtomicUsize::new(0));
+    let posix_renames = Arc::new(AtomicUsize::new(0));
+    let config = Arc::new(server::Config {
+        keys: vec![PrivateKey::from(Ed25519Keypair::from_seed(&[7; 32]))],
+        auth_rejection_time: Duration::from_millis(10),
+        auth_rejection_time_initial: Some(Duration::ZERO),
+        ..Default::default()
+    });
+
+    let root = root.to_path_buf();
+    let counter = Arc::clone(&connections);
+    let renames = Arc::clone(&posix_renames);
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            counter.fetch_add(1, Ordering::SeqCst);
+            let handler = SshSession {
+                root: root.clone(),
+                channels: HashMap::new(),
+                posix_rename: posix_rename.then(|| Arc::clone(&renames)),
+            };
+            let config = Arc::clone(&config);
+            tokio::spawn(async move {
+                if let Ok(session) = server::run_stream(config, stream, handler).await {
+                    let _ = session.await;
+                }
+            });
+        }
+    });
+
+    TestServer {
+        port,
+        connections,
+        posix_renames,
+    }
+}
+
+struct SshSession {
+    root: PathBuf,
+    channels: HashMap<ChannelId, Channel<Msg>>,
+    /// Counter of extension renames, if the extension is supported.
+    posix_rename: Option<Arc<AtomicUsize>>,
+}
+
+impl server::Handler for SshSession {
+    type Error = russh::Error;
+
+    async fn auth_password(&mut self, user: &str, password: &str) -> Result<Auth, Self::Error> {
+        if user == USERNAME && password == PASSWORD {
+            Ok(Auth::Accept)
+        } else {
+            Ok(Auth::reject())
+        }
+    }
+
+    async fn channel_open_session(
+        &mut self,
+        channel: Channel<Msg>,
+        _session: &mut Session,
+    ) -> Result<bool, Self::Error> {
+        self.channels.insert(channel.id(), channel);
+        Ok(true)
+    }
+
+    async fn channel_eof(
+        &mut self,
+        channel: ChannelId,
+        session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        session.close(channel)
+    }
+
+    async fn subsystem_request(
+        &mut self,
+        channel_id: ChannelId,
+        name: &str,
+        session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        match self.channels.remove(&channel_id) {
+            Some(channel) if name == "sftp" => {
+                session.channel_success(channel_id)?;
+                let handler = FsSftp {
+                    root: self.root.clone(),
+                    handles: HashMap::new(),
+                    next_handle: 0,
+                    posix_rename: self.posix_rename.clone(),
+                };
+                russh_sftp::server::run(channel.into_stream(), handler).await;
+            }
+            _ => session.channel_failure(channel_id)?,
+        }
+        Ok(())
+    }
+}
+
+enum OpenHandle {
+    Dir { entries: Vec<File>, sent: bool },
+    File(PathBuf),
+}
+
+/// SFTP handler backed by a local directory.
+struct FsSftp {
+    root: PathBuf,
+    handles: HashMap<String, OpenHandle>,
+    next_handle: u64,
+    posix_rename: Option<Arc<AtomicUsize>>,
+}
+
+impl FsSftp {
+    fn local(&self, path: &str) -> PathBuf {
+        self.root.join(path.trim_start_matches('/'))
+    }
+
+    fn insert(&mut self, handle: OpenHandle) -> String {
+        self.next_handle += 1;
+        let key = self.next_handle.to_string();
+        self.handles.insert(key.clone(), handle);
+        key
+    }
+}
+
+fn ok(id: u32) -> Status {
+    Status {
+        id,
+        status_code: StatusCode::Ok,
+        error_message: "Ok".to_string(),
+        language_tag: "en-US".to_string(),
+    }
+}
+
+/// Split one SSH wire-format string (u32 length, then bytes) off `data`.
+fn take_string(data: &[u8]) -> Result<(String, &[u8]), StatusCode> {
+    let (len, rest) = data
+        .split_first_chunk::<4>()
+        .ok_or(StatusCode::BadMessage)?;
+    let len = u32::from_be_bytes(*len) as usize;
+    if rest.len() < len {
+        return Err(StatusCode::BadMessage);
+    }
+    let (value, rest) = rest.split_at(len);
+    let value = String::from_utf8(value.to_vec()).map_err(|_| StatusCode::BadMessage)?;
+    Ok((value, rest))
+}
+
+fn io_status(error: std::io::Error) -> StatusCode {
+    match error.kind() {
+        std::io::ErrorKind::NotFound => StatusCode::NoSuchFile,
+        std::io::ErrorKind::PermissionDenied => StatusCode::PermissionDenied,
+        _ => StatusCode::Failure,
+    }
+}
+
+impl russh_sftp::server::Handler for FsSftp {
+    type Error = StatusCode;
+
+    fn unimplemented(&self) -> Self::Error {
+        StatusCode::OpUnsupported
+    }
+
+    async fn init(
+        &mut self,
+        _version: u32,
+        _extensions: HashMap<String, String>,
+    ) -> Result<Version, Self::Error> {
+        let mut version = Version::new();
+        if self.posix_rename.is_some() {
+            version
+                .extensions
+                .insert(POSIX_RENAME.to_string(), "1".to_string());
+        }
+        Ok(version)
+    }
+
+    async fn extended(
+        &mut self,
+        id: u32,
+        request: String,
+        data: Vec<u8>,
+    ) -> Result<Packet, Self::Error> {
+        let Some(counter) = self
+            .posix_rename
+            .as_ref()
+            .filter(|_| request == POSIX_RENAME)
+        else {
+            return Err(StatusCode::OpUnsupported);
+        };
+        let (oldpath, rest) = take_string(&data)?;
+        let (newpath, _) = take_string(rest)?;
+        std::fs::rename(self.local(&oldpath), self.local(&newpath)).map_err(io_status)?;
+        counter.fetch_add(1, Ordering::SeqCst);
+        Ok(Packet::Status(ok(id)))
+    }
+
+    async fn opendir(&mut self, id: u32, path: String) -> Result<Handle, Self::Error> {
+        let mut entries = Vec::new();
+        for entry in std::fs::read_dir(self.local(&path)).map_err(io_status)? {
+            let entry = entry.map_err(io_status)?;
+            let metadata = entry.metadata().map_err(io_status)?;
+            entries.push(File::new(
+                entry.file_name().to_string_lossy(),
+                FileAttributes::from(&metadata),
+            ));
+        }
+        let handle = self.insert(OpenHandle::Dir {
+            entries,
+            sent: false,
+        });
+        Ok(Handle { id, handle })
+    }
+
+    async fn readdir(&mut self, id: u32, handle: String) -> Result<Name, Self::Error> {
+        match self.handles.get_mut(&handle) {
+            Some(OpenHandle::Dir { entries, sent }) if !*sent => {
+                *sent = true;
+                Ok(Name {
+                    id,
+                    files: std::mem::take(entries),
+                })
+            }
+            Some(OpenHandle::Dir { .. }) => Err(StatusCode::Eof),
+            _ => Err(StatusCode::Failure),
+        }
+    }
+
+    async fn open(
+        &mut self,
+        id: u32,
+        filename: String,
+        pflags: OpenFlags,
+        _attrs: FileAttributes,
+    ) -> Result<Handle, Self::Error> {
+        let path = self.local(&filename);
+        if pflags.contains(OpenFlags::CREATE) {
+            std::fs::File::options()
+                .write(true)
+                .create(true)
+                .truncate(pflags.contains(OpenFlags::TRUNCATE))
+                .open(&path)
+                .map_err(io_status)?;
+        } else {
+            std::fs::metadata(&path).map_err(io_status)?;
+        }
+        let handle = self.insert(OpenHandle::File(path));
+        Ok(Handle { id, handle })
+    }
+
+    async fn read(
+        &mut self,
+        id: u32,
+        handle: String,
+        offset: u64,
+        len: u32,
+    ) -> Result<Data, Self::Error> {
+        let Some(OpenHandle::File(path)) = self.handles.get(&handle) else {
+            return Err(StatusCode::Failure);
+        };
+        let content = std::fs::read(path).map_err(io_status)?;
+        let start = usize::try_from(offset).map_err(|_| StatusCode::Failure)?;
+        if start >= content.len() {
+            return Err(StatusCode::Eof);
+        }
+        let end = content.len().min(start + len as usize);
+        Ok(Data {
+            id,
+            data: content[start..end].to_vec(),
+        })
+    }
+
+    async fn write(
+        &mut self,
+        id: u32,
+        handle: String,
+        offset: u64,
+        data: Vec<u8>,
+    ) -> Result<Status, Self::Error> {
+        use std::io::{Seek, SeekFrom, Write};
+
+        let Some(OpenHandle::File(path)) = self.handles.get(&handle) else {
+            return Err(StatusCode::Failure);
+        };
+        let mut file = std::fs::File::options()
+            .write(true)
+            .open(path)
+            .map_err(io_status)?;
+        file.seek(SeekFrom::Start(offset)).map_err(io_status)?;
+        file.write_all(&data).map_err(io_status)?;
+        Ok(ok(id))
+    }
+
+    async fn fstat(&mut self, id: u32, handle: String) -> Result<Attrs, Self::Error> {
+        let Some(OpenHandle::File(path)) = self.handles.get(&handle) else {
+            return Err(StatusCode::Failure);
+        };
+        let metadata = std::fs::metadata(path).map_err(io_status)?;
+        Ok(Attrs {
+            id,
+            attrs: FileAttributes::from(&metadata),
+        })
+    }
+
+    async fn stat(&mut self, id: u32, path: String) -> Result<Attrs, Self::Error> {
+        let metadata = std::fs::metadata(self.local(&path)).map_err(io_status)?;
+        Ok(Attrs {
+            id,
+            attrs: FileAttributes::from(&metadata),
+        })
+    }
+
+    async fn lstat(&mut self, id: u32, path: String) -> Result<Attrs, Self::Error> {
+        self.stat(id, path).await
+    }
+
+    async fn close(&mut self, id: u32, handle: String) -> Result<Status, Self::Error> {
+        self.handles.remove(&handle);
+        Ok(ok(id))
+    }
+
+    async fn mkdir(
+        &mut self,
+        id: u32,
+        path: String,
+        _attrs: FileAttributes,
+    ) -> Result<Status, Self::Error> {
+        std::fs::create_dir(self.local(&path)).map_err(io_status)?;
+        Ok(ok(id))
+    }
+
+    async fn rename(
+        &mut self,
+        id: u32,
+        oldpath: String,
+        newpath: String,
+    ) -> Result<Status, Self::Error> {
+        // SFTP v3 rename never overwrites, unlike std::fs::rename
+        if self.local(&newpath).exists() {
+            return Err(StatusCode::Failure);
+        }
+        std::fs::rename(self.local(&oldpath), self.local(&newpath)).map_err(io_status)?;
+        Ok(ok(id))
+    }
+
+    async fn remove(&mut self, id: u32, filename: String) -> Result<Status, Self::Error> {
+        std::fs::remove_file(self.local(&filename)).map_err(io_status)?;
+        Ok(ok(id))
+    }
+}
@@ -1,23 +1,61 @@
-//! Error types for the Slack stub adapter.
+//! Error types for the Slack adapter.
 
 use thiserror::Error;
 
-/// Errors specific to the Slack adapter.
+/// Errors that can occur in the Slack adapter.
 #[derive(Debug, Error)]
 #[non_exhaustive]
 pub enum SlackAdapterError {
-    /// An I/O error occurred during fixture file operations.
-    #[error("slack adapter I/O error: {0}")]
-    Io(#[from] std::io::Error),
-
-    /// A fixture file could not be parsed.
-    #[error("fixture parse error for '{item_id}': {message}")]
-    FixtureParse {
-        /// The item that failed to parse.
-        item_id: String,
+    /// The bot token could not be resolved.
+    #[error("invalid credentials: {message}")]
+    InvalidCredentials {
         /// Error detail.
         message: String,
     },
+
+    /// A Slack Web API method returned `"ok": false`.
+    #[error("Slack API error from {method}: {error}")]
+    ApiError {
+        /// The API method (e.g. `"conversations.history"`).
+        method: String,
+        /// Slack's error code (e.g. `"channel_not_found"`).
+        error: String,
+    },
+
+    /// A Slack Web API method returned a non-success HTTP status.
+    #[error("Slack API HTTP error from {method} ({status}): {message}")]
+    HttpStatus {
+        /// The API method.
+        method: String,
+        /// HTTP status code.
+        status: u16,
+        /// Response body.
+        message: String,
+    },
+
+    /// Slack kept rate limiting after the adapter's retries ran out.
+    #[error("rate limited by Slack on {method}; retry after {retry_after_secs}s")]
+    RateLimited {
+        /// The API method.
+        method: String,
+        /// The last `Retry-After` value, in seconds.
+        retry_after_secs: u64,
+    },
+
+    /// A configured channel is not visible to the bot.
+    #[error("channel '{channel}' not found (is the bot a member?)")]
+    ChannelNotFound {
+        /// The channel ID or name from the spec.
+        channel: String,
+    },
+
+    /// HTTP request failed.
+    #[error("HTTP error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// JSON parsing failed.
+    #[error("JSON parse error: {0}")]
+    Json(#[from] serde_json::Error),
 }
 
 #[cfg(test)]
@@ -25,30 +63,56 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_slack_adapter_error_display_io() {
-        let err = SlackAdapterError::Io(std::io::Error::new(
-            std::io::ErrorKind::NotFound,
-            "file not found",
-        ));
-        assert!(err.to_string().contains("file not found"));
+    fn test_slack_adapter_error_display_api_error() {
+        let err = SlackAdapterError::ApiError {
+            method: "conversations.history".to_string(),
+            error: "not_in_channel".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Slack API error from conversations.history: not_in_channel"
+        );
+    }
+
+    #[test]
+    fn test_slack_adapter_error_display_http_status() {
+        let err = SlackAdapterError::HttpStatus {
+            method: "conversations.list".to_string(),
+            status: 503,
+            message: "unavailable".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Slack API HTTP error from conversations.list (503): unavailable"
+        );
     }
 
     #[test]
-    fn test_slack_adapter_error_display_fixture_parse() {
-        let err = SlackAdapterError::FixtureParse {
-            item_id: "C001:msg-001".to_string(),
-            message: "invalid JSON".to_string(),
+    fn test_slack_adapter_error_display_rate_limited() {
+        let err = SlackAdapterError::RateLimited {
+            method: "conversations.replies".to_string(),
+            retry_after_secs: 30,
         };
-        let msg = err.to_string();
-        assert!(msg.contains("C001:msg-001"));
-        assert!(msg.contains("invalid JSON"));
+        assert_eq!(
+            err.to_string(),
+            "rate limited by Slack on conversations.replies; retry after 30s"
+        );
     }
 
     #[test]
-    fn test_slack_adapter_error_from_io() {
-        let io_err = std::io::Error::new(std::io::ErrorKind::PermissionDenied, "denied");
-        let err: SlackAdapterError = io_err.into();
-        assert!(matches!(err, SlackAdapterError::Io(_)));
+    fn test_slack_adapter_error_display_channel_not_found() {
+        let err = SlackAdapterError::ChannelNotFound {
+            channel: "general".to_string(),
+        };
+        assert!(err.to_string().contains("'general'"));
+    }
+
+    #[test]
+    fn test_slack_adapter_error_display_invalid_credentials() {
+        let err = SlackAdapterError::InvalidCredentials {
+            message: "environment variable 'SLACK_BOT_TOKEN' not set".to_string(),
+        };
+        assert!(err.to_string().contains("SLACK_BOT_TOKEN"));
     }
 
     #[test]
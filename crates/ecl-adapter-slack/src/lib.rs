@@ -1,12 +1,22 @@
-//! Slack stub source adapter for the ECL pipeline runner.
+//! Slack source adapter for the ECL pipeline runner.
 //!
-//! This is a **validation stub** — it implements `SourceAdapter` using fixture
-//! data to prove that the trait abstractions hold for a non-filesystem,
-//! non-Drive source. No real Slack API calls are made.
+//! Implements `SourceAdapter` for Slack channels using the Web API via
+//! `reqwest`:
 //!
-//! The adapter reads messages from a fixture directory where each file
-//! represents a Slack message (JSON or plain text). This allows full
-//! pipeline testing without a Slack workspace.
+//! - `conversations.list` resolves the configured channels (by ID or name)
+//! - `conversations.history` lists each channel's top-level messages
+//! - `conversations.replies` expands threads when `thread_depth > 0`
+//!
+//! All three are cursor-paginated. HTTP 429 responses are retried after
+//! the `Retry-After` delay, a bounded number of times.
+//!
+//! Each message becomes one item whose content is a normalized JSON
+//! document ([`types::MessageDocument`]); the item's `source_hash` is the
+//! BLAKE3 hash of that document, so it is stable across runs until the
+//! message is edited.
+//!
+//! `modified_after` becomes the `oldest` bound of history calls; with
+//! `"last_run"` that is when the last completed run of the pipeline started.
 
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
@@ -15,53 +25,85 @@
 #![deny(clippy::panic)]
 
 mod error;
+pub mod types;
 
 pub use error::SlackAdapterError;
 
-use std::collections::BTreeMap;
-use std::path::{Path, PathBuf};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
 
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use tracing::debug;
 
-use ecl_pipeline_spec::SourceSpec;
 use ecl_pipeline_spec::source::SlackSourceSpec;
+use ecl_pipeline_spec::{CredentialRef, SourceSpec};
 use ecl_pipeline_state::{Blake3Hash, ItemProvenance};
 use ecl_pipeline_topo::error::{ResolveError, SourceError};
 use ecl_pipeline_topo::{ExtractedDocument, SourceAdapter, SourceItem};
 
-/// Slack stub source adapter.
+use crate::types::{
+    ApiEnvelope, Channel, ConversationsListResponse, Message, MessageDocument, MessagesResponse,
+    PAGE_LIMIT, SLACK_API_BASE_URL,
+};
+
+/// How many times a rate-limited call is retried before giving up.
+const DEFAULT_MAX_RETRIES: u32 = 5;
+
+/// Fallback delay when a 429 response carries no usable `Retry-After`.
+const DEFAULT_RETRY_AFTER_SECS: u64 = 30;
+
+/// The `modified_after` filter, as the `oldest` bound of history calls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Oldest {
+    /// A fixed Slack timestamp.
+    At(String),
+    /// When the last completed run started; unbounded on the first run.
+    LastRun,
+}
+
+/// Format an instant as a Slack timestamp (`seconds.micros`).
+fn slack_ts(instant: DateTime<Utc>) -> String {
+    format!(
+        "{}.{:06}",
+        instant.timestamp(),
+        instant.timestamp_subsec_micros()
+    )
+}
+
+/// Slack source adapter.
 ///
-/// Reads fixture files from a local directory, treating each file as a
-/// "Slack message." This validates that `SourceAdapter` works for
-/// message-oriented (non-file) sources without requiring API access.
-#[derive(Debug)]
+/// Enumerates the messages of the configured channels (and, with
+/// `thread_depth > 0`, their thread replies) as JSON documents.
 pub struct SlackAdapter {
-    /// Source name from the pipeline config.
     source_name: String,
-    /// Channel IDs from the spec.
     channels: Vec<String>,
-    /// Fixture directory containing message files.
-    /// Derived from `SLACK_FIXTURE_DIR` env var or defaults to
-    /// a conventional path.
-    fixture_dir: Option<PathBuf>,
-    /// In-memory fixture messages (for programmatic testing).
-    fixtures: Vec<FixtureMessage>,
+    thread_depth: usize,
+    /// `oldest` bound for history calls, from `modified_after`.
+    oldest: Option<Oldest>,
+    credentials: CredentialRef,
+    /// Token override (for testing); skips credential resolution.
+    token: Option<String>,
+    http_client: reqwest::Client,
+    base_url: String,
+    max_retries: u32,
+    /// Documents produced by the last `enumerate`, keyed by item ID, so
+    /// that `fetch` does not call the API again.
+    documents: Mutex<HashMap<String, Vec<u8>>>,
 }
 
-/// An in-memory fixture message for testing.
-#[derive(Debug, Clone)]
-pub struct FixtureMessage {
-    /// Unique message ID (e.g., channel + timestamp).
-    pub id: String,
-    /// Human-readable display name.
-    pub display_name: String,
-    /// Channel this message belongs to.
-    pub channel: String,
-    /// Message content (plain text or JSON).
-    pub content: Vec<u8>,
-    /// MIME type of the content.
-    pub mime_type: String,
+impl std::fmt::Debug for SlackAdapter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SlackAdapter")
+            .field("source_name", &self.source_name)
+            .field("channels", &self.channels)
+            .field("thread_depth", &self.thread_depth)
+            .field("oldest", &self.oldest)
+            .field("base_url", &self.base_url)
+            .finish_non_exhaustive()
+    }
 }
 
 impl SlackAdapter {
@@ -69,7 +111,8 @@ impl SlackAdapter {
     ///
     /// # Errors
     ///
-    /// Returns `ResolveError::UnknownAdapter` if the spec is not a Slack source.
+    /// Returns `ResolveError::UnknownAdapter` if the spec is not a Slack
+    /// source, or `ResolveError::Io` if `modified_after` is malformed.
     pub fn from_spec(source_name: &str, spec: &SourceSpec) -> Result<Self, ResolveError> {
         let slack_spec = match spec {
             SourceSpec::Slack(s) => s,
@@ -81,96 +124,441 @@ impl SlackAdapter {
             }
         };
 
-        Ok(Self::from_slack_spec(source_name, slack_spec))
+        Self::from_slack_spec(source_name, slack_spec)
     }
 
     /// Create a `SlackAdapter` directly from a `SlackSourceSpec`.
-    pub fn from_slack_spec(source_name: &str, spec: &SlackSourceSpec) -> Self {
-        let fixture_dir = std::env::var("SLACK_FIXTURE_DIR").ok().map(PathBuf::from);
+    ///
+    /// `modified_after` may be an RFC 3339 timestamp or `"last_run"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ResolveError::Io` if `modified_after` is malformed.
+    pub fn from_slack_spec(
+        source_name: &str,
+        spec: &SlackSourceSpec,
+    ) -> Result<Self, ResolveError> {
+        let oldest = match spec.modified_after.as_deref() {
+            None => None,
+            Some("last_run") => Some(Oldest::LastRun),
+            Some(timestamp) => {
+                let parsed = timestamp.parse::<DateTime<Utc>>().map_err(|e| {
+                    ResolveError::Io(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        format!(
+                            "invalid modified_after '{timestamp}' for source '{source_name}': {e}"
+                        ),
+                    ))
+                })?;
+                Some(Oldest::At(slack_ts(parsed)))
+            }
+        };
 
-        Self {
+        Ok(Self {
             source_name: source_name.to_string(),
             channels: spec.channels.clone(),
-            fixture_dir,
-            fixtures: Vec::new(),
-        }
+            thread_depth: spec.thread_depth,
+            oldest,
+            credentials: spec.credentials.clone(),
+            token: None,
+            http_client: reqwest::Client::new(),
+            base_url: SLACK_API_BASE_URL.to_string(),
+            max_retries: DEFAULT_MAX_RETRIES,
+            documents: Mutex::new(HashMap::new()),
+        })
     }
 
-    /// Create a `SlackAdapter` with in-memory fixture messages.
-    ///
-    /// This is the preferred constructor for tests — no filesystem needed.
-    pub fn with_fixtures(
-        source_name: &str,
-        channels: Vec<String>,
-        fixtures: Vec<FixtureMessage>,
-    ) -> Self {
-        Self {
-            source_name: source_name.to_string(),
-            channels,
-            fixture_dir: None,
-            fixtures,
+    /// Override the Slack API base URL (for testing with wiremock).
+    pub fn with_base_url(mut self, url: String) -> Self {
+        self.base_url = url;
+        self
+    }
+
+    /// Use this bot token instead of resolving the spec's credentials
+    /// (for testing).
+    pub fn with_token(mut self, token: String) -> Self {
+        self.token = Some(token);
+        self
+    }
+
+    /// Override how many times a rate-limited call is retried.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Resolve the bot token from the spec's credentials.
+    async fn bot_token(&self) -> Result<String, SlackAdapterError> {
+        if let Some(ref token) = self.token {
+            return Ok(token.clone());
         }
+        let token = match &self.credentials {
+            CredentialRef::EnvVar { env } => {
+                std::env::var(env).map_err(|_| SlackAdapterError::InvalidCredentials {
+                    message: format!("environment variable '{env}' not set"),
+                })?
+            }
+            CredentialRef::File { path } => tokio::fs::read_to_string(path).await.map_err(|e| {
+                SlackAdapterError::InvalidCredentials {
+                    message: format!("failed to read token file '{}': {e}", path.display()),
+                }
+            })?,
+            CredentialRef::ApplicationDefault | CredentialRef::Secret { .. } => {
+                return Err(SlackAdapterError::InvalidCredentials {
+                    message: "Slack bot tokens must come from an env var or a file".to_string(),
+                });
+            }
+        };
+        Ok(token.trim().to_string())
     }
 
-    /// Read fixture messages from the fixture directory.
-    ///
-    /// Each file in `fixture_dir/<channel>/` is treated as a message.
-    /// The filename (minus extension) becomes the message timestamp ID.
-    fn read_fixture_dir(&self, dir: &Path) -> Result<Vec<FixtureMessage>, SourceError> {
-        let mut messages = Vec::new();
-
-        for channel in &self.channels {
-            let channel_dir = dir.join(channel);
-            if !channel_dir.exists() {
-                tracing::debug!(channel = %channel, "fixture channel directory not found, skipping");
+    /// Call a Web API method, retrying on HTTP 429 after `Retry-After`.
+    async fn call<T: DeserializeOwned>(
+        &self,
+        token: &str,
+        method: &str,
+        query: &[(&str, &str)],
+    ) -> Result<(ApiEnvelope, T), SlackAdapterError> {
+        let url = format!("{}/{method}", self.base_url);
+        let mut attempt = 0;
+        loop {
+            let response = self
+                .http_client
+                .get(&url)
+                .bearer_auth(token)
+                .query(query)
+                .send()
+                .await?;
+
+            let status = response.status();
+            if status.as_u16() == 429 {
+                let retry_after_secs = response
+                    .headers()
+                    .get("retry-after")
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.trim().parse().ok())
+                    .unwrap_or(DEFAULT_RETRY_AFTER_SECS);
+                if attempt >= self.max_retries {
+                    return Err(SlackAdapterError::RateLimited {
+                        method: method.to_string(),
+                        retry_after_secs,
+                    });
+                }
+                attempt += 1;
+                debug!(
+                    source = %self.source_name,
+                    method,
+                    retry_after_secs,
+                    attempt,
+                    "Slack rate limit hit; waiting"
+                );
+                tokio::time::sleep(Duration::from_secs(retry_after_secs)).await;
                 continue;
             }
+            if !status.is_success() {
+                let body = response.text().await.unwrap_or_default();
+                return Err(SlackAdapterError::HttpStatus {
+                    method: method.to_string(),
+                    status: status.as_u16(),
+                    message: body,
+                });
+            }
 
-            let entries = std::fs::read_dir(&channel_dir).map_err(|e| SourceError::Permanent {
-                source_name: self.source_name.clone(),
-                message: format!("failed to read fixture dir {}: {e}", channel_dir.display()),
-            })?;
+            let body = response.bytes().await?;
+            let envelope: ApiEnvelope = serde_json::from_slice(&body)?;
+            if !envelope.ok {
+                return Err(SlackAdapterError::ApiError {
+                    method: method.to_string(),
+                    error: envelope
+                        .error
+                        .unwrap_or_else(|| "unknown_error".to_string()),
+                });
+            }
+            let payload: T = serde_json::from_slice(&body)?;
+            return Ok((envelope, payload));
+        }
+    }
 
-            for entry in entries {
-                let entry = entry.map_err(|e| SourceError::Permanent {
-                    source_name: self.source_name.clone(),
-                    message: format!("failed to read directory entry: {e}"),
-                })?;
-                let path = entry.path();
-                if !path.is_file() {
-                    continue;
-                }
+    /// Call a cursor-paginated method, collecting every page.
+    async fn call_paginated<T: DeserializeOwned>(
+        &self,
+        token: &str,
+        method: &str,
+        query: &[(&str, &str)],
+    ) -> Result<Vec<T>, SlackAdapterError> {
+        let mut pages = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let mut page_query = query.to_vec();
+            page_query.push(("limit", PAGE_LIMIT));
+            if let Some(ref c) = cursor {
+                page_query.push(("cursor", c.as_str()));
+            }
+            let (envelope, page) = self.call::<T>(token, method, &page_query).await?;
+            pages.push(page);
+            match envelope.next_cursor() {
+                Some(next) => cursor = Some(next.to_string()),
+                None => break,
+            }
+        }
+        Ok(pages)
+    }
 
-                let file_name = path
-                    .file_stem()
-                    .and_then(|s| s.to_str())
-                    .unwrap_or("unknown")
-                    .to_string();
+    /// Resolve the configured channels (IDs or names, with or without a
+    /// leading `#`) against `conversations.list`, in spec order.
+    async fn resolve_channels(&self, token: &str) -> Result<Vec<Channel>, SlackAdapterError> {
+        let pages: Vec<ConversationsListResponse> = self
+            .call_paginated(
+                token,
+                "conversations.list",
+                &[("types", "public_channel,private_channel")],
+            )
+            .await?;
+        let known: Vec<Channel> = pages.into_iter().flat_map(|p| p.channels).collect();
+
+        self.channels
+            .iter()
+            .map(|wanted| {
+                let wanted = wanted.trim_start_matches('#');
+                known
+                    .iter()
+                    .find(|c| c.id == wanted || c.name == wanted)
+                    .cloned()
+                    .ok_or_else(|| SlackAdapterError::ChannelNotFound {
+                        channel: wanted.to_string(),
+                    })
+            })
+            .collect()
+    }
 
-                let content = std::fs::read(&path).map_err(|e| SourceError::Permanent {
-                    source_name: self.source_name.clone(),
-                    message: format!("failed to read fixture file {}: {e}", path.display()),
-                })?;
+    /// The `oldest` bound for this run, given when the last completed run
+    /// started.
+    fn oldest(&self, last_run: Option<DateTime<Utc>>) -> Option<String> {
+        match self.oldest.as_ref()? {
+            Oldest::At(ts) => Some(ts.clone()),
+            Oldest::LastRun => last_run.map(slack_ts),
+        }
+    }
 
-                let mime_type = if path.extension().and_then(|e| e.to_str()) == Some("json") {
-                    "application/json"
-                } else {
-                    "text/plain"
-                };
-
-                let msg_id = format!("{channel}:{file_name}");
-                messages.push(FixtureMessage {
-                    id: msg_id,
-                    display_name: format!("#{channel} — {file_name}"),
-                    channel: channel.clone(),
-                    content,
-                    mime_type: mime_type.to_string(),
-                });
+    /// All top-level messages in `channel` since `oldest`.
+    async fn history(
+        &self,
+        token: &str,
+        channel: &Channel,
+        oldest: Option<&str>,
+    ) -> Result<Vec<Message>, SlackAdapterError> {
+        let mut query = vec![("channel", channel.id.as_str())];
+        if let Some(oldest) = oldest {
+            query.push(("oldest", oldest));
+        }
+        let pages: Vec<MessagesResponse> = self
+            .call_paginated(token, "conversations.history", &query)
+            .await?;
+        Ok(pages.into_iter().flat_map(|p| p.messages).collect())
+    }
+
+    /// All replies in the thread started by `thread_ts` (without the parent).
+    async fn replies(
+        &self,
+        token: &str,
+        channel: &Channel,
+        thread_ts: &str,
+    ) -> Result<Vec<Message>, SlackAdapterError> {
+        let pages: Vec<MessagesResponse> = self
+            .call_paginated(
+                token,
+                "conversations.replies",
+                &[("channel", channel.id.as_str()), ("ts", thread_ts)],
+            )
+            .await?;
+        Ok(pages
+            .into_iter()
+            .flat_map(|p| p.messages)
+            .filter(|m| m.ts != thread_ts)
+            .collect())
+    }
+
+    /// Every message to emit, keyed by item ID.
+    ///
+    /// Slack threads are a single level deep, so any `thread_depth` of 1
+    /// or more expands every thread in full.
+    async fn collect(
+        &self,
+        token: &str,
+        oldest: Option<&str>,
+    ) -> Result<BTreeMap<String, (SourceItem, Vec<u8>)>, SlackAdapterError> {
+        let mut items = BTreeMap::new();
+        for channel in self.resolve_channels(token).await? {
+            let messages = self.history(token, &channel, oldest).await?;
+            debug!(
+                source = %self.source_name,
+                channel = %channel.id,
+                messages = messages.len(),
+                "Slack history listed"
+            );
+            for message in &messages {
+                if self.thread_depth > 0 && message.has_replies() {
+                    for reply in self.replies(token, &channel, &message.ts).await? {
+                        let (item, content) = message_item(&channel, &reply);
+                        items.insert(item.id.clone(), (item, content));
+                    }
+                }
+                // Broadcast replies also appear in history; when threads
+                // are expanded they were already emitted above.
+                if message.is_reply() && self.thread_depth > 0 {
+                    continue;
+                }
+                let (item, content) = message_item(&channel, message);
+                items.insert(item.id.clone(), (item, content));
             }
         }
+        Ok(items)
+    }
 
-        Ok(messages)
+    /// Re-read one message from the API (when `fetch` is called without a
+    /// prior `enumerate`, e.g. after a resume).
+    async fn refetch(&self, token: &str, item_id: &str) -> Result<Vec<u8>, SlackAdapterError> {
+        let not_found = || SlackAdapterError::ApiError {
+            method: "conversations.history".to_string(),
+            error: "message_not_found".to_string(),
+        };
+        let (channel_id, rest) = item_id.split_once(':').ok_or_else(not_found)?;
+        let channel = self
+            .resolve_channels(token)
+            .await?
+            .into_iter()
+            .find(|c| c.id == channel_id)
+            .ok_or_else(not_found)?;
+
+        let message = match rest.split_once('/') {
+            Some((thread_ts, ts)) => self
+                .replies(token, &channel, thread_ts)
+                .await?
+                .into_iter()
+                .find(|m| m.ts == ts),
+            None => {
+                let query = [
+                    ("channel", channel.id.as_str()),
+                    ("latest", rest),
+                    ("oldest", rest),
+                    ("inclusive", "true"),
+                    ("limit", "1"),
+                ];
+                let (_, page) = self
+                    .call::<MessagesResponse>(token, "conversations.history", &query)
+                    .await?;
+                page.messages.into_iter().find(|m| m.ts == rest)
+            }
+        };
+        let message = message.ok_or_else(not_found)?;
+        Ok(message_item(&channel, &message).1)
     }
+
+    /// Map an adapter error to a `SourceError`.
+    fn map_error(err: SlackAdapterError, source_name: &str, item_id: &str) -> SourceError {
+        let source_name = source_name.to_string();
+        match &err {
+            SlackAdapterError::InvalidCredentials { .. } => SourceError::AuthError {
+                source_name,
+                message: err.to_string(),
+            },
+            SlackAdapterError::RateLimited {
+                retry_after_secs, ..
+            } => SourceError::RateLimited {
+                source_name,
+                retry_after_secs: *retry_after_secs,
+            },
+            SlackAdapterError::ApiError { error, .. } => match error.as_str() {
+                "invalid_auth" | "not_authed" | "account_inactive" | "token_revoked"
+                | "token_expired" | "missing_scope" | "no_permission" => SourceError::AuthError {
+                    source_name,
+                    message: err.to_string(),
+                },
+                "message_not_found" | "thread_not_found" if !item_id.is_empty() => {
+                    SourceError::NotFound {
+                        source_name,
+                        item_id: item_id.to_string(),
+                    }
+                }
+                "ratelimited" => SourceError::RateLimited {
+                    source_name,
+                    retry_after_secs: DEFAULT_RETRY_AFTER_SECS,
+                },
+                "internal_error" | "fatal_error" | "service_unavailable" | "request_timeout" => {
+                    SourceError::Transient {
+                        source_name,
+                        message: err.to_string(),
+                    }
+                }
+                _ => SourceError::Permanent {
+                    source_name,
+                    message: err.to_string(),
+                },
+            },
+            SlackAdapterError::HttpStatus { status, .. } => match *status {
+                401 | 403 => SourceError::AuthError {
+                    source_name,
+                    message: err.to_string(),
+                },
+                500..=599 => SourceError::Transient {
+                    source_name,
+                    message: err.to_string(),
+                },
+                _ => SourceError::Permanent {
+                    source_name,
+                    message: err.to_string(),
+                },
+            },
+            SlackAdapterError::Http(_) => SourceError::Transient {
+                source_name,
+                message: err.to_string(),
+            },
+            _ => SourceError::Permanent {
+                source_name,
+                message: err.to_string(),
+            },
+        }
+    }
+}
+
+/// Build the item for `message` in `channel`, with its document content.
+///
+/// Top-level messages are identified as `{channel}:{ts}` and replies as
+/// `{channel}:{thread_ts}/{ts}`, so a reply can be re-read from its ID.
+fn message_item(channel: &Channel, message: &Message) -> (SourceItem, Vec<u8>) {
+    let document = MessageDocument::new(channel, message);
+    // Serializing a struct of strings cannot fail.
+    let content = serde_json::to_vec(&document).unwrap_or_default();
+    let hash = blake3::hash(&content).to_hex().to_string();
+
+    let key = match document.thread_ts {
+        Some(ref thread_ts) => format!("{thread_ts}/{}", message.ts),
+        None => message.ts.clone(),
+    };
+    let label = if channel.name.is_empty() {
+        &channel.id
+    } else {
+        &channel.name
+    };
+    let modified_ts = document.edited_ts.as_deref().unwrap_or(&message.ts);
+
+    let item = SourceItem {
+        id: format!("{}:{key}", channel.id),
+        display_name: format!("#{label} — {}", message.ts),
+        mime_type: "application/json".to_string(),
+        path: format!("slack/{}/{key}", channel.id),
+        modified_at: parse_ts(modified_ts),
+        source_hash: Some(hash),
+    };
+    (item, content)
+}
+
+/// Parse a Slack timestamp (`"1712345678.000100"`) into a UTC datetime.
+fn parse_ts(ts: &str) -> Option<DateTime<Utc>> {
+    let (secs, micros) = ts.split_once('.').unwrap_or((ts, "0"));
+    let secs: i64 = secs.parse().ok()?;
+    let micros: u32 = format!("{micros:0<6}").get(..6)?.parse().ok()?;
+    DateTime::from_timestamp(secs, micros * 1000)
 }
 
 #[async_trait]
@@ -180,91 +568,108 @@ impl SourceAdapter for SlackAdapter {
     }
 
     async fn enumerate(&self) -> Result<Vec<SourceItem>, SourceError> {
-        // Gather messages from either in-memory fixtures or fixture directory.
-        let messages = if !self.fixtures.is_empty() {
-            self.fixtures.clone()
-        } else if let Some(ref dir) = self.fixture_dir {
-            self.read_fixture_dir(dir)?
-        } else {
-            tracing::warn!(
-                source = %self.source_name,
-                "no fixtures configured and SLACK_FIXTURE_DIR not set; returning empty"
-            );
-            return Ok(Vec::new());
-        };
+        self.enumerate_since(None).await
+    }
+
+    async fn enumerate_since(
+        &self,
+        last_run: Option<DateTime<Utc>>,
+    ) -> Result<Vec<SourceItem>, SourceError> {
+        let token = self
+            .bot_token()
+            .await
+            .map_err(|e| Self::map_error(e, &self.source_name, ""))?;
+        let oldest = self.oldest(last_run);
+        let collected = self
+            .collect(&token, oldest.as_deref())
+            .await
+            .map_err(|e| Self::map_error(e, &self.source_name, ""))?;
 
         tracing::info!(
             source = %self.source_name,
-            messages = messages.len(),
+            messages = collected.len(),
             channels = ?self.channels,
             "enumerated Slack messages"
         );
 
-        let items = messages
-            .iter()
-            .map(|msg| SourceItem {
-                id: msg.id.clone(),
-                display_name: msg.display_name.clone(),
-                mime_type: msg.mime_type.clone(),
-                path: format!("slack/{}", msg.id),
-                modified_at: None,
-                source_hash: None,
-            })
-            .collect();
-
+        let mut documents = self
+            .documents
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        documents.clear();
+        let mut items = Vec::with_capacity(collected.len());
+        for (id, (item, content)) in collected {
+            documents.insert(id, content);
+            items.push(item);
+        }
         Ok(items)
     }
 
     async fn fetch(&self, item: &SourceItem) -> Result<ExtractedDocument, SourceError> {
-        // Find the message by ID in fixtures or fixture directory.
-        let messages = if !self.fixtures.is_empty() {
-            self.fixtures.clone()
-        } else if let Some(ref dir) = self.fixture_dir {
-            self.read_fixture_dir(dir)?
-        } else {
-            return Err(SourceError::Permanent {
-                source_name: self.source_name.clone(),
-                message: format!("no fixtures configured for item '{}'", item.id),
-            });
+        let cached = self
+            .documents
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&item.id)
+            .cloned();
+        let content = match cached {
+            Some(content) => content,
+            None => {
+                let token = self
+                    .bot_token()
+                    .await
+                    .map_err(|e| Self::map_error(e, &self.source_name, &item.id))?;
+                self.refetch(&token, &item.id)
+                    .await
+                    .map_err(|e| Self::map_error(e, &self.source_name, &item.id))?
+            }
         };
 
-        let msg =
-            messages
-                .iter()
-                .find(|m| m.id == item.id)
-                .ok_or_else(|| SourceError::NotFound {
-                    source_name: self.source_name.clone(),
-                    item_id: item.id.clone(),
-                })?;
-
-        let content_hash = Blake3Hash::new(blake3::hash(&msg.content).to_hex().as_str());
+        let content_hash = Blake3Hash::new(blake3::hash(&content).to_hex().as_str());
+        let document: MessageDocument =
+            serde_json::from_slice(&content).map_err(|e| SourceError::Permanent {
+                source_name: self.source_name.clone(),
+                message: format!("invalid message document for '{}': {e}", item.id),
+            })?;
 
         let mut metadata = BTreeMap::new();
         metadata.insert(
             "channel".to_string(),
-            serde_json::Value::String(msg.channel.clone()),
+            serde_json::Value::String(document.channel.clone()),
         );
+        metadata.insert(
+            "channel_name".to_string(),
+            serde_json::Value::String(document.channel_name.clone()),
+        );
+        metadata.insert(
+            "ts".to_string(),
+            serde_json::Value::String(document.ts.clone()),
+        );
+        if let Some(thread_ts) = document.thread_ts {
+            metadata.insert(
+                "thread_ts".to_string(),
+                serde_json::Value::String(thread_ts),
+            );
+        }
 
-        let provenance = ItemProvenance {
-            source_kind: "slack".to_string(),
-            metadata,
-            source_modified: None,
-            extracted_at: Utc::now(),
-        };
-
-        tracing::debug!(
+        debug!(
             source = %self.source_name,
             item_id = %item.id,
-            content_bytes = msg.content.len(),
+            content_bytes = content.len(),
             "fetched Slack message"
         );
 
         Ok(ExtractedDocument {
             id: item.id.clone(),
             display_name: item.display_name.clone(),
-            content: msg.content.clone(),
-            mime_type: msg.mime_type.clone(),
-            provenance,
+            content,
+            mime_type: "application/json".to_string(),
+            provenance: ItemProvenance {
+                source_kind: "slack".to_string(),
+                metadata,
+                source_modified: parse_ts(document.edited_ts.as_deref().unwrap_or(&document.ts)),
+                extracted_at: Utc::now(),
+            },
             content_hash,
         })
     }
@@ -274,44 +679,55 @@ impl SourceAdapter for SlackAdapter {
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
-    use ecl_pipeline_spec::source::CredentialRef;
+    use wiremock::matchers::{header, method, path, query_param, query_param_is_missing};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
 
     fn make_slack_spec() -> SlackSourceSpec {
         SlackSourceSpec {
             credentials: CredentialRef::EnvVar {
                 env: "SLACK_TOKEN".to_string(),
             },
-            channels: vec!["C001".to_string(), "C002".to_string()],
+            channels: vec!["C001".to_string(), "#random".to_string()],
             thread_depth: 0,
             modified_after: None,
             stream: None,
         }
     }
 
-    fn make_fixtures() -> Vec<FixtureMessage> {
-        vec![
-            FixtureMessage {
-                id: "C001:msg-001".to_string(),
-                display_name: "#C001 — msg-001".to_string(),
-                channel: "C001".to_string(),
-                content: b"Hello from Slack!".to_vec(),
-                mime_type: "text/plain".to_string(),
-            },
-            FixtureMessage {
-                id: "C001:msg-002".to_string(),
-                display_name: "#C001 — msg-002".to_string(),
-                channel: "C001".to_string(),
-                content: b"Second message".to_vec(),
-                mime_type: "text/plain".to_string(),
-            },
-            FixtureMessage {
-                id: "C002:msg-001".to_string(),
-                display_name: "#C002 — msg-001".to_string(),
-                channel: "C002".to_string(),
-                content: b"{\"text\": \"JSON message\"}".to_vec(),
-                mime_type: "application/json".to_string(),
-            },
-        ]
+    async fn adapter_for(server: &MockServer, spec: &SlackSourceSpec) -> SlackAdapter {
+        SlackAdapter::from_slack_spec("slack-test", spec)
+            .unwrap()
+            .with_base_url(server.uri())
+            .with_token("xoxb-test".to_string())
+    }
+
+    /// Mount `conversations.list` with `general` (C001) and `random` (C002).
+    async fn mount_channels(server: &MockServer) {
+        Mock::given(method("GET"))
+            .and(path("/conversations.list"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "ok": true,
+                "channels": [
+                    {"id": "C001", "name": "general"},
+                    {"id": "C002", "name": "random"}
+                ],
+                "response_metadata": {"next_cursor": ""}
+            })))
+            .mount(server)
+            .await;
+    }
+
+    async fn mount_history(server: &MockServer, channel: &str, messages: serde_json::Value) {
+        Mock::given(method("GET"))
+            .and(path("/conversations.history"))
+            .and(query_param("channel", channel))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "ok": true,
+                "messages": messages,
+                "has_more": false
+            })))
+            .mount(server)
+            .await;
     }
 
     // ── Construction tests ──────────────────────────────────────────────
@@ -323,6 +739,7 @@ mod tests {
         assert_eq!(adapter.source_kind(), "slack");
         assert_eq!(adapter.source_name, "test-slack");
         assert_eq!(adapter.channels.len(), 2);
+        assert_eq!(adapter.base_url, SLACK_API_BASE_URL);
     }
 
     #[test]
@@ -338,221 +755,455 @@ mod tests {
     }
 
     #[test]
-    fn test_with_fixtures_constructor() {
-        let fixtures = make_fixtures();
-        let adapter =
-            SlackAdapter::with_fixtures("test", vec!["C001".to_string()], fixtures.clone());
-        assert_eq!(adapter.fixtures.len(), 3);
-        assert_eq!(adapter.channels.len(), 1);
+    fn test_modified_after_becomes_oldest() {
+        let mut spec = make_slack_spec();
+        spec.modified_after = Some("2026-01-01T00:00:00.5Z".to_string());
+        let adapter = SlackAdapter::from_slack_spec("s", &spec).unwrap();
+        assert_eq!(
+            adapter.oldest,
+            Some(Oldest::At("1767225600.500000".to_string()))
+        );
+
+        spec.modified_after = Some("last_run".to_string());
+        let adapter = SlackAdapter::from_slack_spec("s", &spec).unwrap();
+        assert_eq!(adapter.oldest, Some(Oldest::LastRun));
+        assert_eq!(adapter.oldest(None), None);
+        assert_eq!(
+            adapter
+                .oldest(DateTime::from_timestamp(1_767_225_600, 0))
+                .as_deref(),
+            Some("1767225600.000000")
+        );
+
+        spec.modified_after = Some("yesterday".to_string());
+        assert!(SlackAdapter::from_slack_spec("s", &spec).is_err());
     }
 
     #[test]
-    fn test_source_kind_returns_slack() {
-        let adapter = SlackAdapter::with_fixtures("s", vec![], vec![]);
-        assert_eq!(adapter.source_kind(), "slack");
+    fn test_parse_ts() {
+        let parsed = parse_ts("1767225600.000100").unwrap();
+        assert_eq!(parsed.timestamp(), 1_767_225_600);
+        assert_eq!(parsed.timestamp_subsec_micros(), 100);
+        assert!(parse_ts("not-a-ts").is_none());
     }
 
-    // ── Enumerate tests ─────────────────────────────────────────────────
-
     #[tokio::test]
-    async fn test_enumerate_with_fixtures_returns_all() {
-        let fixtures = make_fixtures();
-        let adapter = SlackAdapter::with_fixtures(
-            "test",
-            vec!["C001".to_string(), "C002".to_string()],
-            fixtures,
-        );
+    async fn test_bot_token_from_file_is_trimmed() {
+        let tmp = tempfile::tempdir().unwrap();
+        let token_path = tmp.path().join("token");
+        std::fs::write(&token_path, "xoxb-from-file\n").unwrap();
 
-        let items = adapter.enumerate().await.unwrap();
-        assert_eq!(items.len(), 3);
+        let mut spec = make_slack_spec();
+        spec.credentials = CredentialRef::File { path: token_path };
+        let adapter = SlackAdapter::from_slack_spec("s", &spec).unwrap();
+        assert_eq!(adapter.bot_token().await.unwrap(), "xoxb-from-file");
+
+        spec.credentials = CredentialRef::ApplicationDefault;
+        let adapter = SlackAdapter::from_slack_spec("s", &spec).unwrap();
+        assert!(adapter.bot_token().await.is_err());
     }
 
-    #[tokio::test]
-    async fn test_enumerate_empty_fixtures_returns_empty() {
-        let adapter = SlackAdapter::with_fixtures("test", vec!["C001".to_string()], vec![]);
+    // ── Enumerate tests ─────────────────────────────────────────────────
 
+    #[tokio::test]
+    async fn test_enumerate_lists_messages_per_channel() {
+        let server = MockServer::start().await;
+        mount_channels(&server).await;
+        mount_history(
+            &server,
+            "C001",
+            serde_json::json!([
+                {"ts": "1767225602.000100", "user": "U1", "text": "second"},
+                {"ts": "1767225601.000100", "user": "U2", "text": "first"}
+            ]),
+        )
+        .await;
+        mount_history(
+            &server,
+            "C002",
+            serde_json::json!([{"ts": "1767225603.000100", "bot_id": "B1", "text": "bot"}]),
+        )
+        .await;
+
+        let adapter = adapter_for(&server, &make_slack_spec()).await;
         let items = adapter.enumerate().await.unwrap();
-        assert!(items.is_empty());
+
+        let ids: Vec<&str> = items.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec![
+                "C001:1767225601.000100",
+                "C001:1767225602.000100",
+                "C002:1767225603.000100"
+            ]
+        );
+        assert_eq!(items[0].display_name, "#general — 1767225601.000100");
+        assert_eq!(items[0].mime_type, "application/json");
+        assert_eq!(items[0].path, "slack/C001/1767225601.000100");
+        assert!(items[0].modified_at.is_some());
+        assert!(items[0].source_hash.is_some());
     }
 
     #[tokio::test]
-    async fn test_enumerate_source_item_fields() {
-        let fixtures = vec![FixtureMessage {
-            id: "C001:ts-123".to_string(),
-            display_name: "#C001 — ts-123".to_string(),
-            channel: "C001".to_string(),
-            content: b"test".to_vec(),
-            mime_type: "text/plain".to_string(),
-        }];
-        let adapter = SlackAdapter::with_fixtures("s", vec!["C001".to_string()], fixtures);
-
-        let items = adapter.enumerate().await.unwrap();
-        assert_eq!(items.len(), 1);
-        assert_eq!(items[0].id, "C001:ts-123");
-        assert_eq!(items[0].display_name, "#C001 — ts-123");
-        assert_eq!(items[0].mime_type, "text/plain");
-        assert_eq!(items[0].path, "slack/C001:ts-123");
-        assert!(items[0].modified_at.is_none());
-        assert!(items[0].source_hash.is_none());
+    async fn test_enumerate_sends_bearer_token_and_oldest() {
+        let server = MockServer::start().await;
+        mount_channels(&server).await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.history"))
+            .and(header("authorization", "Bearer xoxb-test"))
+            .and(query_param("oldest", "1767225600.000000"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "ok": true,
+                "messages": [{"ts": "1767225601.000100", "text": "new"}]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut spec = make_slack_spec();
+        spec.channels = vec!["general".to_string()];
+        spec.modified_after = Some("2026-01-01T00:00:00Z".to_string());
+        let adapter = adapter_for(&server, &spec).await;
+        assert_eq!(adapter.enumerate().await.unwrap().len(), 1);
     }
 
     #[tokio::test]
-    async fn test_enumerate_no_fixtures_no_dir_returns_empty() {
-        let adapter = SlackAdapter {
-            source_name: "test".to_string(),
-            channels: vec!["C001".to_string()],
-            fixture_dir: None,
-            fixtures: Vec::new(),
-        };
+    async fn test_enumerate_since_last_run_sends_oldest() {
+        let server = MockServer::start().await;
+        mount_channels(&server).await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.history"))
+            .and(query_param("oldest", "1767312000.000000"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "ok": true,
+                "messages": [{"ts": "1767312001.000100", "text": "new"}]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.history"))
+            .and(query_param_is_missing("oldest"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "ok": true,
+                "messages": [
+                    {"ts": "1767312001.000100", "text": "new"},
+                    {"ts": "1767225601.000100", "text": "old"}
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut spec = make_slack_spec();
+        spec.channels = vec!["general".to_string()];
+        spec.modified_after = Some("last_run".to_string());
+        let adapter = adapter_for(&server, &spec).await;
+
+        // First run: nothing to compare against, so everything is listed
+        assert_eq!(adapter.enumerate_since(None).await.unwrap().len(), 2);
+        let last_run = DateTime::from_timestamp(1_767_312_000, 0);
+        assert_eq!(adapter.enumerate_since(last_run).await.unwrap().len(), 1);
+    }
 
+    #[tokio::test]
+    async fn test_enumerate_follows_history_cursor() {
+        let server = MockServer::start().await;
+        mount_channels(&server).await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.history"))
+            .and(query_param_is_missing("cursor"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "ok": true,
+                "messages": [{"ts": "2.000000", "text": "page one"}],
+                "has_more": true,
+                "response_metadata": {"next_cursor": "bmV4dA=="}
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.history"))
+            .and(query_param("cursor", "bmV4dA=="))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "ok": true,
+                "messages": [{"ts": "1.000000", "text": "page two"}],
+                "has_more": false,
+                "response_metadata": {"next_cursor": ""}
+            })))
+            .mount(&server)
+            .await;
+
+        let mut spec = make_slack_spec();
+        spec.channels = vec!["C001".to_string()];
+        let adapter = adapter_for(&server, &spec).await;
         let items = adapter.enumerate().await.unwrap();
-        assert!(items.is_empty());
+        assert_eq!(items.len(), 2);
     }
 
-    // ── Fetch tests ─────────────────────────────────────────────────────
-
     #[tokio::test]
-    async fn test_fetch_existing_message() {
-        let fixtures = make_fixtures();
-        let adapter = SlackAdapter::with_fixtures("test", vec!["C001".to_string()], fixtures);
+    async fn test_enumerate_expands_threads_when_thread_depth_set() {
+        let server = MockServer::start().await;
+        mount_channels(&server).await;
+        mount_history(
+            &server,
+            "C001",
+            serde_json::json!([
+                {"ts": "1.000000", "thread_ts": "1.000000", "reply_count": 2, "text": "question"},
+                {"ts": "3.000000", "thread_ts": "1.000000", "subtype": "thread_broadcast", "text": "answer b"}
+            ]),
+        )
+        .await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.replies"))
+            .and(query_param("ts", "1.000000"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "ok": true,
+                "messages": [
+                    {"ts": "1.000000", "thread_ts": "1.000000", "reply_count": 2, "text": "question"},
+                    {"ts": "2.000000", "thread_ts": "1.000000", "text": "answer a"},
+                    {"ts": "3.000000", "thread_ts": "1.000000", "subtype": "thread_broadcast", "text": "answer b"}
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let mut spec = make_slack_spec();
+        spec.channels = vec!["C001".to_string()];
+
+        // thread_depth = 0: replies are not fetched.
+        let adapter = adapter_for(&server, &spec).await;
+        let ids: Vec<String> = adapter
+            .enumerate()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|i| i.id)
+            .collect();
+        assert_eq!(ids, vec!["C001:1.000000", "C001:1.000000/3.000000"]);
 
+        spec.thread_depth = 1;
+        let adapter = adapter_for(&server, &spec).await;
         let items = adapter.enumerate().await.unwrap();
-        let doc = adapter.fetch(&items[0]).await.unwrap();
-
-        assert_eq!(doc.id, "C001:msg-001");
-        assert_eq!(doc.content, b"Hello from Slack!");
-        assert_eq!(doc.mime_type, "text/plain");
-        assert_eq!(doc.provenance.source_kind, "slack");
-        assert!(doc.provenance.metadata.contains_key("channel"));
-        assert!(!doc.content_hash.as_str().is_empty());
+        let ids: Vec<&str> = items.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec![
+                "C001:1.000000",
+                "C001:1.000000/2.000000",
+                "C001:1.000000/3.000000"
+            ]
+        );
+        assert_eq!(items[1].path, "slack/C001/1.000000/2.000000");
     }
 
     #[tokio::test]
-    async fn test_fetch_not_found() {
-        let adapter =
-            SlackAdapter::with_fixtures("test", vec!["C001".to_string()], make_fixtures());
-
-        let fake_item = SourceItem {
-            id: "C999:nonexistent".to_string(),
-            display_name: "ghost".to_string(),
-            mime_type: "text/plain".to_string(),
-            path: "slack/C999:nonexistent".to_string(),
-            modified_at: None,
-            source_hash: None,
-        };
-
-        let result = adapter.fetch(&fake_item).await;
-        assert!(result.is_err());
+    async fn test_enumerate_unknown_channel_fails() {
+        let server = MockServer::start().await;
+        mount_channels(&server).await;
+
+        let mut spec = make_slack_spec();
+        spec.channels = vec!["#missing".to_string()];
+        let adapter = adapter_for(&server, &spec).await;
+        let err = adapter.enumerate().await.unwrap_err();
+        assert!(matches!(err, SourceError::Permanent { .. }));
+        assert!(err.to_string().contains("'missing'"));
     }
 
     #[tokio::test]
-    async fn test_fetch_json_message() {
-        let fixtures = make_fixtures();
-        let adapter = SlackAdapter::with_fixtures("test", vec!["C002".to_string()], fixtures);
-
-        let items = adapter.enumerate().await.unwrap();
-        let json_item = items.iter().find(|i| i.id == "C002:msg-001").unwrap();
-        let doc = adapter.fetch(json_item).await.unwrap();
-
-        assert_eq!(doc.mime_type, "application/json");
-        assert!(doc.content.starts_with(b"{"));
+    async fn test_enumerate_invalid_auth_is_auth_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.list"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"ok": false, "error": "invalid_auth"})),
+            )
+            .mount(&server)
+            .await;
+
+        let adapter = adapter_for(&server, &make_slack_spec()).await;
+        let err = adapter.enumerate().await.unwrap_err();
+        assert!(matches!(err, SourceError::AuthError { .. }));
     }
 
     #[tokio::test]
-    async fn test_fetch_content_hash_is_blake3() {
-        let content = b"Hello from Slack!";
-        let expected_hash = blake3::hash(content).to_hex().to_string();
-
-        let fixtures = vec![FixtureMessage {
-            id: "C001:hash-test".to_string(),
-            display_name: "hash test".to_string(),
-            channel: "C001".to_string(),
-            content: content.to_vec(),
-            mime_type: "text/plain".to_string(),
-        }];
-        let adapter = SlackAdapter::with_fixtures("test", vec!["C001".to_string()], fixtures);
+    async fn test_rate_limit_retries_after_retry_after() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.list"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .up_to_n_times(2)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        mount_channels(&server).await;
+        mount_history(&server, "C001", serde_json::json!([])).await;
+
+        let mut spec = make_slack_spec();
+        spec.channels = vec!["C001".to_string()];
+        let adapter = adapter_for(&server, &spec).await;
+        assert!(adapter.enumerate().await.unwrap().is_empty());
+    }
 
-        let items = adapter.enumerate().await.unwrap();
-        let doc = adapter.fetch(&items[0]).await.unwrap();
-        assert_eq!(doc.content_hash.as_str(), expected_hash);
+    #[tokio::test]
+    async fn test_rate_limit_gives_up_after_max_retries() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.list"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let adapter = adapter_for(&server, &make_slack_spec())
+            .await
+            .with_max_retries(2);
+        let err = adapter.enumerate().await.unwrap_err();
+        assert!(matches!(
+            err,
+            SourceError::RateLimited {
+                retry_after_secs: 0,
+                ..
+            }
+        ));
     }
 
     #[tokio::test]
-    async fn test_fetch_provenance_has_channel() {
-        let fixtures = vec![FixtureMessage {
-            id: "C042:prov-test".to_string(),
-            display_name: "provenance test".to_string(),
-            channel: "C042".to_string(),
-            content: b"test".to_vec(),
-            mime_type: "text/plain".to_string(),
-        }];
-        let adapter = SlackAdapter::with_fixtures("test", vec!["C042".to_string()], fixtures);
+    async fn test_server_error_is_transient() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.list"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("unavailable"))
+            .mount(&server)
+            .await;
+
+        let adapter = adapter_for(&server, &make_slack_spec()).await;
+        let err = adapter.enumerate().await.unwrap_err();
+        assert!(matches!(err, SourceError::Transient { .. }));
+    }
 
-        let items = adapter.enumerate().await.unwrap();
-        let doc = adapter.fetch(&items[0]).await.unwrap();
+    // ── Hash stability ──────────────────────────────────────────────────
 
-        let channel = doc.provenance.metadata.get("channel").unwrap();
-        assert_eq!(channel, &serde_json::Value::String("C042".to_string()));
+    #[tokio::test]
+    async fn test_source_hash_is_stable_until_edit() {
+        async fn hash_of(message: serde_json::Value) -> String {
+            let server = MockServer::start().await;
+            mount_channels(&server).await;
+            mount_history(&server, "C001", serde_json::json!([message])).await;
+            let mut spec = make_slack_spec();
+            spec.channels = vec!["C001".to_string()];
+            let adapter = adapter_for(&server, &spec).await;
+            let items = adapter.enumerate().await.unwrap();
+            items[0].source_hash.clone().unwrap()
+        }
+
+        let original = hash_of(serde_json::json!({"ts": "1.0", "user": "U1", "text": "hi"})).await;
+        let reacted = hash_of(serde_json::json!({
+            "ts": "1.0", "user": "U1", "text": "hi",
+            "reactions": [{"name": "tada", "count": 3}]
+        }))
+        .await;
+        let edited = hash_of(serde_json::json!({
+            "ts": "1.0", "user": "U1", "text": "hi!", "edited": {"ts": "5.0"}
+        }))
+        .await;
+
+        assert_eq!(original, reacted);
+        assert_ne!(original, edited);
     }
 
-    // ── Fixture directory tests ─────────────────────────────────────────
+    // ── Fetch tests ─────────────────────────────────────────────────────
 
     #[tokio::test]
-    async fn test_enumerate_from_fixture_dir() {
-        let tmp = tempfile::tempdir().unwrap();
-        let channel_dir = tmp.path().join("C001");
-        std::fs::create_dir_all(&channel_dir).unwrap();
-        std::fs::write(channel_dir.join("msg-001.txt"), "hello").unwrap();
-        std::fs::write(channel_dir.join("msg-002.json"), r#"{"text":"hi"}"#).unwrap();
-
-        let adapter = SlackAdapter {
-            source_name: "fixture-test".to_string(),
-            channels: vec!["C001".to_string()],
-            fixture_dir: Some(tmp.path().to_path_buf()),
-            fixtures: Vec::new(),
-        };
-
+    async fn test_fetch_after_enumerate_uses_cached_document() {
+        let server = MockServer::start().await;
+        mount_channels(&server).await;
+        mount_history(
+            &server,
+            "C001",
+            serde_json::json!([{"ts": "1.000000", "user": "U1", "text": "hello"}]),
+        )
+        .await;
+
+        let mut spec = make_slack_spec();
+        spec.channels = vec!["C001".to_string()];
+        let adapter = adapter_for(&server, &spec).await;
         let items = adapter.enumerate().await.unwrap();
-        assert_eq!(items.len(), 2);
+        let requests_after_enumerate = server.received_requests().await.unwrap().len();
 
-        let ids: Vec<&str> = items.iter().map(|i| i.id.as_str()).collect();
-        assert!(ids.contains(&"C001:msg-001"));
-        assert!(ids.contains(&"C001:msg-002"));
+        let doc = adapter.fetch(&items[0]).await.unwrap();
+        assert_eq!(
+            server.received_requests().await.unwrap().len(),
+            requests_after_enumerate
+        );
+
+        let expected_hash = blake3::hash(&doc.content).to_hex().to_string();
+        assert_eq!(doc.content_hash.as_str(), expected_hash);
+        assert_eq!(
+            items[0].source_hash.as_deref(),
+            Some(expected_hash.as_str())
+        );
+        assert_eq!(doc.mime_type, "application/json");
+        assert_eq!(doc.provenance.source_kind, "slack");
+        assert_eq!(
+            doc.provenance.metadata.get("channel_name").unwrap(),
+            &serde_json::Value::String("general".to_string())
+        );
+
+        let body: serde_json::Value = serde_json::from_slice(&doc.content).unwrap();
+        assert_eq!(body["text"], "hello");
+        assert_eq!(body["user"], "U1");
     }
 
     #[tokio::test]
-    async fn test_fetch_from_fixture_dir() {
-        let tmp = tempfile::tempdir().unwrap();
-        let channel_dir = tmp.path().join("C001");
-        std::fs::create_dir_all(&channel_dir).unwrap();
-        std::fs::write(channel_dir.join("msg-001.txt"), "fixture content").unwrap();
-
-        let adapter = SlackAdapter {
-            source_name: "fixture-test".to_string(),
-            channels: vec!["C001".to_string()],
-            fixture_dir: Some(tmp.path().to_path_buf()),
-            fixtures: Vec::new(),
+    async fn test_fetch_without_enumerate_refetches_reply() {
+        let server = MockServer::start().await;
+        mount_channels(&server).await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.replies"))
+            .and(query_param("channel", "C001"))
+            .and(query_param("ts", "1.000000"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "ok": true,
+                "messages": [
+                    {"ts": "1.000000", "thread_ts": "1.000000", "reply_count": 1, "text": "q"},
+                    {"ts": "2.000000", "thread_ts": "1.000000", "text": "a"}
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let adapter = adapter_for(&server, &make_slack_spec()).await;
+        let item = SourceItem {
+            id: "C001:1.000000/2.000000".to_string(),
+            display_name: "#general — 2.000000".to_string(),
+            mime_type: "application/json".to_string(),
+            path: "C001:1.000000/2.000000".to_string(),
+            modified_at: None,
+            source_hash: None,
         };
-
-        let items = adapter.enumerate().await.unwrap();
-        let doc = adapter.fetch(&items[0]).await.unwrap();
-        assert_eq!(doc.content, b"fixture content");
-        assert_eq!(doc.mime_type, "text/plain");
+        let doc = adapter.fetch(&item).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&doc.content).unwrap();
+        assert_eq!(body["text"], "a");
+        assert_eq!(body["thread_ts"], "1.000000");
     }
 
     #[tokio::test]
-    async fn test_enumerate_fixture_dir_missing_channel_skips() {
-        let tmp = tempfile::tempdir().unwrap();
-        // Don't create channel dir — should skip gracefully.
-
-        let adapter = SlackAdapter {
-            source_name: "test".to_string(),
-            channels: vec!["C999".to_string()],
-            fixture_dir: Some(tmp.path().to_path_buf()),
-            fixtures: Vec::new(),
+    async fn test_fetch_missing_message_is_not_found() {
+        let server = MockServer::start().await;
+        mount_channels(&server).await;
+        mount_history(&server, "C001", serde_json::json!([])).await;
+
+        let adapter = adapter_for(&server, &make_slack_spec()).await;
+        let item = SourceItem {
+            id: "C001:9.000000".to_string(),
+            display_name: "ghost".to_string(),
+            mime_type: "application/json".to_string(),
+            path: "C001:9.000000".to_string(),
+            modified_at: None,
+            source_hash: None,
         };
-
-        let items = adapter.enumerate().await.unwrap();
-        assert!(items.is_empty());
+        let err = adapter.fetch(&item).await.unwrap_err();
+        assert!(matches!(err, SourceError::NotFound { .. }));
     }
 }
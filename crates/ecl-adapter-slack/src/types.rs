@@ -0,0 +1,249 @@
+//! Slack Web API response types.
+
+use serde::{Deserialize, Serialize};
+
+/// Default Slack Web API base URL.
+pub const SLACK_API_BASE_URL: &str = "https://slack.com/api";
+
+/// Page size requested from paginated Slack methods.
+pub const PAGE_LIMIT: &str = "200";
+
+/// Fields shared by every Slack Web API response.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApiEnvelope {
+    /// Whether the call succeeded.
+    pub ok: bool,
+
+    /// Error code when `ok` is false (e.g. `"invalid_auth"`).
+    pub error: Option<String>,
+
+    /// Pagination cursor.
+    #[serde(default)]
+    pub response_metadata: Option<ResponseMetadata>,
+}
+
+impl ApiEnvelope {
+    /// The cursor for the next page, if there is one.
+    pub fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata
+            .as_ref()
+            .map(|m| m.next_cursor.as_str())
+            .filter(|c| !c.is_empty())
+    }
+}
+
+/// Pagination metadata on list responses.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ResponseMetadata {
+    /// Cursor for the next page; empty on the last page.
+    #[serde(default)]
+    pub next_cursor: String,
+}
+
+/// Response from `conversations.list`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConversationsListResponse {
+    /// Channels on this page.
+    #[serde(default)]
+    pub channels: Vec<Channel>,
+}
+
+/// A conversation as returned by `conversations.list`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Channel {
+    /// Channel ID (e.g. `"C0123ABCD"`).
+    pub id: String,
+
+    /// Channel name without the leading `#`.
+    #[serde(default)]
+    pub name: String,
+}
+
+/// Response from `conversations.history` and `conversations.replies`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MessagesResponse {
+    /// Messages on this page.
+    #[serde(default)]
+    pub messages: Vec<Message>,
+}
+
+/// A Slack message.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Message {
+    /// Message timestamp; unique within its channel.
+    pub ts: String,
+
+    /// Timestamp of the thread's parent, for threaded messages.
+    pub thread_ts: Option<String>,
+
+    /// Posting user ID.
+    pub user: Option<String>,
+
+    /// Posting bot ID, for bot messages.
+    pub bot_id: Option<String>,
+
+    /// Message text.
+    #[serde(default)]
+    pub text: String,
+
+    /// Message subtype (e.g. `"thread_broadcast"`, `"bot_message"`).
+    pub subtype: Option<String>,
+
+    /// Number of replies, for thread parents.
+    #[serde(default)]
+    pub reply_count: u64,
+
+    /// Set when the message has been edited.
+    pub edited: Option<Edited>,
+
+    /// Attached files.
+    #[serde(default)]
+    pub files: Vec<File>,
+}
+
+impl Message {
+    /// Whether this message starts a thread with replies.
+    pub fn has_replies(&self) -> bool {
+        self.reply_count > 0 && self.thread_ts.as_deref() == Some(self.ts.as_str())
+    }
+
+    /// Whether this message is a reply inside a thread.
+    pub fn is_reply(&self) -> bool {
+        self.thread_ts
+            .as_deref()
+            .is_some_and(|thread_ts| thread_ts != self.ts)
+    }
+}
+
+/// Edit marker on a message.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Edited {
+    /// When the message was last edited.
+    pub ts: String,
+}
+
+/// A file attached to a message.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct File {
+    /// File ID.
+    pub id: String,
+
+    /// File name.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// File MIME type.
+    #[serde(default)]
+    pub mimetype: Option<String>,
+}
+
+/// The normalized document the adapter emits for one message.
+///
+/// Only fields that describe the message itself are kept; volatile
+/// fields such as reactions or reply counts are dropped so that the
+/// document (and its hash) changes only when the message does.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageDocument {
+    /// Channel ID.
+    pub channel: String,
+    /// Channel name.
+    pub channel_name: String,
+    /// Message timestamp.
+    pub ts: String,
+    /// Parent timestamp, for replies.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<String>,
+    /// Posting user (or bot) ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// Message subtype.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+    /// Message text.
+    pub text: String,
+    /// Last edit timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edited_ts: Option<String>,
+    /// Attached files.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<File>,
+}
+
+impl MessageDocument {
+    /// Normalize `message` from `channel`.
+    pub fn new(channel: &Channel, message: &Message) -> Self {
+        Self {
+            channel: channel.id.clone(),
+            channel_name: channel.name.clone(),
+            ts: message.ts.clone(),
+            thread_ts: message.thread_ts.clone().filter(|_| message.is_reply()),
+            user: message.user.clone().or_else(|| message.bot_id.clone()),
+            subtype: message.subtype.clone(),
+            text: message.text.clone(),
+            edited_ts: message.edited.as_ref().map(|e| e.ts.clone()),
+            files: message.files.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_envelope_next_cursor() {
+        let page: ApiEnvelope = serde_json::from_str(
+            r#"{"ok": true, "response_metadata": {"next_cursor": "dXNlcjpVMDYxTkZUVDI="}}"#,
+        )
+        .unwrap();
+        assert_eq!(page.next_cursor(), Some("dXNlcjpVMDYxTkZUVDI="));
+
+        let last: ApiEnvelope =
+            serde_json::from_str(r#"{"ok": true, "response_metadata": {"next_cursor": ""}}"#)
+                .unwrap();
+        assert_eq!(last.next_cursor(), None);
+
+        let error: ApiEnvelope =
+            serde_json::from_str(r#"{"ok": false, "error": "invalid_auth"}"#).unwrap();
+        assert!(!error.ok);
+        assert_eq!(error.error.as_deref(), Some("invalid_auth"));
+    }
+
+    #[test]
+    fn test_message_thread_roles() {
+        let parent: Message = serde_json::from_str(
+            r#"{"ts": "1.000100", "thread_ts": "1.000100", "reply_count": 2, "text": "q"}"#,
+        )
+        .unwrap();
+        assert!(parent.has_replies());
+        assert!(!parent.is_reply());
+
+        let reply: Message =
+            serde_json::from_str(r#"{"ts": "2.000100", "thread_ts": "1.000100", "text": "a"}"#)
+                .unwrap();
+        assert!(!reply.has_replies());
+        assert!(reply.is_reply());
+    }
+
+    #[test]
+    fn test_document_drops_volatile_fields() {
+        let channel = Channel {
+            id: "C1".to_string(),
+            name: "general".to_string(),
+        };
+        let before: Message = serde_json::from_str(
+            r#"{"ts": "1.0", "thread_ts": "1.0", "reply_count": 1, "user": "U1", "text": "hi",
+                "reactions": [{"name": "wave", "count": 1}]}"#,
+        )
+        .unwrap();
+        let after: Message = serde_json::from_str(
+            r#"{"ts": "1.0", "thread_ts": "1.0", "reply_count": 5, "user": "U1", "text": "hi"}"#,
+        )
+        .unwrap();
+        let doc = MessageDocument::new(&channel, &before);
+        assert_eq!(doc, MessageDocument::new(&channel, &after));
+        // A thread parent is not itself a reply.
+        assert!(doc.thread_ts.is_none());
+    }
+}
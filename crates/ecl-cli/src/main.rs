@@ -5,6 +5,7 @@
 //! Command-line interface for ECL workflow management and pipeline execution.
 
 mod pipeline;
+mod stages;
 
 use anyhow::Result;
 use clap::{Parser, Subcommand};
@@ -30,6 +31,12 @@ enum Commands {
         #[command(subcommand)]
         command: pipeline::PipelineCommand,
     },
+
+    /// Stage catalog commands.
+    Stages {
+        #[command(subcommand)]
+        command: stages::StagesCommand,
+    },
 }
 
 #[tokio::main]
@@ -47,5 +54,6 @@ async fn main() -> Result<()> {
 
     match cli.command {
         Commands::Pipeline { command } => pipeline::execute(command).await,
+        Commands::Stages { command } => stages::execute(command),
     }
 }
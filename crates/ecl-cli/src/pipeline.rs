@@ -1,16 +1,19 @@
 //! Pipeline CLI subcommands.
 //!
-//! Implements `ecl pipeline run|resume|status|inspect|items|diff`.
+//! Implements `ecl pipeline run|resume|status|inspect|items|diff|daemon`.
 
+mod chain;
+mod daemon;
 mod inspect;
 mod items;
-mod registry;
+pub(crate) mod registry;
 mod resume;
 mod run;
 mod status;
 
 use anyhow::Result;
 use clap::Subcommand;
+use ecl_pipeline_state::StateStore;
 use std::path::PathBuf;
 
 /// Pipeline subcommands.
@@ -20,6 +23,10 @@ pub enum PipelineCommand {
     Run {
         /// Path to the pipeline TOML configuration file.
         config: PathBuf,
+
+        /// Maximum number of `[triggers]` hops allowed from this pipeline.
+        #[arg(long, default_value_t = ecl_pipeline::DEFAULT_MAX_CHAIN_DEPTH)]
+        max_chain_depth: usize,
     },
 
     /// Resume a previously interrupted pipeline run.
@@ -35,13 +42,27 @@ pub enum PipelineCommand {
     /// Show a human-readable summary of pipeline status.
     Status {
         /// Path to the pipeline output directory.
-        output_dir: PathBuf,
+        #[arg(required_unless_present = "chain")]
+        output_dir: Option<PathBuf>,
+
+        /// Show the `[triggers]` DAG rooted at this pipeline config, with
+        /// the latest run of each pipeline, instead of a single summary.
+        #[arg(long, value_name = "CONFIG", conflicts_with = "output_dir")]
+        chain: Option<PathBuf>,
+
+        /// Show this retained run instead of the latest.
+        #[arg(long, value_name = "ID", conflicts_with = "chain")]
+        run: Option<String>,
     },
 
     /// Print full pipeline state as JSON.
     Inspect {
         /// Path to the pipeline output directory.
         output_dir: PathBuf,
+
+        /// Print this retained run instead of the latest.
+        #[arg(long, value_name = "ID")]
+        run: Option<String>,
     },
 
     /// List all items with their status.
@@ -55,60 +76,110 @@ pub enum PipelineCommand {
     },
 
     /// Compare two pipeline runs.
+    ///
+    /// Each side is the latest run of its directory unless chosen with
+    /// `--run`: the first `--run` picks the left run, the second the
+    /// right. With one directory, both runs come from it.
     Diff {
         /// Path to the first pipeline output directory.
         dir1: PathBuf,
 
-        /// Path to the second pipeline output directory.
-        dir2: PathBuf,
+        /// Path to the second pipeline output directory (defaults to the first).
+        dir2: Option<PathBuf>,
+
+        /// Retained run to compare; give up to two (left, then right).
+        #[arg(long = "run", value_name = "ID")]
+        runs: Vec<String>,
+    },
+
+    /// Run pipelines on their `[schedule]` cron expressions until Ctrl-C.
+    Daemon {
+        /// Pipeline TOML configuration files (each needs a `[schedule]`).
+        #[arg(required = true)]
+        configs: Vec<PathBuf>,
     },
 }
 
 /// Execute a pipeline subcommand.
 pub async fn execute(command: PipelineCommand) -> Result<()> {
     match command {
-        PipelineCommand::Run { config } => run::execute(config).await,
+        PipelineCommand::Run {
+            config,
+            max_chain_depth,
+        } => run::execute(config, max_chain_depth).await,
         PipelineCommand::Resume { output_dir, force } => resume::execute(output_dir, force).await,
-        PipelineCommand::Status { output_dir } => status::execute(output_dir).await,
-        PipelineCommand::Inspect { output_dir } => inspect::execute(output_dir).await,
+        PipelineCommand::Status {
+            output_dir,
+            chain,
+            run,
+        } => match chain {
+            Some(config) => status::execute_chain(config).await,
+            None => status::execute(output_dir.unwrap_or_default(), run).await,
+        },
+        PipelineCommand::Inspect { output_dir, run } => inspect::execute(output_dir, run).await,
         PipelineCommand::Items { output_dir, status } => items::execute(output_dir, status).await,
-        PipelineCommand::Diff { dir1, dir2 } => diff_runs(dir1, dir2).await,
+        PipelineCommand::Diff { dir1, dir2, runs } => diff_runs(dir1, dir2, runs).await,
+        PipelineCommand::Daemon { configs } => daemon::execute(configs).await,
     }
 }
 
 /// Compare two pipeline runs.
-async fn diff_runs(dir1: PathBuf, dir2: PathBuf) -> Result<()> {
-    let store1 = ecl_pipeline_state::RedbStateStore::open(dir1.join("checkpoints.redb"))?;
-    let store2 = ecl_pipeline_state::RedbStateStore::open(dir2.join("checkpoints.redb"))?;
-
-    use ecl_pipeline_state::StateStore;
+async fn diff_runs(dir1: PathBuf, dir2: Option<PathBuf>, runs: Vec<String>) -> Result<()> {
+    if runs.len() > 2 {
+        anyhow::bail!("--run may be given at most twice (left, then right)");
+    }
+    let same_dir = match dir2 {
+        None => true,
+        Some(ref dir2) => dir1.canonicalize().ok() == dir2.canonicalize().ok(),
+    };
+    if same_dir && runs.is_empty() {
+        anyhow::bail!("comparing a directory with itself needs --run <id>");
+    }
+    let dir2 = dir2.unwrap_or_else(|| dir1.clone());
 
-    let cp1 = store1
-        .load_checkpoint()
-        .await?
-        .ok_or_else(|| anyhow::anyhow!("no checkpoint found in {}", dir1.display()))?;
-    let cp2 = store2
-        .load_checkpoint()
-        .await?
-        .ok_or_else(|| anyhow::anyhow!("no checkpoint found in {}", dir2.display()))?;
+    let store1 = ecl_pipeline_state::RedbStateStore::open(dir1.join("checkpoints.redb"))?;
+    // redb cannot open the same file twice, so share the handle.
+    let store2 = if same_dir {
+        store1.clone()
+    } else {
+        ecl_pipeline_state::RedbStateStore::open(dir2.join("checkpoints.redb"))?
+    };
+
+    let cp1 = status::load_run_or_latest(&store1, runs.first().map(String::as_str))
+        .await
+        .map_err(|e| anyhow::anyhow!("{}: {e}", dir1.display()))?;
+    let cp2 = status::load_run_or_latest(&store2, runs.get(1).map(String::as_str))
+        .await
+        .map_err(|e| anyhow::anyhow!("{}: {e}", dir2.display()))?;
 
     println!("Comparing runs:");
     println!("  Left:  {} (run {})", dir1.display(), cp1.state.run_id);
     println!("  Right: {} (run {})", dir2.display(), cp2.state.run_id);
     println!();
 
+    // Prefer each run's completed-hash snapshot; runs that never
+    // completed fall back to the hashes in their checkpoint.
+    let left_hashes = store1.load_run_hashes(&cp1.state.run_id).await?;
+    let right_hashes = store2.load_run_hashes(&cp2.state.run_id).await?;
+
     // Collect all item IDs from both runs.
     let mut left_items = std::collections::BTreeMap::new();
     let mut right_items = std::collections::BTreeMap::new();
 
     for source in cp1.state.sources.values() {
         for (id, item) in &source.items {
-            left_items.insert(id.clone(), item);
+            left_items.insert(
+                id.clone(),
+                left_hashes.get(id).unwrap_or(&item.content_hash),
+            );
         }
     }
     for source in cp2.state.sources.values() {
         for (id, item) in &source.items {
-            right_items.insert(id.clone(), item);
+            right_items.insert(
+                id.clone(),
+                right_hashes.get(id).unwrap_or(&item.content_hash),
+            );
         }
     }
 
@@ -136,7 +207,7 @@ async fn diff_runs(dir1: PathBuf, dir2: PathBuf) -> Result<()> {
     // Items in both — compare content hash.
     for (id, left) in &left_items {
         if let Some(right) = right_items.get(id) {
-            if left.content_hash != right.content_hash {
+            if left != right {
                 changed += 1;
                 println!("  ~ {id}");
             } else {
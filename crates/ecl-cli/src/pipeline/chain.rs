@@ -0,0 +1,157 @@
+//! Launching `[triggers]` chains from the CLI.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Mutex, PoisonError};
+
+use anyhow::Context;
+use async_trait::async_trait;
+
+use ecl_pipeline::{
+    ChainLauncher, ChainNode, ChainRun, LaunchOutcome, PipelineChain, PipelineError, PipelineRunner,
+};
+use ecl_pipeline_spec::PipelineSpec;
+use ecl_pipeline_state::{RedbStateStore, StateError, TriggeredBy};
+use ecl_pipeline_topo::resolve::resolve;
+use ecl_stages::StageCatalog;
+
+use super::registry;
+
+/// Runs chained pipelines in-process, each against the checkpoint store
+/// in its own `output_dir`.
+///
+/// Stores are opened once and reused: redb refuses to open the same file
+/// twice in one process, and a pipeline may be reached through several
+/// trigger paths (or, in the daemon, several scheduled roots).
+pub struct CliChainLauncher {
+    /// Whether the chain's root resumes an unfinished checkpoint (as
+    /// `ecl pipeline run` does) or always starts its next run (as the
+    /// daemon does). Triggered pipelines always start their next run.
+    resume_root: bool,
+    catalog: StageCatalog,
+    stores: Mutex<HashMap<PathBuf, RedbStateStore>>,
+}
+
+impl CliChainLauncher {
+    /// Create a launcher with no open stores.
+    pub fn new(resume_root: bool) -> Self {
+        Self {
+            resume_root,
+            catalog: registry::stage_catalog(),
+            stores: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Check every pipeline in `chain` against the stage catalog, so that
+    /// unknown adapters and bad params fail before anything runs.
+    pub fn validate(&self, chain: &PipelineChain) -> anyhow::Result<()> {
+        for node in chain.nodes() {
+            node.spec
+                .validate_with(&self.catalog)
+                .with_context(|| format!("invalid pipeline config: {}", node.config.display()))?;
+        }
+        Ok(())
+    }
+
+    /// Open (or reuse) the checkpoint store for `spec`, creating its
+    /// output directory if needed.
+    pub fn store(&self, spec: &PipelineSpec) -> ecl_pipeline::Result<RedbStateStore> {
+        let path = store_path(spec);
+        let mut stores = self.stores.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(store) = stores.get(&path) {
+            return Ok(store.clone());
+        }
+        std::fs::create_dir_all(&spec.output_dir).map_err(|e| StateError::StoreError {
+            message: format!(
+                "failed to create output dir {}: {e}",
+                spec.output_dir.display()
+            ),
+        })?;
+        let store = RedbStateStore::open(&path)?;
+        stores.insert(path, store.clone());
+        Ok(store)
+    }
+
+    async fn runner(
+        &self,
+        node: &ChainNode,
+        trigger: Option<TriggeredBy>,
+    ) -> ecl_pipeline::Result<PipelineRunner> {
+        let spec = node.spec.clone();
+        let adapters = registry::resolve_adapters(&spec)?;
+        let adapter_fn = registry::adapter_lookup_fn(&adapters);
+        let stage_fn = registry::stage_lookup_fn(&self.catalog, &adapters);
+        let push_adapters = registry::resolve_push_adapters(&spec)?;
+        if trigger.is_some() && !push_adapters.is_empty() {
+            return Err(PipelineError::ChainConfig {
+                path: node.config.clone(),
+                message: "pipelines with push sources run until stopped and cannot be triggered"
+                    .to_string(),
+            });
+        }
+
+        let store = Box::new(self.store(&spec)?);
+        let mut topology = resolve(spec, adapter_fn, stage_fn).await?;
+        topology.push_sources = push_adapters;
+
+        let runner = match trigger {
+            None if self.resume_root => PipelineRunner::new(topology, store).await?,
+            None => PipelineRunner::next_run(topology, store).await?,
+            Some(trigger) => PipelineRunner::next_run(topology, store)
+                .await?
+                .with_trigger(trigger),
+        };
+        Ok(runner)
+    }
+}
+
+#[async_trait]
+impl ChainLauncher for CliChainLauncher {
+    async fn launch(&self, node: &ChainNode, trigger: Option<TriggeredBy>) -> LaunchOutcome {
+        let mut runner = match self.runner(node, trigger).await {
+            Ok(runner) => runner,
+            Err(error) => {
+                return LaunchOutcome {
+                    state: None,
+                    error: Some(error),
+                };
+            }
+        };
+        let error = runner.run().await.err();
+        LaunchOutcome {
+            state: Some(runner.state().clone()),
+            error,
+        }
+    }
+}
+
+/// Print the pipelines a chain run triggered, indented by depth.
+pub fn print_triggered(run: &ChainRun) {
+    if run.children.is_empty() {
+        return;
+    }
+    println!();
+    println!("Triggered pipelines:");
+    print_children(run, 1);
+}
+
+fn print_children(run: &ChainRun, depth: usize) {
+    let indent = "  ".repeat(depth);
+    for child in &run.children {
+        let outcome = match (&child.error, &child.state) {
+            (Some(error), _) => format!("failed: {error}"),
+            (None, Some(state)) => format!(
+                "{} processed, {} failed (run {})",
+                state.stats.total_items_processed, state.stats.total_items_failed, state.run_id
+            ),
+            (None, None) => "did not run".to_string(),
+        };
+        println!("{indent}{} -> {}: {outcome}", run.event, child.pipeline);
+        print_children(child, depth + 1);
+    }
+}
+
+/// Path of a pipeline's checkpoint store.
+pub fn store_path(spec: &PipelineSpec) -> PathBuf {
+    spec.output_dir.join("checkpoints.redb")
+}
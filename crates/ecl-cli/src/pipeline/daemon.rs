@@ -0,0 +1,109 @@
+//! `ecl pipeline daemon` — run pipelines on their cron schedules.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use tokio::sync::watch;
+
+use ecl_pipeline::{
+    DEFAULT_MAX_CHAIN_DEPTH, PipelineChain, PipelineError, PipelineJob, ScheduledPipeline,
+    Scheduler, SystemClock,
+};
+use ecl_pipeline_state::PipelineState;
+
+use super::chain::CliChainLauncher;
+use super::registry;
+
+/// Runs one scheduled execution of a pipeline and whatever its
+/// `[triggers]` chain launches. Each run resolves a fresh topology so
+/// adapters start from a clean slate.
+struct CliPipelineJob {
+    chain: PipelineChain,
+    launcher: Arc<CliChainLauncher>,
+}
+
+#[async_trait]
+impl PipelineJob for CliPipelineJob {
+    async fn run(&self) -> ecl_pipeline::Result<PipelineState> {
+        let run = self.chain.execute(self.launcher.as_ref()).await;
+        match run.error {
+            Some(error) => Err(error),
+            None => run.state.ok_or_else(|| PipelineError::Schedule {
+                pipeline: run.pipeline,
+                message: "run produced no state".to_string(),
+            }),
+        }
+    }
+}
+
+/// Execute `ecl pipeline daemon <config.toml>...`.
+pub async fn execute(configs: Vec<PathBuf>) -> Result<()> {
+    let clock = Arc::new(SystemClock);
+    let mut scheduler = Scheduler::new(clock);
+    // Shared so that pipelines reachable from several scheduled roots
+    // reuse one handle on their checkpoint store.
+    let launcher = Arc::new(CliChainLauncher::new(false));
+
+    for config_path in &configs {
+        let chain = PipelineChain::load(config_path, DEFAULT_MAX_CHAIN_DEPTH)
+            .with_context(|| format!("failed to load config: {}", config_path.display()))?;
+        launcher.validate(&chain)?;
+        let spec = chain.root().spec.clone();
+
+        let Some(schedule) = spec.schedule.clone() else {
+            anyhow::bail!(
+                "{} has no [schedule] section; use `ecl pipeline run` instead",
+                config_path.display()
+            );
+        };
+        if !registry::resolve_push_adapters(&spec)?.is_empty() {
+            anyhow::bail!(
+                "{} has push sources, which run until stopped and cannot be scheduled",
+                config_path.display()
+            );
+        }
+
+        let store = launcher.store(&spec)?;
+        let triggers = chain.nodes().len() - 1;
+        let job = Arc::new(CliPipelineJob {
+            chain,
+            launcher: launcher.clone(),
+        });
+        let pipeline = ScheduledPipeline::new(spec.name.clone(), &schedule, job, Arc::new(store))?;
+
+        println!("Scheduled pipeline: {}", pipeline.name());
+        println!("  Config:   {}", config_path.display());
+        println!("  Cron:     {} (UTC)", schedule.cron);
+        println!("  Catch-up: {:?}", schedule.catch_up);
+        if triggers > 0 {
+            println!("  Triggers: {triggers} chained pipeline(s)");
+        }
+        match pipeline.next_slot(chrono::Utc::now()) {
+            Some(next) => println!("  Next run: {next}"),
+            None => println!("  Next run: never"),
+        }
+        println!();
+        scheduler.add(pipeline);
+    }
+
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    tokio::spawn(async move {
+        match tokio::signal::ctrl_c().await {
+            Ok(()) => {
+                println!("Shutting down; waiting for in-flight runs to finish...");
+                let _ = shutdown_tx.send(true);
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "failed to listen for Ctrl-C");
+                // Keep the sender alive so the daemon keeps running.
+                std::future::pending::<()>().await;
+            }
+        }
+    });
+
+    println!("Daemon running. Press Ctrl-C to stop.");
+    scheduler.run(shutdown_rx).await?;
+    Ok(())
+}
@@ -4,20 +4,19 @@ use std::path::PathBuf;
 
 use anyhow::Result;
 
-use ecl_pipeline_state::{RedbStateStore, StateStore};
+use ecl_pipeline_state::RedbStateStore;
 
-/// Execute `ecl pipeline inspect <output-dir>`.
-pub async fn execute(output_dir: PathBuf) -> Result<()> {
+use super::status::load_run_or_latest;
+
+/// Execute `ecl pipeline inspect [--run <id>] <output-dir>`.
+pub async fn execute(output_dir: PathBuf, run: Option<String>) -> Result<()> {
     let store_path = output_dir.join("checkpoints.redb");
     if !store_path.exists() {
         anyhow::bail!("no checkpoints.redb found in {}", output_dir.display());
     }
 
     let store = RedbStateStore::open(&store_path)?;
-    let checkpoint = store
-        .load_checkpoint()
-        .await?
-        .ok_or_else(|| anyhow::anyhow!("checkpoint database is empty"))?;
+    let checkpoint = load_run_or_latest(&store, run.as_deref()).await?;
 
     let json = serde_json::to_string_pretty(&checkpoint.state)
         .map_err(|e| anyhow::anyhow!("failed to serialize state: {e}"))?;
@@ -1,8 +1,7 @@
 //! Default adapter and stage registries for the CLI.
 //!
-//! Registers all built-in adapters (filesystem, Google Drive) and stages
-//! (extract, normalize, filter, emit) so that TOML configs can reference
-//! them by name.
+//! Registers all built-in source adapters and, through the stage catalog,
+//! every stage so that TOML configs can reference them by name.
 
 use std::collections::BTreeMap;
 use std::sync::Arc;
@@ -15,12 +14,11 @@ use ecl_adapter_zapier::ZapierAdapter;
 use ecl_pipeline_spec::{PipelineSpec, SourceSpec, StageSpec};
 use ecl_pipeline_topo::error::ResolveError;
 use ecl_pipeline_topo::{PushSourceAdapter, SourceAdapter, Stage};
-use ecl_sink_gcs::GcsSinkStage;
-use ecl_sink_kafka::KafkaSinkStage;
-use ecl_stages::{
-    CsvParseStage, EmitStage, ExtractStage, FieldMapStage, FilterStage, NormalizeStage,
-    ValidateStage,
-};
+use ecl_sink_file::{FileSinkConfig, FileSinkStage, SftpSinkConfig, SftpSinkStage};
+use ecl_sink_gcs::{GcsSinkConfig, GcsSinkStage};
+use ecl_sink_kafka::{KafkaSinkConfig, KafkaSinkStage};
+use ecl_stages::catalog::invalid;
+use ecl_stages::{StageCatalog, StageEntry};
 
 /// Pre-resolve all source adapters from the spec.
 ///
@@ -68,89 +66,59 @@ pub fn adapter_lookup_fn(
     }
 }
 
-/// Create a stage lookup closure that uses pre-resolved adapters for extract stages.
-pub fn stage_lookup_fn(
-    adapters: &BTreeMap<String, Arc<dyn SourceAdapter>>,
-) -> impl Fn(&str, &StageSpec) -> Result<Arc<dyn Stage>, ResolveError> + '_ {
-    move |name: &str, spec: &StageSpec| -> Result<Arc<dyn Stage>, ResolveError> {
-        match spec.adapter.as_str() {
-            "extract" => {
-                let source_name = spec.source.as_deref().ok_or_else(|| {
-                    ResolveError::Io(std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        format!("extract stage '{name}' has no 'source' field"),
-                    ))
-                })?;
-                let adapter = adapters.get(source_name).cloned().ok_or_else(|| {
-                    ResolveError::UnknownAdapter {
-                        stage: name.to_string(),
-                        adapter: format!("source '{source_name}' not found"),
-                    }
-                })?;
-                Ok(Arc::new(ExtractStage::new(adapter, source_name)))
-            }
-            "normalize" => Ok(Arc::new(NormalizeStage::new())),
-            "filter" => {
-                let stage = FilterStage::from_params(&spec.params).map_err(|e| {
-                    ResolveError::Io(std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        format!("filter stage '{name}': {e}"),
-                    ))
-                })?;
-                Ok(Arc::new(stage))
-            }
-            "csv_parse" => {
-                let stage = CsvParseStage::from_params(&spec.params).map_err(|e| {
-                    ResolveError::Io(std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        format!("csv_parse stage '{name}': {e}"),
-                    ))
-                })?;
-                Ok(Arc::new(stage))
-            }
-            "field_map" => {
-                let stage = FieldMapStage::from_params(&spec.params).map_err(|e| {
-                    ResolveError::Io(std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        format!("field_map stage '{name}': {e}"),
-                    ))
-                })?;
-                Ok(Arc::new(stage))
-            }
-            "validate" => {
-                let stage = ValidateStage::from_params(&spec.params).map_err(|e| {
-                    ResolveError::Io(std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        format!("validate stage '{name}': {e}"),
-                    ))
-                })?;
-                Ok(Arc::new(stage))
-            }
-            "kafka_sink" => {
-                let stage = KafkaSinkStage::from_params(&spec.params).map_err(|e| {
-                    ResolveError::Io(std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        format!("kafka_sink stage '{name}': {e}"),
-                    ))
-                })?;
-                Ok(Arc::new(stage))
-            }
-            "gcs_sink" => {
-                let stage = GcsSinkStage::from_params(&spec.params).map_err(|e| {
-                    ResolveError::Io(std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        format!("gcs_sink stage '{name}': {e}"),
-                    ))
-                })?;
-                Ok(Arc::new(stage))
-            }
-            "emit" => Ok(Arc::new(EmitStage::new())),
-            other => Err(ResolveError::UnknownAdapter {
-                stage: name.to_string(),
-                adapter: other.to_string(),
-            }),
-        }
-    }
+/// The catalog of every stage adapter the CLI can build: all built-in
+/// `ecl-stages` stages plus the Kafka and GCS sinks.
+pub fn stage_catalog() -> StageCatalog {
+    let mut catalog = StageCatalog::builtin();
+    catalog
+        .register(StageEntry::new::<KafkaSinkConfig>(
+            "kafka_sink",
+            "Produce records to a Kafka topic as Avro via a schema registry",
+            |ctx| {
+                Ok(Arc::new(
+                    KafkaSinkStage::from_params(ctx.params).map_err(|e| invalid(ctx, e))?,
+                ))
+            },
+        ))
+        .register(StageEntry::new::<GcsSinkConfig>(
+            "gcs_sink",
+            "Write each record as a JSON object to a GCS bucket",
+            |ctx| {
+                Ok(Arc::new(
+                    GcsSinkStage::from_params(ctx.params).map_err(|e| invalid(ctx, e))?,
+                ))
+            },
+        ))
+        .register(StageEntry::new::<FileSinkConfig>(
+            "file_sink",
+            "Write item content to files in a local directory",
+            |ctx| {
+                Ok(Arc::new(
+                    FileSinkStage::from_params(ctx.params, ctx.secrets.clone())
+                        .map_err(|e| invalid(ctx, e))?,
+                ))
+            },
+        ))
+        .register(StageEntry::new::<SftpSinkConfig>(
+            "sftp_sink",
+            "Upload item content as files to an SFTP server",
+            |ctx| {
+                Ok(Arc::new(
+                    SftpSinkStage::from_params(ctx.params, ctx.secrets.clone())
+                        .map_err(|e| invalid(ctx, e))?,
+                ))
+            },
+        ));
+    catalog
+}
+
+/// Create a stage lookup closure that builds stages from `catalog`, using
+/// pre-resolved adapters for extract stages.
+pub fn stage_lookup_fn<'a>(
+    catalog: &'a StageCatalog,
+    adapters: &'a BTreeMap<String, Arc<dyn SourceAdapter>>,
+) -> impl Fn(&str, &StageSpec) -> Result<Arc<dyn Stage>, ResolveError> + 'a {
+    move |name: &str, spec: &StageSpec| catalog.build(name, spec, adapters)
 }
 
 /// Pre-resolve all push-based source adapters from the spec.
@@ -56,9 +56,10 @@ pub async fn execute(output_dir: PathBuf, force: bool) -> Result<()> {
     println!();
 
     // Re-resolve the topology from the checkpointed spec.
+    let catalog = registry::stage_catalog();
     let adapters = registry::resolve_adapters(&spec)?;
     let adapter_fn = registry::adapter_lookup_fn(&adapters);
-    let stage_fn = registry::stage_lookup_fn(&adapters);
+    let stage_fn = registry::stage_lookup_fn(&catalog, &adapters);
 
     let push_adapters = registry::resolve_push_adapters(&spec)?;
     let mut topology = resolve(spec, adapter_fn, stage_fn).await?;
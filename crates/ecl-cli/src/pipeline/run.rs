@@ -2,50 +2,50 @@
 
 use std::path::PathBuf;
 
-use anyhow::{Context, Result};
+use anyhow::Result;
 
-use ecl_pipeline::PipelineRunner;
-use ecl_pipeline_spec::PipelineSpec;
-use ecl_pipeline_state::{PipelineStatus, RedbStateStore};
-use ecl_pipeline_topo::resolve::resolve;
+use ecl_pipeline::PipelineChain;
+use ecl_pipeline_state::PipelineStatus;
 
-use super::registry;
+use super::chain::{CliChainLauncher, print_triggered};
 use super::status::print_summary;
 
-/// Execute `ecl pipeline run <config.toml>`.
-pub async fn execute(config_path: PathBuf) -> Result<()> {
-    let toml_content = tokio::fs::read_to_string(&config_path)
-        .await
-        .with_context(|| format!("failed to read config file: {}", config_path.display()))?;
-
-    let spec = PipelineSpec::from_toml(&toml_content)
-        .with_context(|| format!("failed to parse config: {}", config_path.display()))?;
-
-    let output_dir = spec.output_dir.clone();
-    let pipeline_name = spec.name.clone();
-
-    println!("Running pipeline: {pipeline_name}");
+/// Execute `ecl pipeline run [--max-chain-depth N] <config.toml>`.
+///
+/// Pipelines named in the config's `[triggers]` (and theirs, recursively)
+/// run after it; the exit code reflects the root pipeline only.
+pub async fn execute(config_path: PathBuf, max_chain_depth: usize) -> Result<()> {
+    // Loads and validates every config in the chain, rejecting trigger
+    // cycles, over-deep chains, unknown stage adapters and bad stage
+    // params before anything runs.
+    let chain = PipelineChain::load(&config_path, max_chain_depth)?;
+    let launcher = CliChainLauncher::new(true);
+    launcher.validate(&chain)?;
+    let root = chain.root();
+
+    println!("Running pipeline: {}", root.name);
     println!("  Config: {}", config_path.display());
-    println!("  Output: {}", output_dir.display());
+    println!("  Output: {}", root.spec.output_dir.display());
+    if chain.nodes().len() > 1 {
+        println!("  Triggers:");
+        for line in chain.to_string().lines().skip(1) {
+            println!("  {line}");
+        }
+    }
     println!();
 
-    // Pre-resolve adapters, then use them for both lookups.
-    let adapters = registry::resolve_adapters(&spec)?;
-    let adapter_fn = registry::adapter_lookup_fn(&adapters);
-    let stage_fn = registry::stage_lookup_fn(&adapters);
-
-    let push_adapters = registry::resolve_push_adapters(&spec)?;
-    let mut topology = resolve(spec, adapter_fn, stage_fn).await?;
-    topology.push_sources = push_adapters;
-
-    let store_path = output_dir.join("checkpoints.redb");
-    let store = Box::new(RedbStateStore::open(&store_path)?);
-
-    let mut runner = PipelineRunner::new(topology, store).await?;
-    let state = runner.run().await?;
+    let mut run = chain.execute(&launcher).await;
+    if let Some(error) = run.error.take() {
+        print_triggered(&run);
+        return Err(error.into());
+    }
+    let Some(ref state) = run.state else {
+        anyhow::bail!("pipeline '{}' produced no state", run.pipeline);
+    };
 
     println!();
     print_summary(state);
+    print_triggered(&run);
 
     match &state.status {
         PipelineStatus::Completed { .. } => std::process::exit(0),
@@ -4,34 +4,129 @@ use std::path::PathBuf;
 
 use anyhow::Result;
 
+use ecl_pipeline::PipelineChain;
+
 use ecl_pipeline_state::{
-    ItemStatus, PipelineState, PipelineStatus, RedbStateStore, StageStatus, StateStore,
+    Checkpoint, ItemStatus, PipelineState, PipelineStatus, RedbStateStore, RunId, RunSummary,
+    ScheduleFiring, StageStatus, StateStore,
 };
 
-/// Execute `ecl pipeline status <output-dir>`.
-pub async fn execute(output_dir: PathBuf) -> Result<()> {
+use super::chain::store_path;
+
+/// How many recent schedule firings `status` shows.
+const RECENT_FIRINGS: usize = 5;
+
+/// How many retained runs `status` lists.
+const RECENT_RUNS: usize = 10;
+
+/// Execute `ecl pipeline status [--run <id>] <output-dir>`.
+pub async fn execute(output_dir: PathBuf, run: Option<String>) -> Result<()> {
     let store_path = output_dir.join("checkpoints.redb");
     if !store_path.exists() {
         anyhow::bail!("no checkpoints.redb found in {}", output_dir.display());
     }
 
     let store = RedbStateStore::open(&store_path)?;
-    let checkpoint = store
-        .load_checkpoint()
-        .await?
-        .ok_or_else(|| anyhow::anyhow!("checkpoint database is empty"))?;
+    let checkpoint = load_run_or_latest(&store, run.as_deref()).await?;
 
     print_summary(&checkpoint.state);
+
+    let runs = store.list_runs().await?;
+    if runs.len() > 1 {
+        println!();
+        print_runs(&runs, &checkpoint.state.run_id);
+    }
+
+    let firings = store.load_schedule_firings(RECENT_FIRINGS).await?;
+    if !firings.is_empty() {
+        println!();
+        print_firings(&firings);
+    }
     Ok(())
 }
 
-/// Print a human-readable summary of pipeline state.
-pub fn print_summary(state: &PipelineState) {
-    println!("Pipeline: {}", state.pipeline_name);
-    println!("Run ID:   {}", state.run_id);
-    println!("Started:  {}", state.started_at);
+/// Load the checkpoint of run `run`, or the latest checkpoint when no
+/// run is given.
+pub async fn load_run_or_latest(store: &RedbStateStore, run: Option<&str>) -> Result<Checkpoint> {
+    match run {
+        Some(id) => store.load_run(&RunId::new(id)).await?.ok_or_else(|| {
+            anyhow::anyhow!(
+                "run '{id}' not found (it may have been pruned); \
+                 `ecl pipeline status` lists retained runs"
+            )
+        }),
+        None => store
+            .load_checkpoint()
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("checkpoint database is empty")),
+    }
+}
 
-    let status_str = match &state.status {
+/// Print the most recent retained runs, marking the one shown.
+fn print_runs(runs: &[RunSummary], shown: &RunId) {
+    println!("Run history ({} retained):", runs.len());
+    for run in runs.iter().take(RECENT_RUNS) {
+        let marker = if &run.run_id == shown { "*" } else { " " };
+        println!(
+            "{marker} {}  {}  {}",
+            run.run_id,
+            run.started_at,
+            status_line(&run.status)
+        );
+    }
+}
+
+/// Print recent schedule firings, newest first.
+fn print_firings(firings: &[ScheduleFiring]) {
+    println!("Recent schedule firings:");
+    for firing in firings {
+        println!("  {}: {}", firing.scheduled_for, firing.outcome);
+    }
+}
+
+/// Execute `ecl pipeline status --chain <config.toml>`: print the
+/// `[triggers]` DAG with the latest run of each pipeline in it.
+pub async fn execute_chain(config: PathBuf) -> Result<()> {
+    // Display only: depth limits are enforced when the chain runs.
+    let chain = PipelineChain::load(&config, usize::MAX)?;
+
+    let mut latest = Vec::with_capacity(chain.nodes().len());
+    for node in chain.nodes() {
+        let path = store_path(&node.spec);
+        let checkpoint = if path.exists() {
+            RedbStateStore::open(&path)?.load_checkpoint().await?
+        } else {
+            None
+        };
+        latest.push(checkpoint.map(|c| c.state));
+    }
+
+    println!("Pipeline chain: {}", chain.root().name);
+    println!();
+    for entry in chain.walk() {
+        let node = &chain.nodes()[entry.node];
+        let indent = "  ".repeat(entry.depth);
+        let edge = entry.event.map(|e| format!("{e} -> ")).unwrap_or_default();
+        let latest = match &latest[entry.node] {
+            Some(state) => {
+                let mut line = format!("{} (run {})", status_line(&state.status), state.run_id);
+                if let Some(ref trigger) = state.triggered_by {
+                    line.push_str(&format!(
+                        ", triggered by {} {}",
+                        trigger.pipeline, trigger.event
+                    ));
+                }
+                line
+            }
+            None => "never run".to_string(),
+        };
+        println!("{indent}{edge}{}: {latest}", node.name);
+    }
+    Ok(())
+}
+
+fn status_line(status: &PipelineStatus) -> String {
+    match status {
         PipelineStatus::Pending => "Pending".to_string(),
         PipelineStatus::Running { current_stage } => format!("Running ({current_stage})"),
         PipelineStatus::Completed { finished_at } => format!("Completed ({finished_at})"),
@@ -41,8 +136,22 @@ pub fn print_summary(state: &PipelineState) {
         PipelineStatus::Interrupted { interrupted_at } => {
             format!("Interrupted ({interrupted_at})")
         }
-    };
-    println!("Status:   {status_str}");
+    }
+}
+
+/// Print a human-readable summary of pipeline state.
+pub fn print_summary(state: &PipelineState) {
+    println!("Pipeline: {}", state.pipeline_name);
+    println!("Run ID:   {}", state.run_id);
+    println!("Started:  {}", state.started_at);
+
+    println!("Status:   {}", status_line(&state.status));
+    if let Some(ref trigger) = state.triggered_by {
+        println!(
+            "Triggered by: {} ({}, run {})",
+            trigger.pipeline, trigger.event, trigger.run_id
+        );
+    }
     println!();
 
     // Item statistics.
@@ -87,6 +196,7 @@ pub fn print_summary(state: &PipelineState) {
                 StageStatus::Running => "Running",
                 StageStatus::Completed => "Completed",
                 StageStatus::Skipped { .. } => "Skipped",
+                StageStatus::SkippedByCondition { .. } => "Skipped (condition)",
                 StageStatus::Failed { .. } => "Failed",
             };
             println!(
@@ -0,0 +1,118 @@
+//! Stage catalog CLI subcommands.
+//!
+//! Implements `ecl stages list|describe`.
+
+use anyhow::Result;
+use clap::Subcommand;
+use serde_json::Value;
+
+use crate::pipeline::registry;
+
+/// Stage catalog subcommands.
+#[derive(Subcommand, Debug)]
+pub enum StagesCommand {
+    /// List every stage adapter a pipeline config can use.
+    List,
+
+    /// Show a stage adapter's parameters.
+    Describe {
+        /// The adapter name (as in a stage's `adapter = "..."`).
+        name: String,
+
+        /// Print the params JSON Schema instead of a summary.
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// Execute a stages subcommand.
+pub fn execute(command: StagesCommand) -> Result<()> {
+    let catalog = registry::stage_catalog();
+    match command {
+        StagesCommand::List => {
+            let width = catalog.entries().map(|e| e.name().len()).max().unwrap_or(0);
+            for entry in catalog.entries() {
+                println!("{:<width$}  {}", entry.name(), entry.summary());
+            }
+            Ok(())
+        }
+        StagesCommand::Describe { name, json } => {
+            let Some(entry) = catalog.get(&name) else {
+                anyhow::bail!("unknown stage adapter '{name}'; see `ecl stages list`");
+            };
+            let schema = entry.params_schema();
+            if json {
+                println!("{}", serde_json::to_string_pretty(schema)?);
+                return Ok(());
+            }
+
+            println!("{}: {}", entry.name(), entry.summary());
+            println!();
+            let properties = schema.get("properties").and_then(Value::as_object);
+            let Some(properties) = properties.filter(|p| !p.is_empty()) else {
+                println!("Takes no params.");
+                return Ok(());
+            };
+            let required: Vec<&str> = schema
+                .get("required")
+                .and_then(Value::as_array)
+                .map(|names| names.iter().filter_map(Value::as_str).collect())
+                .unwrap_or_default();
+
+            println!("Params:");
+            for (field, property) in properties {
+                let requirement = if required.contains(&field.as_str()) {
+                    "required".to_string()
+                } else {
+                    match property.get("default") {
+                        Some(Value::Null) | None => "optional".to_string(),
+                        Some(default) => format!("default {default}"),
+                    }
+                };
+                println!("  {field} ({}, {requirement})", type_label(property));
+                if let Some(description) = property.get("description").and_then(Value::as_str) {
+                    for line in description.lines() {
+                        println!("      {line}");
+                    }
+                }
+            }
+            if schema.get("$defs").is_some() {
+                println!();
+                println!("Nested types are listed in `ecl stages describe {name} --json`.");
+            }
+            Ok(())
+        }
+    }
+}
+
+/// A short human-readable type for a property schema.
+fn type_label(property: &Value) -> String {
+    if let Some(reference) = property.get("$ref").and_then(Value::as_str) {
+        return reference
+            .rsplit('/')
+            .next()
+            .unwrap_or(reference)
+            .to_string();
+    }
+    let types: Vec<&str> = match property.get("type") {
+        Some(Value::String(name)) => vec![name.as_str()],
+        Some(Value::Array(names)) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .filter(|name| *name != "null")
+            .collect(),
+        _ => return "any".to_string(),
+    };
+    match types.as_slice() {
+        ["array"] => match property.get("items") {
+            Some(items) => format!("array of {}", type_label(items)),
+            None => "array".to_string(),
+        },
+        ["object"] => match property.get("additionalProperties") {
+            Some(values) if values.is_object() => format!("map of {}", type_label(values)),
+            _ => "object".to_string(),
+        },
+        [] => "any".to_string(),
+        _ => types.join(" or "),
+    }
+}
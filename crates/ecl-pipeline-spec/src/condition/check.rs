@@ -0,0 +1,234 @@
+//! Static type and name checking for condition expressions.
+
+use super::{
+    ConditionError, Expr, Reference, STAGE_STATUSES, StageField, TRIGGER_EVENTS, TriggerField,
+    Value, ValueType,
+};
+
+/// The names a condition may refer to, taken from the pipeline spec.
+#[derive(Debug, Clone, Copy)]
+pub struct CheckScope<'a> {
+    /// The stage that owns the condition.
+    pub stage: &'a str,
+    /// The stage's own source, if it declares one.
+    pub source: Option<&'a str>,
+    /// All source names in the spec.
+    pub sources: &'a [&'a str],
+    /// All stage names in the spec.
+    pub stages: &'a [&'a str],
+}
+
+/// Check that an expression is well-typed, evaluates to a boolean and
+/// only references sources and stages that exist.
+///
+/// # Errors
+///
+/// Returns [`ConditionError::Type`] for operand mismatches or a
+/// non-boolean result, and [`ConditionError::UnknownReference`] for
+/// references to missing sources or stages.
+pub fn check(expr: &Expr, scope: &CheckScope<'_>) -> Result<(), ConditionError> {
+    let ty = type_of(expr, scope)?;
+    if ty != ValueType::Bool {
+        return Err(ConditionError::Type {
+            message: format!("condition must evaluate to a boolean, found {ty}"),
+        });
+    }
+    Ok(())
+}
+
+fn type_of(expr: &Expr, scope: &CheckScope<'_>) -> Result<ValueType, ConditionError> {
+    match expr {
+        Expr::Literal(value) => Ok(value.value_type()),
+        Expr::Ref(reference) => {
+            check_reference(reference, scope)?;
+            Ok(reference.value_type())
+        }
+        Expr::Not(inner) => {
+            let ty = type_of(inner, scope)?;
+            if ty != ValueType::Bool {
+                return Err(ConditionError::Type {
+                    message: format!("'!' expects a boolean operand, found {ty}"),
+                });
+            }
+            Ok(ValueType::Bool)
+        }
+        Expr::Binary { op, lhs, rhs } => {
+            let lty = type_of(lhs, scope)?;
+            let rty = type_of(rhs, scope)?;
+            if !op.is_comparison() {
+                if lty != ValueType::Bool || rty != ValueType::Bool {
+                    return Err(ConditionError::Type {
+                        message: format!("'{op}' expects boolean operands, found {lty} and {rty}"),
+                    });
+                }
+                return Ok(ValueType::Bool);
+            }
+            if lty != rty {
+                return Err(ConditionError::Type {
+                    message: format!("cannot compare {lty} with {rty} using '{op}'"),
+                });
+            }
+            if op.is_ordering() && lty != ValueType::Int {
+                return Err(ConditionError::Type {
+                    message: format!("'{op}' is only defined for integers, found {lty}"),
+                });
+            }
+            check_status_literal(lhs, rhs)?;
+            check_status_literal(rhs, lhs)?;
+            Ok(ValueType::Bool)
+        }
+    }
+}
+
+fn check_reference(reference: &Reference, scope: &CheckScope<'_>) -> Result<(), ConditionError> {
+    let unknown = |message: String| ConditionError::UnknownReference {
+        path: reference.to_string(),
+        message,
+    };
+    match reference {
+        Reference::Source { name: None, .. } if scope.source.is_none() => Err(unknown(format!(
+            "stage '{}' has no source; use sources.<name> instead",
+            scope.stage
+        ))),
+        Reference::Source {
+            name: Some(name), ..
+        } if !scope.sources.contains(&name.as_str()) => {
+            Err(unknown(format!("no source named '{name}'")))
+        }
+        Reference::Stage { name, .. } if name == scope.stage => Err(unknown(
+            "a stage condition cannot refer to its own stage".to_string(),
+        )),
+        Reference::Stage { name, .. } if !scope.stages.contains(&name.as_str()) => {
+            Err(unknown(format!("no stage named '{name}'")))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Reject comparisons of a stage status or trigger event against a string
+/// that is not a recognised value (a typo would otherwise silently never
+/// match).
+fn check_status_literal(reference: &Expr, literal: &Expr) -> Result<(), ConditionError> {
+    let (Expr::Ref(reference), Expr::Literal(Value::Str(value))) = (reference, literal) else {
+        return Ok(());
+    };
+    let (what, allowed) = match reference {
+        Reference::Stage {
+            field: StageField::Status,
+            ..
+        } => ("stage status", STAGE_STATUSES),
+        Reference::Trigger(TriggerField::Event) => ("trigger event", TRIGGER_EVENTS),
+        _ => return Ok(()),
+    };
+    if !allowed.contains(&value.as_str()) {
+        return Err(ConditionError::Type {
+            message: format!(
+                "unknown {what} '{value}', expected one of: {}",
+                allowed.join(", ")
+            ),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::condition::parse;
+
+    fn scope(source: Option<&'static str>) -> CheckScope<'static> {
+        CheckScope {
+            stage: "emit",
+            source,
+            sources: &["local", "drive"],
+            stages: &["extract", "normalize", "emit"],
+        }
+    }
+
+    fn check_str(input: &str) -> Result<(), ConditionError> {
+        check(&parse(input).unwrap(), &scope(Some("local")))
+    }
+
+    #[test]
+    fn test_check_accepts_valid_expressions() {
+        let valid = [
+            "source.items_discovered > 0",
+            "sources.drive.items_accepted >= 10 || items.count == 0",
+            "stages.extract.status == 'completed' && !(pipeline.items_failed > 0)",
+            "stages.normalize.items_failed == 0",
+            "true",
+            "stages.extract.status != \"skipped\"",
+            "trigger.event == 'on_failure' && trigger.items_failed > 0",
+            "trigger.pipeline == 'ingest'",
+        ];
+        for input in valid {
+            assert!(check_str(input).is_ok(), "expected '{input}' to check");
+        }
+    }
+
+    #[test]
+    fn test_check_rejects_non_boolean_result() {
+        let err = check_str("items.count").unwrap_err();
+        assert!(matches!(err, ConditionError::Type { .. }));
+    }
+
+    #[test]
+    fn test_check_rejects_mismatched_comparison() {
+        let err = check_str("stages.extract.status == 1").unwrap_err();
+        assert!(matches!(err, ConditionError::Type { .. }));
+    }
+
+    #[test]
+    fn test_check_rejects_ordering_on_strings() {
+        let err = check_str("stages.extract.status > 'completed'").unwrap_err();
+        assert!(matches!(err, ConditionError::Type { .. }));
+    }
+
+    #[test]
+    fn test_check_rejects_logical_on_integers() {
+        let err = check_str("items.count && true").unwrap_err();
+        assert!(matches!(err, ConditionError::Type { .. }));
+        let err = check_str("!items.count").unwrap_err();
+        assert!(matches!(err, ConditionError::Type { .. }));
+    }
+
+    #[test]
+    fn test_check_rejects_unknown_status_literal() {
+        let err = check_str("'complete' == stages.extract.status").unwrap_err();
+        assert!(matches!(err, ConditionError::Type { message } if message.contains("complete")));
+    }
+
+    #[test]
+    fn test_check_rejects_unknown_trigger_event_literal() {
+        let err = check_str("trigger.event == 'success'").unwrap_err();
+        assert!(
+            matches!(err, ConditionError::Type { message } if message.contains("trigger event"))
+        );
+    }
+
+    #[test]
+    fn test_check_rejects_unknown_source() {
+        let err = check_str("sources.slack.items_discovered > 0").unwrap_err();
+        assert!(matches!(err, ConditionError::UnknownReference { .. }));
+    }
+
+    #[test]
+    fn test_check_rejects_unknown_stage() {
+        let err = check_str("stages.publish.status == 'completed'").unwrap_err();
+        assert!(matches!(err, ConditionError::UnknownReference { .. }));
+    }
+
+    #[test]
+    fn test_check_rejects_self_reference() {
+        let err = check_str("stages.emit.items_processed > 0").unwrap_err();
+        assert!(matches!(err, ConditionError::UnknownReference { .. }));
+    }
+
+    #[test]
+    fn test_check_rejects_own_source_without_source() {
+        let expr = parse("source.items_discovered > 0").unwrap();
+        let err = check(&expr, &scope(None)).unwrap_err();
+        assert!(matches!(err, ConditionError::UnknownReference { .. }));
+    }
+}
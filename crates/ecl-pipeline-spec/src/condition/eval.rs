@@ -0,0 +1,173 @@
+//! Evaluation of condition expressions against runtime state.
+
+use super::{BinaryOp, ConditionError, Expr, Reference, Value};
+
+/// Supplies values for references at evaluation time.
+///
+/// The spec layer has no access to pipeline state, so the runner
+/// implements this over its `PipelineState`.
+pub trait ConditionContext {
+    /// Look up the current value of a reference, or `None` if it cannot
+    /// be resolved (e.g. a source that was never enumerated).
+    fn resolve(&self, reference: &Reference) -> Option<Value>;
+}
+
+/// Evaluate a (previously checked) expression to a boolean.
+///
+/// `&&` and `||` short-circuit, so references on the untaken side are
+/// never resolved.
+///
+/// # Errors
+///
+/// Returns [`ConditionError::Unresolved`] when the context has no value
+/// for a reference, and [`ConditionError::Type`] if the expression was
+/// not checked and turns out to be ill-typed.
+pub fn evaluate(expr: &Expr, ctx: &dyn ConditionContext) -> Result<bool, ConditionError> {
+    match eval_value(expr, ctx)? {
+        Value::Bool(b) => Ok(b),
+        other => Err(ConditionError::Type {
+            message: format!(
+                "condition must evaluate to a boolean, found {}",
+                other.value_type()
+            ),
+        }),
+    }
+}
+
+fn eval_value(expr: &Expr, ctx: &dyn ConditionContext) -> Result<Value, ConditionError> {
+    match expr {
+        Expr::Literal(value) => Ok(value.clone()),
+        Expr::Ref(reference) => ctx
+            .resolve(reference)
+            .ok_or_else(|| ConditionError::Unresolved {
+                path: reference.to_string(),
+            }),
+        Expr::Not(inner) => Ok(Value::Bool(!evaluate(inner, ctx)?)),
+        Expr::Binary {
+            op: BinaryOp::And,
+            lhs,
+            rhs,
+        } => Ok(Value::Bool(evaluate(lhs, ctx)? && evaluate(rhs, ctx)?)),
+        Expr::Binary {
+            op: BinaryOp::Or,
+            lhs,
+            rhs,
+        } => Ok(Value::Bool(evaluate(lhs, ctx)? || evaluate(rhs, ctx)?)),
+        Expr::Binary { op, lhs, rhs } => {
+            let lhs = eval_value(lhs, ctx)?;
+            let rhs = eval_value(rhs, ctx)?;
+            compare(*op, &lhs, &rhs).map(Value::Bool)
+        }
+    }
+}
+
+fn compare(op: BinaryOp, lhs: &Value, rhs: &Value) -> Result<bool, ConditionError> {
+    let ordering = match (lhs, rhs) {
+        (Value::Int(a), Value::Int(b)) => a.cmp(b),
+        (Value::Bool(a), Value::Bool(b)) if !op.is_ordering() => a.cmp(b),
+        (Value::Str(a), Value::Str(b)) if !op.is_ordering() => a.cmp(b),
+        _ => {
+            return Err(ConditionError::Type {
+                message: format!(
+                    "cannot compare {} with {} using '{op}'",
+                    lhs.value_type(),
+                    rhs.value_type()
+                ),
+            });
+        }
+    };
+    Ok(match op {
+        BinaryOp::Eq => ordering.is_eq(),
+        BinaryOp::Ne => ordering.is_ne(),
+        BinaryOp::Lt => ordering.is_lt(),
+        BinaryOp::Le => ordering.is_le(),
+        BinaryOp::Gt => ordering.is_gt(),
+        BinaryOp::Ge => ordering.is_ge(),
+        BinaryOp::And | BinaryOp::Or => {
+            return Err(ConditionError::Type {
+                message: format!("'{op}' is not a comparison operator"),
+            });
+        }
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::condition::{SourceField, StageField, parse};
+
+    /// A fixed context: own source discovered 3 items, `extract` completed,
+    /// 5 items routed to the stage, and no value for `sources.missing`.
+    struct FixedContext;
+
+    impl ConditionContext for FixedContext {
+        fn resolve(&self, reference: &Reference) -> Option<Value> {
+            match reference {
+                Reference::Source {
+                    name: None,
+                    field: SourceField::ItemsDiscovered,
+                } => Some(Value::Int(3)),
+                Reference::Source { name: Some(n), .. } if n == "missing" => None,
+                Reference::Source { .. } => Some(Value::Int(0)),
+                Reference::Stage {
+                    field: StageField::Status,
+                    ..
+                } => Some(Value::Str("completed".to_string())),
+                Reference::Stage { .. } => Some(Value::Int(1)),
+                Reference::Pipeline(_) => Some(Value::Int(0)),
+                Reference::ItemCount => Some(Value::Int(5)),
+                Reference::Trigger(_) => Some(Value::Str("none".to_string())),
+            }
+        }
+    }
+
+    fn eval_str(input: &str) -> Result<bool, ConditionError> {
+        evaluate(&parse(input).unwrap(), &FixedContext)
+    }
+
+    #[test]
+    fn test_evaluate_comparisons() {
+        assert!(eval_str("source.items_discovered > 0").unwrap());
+        assert!(eval_str("source.items_discovered >= 3").unwrap());
+        assert!(!eval_str("source.items_discovered < 3").unwrap());
+        assert!(eval_str("items.count <= 5").unwrap());
+        assert!(eval_str("items.count != 4").unwrap());
+        assert!(eval_str("stages.extract.status == 'completed'").unwrap());
+        assert!(!eval_str("stages.extract.status == 'failed'").unwrap());
+        assert!(eval_str("true == true").unwrap());
+    }
+
+    #[test]
+    fn test_evaluate_boolean_logic() {
+        assert!(eval_str("items.count > 0 && pipeline.items_failed == 0").unwrap());
+        assert!(eval_str("items.count == 0 || pipeline.items_failed == 0").unwrap());
+        assert!(!eval_str("not (items.count > 0)").unwrap());
+        assert!(eval_str("!false and (false or true)").unwrap());
+    }
+
+    #[test]
+    fn test_evaluate_short_circuits_unresolved_reference() {
+        assert!(!eval_str("false && sources.missing.items_discovered > 0").unwrap());
+        assert!(eval_str("true || sources.missing.items_discovered > 0").unwrap());
+    }
+
+    #[test]
+    fn test_evaluate_unresolved_reference_errors() {
+        let err = eval_str("sources.missing.items_discovered > 0").unwrap_err();
+        assert_eq!(
+            err,
+            ConditionError::Unresolved {
+                path: "sources.missing.items_discovered".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_evaluate_unchecked_type_error() {
+        let err = eval_str("items.count").unwrap_err();
+        assert!(matches!(err, ConditionError::Type { .. }));
+        let err = eval_str("stages.extract.status < 'x'").unwrap_err();
+        assert!(matches!(err, ConditionError::Type { .. }));
+    }
+}
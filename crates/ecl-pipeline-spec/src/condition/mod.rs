@@ -0,0 +1,515 @@
+//! Stage condition expressions.
+//!
+//! A stage's `condition` is a small boolean expression evaluated by the
+//! runner just before the stage's batch executes. When it evaluates to
+//! `false`, the stage is skipped.
+//!
+//! # Grammar
+//!
+//! ```text
+//! expr       := or
+//! or         := and (("||" | "or") and)*
+//! and        := not (("&&" | "and") not)*
+//! not        := ("!" | "not") not | comparison
+//! comparison := primary (("==" | "!=" | "<" | "<=" | ">" | ">=") primary)?
+//! primary    := integer | string | "true" | "false" | reference | "(" expr ")"
+//! ```
+//!
+//! Strings are single- or double-quoted. References are dotted paths:
+//!
+//! | Reference                                  | Type    |
+//! |--------------------------------------------|---------|
+//! | `pipeline.items_discovered`                | integer |
+//! | `pipeline.items_processed`                 | integer |
+//! | `pipeline.items_skipped_unchanged`         | integer |
+//! | `pipeline.items_failed`                    | integer |
+//! | `source.<field>` (the stage's own source)  | integer |
+//! | `sources.<name>.<field>`                   | integer |
+//! | `stages.<name>.status`                     | string  |
+//! | `stages.<name>.items_processed`            | integer |
+//! | `stages.<name>.items_failed`               | integer |
+//! | `stages.<name>.items_skipped`              | integer |
+//! | `items.count` (items routed to this stage) | integer |
+//! | `trigger.pipeline`                         | string  |
+//! | `trigger.run_id`                           | string  |
+//! | `trigger.event`                            | string  |
+//! | `trigger.<pipeline field>`                 | integer |
+//!
+//! Source fields are `items_discovered`, `items_accepted` and
+//! `items_skipped_unchanged`. Stage status is one of `pending`, `running`,
+//! `completed`, `skipped` or `failed`.
+//!
+//! `trigger.*` describes the run that triggered this one through
+//! `[triggers]` chaining: the parent pipeline's name, run id, event
+//! (`on_success` or `on_failure`) and final `pipeline.*` counters. In a
+//! run that was not triggered, `trigger.event` is `none`, the strings are
+//! empty and the counters are zero.
+//!
+//! # Example
+//!
+//! ```
+//! use ecl_pipeline_spec::condition::{self, CheckScope};
+//!
+//! let expr = condition::parse("source.items_discovered > 0 && stages.fetch.status == 'completed'")
+//!     .unwrap();
+//! let scope = CheckScope {
+//!     stage: "emit",
+//!     source: Some("local"),
+//!     sources: &["local"],
+//!     stages: &["fetch", "emit"],
+//! };
+//! assert!(condition::check(&expr, &scope).is_ok());
+//! ```
+
+mod check;
+mod eval;
+mod parser;
+
+pub use check::{CheckScope, check};
+pub use eval::{ConditionContext, evaluate};
+pub use parser::parse;
+
+use thiserror::Error;
+
+/// The recognised values of `stages.<name>.status`.
+pub const STAGE_STATUSES: &[&str] = &["pending", "running", "completed", "skipped", "failed"];
+
+/// The recognised values of `trigger.event`.
+pub const TRIGGER_EVENTS: &[&str] = &["on_success", "on_failure", "none"];
+
+/// Errors raised while parsing, checking or evaluating a condition.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum ConditionError {
+    /// The expression is not syntactically valid.
+    #[error("syntax error at offset {offset}: {message}")]
+    Syntax {
+        /// Byte offset into the expression where the error was detected.
+        offset: usize,
+        /// Description of the problem.
+        message: String,
+    },
+
+    /// A reference names a field, source or stage that does not exist.
+    #[error("unknown reference '{path}': {message}")]
+    UnknownReference {
+        /// The reference as written.
+        path: String,
+        /// Why the reference is not valid.
+        message: String,
+    },
+
+    /// Operand types do not fit the operator.
+    #[error("type error: {message}")]
+    Type {
+        /// Description of the mismatch.
+        message: String,
+    },
+
+    /// A reference could not be resolved against the runtime context.
+    #[error("reference '{path}' could not be resolved at runtime")]
+    Unresolved {
+        /// The reference that had no value.
+        path: String,
+    },
+}
+
+/// A parsed condition expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    /// A literal value.
+    Literal(Value),
+    /// A reference to pipeline state.
+    Ref(Reference),
+    /// Logical negation.
+    Not(Box<Expr>),
+    /// A binary operation.
+    Binary {
+        /// The operator.
+        op: BinaryOp,
+        /// Left operand.
+        lhs: Box<Expr>,
+        /// Right operand.
+        rhs: Box<Expr>,
+    },
+}
+
+/// Binary operators, from lowest to highest precedence group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    /// Logical OR (`||`, `or`).
+    Or,
+    /// Logical AND (`&&`, `and`).
+    And,
+    /// Equality (`==`).
+    Eq,
+    /// Inequality (`!=`).
+    Ne,
+    /// Less than (`<`).
+    Lt,
+    /// Less than or equal (`<=`).
+    Le,
+    /// Greater than (`>`).
+    Gt,
+    /// Greater than or equal (`>=`).
+    Ge,
+}
+
+impl BinaryOp {
+    /// Whether this operator compares two operands (as opposed to
+    /// combining two booleans).
+    pub fn is_comparison(self) -> bool {
+        !matches!(self, Self::Or | Self::And)
+    }
+
+    /// Whether this operator requires ordered (integer) operands.
+    pub fn is_ordering(self) -> bool {
+        matches!(self, Self::Lt | Self::Le | Self::Gt | Self::Ge)
+    }
+}
+
+impl std::fmt::Display for BinaryOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Or => "||",
+            Self::And => "&&",
+            Self::Eq => "==",
+            Self::Ne => "!=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+        };
+        f.write_str(s)
+    }
+}
+
+/// A runtime value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    /// A signed integer (all counters are integers).
+    Int(i64),
+    /// A boolean.
+    Bool(bool),
+    /// A string (used for stage status).
+    Str(String),
+}
+
+impl Value {
+    /// The static type of this value.
+    pub fn value_type(&self) -> ValueType {
+        match self {
+            Self::Int(_) => ValueType::Int,
+            Self::Bool(_) => ValueType::Bool,
+            Self::Str(_) => ValueType::Str,
+        }
+    }
+}
+
+/// The static type of an expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueType {
+    /// Integer.
+    Int,
+    /// Boolean.
+    Bool,
+    /// String.
+    Str,
+}
+
+impl std::fmt::Display for ValueType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Int => write!(f, "integer"),
+            Self::Bool => write!(f, "boolean"),
+            Self::Str => write!(f, "string"),
+        }
+    }
+}
+
+/// A reference to a piece of pipeline state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reference {
+    /// `pipeline.<field>`: aggregate pipeline statistics.
+    Pipeline(PipelineField),
+    /// `source.<field>` or `sources.<name>.<field>`.
+    Source {
+        /// Explicit source name; `None` means the stage's own source.
+        name: Option<String>,
+        /// The field being read.
+        field: SourceField,
+    },
+    /// `stages.<name>.<field>`: state of another stage.
+    Stage {
+        /// The stage name.
+        name: String,
+        /// The field being read.
+        field: StageField,
+    },
+    /// `items.count`: number of items routed to the stage being evaluated.
+    ItemCount,
+    /// `trigger.<field>`: the run that triggered this one.
+    Trigger(TriggerField),
+}
+
+impl Reference {
+    /// The static type of the referenced value.
+    pub fn value_type(&self) -> ValueType {
+        match self {
+            Self::Stage {
+                field: StageField::Status,
+                ..
+            } => ValueType::Str,
+            Self::Trigger(field) => field.value_type(),
+            _ => ValueType::Int,
+        }
+    }
+}
+
+impl std::fmt::Display for Reference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pipeline(field) => write!(f, "pipeline.{}", field.as_str()),
+            Self::Source { name: None, field } => write!(f, "source.{}", field.as_str()),
+            Self::Source {
+                name: Some(name),
+                field,
+            } => write!(f, "sources.{name}.{}", field.as_str()),
+            Self::Stage { name, field } => write!(f, "stages.{name}.{}", field.as_str()),
+            Self::ItemCount => write!(f, "items.count"),
+            Self::Trigger(field) => write!(f, "trigger.{}", field.as_str()),
+        }
+    }
+}
+
+/// Fields available under `pipeline.`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PipelineField {
+    /// Total items discovered across all sources.
+    ItemsDiscovered,
+    /// Total items that completed all stages.
+    ItemsProcessed,
+    /// Total items skipped due to unchanged content hash.
+    ItemsSkippedUnchanged,
+    /// Total items that failed processing.
+    ItemsFailed,
+}
+
+impl PipelineField {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "items_discovered" => Some(Self::ItemsDiscovered),
+            "items_processed" => Some(Self::ItemsProcessed),
+            "items_skipped_unchanged" => Some(Self::ItemsSkippedUnchanged),
+            "items_failed" => Some(Self::ItemsFailed),
+            _ => None,
+        }
+    }
+
+    /// The field name as written in expressions.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ItemsDiscovered => "items_discovered",
+            Self::ItemsProcessed => "items_processed",
+            Self::ItemsSkippedUnchanged => "items_skipped_unchanged",
+            Self::ItemsFailed => "items_failed",
+        }
+    }
+}
+
+/// Fields available under `source.` and `sources.<name>.`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceField {
+    /// Items discovered by enumeration.
+    ItemsDiscovered,
+    /// Items that passed filters.
+    ItemsAccepted,
+    /// Items skipped due to unchanged content hash.
+    ItemsSkippedUnchanged,
+}
+
+impl SourceField {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "items_discovered" => Some(Self::ItemsDiscovered),
+            "items_accepted" => Some(Self::ItemsAccepted),
+            "items_skipped_unchanged" => Some(Self::ItemsSkippedUnchanged),
+            _ => None,
+        }
+    }
+
+    /// The field name as written in expressions.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ItemsDiscovered => "items_discovered",
+            Self::ItemsAccepted => "items_accepted",
+            Self::ItemsSkippedUnchanged => "items_skipped_unchanged",
+        }
+    }
+}
+
+/// Fields available under `stages.<name>.`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StageField {
+    /// Lower-case stage status (see [`STAGE_STATUSES`]).
+    Status,
+    /// Items processed by the stage.
+    ItemsProcessed,
+    /// Items that failed in the stage.
+    ItemsFailed,
+    /// Items skipped in the stage.
+    ItemsSkipped,
+}
+
+impl StageField {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "status" => Some(Self::Status),
+            "items_processed" => Some(Self::ItemsProcessed),
+            "items_failed" => Some(Self::ItemsFailed),
+            "items_skipped" => Some(Self::ItemsSkipped),
+            _ => None,
+        }
+    }
+
+    /// The field name as written in expressions.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Status => "status",
+            Self::ItemsProcessed => "items_processed",
+            Self::ItemsFailed => "items_failed",
+            Self::ItemsSkipped => "items_skipped",
+        }
+    }
+}
+
+/// Fields available under `trigger.`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriggerField {
+    /// Name of the triggering pipeline.
+    Pipeline,
+    /// Run id of the triggering run.
+    RunId,
+    /// Which trigger fired (see [`TRIGGER_EVENTS`]).
+    Event,
+    /// A final counter of the triggering run.
+    Stats(PipelineField),
+}
+
+impl TriggerField {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "pipeline" => Some(Self::Pipeline),
+            "run_id" => Some(Self::RunId),
+            "event" => Some(Self::Event),
+            other => PipelineField::from_name(other).map(Self::Stats),
+        }
+    }
+
+    /// The field name as written in expressions.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Pipeline => "pipeline",
+            Self::RunId => "run_id",
+            Self::Event => "event",
+            Self::Stats(field) => field.as_str(),
+        }
+    }
+
+    /// The static type of the field.
+    pub fn value_type(self) -> ValueType {
+        match self {
+            Self::Stats(_) => ValueType::Int,
+            _ => ValueType::Str,
+        }
+    }
+}
+
+/// Parse and type-check a condition in one step.
+///
+/// This is what [`crate::validation::validate`] uses for every stage
+/// that declares a `condition`.
+pub fn compile(input: &str, scope: &CheckScope<'_>) -> Result<Expr, ConditionError> {
+    let expr = parse(input)?;
+    check(&expr, scope)?;
+    Ok(expr)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn scope() -> CheckScope<'static> {
+        CheckScope {
+            stage: "emit",
+            source: Some("local"),
+            sources: &["local", "drive"],
+            stages: &["extract", "emit"],
+        }
+    }
+
+    #[test]
+    fn test_compile_valid_expression() {
+        let expr = compile("source.items_discovered > 0", &scope()).unwrap();
+        assert!(matches!(
+            expr,
+            Expr::Binary {
+                op: BinaryOp::Gt,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_compile_rejects_syntax_error() {
+        let err = compile("source.items_discovered >", &scope()).unwrap_err();
+        assert!(matches!(err, ConditionError::Syntax { .. }));
+    }
+
+    #[test]
+    fn test_compile_rejects_type_error() {
+        let err = compile("source.items_discovered", &scope()).unwrap_err();
+        assert!(matches!(err, ConditionError::Type { .. }));
+    }
+
+    #[test]
+    fn test_reference_display_roundtrips_through_parser() {
+        let refs = [
+            "pipeline.items_failed",
+            "source.items_accepted",
+            "sources.drive.items_skipped_unchanged",
+            "stages.extract.status",
+            "items.count",
+        ];
+        for text in refs {
+            let expr = parse(text).unwrap();
+            let Expr::Ref(reference) = expr else {
+                unreachable!("expected a reference for '{text}'");
+            };
+            assert_eq!(reference.to_string(), text);
+        }
+    }
+
+    #[test]
+    fn test_reference_value_types() {
+        let status = Reference::Stage {
+            name: "extract".to_string(),
+            field: StageField::Status,
+        };
+        assert_eq!(status.value_type(), ValueType::Str);
+        assert_eq!(Reference::ItemCount.value_type(), ValueType::Int);
+    }
+
+    #[test]
+    fn test_error_display_syntax() {
+        let err = ConditionError::Syntax {
+            offset: 4,
+            message: "unexpected end of input".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "syntax error at offset 4: unexpected end of input"
+        );
+    }
+}
@@ -0,0 +1,450 @@
+//! Tokenizer and recursive-descent parser for condition expressions.
+
+use super::{
+    BinaryOp, ConditionError, Expr, PipelineField, Reference, SourceField, StageField,
+    TriggerField, Value,
+};
+
+/// A lexical token with its byte offset in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Int(i64),
+    Str(String),
+    Ident(String),
+    Dot,
+    LParen,
+    RParen,
+    Not,
+    And,
+    Or,
+    Op(BinaryOp),
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Int(n) => write!(f, "{n}"),
+            Self::Str(s) => write!(f, "'{s}'"),
+            Self::Ident(s) => write!(f, "{s}"),
+            Self::Dot => write!(f, "."),
+            Self::LParen => write!(f, "("),
+            Self::RParen => write!(f, ")"),
+            Self::Not => write!(f, "!"),
+            Self::And => write!(f, "&&"),
+            Self::Or => write!(f, "||"),
+            Self::Op(op) => write!(f, "{op}"),
+        }
+    }
+}
+
+fn syntax(offset: usize, message: impl Into<String>) -> ConditionError {
+    ConditionError::Syntax {
+        offset,
+        message: message.into(),
+    }
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_'
+}
+
+fn is_ident_continue(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '-'
+}
+
+/// Split the input into tokens.
+fn tokenize(input: &str) -> Result<Vec<(usize, Token)>, ConditionError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some(&(offset, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        let two = input.get(offset..offset + 2);
+        let token = match (c, two) {
+            (_, Some("&&")) => Some((Token::And, 2)),
+            (_, Some("||")) => Some((Token::Or, 2)),
+            (_, Some("==")) => Some((Token::Op(BinaryOp::Eq), 2)),
+            (_, Some("!=")) => Some((Token::Op(BinaryOp::Ne), 2)),
+            (_, Some("<=")) => Some((Token::Op(BinaryOp::Le), 2)),
+            (_, Some(">=")) => Some((Token::Op(BinaryOp::Ge), 2)),
+            ('<', _) => Some((Token::Op(BinaryOp::Lt), 1)),
+            ('>', _) => Some((Token::Op(BinaryOp::Gt), 1)),
+            ('!', _) => Some((Token::Not, 1)),
+            ('(', _) => Some((Token::LParen, 1)),
+            (')', _) => Some((Token::RParen, 1)),
+            ('.', _) => Some((Token::Dot, 1)),
+            _ => None,
+        };
+        if let Some((token, len)) = token {
+            for _ in 0..len {
+                chars.next();
+            }
+            tokens.push((offset, token));
+            continue;
+        }
+
+        if c == '\'' || c == '"' {
+            chars.next();
+            let start = offset + 1;
+            let mut end = None;
+            for (i, ch) in chars.by_ref() {
+                if ch == c {
+                    end = Some(i);
+                    break;
+                }
+            }
+            let end = end.ok_or_else(|| syntax(offset, "unterminated string literal"))?;
+            tokens.push((offset, Token::Str(input[start..end].to_string())));
+            continue;
+        }
+
+        if c.is_ascii_digit() {
+            let mut end = offset;
+            while let Some(&(i, ch)) = chars.peek() {
+                if !ch.is_ascii_digit() {
+                    break;
+                }
+                end = i + ch.len_utf8();
+                chars.next();
+            }
+            let text = &input[offset..end];
+            let value = text
+                .parse::<i64>()
+                .map_err(|e| syntax(offset, format!("invalid integer '{text}': {e}")))?;
+            tokens.push((offset, Token::Int(value)));
+            continue;
+        }
+
+        if is_ident_start(c) {
+            let mut end = offset;
+            while let Some(&(i, ch)) = chars.peek() {
+                if !is_ident_continue(ch) {
+                    break;
+                }
+                end = i + ch.len_utf8();
+                chars.next();
+            }
+            let word = &input[offset..end];
+            let token = match word {
+                "and" => Token::And,
+                "or" => Token::Or,
+                "not" => Token::Not,
+                _ => Token::Ident(word.to_string()),
+            };
+            tokens.push((offset, token));
+            continue;
+        }
+
+        return Err(syntax(offset, format!("unexpected character '{c}'")));
+    }
+
+    Ok(tokens)
+}
+
+/// Parse a condition expression.
+///
+/// Parsing only checks syntax and that reference paths name known
+/// fields; use [`super::check`] to validate types and names against a
+/// pipeline spec.
+///
+/// # Errors
+///
+/// Returns [`ConditionError::Syntax`] for malformed input and
+/// [`ConditionError::UnknownReference`] for unrecognised paths.
+pub fn parse(input: &str) -> Result<Expr, ConditionError> {
+    let tokens = tokenize(input)?;
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+        len: input.len(),
+    };
+    let expr = parser.parse_or()?;
+    if let Some((offset, token)) = parser.tokens.get(parser.pos) {
+        return Err(syntax(*offset, format!("unexpected token '{token}'")));
+    }
+    Ok(expr)
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    pos: usize,
+    len: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(_, t)| t)
+    }
+
+    fn offset(&self) -> usize {
+        self.tokens.get(self.pos).map_or(self.len, |(o, _)| *o)
+    }
+
+    fn next(&mut self) -> Option<(usize, Token)> {
+        let token = self.tokens.get(self.pos).cloned();
+        if token.is_some() {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, ConditionError> {
+        let mut lhs = self.parse_and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            let rhs = self.parse_and()?;
+            lhs = Expr::Binary {
+                op: BinaryOp::Or,
+                lhs: Box::new(lhs),
+                rhs: Box::new(rhs),
+            };
+        }
+        Ok(lhs)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, ConditionError> {
+        let mut lhs = self.parse_not()?;
+        while self.peek() == Some(&Token::And) {
+            self.pos += 1;
+            let rhs = self.parse_not()?;
+            lhs = Expr::Binary {
+                op: BinaryOp::And,
+                lhs: Box::new(lhs),
+                rhs: Box::new(rhs),
+            };
+        }
+        Ok(lhs)
+    }
+
+    fn parse_not(&mut self) -> Result<Expr, ConditionError> {
+        if self.peek() == Some(&Token::Not) {
+            self.pos += 1;
+            let inner = self.parse_not()?;
+            return Ok(Expr::Not(Box::new(inner)));
+        }
+        self.parse_comparison()
+    }
+
+    fn parse_comparison(&mut self) -> Result<Expr, ConditionError> {
+        let lhs = self.parse_primary()?;
+        let Some(Token::Op(op)) = self.peek().cloned() else {
+            return Ok(lhs);
+        };
+        self.pos += 1;
+        let rhs = self.parse_primary()?;
+        if let Some(Token::Op(next)) = self.peek() {
+            return Err(syntax(
+                self.offset(),
+                format!("comparison operators cannot be chained ('{op}' followed by '{next}')"),
+            ));
+        }
+        Ok(Expr::Binary {
+            op,
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+        })
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, ConditionError> {
+        let offset = self.offset();
+        match self.next() {
+            Some((_, Token::Int(n))) => Ok(Expr::Literal(Value::Int(n))),
+            Some((_, Token::Str(s))) => Ok(Expr::Literal(Value::Str(s))),
+            Some((_, Token::LParen)) => {
+                let inner = self.parse_or()?;
+                match self.next() {
+                    Some((_, Token::RParen)) => Ok(inner),
+                    _ => Err(syntax(self.offset(), "expected ')'")),
+                }
+            }
+            Some((_, Token::Ident(word))) => match word.as_str() {
+                "true" => Ok(Expr::Literal(Value::Bool(true))),
+                "false" => Ok(Expr::Literal(Value::Bool(false))),
+                _ => self.parse_reference(offset, word),
+            },
+            Some((o, token)) => Err(syntax(o, format!("unexpected token '{token}'"))),
+            None => Err(syntax(offset, "unexpected end of input")),
+        }
+    }
+
+    fn parse_reference(&mut self, offset: usize, head: String) -> Result<Expr, ConditionError> {
+        let mut segments = vec![head];
+        while self.peek() == Some(&Token::Dot) {
+            self.pos += 1;
+            match self.next() {
+                Some((_, Token::Ident(segment))) => segments.push(segment),
+                _ => return Err(syntax(self.offset(), "expected identifier after '.'")),
+            }
+        }
+        resolve_path(&segments)
+            .map(Expr::Ref)
+            .map_err(|message| ConditionError::UnknownReference {
+                path: segments.join("."),
+                message: format!("{message} (at offset {offset})"),
+            })
+    }
+}
+
+/// Map a dotted path onto a typed [`Reference`].
+fn resolve_path(segments: &[String]) -> Result<Reference, String> {
+    let parts: Vec<&str> = segments.iter().map(String::as_str).collect();
+    match parts.as_slice() {
+        ["pipeline", field] => PipelineField::from_name(field)
+            .map(Reference::Pipeline)
+            .ok_or_else(|| format!("'{field}' is not a pipeline field")),
+        ["source", field] => SourceField::from_name(field)
+            .map(|field| Reference::Source { name: None, field })
+            .ok_or_else(|| format!("'{field}' is not a source field")),
+        ["sources", name, field] => SourceField::from_name(field)
+            .map(|field| Reference::Source {
+                name: Some((*name).to_string()),
+                field,
+            })
+            .ok_or_else(|| format!("'{field}' is not a source field")),
+        ["stages", name, field] => StageField::from_name(field)
+            .map(|field| Reference::Stage {
+                name: (*name).to_string(),
+                field,
+            })
+            .ok_or_else(|| format!("'{field}' is not a stage field")),
+        ["items", "count"] => Ok(Reference::ItemCount),
+        ["trigger", field] => TriggerField::from_name(field)
+            .map(Reference::Trigger)
+            .ok_or_else(|| format!("'{field}' is not a trigger field")),
+        _ => Err("expected one of pipeline.*, source.*, sources.<name>.*, \
+                  stages.<name>.*, items.count or trigger.*"
+            .to_string()),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn int(n: i64) -> Box<Expr> {
+        Box::new(Expr::Literal(Value::Int(n)))
+    }
+
+    #[test]
+    fn test_parse_simple_comparison() {
+        let expr = parse("source.items_discovered > 0").unwrap();
+        assert_eq!(
+            expr,
+            Expr::Binary {
+                op: BinaryOp::Gt,
+                lhs: Box::new(Expr::Ref(Reference::Source {
+                    name: None,
+                    field: SourceField::ItemsDiscovered,
+                })),
+                rhs: int(0),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_and_binds_tighter_than_or() {
+        let expr = parse("true || false && false").unwrap();
+        let Expr::Binary { op, rhs, .. } = expr else {
+            unreachable!("expected binary expression");
+        };
+        assert_eq!(op, BinaryOp::Or);
+        assert!(matches!(
+            *rhs,
+            Expr::Binary {
+                op: BinaryOp::And,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_keyword_operators() {
+        let symbolic = parse("!(items.count == 0) && pipeline.items_failed == 0").unwrap();
+        let keywords = parse("not (items.count == 0) and pipeline.items_failed == 0").unwrap();
+        assert_eq!(symbolic, keywords);
+    }
+
+    #[test]
+    fn test_parse_string_literals_both_quotes() {
+        let single = parse("stages.fetch.status == 'completed'").unwrap();
+        let double = parse("stages.fetch.status == \"completed\"").unwrap();
+        assert_eq!(single, double);
+    }
+
+    #[test]
+    fn test_parse_hyphenated_names() {
+        let expr = parse("sources.my-drive.items_accepted >= 10").unwrap();
+        let Expr::Binary { lhs, .. } = expr else {
+            unreachable!("expected binary expression");
+        };
+        assert_eq!(
+            *lhs,
+            Expr::Ref(Reference::Source {
+                name: Some("my-drive".to_string()),
+                field: SourceField::ItemsAccepted,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_chained_comparison() {
+        let err = parse("1 < items.count < 5").unwrap_err();
+        assert!(matches!(err, ConditionError::Syntax { .. }));
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_field() {
+        let err = parse("source.bytes > 0").unwrap_err();
+        assert!(matches!(err, ConditionError::UnknownReference { .. }));
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_root() {
+        let err = parse("x > 1").unwrap_err();
+        assert!(matches!(err, ConditionError::UnknownReference { path, .. } if path == "x"));
+    }
+
+    #[test]
+    fn test_parse_rejects_unterminated_string() {
+        let err = parse("stages.a.status == 'done").unwrap_err();
+        assert!(matches!(err, ConditionError::Syntax { offset: 19, .. }));
+    }
+
+    #[test]
+    fn test_parse_rejects_unbalanced_parens() {
+        assert!(parse("(items.count > 0").is_err());
+        assert!(parse("items.count > 0)").is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_empty_input() {
+        let err = parse("   ").unwrap_err();
+        assert!(matches!(err, ConditionError::Syntax { .. }));
+    }
+
+    #[test]
+    fn test_parse_rejects_unexpected_character() {
+        let err = parse("items.count > $").unwrap_err();
+        assert!(matches!(err, ConditionError::Syntax { offset: 14, .. }));
+    }
+
+    #[test]
+    fn test_parse_trigger_references() {
+        assert_eq!(
+            resolve_path(&["trigger".to_string(), "event".to_string()]),
+            Ok(Reference::Trigger(TriggerField::Event))
+        );
+        assert_eq!(
+            resolve_path(&["trigger".to_string(), "items_failed".to_string()]),
+            Ok(Reference::Trigger(TriggerField::Stats(
+                PipelineField::ItemsFailed
+            )))
+        );
+        assert!(parse("trigger.bogus == 1").is_err());
+    }
+}
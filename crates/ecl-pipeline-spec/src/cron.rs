@@ -0,0 +1,459 @@
+//! Cron expressions for `ScheduleSpec`.
+//!
+//! Supports the classic 5-field form (`min hour dom month dow`), a 6-field
+//! form with leading seconds, and the 7-field form with a trailing year.
+//! All times are evaluated in UTC.
+//!
+//! Each field accepts `*`, `?` (day fields only), single values, ranges
+//! (`a-b`), steps (`*/n`, `a-b/n`, `a/n`) and comma-separated lists.
+//! Months accept `JAN`–`DEC` and days of week accept `SUN`–`SAT`
+//! (`0` and `7` are both Sunday). As in Vixie cron, when both day-of-month
+//! and day-of-week are restricted, a day matches if *either* matches.
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, Timelike, Utc};
+use thiserror::Error;
+
+/// The latest year a schedule will be searched to.
+const MAX_YEAR: u32 = 2099;
+
+/// Errors raised while parsing a cron expression.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("invalid cron expression '{expr}': {message}")]
+pub struct CronError {
+    /// The expression as written.
+    pub expr: String,
+    /// Why it was rejected.
+    pub message: String,
+}
+
+/// A set of allowed values for one cron field, stored as a bitmask.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FieldSet {
+    bits: u128,
+    /// False when the field was `*` or `?` (matters for day fields).
+    restricted: bool,
+}
+
+impl FieldSet {
+    fn contains(self, value: u32) -> bool {
+        value < 128 && self.bits & (1u128 << value) != 0
+    }
+}
+
+/// Field bounds and aliases.
+struct FieldDef {
+    name: &'static str,
+    min: u32,
+    max: u32,
+    names: &'static [&'static str],
+    /// First value of `names` (1 for months, 0 for weekdays).
+    names_base: u32,
+}
+
+const SECONDS: FieldDef = FieldDef {
+    name: "second",
+    min: 0,
+    max: 59,
+    names: &[],
+    names_base: 0,
+};
+const MINUTES: FieldDef = FieldDef {
+    name: "minute",
+    min: 0,
+    max: 59,
+    names: &[],
+    names_base: 0,
+};
+const HOURS: FieldDef = FieldDef {
+    name: "hour",
+    min: 0,
+    max: 23,
+    names: &[],
+    names_base: 0,
+};
+const DAYS_OF_MONTH: FieldDef = FieldDef {
+    name: "day-of-month",
+    min: 1,
+    max: 31,
+    names: &[],
+    names_base: 0,
+};
+const MONTHS: FieldDef = FieldDef {
+    name: "month",
+    min: 1,
+    max: 12,
+    names: &[
+        "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+    ],
+    names_base: 1,
+};
+const DAYS_OF_WEEK: FieldDef = FieldDef {
+    name: "day-of-week",
+    min: 0,
+    max: 7,
+    names: &["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"],
+    names_base: 0,
+};
+const YEARS: FieldDef = FieldDef {
+    name: "year",
+    min: 2000,
+    max: MAX_YEAR,
+    names: &[],
+    names_base: 0,
+};
+
+/// A parsed cron expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronExpr {
+    source: String,
+    seconds: FieldSet,
+    minutes: FieldSet,
+    hours: FieldSet,
+    days_of_month: FieldSet,
+    months: FieldSet,
+    days_of_week: FieldSet,
+    /// Years as offsets from 2000; `None` means any year.
+    years: Option<FieldSet>,
+}
+
+impl CronExpr {
+    /// Parse a 5-, 6- or 7-field cron expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CronError` if the field count is wrong or any field
+    /// contains an out-of-range value or malformed syntax.
+    pub fn parse(expr: &str) -> Result<Self, CronError> {
+        let err = |message: String| CronError {
+            expr: expr.to_string(),
+            message,
+        };
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        let (sec, rest) = match fields.len() {
+            5 => ("0", &fields[..]),
+            6 | 7 => (fields[0], &fields[1..]),
+            n => return Err(err(format!("expected 5, 6 or 7 fields, found {n}"))),
+        };
+
+        let year = rest.get(5).copied();
+        let years = match year {
+            None | Some("*") | Some("?") => None,
+            Some(field) => {
+                // Years don't fit a u128 bitmask directly; store offsets.
+                let set = parse_field(field, &YEARS).map_err(err)?;
+                Some(set)
+            }
+        };
+
+        let mut days_of_week = parse_field(rest[4], &DAYS_OF_WEEK).map_err(err)?;
+        // Fold 7 (Sunday) onto 0.
+        if days_of_week.contains(7) {
+            days_of_week.bits = (days_of_week.bits | 1) & !(1u128 << 7);
+        }
+
+        Ok(Self {
+            source: expr.to_string(),
+            seconds: parse_field(sec, &SECONDS).map_err(err)?,
+            minutes: parse_field(rest[0], &MINUTES).map_err(err)?,
+            hours: parse_field(rest[1], &HOURS).map_err(err)?,
+            days_of_month: parse_field(rest[2], &DAYS_OF_MONTH).map_err(err)?,
+            months: parse_field(rest[3], &MONTHS).map_err(err)?,
+            days_of_week,
+            years,
+        })
+    }
+
+    /// The expression as written.
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// The first firing time strictly after `after`, or `None` if the
+    /// schedule never fires again (e.g. a past year or February 30th).
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let start = after.naive_utc().with_nanosecond(0)? + Duration::seconds(1);
+        self.next_from(start).map(|t| t.and_utc())
+    }
+
+    /// All firing times in the half-open interval `(after, until]`,
+    /// oldest first, capped at `limit` entries (the most recent are kept).
+    pub fn occurrences_between(
+        &self,
+        after: DateTime<Utc>,
+        until: DateTime<Utc>,
+        limit: usize,
+    ) -> Vec<DateTime<Utc>> {
+        let mut out = std::collections::VecDeque::new();
+        let mut cursor = after;
+        while let Some(next) = self.next_after(cursor) {
+            if next > until {
+                break;
+            }
+            if out.len() == limit {
+                out.pop_front();
+            }
+            if limit > 0 {
+                out.push_back(next);
+            }
+            cursor = next;
+        }
+        out.into()
+    }
+
+    fn day_matches(&self, date: NaiveDate) -> bool {
+        let dom = self.days_of_month.contains(date.day());
+        let dow = self
+            .days_of_week
+            .contains(date.weekday().num_days_from_sunday());
+        match (self.days_of_month.restricted, self.days_of_week.restricted) {
+            (true, true) => dom || dow,
+            (true, false) => dom,
+            (false, true) => dow,
+            (false, false) => true,
+        }
+    }
+
+    fn year_matches(&self, year: i32) -> bool {
+        let Ok(year) = u32::try_from(year) else {
+            return false;
+        };
+        match self.years {
+            None => year <= MAX_YEAR,
+            Some(set) => year >= YEARS.min && set.contains(year - YEARS.min),
+        }
+    }
+
+    fn next_from(&self, mut t: NaiveDateTime) -> Option<NaiveDateTime> {
+        loop {
+            if t.year() > MAX_YEAR as i32 {
+                return None;
+            }
+            if !self.year_matches(t.year()) {
+                t = NaiveDate::from_ymd_opt(t.year() + 1, 1, 1)?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if !self.months.contains(t.month()) {
+                let (y, m) = if t.month() == 12 {
+                    (t.year() + 1, 1)
+                } else {
+                    (t.year(), t.month() + 1)
+                };
+                t = NaiveDate::from_ymd_opt(y, m, 1)?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if !self.day_matches(t.date()) {
+                t = t.date().succ_opt()?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if !self.hours.contains(t.hour()) {
+                t = t.date().and_hms_opt(t.hour(), 0, 0)? + Duration::hours(1);
+                continue;
+            }
+            if !self.minutes.contains(t.minute()) {
+                t = t.date().and_hms_opt(t.hour(), t.minute(), 0)? + Duration::minutes(1);
+                continue;
+            }
+            if !self.seconds.contains(t.second()) {
+                t += Duration::seconds(1);
+                continue;
+            }
+            return Some(t);
+        }
+    }
+}
+
+impl std::fmt::Display for CronExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+/// Parse one field into a bitmask. Year values are stored as offsets
+/// from `YEARS.min` so they fit in 128 bits.
+fn parse_field(field: &str, def: &FieldDef) -> Result<FieldSet, String> {
+    let offset = if def.name == YEARS.name { YEARS.min } else { 0 };
+    if field == "*" || (field == "?" && matches!(def.name, "day-of-month" | "day-of-week")) {
+        let mut bits = 0u128;
+        for v in def.min..=def.max {
+            let shifted = v - offset;
+            if shifted < 128 {
+                bits |= 1u128 << shifted;
+            }
+        }
+        return Ok(FieldSet {
+            bits,
+            restricted: false,
+        });
+    }
+
+    let mut bits = 0u128;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step
+                    .parse()
+                    .map_err(|_| format!("invalid step '{step}' in {} field", def.name))?;
+                if step == 0 {
+                    return Err(format!("step must be positive in {} field", def.name));
+                }
+                (range, step)
+            }
+            None => (part, 1),
+        };
+
+        let (lo, hi) = if range == "*" {
+            (def.min, def.max)
+        } else if let Some((lo, hi)) = range.split_once('-') {
+            (parse_value(lo, def)?, parse_value(hi, def)?)
+        } else {
+            let v = parse_value(range, def)?;
+            // `a/n` means "from a to the end, every n".
+            if part.contains('/') {
+                (v, def.max)
+            } else {
+                (v, v)
+            }
+        };
+        if lo > hi {
+            return Err(format!("range {lo}-{hi} is reversed in {} field", def.name));
+        }
+
+        let mut v = lo;
+        while v <= hi {
+            let shifted = v - offset;
+            if shifted >= 128 {
+                return Err(format!("value {v} out of range in {} field", def.name));
+            }
+            bits |= 1u128 << shifted;
+            v += step;
+        }
+    }
+
+    Ok(FieldSet {
+        bits,
+        restricted: true,
+    })
+}
+
+fn parse_value(text: &str, def: &FieldDef) -> Result<u32, String> {
+    let upper = text.to_ascii_uppercase();
+    if let Some(idx) = def.names.iter().position(|n| *n == upper) {
+        return Ok(def.names_base + idx as u32);
+    }
+    let value: u32 = text
+        .parse()
+        .map_err(|_| format!("invalid value '{text}' in {} field", def.name))?;
+    if value < def.min || value > def.max {
+        return Err(format!(
+            "value {value} out of range {}-{} in {} field",
+            def.min, def.max, def.name
+        ));
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(y: i32, mo: u32, d: u32, h: u32, mi: u32, s: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, mo, d, h, mi, s).unwrap()
+    }
+
+    #[test]
+    fn test_parse_five_fields() {
+        let cron = CronExpr::parse("30 21 * * *").unwrap();
+        assert_eq!(cron.as_str(), "30 21 * * *");
+        assert_eq!(
+            cron.next_after(at(2026, 3, 13, 10, 0, 0)),
+            Some(at(2026, 3, 13, 21, 30, 0))
+        );
+    }
+
+    #[test]
+    fn test_next_after_is_strictly_after() {
+        let cron = CronExpr::parse("30 21 * * *").unwrap();
+        assert_eq!(
+            cron.next_after(at(2026, 3, 13, 21, 30, 0)),
+            Some(at(2026, 3, 14, 21, 30, 0))
+        );
+    }
+
+    #[test]
+    fn test_parse_seven_fields_with_seconds_and_year() {
+        let cron = CronExpr::parse("15 0 12 1 JAN ? 2027").unwrap();
+        assert_eq!(
+            cron.next_after(at(2026, 6, 1, 0, 0, 0)),
+            Some(at(2027, 1, 1, 12, 0, 15))
+        );
+        assert_eq!(cron.next_after(at(2027, 1, 1, 12, 0, 15)), None);
+    }
+
+    #[test]
+    fn test_steps_ranges_and_lists() {
+        let cron = CronExpr::parse("*/15 9-17 * * MON-FRI").unwrap();
+        // Friday 2026-03-13 17:50 -> Monday 09:00.
+        assert_eq!(
+            cron.next_after(at(2026, 3, 13, 17, 50, 0)),
+            Some(at(2026, 3, 16, 9, 0, 0))
+        );
+        let cron = CronExpr::parse("0 0,12 * * *").unwrap();
+        assert_eq!(
+            cron.next_after(at(2026, 3, 13, 1, 0, 0)),
+            Some(at(2026, 3, 13, 12, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_day_of_month_or_day_of_week() {
+        // The 1st of the month OR any Sunday.
+        let cron = CronExpr::parse("0 0 1 * 0").unwrap();
+        // 2026-03-13 is a Friday; next Sunday is the 15th.
+        assert_eq!(
+            cron.next_after(at(2026, 3, 13, 0, 0, 0)),
+            Some(at(2026, 3, 15, 0, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_sunday_as_seven() {
+        let a = CronExpr::parse("0 0 * * 7").unwrap();
+        let b = CronExpr::parse("0 0 * * SUN").unwrap();
+        let t = at(2026, 3, 13, 0, 0, 0);
+        assert_eq!(a.next_after(t), b.next_after(t));
+    }
+
+    #[test]
+    fn test_impossible_date_never_fires() {
+        let cron = CronExpr::parse("0 0 30 2 *").unwrap();
+        assert_eq!(cron.next_after(at(2026, 1, 1, 0, 0, 0)), None);
+    }
+
+    #[test]
+    fn test_occurrences_between_caps_to_most_recent() {
+        let cron = CronExpr::parse("0 * * * *").unwrap();
+        let all = cron.occurrences_between(at(2026, 3, 13, 0, 0, 0), at(2026, 3, 13, 5, 0, 0), 10);
+        assert_eq!(all.len(), 5);
+        assert_eq!(all[0], at(2026, 3, 13, 1, 0, 0));
+        let capped =
+            cron.occurrences_between(at(2026, 3, 13, 0, 0, 0), at(2026, 3, 13, 5, 0, 0), 2);
+        assert_eq!(
+            capped,
+            vec![at(2026, 3, 13, 4, 0, 0), at(2026, 3, 13, 5, 0, 0)]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(CronExpr::parse("* * * *").is_err());
+        assert!(CronExpr::parse("60 * * * *").is_err());
+        assert!(CronExpr::parse("*/0 * * * *").is_err());
+        assert!(CronExpr::parse("5-1 * * * *").is_err());
+        assert!(CronExpr::parse("0 0 * FOO *").is_err());
+        assert!(CronExpr::parse("? * * * *").is_err());
+        let err = CronExpr::parse("0 25 * * *").unwrap_err();
+        assert!(err.to_string().contains("hour"));
+    }
+}
@@ -16,6 +16,10 @@ pub struct DefaultsSpec {
     /// Default checkpoint strategy.
     #[serde(default)]
     pub checkpoint: CheckpointStrategy,
+
+    /// How much run history the checkpoint store keeps.
+    #[serde(default)]
+    pub retention: RetentionSpec,
 }
 
 fn default_concurrency() -> usize {
@@ -28,6 +32,7 @@ impl Default for DefaultsSpec {
             concurrency: default_concurrency(),
             retry: RetrySpec::default(),
             checkpoint: CheckpointStrategy::default(),
+            retention: RetentionSpec::default(),
         }
     }
 }
@@ -95,6 +100,31 @@ pub enum CheckpointStrategy {
     },
 }
 
+/// Checkpoint history retention.
+///
+/// Each run's final checkpoint and content-hash snapshot is kept until it
+/// falls outside a limit: beyond the `keep_runs` most recent runs, or
+/// started more than `keep_days` days ago. With neither set, history is
+/// kept forever. The most recent run and the most recent completed run
+/// are never pruned, since resume and incrementality depend on them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetentionSpec {
+    /// Keep at most this many runs.
+    #[serde(default)]
+    pub keep_runs: Option<usize>,
+
+    /// Keep runs started within this many days.
+    #[serde(default)]
+    pub keep_days: Option<u32>,
+}
+
+impl RetentionSpec {
+    /// Whether this policy never prunes anything.
+    pub fn is_unbounded(&self) -> bool {
+        self.keep_runs.is_none() && self.keep_days.is_none()
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -145,11 +175,26 @@ mod tests {
                 max_backoff_ms: 60_000,
             },
             checkpoint: CheckpointStrategy::Items { count: 50 },
+            retention: RetentionSpec {
+                keep_runs: Some(10),
+                keep_days: None,
+            },
         };
         let json = serde_json::to_string(&defaults).unwrap();
         let deserialized: DefaultsSpec = serde_json::from_str(&json).unwrap();
         assert_eq!(deserialized.concurrency, 8);
         assert_eq!(deserialized.retry, defaults.retry);
+        assert_eq!(deserialized.retention, defaults.retention);
+    }
+
+    #[test]
+    fn test_retention_spec_default_is_unbounded() {
+        let defaults: DefaultsSpec = toml::from_str("concurrency = 2").unwrap();
+        assert!(defaults.retention.is_unbounded());
+
+        let retention: RetentionSpec = toml::from_str("keep_days = 30").unwrap();
+        assert_eq!(retention.keep_days, Some(30));
+        assert!(!retention.is_unbounded());
     }
 
     #[test]
@@ -203,6 +248,7 @@ mod proptests {
                 concurrency,
                 retry: RetrySpec::default(),
                 checkpoint: CheckpointStrategy::default(),
+                retention: RetentionSpec::default(),
             };
             let json = serde_json::to_string(&defaults).unwrap();
             let deserialized: DefaultsSpec = serde_json::from_str(&json).unwrap();
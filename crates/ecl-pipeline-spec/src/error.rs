@@ -37,6 +37,44 @@ pub enum SpecError {
     #[error("pipeline has no sources defined")]
     EmptySources,
 
+    /// A stage's `condition` failed to parse or type-check.
+    #[error("stage '{stage}' has an invalid condition: {source}")]
+    InvalidCondition {
+        /// The stage that declares the condition.
+        stage: String,
+        /// The underlying condition error.
+        source: crate::condition::ConditionError,
+    },
+
+    /// The `[schedule]` cron expression is malformed.
+    #[error("invalid schedule: {source}")]
+    InvalidSchedule {
+        /// The underlying cron parse error.
+        source: crate::cron::CronError,
+    },
+
+    /// A stage names an adapter the stage catalog does not know.
+    #[error("stage '{stage}' uses unknown adapter '{adapter}'")]
+    UnknownAdapter {
+        /// The stage that names the adapter.
+        stage: String,
+        /// The adapter name that was not recognized.
+        adapter: String,
+    },
+
+    /// A stage's `params` do not match its adapter's parameter schema.
+    #[error("stage '{stage}' ({adapter}) has invalid params at {path}: {message}")]
+    InvalidParams {
+        /// The stage whose params are invalid.
+        stage: String,
+        /// The stage's adapter.
+        adapter: String,
+        /// Dotted path to the offending value, rooted at `params`.
+        path: String,
+        /// What is wrong with it.
+        message: String,
+    },
+
     /// Validation error with a custom message.
     #[error("validation error: {message}")]
     ValidationError {
@@ -105,6 +143,60 @@ mod tests {
         assert_eq!(err.to_string(), "pipeline has no sources defined");
     }
 
+    #[test]
+    fn test_error_display_invalid_condition() {
+        let err = SpecError::InvalidCondition {
+            stage: "emit".to_string(),
+            source: crate::condition::ConditionError::Type {
+                message: "condition must evaluate to a boolean, found integer".to_string(),
+            },
+        };
+        assert_eq!(
+            err.to_string(),
+            "stage 'emit' has an invalid condition: type error: condition must evaluate to a boolean, found integer"
+        );
+    }
+
+    #[test]
+    fn test_error_display_invalid_schedule() {
+        let err = SpecError::InvalidSchedule {
+            source: crate::cron::CronError {
+                expr: "* *".to_string(),
+                message: "expected 5, 6 or 7 fields, found 2".to_string(),
+            },
+        };
+        assert_eq!(
+            err.to_string(),
+            "invalid schedule: invalid cron expression '* *': expected 5, 6 or 7 fields, found 2"
+        );
+    }
+
+    #[test]
+    fn test_error_display_unknown_adapter() {
+        let err = SpecError::UnknownAdapter {
+            stage: "shape".to_string(),
+            adapter: "reshape".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "stage 'shape' uses unknown adapter 'reshape'"
+        );
+    }
+
+    #[test]
+    fn test_error_display_invalid_params() {
+        let err = SpecError::InvalidParams {
+            stage: "merge".to_string(),
+            adapter: "join".to_string(),
+            path: "params".to_string(),
+            message: "missing required field 'left_key'".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "stage 'merge' (join) has invalid params at params: missing required field 'left_key'"
+        );
+    }
+
     #[test]
     fn test_error_display_validation_error() {
         let err = SpecError::ValidationError {
@@ -4,19 +4,26 @@
 //! immutable after parsing and derive `Serialize + Deserialize` for
 //! embedding in checkpoints.
 
+pub mod condition;
+pub mod cron;
 pub mod defaults;
 pub mod error;
 pub mod lifecycle;
+pub mod params;
 pub mod source;
 pub mod stage;
 pub mod validation;
 
-pub use defaults::{CheckpointStrategy, DefaultsSpec, RetrySpec};
+pub use condition::ConditionError;
+pub use cron::{CronError, CronExpr};
+pub use defaults::{CheckpointStrategy, DefaultsSpec, RetentionSpec, RetrySpec};
 pub use error::{Result, SpecError};
 pub use lifecycle::LifecycleSpec;
+pub use params::{ParamsCatalog, ParamsViolation};
 pub use source::{
     CredentialRef, FileTypeFilter, FilesystemSourceSpec, FilterAction, FilterRule, GcsSourceSpec,
-    GoogleDriveSourceSpec, SftpSourceSpec, SlackSourceSpec, SourceSpec,
+    GoogleDriveSourceSpec, SftpFileAction, SftpPostProcessSpec, SftpSourceSpec, SlackSourceSpec,
+    SourceSpec,
 };
 pub use stage::{ResourceSpec, StageSpec};
 
@@ -54,8 +61,41 @@ pub struct TriggersSpec {
 /// Schedule configuration for cron-based pipeline execution.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScheduleSpec {
-    /// Cron expression (5 or 7 fields). Example: "30 21 * * *"
+    /// Cron expression (5, 6 or 7 fields, UTC). Example: "30 21 * * *"
     pub cron: String,
+
+    /// What the scheduler does with firings missed while it was down.
+    #[serde(default)]
+    pub catch_up: CatchUpPolicy,
+
+    /// How many of the most recent missed firings are considered at
+    /// startup; older ones are dropped without a record.
+    #[serde(default = "default_max_catch_up")]
+    pub max_catch_up: usize,
+}
+
+fn default_max_catch_up() -> usize {
+    10
+}
+
+impl ScheduleSpec {
+    /// Parse the cron expression.
+    pub fn parse_cron(&self) -> std::result::Result<CronExpr, CronError> {
+        CronExpr::parse(&self.cron)
+    }
+}
+
+/// How missed schedule firings are handled when the scheduler starts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CatchUpPolicy {
+    /// Record missed firings but do not run them.
+    #[default]
+    None,
+    /// Run once for the most recent missed firing.
+    Latest,
+    /// Run every missed firing in order, up to `max_catch_up`.
+    All,
 }
 
 /// The root configuration, deserialized from TOML.
@@ -117,6 +157,13 @@ impl PipelineSpec {
     pub fn validate(&self) -> Result<()> {
         validation::validate(self)
     }
+
+    /// Validate the spec, then check every stage's adapter and `params`
+    /// against `catalog`.
+    pub fn validate_with(&self, catalog: &dyn ParamsCatalog) -> Result<()> {
+        validation::validate(self)?;
+        validation::validate_params(self, catalog)
+    }
 }
 
 #[cfg(test)]
@@ -295,10 +342,22 @@ resources = { reads = ["raw-docs"] }
     fn test_schedule_spec_serde() {
         let schedule = ScheduleSpec {
             cron: "30 21 * * *".to_string(),
+            catch_up: CatchUpPolicy::All,
+            max_catch_up: 3,
         };
         let json = serde_json::to_string(&schedule).unwrap();
         let deserialized: ScheduleSpec = serde_json::from_str(&json).unwrap();
         assert_eq!(deserialized.cron, "30 21 * * *");
+        assert_eq!(deserialized.catch_up, CatchUpPolicy::All);
+        assert_eq!(deserialized.max_catch_up, 3);
+    }
+
+    #[test]
+    fn test_schedule_spec_defaults() {
+        let schedule: ScheduleSpec = toml::from_str(r#"cron = "0 * * * *""#).unwrap();
+        assert_eq!(schedule.catch_up, CatchUpPolicy::None);
+        assert_eq!(schedule.max_catch_up, 10);
+        assert!(schedule.parse_cron().is_ok());
     }
 
     #[test]
@@ -340,5 +399,6 @@ resources = { reads = ["raw"] }
         assert_eq!(triggers.on_success, vec!["walgreens-transformation.toml"]);
         let schedule = spec.schedule.unwrap();
         assert_eq!(schedule.cron, "30 21 * * *");
+        assert_eq!(schedule.catch_up, CatchUpPolicy::None);
     }
 }
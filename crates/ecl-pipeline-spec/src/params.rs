@@ -0,0 +1,385 @@
+//! Stage parameter schemas.
+//!
+//! Each stage adapter publishes a JSON Schema for its `params` table. A
+//! [`ParamsCatalog`] maps adapter names to those schemas so that
+//! [`PipelineSpec::validate_with`](crate::PipelineSpec::validate_with) can
+//! reject unknown adapters and malformed params before a run starts.
+//!
+//! [`check`] implements the subset of JSON Schema that derived parameter
+//! schemas use: `type`, `enum`, `const`, `properties`, `required`,
+//! `additionalProperties`, `items`, `minItems`/`maxItems`,
+//! `minLength`/`maxLength`, `minimum`/`maximum`, `allOf`/`anyOf`/`oneOf`
+//! and local `$ref`s. Other keywords (`format`, `default`, descriptions)
+//! are ignored.
+
+use serde_json::Value;
+use std::fmt;
+
+/// The stage adapters a runtime can build, and the schema of each one's params.
+pub trait ParamsCatalog {
+    /// The JSON Schema for `adapter`'s params, or `None` if the adapter
+    /// is not registered.
+    fn params_schema(&self, adapter: &str) -> Option<&Value>;
+}
+
+/// A params value that does not satisfy its schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamsViolation {
+    /// Where the violation is, as a dotted path rooted at `params`
+    /// (e.g. `params.conversions[0].format`).
+    pub path: String,
+    /// What is wrong at `path`.
+    pub message: String,
+}
+
+impl fmt::Display for ParamsViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Check `params` against `schema`, returning the first violation found.
+pub fn check(schema: &Value, params: &Value) -> Result<(), ParamsViolation> {
+    Checker { root: schema }.check(schema, params, "params")
+}
+
+/// Walks a value alongside its schema; `root` resolves `$ref`s.
+struct Checker<'a> {
+    root: &'a Value,
+}
+
+impl Checker<'_> {
+    fn check(&self, schema: &Value, value: &Value, path: &str) -> Result<(), ParamsViolation> {
+        let schema = match schema {
+            Value::Bool(true) => return Ok(()),
+            Value::Bool(false) => return Err(violation(path, "is not allowed")),
+            Value::Object(schema) => schema,
+            _ => return Ok(()),
+        };
+
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let target = reference
+                .strip_prefix('#')
+                .and_then(|pointer| self.root.pointer(pointer))
+                .ok_or_else(|| {
+                    violation(path, &format!("unresolvable schema $ref '{reference}'"))
+                })?;
+            self.check(target, value, path)?;
+        }
+
+        if let Some(types) = schema.get("type") {
+            let allowed: Vec<&str> = match types {
+                Value::String(name) => vec![name.as_str()],
+                Value::Array(names) => names.iter().filter_map(Value::as_str).collect(),
+                _ => Vec::new(),
+            };
+            if !allowed.is_empty() && !allowed.iter().any(|name| has_type(value, name)) {
+                return Err(violation(
+                    path,
+                    &format!(
+                        "expected {}, found {}",
+                        allowed.join(" or "),
+                        type_name(value)
+                    ),
+                ));
+            }
+        }
+
+        if let Some(options) = schema.get("enum").and_then(Value::as_array)
+            && !options.contains(value)
+        {
+            let options: Vec<String> = options.iter().map(Value::to_string).collect();
+            return Err(violation(
+                path,
+                &format!("must be one of {}, found {value}", options.join(", ")),
+            ));
+        }
+        if let Some(expected) = schema.get("const")
+            && expected != value
+        {
+            return Err(violation(
+                path,
+                &format!("must be {expected}, found {value}"),
+            ));
+        }
+
+        if let Some(parts) = schema.get("allOf").and_then(Value::as_array) {
+            for part in parts {
+                self.check(part, value, path)?;
+            }
+        }
+        if let Some(options) = schema.get("anyOf").and_then(Value::as_array)
+            && !options
+                .iter()
+                .any(|option| self.check(option, value, path).is_ok())
+        {
+            return Err(violation(path, "does not match any of the allowed forms"));
+        }
+        if let Some(options) = schema.get("oneOf").and_then(Value::as_array) {
+            let matches = options
+                .iter()
+                .filter(|option| self.check(option, value, path).is_ok())
+                .count();
+            if matches != 1 {
+                return Err(violation(
+                    path,
+                    &format!("must match exactly one of the allowed forms (matched {matches})"),
+                ));
+            }
+        }
+
+        match value {
+            Value::Object(fields) => self.check_object(schema, fields, path),
+            Value::Array(items) => self.check_array(schema, items, path),
+            Value::String(text) => check_string(schema, text, path),
+            Value::Number(number) => check_number(schema, number.as_f64(), path),
+            Value::Null | Value::Bool(_) => Ok(()),
+        }
+    }
+
+    fn check_object(
+        &self,
+        schema: &serde_json::Map<String, Value>,
+        fields: &serde_json::Map<String, Value>,
+        path: &str,
+    ) -> Result<(), ParamsViolation> {
+        if let Some(required) = schema.get("required").and_then(Value::as_array) {
+            for name in required.iter().filter_map(Value::as_str) {
+                if !fields.contains_key(name) {
+                    return Err(violation(path, &format!("missing required field '{name}'")));
+                }
+            }
+        }
+
+        let properties = schema.get("properties").and_then(Value::as_object);
+        let additional = schema.get("additionalProperties");
+        for (name, field) in fields {
+            let field_path = format!("{path}.{name}");
+            match properties.and_then(|properties| properties.get(name)) {
+                Some(field_schema) => self.check(field_schema, field, &field_path)?,
+                None => match additional {
+                    Some(Value::Bool(false)) => {
+                        return Err(violation(path, &format!("unknown field '{name}'")));
+                    }
+                    Some(additional) => self.check(additional, field, &field_path)?,
+                    None => {}
+                },
+            }
+        }
+        Ok(())
+    }
+
+    fn check_array(
+        &self,
+        schema: &serde_json::Map<String, Value>,
+        items: &[Value],
+        path: &str,
+    ) -> Result<(), ParamsViolation> {
+        if let Some(min) = schema.get("minItems").and_then(Value::as_u64)
+            && (items.len() as u64) < min
+        {
+            return Err(violation(
+                path,
+                &format!("must have at least {min} item(s)"),
+            ));
+        }
+        if let Some(max) = schema.get("maxItems").and_then(Value::as_u64)
+            && (items.len() as u64) > max
+        {
+            return Err(violation(path, &format!("must have at most {max} item(s)")));
+        }
+        if let Some(item_schema) = schema.get("items") {
+            for (index, item) in items.iter().enumerate() {
+                self.check(item_schema, item, &format!("{path}[{index}]"))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn check_string(
+    schema: &serde_json::Map<String, Value>,
+    text: &str,
+    path: &str,
+) -> Result<(), ParamsViolation> {
+    let length = text.chars().count() as u64;
+    if let Some(min) = schema.get("minLength").and_then(Value::as_u64)
+        && length < min
+    {
+        return Err(violation(
+            path,
+            &format!("must be at least {min} character(s)"),
+        ));
+    }
+    if let Some(max) = schema.get("maxLength").and_then(Value::as_u64)
+        && length > max
+    {
+        return Err(violation(
+            path,
+            &format!("must be at most {max} character(s)"),
+        ));
+    }
+    Ok(())
+}
+
+fn check_number(
+    schema: &serde_json::Map<String, Value>,
+    number: Option<f64>,
+    path: &str,
+) -> Result<(), ParamsViolation> {
+    let Some(number) = number else {
+        return Ok(());
+    };
+    if let Some(min) = schema.get("minimum").and_then(Value::as_f64)
+        && number < min
+    {
+        return Err(violation(path, &format!("must be at least {min}")));
+    }
+    if let Some(max) = schema.get("maximum").and_then(Value::as_f64)
+        && number > max
+    {
+        return Err(violation(path, &format!("must be at most {max}")));
+    }
+    Ok(())
+}
+
+fn has_type(value: &Value, name: &str) -> bool {
+    match name {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+        _ => true,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn violation(path: &str, message: &str) -> ParamsViolation {
+    ParamsViolation {
+        path: path.to_string(),
+        message: message.to_string(),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Shaped like a schemars-derived struct schema.
+    fn join_schema() -> Value {
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "JoinConfig",
+            "type": "object",
+            "properties": {
+                "join_type": { "type": "string", "default": "inner" },
+                "left_key": { "type": "string" },
+                "right_prefix": { "type": ["string", "null"] },
+                "keys": { "type": "array", "items": { "$ref": "#/$defs/Key" } },
+                "limit": { "type": "integer", "format": "uint32", "minimum": 0 },
+                "delimiter": { "type": "string", "minLength": 1, "maxLength": 1 }
+            },
+            "required": ["left_key"],
+            "$defs": {
+                "Key": {
+                    "type": "object",
+                    "properties": { "name": { "type": "string" } },
+                    "required": ["name"],
+                    "additionalProperties": false
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_check_accepts_valid_params() {
+        let params = json!({
+            "left_key": "id",
+            "right_prefix": null,
+            "keys": [{ "name": "a" }],
+            "limit": 10,
+            "delimiter": ","
+        });
+        check(&join_schema(), &params).unwrap();
+    }
+
+    #[test]
+    fn test_check_missing_required_field() {
+        let err = check(&join_schema(), &json!({})).unwrap_err();
+        assert_eq!(err.path, "params");
+        assert_eq!(err.message, "missing required field 'left_key'");
+    }
+
+    #[test]
+    fn test_check_wrong_type_reports_field_path() {
+        let err = check(&join_schema(), &json!({ "left_key": 7 })).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "params.left_key: expected string, found number"
+        );
+    }
+
+    #[test]
+    fn test_check_follows_refs_into_arrays() {
+        let params = json!({ "left_key": "id", "keys": [{ "name": "a" }, {}] });
+        let err = check(&join_schema(), &params).unwrap_err();
+        assert_eq!(err.path, "params.keys[1]");
+        assert_eq!(err.message, "missing required field 'name'");
+    }
+
+    #[test]
+    fn test_check_rejects_unknown_fields_when_closed() {
+        let params = json!({ "left_key": "id", "keys": [{ "name": "a", "nmae": "b" }] });
+        let err = check(&join_schema(), &params).unwrap_err();
+        assert_eq!(err.to_string(), "params.keys[0]: unknown field 'nmae'");
+    }
+
+    #[test]
+    fn test_check_numeric_and_length_bounds() {
+        let err = check(&join_schema(), &json!({ "left_key": "id", "limit": -1 })).unwrap_err();
+        assert_eq!(err.path, "params.limit");
+        let err = check(
+            &join_schema(),
+            &json!({ "left_key": "id", "delimiter": "||" }),
+        )
+        .unwrap_err();
+        assert_eq!(err.message, "must be at most 1 character(s)");
+    }
+
+    #[test]
+    fn test_check_enum_and_one_of() {
+        let schema = json!({
+            "oneOf": [
+                { "type": "string", "enum": ["zip", "gzip"] },
+                { "type": "object", "properties": { "kind": { "const": "custom" } }, "required": ["kind"] }
+            ]
+        });
+        check(&schema, &json!("zip")).unwrap();
+        check(&schema, &json!({ "kind": "custom" })).unwrap();
+        let err = check(&schema, &json!("rar")).unwrap_err();
+        assert!(err.message.contains("exactly one"));
+    }
+
+    #[test]
+    fn test_check_boolean_schemas() {
+        check(&json!(true), &json!({ "anything": 1 })).unwrap();
+        assert!(check(&json!(false), &json!(null)).is_err());
+    }
+}
@@ -65,7 +65,7 @@ pub struct SlackSourceSpec {
     /// Bot token credentials reference.
     pub credentials: CredentialRef,
 
-    /// Channel IDs to fetch messages from.
+    /// Channels to fetch messages from, by ID or name.
     pub channels: Vec<String>,
 
     /// How deep to follow threads (0 = top-level only).
@@ -183,15 +183,80 @@ pub struct SftpSourceSpec {
     #[serde(default)]
     pub pattern: Option<String>,
 
+    /// Walk subdirectories of `remote_path`. Default: false.
+    #[serde(default)]
+    pub recursive: bool,
+
+    /// How many directory levels below `remote_path` to walk when
+    /// `recursive` is set (0 = `remote_path` only). Unlimited if omitted.
+    #[serde(default)]
+    pub max_depth: Option<usize>,
+
+    /// Only process files modified after this timestamp.
+    /// Supports "last_run" as a magic value for incrementality.
+    #[serde(default)]
+    pub modified_after: Option<String>,
+
+    /// SSH keepalive interval in seconds for the pooled session (0 = off).
+    /// Default: 30.
+    #[serde(default = "default_keepalive_secs")]
+    pub keepalive_secs: u64,
+
+    /// What to do with fetched files on the server after a run.
+    #[serde(default)]
+    pub post_process: Option<SftpPostProcessSpec>,
+
     /// Named data stream for items from this source.
     #[serde(default)]
     pub stream: Option<String>,
 }
 
+/// Post-run handling of fetched SFTP files, the SFTP counterpart of
+/// [`LifecycleSpec`](crate::LifecycleSpec) for GCS.
+///
+/// A run succeeds when no item failed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SftpPostProcessSpec {
+    /// Action on pipeline success. Default: none.
+    #[serde(default)]
+    pub on_success: SftpFileAction,
+
+    /// Action on pipeline failure. Default: none.
+    #[serde(default)]
+    pub on_failure: SftpFileAction,
+}
+
+/// An action applied to each fetched file on the SFTP server.
+///
+/// Internally tagged (`action = "move"`), like [`CredentialRef`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum SftpFileAction {
+    /// Leave files where they are.
+    #[default]
+    None,
+    /// Move files into a directory, created if missing.
+    Move {
+        /// Destination directory; `{run_id}` expands to the run ID.
+        dir: String,
+    },
+    /// Rename files in place by appending a suffix.
+    Rename {
+        /// Suffix to append (e.g. `".done"`).
+        suffix: String,
+    },
+    /// Delete the files.
+    Delete,
+}
+
 fn default_ssh_port() -> u16 {
     22
 }
 
+fn default_keepalive_secs() -> u64 {
+    30
+}
+
 fn default_remote_root() -> String {
     "/".to_string()
 }
@@ -495,6 +560,18 @@ mod tests {
             },
             remote_path: "/data".to_string(),
             pattern: Some("wag_banyan_*.csv*".to_string()),
+            recursive: true,
+            max_depth: Some(2),
+            modified_after: None,
+            keepalive_secs: 30,
+            post_process: Some(SftpPostProcessSpec {
+                on_success: SftpFileAction::Move {
+                    dir: "/archive/{run_id}".to_string(),
+                },
+                on_failure: SftpFileAction::Rename {
+                    suffix: ".failed".to_string(),
+                },
+            }),
             stream: Some("raw-files".to_string()),
         });
         let json = serde_json::to_string(&source).unwrap();
@@ -516,9 +593,39 @@ mod tests {
             assert_eq!(sftp.port, 22);
             assert_eq!(sftp.remote_path, "/");
             assert!(sftp.pattern.is_none());
+            assert!(!sftp.recursive);
+            assert!(sftp.max_depth.is_none());
+            assert_eq!(sftp.keepalive_secs, 30);
+            assert!(sftp.post_process.is_none());
             assert!(sftp.stream.is_none());
         } else {
             panic!("expected Sftp variant");
         }
     }
+
+    #[test]
+    fn test_sftp_post_process_from_toml() {
+        let toml_str = r#"
+            on_success = { action = "move", dir = "/archive/{run_id}" }
+            on_failure = { action = "rename", suffix = ".failed" }
+        "#;
+        let spec: SftpPostProcessSpec = toml::from_str(toml_str).unwrap();
+        assert_eq!(
+            spec.on_success,
+            SftpFileAction::Move {
+                dir: "/archive/{run_id}".to_string()
+            }
+        );
+        assert_eq!(
+            spec.on_failure,
+            SftpFileAction::Rename {
+                suffix: ".failed".to_string()
+            }
+        );
+
+        let spec: SftpPostProcessSpec =
+            toml::from_str(r#"on_success = { action = "delete" }"#).unwrap();
+        assert_eq!(spec.on_success, SftpFileAction::Delete);
+        assert_eq!(spec.on_failure, SftpFileAction::None);
+    }
 }
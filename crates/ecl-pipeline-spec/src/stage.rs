@@ -47,6 +47,17 @@ pub struct StageSpec {
     pub output_stream: Option<String>,
 }
 
+impl StageSpec {
+    /// The stage's params, with an omitted `params` table read as an
+    /// empty one.
+    pub fn effective_params(&self) -> serde_json::Value {
+        match self.params {
+            serde_json::Value::Null => serde_json::Value::Object(serde_json::Map::new()),
+            ref params => params.clone(),
+        }
+    }
+}
+
 /// Resource access declarations in TOML-friendly form.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ResourceSpec {
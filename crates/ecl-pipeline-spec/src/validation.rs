@@ -1,7 +1,9 @@
 //! Validation logic for pipeline specifications.
 
 use crate::PipelineSpec;
+use crate::condition::{self, CheckScope};
 use crate::error::{Result, SpecError};
+use crate::params::{self, ParamsCatalog};
 
 /// Validate a pipeline specification.
 ///
@@ -9,6 +11,8 @@ use crate::error::{Result, SpecError};
 /// - Pipeline has at least one source
 /// - Pipeline has at least one stage
 /// - Every stage with a `source` field references an existing source
+/// - Every stage `condition` parses and type-checks against the spec
+/// - The `[schedule]` cron expression, if any, parses
 pub fn validate(spec: &PipelineSpec) -> Result<()> {
     if spec.sources.is_empty() {
         return Err(SpecError::EmptySources);
@@ -30,6 +34,56 @@ pub fn validate(spec: &PipelineSpec) -> Result<()> {
         }
     }
 
+    // Validate stage conditions.
+    let source_names: Vec<&str> = spec.sources.keys().map(String::as_str).collect();
+    let stage_names: Vec<&str> = spec.stages.keys().map(String::as_str).collect();
+    for (stage_name, stage_spec) in &spec.stages {
+        if let Some(ref expr) = stage_spec.condition {
+            let scope = CheckScope {
+                stage: stage_name,
+                source: stage_spec.source.as_deref(),
+                sources: &source_names,
+                stages: &stage_names,
+            };
+            condition::compile(expr, &scope).map_err(|source| SpecError::InvalidCondition {
+                stage: stage_name.clone(),
+                source,
+            })?;
+        }
+    }
+
+    if let Some(ref schedule) = spec.schedule {
+        schedule
+            .parse_cron()
+            .map_err(|source| SpecError::InvalidSchedule { source })?;
+    }
+
+    Ok(())
+}
+
+/// Check every stage's adapter and params against a stage catalog.
+///
+/// Checks:
+/// - Every stage's `adapter` is registered in `catalog`
+/// - Every stage's `params` (an omitted table counts as empty) satisfy
+///   the adapter's parameter schema
+pub fn validate_params(spec: &PipelineSpec, catalog: &dyn ParamsCatalog) -> Result<()> {
+    for (stage_name, stage_spec) in &spec.stages {
+        let schema = catalog.params_schema(&stage_spec.adapter).ok_or_else(|| {
+            SpecError::UnknownAdapter {
+                stage: stage_name.clone(),
+                adapter: stage_spec.adapter.clone(),
+            }
+        })?;
+        params::check(schema, &stage_spec.effective_params()).map_err(|violation| {
+            SpecError::InvalidParams {
+                stage: stage_name.clone(),
+                adapter: stage_spec.adapter.clone(),
+                path: violation.path,
+                message: violation.message,
+            }
+        })?;
+    }
     Ok(())
 }
 
@@ -124,9 +178,136 @@ mod tests {
         assert!(matches!(err, SpecError::UnknownSource { .. }));
     }
 
+    #[test]
+    fn test_validate_valid_condition_passes() {
+        let mut spec = minimal_spec();
+        spec.stages.get_mut("extract").unwrap().condition =
+            Some("source.items_discovered > 0".to_string());
+        assert!(validate(&spec).is_ok());
+    }
+
+    #[test]
+    fn test_validate_invalid_condition_syntax_fails() {
+        let mut spec = minimal_spec();
+        spec.stages.get_mut("extract").unwrap().condition =
+            Some("source.items_discovered >".to_string());
+        let err = validate(&spec).unwrap_err();
+        assert!(matches!(err, SpecError::InvalidCondition { ref stage, .. } if stage == "extract"));
+    }
+
+    #[test]
+    fn test_validate_condition_unknown_stage_fails() {
+        let mut spec = minimal_spec();
+        spec.stages.get_mut("extract").unwrap().condition =
+            Some("stages.missing.status == 'completed'".to_string());
+        let err = validate(&spec).unwrap_err();
+        assert!(matches!(
+            err,
+            SpecError::InvalidCondition {
+                source: crate::condition::ConditionError::UnknownReference { .. },
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_validate_invalid_schedule_fails() {
+        let mut spec = minimal_spec();
+        spec.schedule = Some(crate::ScheduleSpec {
+            cron: "61 * * * *".to_string(),
+            catch_up: crate::CatchUpPolicy::None,
+            max_catch_up: 10,
+        });
+        let err = validate(&spec).unwrap_err();
+        assert!(matches!(err, SpecError::InvalidSchedule { .. }));
+    }
+
     #[test]
     fn test_validate_valid_spec_passes() {
         let spec = minimal_spec();
         assert!(validate(&spec).is_ok());
     }
+
+    /// Knows only `extract` (no params) and `rename` (requires `to`).
+    struct TestCatalog {
+        extract: serde_json::Value,
+        rename: serde_json::Value,
+    }
+
+    impl TestCatalog {
+        fn new() -> Self {
+            Self {
+                extract: serde_json::json!({ "type": "object" }),
+                rename: serde_json::json!({
+                    "type": "object",
+                    "properties": { "to": { "type": "string" } },
+                    "required": ["to"]
+                }),
+            }
+        }
+    }
+
+    impl ParamsCatalog for TestCatalog {
+        fn params_schema(&self, adapter: &str) -> Option<&serde_json::Value> {
+            match adapter {
+                "extract" => Some(&self.extract),
+                "rename" => Some(&self.rename),
+                _ => None,
+            }
+        }
+    }
+
+    fn with_stage(adapter: &str, params: serde_json::Value) -> PipelineSpec {
+        let mut spec = minimal_spec();
+        let mut stage = spec.stages["extract"].clone();
+        stage.adapter = adapter.to_string();
+        stage.source = None;
+        stage.params = params;
+        spec.stages.insert("shape".to_string(), stage);
+        spec
+    }
+
+    #[test]
+    fn test_validate_params_accepts_omitted_params() {
+        // `extract` has no params table at all; it reads as `{}`.
+        let spec = with_stage("rename", serde_json::json!({ "to": "title" }));
+        validate_params(&spec, &TestCatalog::new()).unwrap();
+    }
+
+    #[test]
+    fn test_validate_params_unknown_adapter_fails() {
+        let spec = with_stage("reshape", serde_json::Value::Null);
+        let err = validate_params(&spec, &TestCatalog::new()).unwrap_err();
+        assert!(matches!(
+            err,
+            SpecError::UnknownAdapter { ref stage, ref adapter } if stage == "shape" && adapter == "reshape"
+        ));
+    }
+
+    #[test]
+    fn test_validate_params_schema_violation_fails() {
+        let spec = with_stage("rename", serde_json::json!({ "to": 3 }));
+        let err = validate_params(&spec, &TestCatalog::new()).unwrap_err();
+        match err {
+            SpecError::InvalidParams {
+                stage,
+                path,
+                message,
+                ..
+            } => {
+                assert_eq!(stage, "shape");
+                assert_eq!(path, "params.to");
+                assert_eq!(message, "expected string, found number");
+            }
+            other => unreachable!("unexpected error: {other}"),
+        }
+    }
+
+    #[test]
+    fn test_validate_with_runs_structural_checks_first() {
+        let mut spec = with_stage("reshape", serde_json::Value::Null);
+        spec.sources.clear();
+        let err = spec.validate_with(&TestCatalog::new()).unwrap_err();
+        assert!(matches!(err, SpecError::EmptySources));
+    }
 }
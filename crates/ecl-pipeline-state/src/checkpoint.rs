@@ -190,6 +190,7 @@ resources = { reads = ["raw-docs"] }
             sources,
             stages,
             stats: PipelineStats::default(),
+            triggered_by: None,
         };
 
         Checkpoint {
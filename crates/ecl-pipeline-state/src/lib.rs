@@ -15,6 +15,7 @@ pub mod error;
 pub mod ids;
 pub mod memory_store;
 pub mod redb_store;
+pub mod retention;
 pub mod store;
 pub mod types;
 
@@ -25,8 +26,9 @@ pub use memory_store::InMemoryStateStore;
 pub use redb_store::RedbStateStore;
 pub use store::StateStore;
 pub use types::{
-    CompletedStageRecord, ItemProvenance, ItemState, ItemStatus, PipelineStats, PipelineStatus,
-    SourceState, StageState, StageStatus,
+    CompletedStageRecord, FiringOutcome, ItemProvenance, ItemState, ItemStatus, PipelineStats,
+    PipelineStatus, RunSummary, ScheduleFiring, SourceState, StageState, StageStatus, TriggerEvent,
+    TriggeredBy,
 };
 
 use chrono::{DateTime, Utc};
@@ -63,6 +65,11 @@ pub struct PipelineState {
 
     /// Summary statistics (derived, but cached for observability).
     pub stats: PipelineStats,
+
+    /// The upstream run that triggered this one, if it was started by a
+    /// `[triggers]` chain rather than directly.
+    #[serde(default)]
+    pub triggered_by: Option<TriggeredBy>,
 }
 
 impl PipelineState {
@@ -178,6 +185,7 @@ mod tests {
             sources,
             stages,
             stats: PipelineStats::default(),
+            triggered_by: None,
         }
     }
 
@@ -190,6 +198,14 @@ mod tests {
         assert_eq!(json, json2);
     }
 
+    #[test]
+    fn test_pipeline_state_without_triggered_by_deserializes() {
+        let mut value = serde_json::to_value(make_pipeline_state()).unwrap();
+        value.as_object_mut().unwrap().remove("triggered_by");
+        let state: PipelineState = serde_json::from_value(value).unwrap();
+        assert!(state.triggered_by.is_none());
+    }
+
     #[test]
     fn test_pipeline_state_update_stats_empty() {
         let mut state = PipelineState {
@@ -202,6 +218,7 @@ mod tests {
             sources: BTreeMap::new(),
             stages: BTreeMap::new(),
             stats: PipelineStats::default(),
+            triggered_by: None,
         };
         state.update_stats();
         assert_eq!(
@@ -259,6 +276,7 @@ mod tests {
             sources: BTreeMap::new(),
             stages: BTreeMap::new(),
             stats: PipelineStats::default(),
+            triggered_by: None,
         };
         state.sources.insert(
             "source-a".to_string(),
@@ -294,6 +312,7 @@ mod tests {
             sources: BTreeMap::new(),
             stages: BTreeMap::new(),
             stats: PipelineStats::default(),
+            triggered_by: None,
         };
         let mut items = BTreeMap::new();
         items.insert(
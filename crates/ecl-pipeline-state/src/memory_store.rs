@@ -1,13 +1,17 @@
 //! In-memory StateStore implementation for testing.
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ecl_pipeline_spec::RetentionSpec;
 use std::collections::BTreeMap;
 use tokio::sync::RwLock;
 
 use crate::checkpoint::Checkpoint;
 use crate::error::StateError;
 use crate::ids::{Blake3Hash, RunId};
+use crate::retention::expired_runs;
 use crate::store::StateStore;
+use crate::types::{RunSummary, ScheduleFiring};
 
 /// In-memory state store for unit and integration testing.
 ///
@@ -17,8 +21,14 @@ use crate::store::StateStore;
 pub struct InMemoryStateStore {
     /// The most recent checkpoint.
     checkpoint: RwLock<Option<Checkpoint>>,
+    /// The last checkpoint of every retained run, keyed by run id.
+    runs: RwLock<BTreeMap<String, Checkpoint>>,
     /// Content hashes from the most recent completed run.
     hashes: RwLock<BTreeMap<String, Blake3Hash>>,
+    /// Content-hash snapshot of every retained completed run, keyed by run id.
+    run_hashes: RwLock<BTreeMap<String, BTreeMap<String, Blake3Hash>>>,
+    /// Schedule firings, keyed by scheduled slot.
+    firings: RwLock<BTreeMap<DateTime<Utc>, ScheduleFiring>>,
 }
 
 impl InMemoryStateStore {
@@ -26,7 +36,10 @@ impl InMemoryStateStore {
     pub fn new() -> Self {
         Self {
             checkpoint: RwLock::new(None),
+            runs: RwLock::new(BTreeMap::new()),
             hashes: RwLock::new(BTreeMap::new()),
+            run_hashes: RwLock::new(BTreeMap::new()),
+            firings: RwLock::new(BTreeMap::new()),
         }
     }
 }
@@ -39,6 +52,10 @@ impl StateStore for InMemoryStateStore {
     ) -> std::result::Result<(), StateError> {
         let mut guard = self.checkpoint.write().await;
         *guard = Some(checkpoint.clone());
+        self.runs.write().await.insert(
+            checkpoint.state.run_id.as_str().to_string(),
+            checkpoint.clone(),
+        );
         Ok(())
     }
 
@@ -56,13 +73,75 @@ impl StateStore for InMemoryStateStore {
 
     async fn save_completed_hashes(
         &self,
-        _run_id: &RunId,
+        run_id: &RunId,
         hashes: &BTreeMap<String, Blake3Hash>,
     ) -> std::result::Result<(), StateError> {
         let mut guard = self.hashes.write().await;
         *guard = hashes.clone();
+        self.run_hashes
+            .write()
+            .await
+            .insert(run_id.as_str().to_string(), hashes.clone());
+        Ok(())
+    }
+
+    async fn record_schedule_firing(
+        &self,
+        firing: &ScheduleFiring,
+    ) -> std::result::Result<(), StateError> {
+        let mut guard = self.firings.write().await;
+        guard.insert(firing.scheduled_for, firing.clone());
         Ok(())
     }
+
+    async fn load_schedule_firings(
+        &self,
+        limit: usize,
+    ) -> std::result::Result<Vec<ScheduleFiring>, StateError> {
+        let guard = self.firings.read().await;
+        Ok(guard.values().rev().take(limit).cloned().collect())
+    }
+
+    async fn list_runs(&self) -> std::result::Result<Vec<RunSummary>, StateError> {
+        let guard = self.runs.read().await;
+        let mut runs: Vec<RunSummary> = guard
+            .values()
+            .map(|checkpoint| RunSummary::from_state(&checkpoint.state))
+            .collect();
+        runs.sort_by_key(|run| std::cmp::Reverse(run.started_at));
+        Ok(runs)
+    }
+
+    async fn load_run(
+        &self,
+        run_id: &RunId,
+    ) -> std::result::Result<Option<Checkpoint>, StateError> {
+        let guard = self.runs.read().await;
+        Ok(guard.get(run_id.as_str()).cloned())
+    }
+
+    async fn load_run_hashes(
+        &self,
+        run_id: &RunId,
+    ) -> std::result::Result<BTreeMap<String, Blake3Hash>, StateError> {
+        let guard = self.run_hashes.read().await;
+        Ok(guard.get(run_id.as_str()).cloned().unwrap_or_default())
+    }
+
+    async fn prune_runs(
+        &self,
+        policy: &RetentionSpec,
+        now: DateTime<Utc>,
+    ) -> std::result::Result<Vec<RunId>, StateError> {
+        let expired = expired_runs(policy, &self.list_runs().await?, now);
+        let mut guard = self.runs.write().await;
+        let mut run_hashes = self.run_hashes.write().await;
+        for run_id in &expired {
+            guard.remove(run_id.as_str());
+            run_hashes.remove(run_id.as_str());
+        }
+        Ok(expired)
+    }
 }
 
 #[cfg(test)]
@@ -75,7 +154,7 @@ mod tests {
     use crate::types::{
         ItemProvenance, ItemState, ItemStatus, PipelineStats, SourceState, StageState, StageStatus,
     };
-    use chrono::{TimeZone, Utc};
+    use chrono::{TimeZone, Timelike};
     use ecl_pipeline_spec::PipelineSpec;
 
     const MINIMAL_TOML: &str = r#"
@@ -159,6 +238,7 @@ resources = { reads = ["raw-docs"] }
             sources,
             stages,
             stats: PipelineStats::default(),
+            triggered_by: None,
         };
 
         Checkpoint {
@@ -228,6 +308,85 @@ resources = { reads = ["raw-docs"] }
         assert!(loaded.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_memory_store_schedule_firings_newest_first() {
+        use crate::types::FiringOutcome;
+        let store = InMemoryStateStore::new();
+        for hour in [9, 11, 10] {
+            let slot = Utc.with_ymd_and_hms(2026, 3, 13, hour, 0, 0).unwrap();
+            store
+                .record_schedule_firing(&ScheduleFiring {
+                    scheduled_for: slot,
+                    fired_at: slot,
+                    outcome: FiringOutcome::Running,
+                })
+                .await
+                .unwrap();
+        }
+        // Re-recording a slot replaces it.
+        let slot = Utc.with_ymd_and_hms(2026, 3, 13, 11, 0, 0).unwrap();
+        store
+            .record_schedule_firing(&ScheduleFiring {
+                scheduled_for: slot,
+                fired_at: slot,
+                outcome: FiringOutcome::Missed,
+            })
+            .await
+            .unwrap();
+
+        let firings = store.load_schedule_firings(2).await.unwrap();
+        assert_eq!(firings.len(), 2);
+        assert_eq!(firings[0].scheduled_for, slot);
+        assert_eq!(firings[0].outcome, FiringOutcome::Missed);
+        assert_eq!(firings[1].scheduled_for.hour(), 10);
+    }
+
+    #[tokio::test]
+    async fn test_memory_store_run_history() {
+        let store = InMemoryStateStore::new();
+        for (run, days_ago) in [("run-a", 3), ("run-b", 2), ("run-c", 1)] {
+            let mut checkpoint = make_checkpoint();
+            checkpoint.state.run_id = RunId::new(run);
+            checkpoint.state.started_at = test_time() - chrono::Duration::days(days_ago);
+            store.save_checkpoint(&checkpoint).await.unwrap();
+            let hashes = BTreeMap::from([("doc".to_string(), Blake3Hash::new(run))]);
+            store
+                .save_completed_hashes(&RunId::new(run), &hashes)
+                .await
+                .unwrap();
+        }
+
+        let runs = store.list_runs().await.unwrap();
+        let ids: Vec<&str> = runs.iter().map(|r| r.run_id.as_str()).collect();
+        assert_eq!(ids, vec!["run-c", "run-b", "run-a"]);
+        let loaded = store.load_run(&RunId::new("run-a")).await.unwrap();
+        assert_eq!(loaded.unwrap().state.run_id.as_str(), "run-a");
+        let hashes = store.load_run_hashes(&RunId::new("run-b")).await.unwrap();
+        assert_eq!(hashes["doc"], Blake3Hash::new("run-b"));
+
+        let policy = RetentionSpec {
+            keep_runs: Some(2),
+            keep_days: None,
+        };
+        let pruned = store.prune_runs(&policy, test_time()).await.unwrap();
+        assert_eq!(pruned, vec![RunId::new("run-a")]);
+        assert!(
+            store
+                .load_run(&RunId::new("run-a"))
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(store.list_runs().await.unwrap().len(), 2);
+        assert!(
+            store
+                .load_run_hashes(&RunId::new("run-a"))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
     #[tokio::test]
     async fn test_memory_store_object_safety() {
         let store: Box<dyn StateStore> = Box::new(InMemoryStateStore::new());
@@ -6,15 +6,19 @@
 //! `tokio::task::spawn_blocking` to avoid blocking the async runtime.
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ecl_pipeline_spec::RetentionSpec;
 use redb::{Database, ReadableDatabase, ReadableTable, TableDefinition};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
 use std::path::Path;
 use std::sync::Arc;
 
 use crate::checkpoint::Checkpoint;
 use crate::error::StateError;
 use crate::ids::{Blake3Hash, RunId};
+use crate::retention::expired_runs;
 use crate::store::StateStore;
+use crate::types::{RunSummary, ScheduleFiring};
 
 /// redb table: run_id (str) -> serialized JSON checkpoint (bytes).
 const CHECKPOINTS: TableDefinition<&str, &[u8]> = TableDefinition::new("checkpoints");
@@ -28,6 +32,18 @@ const HASHES: TableDefinition<&str, &str> = TableDefinition::new("hashes");
 /// - "latest_completed_run_id" — the run_id of the most recent completed run
 const METADATA: TableDefinition<&str, &str> = TableDefinition::new("metadata");
 
+/// redb table: run_id (str) -> serialized JSON `RunSummary` (bytes).
+/// Lets `list_runs` avoid deserializing every full checkpoint.
+const RUNS: TableDefinition<&str, &[u8]> = TableDefinition::new("runs");
+
+/// redb table: (run_id, item_id) -> blake3 hex hash, the content-hash
+/// snapshot saved by each completed run.
+const RUN_HASHES: TableDefinition<(&str, &str), &str> = TableDefinition::new("run_hashes");
+
+/// redb table: scheduled slot (RFC 3339, UTC, second precision) ->
+/// serialized JSON `ScheduleFiring` (bytes). Keys sort chronologically.
+const FIRINGS: TableDefinition<&str, &[u8]> = TableDefinition::new("schedule_firings");
+
 /// Metadata key for the run ID of the most recent checkpoint.
 const KEY_LATEST_RUN_ID: &str = "latest_run_id";
 
@@ -36,10 +52,16 @@ const KEY_LATEST_COMPLETED_RUN_ID: &str = "latest_completed_run_id";
 
 /// Redb-backed state store providing crash-safe, ACID persistence.
 ///
-/// Uses three tables:
-/// - `checkpoints`: maps run_id -> serialized JSON checkpoint
+/// Uses six tables:
+/// - `checkpoints`: maps run_id -> serialized JSON checkpoint (the last
+///   checkpoint of every retained run)
+/// - `runs`: maps run_id -> serialized JSON run summary
 /// - `hashes`: maps item_id -> blake3 hex hash (for the latest completed run)
+/// - `run_hashes`: maps (run_id, item_id) -> blake3 hex hash (per-run snapshots)
 /// - `metadata`: maps string keys -> string values (for tracking latest run IDs)
+/// - `schedule_firings`: maps scheduled slot -> serialized JSON firing record
+///
+/// History grows until pruned with `StateStore::prune_runs`.
 ///
 /// All operations run inside `tokio::task::spawn_blocking` because redb
 /// performs synchronous disk I/O.
@@ -72,9 +94,9 @@ impl StateStore for RedbStateStore {
     /// Save a checkpoint atomically.
     ///
     /// Serializes the checkpoint to JSON, then writes it to the
-    /// `CHECKPOINTS` table keyed by run_id. Also updates
-    /// `METADATA["latest_run_id"]`. All writes happen in a single
-    /// redb write transaction (ACID).
+    /// `CHECKPOINTS` table keyed by run_id. Also updates the run's
+    /// `RUNS` summary and `METADATA["latest_run_id"]`. All writes happen
+    /// in a single redb write transaction (ACID).
     async fn save_checkpoint(
         &self,
         checkpoint: &Checkpoint,
@@ -84,6 +106,10 @@ impl StateStore for RedbStateStore {
             serde_json::to_vec(checkpoint).map_err(|e| StateError::SerializationError {
                 message: format!("failed to serialize checkpoint: {e}"),
             })?;
+        let summary_bytes = serde_json::to_vec(&RunSummary::from_state(&checkpoint.state))
+            .map_err(|e| StateError::SerializationError {
+                message: format!("failed to serialize run summary: {e}"),
+            })?;
         let run_id = checkpoint.state.run_id.as_str().to_owned();
 
         tokio::task::spawn_blocking(move || {
@@ -103,6 +129,17 @@ impl StateStore for RedbStateStore {
                         message: format!("failed to insert checkpoint: {e}"),
                     })?;
             }
+            {
+                let mut runs = write_txn
+                    .open_table(RUNS)
+                    .map_err(|e| StateError::StoreError {
+                        message: format!("failed to open runs table: {e}"),
+                    })?;
+                runs.insert(run_id.as_str(), summary_bytes.as_slice())
+                    .map_err(|e| StateError::StoreError {
+                        message: format!("failed to insert run summary: {e}"),
+                    })?;
+            }
             {
                 let mut meta =
                     write_txn
@@ -250,7 +287,8 @@ impl StateStore for RedbStateStore {
 
     /// Save content hashes at the end of a successful run.
     ///
-    /// Clears the `HASHES` table, writes all new hashes, and updates
+    /// Clears the `HASHES` table, writes all new hashes, records the same
+    /// hashes as the run's `RUN_HASHES` snapshot, and updates
     /// `METADATA["latest_completed_run_id"]`. All in a single ACID
     /// transaction.
     async fn save_completed_hashes(
@@ -309,6 +347,21 @@ impl StateStore for RedbStateStore {
                         })?;
                 }
             }
+            {
+                let mut snapshot =
+                    write_txn
+                        .open_table(RUN_HASHES)
+                        .map_err(|e| StateError::StoreError {
+                            message: format!("failed to open run_hashes table: {e}"),
+                        })?;
+                for (item_id, hash_hex) in &hashes_owned {
+                    snapshot
+                        .insert((run_id_str.as_str(), item_id.as_str()), hash_hex.as_str())
+                        .map_err(|e| StateError::StoreError {
+                            message: format!("failed to insert run hash: {e}"),
+                        })?;
+                }
+            }
             {
                 let mut meta =
                     write_txn
@@ -331,6 +384,307 @@ impl StateStore for RedbStateStore {
             message: format!("spawn_blocking join error: {e}"),
         })?
     }
+
+    /// Record a schedule firing, keyed by its scheduled slot.
+    async fn record_schedule_firing(
+        &self,
+        firing: &ScheduleFiring,
+    ) -> std::result::Result<(), StateError> {
+        let db = self.db.clone();
+        let json_bytes =
+            serde_json::to_vec(firing).map_err(|e| StateError::SerializationError {
+                message: format!("failed to serialize schedule firing: {e}"),
+            })?;
+        let key = firing_key(firing.scheduled_for);
+
+        tokio::task::spawn_blocking(move || {
+            let write_txn = db.begin_write().map_err(|e| StateError::StoreError {
+                message: format!("failed to begin write transaction: {e}"),
+            })?;
+            {
+                let mut table =
+                    write_txn
+                        .open_table(FIRINGS)
+                        .map_err(|e| StateError::StoreError {
+                            message: format!("failed to open schedule_firings table: {e}"),
+                        })?;
+                table
+                    .insert(key.as_str(), json_bytes.as_slice())
+                    .map_err(|e| StateError::StoreError {
+                        message: format!("failed to insert schedule firing: {e}"),
+                    })?;
+            }
+            write_txn.commit().map_err(|e| StateError::StoreError {
+                message: format!("failed to commit transaction: {e}"),
+            })?;
+            Ok(())
+        })
+        .await
+        .map_err(|e| StateError::StoreError {
+            message: format!("spawn_blocking join error: {e}"),
+        })?
+    }
+
+    /// Load the most recent schedule firings, newest first.
+    ///
+    /// Returns an empty list if no firing has been recorded yet.
+    async fn load_schedule_firings(
+        &self,
+        limit: usize,
+    ) -> std::result::Result<Vec<ScheduleFiring>, StateError> {
+        let db = self.db.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let read_txn = db.begin_read().map_err(|e| StateError::StoreError {
+                message: format!("failed to begin read transaction: {e}"),
+            })?;
+
+            let table: redb::ReadOnlyTable<&str, &[u8]> = match read_txn.open_table(FIRINGS) {
+                Ok(table) => table,
+                Err(_) => return Ok(Vec::new()),
+            };
+
+            let iter = table.iter().map_err(|e| StateError::StoreError {
+                message: format!("failed to iterate schedule_firings table: {e}"),
+            })?;
+
+            let mut firings = Vec::new();
+            for entry in iter.rev().take(limit) {
+                let entry = entry.map_err(|e| StateError::StoreError {
+                    message: format!("failed to read schedule firing: {e}"),
+                })?;
+                let firing: ScheduleFiring =
+                    serde_json::from_slice(entry.1.value()).map_err(|e| {
+                        StateError::SerializationError {
+                            message: format!("failed to deserialize schedule firing: {e}"),
+                        }
+                    })?;
+                firings.push(firing);
+            }
+
+            Ok(firings)
+        })
+        .await
+        .map_err(|e| StateError::StoreError {
+            message: format!("spawn_blocking join error: {e}"),
+        })?
+    }
+
+    /// List retained runs, most recently started first.
+    ///
+    /// Runs checkpointed before the `RUNS` table existed are summarized
+    /// from their checkpoints.
+    async fn list_runs(&self) -> std::result::Result<Vec<RunSummary>, StateError> {
+        let db = self.db.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let read_txn = db.begin_read().map_err(|e| StateError::StoreError {
+                message: format!("failed to begin read transaction: {e}"),
+            })?;
+
+            let checkpoints: redb::ReadOnlyTable<&str, &[u8]> =
+                match read_txn.open_table(CHECKPOINTS) {
+                    Ok(table) => table,
+                    Err(_) => return Ok(Vec::new()),
+                };
+            let summaries: Option<redb::ReadOnlyTable<&str, &[u8]>> =
+                read_txn.open_table(RUNS).ok();
+
+            let iter = checkpoints.iter().map_err(|e| StateError::StoreError {
+                message: format!("failed to iterate checkpoints table: {e}"),
+            })?;
+
+            let mut runs = Vec::new();
+            for entry in iter {
+                let entry = entry.map_err(|e| StateError::StoreError {
+                    message: format!("failed to read checkpoint entry: {e}"),
+                })?;
+                let summary = match summaries.as_ref() {
+                    Some(table) => {
+                        table
+                            .get(entry.0.value())
+                            .map_err(|e| StateError::StoreError {
+                                message: format!("failed to read run summary: {e}"),
+                            })?
+                    }
+                    None => None,
+                };
+                let summary: RunSummary = match summary {
+                    Some(bytes) => serde_json::from_slice(bytes.value()).map_err(|e| {
+                        StateError::SerializationError {
+                            message: format!("failed to deserialize run summary: {e}"),
+                        }
+                    })?,
+                    None => {
+                        let checkpoint: Checkpoint = serde_json::from_slice(entry.1.value())
+                            .map_err(|e| StateError::SerializationError {
+                                message: format!("failed to deserialize checkpoint: {e}"),
+                            })?;
+                        RunSummary::from_state(&checkpoint.state)
+                    }
+                };
+                runs.push(summary);
+            }
+
+            runs.sort_by_key(|run| std::cmp::Reverse(run.started_at));
+            Ok(runs)
+        })
+        .await
+        .map_err(|e| StateError::StoreError {
+            message: format!("spawn_blocking join error: {e}"),
+        })?
+    }
+
+    /// Load the last checkpoint of a retained run.
+    async fn load_run(
+        &self,
+        run_id: &RunId,
+    ) -> std::result::Result<Option<Checkpoint>, StateError> {
+        let db = self.db.clone();
+        let run_id = run_id.as_str().to_owned();
+
+        tokio::task::spawn_blocking(move || {
+            let read_txn = db.begin_read().map_err(|e| StateError::StoreError {
+                message: format!("failed to begin read transaction: {e}"),
+            })?;
+
+            let table: redb::ReadOnlyTable<&str, &[u8]> = match read_txn.open_table(CHECKPOINTS) {
+                Ok(table) => table,
+                Err(_) => return Ok(None),
+            };
+
+            let Some(bytes) = table
+                .get(run_id.as_str())
+                .map_err(|e| StateError::StoreError {
+                    message: format!("failed to read checkpoint: {e}"),
+                })?
+            else {
+                return Ok(None);
+            };
+
+            let checkpoint: Checkpoint = serde_json::from_slice(bytes.value()).map_err(|e| {
+                StateError::SerializationError {
+                    message: format!("failed to deserialize checkpoint: {e}"),
+                }
+            })?;
+            Ok(Some(checkpoint))
+        })
+        .await
+        .map_err(|e| StateError::StoreError {
+            message: format!("spawn_blocking join error: {e}"),
+        })?
+    }
+
+    /// Load a run's content-hash snapshot by scanning its key range in
+    /// the `RUN_HASHES` table.
+    async fn load_run_hashes(
+        &self,
+        run_id: &RunId,
+    ) -> std::result::Result<BTreeMap<String, Blake3Hash>, StateError> {
+        let db = self.db.clone();
+        let run_id = run_id.as_str().to_owned();
+
+        tokio::task::spawn_blocking(move || {
+            let read_txn = db.begin_read().map_err(|e| StateError::StoreError {
+                message: format!("failed to begin read transaction: {e}"),
+            })?;
+
+            let table: redb::ReadOnlyTable<(&str, &str), &str> =
+                match read_txn.open_table(RUN_HASHES) {
+                    Ok(table) => table,
+                    Err(_) => return Ok(BTreeMap::new()),
+                };
+
+            let iter =
+                table
+                    .range((run_id.as_str(), "")..)
+                    .map_err(|e| StateError::StoreError {
+                        message: format!("failed to iterate run_hashes table: {e}"),
+                    })?;
+
+            let mut hashes = BTreeMap::new();
+            for entry in iter {
+                let entry = entry.map_err(|e| StateError::StoreError {
+                    message: format!("failed to read run hash entry: {e}"),
+                })?;
+                let (entry_run, item_id) = entry.0.value();
+                if entry_run != run_id {
+                    break;
+                }
+                hashes.insert(item_id.to_owned(), Blake3Hash::new(entry.1.value()));
+            }
+            Ok(hashes)
+        })
+        .await
+        .map_err(|e| StateError::StoreError {
+            message: format!("spawn_blocking join error: {e}"),
+        })?
+    }
+
+    /// Delete expired runs' checkpoints, summaries and hash snapshots in
+    /// a single ACID transaction.
+    async fn prune_runs(
+        &self,
+        policy: &RetentionSpec,
+        now: DateTime<Utc>,
+    ) -> std::result::Result<Vec<RunId>, StateError> {
+        let expired = expired_runs(policy, &self.list_runs().await?, now);
+        if expired.is_empty() {
+            return Ok(expired);
+        }
+
+        let db = self.db.clone();
+        let expired_ids: HashSet<String> = expired.iter().map(|r| r.as_str().to_owned()).collect();
+
+        tokio::task::spawn_blocking(move || {
+            let write_txn = db.begin_write().map_err(|e| StateError::StoreError {
+                message: format!("failed to begin write transaction: {e}"),
+            })?;
+            for definition in [CHECKPOINTS, RUNS] {
+                let mut table =
+                    write_txn
+                        .open_table(definition)
+                        .map_err(|e| StateError::StoreError {
+                            message: format!("failed to open {definition} table: {e}"),
+                        })?;
+                for run_id in &expired_ids {
+                    table
+                        .remove(run_id.as_str())
+                        .map_err(|e| StateError::StoreError {
+                            message: format!("failed to remove run {run_id}: {e}"),
+                        })?;
+                }
+            }
+            {
+                let mut snapshot =
+                    write_txn
+                        .open_table(RUN_HASHES)
+                        .map_err(|e| StateError::StoreError {
+                            message: format!("failed to open run_hashes table: {e}"),
+                        })?;
+                snapshot
+                    .retain(|(run_id, _), _| !expired_ids.contains(run_id))
+                    .map_err(|e| StateError::StoreError {
+                        message: format!("failed to prune run hashes: {e}"),
+                    })?;
+            }
+            write_txn.commit().map_err(|e| StateError::StoreError {
+                message: format!("failed to commit transaction: {e}"),
+            })?;
+            Ok(())
+        })
+        .await
+        .map_err(|e| StateError::StoreError {
+            message: format!("spawn_blocking join error: {e}"),
+        })??;
+
+        Ok(expired)
+    }
+}
+
+/// Table key for a firing: fixed-width RFC 3339 so keys sort by time.
+fn firing_key(scheduled_for: chrono::DateTime<chrono::Utc>) -> String {
+    scheduled_for.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
 }
 
 #[cfg(test)]
@@ -391,6 +745,7 @@ resources = { reads = ["raw-docs"] }
             sources: BTreeMap::new(),
             stages,
             stats: PipelineStats::default(),
+            triggered_by: None,
         };
 
         Checkpoint {
@@ -850,6 +1205,214 @@ resources = { reads = ["raw-docs"] }
         assert!(matches!(err, StateError::SerializationError { .. }));
     }
 
+    #[tokio::test]
+    async fn test_redb_store_schedule_firings() {
+        use crate::types::FiringOutcome;
+        use chrono::TimeZone;
+
+        let dir = TempDir::new().unwrap();
+        let db_path = dir.path().join("test.redb");
+        let store = RedbStateStore::open(&db_path).unwrap();
+        assert!(store.load_schedule_firings(10).await.unwrap().is_empty());
+
+        for hour in [9, 11, 10] {
+            let slot = Utc.with_ymd_and_hms(2026, 3, 13, hour, 0, 0).unwrap();
+            store
+                .record_schedule_firing(&ScheduleFiring {
+                    scheduled_for: slot,
+                    fired_at: slot,
+                    outcome: FiringOutcome::Running,
+                })
+                .await
+                .unwrap();
+        }
+        let slot = Utc.with_ymd_and_hms(2026, 3, 13, 11, 0, 0).unwrap();
+        store
+            .record_schedule_firing(&ScheduleFiring {
+                scheduled_for: slot,
+                fired_at: slot,
+                outcome: FiringOutcome::Completed {
+                    run_id: RunId::new("run-11"),
+                },
+            })
+            .await
+            .unwrap();
+
+        // Reopen to confirm durability.
+        drop(store);
+        let store = RedbStateStore::open(&db_path).unwrap();
+        let firings = store.load_schedule_firings(2).await.unwrap();
+        assert_eq!(firings.len(), 2);
+        assert_eq!(firings[0].scheduled_for, slot);
+        assert!(matches!(
+            firings[0].outcome,
+            FiringOutcome::Completed { .. }
+        ));
+        assert_eq!(
+            firings[1].scheduled_for,
+            Utc.with_ymd_and_hms(2026, 3, 13, 10, 0, 0).unwrap()
+        );
+    }
+
+    /// A checkpoint for `run_id`, started `days_ago` days before
+    /// `base`, with the given status.
+    fn history_checkpoint(
+        run_id: &str,
+        base: chrono::DateTime<Utc>,
+        days_ago: i64,
+        status: PipelineStatus,
+    ) -> Checkpoint {
+        let mut checkpoint = make_test_checkpoint(run_id, 1);
+        checkpoint.state.started_at = base - chrono::Duration::days(days_ago);
+        checkpoint.state.status = status;
+        checkpoint
+    }
+
+    #[tokio::test]
+    async fn test_redb_store_run_history() {
+        let dir = TempDir::new().unwrap();
+        let store = RedbStateStore::open(dir.path().join("test.redb")).unwrap();
+        assert!(store.list_runs().await.unwrap().is_empty());
+
+        let now = Utc::now();
+        for (run, days_ago) in [("run-a", 2), ("run-c", 0), ("run-b", 1)] {
+            let checkpoint = history_checkpoint(run, now, days_ago, PipelineStatus::Pending);
+            store.save_checkpoint(&checkpoint).await.unwrap();
+        }
+
+        let runs = store.list_runs().await.unwrap();
+        let ids: Vec<&str> = runs.iter().map(|r| r.run_id.as_str()).collect();
+        assert_eq!(ids, vec!["run-c", "run-b", "run-a"]);
+
+        // The latest checkpoint is the last one saved; older runs are
+        // still loadable by id.
+        let latest = store.load_checkpoint().await.unwrap().unwrap();
+        assert_eq!(latest.state.run_id.as_str(), "run-b");
+        let old = store.load_run(&RunId::new("run-a")).await.unwrap().unwrap();
+        assert_eq!(old.state.run_id.as_str(), "run-a");
+        assert!(store.load_run(&RunId::new("nope")).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_redb_store_run_hash_snapshots() {
+        let dir = TempDir::new().unwrap();
+        let store = RedbStateStore::open(dir.path().join("test.redb")).unwrap();
+
+        let first = make_test_hashes(&[("a.txt", "aa"), ("b.txt", "bb")]);
+        let second = make_test_hashes(&[("a.txt", "a2")]);
+        store
+            .save_completed_hashes(&RunId::new("run-1"), &first)
+            .await
+            .unwrap();
+        store
+            .save_completed_hashes(&RunId::new("run-2"), &second)
+            .await
+            .unwrap();
+
+        // The incrementality table holds only the latest run...
+        assert_eq!(store.load_previous_hashes().await.unwrap(), second);
+        // ...while each run keeps its own snapshot.
+        let loaded = store.load_run_hashes(&RunId::new("run-1")).await.unwrap();
+        assert_eq!(loaded, first);
+        let loaded = store.load_run_hashes(&RunId::new("run-2")).await.unwrap();
+        assert_eq!(loaded, second);
+        assert!(
+            store
+                .load_run_hashes(&RunId::new("run-3"))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_redb_store_prune_runs() {
+        let dir = TempDir::new().unwrap();
+        let store = RedbStateStore::open(dir.path().join("test.redb")).unwrap();
+        let now = Utc::now();
+        let completed = PipelineStatus::Completed { finished_at: now };
+
+        for (run, days_ago) in [("run-1", 40), ("run-2", 20), ("run-3", 10), ("run-4", 0)] {
+            let checkpoint = history_checkpoint(run, now, days_ago, completed.clone());
+            store.save_checkpoint(&checkpoint).await.unwrap();
+            store
+                .save_completed_hashes(&RunId::new(run), &make_test_hashes(&[("a.txt", run)]))
+                .await
+                .unwrap();
+        }
+
+        // Unbounded retention prunes nothing.
+        let pruned = store
+            .prune_runs(&RetentionSpec::default(), now)
+            .await
+            .unwrap();
+        assert!(pruned.is_empty());
+
+        let policy = RetentionSpec {
+            keep_runs: Some(3),
+            keep_days: Some(15),
+        };
+        let mut pruned = store.prune_runs(&policy, now).await.unwrap();
+        pruned.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        assert_eq!(pruned, vec![RunId::new("run-1"), RunId::new("run-2")]);
+
+        let ids: Vec<String> = store
+            .list_runs()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|r| r.run_id.as_str().to_string())
+            .collect();
+        assert_eq!(ids, vec!["run-4", "run-3"]);
+        assert!(
+            store
+                .load_run(&RunId::new("run-1"))
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            store
+                .load_run_hashes(&RunId::new("run-2"))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            store
+                .load_run_hashes(&RunId::new("run-3"))
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+        // Incrementality is untouched.
+        assert_eq!(store.load_previous_hashes().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_redb_store_list_runs_summarizes_legacy_checkpoints() {
+        let dir = TempDir::new().unwrap();
+        let db_path = dir.path().join("test.redb");
+        let store = RedbStateStore::open(&db_path).unwrap();
+        store
+            .save_checkpoint(&make_test_checkpoint("run-legacy", 1))
+            .await
+            .unwrap();
+
+        // Simulate a database written before run summaries existed.
+        {
+            let write_txn = store.db.begin_write().unwrap();
+            write_txn.delete_table(RUNS).unwrap();
+            write_txn.commit().unwrap();
+        }
+
+        let runs = store.list_runs().await.unwrap();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].run_id.as_str(), "run-legacy");
+        assert_eq!(runs[0].pipeline_name, "test-pipeline");
+    }
+
     #[tokio::test]
     async fn test_redb_store_clone() {
         let dir = TempDir::new().unwrap();
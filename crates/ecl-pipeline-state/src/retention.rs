@@ -0,0 +1,142 @@
+//! Checkpoint history retention.
+//!
+//! Decides which runs a `StateStore` may forget under a `RetentionSpec`.
+//! The decision is pure so every store prunes the same runs.
+
+use chrono::{DateTime, Utc};
+use ecl_pipeline_spec::RetentionSpec;
+
+use crate::ids::RunId;
+use crate::types::{PipelineStatus, RunSummary};
+
+/// The runs in `runs` that `policy` no longer keeps as of `now`.
+///
+/// A run expires when it is not among the `keep_runs` most recently
+/// started runs, or when it started more than `keep_days` days before
+/// `now`. The most recently started run and the most recently started
+/// completed run never expire.
+pub fn expired_runs(policy: &RetentionSpec, runs: &[RunSummary], now: DateTime<Utc>) -> Vec<RunId> {
+    if policy.is_unbounded() {
+        return Vec::new();
+    }
+
+    let mut newest_first: Vec<&RunSummary> = runs.iter().collect();
+    newest_first.sort_by_key(|run| std::cmp::Reverse(run.started_at));
+
+    let latest = newest_first.first().map(|run| &run.run_id);
+    let latest_completed = newest_first
+        .iter()
+        .find(|run| matches!(run.status, PipelineStatus::Completed { .. }))
+        .map(|run| &run.run_id);
+    let cutoff = policy
+        .keep_days
+        .map(|days| now - chrono::Duration::days(i64::from(days)));
+
+    newest_first
+        .iter()
+        .enumerate()
+        .filter(|(position, run)| {
+            let over_count = policy.keep_runs.is_some_and(|keep| *position >= keep);
+            let too_old = cutoff.is_some_and(|cutoff| run.started_at < cutoff);
+            over_count || too_old
+        })
+        .map(|(_, run)| &run.run_id)
+        .filter(|run_id| Some(*run_id) != latest && Some(*run_id) != latest_completed)
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::types::PipelineStats;
+    use chrono::TimeZone;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 13, 10, 0, 0).unwrap()
+    }
+
+    /// A run `days_ago` days old; `completed` selects the final status.
+    fn run(id: &str, days_ago: i64, completed: bool) -> RunSummary {
+        let started_at = now() - chrono::Duration::days(days_ago);
+        RunSummary {
+            run_id: RunId::new(id),
+            pipeline_name: "test".to_string(),
+            started_at,
+            last_checkpoint: started_at,
+            status: if completed {
+                PipelineStatus::Completed {
+                    finished_at: started_at,
+                }
+            } else {
+                PipelineStatus::Interrupted {
+                    interrupted_at: started_at,
+                }
+            },
+            stats: PipelineStats::default(),
+            triggered_by: None,
+        }
+    }
+
+    fn ids(runs: Vec<RunId>) -> Vec<String> {
+        runs.into_iter().map(|r| r.as_str().to_string()).collect()
+    }
+
+    #[test]
+    fn test_unbounded_policy_keeps_everything() {
+        let runs = vec![run("a", 100, true), run("b", 50, true)];
+        assert!(expired_runs(&RetentionSpec::default(), &runs, now()).is_empty());
+    }
+
+    #[test]
+    fn test_keep_runs_expires_oldest() {
+        let runs = vec![
+            run("a", 4, true),
+            run("b", 3, true),
+            run("c", 2, true),
+            run("d", 1, true),
+        ];
+        let policy = RetentionSpec {
+            keep_runs: Some(2),
+            keep_days: None,
+        };
+        assert_eq!(ids(expired_runs(&policy, &runs, now())), vec!["b", "a"]);
+    }
+
+    #[test]
+    fn test_keep_days_expires_old_runs() {
+        let runs = vec![
+            run("old", 40, true),
+            run("recent", 10, true),
+            run("new", 0, true),
+        ];
+        let policy = RetentionSpec {
+            keep_runs: None,
+            keep_days: Some(30),
+        };
+        assert_eq!(ids(expired_runs(&policy, &runs, now())), vec!["old"]);
+    }
+
+    #[test]
+    fn test_latest_completed_run_is_protected() {
+        // The newest two runs were interrupted; the last completed run
+        // still backs incrementality.
+        let runs = vec![run("done", 3, true), run("x", 2, false), run("y", 1, false)];
+        let policy = RetentionSpec {
+            keep_runs: Some(1),
+            keep_days: None,
+        };
+        assert_eq!(ids(expired_runs(&policy, &runs, now())), vec!["x"]);
+    }
+
+    #[test]
+    fn test_latest_run_is_protected_even_when_old() {
+        let runs = vec![run("only", 365, true)];
+        let policy = RetentionSpec {
+            keep_runs: Some(0),
+            keep_days: Some(1),
+        };
+        assert!(expired_runs(&policy, &runs, now()).is_empty());
+    }
+}
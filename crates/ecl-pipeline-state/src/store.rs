@@ -5,13 +5,17 @@
 //! InMemoryStateStore (this crate).
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ecl_pipeline_spec::RetentionSpec;
 use std::collections::BTreeMap;
 
 use crate::checkpoint::Checkpoint;
 use crate::error::StateError;
 use crate::ids::{Blake3Hash, RunId};
+use crate::types::{RunSummary, ScheduleFiring};
 
-/// Persistent state storage for pipeline checkpoints and content hashes.
+/// Persistent state storage for pipeline checkpoints, content hashes,
+/// run history, and schedule firing history.
 ///
 /// Implementations must be crash-safe: either the full checkpoint
 /// is persisted or none of it is. redb provides this via ACID
@@ -39,4 +43,40 @@ pub trait StateStore: Send + Sync {
         run_id: &RunId,
         hashes: &BTreeMap<String, Blake3Hash>,
     ) -> std::result::Result<(), StateError>;
+
+    /// Record a schedule firing. A firing with the same `scheduled_for`
+    /// replaces the earlier record (e.g. `Running` -> `Completed`).
+    async fn record_schedule_firing(
+        &self,
+        firing: &ScheduleFiring,
+    ) -> std::result::Result<(), StateError>;
+
+    /// Load up to `limit` of the most recent schedule firings, newest first.
+    async fn load_schedule_firings(
+        &self,
+        limit: usize,
+    ) -> std::result::Result<Vec<ScheduleFiring>, StateError>;
+
+    /// List every retained run, most recently started first.
+    async fn list_runs(&self) -> std::result::Result<Vec<RunSummary>, StateError>;
+
+    /// Load the last checkpoint written by `run_id`, if that run is
+    /// still retained.
+    async fn load_run(&self, run_id: &RunId)
+    -> std::result::Result<Option<Checkpoint>, StateError>;
+
+    /// Load the content hashes saved when `run_id` completed. Empty if
+    /// the run never completed or has been pruned.
+    async fn load_run_hashes(
+        &self,
+        run_id: &RunId,
+    ) -> std::result::Result<BTreeMap<String, Blake3Hash>, StateError>;
+
+    /// Forget the runs that `policy` no longer keeps as of `now` (see
+    /// [`crate::retention::expired_runs`]), returning their ids.
+    async fn prune_runs(
+        &self,
+        policy: &RetentionSpec,
+        now: DateTime<Utc>,
+    ) -> std::result::Result<Vec<RunId>, StateError>;
 }
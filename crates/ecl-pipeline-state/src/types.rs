@@ -7,7 +7,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
-use crate::ids::{Blake3Hash, StageId};
+use crate::ids::{Blake3Hash, RunId, StageId};
 
 /// Overall pipeline execution status.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -202,6 +202,11 @@ pub enum StageStatus {
         /// Reason the stage was skipped.
         reason: String,
     },
+    /// Stage was skipped because its `condition` evaluated to false.
+    SkippedByCondition {
+        /// The condition expression that was not met.
+        condition: String,
+    },
     /// Stage failed.
     Failed {
         /// Error description.
@@ -216,11 +221,30 @@ impl std::fmt::Display for StageStatus {
             Self::Running => write!(f, "Running"),
             Self::Completed => write!(f, "Completed"),
             Self::Skipped { reason } => write!(f, "Skipped: {reason}"),
+            Self::SkippedByCondition { condition } => {
+                write!(f, "Skipped (condition not met): {condition}")
+            }
             Self::Failed { error } => write!(f, "Failed: {error}"),
         }
     }
 }
 
+impl StageStatus {
+    /// The lower-case status name, as compared against in stage
+    /// condition expressions (`stages.<name>.status == "completed"`).
+    ///
+    /// Both `Skipped` and `SkippedByCondition` report `"skipped"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Running => "running",
+            Self::Completed => "completed",
+            Self::Skipped { .. } | Self::SkippedByCondition { .. } => "skipped",
+            Self::Failed { .. } => "failed",
+        }
+    }
+}
+
 /// Summary statistics for the pipeline run.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct PipelineStats {
@@ -234,6 +258,128 @@ pub struct PipelineStats {
     pub total_items_failed: usize,
 }
 
+/// A compact description of one run, as listed by
+/// `StateStore::list_runs`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunSummary {
+    /// The run.
+    pub run_id: RunId,
+    /// Pipeline name.
+    pub pipeline_name: String,
+    /// When the run started.
+    pub started_at: DateTime<Utc>,
+    /// When the run's last checkpoint was written.
+    pub last_checkpoint: DateTime<Utc>,
+    /// Status as of the last checkpoint.
+    pub status: PipelineStatus,
+    /// Statistics as of the last checkpoint.
+    pub stats: PipelineStats,
+    /// The upstream run that triggered this one, if any.
+    #[serde(default)]
+    pub triggered_by: Option<TriggeredBy>,
+}
+
+impl RunSummary {
+    /// Summarize a run's state.
+    pub fn from_state(state: &crate::PipelineState) -> Self {
+        Self {
+            run_id: state.run_id.clone(),
+            pipeline_name: state.pipeline_name.clone(),
+            started_at: state.started_at,
+            last_checkpoint: state.last_checkpoint,
+            status: state.status.clone(),
+            stats: state.stats.clone(),
+            triggered_by: state.triggered_by.clone(),
+        }
+    }
+}
+
+/// Which outcome of an upstream run fired a trigger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TriggerEvent {
+    /// The upstream run completed successfully.
+    OnSuccess,
+    /// The upstream run failed.
+    OnFailure,
+}
+
+impl TriggerEvent {
+    /// The name used in TOML (`[triggers]`) and in conditions (`trigger.event`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::OnSuccess => "on_success",
+            Self::OnFailure => "on_failure",
+        }
+    }
+}
+
+impl std::fmt::Display for TriggerEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The upstream run that started a chained pipeline run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TriggeredBy {
+    /// Name of the upstream pipeline.
+    pub pipeline: String,
+    /// The upstream run.
+    pub run_id: RunId,
+    /// Which upstream outcome fired the trigger.
+    pub event: TriggerEvent,
+    /// The upstream run's final statistics.
+    pub stats: PipelineStats,
+    /// Position in the chain: 1 for a pipeline triggered by the root run.
+    pub depth: usize,
+}
+
+/// One firing of a pipeline's cron schedule, as recorded by the scheduler.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleFiring {
+    /// The cron slot this firing belongs to.
+    pub scheduled_for: DateTime<Utc>,
+    /// When the scheduler acted on the slot.
+    pub fired_at: DateTime<Utc>,
+    /// What happened.
+    pub outcome: FiringOutcome,
+}
+
+/// The result of a schedule firing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FiringOutcome {
+    /// The run was started and has not finished yet.
+    Running,
+    /// The run completed successfully.
+    Completed {
+        /// The run that executed.
+        run_id: RunId,
+    },
+    /// The run failed.
+    Failed {
+        /// Error description.
+        error: String,
+    },
+    /// The slot came due while a previous run was still active.
+    SkippedOverlap,
+    /// The slot was missed while the scheduler was down and the
+    /// catch-up policy did not replay it.
+    Missed,
+}
+
+impl std::fmt::Display for FiringOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Running => write!(f, "Running"),
+            Self::Completed { run_id } => write!(f, "Completed ({run_id})"),
+            Self::Failed { error } => write!(f, "Failed: {error}"),
+            Self::SkippedOverlap => write!(f, "Skipped (previous run still active)"),
+            Self::Missed => write!(f, "Missed"),
+        }
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -387,6 +533,9 @@ mod tests {
             StageStatus::Skipped {
                 reason: "condition".to_string(),
             },
+            StageStatus::SkippedByCondition {
+                condition: "items.count > 0".to_string(),
+            },
             StageStatus::Failed {
                 error: "timeout".to_string(),
             },
@@ -501,6 +650,13 @@ mod tests {
             .to_string(),
             "Skipped: cond"
         );
+        assert_eq!(
+            StageStatus::SkippedByCondition {
+                condition: "items.count > 0".to_string()
+            }
+            .to_string(),
+            "Skipped (condition not met): items.count > 0"
+        );
         assert_eq!(
             StageStatus::Failed {
                 error: "timeout".to_string()
@@ -510,6 +666,84 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_stage_status_name() {
+        assert_eq!(StageStatus::Pending.name(), "pending");
+        assert_eq!(StageStatus::Completed.name(), "completed");
+        assert_eq!(
+            StageStatus::SkippedByCondition {
+                condition: "x".to_string()
+            }
+            .name(),
+            "skipped"
+        );
+        assert_eq!(
+            StageStatus::Failed {
+                error: "e".to_string()
+            }
+            .name(),
+            "failed"
+        );
+    }
+
+    #[test]
+    fn test_schedule_firing_serde_roundtrip() {
+        for outcome in [
+            FiringOutcome::Running,
+            FiringOutcome::Completed {
+                run_id: RunId::new("run-1"),
+            },
+            FiringOutcome::Failed {
+                error: "boom".to_string(),
+            },
+            FiringOutcome::SkippedOverlap,
+            FiringOutcome::Missed,
+        ] {
+            let firing = ScheduleFiring {
+                scheduled_for: test_time(),
+                fired_at: test_time(),
+                outcome,
+            };
+            let json = serde_json::to_string(&firing).unwrap();
+            let deserialized: ScheduleFiring = serde_json::from_str(&json).unwrap();
+            assert_eq!(firing, deserialized);
+        }
+    }
+
+    #[test]
+    fn test_firing_outcome_display() {
+        assert_eq!(
+            FiringOutcome::Completed {
+                run_id: RunId::new("run-1")
+            }
+            .to_string(),
+            "Completed (run-1)"
+        );
+        assert_eq!(
+            FiringOutcome::SkippedOverlap.to_string(),
+            "Skipped (previous run still active)"
+        );
+    }
+
+    #[test]
+    fn test_triggered_by_serde_roundtrip() {
+        let triggered_by = TriggeredBy {
+            pipeline: "ingest".to_string(),
+            run_id: RunId::new("run-1"),
+            event: TriggerEvent::OnFailure,
+            stats: PipelineStats {
+                total_items_failed: 2,
+                ..PipelineStats::default()
+            },
+            depth: 1,
+        };
+        let json = serde_json::to_string(&triggered_by).unwrap();
+        assert!(json.contains("\"on_failure\""));
+        let deserialized: TriggeredBy = serde_json::from_str(&json).unwrap();
+        assert_eq!(triggered_by, deserialized);
+        assert_eq!(TriggerEvent::OnSuccess.to_string(), "on_success");
+    }
+
     #[test]
     fn test_pipeline_stats_default() {
         let stats = PipelineStats::default();
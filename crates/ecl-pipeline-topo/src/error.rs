@@ -53,6 +53,15 @@ pub enum ResolveError {
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
 
+    /// A stage's condition expression could not be parsed.
+    #[error("invalid condition in stage '{stage}': {source}")]
+    InvalidCondition {
+        /// The stage that declares the condition.
+        stage: String,
+        /// The underlying condition error.
+        source: ecl_pipeline_spec::ConditionError,
+    },
+
     /// An unknown stage adapter was specified.
     #[error("unknown stage adapter '{adapter}' in stage '{stage}'")]
     UnknownAdapter {
@@ -35,6 +35,7 @@ use std::time::Duration;
 use serde::{Deserialize, Serialize};
 
 use ecl_pipeline_spec::PipelineSpec;
+use ecl_pipeline_spec::condition::{self, ConditionContext, ConditionError, Expr};
 use ecl_pipeline_state::{Blake3Hash, StageId};
 
 /// The resolved pipeline, ready to execute.
@@ -133,26 +134,68 @@ impl Default for RetryPolicy {
 }
 
 /// A condition expression that determines whether a stage should run.
-/// Currently a simple string wrapper; the evaluator is deferred to a
-/// future milestone.
+///
+/// Holds both the source text (for display and serialization) and the
+/// parsed expression. Construct with [`ConditionExpr::parse`]; the spec
+/// layer has already type-checked the expression against the pipeline's
+/// sources and stages by the time the topology is resolved.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct ConditionExpr(String);
+#[serde(try_from = "String", into = "String")]
+pub struct ConditionExpr {
+    source: String,
+    expr: Expr,
+}
 
 impl ConditionExpr {
-    /// Create a new condition expression from a string.
-    pub fn new(expr: impl Into<String>) -> Self {
-        Self(expr.into())
+    /// Parse a condition expression from a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ConditionError` if the expression is not syntactically
+    /// valid or references an unknown field.
+    pub fn parse(expr: impl Into<String>) -> Result<Self, ConditionError> {
+        let source = expr.into();
+        let expr = condition::parse(&source)?;
+        Ok(Self { source, expr })
     }
 
     /// Get the expression string.
     pub fn as_str(&self) -> &str {
-        &self.0
+        &self.source
+    }
+
+    /// Get the parsed expression.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+
+    /// Evaluate the condition against a runtime context.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ConditionError` if a reference cannot be resolved.
+    pub fn evaluate(&self, ctx: &dyn ConditionContext) -> Result<bool, ConditionError> {
+        condition::evaluate(&self.expr, ctx)
+    }
+}
+
+impl TryFrom<String> for ConditionExpr {
+    type Error = ConditionError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(value)
+    }
+}
+
+impl From<ConditionExpr> for String {
+    fn from(value: ConditionExpr) -> Self {
+        value.source
     }
 }
 
 impl std::fmt::Display for ConditionExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(&self.0)
+        f.write_str(&self.source)
     }
 }
 
@@ -164,25 +207,54 @@ mod tests {
     use ecl_pipeline_spec::RetrySpec;
 
     #[test]
-    fn test_condition_expr_new_and_as_str() {
-        let expr = ConditionExpr::new("x > 1");
-        assert_eq!(expr.as_str(), "x > 1");
+    fn test_condition_expr_parse_and_as_str() {
+        let expr = ConditionExpr::parse("items.count > 1").unwrap();
+        assert_eq!(expr.as_str(), "items.count > 1");
+    }
+
+    #[test]
+    fn test_condition_expr_parse_invalid_fails() {
+        let err = ConditionExpr::parse("x > 1").unwrap_err();
+        assert!(matches!(err, ConditionError::UnknownReference { .. }));
     }
 
     #[test]
     fn test_condition_expr_display() {
-        let expr = ConditionExpr::new("x > 1");
-        assert_eq!(format!("{expr}"), "x > 1");
+        let expr = ConditionExpr::parse("items.count > 1").unwrap();
+        assert_eq!(format!("{expr}"), "items.count > 1");
     }
 
     #[test]
     fn test_condition_expr_serde_roundtrip() {
-        let expr = ConditionExpr::new("items.count > 0");
+        let expr = ConditionExpr::parse("items.count > 0").unwrap();
         let json = serde_json::to_string(&expr).unwrap();
+        assert_eq!(json, "\"items.count > 0\"");
         let deserialized: ConditionExpr = serde_json::from_str(&json).unwrap();
         assert_eq!(expr, deserialized);
     }
 
+    #[test]
+    fn test_condition_expr_deserialize_invalid_fails() {
+        let result: Result<ConditionExpr, _> = serde_json::from_str("\"items.count >\"");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_condition_expr_evaluate() {
+        use ecl_pipeline_spec::condition::{Reference, Value};
+
+        struct Items(i64);
+        impl ConditionContext for Items {
+            fn resolve(&self, _reference: &Reference) -> Option<Value> {
+                Some(Value::Int(self.0))
+            }
+        }
+
+        let expr = ConditionExpr::parse("items.count > 0").unwrap();
+        assert!(expr.evaluate(&Items(2)).unwrap());
+        assert!(!expr.evaluate(&Items(0)).unwrap());
+    }
+
     #[test]
     fn test_retry_policy_default_values() {
         let policy = RetryPolicy::default();
@@ -322,14 +394,17 @@ resources = { creates = ["docs"] }
             skip_on_error: true,
             timeout: Some(Duration::from_secs(60)),
             source: Some("gdrive".to_string()),
-            condition: Some(ConditionExpr::new("items.count > 0")),
+            condition: Some(ConditionExpr::parse("items.count > 0").unwrap()),
         };
 
         assert_eq!(stage.id.as_str(), "normalize");
         assert!(stage.skip_on_error);
         assert_eq!(stage.timeout, Some(Duration::from_secs(60)));
         assert_eq!(stage.source, Some("gdrive".to_string()));
-        assert_eq!(stage.condition, Some(ConditionExpr::new("items.count > 0")));
+        assert_eq!(
+            stage.condition.as_ref().map(ConditionExpr::as_str),
+            Some("items.count > 0")
+        );
     }
 
     #[tokio::test]
@@ -425,8 +500,8 @@ mod proptests {
         }
 
         #[test]
-        fn test_condition_expr_proptest_roundtrip(s in "\\PC{1,100}") {
-            let expr = ConditionExpr::new(s);
+        fn test_condition_expr_proptest_roundtrip(n in 0..1_000_000i64, op in "(==|!=|<|<=|>|>=)") {
+            let expr = ConditionExpr::parse(format!("items.count {op} {n}")).unwrap();
             let json = serde_json::to_string(&expr).unwrap();
             let deserialized: ConditionExpr = serde_json::from_str(&json).unwrap();
             prop_assert_eq!(expr, deserialized);
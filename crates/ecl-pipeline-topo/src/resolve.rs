@@ -86,7 +86,7 @@ where
     let mut stages: BTreeMap<String, ResolvedStage> = BTreeMap::new();
     for (name, stage_spec) in &spec.stages {
         let handler = stage_lookup(name, stage_spec)?;
-        let resolved = resolve_stage(name, stage_spec, handler, &spec.defaults);
+        let resolved = resolve_stage(name, stage_spec, handler, &spec.defaults)?;
         stages.insert(name.clone(), resolved);
     }
 
@@ -139,7 +139,7 @@ fn resolve_stage(
     stage_spec: &StageSpec,
     handler: Arc<dyn Stage>,
     defaults: &DefaultsSpec,
-) -> ResolvedStage {
+) -> Result<ResolvedStage, ResolveError> {
     // Merge retry: stage override > global default.
     let retry = resolve_retry_policy(stage_spec.retry.as_ref(), &defaults.retry);
 
@@ -147,9 +147,17 @@ fn resolve_stage(
     let timeout = stage_spec.timeout_secs.map(Duration::from_secs);
 
     // Resolve condition expression.
-    let condition = stage_spec.condition.as_ref().map(ConditionExpr::new);
+    let condition = stage_spec
+        .condition
+        .as_ref()
+        .map(|expr| ConditionExpr::parse(expr.as_str()))
+        .transpose()
+        .map_err(|source| ResolveError::InvalidCondition {
+            stage: name.to_string(),
+            source,
+        })?;
 
-    ResolvedStage {
+    Ok(ResolvedStage {
         id: StageId::new(name),
         handler,
         retry,
@@ -157,7 +165,7 @@ fn resolve_stage(
         timeout,
         source: stage_spec.source.clone(),
         condition,
-    }
+    })
 }
 
 /// Merge a stage-level `RetrySpec` override with the global default
@@ -809,7 +817,7 @@ root = "/tmp"
 adapter = "extract"
 source = "local"
 resources = {{ creates = ["docs"] }}
-condition = "x > 1"
+condition = "source.items_discovered > 1"
 
 [stages.unconditional]
 adapter = "emit"
@@ -825,7 +833,7 @@ resources = {{ reads = ["docs"] }}
 
         assert_eq!(
             topo.stages["conditional"].condition,
-            Some(ConditionExpr::new("x > 1"))
+            Some(ConditionExpr::parse("source.items_discovered > 1").unwrap())
         );
         assert_eq!(topo.stages["unconditional"].condition, None);
     }
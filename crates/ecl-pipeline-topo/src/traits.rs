@@ -70,10 +70,47 @@ pub trait SourceAdapter: Send + Sync + std::fmt::Debug {
     /// modified_after) during enumeration.
     async fn enumerate(&self) -> Result<Vec<SourceItem>, SourceError>;
 
+    /// Enumerate items, given when the last completed run of this pipeline
+    /// started (`None` on the first run).
+    ///
+    /// The runner calls this instead of `enumerate()`. Adapters that
+    /// support `modified_after = "last_run"` override it; the default
+    /// ignores `last_run`.
+    async fn enumerate_since(
+        &self,
+        _last_run: Option<DateTime<Utc>>,
+    ) -> Result<Vec<SourceItem>, SourceError> {
+        self.enumerate().await
+    }
+
     /// Fetch the full content of a single item.
     /// Separate from `enumerate()` because fetching is expensive and we
     /// want to skip unchanged items before paying this cost.
     async fn fetch(&self, item: &SourceItem) -> Result<ExtractedDocument, SourceError>;
+
+    /// Whether `SourceItem::source_hash` changes whenever an item's content
+    /// does, so unchanged items can be skipped without fetching them.
+    ///
+    /// When true, the runner compares each item's `source_hash` with the
+    /// previous run's and marks matches unchanged. The default is false:
+    /// items are always fetched.
+    fn skips_unchanged_by_source_hash(&self) -> bool {
+        false
+    }
+
+    /// Called once after a run completes, with the IDs of every item
+    /// enumerated from this source and whether the run had no failed items.
+    ///
+    /// Adapters that manage files on the remote side (archiving or
+    /// renaming processed files) override this. The default does nothing.
+    async fn on_run_complete(
+        &self,
+        _run_id: &str,
+        _item_ids: &[String],
+        _succeeded: bool,
+    ) -> Result<(), SourceError> {
+        Ok(())
+    }
 }
 
 /// A push-based source adapter that receives data via external events
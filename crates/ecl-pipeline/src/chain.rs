@@ -0,0 +1,672 @@
+//! Pipeline chaining through `[triggers]`.
+//!
+//! A pipeline's `TriggersSpec` names downstream pipeline configs to run
+//! after it finishes: `on_success` when the run completed with no failed
+//! items, `on_failure` otherwise (the same rule file lifecycle uses).
+//! `PipelineChain::load` follows those references from a root config and
+//! builds the whole DAG up front, so trigger cycles and over-deep chains
+//! are rejected before anything runs.
+//!
+//! `PipelineChain::execute` then runs the root and walks the DAG, launching
+//! each triggered pipeline in-process through a `ChainLauncher`. Children
+//! receive a `TriggeredBy` describing the upstream run (name, run id,
+//! event, final stats), which their stage conditions can read as
+//! `trigger.*`. A failing child is logged and fires its own `on_failure`
+//! triggers, but never fails its parent.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+
+use ecl_pipeline_spec::PipelineSpec;
+use ecl_pipeline_state::{PipelineState, PipelineStats, RunId, TriggerEvent, TriggeredBy};
+
+use crate::error::{PipelineError, Result};
+
+/// Default limit on how many trigger hops a chain may take from its root.
+pub const DEFAULT_MAX_CHAIN_DEPTH: usize = 8;
+
+/// One pipeline in a chain.
+#[derive(Debug, Clone)]
+pub struct ChainNode {
+    /// Pipeline name (from the spec).
+    pub name: String,
+    /// Canonical path of the pipeline's TOML config.
+    pub config: PathBuf,
+    /// The parsed and validated spec.
+    pub spec: PipelineSpec,
+    /// Indices (into `PipelineChain::nodes`) of pipelines run on success.
+    pub on_success: Vec<usize>,
+    /// Indices (into `PipelineChain::nodes`) of pipelines run on failure.
+    pub on_failure: Vec<usize>,
+}
+
+/// A position in a depth-first walk of the chain, as yielded by
+/// [`PipelineChain::walk`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChainEntry {
+    /// Index into `PipelineChain::nodes`.
+    pub node: usize,
+    /// Trigger hops from the root (the root is 0).
+    pub depth: usize,
+    /// The edge that led here; `None` for the root.
+    pub event: Option<TriggerEvent>,
+}
+
+/// The DAG of pipelines reachable from a root config through `[triggers]`.
+///
+/// A config reachable along several paths appears once in `nodes` but runs
+/// once per path that fires.
+#[derive(Debug, Clone)]
+pub struct PipelineChain {
+    nodes: Vec<ChainNode>,
+}
+
+impl PipelineChain {
+    /// Load the chain rooted at `root_config`.
+    ///
+    /// Trigger paths are resolved relative to the directory of the config
+    /// that declares them. Every reachable config is read and validated.
+    ///
+    /// # Errors
+    ///
+    /// - `PipelineError::ChainConfig` if a config cannot be read or parsed.
+    /// - `PipelineError::TriggerCycle` if a pipeline can (transitively)
+    ///   trigger itself, whichever events the edges are on.
+    /// - `PipelineError::ChainTooDeep` if a pipeline sits more than
+    ///   `max_depth` trigger hops from the root.
+    pub fn load(root_config: &Path, max_depth: usize) -> Result<Self> {
+        let mut loader = Loader {
+            max_depth,
+            nodes: Vec::new(),
+            index: HashMap::new(),
+            deepest: Vec::new(),
+            path: Vec::new(),
+        };
+        let root = canonical(root_config)?;
+        loader.visit(root, 0)?;
+        Ok(Self {
+            nodes: loader.nodes,
+        })
+    }
+
+    /// The root pipeline.
+    pub fn root(&self) -> &ChainNode {
+        &self.nodes[0]
+    }
+
+    /// All pipelines in the chain; the root is first.
+    pub fn nodes(&self) -> &[ChainNode] {
+        &self.nodes
+    }
+
+    /// Depth-first walk from the root, `on_success` edges before
+    /// `on_failure` edges. Shared pipelines appear once per path.
+    pub fn walk(&self) -> Vec<ChainEntry> {
+        let mut entries = Vec::new();
+        let mut stack = vec![ChainEntry {
+            node: 0,
+            depth: 0,
+            event: None,
+        }];
+        while let Some(entry) = stack.pop() {
+            let node = &self.nodes[entry.node];
+            let children = node
+                .on_success
+                .iter()
+                .map(|&child| (child, TriggerEvent::OnSuccess))
+                .chain(
+                    node.on_failure
+                        .iter()
+                        .map(|&child| (child, TriggerEvent::OnFailure)),
+                );
+            let mut next: Vec<ChainEntry> = children
+                .map(|(child, event)| ChainEntry {
+                    node: child,
+                    depth: entry.depth + 1,
+                    event: Some(event),
+                })
+                .collect();
+            next.reverse();
+            stack.extend(next);
+            entries.push(entry);
+        }
+        entries
+    }
+
+    /// Run the root pipeline and every pipeline its outcome triggers.
+    ///
+    /// Triggered pipelines run sequentially in declaration order. The
+    /// returned tree mirrors what actually ran.
+    pub async fn execute(&self, launcher: &dyn ChainLauncher) -> ChainRun {
+        self.run_node(0, None, launcher).await
+    }
+
+    async fn run_node(
+        &self,
+        index: usize,
+        trigger: Option<TriggeredBy>,
+        launcher: &dyn ChainLauncher,
+    ) -> ChainRun {
+        let node = &self.nodes[index];
+        let depth = trigger.as_ref().map_or(0, |t| t.depth);
+        if let Some(ref t) = trigger {
+            tracing::info!(
+                pipeline = %node.name,
+                triggered_by = %t.pipeline,
+                run_id = %t.run_id,
+                event = %t.event,
+                depth,
+                "triggering chained pipeline",
+            );
+        }
+
+        let outcome = launcher.launch(node, trigger).await;
+        let event = outcome.event();
+        if let Some(ref error) = outcome.error {
+            tracing::warn!(pipeline = %node.name, error = %error, "chained pipeline failed");
+        }
+
+        let targets = match event {
+            TriggerEvent::OnSuccess => &node.on_success,
+            TriggerEvent::OnFailure => &node.on_failure,
+        };
+        let triggered_by = TriggeredBy {
+            pipeline: node.name.clone(),
+            run_id: outcome
+                .state
+                .as_ref()
+                .map_or_else(|| RunId::new(""), |s| s.run_id.clone()),
+            event,
+            stats: outcome
+                .state
+                .as_ref()
+                .map(|s| s.stats.clone())
+                .unwrap_or_else(PipelineStats::default),
+            depth: depth + 1,
+        };
+
+        let mut children = Vec::with_capacity(targets.len());
+        for &child in targets {
+            let run = Box::pin(self.run_node(child, Some(triggered_by.clone()), launcher)).await;
+            children.push(run);
+        }
+
+        ChainRun {
+            pipeline: node.name.clone(),
+            config: node.config.clone(),
+            event,
+            state: outcome.state,
+            error: outcome.error,
+            children,
+        }
+    }
+}
+
+impl std::fmt::Display for PipelineChain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for entry in self.walk() {
+            let node = &self.nodes[entry.node];
+            let indent = "  ".repeat(entry.depth);
+            match entry.event {
+                Some(event) => writeln!(
+                    f,
+                    "{indent}{event} -> {} ({})",
+                    node.name,
+                    node.config.display()
+                )?,
+                None => writeln!(f, "{} ({})", node.name, node.config.display())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Builds the chain depth-first, tracking the current path for cycle
+/// detection and the deepest depth each node was reached at.
+struct Loader {
+    max_depth: usize,
+    nodes: Vec<ChainNode>,
+    index: HashMap<PathBuf, usize>,
+    deepest: Vec<usize>,
+    path: Vec<usize>,
+}
+
+impl Loader {
+    fn visit(&mut self, config: PathBuf, depth: usize) -> Result<usize> {
+        let index = match self.index.get(&config) {
+            Some(&index) => {
+                if let Some(pos) = self.path.iter().position(|&n| n == index) {
+                    let mut cycle: Vec<String> = self.path[pos..]
+                        .iter()
+                        .map(|&n| self.nodes[n].name.clone())
+                        .collect();
+                    cycle.push(self.nodes[index].name.clone());
+                    return Err(PipelineError::TriggerCycle { cycle });
+                }
+                // Already explored at least this deep: nothing new below.
+                if self.deepest[index] >= depth {
+                    return Ok(index);
+                }
+                index
+            }
+            None => self.add(config)?,
+        };
+
+        if depth > self.max_depth {
+            return Err(PipelineError::ChainTooDeep {
+                pipeline: self.nodes[index].name.clone(),
+                depth,
+                max_depth: self.max_depth,
+            });
+        }
+        self.deepest[index] = depth;
+
+        self.path.push(index);
+        let base = self.nodes[index]
+            .config
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        let triggers = self.nodes[index].spec.triggers.clone().unwrap_or_default();
+        let mut on_success = Vec::with_capacity(triggers.on_success.len());
+        for target in &triggers.on_success {
+            on_success.push(self.visit(canonical(&base.join(target))?, depth + 1)?);
+        }
+        let mut on_failure = Vec::with_capacity(triggers.on_failure.len());
+        for target in &triggers.on_failure {
+            on_failure.push(self.visit(canonical(&base.join(target))?, depth + 1)?);
+        }
+        self.path.pop();
+
+        let node = &mut self.nodes[index];
+        node.on_success = on_success;
+        node.on_failure = on_failure;
+        Ok(index)
+    }
+
+    fn add(&mut self, config: PathBuf) -> Result<usize> {
+        let chain_error = |message: String| PipelineError::ChainConfig {
+            path: config.clone(),
+            message,
+        };
+        let toml = std::fs::read_to_string(&config).map_err(|e| chain_error(e.to_string()))?;
+        let spec = PipelineSpec::from_toml(&toml).map_err(|e| chain_error(e.to_string()))?;
+
+        let index = self.nodes.len();
+        self.nodes.push(ChainNode {
+            name: spec.name.clone(),
+            config: config.clone(),
+            spec,
+            on_success: Vec::new(),
+            on_failure: Vec::new(),
+        });
+        self.deepest.push(0);
+        self.index.insert(config, index);
+        Ok(index)
+    }
+}
+
+fn canonical(path: &Path) -> Result<PathBuf> {
+    path.canonicalize().map_err(|e| PipelineError::ChainConfig {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })
+}
+
+/// Runs a single pipeline of a chain.
+#[async_trait]
+pub trait ChainLauncher: Send + Sync {
+    /// Run `node` to completion. `trigger` is `None` for the chain's root
+    /// and describes the upstream run otherwise; implementations should
+    /// attach it to the run (see `PipelineRunner::with_trigger`).
+    async fn launch(&self, node: &ChainNode, trigger: Option<TriggeredBy>) -> LaunchOutcome;
+}
+
+/// What happened when a `ChainLauncher` ran a pipeline.
+#[derive(Debug, Default)]
+pub struct LaunchOutcome {
+    /// The run's final state, if a run got far enough to have one.
+    pub state: Option<PipelineState>,
+    /// The error that ended the run, if any.
+    pub error: Option<PipelineError>,
+}
+
+impl LaunchOutcome {
+    /// Which triggers this outcome fires: `on_success` only if the run
+    /// finished without error and without failed items.
+    pub fn event(&self) -> TriggerEvent {
+        match (&self.error, &self.state) {
+            (None, Some(state)) if state.stats.total_items_failed == 0 => TriggerEvent::OnSuccess,
+            _ => TriggerEvent::OnFailure,
+        }
+    }
+}
+
+/// The result of executing a chain: one node per pipeline run.
+#[derive(Debug)]
+pub struct ChainRun {
+    /// Pipeline name.
+    pub pipeline: String,
+    /// The pipeline's config path.
+    pub config: PathBuf,
+    /// Which of this run's triggers fired.
+    pub event: TriggerEvent,
+    /// The run's final state, if it got far enough to have one.
+    pub state: Option<PipelineState>,
+    /// The error that ended the run, if any.
+    pub error: Option<PipelineError>,
+    /// Runs triggered by this one, in launch order.
+    pub children: Vec<ChainRun>,
+}
+
+impl ChainRun {
+    /// Total number of runs in this subtree, including this one.
+    pub fn run_count(&self) -> usize {
+        1 + self.children.iter().map(ChainRun::run_count).sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use ecl_pipeline_state::PipelineStatus;
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+
+    /// Write `<name>.toml` into `dir`, with optional trigger lists.
+    fn write_config(dir: &Path, name: &str, on_success: &[&str], on_failure: &[&str]) {
+        let list = |names: &[&str]| {
+            names
+                .iter()
+                .map(|n| format!("\"{n}.toml\""))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let toml = format!(
+            r#"
+name = "{name}"
+version = 1
+output_dir = "./output/{name}"
+
+[sources.local]
+kind = "filesystem"
+root = "/tmp/{name}"
+
+[stages.emit]
+adapter = "emit"
+source = "local"
+resources = {{ creates = ["out"] }}
+
+[triggers]
+on_success = [{}]
+on_failure = [{}]
+"#,
+            list(on_success),
+            list(on_failure),
+        );
+        std::fs::write(dir.join(format!("{name}.toml")), toml).unwrap();
+    }
+
+    fn state(name: &str, run: &str, failed: usize) -> PipelineState {
+        PipelineState {
+            run_id: RunId::new(run),
+            pipeline_name: name.to_string(),
+            started_at: Utc::now(),
+            last_checkpoint: Utc::now(),
+            status: PipelineStatus::Completed {
+                finished_at: Utc::now(),
+            },
+            current_batch: 1,
+            sources: BTreeMap::new(),
+            stages: BTreeMap::new(),
+            stats: PipelineStats {
+                total_items_failed: failed,
+                ..PipelineStats::default()
+            },
+            triggered_by: None,
+        }
+    }
+
+    /// Records every launch; pipelines named in `fail` return an error.
+    #[derive(Default)]
+    struct MockLauncher {
+        fail: Vec<&'static str>,
+        launches: Mutex<Vec<(String, Option<TriggeredBy>)>>,
+    }
+
+    #[async_trait]
+    impl ChainLauncher for MockLauncher {
+        async fn launch(&self, node: &ChainNode, trigger: Option<TriggeredBy>) -> LaunchOutcome {
+            let mut launches = self.launches.lock().unwrap();
+            launches.push((node.name.clone(), trigger));
+            let run = format!("{}-run", node.name);
+            if self.fail.contains(&node.name.as_str()) {
+                return LaunchOutcome {
+                    state: Some(state(&node.name, &run, 0)),
+                    error: Some(PipelineError::Lifecycle {
+                        message: "boom".to_string(),
+                    }),
+                };
+            }
+            LaunchOutcome {
+                state: Some(state(&node.name, &run, 0)),
+                error: None,
+            }
+        }
+    }
+
+    impl MockLauncher {
+        fn launched(&self) -> Vec<String> {
+            self.launches
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(name, _)| name.clone())
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_load_resolves_relative_trigger_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("sub")).unwrap();
+        write_config(dir.path(), "root", &["sub/child"], &[]);
+        write_config(&dir.path().join("sub"), "child", &["leaf"], &[]);
+        write_config(&dir.path().join("sub"), "leaf", &[], &[]);
+
+        let chain = PipelineChain::load(&dir.path().join("root.toml"), 8).unwrap();
+        let names: Vec<&str> = chain.nodes().iter().map(|n| n.name.as_str()).collect();
+        assert_eq!(names, vec!["root", "child", "leaf"]);
+        assert_eq!(chain.root().on_success, vec![1]);
+        assert_eq!(chain.nodes()[1].on_success, vec![2]);
+    }
+
+    #[test]
+    fn test_load_without_triggers_is_single_node() {
+        let dir = tempfile::tempdir().unwrap();
+        write_config(dir.path(), "solo", &[], &[]);
+        let chain = PipelineChain::load(&dir.path().join("solo.toml"), 8).unwrap();
+        assert_eq!(chain.nodes().len(), 1);
+        assert_eq!(chain.walk().len(), 1);
+    }
+
+    #[test]
+    fn test_load_rejects_cycle() {
+        let dir = tempfile::tempdir().unwrap();
+        write_config(dir.path(), "a", &["b"], &[]);
+        write_config(dir.path(), "b", &[], &["a"]);
+        let err = PipelineChain::load(&dir.path().join("a.toml"), 8).unwrap_err();
+        match err {
+            PipelineError::TriggerCycle { cycle } => assert_eq!(cycle, vec!["a", "b", "a"]),
+            other => unreachable!("expected TriggerCycle, got {other}"),
+        }
+    }
+
+    #[test]
+    fn test_load_rejects_self_trigger() {
+        let dir = tempfile::tempdir().unwrap();
+        write_config(dir.path(), "a", &["a"], &[]);
+        let err = PipelineChain::load(&dir.path().join("a.toml"), 8).unwrap_err();
+        assert!(matches!(err, PipelineError::TriggerCycle { .. }));
+    }
+
+    #[test]
+    fn test_load_allows_diamond() {
+        let dir = tempfile::tempdir().unwrap();
+        write_config(dir.path(), "a", &["b", "c"], &[]);
+        write_config(dir.path(), "b", &["d"], &[]);
+        write_config(dir.path(), "c", &["d"], &[]);
+        write_config(dir.path(), "d", &[], &[]);
+        let chain = PipelineChain::load(&dir.path().join("a.toml"), 8).unwrap();
+        assert_eq!(chain.nodes().len(), 4);
+        // `d` is reached through both `b` and `c`.
+        assert_eq!(chain.walk().len(), 5);
+    }
+
+    #[test]
+    fn test_load_enforces_max_depth() {
+        let dir = tempfile::tempdir().unwrap();
+        write_config(dir.path(), "a", &["b"], &[]);
+        write_config(dir.path(), "b", &["c"], &[]);
+        write_config(dir.path(), "c", &[], &[]);
+
+        assert!(PipelineChain::load(&dir.path().join("a.toml"), 2).is_ok());
+        let err = PipelineChain::load(&dir.path().join("a.toml"), 1).unwrap_err();
+        match err {
+            PipelineError::ChainTooDeep {
+                pipeline,
+                depth,
+                max_depth,
+            } => {
+                assert_eq!(pipeline, "c");
+                assert_eq!(depth, 2);
+                assert_eq!(max_depth, 1);
+            }
+            other => unreachable!("expected ChainTooDeep, got {other}"),
+        }
+    }
+
+    #[test]
+    fn test_load_reports_missing_trigger_config() {
+        let dir = tempfile::tempdir().unwrap();
+        write_config(dir.path(), "a", &["missing"], &[]);
+        let err = PipelineChain::load(&dir.path().join("a.toml"), 8).unwrap_err();
+        match err {
+            PipelineError::ChainConfig { path, .. } => assert!(path.ends_with("missing.toml")),
+            other => unreachable!("expected ChainConfig, got {other}"),
+        }
+    }
+
+    #[test]
+    fn test_display_renders_tree() {
+        let dir = tempfile::tempdir().unwrap();
+        write_config(dir.path(), "a", &["b"], &["alert"]);
+        write_config(dir.path(), "b", &[], &[]);
+        write_config(dir.path(), "alert", &[], &[]);
+        let chain = PipelineChain::load(&dir.path().join("a.toml"), 8).unwrap();
+        let rendered = chain.to_string();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("a ("));
+        assert!(lines[1].starts_with("  on_success -> b ("));
+        assert!(lines[2].starts_with("  on_failure -> alert ("));
+    }
+
+    #[tokio::test]
+    async fn test_triggers_on_success_called() {
+        let dir = tempfile::tempdir().unwrap();
+        write_config(dir.path(), "a", &["b"], &["alert"]);
+        write_config(dir.path(), "b", &[], &[]);
+        write_config(dir.path(), "alert", &[], &[]);
+        let chain = PipelineChain::load(&dir.path().join("a.toml"), 8).unwrap();
+
+        let launcher = MockLauncher::default();
+        let run = chain.execute(&launcher).await;
+        assert_eq!(launcher.launched(), vec!["a", "b"]);
+        assert_eq!(run.event, TriggerEvent::OnSuccess);
+        assert_eq!(run.run_count(), 2);
+
+        let launches = launcher.launches.lock().unwrap();
+        assert!(launches[0].1.is_none(), "root is not triggered");
+        let trigger = launches[1].1.as_ref().unwrap();
+        assert_eq!(trigger.pipeline, "a");
+        assert_eq!(trigger.run_id, RunId::new("a-run"));
+        assert_eq!(trigger.event, TriggerEvent::OnSuccess);
+        assert_eq!(trigger.depth, 1);
+    }
+
+    #[tokio::test]
+    async fn test_triggers_on_failure_called() {
+        let dir = tempfile::tempdir().unwrap();
+        write_config(dir.path(), "a", &["b"], &["alert"]);
+        write_config(dir.path(), "b", &[], &[]);
+        write_config(dir.path(), "alert", &[], &[]);
+        let chain = PipelineChain::load(&dir.path().join("a.toml"), 8).unwrap();
+
+        let launcher = MockLauncher {
+            fail: vec!["a"],
+            ..MockLauncher::default()
+        };
+        let run = chain.execute(&launcher).await;
+        assert_eq!(launcher.launched(), vec!["a", "alert"]);
+        assert!(run.error.is_some());
+        let launches = launcher.launches.lock().unwrap();
+        assert_eq!(
+            launches[1].1.as_ref().unwrap().event,
+            TriggerEvent::OnFailure
+        );
+    }
+
+    #[test]
+    fn test_triggers_on_failure_for_failed_items() {
+        let outcome = LaunchOutcome {
+            state: Some(state("a", "run-1", 2)),
+            error: None,
+        };
+        assert_eq!(outcome.event(), TriggerEvent::OnFailure);
+        assert_eq!(LaunchOutcome::default().event(), TriggerEvent::OnFailure);
+    }
+
+    #[tokio::test]
+    async fn test_triggers_none_no_error() {
+        let dir = tempfile::tempdir().unwrap();
+        write_config(dir.path(), "solo", &[], &[]);
+        let chain = PipelineChain::load(&dir.path().join("solo.toml"), 8).unwrap();
+
+        let launcher = MockLauncher::default();
+        let run = chain.execute(&launcher).await;
+        assert_eq!(launcher.launched(), vec!["solo"]);
+        assert!(run.error.is_none());
+        assert!(run.children.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_triggers_child_failure_logged_not_fatal() {
+        let dir = tempfile::tempdir().unwrap();
+        write_config(dir.path(), "a", &["b", "c"], &[]);
+        write_config(dir.path(), "b", &[], &["alert"]);
+        write_config(dir.path(), "c", &[], &[]);
+        write_config(dir.path(), "alert", &[], &[]);
+        let chain = PipelineChain::load(&dir.path().join("a.toml"), 8).unwrap();
+
+        let launcher = MockLauncher {
+            fail: vec!["b"],
+            ..MockLauncher::default()
+        };
+        let run = chain.execute(&launcher).await;
+        // `b` fails, fires its own on_failure, and `c` still runs.
+        assert_eq!(launcher.launched(), vec!["a", "b", "alert", "c"]);
+        assert!(run.error.is_none());
+        assert_eq!(run.event, TriggerEvent::OnSuccess);
+        assert!(run.children[0].error.is_some());
+
+        let launches = launcher.launches.lock().unwrap();
+        let alert_trigger = launches[2].1.as_ref().unwrap();
+        assert_eq!(alert_trigger.pipeline, "b");
+        assert_eq!(alert_trigger.depth, 2);
+    }
+}
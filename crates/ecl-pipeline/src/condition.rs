@@ -0,0 +1,232 @@
+//! Runtime context for evaluating stage conditions.
+//!
+//! Bridges the spec layer's `ConditionContext` trait to the runner's
+//! `PipelineState`. Conditions are evaluated once per stage, just before
+//! the stage's batch executes, so they observe the state as of the end of
+//! the previous batch.
+
+use ecl_pipeline_spec::condition::{
+    ConditionContext, PipelineField, Reference, SourceField, StageField, TriggerField, Value,
+};
+use ecl_pipeline_state::{PipelineState, PipelineStats, SourceState, StageId};
+
+/// A read-only view of pipeline state for one stage's condition.
+#[derive(Debug)]
+pub struct StageConditionContext<'a> {
+    /// The pipeline state at the start of the current batch.
+    pub state: &'a PipelineState,
+    /// The evaluated stage's own source (resolves `source.*`).
+    pub source: Option<&'a str>,
+    /// Number of items routed to the evaluated stage (`items.count`).
+    pub item_count: usize,
+}
+
+impl ConditionContext for StageConditionContext<'_> {
+    fn resolve(&self, reference: &Reference) -> Option<Value> {
+        match reference {
+            Reference::Pipeline(field) => Some(stat(&self.state.stats, *field)),
+            Reference::Source { name, field } => {
+                let name = name.as_deref().or(self.source)?;
+                // A source that exists in the spec but was never enumerated
+                // (e.g. a push source) reads as all zeros.
+                let default = SourceState::default();
+                let source = self.state.sources.get(name).unwrap_or(&default);
+                let value = match field {
+                    SourceField::ItemsDiscovered => source.items_discovered,
+                    SourceField::ItemsAccepted => source.items_accepted,
+                    SourceField::ItemsSkippedUnchanged => source.items_skipped_unchanged,
+                };
+                Some(int(value))
+            }
+            Reference::Stage { name, field } => {
+                let stage = self.state.stages.get(&StageId::new(name))?;
+                Some(match field {
+                    StageField::Status => Value::Str(stage.status.name().to_string()),
+                    StageField::ItemsProcessed => int(stage.items_processed),
+                    StageField::ItemsFailed => int(stage.items_failed),
+                    StageField::ItemsSkipped => int(stage.items_skipped),
+                })
+            }
+            Reference::ItemCount => Some(int(self.item_count)),
+            Reference::Trigger(field) => {
+                // An untriggered run reads as event `none` with empty
+                // strings and zero counters, so conditions need no guard.
+                let triggered_by = self.state.triggered_by.as_ref();
+                Some(match field {
+                    TriggerField::Pipeline => {
+                        Value::Str(triggered_by.map(|t| t.pipeline.clone()).unwrap_or_default())
+                    }
+                    TriggerField::RunId => Value::Str(
+                        triggered_by
+                            .map(|t| t.run_id.as_str().to_string())
+                            .unwrap_or_default(),
+                    ),
+                    TriggerField::Event => Value::Str(
+                        triggered_by
+                            .map_or("none", |t| t.event.as_str())
+                            .to_string(),
+                    ),
+                    TriggerField::Stats(field) => match triggered_by {
+                        Some(t) => stat(&t.stats, *field),
+                        None => Value::Int(0),
+                    },
+                })
+            }
+        }
+    }
+}
+
+fn stat(stats: &PipelineStats, field: PipelineField) -> Value {
+    int(match field {
+        PipelineField::ItemsDiscovered => stats.total_items_discovered,
+        PipelineField::ItemsProcessed => stats.total_items_processed,
+        PipelineField::ItemsSkippedUnchanged => stats.total_items_skipped_unchanged,
+        PipelineField::ItemsFailed => stats.total_items_failed,
+    })
+}
+
+fn int(value: usize) -> Value {
+    Value::Int(i64::try_from(value).unwrap_or(i64::MAX))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use ecl_pipeline_spec::condition::{evaluate, parse};
+    use ecl_pipeline_state::{
+        PipelineStatus, RunId, StageState, StageStatus, TriggerEvent, TriggeredBy,
+    };
+    use std::collections::BTreeMap;
+
+    fn test_state() -> PipelineState {
+        let mut sources = BTreeMap::new();
+        sources.insert(
+            "local".to_string(),
+            SourceState {
+                items_discovered: 4,
+                items_accepted: 3,
+                items_skipped_unchanged: 1,
+                items: BTreeMap::new(),
+            },
+        );
+        let mut stages = BTreeMap::new();
+        stages.insert(
+            StageId::new("extract"),
+            StageState {
+                status: StageStatus::Completed,
+                items_processed: 3,
+                items_failed: 0,
+                items_skipped: 0,
+                started_at: None,
+                completed_at: None,
+            },
+        );
+        PipelineState {
+            run_id: RunId::new("run-1"),
+            pipeline_name: "test".to_string(),
+            started_at: Utc::now(),
+            last_checkpoint: Utc::now(),
+            status: PipelineStatus::Pending,
+            current_batch: 1,
+            sources,
+            stages,
+            stats: PipelineStats {
+                total_items_discovered: 4,
+                total_items_processed: 3,
+                total_items_skipped_unchanged: 1,
+                total_items_failed: 0,
+            },
+            triggered_by: None,
+        }
+    }
+
+    fn eval(input: &str, source: Option<&str>) -> bool {
+        let state = test_state();
+        let ctx = StageConditionContext {
+            state: &state,
+            source,
+            item_count: 2,
+        };
+        evaluate(&parse(input).unwrap(), &ctx).unwrap()
+    }
+
+    #[test]
+    fn test_resolves_pipeline_stats() {
+        assert!(eval("pipeline.items_discovered == 4", None));
+        assert!(eval("pipeline.items_failed == 0", None));
+    }
+
+    #[test]
+    fn test_resolves_own_and_named_sources() {
+        assert!(eval("source.items_accepted == 3", Some("local")));
+        assert!(eval("sources.local.items_skipped_unchanged == 1", None));
+    }
+
+    #[test]
+    fn test_unenumerated_source_reads_as_zero() {
+        assert!(eval("sources.webhook.items_discovered == 0", None));
+    }
+
+    #[test]
+    fn test_resolves_stage_status_and_counts() {
+        assert!(eval("stages.extract.status == 'completed'", None));
+        assert!(eval("stages.extract.items_processed == 3", None));
+    }
+
+    #[test]
+    fn test_resolves_item_count() {
+        assert!(eval("items.count == 2", None));
+    }
+
+    #[test]
+    fn test_untriggered_run_reads_trigger_defaults() {
+        assert!(eval("trigger.event == 'none'", None));
+        assert!(eval("trigger.pipeline == ''", None));
+        assert!(eval("trigger.items_failed == 0", None));
+    }
+
+    #[test]
+    fn test_resolves_trigger_fields() {
+        let mut state = test_state();
+        state.triggered_by = Some(TriggeredBy {
+            pipeline: "ingest".to_string(),
+            run_id: RunId::new("run-0"),
+            event: TriggerEvent::OnFailure,
+            stats: PipelineStats {
+                total_items_failed: 3,
+                ..PipelineStats::default()
+            },
+            depth: 1,
+        });
+        let ctx = StageConditionContext {
+            state: &state,
+            source: None,
+            item_count: 0,
+        };
+        for input in [
+            "trigger.event == 'on_failure'",
+            "trigger.pipeline == 'ingest'",
+            "trigger.run_id == 'run-0'",
+            "trigger.items_failed > 2",
+        ] {
+            assert!(evaluate(&parse(input).unwrap(), &ctx).unwrap(), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_own_source_without_source_is_unresolved() {
+        let state = test_state();
+        let ctx = StageConditionContext {
+            state: &state,
+            source: None,
+            item_count: 0,
+        };
+        let reference = Reference::Source {
+            name: None,
+            field: SourceField::ItemsDiscovered,
+        };
+        assert_eq!(ctx.resolve(&reference), None);
+    }
+}
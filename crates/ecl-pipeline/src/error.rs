@@ -80,6 +80,54 @@ pub enum PipelineError {
         /// Error detail.
         error: String,
     },
+
+    /// A stage condition could not be evaluated.
+    #[error("condition for stage '{stage}' could not be evaluated: {source}")]
+    Condition {
+        /// The stage whose condition failed.
+        stage: String,
+        /// The underlying condition error.
+        source: ecl_pipeline_spec::ConditionError,
+    },
+
+    /// A pipeline has no usable schedule.
+    #[error("schedule error for pipeline '{pipeline}': {message}")]
+    Schedule {
+        /// The pipeline being scheduled.
+        pipeline: String,
+        /// Error detail.
+        message: String,
+    },
+
+    /// Pipelines trigger each other in a loop.
+    #[error("trigger cycle detected: {}", cycle.join(" -> "))]
+    TriggerCycle {
+        /// Pipeline names along the cycle, starting and ending with the
+        /// same pipeline.
+        cycle: Vec<String>,
+    },
+
+    /// A trigger chain goes deeper than allowed.
+    #[error(
+        "pipeline '{pipeline}' is {depth} triggers deep, exceeding the maximum chain depth of {max_depth}"
+    )]
+    ChainTooDeep {
+        /// The pipeline past the limit.
+        pipeline: String,
+        /// Its distance (in triggers) from the root.
+        depth: usize,
+        /// The configured limit.
+        max_depth: usize,
+    },
+
+    /// A pipeline config in a trigger chain could not be loaded.
+    #[error("failed to load chained pipeline config '{}': {message}", path.display())]
+    ChainConfig {
+        /// The config path.
+        path: std::path::PathBuf,
+        /// Error detail.
+        message: String,
+    },
 }
 
 /// Result type for pipeline operations.
@@ -125,6 +173,57 @@ mod tests {
         assert!(msg.contains("parse error"), "should contain error");
     }
 
+    #[test]
+    fn test_error_display_condition() {
+        let err = PipelineError::Condition {
+            stage: "emit".to_string(),
+            source: ecl_pipeline_spec::ConditionError::Unresolved {
+                path: "stages.extract.status".to_string(),
+            },
+        };
+        let msg = err.to_string();
+        assert!(msg.contains("emit"), "should contain stage name");
+        assert!(
+            msg.contains("stages.extract.status"),
+            "should contain reference"
+        );
+    }
+
+    #[test]
+    fn test_error_display_schedule() {
+        let err = PipelineError::Schedule {
+            pipeline: "nightly".to_string(),
+            message: "no [schedule] section".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "schedule error for pipeline 'nightly': no [schedule] section"
+        );
+    }
+
+    #[test]
+    fn test_error_display_trigger_cycle() {
+        let err = PipelineError::TriggerCycle {
+            cycle: vec!["a".to_string(), "b".to_string(), "a".to_string()],
+        };
+        assert_eq!(err.to_string(), "trigger cycle detected: a -> b -> a");
+    }
+
+    #[test]
+    fn test_error_display_chain_too_deep() {
+        let err = PipelineError::ChainTooDeep {
+            pipeline: "publish".to_string(),
+            depth: 9,
+            max_depth: 8,
+        };
+        let msg = err.to_string();
+        assert!(msg.contains("publish"), "should contain pipeline");
+        assert!(
+            msg.contains("maximum chain depth of 8"),
+            "should contain limit"
+        );
+    }
+
     #[test]
     fn test_error_implements_send_sync() {
         fn assert_send_sync<T: Send + Sync>() {}
@@ -8,8 +8,11 @@
 //! - Batch execution with concurrent stages
 //! - Per-item bounded concurrency within stages
 //! - Retry with exponential backoff
+//! - Conditional stages (`StageSpec.condition`)
 //! - Checkpointing at batch boundaries
 //! - Resume from checkpoint after interruption
+//! - Cron scheduling with overlap protection and catch-up (`scheduler`)
+//! - Pipeline chaining through `[triggers]` (`chain`)
 //!
 //! # Usage
 //!
@@ -21,15 +24,23 @@
 //! ```
 
 pub mod batch;
+pub mod chain;
+pub mod condition;
 pub mod error;
 pub mod lifecycle;
 pub mod registry;
 pub mod runner;
+pub mod scheduler;
 
 pub use batch::{
     RetryResult, StageItemFailure, StageItemSkipped, StageItemSuccess, StageResult,
     execute_stage_items, execute_with_retry,
 };
+pub use chain::{
+    ChainEntry, ChainLauncher, ChainNode, ChainRun, DEFAULT_MAX_CHAIN_DEPTH, LaunchOutcome,
+    PipelineChain,
+};
 pub use error::{PipelineError, Result};
 pub use registry::{AdapterRegistry, StageRegistry};
 pub use runner::PipelineRunner;
+pub use scheduler::{Clock, ManualClock, PipelineJob, ScheduledPipeline, Scheduler, SystemClock};
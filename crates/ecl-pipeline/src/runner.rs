@@ -4,19 +4,20 @@
 //! lifecycle: enumerate sources, apply incrementality, run batches,
 //! checkpoint at boundaries, and finalize.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::sync::Arc;
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use tokio::sync::Notify;
 
 use ecl_pipeline_state::{
     Blake3Hash, Checkpoint, ItemProvenance, ItemState, ItemStatus, PipelineState, PipelineStats,
-    PipelineStatus, RunId, StageId, StageState, StageStatus, StateStore,
+    PipelineStatus, RunId, StageId, StageState, StageStatus, StateStore, TriggeredBy,
 };
 use ecl_pipeline_topo::{ExtractedDocument, PipelineItem, PipelineTopology, StageContext};
 
 use crate::batch::{StageResult, execute_stage_batch, execute_stage_items};
+use crate::condition::StageConditionContext;
 use crate::error::{PipelineError, Result};
 
 /// The pipeline runner: orchestrates enumeration, incrementality,
@@ -85,44 +86,7 @@ impl PipelineRunner {
                 );
                 checkpoint.state
             }
-            None => {
-                let now = Utc::now();
-                let run_id = RunId::new(format!(
-                    "{}-{}",
-                    topology.spec.name,
-                    now.format("%Y%m%dT%H%M%S")
-                ));
-
-                // Initialize stage states from topology schedule.
-                let mut stages = BTreeMap::new();
-                for batch in &topology.schedule {
-                    for stage_id in batch {
-                        stages.insert(
-                            stage_id.clone(),
-                            StageState {
-                                status: StageStatus::Pending,
-                                items_processed: 0,
-                                items_failed: 0,
-                                items_skipped: 0,
-                                started_at: None,
-                                completed_at: None,
-                            },
-                        );
-                    }
-                }
-
-                PipelineState {
-                    run_id,
-                    pipeline_name: topology.spec.name.clone(),
-                    started_at: now,
-                    last_checkpoint: now,
-                    status: PipelineStatus::Pending,
-                    current_batch: 0,
-                    sources: BTreeMap::new(),
-                    stages,
-                    stats: PipelineStats::default(),
-                }
-            }
+            None => fresh_state(&topology),
         };
 
         Ok(Self {
@@ -135,6 +99,46 @@ impl PipelineRunner {
         })
     }
 
+    /// Create a runner for the next scheduled run of a pipeline.
+    ///
+    /// Like [`PipelineRunner::new`], but a checkpoint whose run already
+    /// completed is not resumed: a fresh run is started instead (previous
+    /// content hashes still drive incrementality). Unfinished runs are
+    /// resumed as usual.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`PipelineRunner::new`].
+    pub async fn next_run(topology: PipelineTopology, store: Box<dyn StateStore>) -> Result<Self> {
+        let completed = matches!(
+            store.load_checkpoint().await?,
+            Some(ref checkpoint) if matches!(checkpoint.state.status, PipelineStatus::Completed { .. })
+        );
+        if !completed {
+            return Self::new(topology, store).await;
+        }
+
+        let state = fresh_state(&topology);
+        tracing::info!(run_id = %state.run_id, "Starting fresh run after completed checkpoint");
+        Ok(Self {
+            topology,
+            state,
+            store,
+            checkpoint_sequence: 0,
+            shutdown: Arc::new(Notify::new()),
+            active_items: Vec::new(),
+        })
+    }
+
+    /// Mark this run as triggered by an upstream pipeline.
+    ///
+    /// The trigger is stored in the run's state (and so in every
+    /// checkpoint), where stage conditions read it as `trigger.*`.
+    pub fn with_trigger(mut self, trigger: TriggeredBy) -> Self {
+        self.state.triggered_by = Some(trigger);
+        self
+    }
+
     /// Execute the pipeline.
     ///
     /// Lifecycle:
@@ -181,6 +185,18 @@ impl PipelineRunner {
         };
         self.checkpoint().await?;
 
+        // Prune run history now that this run is safely recorded.
+        let retention = &self.topology.spec.defaults.retention;
+        if !retention.is_unbounded() {
+            match self.store.prune_runs(retention, Utc::now()).await {
+                Ok(pruned) if !pruned.is_empty() => {
+                    tracing::info!(pruned = pruned.len(), "pruned expired runs from history");
+                }
+                Ok(_) => {}
+                Err(e) => tracing::warn!(error = %e, "checkpoint retention failed (non-fatal)"),
+            }
+        }
+
         // Phase 5: File lifecycle management (if configured).
         if let Some(ref lifecycle_spec) = self.topology.spec.lifecycle {
             let source_objects = self.collect_source_object_ids();
@@ -196,6 +212,30 @@ impl PipelineRunner {
             }
         }
 
+        // Phase 6: Let pull sources post-process their items (e.g. archive
+        // fetched SFTP files).
+        let succeeded = self.state.stats.total_items_failed == 0;
+        for (name, adapter) in &self.topology.sources {
+            let item_ids: Vec<String> = self
+                .state
+                .sources
+                .get(name)
+                .map(|source| {
+                    source
+                        .items
+                        .values()
+                        .map(|item| item.source_id.clone())
+                        .collect()
+                })
+                .unwrap_or_default();
+            if let Err(e) = adapter
+                .on_run_complete(self.state.run_id.as_str(), &item_ids, succeeded)
+                .await
+            {
+                tracing::warn!(source = %name, error = %e, "source on_run_complete failed (non-fatal)");
+            }
+        }
+
         let duration_ms = run_start.elapsed().as_millis() as u64;
         tracing::info!(
             pipeline = %pipeline_name,
@@ -217,11 +257,20 @@ impl PipelineRunner {
     async fn execute_batch(&mut self, batch_idx: usize, stages: &[StageId]) -> Result<()> {
         tracing::info!(batch = batch_idx, stages = stages.len(), "executing batch");
 
-        // Filter out stages whose conditions are not met.
-        let active_stages: Vec<&StageId> = stages
-            .iter()
-            .filter(|id| self.should_execute_stage(id))
-            .collect();
+        // Filter out stages whose conditions are not met. All conditions in
+        // a batch are evaluated against the state as of the batch start.
+        let mut active_stages: Vec<&StageId> = Vec::new();
+        let mut skipped_stages: Vec<&StageId> = Vec::new();
+        for stage_id in stages {
+            if self.should_execute_stage(stage_id)? {
+                active_stages.push(stage_id);
+            } else {
+                skipped_stages.push(stage_id);
+            }
+        }
+        for stage_id in skipped_stages {
+            self.skip_stage_by_condition(stage_id);
+        }
 
         // Execute stages concurrently (one tokio task per stage).
         let mut join_set = tokio::task::JoinSet::new();
@@ -269,20 +318,20 @@ impl PipelineRunner {
 
     /// Enumerate all sources and populate the item list.
     ///
-    /// Calls `SourceAdapter::enumerate()` for each source in the topology.
-    /// Creates `ItemState` entries in `PipelineState::sources` for each
+    /// Calls `SourceAdapter::enumerate_since()` for each source in the
+    /// topology with the start of the last completed run. Creates
+    /// `ItemState` entries in `PipelineState::sources` for each
     /// discovered item.
     async fn enumerate_sources(&mut self) -> Result<()> {
+        let last_run = self.last_run_started_at().await?;
         for (name, adapter) in &self.topology.sources {
             tracing::info!(source = %name, "enumerating source");
-            let items =
-                adapter
-                    .enumerate()
-                    .await
-                    .map_err(|e| PipelineError::SourceEnumeration {
-                        source_name: name.clone(),
-                        detail: e.to_string(),
-                    })?;
+            let items = adapter.enumerate_since(last_run).await.map_err(|e| {
+                PipelineError::SourceEnumeration {
+                    source_name: name.clone(),
+                    detail: e.to_string(),
+                }
+            })?;
             tracing::info!(source = %name, items = items.len(), "source enumeration complete");
 
             let source_state = self.state.sources.entry(name.clone()).or_default();
@@ -311,7 +360,13 @@ impl PipelineRunner {
                         display_name: item.display_name.clone(),
                         source_id: item.id.clone(),
                         source_name: name.clone(),
-                        content_hash: Blake3Hash::new(""),
+                        // The adapter's cheap fingerprint, if it opted in to
+                        // skipping on it; empty (never matches) otherwise.
+                        content_hash: if adapter.skips_unchanged_by_source_hash() {
+                            Blake3Hash::new(item.source_hash.clone().unwrap_or_default())
+                        } else {
+                            Blake3Hash::new("")
+                        },
                         status: ItemStatus::Pending,
                         completed_stages: vec![],
                         provenance: provenance.clone(),
@@ -337,15 +392,37 @@ impl PipelineRunner {
         Ok(())
     }
 
+    /// When the most recent completed run of this pipeline started, for
+    /// sources filtering with `modified_after = "last_run"`.
+    ///
+    /// The start rather than the end is used so files written while that
+    /// run was listing are picked up again rather than missed.
+    async fn last_run_started_at(&self) -> Result<Option<DateTime<Utc>>> {
+        let runs = self.store.list_runs().await?;
+        Ok(runs
+            .into_iter()
+            .filter(|run| {
+                run.run_id != self.state.run_id
+                    && run.pipeline_name == self.state.pipeline_name
+                    && matches!(run.status, PipelineStatus::Completed { .. })
+            })
+            .map(|run| run.started_at)
+            .max())
+    }
+
     /// Compare content hashes against previous run; mark unchanged items.
     ///
     /// Loads hashes from the store's most recent completed run and compares
-    /// each item's content hash. Items with matching hashes are marked as
-    /// `ItemStatus::Unchanged` and will be skipped during execution.
+    /// each item's content hash (the `source_hash` reported at enumeration,
+    /// for adapters that opt in via `skips_unchanged_by_source_hash`).
+    /// Items with matching hashes are marked as `ItemStatus::Unchanged` and
+    /// removed from the active pool, so they are neither fetched nor run
+    /// through any stage. Items without a hash never match.
     async fn apply_incrementality(&mut self) -> Result<()> {
         let previous_hashes = self.store.load_previous_hashes().await?;
 
-        for source_state in self.state.sources.values_mut() {
+        let mut unchanged = BTreeSet::new();
+        for (source_name, source_state) in self.state.sources.iter_mut() {
             let mut skipped = 0usize;
             for (item_id, item_state) in source_state.items.iter_mut() {
                 if let Some(prev_hash) = previous_hashes.get(item_id)
@@ -353,11 +430,14 @@ impl PipelineRunner {
                     && item_state.content_hash == *prev_hash
                 {
                     item_state.status = ItemStatus::Unchanged;
+                    unchanged.insert((source_name.clone(), item_id.clone()));
                     skipped += 1;
                 }
             }
             source_state.items_skipped_unchanged += skipped;
         }
+        self.active_items
+            .retain(|item| !unchanged.contains(&(item.source_name.clone(), item.id.clone())));
         self.state.update_stats();
         Ok(())
     }
@@ -379,12 +459,19 @@ impl PipelineRunner {
         Ok(())
     }
 
-    /// Save all completed item content hashes for future incrementality.
+    /// Save item content hashes for future incrementality.
+    ///
+    /// Unchanged items are saved alongside completed ones: the store replaces
+    /// the previous snapshot, so leaving them out would make the next run
+    /// process them again.
     async fn save_completed_hashes(&self) -> Result<()> {
         let mut hashes = BTreeMap::new();
         for source_state in self.state.sources.values() {
             for (item_id, item_state) in &source_state.items {
-                if matches!(item_state.status, ItemStatus::Completed) {
+                if matches!(
+                    item_state.status,
+                    ItemStatus::Completed | ItemStatus::Unchanged
+                ) {
                     hashes.insert(item_id.clone(), item_state.content_hash.clone());
                 }
             }
@@ -397,10 +484,72 @@ impl PipelineRunner {
 
     /// Determine whether a stage should execute.
     ///
-    /// Currently always returns true. Condition expression evaluation
-    /// is deferred to a future milestone.
-    fn should_execute_stage(&self, _stage_id: &StageId) -> bool {
-        true
+    /// Stages without a condition always execute. Otherwise the stage's
+    /// `ConditionExpr` is evaluated against the current pipeline state,
+    /// the stage's own source, and the number of items routed to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PipelineError::Condition` if a reference in the condition
+    /// cannot be resolved.
+    fn should_execute_stage(&self, stage_id: &StageId) -> Result<bool> {
+        let Some(stage) = self.topology.stages.get(stage_id.as_str()) else {
+            return Ok(true);
+        };
+        let Some(ref condition) = stage.condition else {
+            return Ok(true);
+        };
+
+        let ctx = StageConditionContext {
+            state: &self.state,
+            source: stage.source.as_deref(),
+            item_count: self.count_items_for_stage(stage_id),
+        };
+        condition
+            .evaluate(&ctx)
+            .map_err(|source| PipelineError::Condition {
+                stage: stage_id.as_str().to_string(),
+                source,
+            })
+    }
+
+    /// Record a stage as skipped because its condition was not met.
+    ///
+    /// The stage's input items stay in the active pool, so downstream
+    /// stages still see them.
+    fn skip_stage_by_condition(&mut self, stage_id: &StageId) {
+        let condition = self
+            .topology
+            .stages
+            .get(stage_id.as_str())
+            .and_then(|stage| stage.condition.as_ref())
+            .map(|condition| condition.as_str().to_string())
+            .unwrap_or_default();
+        tracing::info!(stage = %stage_id, %condition, "stage condition not met, skipping");
+
+        if let Some(stage_state) = self.state.stages.get_mut(stage_id) {
+            let now = Utc::now();
+            stage_state.status = StageStatus::SkippedByCondition { condition };
+            stage_state.started_at = Some(now);
+            stage_state.completed_at = Some(now);
+        }
+    }
+
+    /// Count the items that `collect_items_for_stage` would return,
+    /// without cloning them.
+    fn count_items_for_stage(&self, stage_id: &StageId) -> usize {
+        let input_streams = self
+            .topology
+            .spec
+            .stages
+            .get(stage_id.as_str())
+            .map(|spec| spec.input_streams.as_slice())
+            .unwrap_or_default();
+
+        self.active_items
+            .iter()
+            .filter(|item| matches_stream(input_streams, &item.stream))
+            .count()
     }
 
     /// Collect items for a given stage from the active items pool.
@@ -729,6 +878,47 @@ fn matches_stream(input_streams: &[String], item_stream: &Option<String>) -> boo
     input_streams.iter().any(|s| s == stream)
 }
 
+/// Build the initial state for a new run of the given topology.
+fn fresh_state(topology: &PipelineTopology) -> PipelineState {
+    let now = Utc::now();
+    let run_id = RunId::new(format!(
+        "{}-{}",
+        topology.spec.name,
+        now.format("%Y%m%dT%H%M%S")
+    ));
+
+    // Initialize stage states from topology schedule.
+    let mut stages = BTreeMap::new();
+    for batch in &topology.schedule {
+        for stage_id in batch {
+            stages.insert(
+                stage_id.clone(),
+                StageState {
+                    status: StageStatus::Pending,
+                    items_processed: 0,
+                    items_failed: 0,
+                    items_skipped: 0,
+                    started_at: None,
+                    completed_at: None,
+                },
+            );
+        }
+    }
+
+    PipelineState {
+        run_id,
+        pipeline_name: topology.spec.name.clone(),
+        started_at: now,
+        last_checkpoint: now,
+        status: PipelineStatus::Pending,
+        current_batch: 0,
+        sources: BTreeMap::new(),
+        stages,
+        stats: PipelineStats::default(),
+        triggered_by: None,
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -746,6 +936,7 @@ mod tests {
     struct MockSourceAdapter {
         kind: String,
         items: Vec<SourceItem>,
+        skips_unchanged: bool,
     }
 
     impl MockSourceAdapter {
@@ -753,8 +944,15 @@ mod tests {
             Self {
                 kind: kind.to_string(),
                 items,
+                skips_unchanged: false,
             }
         }
+
+        /// Opt in to skipping unchanged items by `source_hash`.
+        fn skipping_unchanged(mut self) -> Self {
+            self.skips_unchanged = true;
+            self
+        }
     }
 
     #[async_trait::async_trait]
@@ -767,6 +965,10 @@ mod tests {
             Ok(self.items.clone())
         }
 
+        fn skips_unchanged_by_source_hash(&self) -> bool {
+            self.skips_unchanged
+        }
+
         async fn fetch(
             &self,
             item: &SourceItem,
@@ -787,6 +989,44 @@ mod tests {
         }
     }
 
+    /// A source adapter that records `on_run_complete` calls.
+    #[derive(Debug)]
+    struct RecordingSourceAdapter {
+        inner: MockSourceAdapter,
+        completions: std::sync::Mutex<Vec<(Vec<String>, bool)>>,
+    }
+
+    #[async_trait::async_trait]
+    impl SourceAdapter for RecordingSourceAdapter {
+        fn source_kind(&self) -> &str {
+            self.inner.source_kind()
+        }
+
+        async fn enumerate(&self) -> std::result::Result<Vec<SourceItem>, SourceError> {
+            self.inner.enumerate().await
+        }
+
+        async fn fetch(
+            &self,
+            item: &SourceItem,
+        ) -> std::result::Result<ExtractedDocument, SourceError> {
+            self.inner.fetch(item).await
+        }
+
+        async fn on_run_complete(
+            &self,
+            _run_id: &str,
+            item_ids: &[String],
+            succeeded: bool,
+        ) -> std::result::Result<(), SourceError> {
+            self.completions
+                .lock()
+                .unwrap()
+                .push((item_ids.to_vec(), succeeded));
+            Ok(())
+        }
+    }
+
     /// A source adapter that always fails enumeration.
     #[derive(Debug)]
     struct FailingSourceAdapter;
@@ -1027,6 +1267,7 @@ mod tests {
                 sources: BTreeMap::new(),
                 stages: BTreeMap::new(),
                 stats: PipelineStats::default(),
+                triggered_by: None,
             },
         };
         store.save_checkpoint(&checkpoint).await.unwrap();
@@ -1036,6 +1277,199 @@ mod tests {
         assert_eq!(runner.state().current_batch, 1);
     }
 
+    fn checkpoint_with_status(topo: &PipelineTopology, status: PipelineStatus) -> Checkpoint {
+        Checkpoint {
+            version: 1,
+            sequence: 3,
+            created_at: Utc::now(),
+            spec: (*topo.spec).clone(),
+            schedule: topo.schedule.clone(),
+            spec_hash: Blake3Hash::new("test-hash-abc123"),
+            state: PipelineState {
+                run_id: RunId::new("previous-run"),
+                pipeline_name: "test-pipeline".to_string(),
+                started_at: Utc::now(),
+                last_checkpoint: Utc::now(),
+                status,
+                current_batch: 1,
+                sources: BTreeMap::new(),
+                stages: BTreeMap::new(),
+                stats: PipelineStats::default(),
+                triggered_by: None,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_runner_next_run_starts_fresh_after_completed() {
+        let topo = build_test_topology(
+            vec![(
+                "src".to_string(),
+                Arc::new(MockSourceAdapter::new("fs", vec![])),
+            )],
+            vec![(
+                "stage-a".to_string(),
+                Arc::new(MockStage::new("stage-a")),
+                None,
+                false,
+            )],
+        );
+        let store = Box::new(InMemoryStateStore::new());
+        let checkpoint = checkpoint_with_status(
+            &topo,
+            PipelineStatus::Completed {
+                finished_at: Utc::now(),
+            },
+        );
+        store.save_checkpoint(&checkpoint).await.unwrap();
+
+        let runner = PipelineRunner::next_run(topo, store).await.unwrap();
+        assert_ne!(runner.state().run_id.as_str(), "previous-run");
+        assert_eq!(runner.state().current_batch, 0);
+        assert!(matches!(runner.state().status, PipelineStatus::Pending));
+    }
+
+    #[tokio::test]
+    async fn test_last_run_started_at_uses_completed_runs() {
+        let topo = build_test_topology(
+            vec![(
+                "src".to_string(),
+                Arc::new(MockSourceAdapter::new("fs", vec![])),
+            )],
+            vec![(
+                "stage-a".to_string(),
+                Arc::new(MockStage::new("stage-a")),
+                None,
+                false,
+            )],
+        );
+        let store = Box::new(InMemoryStateStore::new());
+        let runner = PipelineRunner::new(topo.clone(), store).await.unwrap();
+        assert_eq!(runner.last_run_started_at().await.unwrap(), None);
+
+        let store = Box::new(InMemoryStateStore::new());
+        let mut previous = checkpoint_with_status(
+            &topo,
+            PipelineStatus::Completed {
+                finished_at: Utc::now(),
+            },
+        );
+        let started_at = Utc::now() - chrono::Duration::hours(1);
+        previous.state.started_at = started_at;
+        store.save_checkpoint(&previous).await.unwrap();
+
+        let runner = PipelineRunner::next_run(topo, store).await.unwrap();
+        assert_eq!(
+            runner.last_run_started_at().await.unwrap(),
+            Some(started_at)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_runner_with_trigger_is_checkpointed() {
+        let topo = build_test_topology(
+            vec![(
+                "src".to_string(),
+                Arc::new(MockSourceAdapter::new("fs", vec![])),
+            )],
+            vec![(
+                "stage-a".to_string(),
+                Arc::new(MockStage::new("stage-a")),
+                None,
+                false,
+            )],
+        );
+        let trigger = ecl_pipeline_state::TriggeredBy {
+            pipeline: "upstream".to_string(),
+            run_id: RunId::new("upstream-run"),
+            event: ecl_pipeline_state::TriggerEvent::OnSuccess,
+            stats: PipelineStats::default(),
+            depth: 1,
+        };
+
+        let mut runner = PipelineRunner::new(topo, Box::new(InMemoryStateStore::new()))
+            .await
+            .unwrap()
+            .with_trigger(trigger.clone());
+        let state = runner.run().await.unwrap();
+        assert_eq!(state.triggered_by, Some(trigger.clone()));
+
+        let checkpoint = runner.store.load_checkpoint().await.unwrap().unwrap();
+        assert_eq!(checkpoint.state.triggered_by, Some(trigger));
+    }
+
+    #[tokio::test]
+    async fn test_runner_prunes_history_after_completion() {
+        let mut topo = build_test_topology(
+            vec![(
+                "src".to_string(),
+                Arc::new(MockSourceAdapter::new("fs", vec![])),
+            )],
+            vec![(
+                "stage-a".to_string(),
+                Arc::new(MockStage::new("stage-a")),
+                None,
+                false,
+            )],
+        );
+        Arc::make_mut(&mut topo.spec).defaults.retention = RetentionSpec {
+            keep_runs: Some(1),
+            keep_days: None,
+        };
+        let store = Box::new(InMemoryStateStore::new());
+        let mut previous = checkpoint_with_status(
+            &topo,
+            PipelineStatus::Completed {
+                finished_at: Utc::now(),
+            },
+        );
+        previous.state.started_at = Utc::now() - chrono::Duration::days(1);
+        store.save_checkpoint(&previous).await.unwrap();
+
+        let mut runner = PipelineRunner::next_run(topo, store).await.unwrap();
+        runner.run().await.unwrap();
+
+        let runs = runner.store.list_runs().await.unwrap();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].run_id, runner.state().run_id);
+        assert!(
+            runner
+                .store
+                .load_run(&RunId::new("previous-run"))
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_runner_next_run_resumes_unfinished() {
+        let topo = build_test_topology(
+            vec![(
+                "src".to_string(),
+                Arc::new(MockSourceAdapter::new("fs", vec![])),
+            )],
+            vec![(
+                "stage-a".to_string(),
+                Arc::new(MockStage::new("stage-a")),
+                None,
+                false,
+            )],
+        );
+        let store = Box::new(InMemoryStateStore::new());
+        let checkpoint = checkpoint_with_status(
+            &topo,
+            PipelineStatus::Interrupted {
+                interrupted_at: Utc::now(),
+            },
+        );
+        store.save_checkpoint(&checkpoint).await.unwrap();
+
+        let runner = PipelineRunner::next_run(topo, store).await.unwrap();
+        assert_eq!(runner.state().run_id.as_str(), "previous-run");
+        assert_eq!(runner.state().current_batch, 1);
+    }
+
     #[tokio::test]
     async fn test_runner_new_config_drift_error() {
         let topo = build_test_topology(
@@ -1069,6 +1503,7 @@ mod tests {
                 sources: BTreeMap::new(),
                 stages: BTreeMap::new(),
                 stats: PipelineStats::default(),
+                triggered_by: None,
             },
         };
         store.save_checkpoint(&checkpoint).await.unwrap();
@@ -1143,6 +1578,7 @@ mod tests {
                 sources,
                 stages: BTreeMap::new(),
                 stats: PipelineStats::default(),
+                triggered_by: None,
             },
         };
         store.save_checkpoint(&checkpoint).await.unwrap();
@@ -1286,6 +1722,78 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_apply_incrementality_uses_source_hash() {
+        let mut changed = make_source_item("b");
+        changed.source_hash = Some("2048:1767225600".to_string());
+        let mut unchanged = make_source_item("a");
+        unchanged.source_hash = Some("1024:1767225600".to_string());
+        let topo = build_test_topology(
+            vec![(
+                "src".to_string(),
+                Arc::new(
+                    MockSourceAdapter::new("sftp", vec![unchanged, changed]).skipping_unchanged(),
+                ),
+            )],
+            vec![(
+                "stage-a".to_string(),
+                Arc::new(MockStage::new("stage-a")),
+                None,
+                false,
+            )],
+        );
+
+        let store = InMemoryStateStore::new();
+        let mut hashes = BTreeMap::new();
+        hashes.insert("a".to_string(), Blake3Hash::new("1024:1767225600"));
+        hashes.insert("b".to_string(), Blake3Hash::new("1024:1767225600"));
+        store
+            .save_completed_hashes(&RunId::new("prev"), &hashes)
+            .await
+            .unwrap();
+
+        let mut runner = PipelineRunner::new(topo, Box::new(store)).await.unwrap();
+        runner.enumerate_sources().await.unwrap();
+        runner.apply_incrementality().await.unwrap();
+
+        let items = &runner.state().sources["src"].items;
+        assert!(matches!(items["a"].status, ItemStatus::Unchanged));
+        assert!(matches!(items["b"].status, ItemStatus::Pending));
+    }
+
+    #[tokio::test]
+    async fn test_apply_incrementality_ignores_source_hash_without_opt_in() {
+        let mut item = make_source_item("a");
+        item.source_hash = Some("md5abc".to_string());
+        let topo = build_test_topology(
+            vec![(
+                "src".to_string(),
+                Arc::new(MockSourceAdapter::new("gdrive", vec![item])),
+            )],
+            vec![(
+                "stage-a".to_string(),
+                Arc::new(MockStage::new("stage-a")),
+                None,
+                false,
+            )],
+        );
+
+        let store = InMemoryStateStore::new();
+        let mut hashes = BTreeMap::new();
+        hashes.insert("a".to_string(), Blake3Hash::new("md5abc"));
+        store
+            .save_completed_hashes(&RunId::new("prev"), &hashes)
+            .await
+            .unwrap();
+
+        let mut runner = PipelineRunner::new(topo, Box::new(store)).await.unwrap();
+        runner.enumerate_sources().await.unwrap();
+        runner.apply_incrementality().await.unwrap();
+
+        let items = &runner.state().sources["src"].items;
+        assert!(matches!(items["a"].status, ItemStatus::Pending));
+    }
+
     #[tokio::test]
     async fn test_apply_incrementality_no_previous_hashes() {
         let topo = build_test_topology(
@@ -1437,6 +1945,46 @@ mod tests {
         assert_eq!(hashes["a"].as_str(), "hash-a");
     }
 
+    #[tokio::test]
+    async fn test_unchanged_items_stay_skipped_across_runs() {
+        let mut item = make_source_item("a");
+        item.source_hash = Some("1024:1767225600".to_string());
+        let topo = build_test_topology(
+            vec![(
+                "src".to_string(),
+                Arc::new(MockSourceAdapter::new("sftp", vec![item]).skipping_unchanged()),
+            )],
+            vec![(
+                "stage-a".to_string(),
+                Arc::new(MockStage::new("stage-a")),
+                None,
+                false,
+            )],
+        );
+
+        let mut runner = PipelineRunner::new(topo.clone(), Box::new(InMemoryStateStore::new()))
+            .await
+            .unwrap();
+        let state = runner.run().await.unwrap();
+        assert!(matches!(
+            state.sources["src"].items["a"].status,
+            ItemStatus::Completed
+        ));
+
+        for _ in 0..2 {
+            let mut next = PipelineRunner::next_run(topo.clone(), runner.store)
+                .await
+                .unwrap();
+            let state = next.run().await.unwrap();
+            assert!(matches!(
+                state.sources["src"].items["a"].status,
+                ItemStatus::Unchanged
+            ));
+            assert_eq!(state.sources["src"].items_skipped_unchanged, 1);
+            runner = next;
+        }
+    }
+
     // ── Full run() lifecycle tests ──────────────────────────────────────
 
     #[tokio::test]
@@ -1460,6 +2008,32 @@ mod tests {
         assert!(matches!(state.status, PipelineStatus::Completed { .. }));
     }
 
+    #[tokio::test]
+    async fn test_run_notifies_sources_on_completion() {
+        let adapter = Arc::new(RecordingSourceAdapter {
+            inner: MockSourceAdapter::new("fs", vec![make_source_item("a"), make_source_item("b")]),
+            completions: std::sync::Mutex::new(Vec::new()),
+        });
+        let topo = build_test_topology(
+            vec![("src".to_string(), adapter.clone())],
+            vec![(
+                "stage-a".to_string(),
+                Arc::new(MockStage::new("stage-a")),
+                None,
+                false,
+            )],
+        );
+        let store = Box::new(InMemoryStateStore::new());
+        let mut runner = PipelineRunner::new(topo, store).await.unwrap();
+
+        runner.run().await.unwrap();
+        let completions = adapter.completions.lock().unwrap();
+        assert_eq!(
+            *completions,
+            vec![(vec!["a".to_string(), "b".to_string()], true)]
+        );
+    }
+
     #[tokio::test]
     async fn test_run_multi_stage_pipeline() {
         let topo = build_test_topology(
@@ -1660,6 +2234,7 @@ mod tests {
                     total_items_skipped_unchanged: 0,
                     total_items_failed: 0,
                 },
+                triggered_by: None,
             },
         };
         store.save_checkpoint(&checkpoint).await.unwrap();
@@ -1693,8 +2268,13 @@ mod tests {
 
     // ── Helper method tests ─────────────────────────────────────────────
 
+    /// Attach a condition to a stage in a test topology.
+    fn set_condition(topo: &mut PipelineTopology, stage: &str, expr: &str) {
+        topo.stages.get_mut(stage).unwrap().condition = Some(ConditionExpr::parse(expr).unwrap());
+    }
+
     #[tokio::test]
-    async fn test_should_execute_stage_always_returns_true() {
+    async fn test_should_execute_stage_without_condition_returns_true() {
         let topo = build_test_topology(
             vec![(
                 "src".to_string(),
@@ -1710,8 +2290,130 @@ mod tests {
         let store = Box::new(InMemoryStateStore::new());
         let runner = PipelineRunner::new(topo, store).await.unwrap();
 
-        assert!(runner.should_execute_stage(&StageId::new("stage-a")));
-        assert!(runner.should_execute_stage(&StageId::new("anything")));
+        assert!(
+            runner
+                .should_execute_stage(&StageId::new("stage-a"))
+                .unwrap()
+        );
+        assert!(
+            runner
+                .should_execute_stage(&StageId::new("anything"))
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_execute_stage_evaluates_condition() {
+        let mut topo = build_test_topology(
+            vec![(
+                "src".to_string(),
+                Arc::new(MockSourceAdapter::new("fs", vec![make_source_item("a")])),
+            )],
+            vec![
+                (
+                    "stage-a".to_string(),
+                    Arc::new(MockStage::new("stage-a")),
+                    Some("src".to_string()),
+                    false,
+                ),
+                (
+                    "stage-b".to_string(),
+                    Arc::new(MockStage::new("stage-b")),
+                    None,
+                    false,
+                ),
+            ],
+        );
+        set_condition(&mut topo, "stage-a", "source.items_discovered > 0");
+        set_condition(&mut topo, "stage-b", "items.count > 1");
+        let store = Box::new(InMemoryStateStore::new());
+        let mut runner = PipelineRunner::new(topo, store).await.unwrap();
+
+        assert!(
+            !runner
+                .should_execute_stage(&StageId::new("stage-a"))
+                .unwrap()
+        );
+        runner.enumerate_sources().await.unwrap();
+        assert!(
+            runner
+                .should_execute_stage(&StageId::new("stage-a"))
+                .unwrap()
+        );
+        assert!(
+            !runner
+                .should_execute_stage(&StageId::new("stage-b"))
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_should_execute_stage_unresolved_reference_errors() {
+        let mut topo = build_test_topology(
+            vec![(
+                "src".to_string(),
+                Arc::new(MockSourceAdapter::new("fs", vec![])),
+            )],
+            vec![(
+                "stage-a".to_string(),
+                Arc::new(MockStage::new("stage-a")),
+                None,
+                false,
+            )],
+        );
+        // Unchecked: refers to a stage that is not in the state.
+        set_condition(&mut topo, "stage-a", "stages.ghost.status == 'completed'");
+        let store = Box::new(InMemoryStateStore::new());
+        let runner = PipelineRunner::new(topo, store).await.unwrap();
+
+        let err = runner
+            .should_execute_stage(&StageId::new("stage-a"))
+            .unwrap_err();
+        assert!(matches!(err, PipelineError::Condition { ref stage, .. } if stage == "stage-a"));
+    }
+
+    #[tokio::test]
+    async fn test_run_skips_stage_when_condition_false() {
+        let mut topo = build_test_topology(
+            vec![(
+                "src".to_string(),
+                Arc::new(MockSourceAdapter::new("fs", vec![make_source_item("a")])),
+            )],
+            vec![
+                (
+                    "stage-a".to_string(),
+                    Arc::new(MockStage::new("stage-a")),
+                    None,
+                    false,
+                ),
+                (
+                    "stage-b".to_string(),
+                    Arc::new(MockStage::new("stage-b")),
+                    None,
+                    false,
+                ),
+            ],
+        );
+        set_condition(&mut topo, "stage-a", "pipeline.items_discovered == 0");
+        set_condition(&mut topo, "stage-b", "stages.stage-a.status == 'skipped'");
+        let store = Box::new(InMemoryStateStore::new());
+        let mut runner = PipelineRunner::new(topo, store).await.unwrap();
+
+        let state = runner.run().await.unwrap();
+        assert!(matches!(state.status, PipelineStatus::Completed { .. }));
+
+        let stage_a = &state.stages[&StageId::new("stage-a")];
+        assert!(matches!(
+            stage_a.status,
+            StageStatus::SkippedByCondition { ref condition }
+                if condition == "pipeline.items_discovered == 0"
+        ));
+        assert_eq!(stage_a.items_processed, 0);
+
+        // stage-b ran, and still saw the item stage-a did not consume.
+        let stage_b = &state.stages[&StageId::new("stage-b")];
+        assert!(matches!(stage_b.status, StageStatus::Completed));
+        assert_eq!(stage_b.items_processed, 1);
     }
 
     #[tokio::test]
@@ -0,0 +1,753 @@
+//! Cron scheduler for pipelines with a `[schedule]` section.
+//!
+//! The `Scheduler` drives one task per scheduled pipeline. Each task
+//! sleeps until the next cron slot, runs the pipeline, and records every
+//! firing in that pipeline's `StateStore`. A pipeline never overlaps
+//! itself: slots that come due while a run is still active are recorded
+//! as `SkippedOverlap` and not run. On startup, slots missed since the
+//! last recorded firing are handled according to the schedule's
+//! `CatchUpPolicy`.
+//!
+//! Time comes from a `Clock`, so tests can drive the scheduler with a
+//! `ManualClock` instead of waiting on the wall clock.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::watch;
+
+use ecl_pipeline_spec::{CatchUpPolicy, CronExpr, ScheduleSpec};
+use ecl_pipeline_state::{FiringOutcome, PipelineState, ScheduleFiring, StateStore};
+
+use crate::error::{PipelineError, Result};
+
+/// Longest single sleep taken by `SystemClock`. Sleeping in bounded
+/// steps keeps the scheduler honest if the wall clock jumps.
+const MAX_SLEEP: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// A source of the current time that can also wait for a deadline.
+#[async_trait]
+pub trait Clock: Send + Sync {
+    /// The current time.
+    fn now(&self) -> DateTime<Utc>;
+
+    /// Wait until `now() >= deadline`. Returns immediately if the
+    /// deadline has already passed.
+    async fn sleep_until(&self, deadline: DateTime<Utc>);
+}
+
+/// The wall clock.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+#[async_trait]
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    async fn sleep_until(&self, deadline: DateTime<Utc>) {
+        // `to_std` fails for negative durations, i.e. a passed deadline.
+        while let Ok(remaining) = (deadline - Utc::now()).to_std() {
+            if remaining.is_zero() {
+                return;
+            }
+            tokio::time::sleep(remaining.min(MAX_SLEEP)).await;
+        }
+    }
+}
+
+/// A clock that only moves when told to. For tests.
+#[derive(Debug)]
+pub struct ManualClock {
+    now: watch::Sender<DateTime<Utc>>,
+}
+
+impl ManualClock {
+    /// Create a clock stopped at `start`.
+    pub fn new(start: DateTime<Utc>) -> Self {
+        let (now, _) = watch::channel(start);
+        Self { now }
+    }
+
+    /// Jump to `time`, waking any sleepers whose deadline has passed.
+    pub fn set(&self, time: DateTime<Utc>) {
+        self.now.send_replace(time);
+    }
+
+    /// Move forward by `delta`.
+    pub fn advance(&self, delta: chrono::Duration) {
+        self.now.send_modify(|now| *now += delta);
+    }
+
+    /// Number of tasks currently blocked in `sleep_until`.
+    pub fn sleepers(&self) -> usize {
+        self.now.receiver_count()
+    }
+}
+
+#[async_trait]
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.borrow()
+    }
+
+    async fn sleep_until(&self, deadline: DateTime<Utc>) {
+        let mut rx = self.now.subscribe();
+        // Only fails if the sender is dropped, which cannot happen while
+        // `self` is borrowed.
+        let _ = rx.wait_for(|now| *now >= deadline).await;
+    }
+}
+
+/// One execution of a scheduled pipeline.
+///
+/// The CLI implements this by resolving the pipeline's topology and
+/// driving a `PipelineRunner`; tests use a mock.
+#[async_trait]
+pub trait PipelineJob: Send + Sync {
+    /// Run the pipeline to completion.
+    async fn run(&self) -> Result<PipelineState>;
+}
+
+/// A pipeline registered with the scheduler.
+pub struct ScheduledPipeline {
+    /// Pipeline name (for logs and errors).
+    name: String,
+    /// The parsed cron expression.
+    cron: CronExpr,
+    /// What to do with slots missed while the scheduler was down.
+    catch_up: CatchUpPolicy,
+    /// Upper bound on missed slots considered at startup.
+    max_catch_up: usize,
+    /// Runs the pipeline.
+    job: Arc<dyn PipelineJob>,
+    /// Where firings are recorded.
+    store: Arc<dyn StateStore>,
+}
+
+impl std::fmt::Debug for ScheduledPipeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScheduledPipeline")
+            .field("name", &self.name)
+            .field("cron", &self.cron.as_str())
+            .field("catch_up", &self.catch_up)
+            .field("max_catch_up", &self.max_catch_up)
+            .field("job", &"<dyn PipelineJob>")
+            .field("store", &"<dyn StateStore>")
+            .finish()
+    }
+}
+
+impl ScheduledPipeline {
+    /// Register a pipeline with its schedule.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PipelineError::Schedule` if the cron expression is invalid.
+    pub fn new(
+        name: impl Into<String>,
+        schedule: &ScheduleSpec,
+        job: Arc<dyn PipelineJob>,
+        store: Arc<dyn StateStore>,
+    ) -> Result<Self> {
+        let name = name.into();
+        let cron = schedule.parse_cron().map_err(|e| PipelineError::Schedule {
+            pipeline: name.clone(),
+            message: e.to_string(),
+        })?;
+        Ok(Self {
+            name,
+            cron,
+            catch_up: schedule.catch_up,
+            max_catch_up: schedule.max_catch_up,
+            job,
+            store,
+        })
+    }
+
+    /// The pipeline name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The next slot after `after`, if the schedule fires again.
+    pub fn next_slot(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.cron.next_after(after)
+    }
+
+    /// Main loop: catch up, then fire each slot until shutdown.
+    async fn drive(&self, clock: &dyn Clock, mut shutdown: watch::Receiver<bool>) {
+        let mut cursor = self.catch_up(clock).await;
+        loop {
+            if *shutdown.borrow() {
+                break;
+            }
+            let Some(slot) = self.cron.next_after(cursor) else {
+                tracing::info!(pipeline = %self.name, "schedule has no future slots");
+                break;
+            };
+            tracing::debug!(pipeline = %self.name, %slot, "waiting for next slot");
+            tokio::select! {
+                _ = clock.sleep_until(slot) => {}
+                // Also resolves if the shutdown sender is dropped.
+                _ = shutdown.wait_for(|stop| *stop) => break,
+            }
+            cursor = self.fire(clock, slot).await;
+        }
+        tracing::info!(pipeline = %self.name, "scheduler stopped");
+    }
+
+    /// Handle slots missed since the last recorded firing. Returns the
+    /// cursor from which regular scheduling resumes.
+    async fn catch_up(&self, clock: &dyn Clock) -> DateTime<Utc> {
+        let now = clock.now();
+        let last = match self.store.load_schedule_firings(1).await {
+            Ok(firings) => firings.first().map(|f| f.scheduled_for),
+            Err(e) => {
+                tracing::warn!(
+                    pipeline = %self.name,
+                    error = %e,
+                    "failed to load schedule history; skipping catch-up"
+                );
+                None
+            }
+        };
+        // First start: nothing was missed.
+        let Some(last) = last else {
+            return now;
+        };
+
+        let missed = self.cron.occurrences_between(last, now, self.max_catch_up);
+        let replay: &[DateTime<Utc>] = match self.catch_up {
+            CatchUpPolicy::None => &[],
+            CatchUpPolicy::Latest => missed.last().map(std::slice::from_ref).unwrap_or(&[]),
+            CatchUpPolicy::All => &missed,
+        };
+        if !missed.is_empty() {
+            tracing::info!(
+                pipeline = %self.name,
+                missed = missed.len(),
+                replaying = replay.len(),
+                policy = ?self.catch_up,
+                "catching up missed schedule slots"
+            );
+        }
+
+        for slot in missed.iter().filter(|slot| !replay.contains(slot)) {
+            self.record(*slot, now, FiringOutcome::Missed).await;
+        }
+        let mut cursor = now;
+        for slot in replay {
+            cursor = cursor.max(self.fire(clock, *slot).await);
+        }
+        cursor
+    }
+
+    /// Run the pipeline for `slot`. Slots that come due while the run is
+    /// active are recorded as overlaps. Returns the latest slot handled.
+    async fn fire(&self, clock: &dyn Clock, slot: DateTime<Utc>) -> DateTime<Utc> {
+        let fired_at = clock.now();
+        tracing::info!(pipeline = %self.name, %slot, "schedule firing");
+        self.record(slot, fired_at, FiringOutcome::Running).await;
+
+        let mut handled = slot;
+        // Catch-up replays fire for past slots; only slots after the
+        // current time can overlap.
+        let mut next_slot = self.cron.next_after(slot.max(fired_at));
+        let job = self.job.run();
+        tokio::pin!(job);
+        let outcome = loop {
+            tokio::select! {
+                biased;
+                result = &mut job => break match result {
+                    Ok(state) => FiringOutcome::Completed { run_id: state.run_id },
+                    Err(e) => FiringOutcome::Failed { error: e.to_string() },
+                },
+                _ = sleep_until_slot(clock, next_slot) => {
+                    if let Some(overlap) = next_slot {
+                        tracing::warn!(
+                            pipeline = %self.name,
+                            slot = %overlap,
+                            "previous run still active; skipping slot"
+                        );
+                        self.record(overlap, clock.now(), FiringOutcome::SkippedOverlap)
+                            .await;
+                        handled = overlap;
+                        next_slot = self.cron.next_after(overlap);
+                    }
+                }
+            }
+        };
+
+        match &outcome {
+            FiringOutcome::Failed { error } => {
+                tracing::error!(pipeline = %self.name, %slot, %error, "scheduled run failed");
+            }
+            other => {
+                tracing::info!(pipeline = %self.name, %slot, outcome = %other, "scheduled run finished")
+            }
+        }
+        self.record(slot, fired_at, outcome).await;
+        handled
+    }
+
+    /// Persist a firing. Failures are logged, not fatal: a store hiccup
+    /// should not stop the daemon.
+    async fn record(
+        &self,
+        scheduled_for: DateTime<Utc>,
+        fired_at: DateTime<Utc>,
+        outcome: FiringOutcome,
+    ) {
+        let firing = ScheduleFiring {
+            scheduled_for,
+            fired_at,
+            outcome,
+        };
+        if let Err(e) = self.store.record_schedule_firing(&firing).await {
+            tracing::error!(
+                pipeline = %self.name,
+                error = %e,
+                "failed to record schedule firing"
+            );
+        }
+    }
+}
+
+/// Sleep until `slot`, or forever if there is none.
+async fn sleep_until_slot(clock: &dyn Clock, slot: Option<DateTime<Utc>>) {
+    match slot {
+        Some(slot) => clock.sleep_until(slot).await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Runs a set of scheduled pipelines until shutdown.
+pub struct Scheduler {
+    /// Time source shared by all pipelines.
+    clock: Arc<dyn Clock>,
+    /// Registered pipelines.
+    pipelines: Vec<ScheduledPipeline>,
+}
+
+impl std::fmt::Debug for Scheduler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Scheduler")
+            .field("clock", &"<dyn Clock>")
+            .field("pipelines", &self.pipelines)
+            .finish()
+    }
+}
+
+impl Scheduler {
+    /// Create an empty scheduler on the given clock.
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            clock,
+            pipelines: Vec::new(),
+        }
+    }
+
+    /// Register a pipeline.
+    pub fn add(&mut self, pipeline: ScheduledPipeline) {
+        self.pipelines.push(pipeline);
+    }
+
+    /// The registered pipelines.
+    pub fn pipelines(&self) -> &[ScheduledPipeline] {
+        &self.pipelines
+    }
+
+    /// Run every registered pipeline on its schedule until `shutdown`
+    /// becomes `true` (or its sender is dropped). In-flight runs are
+    /// allowed to finish before this returns.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PipelineError::JoinError` if a pipeline task panics.
+    pub async fn run(self, shutdown: watch::Receiver<bool>) -> Result<()> {
+        let mut tasks = tokio::task::JoinSet::new();
+        for pipeline in self.pipelines {
+            let clock = Arc::clone(&self.clock);
+            let shutdown = shutdown.clone();
+            tasks.spawn(async move { pipeline.drive(clock.as_ref(), shutdown).await });
+        }
+        while let Some(result) = tasks.join_next().await {
+            result?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use ecl_pipeline_state::{InMemoryStateStore, PipelineStats, PipelineStatus, RunId};
+    use std::collections::BTreeMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::sync::{Semaphore, mpsc};
+
+    fn at(h: u32, m: u32, s: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 13, h, m, s).unwrap()
+    }
+
+    /// A job that reports each start and waits for a permit before
+    /// finishing.
+    struct MockJob {
+        runs: AtomicUsize,
+        started: mpsc::UnboundedSender<usize>,
+        release: Arc<Semaphore>,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl PipelineJob for MockJob {
+        async fn run(&self) -> Result<PipelineState> {
+            let n = self.runs.fetch_add(1, Ordering::SeqCst) + 1;
+            let _ = self.started.send(n);
+            self.release.acquire().await.unwrap().forget();
+            if self.fail {
+                return Err(PipelineError::Lifecycle {
+                    message: "boom".to_string(),
+                });
+            }
+            Ok(PipelineState {
+                run_id: RunId::new(format!("run-{n}")),
+                pipeline_name: "nightly".to_string(),
+                started_at: Utc::now(),
+                last_checkpoint: Utc::now(),
+                status: PipelineStatus::Completed {
+                    finished_at: Utc::now(),
+                },
+                current_batch: 0,
+                sources: BTreeMap::new(),
+                stages: BTreeMap::new(),
+                stats: PipelineStats::default(),
+                triggered_by: None,
+            })
+        }
+    }
+
+    struct Harness {
+        clock: Arc<ManualClock>,
+        store: Arc<InMemoryStateStore>,
+        job: Arc<MockJob>,
+        started: mpsc::UnboundedReceiver<usize>,
+        shutdown: watch::Sender<bool>,
+        task: tokio::task::JoinHandle<Result<()>>,
+    }
+
+    struct Options {
+        start: DateTime<Utc>,
+        catch_up: CatchUpPolicy,
+        max_catch_up: usize,
+        permits: usize,
+        fail: bool,
+        history: Vec<ScheduleFiring>,
+    }
+
+    impl Default for Options {
+        fn default() -> Self {
+            Self {
+                start: at(10, 0, 30),
+                catch_up: CatchUpPolicy::None,
+                max_catch_up: 10,
+                permits: 1000,
+                fail: false,
+                history: Vec::new(),
+            }
+        }
+    }
+
+    async fn start(opts: Options) -> Harness {
+        let clock = Arc::new(ManualClock::new(opts.start));
+        let store = Arc::new(InMemoryStateStore::new());
+        for firing in &opts.history {
+            store.record_schedule_firing(firing).await.unwrap();
+        }
+        let (started_tx, started) = mpsc::unbounded_channel();
+        let job = Arc::new(MockJob {
+            runs: AtomicUsize::new(0),
+            started: started_tx,
+            release: Arc::new(Semaphore::new(opts.permits)),
+            fail: opts.fail,
+        });
+        let schedule = ScheduleSpec {
+            cron: "* * * * *".to_string(),
+            catch_up: opts.catch_up,
+            max_catch_up: opts.max_catch_up,
+        };
+        let pipeline =
+            ScheduledPipeline::new("nightly", &schedule, job.clone(), store.clone()).unwrap();
+        let mut scheduler = Scheduler::new(clock.clone());
+        scheduler.add(pipeline);
+        let (shutdown, rx) = watch::channel(false);
+        let task = tokio::spawn(scheduler.run(rx));
+        Harness {
+            clock,
+            store,
+            job,
+            started,
+            shutdown,
+            task,
+        }
+    }
+
+    /// Yield until `cond` holds; fail the test if it never does.
+    async fn settle(mut cond: impl FnMut() -> bool) {
+        for _ in 0..10_000 {
+            if cond() {
+                return;
+            }
+            tokio::task::yield_now().await;
+        }
+        unreachable!("condition never became true");
+    }
+
+    /// Yield until the firing for `slot` has the expected outcome.
+    async fn settle_firing(store: &InMemoryStateStore, slot: DateTime<Utc>, expected: &str) {
+        for _ in 0..10_000 {
+            let firings = store.load_schedule_firings(usize::MAX).await.unwrap();
+            if firings
+                .iter()
+                .any(|f| f.scheduled_for == slot && f.outcome.to_string().starts_with(expected))
+            {
+                return;
+            }
+            tokio::task::yield_now().await;
+        }
+        unreachable!("firing for {slot} never reached '{expected}'");
+    }
+
+    async fn stop(h: Harness) -> Vec<ScheduleFiring> {
+        h.shutdown.send(true).unwrap();
+        h.task.await.unwrap().unwrap();
+        h.store.load_schedule_firings(usize::MAX).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_manual_clock_sleep_wakes_on_set() {
+        let clock = Arc::new(ManualClock::new(at(10, 0, 0)));
+        let sleeper = {
+            let clock = clock.clone();
+            tokio::spawn(async move { clock.sleep_until(at(10, 1, 0)).await })
+        };
+        settle(|| clock.sleepers() == 1).await;
+        clock.advance(chrono::Duration::seconds(30));
+        tokio::task::yield_now().await;
+        assert!(!sleeper.is_finished());
+        clock.set(at(10, 1, 0));
+        sleeper.await.unwrap();
+        assert_eq!(clock.now(), at(10, 1, 0));
+    }
+
+    #[tokio::test]
+    async fn test_system_clock_past_deadline_returns_immediately() {
+        SystemClock
+            .sleep_until(Utc::now() - chrono::Duration::seconds(5))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_fires_each_slot_and_records_completion() {
+        let mut h = start(Options::default()).await;
+        settle(|| h.clock.sleepers() == 1).await;
+
+        h.clock.set(at(10, 1, 0));
+        assert_eq!(h.started.recv().await, Some(1));
+        settle_firing(&h.store, at(10, 1, 0), "Completed").await;
+
+        h.clock.set(at(10, 2, 0));
+        assert_eq!(h.started.recv().await, Some(2));
+        settle_firing(&h.store, at(10, 2, 0), "Completed").await;
+
+        let firings = stop(h).await;
+        assert_eq!(firings.len(), 2);
+        assert_eq!(firings[0].scheduled_for, at(10, 2, 0));
+        assert_eq!(
+            firings[0].outcome,
+            FiringOutcome::Completed {
+                run_id: RunId::new("run-2")
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_overlapping_slot_is_skipped_and_recorded() {
+        let mut h = start(Options {
+            permits: 0,
+            ..Options::default()
+        })
+        .await;
+        settle(|| h.clock.sleepers() == 1).await;
+
+        h.clock.set(at(10, 1, 0));
+        assert_eq!(h.started.recv().await, Some(1));
+        settle_firing(&h.store, at(10, 1, 0), "Running").await;
+
+        // The first run is still blocked when the next slot arrives.
+        settle(|| h.clock.sleepers() == 1).await;
+        h.clock.set(at(10, 2, 0));
+        settle_firing(&h.store, at(10, 2, 0), "Skipped").await;
+        assert_eq!(h.job.runs.load(Ordering::SeqCst), 1);
+
+        h.job.release.add_permits(1);
+        settle_firing(&h.store, at(10, 1, 0), "Completed").await;
+
+        h.clock.set(at(10, 3, 0));
+        assert_eq!(h.started.recv().await, Some(2));
+        h.job.release.add_permits(1);
+        settle_firing(&h.store, at(10, 3, 0), "Completed").await;
+
+        let firings = stop(h).await;
+        let outcomes: Vec<String> = firings.iter().map(|f| f.outcome.to_string()).collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                "Completed (run-2)",
+                "Skipped (previous run still active)",
+                "Completed (run-1)",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_run_is_recorded() {
+        let mut h = start(Options {
+            fail: true,
+            ..Options::default()
+        })
+        .await;
+        settle(|| h.clock.sleepers() == 1).await;
+        h.clock.set(at(10, 1, 0));
+        assert_eq!(h.started.recv().await, Some(1));
+        settle_firing(&h.store, at(10, 1, 0), "Failed").await;
+
+        let firings = stop(h).await;
+        assert!(
+            matches!(&firings[0].outcome, FiringOutcome::Failed { error } if error.contains("boom"))
+        );
+    }
+
+    fn history_at(slot: DateTime<Utc>) -> Vec<ScheduleFiring> {
+        vec![ScheduleFiring {
+            scheduled_for: slot,
+            fired_at: slot,
+            outcome: FiringOutcome::Completed {
+                run_id: RunId::new("run-0"),
+            },
+        }]
+    }
+
+    #[tokio::test]
+    async fn test_catch_up_none_records_missed_slots() {
+        let h = start(Options {
+            start: at(9, 5, 30),
+            catch_up: CatchUpPolicy::None,
+            history: history_at(at(9, 0, 0)),
+            ..Options::default()
+        })
+        .await;
+        settle_firing(&h.store, at(9, 5, 0), "Missed").await;
+        settle(|| h.clock.sleepers() == 1).await;
+
+        let job = h.job.clone();
+        let firings = stop(h).await;
+        assert_eq!(job.runs.load(Ordering::SeqCst), 0);
+        // Five missed slots plus the seeded history.
+        assert_eq!(firings.len(), 6);
+        assert!(
+            firings[..5]
+                .iter()
+                .all(|f| f.outcome == FiringOutcome::Missed)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_catch_up_latest_replays_most_recent_slot() {
+        let h = start(Options {
+            start: at(9, 5, 30),
+            catch_up: CatchUpPolicy::Latest,
+            history: history_at(at(9, 0, 0)),
+            ..Options::default()
+        })
+        .await;
+        settle_firing(&h.store, at(9, 5, 0), "Completed").await;
+
+        let job = h.job.clone();
+        let firings = stop(h).await;
+        assert_eq!(job.runs.load(Ordering::SeqCst), 1);
+        assert_eq!(firings[0].scheduled_for, at(9, 5, 0));
+        assert!(
+            firings[1..5]
+                .iter()
+                .all(|f| f.outcome == FiringOutcome::Missed)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_catch_up_all_replays_up_to_limit() {
+        let h = start(Options {
+            start: at(9, 5, 30),
+            catch_up: CatchUpPolicy::All,
+            max_catch_up: 3,
+            history: history_at(at(9, 0, 0)),
+            ..Options::default()
+        })
+        .await;
+        settle_firing(&h.store, at(9, 5, 0), "Completed").await;
+
+        let job = h.job.clone();
+        let firings = stop(h).await;
+        assert_eq!(job.runs.load(Ordering::SeqCst), 3);
+        let slots: Vec<DateTime<Utc>> = firings.iter().map(|f| f.scheduled_for).collect();
+        assert_eq!(
+            slots,
+            vec![at(9, 5, 0), at(9, 4, 0), at(9, 3, 0), at(9, 0, 0)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_first_start_does_not_catch_up() {
+        let h = start(Options {
+            catch_up: CatchUpPolicy::All,
+            ..Options::default()
+        })
+        .await;
+        settle(|| h.clock.sleepers() == 1).await;
+        let job = h.job.clone();
+        let firings = stop(h).await;
+        assert!(firings.is_empty());
+        assert_eq!(job.runs.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_scheduled_pipeline_rejects_invalid_cron() {
+        let (started, _) = mpsc::unbounded_channel();
+        let job = Arc::new(MockJob {
+            runs: AtomicUsize::new(0),
+            started,
+            release: Arc::new(Semaphore::new(0)),
+            fail: false,
+        });
+        let schedule = ScheduleSpec {
+            cron: "not a cron".to_string(),
+            catch_up: CatchUpPolicy::None,
+            max_catch_up: 10,
+        };
+        let err = ScheduledPipeline::new(
+            "nightly",
+            &schedule,
+            job,
+            Arc::new(InMemoryStateStore::new()),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(err, PipelineError::Schedule { ref pipeline, .. } if pipeline == "nightly")
+        );
+    }
+}
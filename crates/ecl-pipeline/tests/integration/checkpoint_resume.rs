@@ -269,6 +269,7 @@ async fn test_resume_from_checkpoint_completes() {
                 total_items_skipped_unchanged: 0,
                 total_items_failed: 0,
             },
+            triggered_by: None,
         },
     };
     store.save_checkpoint(&checkpoint).await.unwrap();
@@ -309,6 +310,7 @@ async fn test_config_drift_detected_on_resume() {
             sources: BTreeMap::new(),
             stages: BTreeMap::new(),
             stats: PipelineStats::default(),
+            triggered_by: None,
         },
     };
     store.save_checkpoint(&checkpoint).await.unwrap();
@@ -378,6 +380,7 @@ async fn test_processing_items_reset_to_pending_on_resume() {
             sources,
             stages: BTreeMap::new(),
             stats: PipelineStats::default(),
+            triggered_by: None,
         },
     };
     store.save_checkpoint(&checkpoint).await.unwrap();
@@ -0,0 +1,248 @@
+//! OpenPGP encryption of delivered files.
+//!
+//! Recipient public keys are named secrets, resolved once through the
+//! pipeline's `SecretResolver` on first use. Each recipient's first
+//! encryption-capable subkey is used, falling back to the primary key.
+
+use std::sync::Arc;
+
+use pgp::composed::{ArmorOptions, Deserializable, MessageBuilder, SignedPublicKey};
+use pgp::crypto::sym::SymmetricKeyAlgorithm;
+use pgp::types::KeyDetails;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use tokio::sync::OnceCell;
+use tracing::debug;
+
+use ecl_pipeline_topo::error::StageError;
+use ecl_secrets::{SecretError, SecretResolver};
+
+use crate::permanent;
+
+/// OpenPGP encryption settings for delivered files.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct EncryptConfig {
+    /// Secrets holding recipient public keys (armored or binary). Every
+    /// recipient can decrypt the file.
+    pub recipient_key_secrets: Vec<String>,
+    /// Write ASCII-armored output (`.asc`) instead of binary (`.pgp`).
+    /// Default: false.
+    #[serde(default)]
+    pub armor: bool,
+}
+
+/// Encrypts content to the configured recipients.
+pub(crate) struct Encryptor {
+    stage: &'static str,
+    config: EncryptConfig,
+    resolver: Arc<dyn SecretResolver>,
+    recipients: OnceCell<Vec<SignedPublicKey>>,
+}
+
+impl std::fmt::Debug for Encryptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Encryptor")
+            .field("config", &self.config)
+            .field("resolver", &self.resolver)
+            .field("keys_loaded", &self.recipients.initialized())
+            .finish()
+    }
+}
+
+impl Encryptor {
+    pub(crate) fn new(
+        stage: &'static str,
+        config: EncryptConfig,
+        resolver: Arc<dyn SecretResolver>,
+    ) -> Self {
+        Self {
+            stage,
+            config,
+            resolver,
+            recipients: OnceCell::new(),
+        }
+    }
+
+    /// File name suffix for encrypted output.
+    pub(crate) fn suffix(&self) -> &'static str {
+        if self.config.armor { ".asc" } else { ".pgp" }
+    }
+
+    /// Encrypt `content` (stored under `name` in the literal packet).
+    pub(crate) async fn encrypt(
+        &self,
+        name: &str,
+        content: Vec<u8>,
+        item_id: &str,
+    ) -> Result<Vec<u8>, StageError> {
+        let recipients = self
+            .recipients
+            .get_or_try_init(|| self.load_keys(item_id))
+            .await?;
+
+        let mut builder = MessageBuilder::from_bytes(name.to_string(), content)
+            .seipd_v1(rand::thread_rng(), SymmetricKeyAlgorithm::AES256);
+        for key in recipients {
+            let result = match key
+                .public_subkeys
+                .iter()
+                .find(|sub| sub.algorithm().can_encrypt())
+            {
+                Some(subkey) => builder.encrypt_to_key(rand::thread_rng(), subkey),
+                None => builder.encrypt_to_key(rand::thread_rng(), &key.primary_key),
+            };
+            result.map_err(|e| {
+                permanent(
+                    self.stage,
+                    item_id,
+                    format!("cannot encrypt to {}: {e}", key.fingerprint()),
+                )
+            })?;
+        }
+
+        let encrypted = if self.config.armor {
+            builder
+                .to_armored_string(rand::thread_rng(), ArmorOptions::default())
+                .map(String::into_bytes)
+        } else {
+            builder.to_vec(rand::thread_rng())
+        };
+        encrypted.map_err(|e| permanent(self.stage, item_id, format!("encryption failed: {e}")))
+    }
+
+    /// Resolve and parse the recipient keys.
+    async fn load_keys(&self, item_id: &str) -> Result<Vec<SignedPublicKey>, StageError> {
+        if self.config.recipient_key_secrets.is_empty() {
+            return Err(permanent(
+                self.stage,
+                item_id,
+                "encrypt requires at least one recipient_key_secret".to_string(),
+            ));
+        }
+        let mut keys = Vec::with_capacity(self.config.recipient_key_secrets.len());
+        for name in &self.config.recipient_key_secrets {
+            let bytes = self.resolver.resolve(name).await.map_err(|e| match e {
+                SecretError::Provider { .. } => StageError::Transient {
+                    stage: self.stage.into(),
+                    item_id: item_id.to_string(),
+                    message: e.to_string(),
+                },
+                _ => permanent(self.stage, item_id, e.to_string()),
+            })?;
+            let (key, _) = SignedPublicKey::from_reader_single(bytes.as_slice()).map_err(|e| {
+                permanent(
+                    self.stage,
+                    item_id,
+                    format!("invalid public key '{name}': {e}"),
+                )
+            })?;
+            keys.push(key);
+        }
+        debug!(recipients = keys.len(), "loaded PGP recipient keys");
+        Ok(keys)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::tests::MockResolver;
+    use ecl_stages::test_keys::keygen;
+    use pgp::composed::{Message, SignedSecretKey};
+    use pgp::ser::Serialize as _;
+    use pgp::types::Password;
+
+    /// Resolver holding each key's public half under `name`.
+    pub(crate) fn public_keys(keys: &[(&str, &SignedSecretKey)]) -> Arc<dyn SecretResolver> {
+        Arc::new(MockResolver(
+            keys.iter()
+                .map(|(name, key)| (name.to_string(), key.to_public_key().to_bytes().unwrap()))
+                .collect(),
+        ))
+    }
+
+    pub(crate) fn decrypt(content: &[u8], key: &SignedSecretKey) -> Vec<u8> {
+        let message = if content.starts_with(b"-----BEGIN PGP") {
+            Message::from_armor(content).unwrap().0
+        } else {
+            Message::from_bytes(content).unwrap()
+        };
+        let mut message = message.decrypt(&Password::empty(), key).unwrap();
+        message.as_data_vec().unwrap()
+    }
+
+    fn encryptor(names: &[&str], armor: bool, resolver: Arc<dyn SecretResolver>) -> Encryptor {
+        Encryptor::new(
+            "file_sink",
+            EncryptConfig {
+                recipient_key_secrets: names.iter().map(|n| n.to_string()).collect(),
+                armor,
+            },
+            resolver,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_encrypt_to_every_recipient() {
+        let ours = keygen("Us <us@example.com>", None);
+        let partner = keygen("Partner <partner@example.com>", None);
+        let encryptor = encryptor(
+            &["ours", "partner"],
+            false,
+            public_keys(&[("ours", &ours), ("partner", &partner)]),
+        );
+        assert_eq!(encryptor.suffix(), ".pgp");
+
+        let encrypted = encryptor
+            .encrypt("a.csv", b"id\n1\n".to_vec(), "a")
+            .await
+            .unwrap();
+        assert_eq!(decrypt(&encrypted, &ours), b"id\n1\n");
+        assert_eq!(decrypt(&encrypted, &partner), b"id\n1\n");
+    }
+
+    #[tokio::test]
+    async fn test_encrypt_armored() {
+        let partner = keygen("Partner <partner@example.com>", None);
+        let encryptor = encryptor(&["partner"], true, public_keys(&[("partner", &partner)]));
+        assert_eq!(encryptor.suffix(), ".asc");
+
+        let encrypted = encryptor
+            .encrypt("a.csv", b"hello".to_vec(), "a")
+            .await
+            .unwrap();
+        assert!(encrypted.starts_with(b"-----BEGIN PGP MESSAGE-----"));
+        assert_eq!(decrypt(&encrypted, &partner), b"hello");
+    }
+
+    #[tokio::test]
+    async fn test_encrypt_missing_or_invalid_key() {
+        let encryptor = encryptor(&["partner"], false, public_keys(&[]));
+        let err = encryptor
+            .encrypt("a.csv", b"x".to_vec(), "a")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("secret not found: partner"));
+
+        let resolver = Arc::new(MockResolver(
+            [("partner".to_string(), b"not a key".to_vec())].into(),
+        ));
+        let encryptor = self::encryptor(&["partner"], false, resolver);
+        let err = encryptor
+            .encrypt("a.csv", b"x".to_vec(), "a")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("invalid public key 'partner'"));
+    }
+
+    #[tokio::test]
+    async fn test_encrypt_requires_recipients() {
+        let encryptor = encryptor(&[], false, public_keys(&[]));
+        let err = encryptor
+            .encrypt("a.csv", b"x".to_vec(), "a")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, StageError::Permanent { .. }));
+    }
+}
@@ -0,0 +1,413 @@
+//! File delivery sinks for the ECL pipeline runner.
+//!
+//! Two stages deliver item content as files:
+//!
+//! - [`FileSinkStage`] (`file_sink`) writes to a local directory.
+//! - [`SftpSinkStage`] (`sftp_sink`) uploads to an SFTP server, using the
+//!   same connection settings as an SFTP source.
+//!
+//! Both share [`DeliveryConfig`]: one file per item, or one file assembled
+//! from every item in the batch; optional OpenPGP encryption to one or more
+//! recipients; and writes through a temporary name followed by a rename, so
+//! a partner polling the directory never sees a partial file.
+//!
+//! Items pass through unchanged apart from a `delivered_path` metadata key,
+//! so downstream stages (if any) still receive them.
+
+#![forbid(unsafe_code)]
+#![warn(missing_docs)]
+#![deny(clippy::unwrap_used)]
+#![warn(clippy::expect_used)]
+#![deny(clippy::panic)]
+
+mod encrypt;
+pub mod local;
+pub mod sftp;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use tracing::debug;
+
+use ecl_pipeline_topo::error::StageError;
+use ecl_pipeline_topo::{PipelineItem, StageContext};
+
+pub use encrypt::EncryptConfig;
+pub use local::{FileSinkConfig, FileSinkStage};
+pub use sftp::{SftpSinkConfig, SftpSinkStage};
+
+use encrypt::Encryptor;
+
+/// How items map to output files.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryMode {
+    /// One file per item (default).
+    #[default]
+    Item,
+    /// One file holding every item's content, in order. Makes the stage a
+    /// batch stage.
+    Batch,
+}
+
+/// Where and how delivered files are written. Flattened into each sink's
+/// config.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct DeliveryConfig {
+    /// Target directory. `{date}` expands to the UTC date (`YYYY-MM-DD`).
+    pub path: String,
+    /// File name template. Placeholders: `{display_name}`, `{item_id}`,
+    /// `{stream}`, `{date}` and `{timestamp}` (`YYYYMMDDTHHMMSSZ`).
+    /// Default: `{display_name}`. Required in batch mode, where only
+    /// `{date}` and `{timestamp}` are allowed.
+    #[serde(default)]
+    pub file_name: Option<String>,
+    /// `"item"` (default) or `"batch"`.
+    #[serde(default)]
+    pub mode: DeliveryMode,
+    /// Inserted between items' content in batch mode. Default: none.
+    #[serde(default)]
+    pub separator: String,
+    /// Write a file even when a batch is empty. Default: false.
+    #[serde(default)]
+    pub write_empty: bool,
+    /// Suffix of the temporary name written before the final rename.
+    /// Default: `".part"`.
+    #[serde(default = "default_temp_suffix")]
+    pub temp_suffix: String,
+    /// Encrypt files to OpenPGP recipients. Adds `.pgp` (or `.asc` when
+    /// armored) to the file name.
+    #[serde(default)]
+    pub encrypt: Option<EncryptConfig>,
+}
+
+fn default_temp_suffix() -> String {
+    ".part".to_string()
+}
+
+/// A destination that can write a file atomically.
+#[async_trait]
+pub(crate) trait Target: Send + Sync {
+    /// Write `content` to `dir/name` through `dir/name{temp_suffix}`,
+    /// creating `dir` if needed. Returns the final path.
+    async fn write_atomic(
+        &self,
+        ctx: &StageContext,
+        dir: &str,
+        name: &str,
+        temp_suffix: &str,
+        content: Vec<u8>,
+        item_id: &str,
+    ) -> Result<String, StageError>;
+}
+
+/// Shared delivery logic: file naming, batching and encryption.
+#[derive(Debug)]
+pub(crate) struct Delivery {
+    stage: &'static str,
+    config: DeliveryConfig,
+    encryptor: Option<Encryptor>,
+}
+
+impl Delivery {
+    /// Validate `config` and set up encryption.
+    pub(crate) fn new(
+        stage: &'static str,
+        config: DeliveryConfig,
+        secrets: std::sync::Arc<dyn ecl_secrets::SecretResolver>,
+    ) -> Result<Self, StageError> {
+        if config.mode == DeliveryMode::Batch {
+            match &config.file_name {
+                None => {
+                    return Err(permanent(
+                        stage,
+                        "",
+                        "batch mode requires a file_name".to_string(),
+                    ));
+                }
+                Some(name) if ITEM_PLACEHOLDERS.iter().any(|p| name.contains(p)) => {
+                    return Err(permanent(
+                        stage,
+                        "",
+                        format!("batch file_name '{name}' cannot use per-item placeholders"),
+                    ));
+                }
+                Some(_) => {}
+            }
+        }
+        let encryptor = config
+            .encrypt
+            .clone()
+            .map(|encrypt| Encryptor::new(stage, encrypt, secrets));
+        Ok(Self {
+            stage,
+            config,
+            encryptor,
+        })
+    }
+
+    /// Whether the owning stage must see every item at once.
+    pub(crate) fn requires_batch(&self) -> bool {
+        self.config.mode == DeliveryMode::Batch
+    }
+
+    /// Write `items` to `target` according to the configured mode, and pass
+    /// them through with `delivered_path` set.
+    pub(crate) async fn deliver(
+        &self,
+        target: &dyn Target,
+        ctx: &StageContext,
+        items: Vec<PipelineItem>,
+    ) -> Result<Vec<PipelineItem>, StageError> {
+        let now = Utc::now();
+        let dir = self.config.path.replace("{date}", &date(now));
+
+        match self.config.mode {
+            DeliveryMode::Item => {
+                let mut delivered = Vec::with_capacity(items.len());
+                for mut item in items {
+                    let template = self.config.file_name.as_deref().unwrap_or("{display_name}");
+                    let name = render(template, Some(&item), now);
+                    let path = self
+                        .write(target, ctx, &dir, name, item.content.to_vec(), &item.id)
+                        .await?;
+                    item.metadata
+                        .insert("delivered_path".to_string(), serde_json::json!(path));
+                    delivered.push(item);
+                }
+                Ok(delivered)
+            }
+            DeliveryMode::Batch => {
+                if items.is_empty() && !self.config.write_empty {
+                    debug!(stage = self.stage, "empty batch; nothing delivered");
+                    return Ok(items);
+                }
+                let template = self.config.file_name.as_deref().unwrap_or_default();
+                let name = render(template, None, now);
+                let content = self.assemble(&items);
+                let path = self.write(target, ctx, &dir, name, content, "").await?;
+                Ok(items
+                    .into_iter()
+                    .map(|mut item| {
+                        item.metadata
+                            .insert("delivered_path".to_string(), serde_json::json!(path));
+                        item
+                    })
+                    .collect())
+            }
+        }
+    }
+
+    /// Concatenate items' content with the configured separator.
+    fn assemble(&self, items: &[PipelineItem]) -> Vec<u8> {
+        let mut content = Vec::new();
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                content.extend_from_slice(self.config.separator.as_bytes());
+            }
+            content.extend_from_slice(&item.content);
+        }
+        content
+    }
+
+    /// Encrypt if configured, then write through `target`.
+    async fn write(
+        &self,
+        target: &dyn Target,
+        ctx: &StageContext,
+        dir: &str,
+        mut name: String,
+        mut content: Vec<u8>,
+        item_id: &str,
+    ) -> Result<String, StageError> {
+        if let Some(encryptor) = &self.encryptor {
+            content = encryptor.encrypt(&name, content, item_id).await?;
+            name.push_str(encryptor.suffix());
+        }
+        let size = content.len();
+        let path = target
+            .write_atomic(ctx, dir, &name, &self.config.temp_suffix, content, item_id)
+            .await?;
+        debug!(stage = self.stage, path = %path, size, "delivered file");
+        Ok(path)
+    }
+}
+
+/// Placeholders that need an item and so are rejected in batch mode.
+const ITEM_PLACEHOLDERS: [&str; 3] = ["{display_name}", "{item_id}", "{stream}"];
+
+/// Expand a file name template. Item placeholders are left as-is when
+/// there is no item (batch mode rejects them up front).
+fn render(template: &str, item: Option<&PipelineItem>, now: DateTime<Utc>) -> String {
+    let mut name = template
+        .replace("{date}", &date(now))
+        .replace("{timestamp}", &now.format("%Y%m%dT%H%M%SZ").to_string());
+    if let Some(item) = item {
+        name = name
+            .replace("{display_name}", &item.display_name)
+            .replace("{item_id}", &item.id)
+            .replace("{stream}", item.stream.as_deref().unwrap_or("default"));
+    }
+    name
+}
+
+fn date(now: DateTime<Utc>) -> String {
+    now.format("%Y-%m-%d").to_string()
+}
+
+pub(crate) fn permanent(stage: &str, item_id: &str, message: String) -> StageError {
+    StageError::Permanent {
+        stage: stage.to_string(),
+        item_id: item_id.to_string(),
+        message,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub(crate) mod tests {
+    use super::*;
+    use ecl_pipeline_spec::PipelineSpec;
+    use ecl_pipeline_state::{Blake3Hash, ItemProvenance};
+    use ecl_secrets::{SecretError, SecretResolver};
+    use serde_json::json;
+    use std::collections::{BTreeMap, HashMap};
+    use std::sync::Arc;
+
+    #[derive(Debug, Default)]
+    pub(crate) struct MockResolver(pub(crate) HashMap<String, Vec<u8>>);
+
+    #[async_trait]
+    impl SecretResolver for MockResolver {
+        async fn resolve(&self, name: &str) -> Result<Vec<u8>, SecretError> {
+            self.0
+                .get(name)
+                .cloned()
+                .ok_or_else(|| SecretError::NotFound {
+                    name: name.to_string(),
+                })
+        }
+    }
+
+    pub(crate) fn no_secrets() -> Arc<dyn SecretResolver> {
+        Arc::new(MockResolver::default())
+    }
+
+    pub(crate) fn make_item(id: &str, content: &str) -> PipelineItem {
+        PipelineItem {
+            id: id.to_string(),
+            display_name: id.to_string(),
+            content: Arc::from(content.as_bytes()),
+            mime_type: "text/csv".to_string(),
+            source_name: "test".to_string(),
+            source_content_hash: Blake3Hash::new("test"),
+            provenance: ItemProvenance {
+                source_kind: "test".to_string(),
+                metadata: BTreeMap::new(),
+                source_modified: None,
+                extracted_at: Utc::now(),
+            },
+            metadata: BTreeMap::new(),
+            record: None,
+            stream: Some("daily".to_string()),
+        }
+    }
+
+    pub(crate) fn ctx(output_dir: &std::path::Path) -> StageContext {
+        let spec = PipelineSpec::from_toml(
+            r#"
+            name = "test"
+            version = 1
+            output_dir = "/tmp/ecl-test"
+            [sources.test]
+            kind = "filesystem"
+            root = "/tmp"
+            [stages.emit]
+            adapter = "emit"
+            resources = { reads = ["raw"] }
+            "#,
+        )
+        .unwrap();
+
+        StageContext {
+            spec: Arc::new(spec),
+            output_dir: output_dir.to_path_buf(),
+            params: json!({}),
+            span: tracing::Span::none(),
+        }
+    }
+
+    fn config(params: serde_json::Value) -> DeliveryConfig {
+        serde_json::from_value(params).unwrap()
+    }
+
+    #[test]
+    fn test_delivery_config_defaults() {
+        let config = config(json!({ "path": "out" }));
+        assert_eq!(config.mode, DeliveryMode::Item);
+        assert!(config.file_name.is_none());
+        assert_eq!(config.temp_suffix, ".part");
+        assert!(config.separator.is_empty());
+        assert!(!config.write_empty);
+        assert!(config.encrypt.is_none());
+    }
+
+    #[test]
+    fn test_render_placeholders() {
+        let now = DateTime::parse_from_rfc3339("2026-03-15T08:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let item = make_item("row-1", "");
+        assert_eq!(
+            render("{stream}_{display_name}_{date}.csv", Some(&item), now),
+            "daily_row-1_2026-03-15.csv"
+        );
+        assert_eq!(
+            render("report_{timestamp}.csv", None, now),
+            "report_20260315T083000Z.csv"
+        );
+    }
+
+    #[test]
+    fn test_batch_mode_requires_item_free_file_name() {
+        let result = Delivery::new(
+            "file_sink",
+            config(json!({ "path": "out", "mode": "batch" })),
+            no_secrets(),
+        );
+        assert!(matches!(result, Err(StageError::Permanent { .. })));
+
+        let result = Delivery::new(
+            "file_sink",
+            config(json!({ "path": "out", "mode": "batch", "file_name": "{item_id}.csv" })),
+            no_secrets(),
+        );
+        assert!(matches!(result, Err(StageError::Permanent { .. })));
+
+        let delivery = Delivery::new(
+            "file_sink",
+            config(json!({ "path": "out", "mode": "batch", "file_name": "all_{date}.csv" })),
+            no_secrets(),
+        )
+        .unwrap();
+        assert!(delivery.requires_batch());
+    }
+
+    #[test]
+    fn test_assemble_joins_with_separator() {
+        let delivery = Delivery::new(
+            "file_sink",
+            config(json!({
+                "path": "out",
+                "mode": "batch",
+                "file_name": "all.txt",
+                "separator": "\n"
+            })),
+            no_secrets(),
+        )
+        .unwrap();
+        let items = vec![make_item("a", "one"), make_item("b", "two")];
+        assert_eq!(delivery.assemble(&items), b"one\ntwo");
+    }
+}
@@ -0,0 +1,261 @@
+//! Local filesystem sink (`file_sink`).
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use ecl_pipeline_topo::error::StageError;
+use ecl_pipeline_topo::{PipelineItem, Stage, StageContext};
+use ecl_secrets::SecretResolver;
+
+use crate::{Delivery, DeliveryConfig, Target};
+
+/// Configuration for the local filesystem sink, parsed from stage params.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct FileSinkConfig {
+    /// Delivery settings. A relative `path` is resolved against the
+    /// pipeline's output directory.
+    #[serde(flatten)]
+    pub delivery: DeliveryConfig,
+}
+
+/// Sink stage that writes item content to files in a local directory.
+#[derive(Debug)]
+pub struct FileSinkStage {
+    delivery: Delivery,
+}
+
+impl FileSinkStage {
+    /// Create a file sink from JSON params, resolving encryption keys
+    /// through `resolver`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StageError::Permanent` if params cannot be deserialized or
+    /// the delivery settings are inconsistent.
+    pub fn from_params(
+        params: &serde_json::Value,
+        resolver: Arc<dyn SecretResolver>,
+    ) -> Result<Self, StageError> {
+        let config: FileSinkConfig =
+            serde_json::from_value(params.clone()).map_err(|e| StageError::Permanent {
+                stage: "file_sink".to_string(),
+                item_id: String::new(),
+                message: format!("invalid file_sink config: {e}"),
+            })?;
+
+        Ok(Self {
+            delivery: Delivery::new("file_sink", config.delivery, resolver)?,
+        })
+    }
+}
+
+/// Writes into the local filesystem.
+struct LocalTarget;
+
+#[async_trait]
+impl Target for LocalTarget {
+    async fn write_atomic(
+        &self,
+        ctx: &StageContext,
+        dir: &str,
+        name: &str,
+        temp_suffix: &str,
+        content: Vec<u8>,
+        item_id: &str,
+    ) -> Result<String, StageError> {
+        let dir: PathBuf = if Path::new(dir).is_absolute() {
+            PathBuf::from(dir)
+        } else {
+            ctx.output_dir.join(dir)
+        };
+        let path = dir.join(name);
+        let temp = dir.join(format!("{name}{temp_suffix}"));
+        let io_error = |action: &str, path: &Path, e: std::io::Error| StageError::Transient {
+            stage: "file_sink".to_string(),
+            item_id: item_id.to_string(),
+            message: format!("failed to {action} {}: {e}", path.display()),
+        };
+
+        // The name may contain subdirectories (e.g. `{display_name}`).
+        let parent = path.parent().unwrap_or(&dir);
+        tokio::fs::create_dir_all(parent)
+            .await
+            .map_err(|e| io_error("create directory", parent, e))?;
+        tokio::fs::write(&temp, content)
+            .await
+            .map_err(|e| io_error("write", &temp, e))?;
+        tokio::fs::rename(&temp, &path)
+            .await
+            .map_err(|e| io_error("rename", &temp, e))?;
+
+        Ok(path.display().to_string())
+    }
+}
+
+#[async_trait]
+impl Stage for FileSinkStage {
+    fn name(&self) -> &str {
+        "file_sink"
+    }
+
+    fn requires_batch(&self) -> bool {
+        self.delivery.requires_batch()
+    }
+
+    async fn process(
+        &self,
+        item: PipelineItem,
+        ctx: &StageContext,
+    ) -> Result<Vec<PipelineItem>, StageError> {
+        self.delivery.deliver(&LocalTarget, ctx, vec![item]).await
+    }
+
+    async fn process_batch(
+        &self,
+        items: Vec<PipelineItem>,
+        ctx: &StageContext,
+    ) -> Result<Vec<PipelineItem>, StageError> {
+        self.delivery.deliver(&LocalTarget, ctx, items).await
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::encrypt::tests::{decrypt, public_keys};
+    use crate::tests::{ctx, make_item, no_secrets};
+    use ecl_stages::test_keys::keygen;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_file_sink_writes_one_file_per_item() {
+        let dir = tempfile::tempdir().unwrap();
+        let stage = FileSinkStage::from_params(
+            &json!({ "path": "deliveries", "file_name": "{stream}_{item_id}.csv" }),
+            no_secrets(),
+        )
+        .unwrap();
+        assert!(!stage.requires_batch());
+
+        let result = stage
+            .process(make_item("a", "id\n1\n"), &ctx(dir.path()))
+            .await
+            .unwrap();
+        let expected = dir.path().join("deliveries/daily_a.csv");
+        assert_eq!(std::fs::read_to_string(&expected).unwrap(), "id\n1\n");
+        assert_eq!(
+            result[0].metadata["delivered_path"],
+            json!(expected.display().to_string())
+        );
+        // No temporary file is left behind.
+        assert_eq!(
+            std::fs::read_dir(dir.path().join("deliveries"))
+                .unwrap()
+                .count(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_file_sink_absolute_path_and_overwrite() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("abs");
+        let stage =
+            FileSinkStage::from_params(&json!({ "path": out.display().to_string() }), no_secrets())
+                .unwrap();
+        let ctx = ctx(&dir.path().join("unused"));
+
+        stage
+            .process(make_item("a.csv", "old"), &ctx)
+            .await
+            .unwrap();
+        stage
+            .process(make_item("a.csv", "new"), &ctx)
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read_to_string(out.join("a.csv")).unwrap(), "new");
+        assert!(!dir.path().join("unused").exists());
+    }
+
+    #[tokio::test]
+    async fn test_file_sink_batch_mode() {
+        let dir = tempfile::tempdir().unwrap();
+        let stage = FileSinkStage::from_params(
+            &json!({
+                "path": "out",
+                "mode": "batch",
+                "file_name": "all.txt",
+                "separator": "\n"
+            }),
+            no_secrets(),
+        )
+        .unwrap();
+        assert!(stage.requires_batch());
+
+        let items = vec![make_item("a", "one"), make_item("b", "two")];
+        let result = stage.process_batch(items, &ctx(dir.path())).await.unwrap();
+        assert_eq!(result.len(), 2);
+        let path = dir.path().join("out/all.txt");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "one\ntwo");
+        assert_eq!(
+            result[1].metadata["delivered_path"],
+            json!(path.display().to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_file_sink_empty_batch() {
+        let dir = tempfile::tempdir().unwrap();
+        let params = json!({ "path": "out", "mode": "batch", "file_name": "all.txt" });
+        let stage = FileSinkStage::from_params(&params, no_secrets()).unwrap();
+        stage
+            .process_batch(Vec::new(), &ctx(dir.path()))
+            .await
+            .unwrap();
+        assert!(!dir.path().join("out/all.txt").exists());
+
+        let mut params = params;
+        params["write_empty"] = json!(true);
+        let stage = FileSinkStage::from_params(&params, no_secrets()).unwrap();
+        stage
+            .process_batch(Vec::new(), &ctx(dir.path()))
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read(dir.path().join("out/all.txt")).unwrap(), b"");
+    }
+
+    #[tokio::test]
+    async fn test_file_sink_encrypts() {
+        let dir = tempfile::tempdir().unwrap();
+        let partner = keygen("Partner <partner@example.com>", None);
+        let stage = FileSinkStage::from_params(
+            &json!({ "path": "out", "encrypt": { "recipient_key_secrets": ["partner"] } }),
+            public_keys(&[("partner", &partner)]),
+        )
+        .unwrap();
+
+        let result = stage
+            .process(make_item("a.csv", "secret"), &ctx(dir.path()))
+            .await
+            .unwrap();
+        let path = dir.path().join("out/a.csv.pgp");
+        assert_eq!(
+            result[0].metadata["delivered_path"],
+            json!(path.display().to_string())
+        );
+        // The item itself passes through unencrypted.
+        assert_eq!(result[0].content.as_ref(), b"secret");
+        assert_eq!(decrypt(&std::fs::read(path).unwrap(), &partner), b"secret");
+    }
+
+    #[test]
+    fn test_file_sink_requires_path() {
+        let err = FileSinkStage::from_params(&json!({}), no_secrets()).unwrap_err();
+        assert!(err.to_string().contains("path"));
+    }
+}
@@ -0,0 +1,481 @@
+//! SFTP sink (`sftp_sink`).
+//!
+//! Uploads through the same pooled, reconnecting connection the SFTP source
+//! uses. Files are written under a temporary name and renamed into place.
+//! Plain SFTP rename does not overwrite, so when the server advertises
+//! `posix-rename@openssh.com` that is used to replace the target atomically.
+//! Otherwise an existing target is first moved to a backup name, the new
+//! file renamed over, and the backup deleted; the target path is briefly
+//! absent but an old or new copy always survives a failure.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use russh_sftp::client::SftpSession;
+use russh_sftp::client::error::Error as SftpError;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::OnceCell;
+use tracing::warn;
+
+use ecl_adapter_sftp::{SftpConnection, create_dir_all, join_remote};
+use ecl_pipeline_spec::CredentialRef;
+use ecl_pipeline_topo::error::{SourceError, StageError};
+use ecl_pipeline_topo::{PipelineItem, Stage, StageContext};
+use ecl_secrets::SecretResolver;
+
+use crate::{Delivery, DeliveryConfig, Target};
+
+/// Configuration for the SFTP sink, parsed from stage params.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SftpSinkConfig {
+    /// SFTP server hostname.
+    pub host: String,
+    /// SFTP server port. Default: 22.
+    #[serde(default = "default_ssh_port")]
+    pub port: u16,
+    /// SSH username.
+    pub username: String,
+    /// Credential reference (private key or password).
+    #[schemars(with = "serde_json::Value")]
+    pub credentials: CredentialRef,
+    /// SSH keepalive interval in seconds (0 = off). Default: 30.
+    #[serde(default = "default_keepalive_secs")]
+    pub keepalive_secs: u64,
+    /// Delivery settings. `path` is a remote directory.
+    #[serde(flatten)]
+    pub delivery: DeliveryConfig,
+}
+
+fn default_ssh_port() -> u16 {
+    22
+}
+
+fn default_keepalive_secs() -> u64 {
+    30
+}
+
+/// Sink stage that uploads item content to an SFTP server.
+pub struct SftpSinkStage {
+    config: SftpSinkConfig,
+    delivery: Delivery,
+    resolver: Arc<dyn SecretResolver>,
+    connection: OnceCell<SftpConnection>,
+}
+
+impl std::fmt::Debug for SftpSinkStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SftpSinkStage")
+            .field("config", &self.config)
+            .field("delivery", &self.delivery)
+            .field("connected", &self.connection.initialized())
+            .finish_non_exhaustive()
+    }
+}
+
+impl SftpSinkStage {
+    /// Create an SFTP sink from JSON params, resolving credentials and
+    /// encryption keys through `resolver`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StageError::Permanent` if params cannot be deserialized or
+    /// the delivery settings are inconsistent.
+    pub fn from_params(
+        params: &serde_json::Value,
+        resolver: Arc<dyn SecretResolver>,
+    ) -> Result<Self, StageError> {
+        let config: SftpSinkConfig =
+            serde_json::from_value(params.clone()).map_err(|e| StageError::Permanent {
+                stage: "sftp_sink".to_string(),
+                item_id: String::new(),
+                message: format!("invalid sftp_sink config: {e}"),
+            })?;
+        let delivery = Delivery::new("sftp_sink", config.delivery.clone(), Arc::clone(&resolver))?;
+
+        Ok(Self {
+            config,
+            delivery,
+            resolver,
+            connection: OnceCell::new(),
+        })
+    }
+
+    /// The connection, resolving credentials on first use.
+    async fn connection(&self, item_id: &str) -> Result<&SftpConnection, StageError> {
+        self.connection
+            .get_or_try_init(|| async {
+                SftpConnection::from_credentials(
+                    "sftp_sink",
+                    &self.config.host,
+                    self.config.port,
+                    &self.config.username,
+                    &self.config.credentials,
+                    self.config.keepalive_secs,
+                    self.resolver.as_ref(),
+                )
+                .await
+            })
+            .await
+            .map_err(|e| stage_error(e, item_id))
+    }
+
+    async fn deliver(
+        &self,
+        ctx: &StageContext,
+        items: Vec<PipelineItem>,
+    ) -> Result<Vec<PipelineItem>, StageError> {
+        let item_id = items.first().map(|item| item.id.as_str()).unwrap_or("");
+        let connection = self.connection(item_id).await?;
+        self.delivery
+            .deliver(&SftpTarget { connection }, ctx, items)
+            .await
+    }
+}
+
+/// Writes to the remote server.
+struct SftpTarget<'a> {
+    connection: &'a SftpConnection,
+}
+
+impl SftpTarget<'_> {
+    async fn upload(&self, temp: &str, path: &str, content: &[u8]) -> Result<(), SourceError> {
+        let sftp = self.connection.session().await?;
+        let fail = |e, context: String| self.connection.error(e, context);
+
+        if let Some((parent, _)) = path.rsplit_once('/')
+            && !parent.is_empty()
+            && let Err(e) = create_dir_all(&sftp, parent).await
+        {
+            return Err(fail(e, format!("failed to create {parent}")).await);
+        }
+
+        let mut file = match sftp.create(temp).await {
+            Ok(file) => file,
+            Err(e) => return Err(fail(e, format!("failed to create {temp}")).await),
+        };
+        if let Err(e) = file.write_all(content).await {
+            return Err(transient(format!("failed to write {temp}: {e}")));
+        }
+        if let Err(e) = file.shutdown().await {
+            return Err(transient(format!("failed to close {temp}: {e}")));
+        }
+
+        let renamed = match self.connection.posix_rename().await? {
+            Some(posix) => posix.rename(temp, path).await,
+            None => replace_via_backup(&sftp, temp, path).await,
+        };
+        if let Err(e) = renamed {
+            return Err(fail(e, format!("failed to rename {temp} to {path}")).await);
+        }
+        Ok(())
+    }
+}
+
+/// Move `temp` onto `path` without posix-rename: park any existing target
+/// under a backup name, rename, then drop the backup. The old file is put
+/// back if the second rename fails.
+async fn replace_via_backup(sftp: &SftpSession, temp: &str, path: &str) -> Result<(), SftpError> {
+    let backup = format!("{temp}.old");
+    // A leftover from an interrupted upload would block the rename below
+    if sftp.try_exists(&backup).await? {
+        sftp.remove_file(&backup).await?;
+    }
+    if !sftp.try_exists(path).await? {
+        return sftp.rename(temp, path).await;
+    }
+
+    sftp.rename(path, &backup).await?;
+    if let Err(e) = sftp.rename(temp, path).await {
+        if let Err(restore) = sftp.rename(&backup, path).await {
+            warn!(path, backup = %backup, error = %restore, "Failed to restore previous file");
+        }
+        return Err(e);
+    }
+    if let Err(e) = sftp.remove_file(&backup).await {
+        warn!(backup = %backup, error = %e, "Failed to remove replaced file");
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl Target for SftpTarget<'_> {
+    async fn write_atomic(
+        &self,
+        _ctx: &StageContext,
+        dir: &str,
+        name: &str,
+        temp_suffix: &str,
+        content: Vec<u8>,
+        item_id: &str,
+    ) -> Result<String, StageError> {
+        let path = join_remote(dir, name);
+        let temp = format!("{path}{temp_suffix}");
+        self.upload(&temp, &path, &content)
+            .await
+            .map_err(|e| stage_error(e, item_id))?;
+        Ok(path)
+    }
+}
+
+fn transient(message: String) -> SourceError {
+    SourceError::Transient {
+        source_name: "sftp_sink".to_string(),
+        message,
+    }
+}
+
+/// Auth failures need a config change; anything else may succeed on retry.
+fn stage_error(error: SourceError, item_id: &str) -> StageError {
+    let message = error.to_string();
+    match error {
+        SourceError::AuthError { .. } => StageError::Permanent {
+            stage: "sftp_sink".to_string(),
+            item_id: item_id.to_string(),
+            message,
+        },
+        _ => StageError::Transient {
+            stage: "sftp_sink".to_string(),
+            item_id: item_id.to_string(),
+            message,
+        },
+    }
+}
+
+#[async_trait]
+impl Stage for SftpSinkStage {
+    fn name(&self) -> &str {
+        "sftp_sink"
+    }
+
+    fn requires_batch(&self) -> bool {
+        self.delivery.requires_batch()
+    }
+
+    async fn process(
+        &self,
+        item: PipelineItem,
+        ctx: &StageContext,
+    ) -> Result<Vec<PipelineItem>, StageError> {
+        self.deliver(ctx, vec![item]).await
+    }
+
+    async fn process_batch(
+        &self,
+        items: Vec<PipelineItem>,
+        ctx: &StageContext,
+    ) -> Result<Vec<PipelineItem>, StageError> {
+        self.deliver(ctx, items).await
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::encrypt::tests::decrypt;
+    use crate::tests::{MockResolver, ctx, make_item};
+    use ecl_adapter_sftp::test_server::{self, PASSWORD, USERNAME};
+    use ecl_stages::test_keys::keygen;
+    use pgp::ser::Serialize as _;
+    use serde_json::json;
+
+    /// Resolver holding the server password plus `entries`.
+    fn resolver(entries: &[(&str, Vec<u8>)]) -> Arc<dyn SecretResolver> {
+        let mut secrets: std::collections::HashMap<String, Vec<u8>> = entries
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect();
+        secrets.insert("sftp-password".to_string(), PASSWORD.as_bytes().to_vec());
+        secrets.insert("wrong-password".to_string(), b"wrong".to_vec());
+        Arc::new(MockResolver(secrets))
+    }
+
+    fn params(port: u16, delivery: serde_json::Value) -> serde_json::Value {
+        let mut params = json!({
+            "host": "127.0.0.1",
+            "port": port,
+            "username": USERNAME,
+            "credentials": { "type": "secret", "name": "sftp-password" },
+        });
+        params
+            .as_object_mut()
+            .unwrap()
+            .extend(delivery.as_object().unwrap().clone());
+        params
+    }
+
+    #[test]
+    fn test_sftp_sink_config_defaults() {
+        let stage = SftpSinkStage::from_params(&params(0, json!({ "path": "/in" })), resolver(&[]))
+            .unwrap();
+        assert_eq!(stage.config.keepalive_secs, 30);
+        assert_eq!(
+            SftpSinkStage::from_params(
+                &json!({
+                    "host": "h",
+                    "username": "u",
+                    "credentials": { "type": "secret", "name": "x" },
+                    "path": "/"
+                }),
+                resolver(&[]),
+            )
+            .unwrap()
+            .config
+            .port,
+            22
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sftp_sink_uploads_and_reuses_session() {
+        let root = tempfile::tempdir().unwrap();
+        let server = test_server::spawn(root.path()).await;
+        let stage = SftpSinkStage::from_params(
+            &params(server.port, json!({ "path": "/drop/{date}" })),
+            resolver(&[]),
+        )
+        .unwrap();
+        let ctx = ctx(root.path());
+
+        let first = stage
+            .process(make_item("a.csv", "one"), &ctx)
+            .await
+            .unwrap();
+        stage
+            .process(make_item("b.csv", "two"), &ctx)
+            .await
+            .unwrap();
+
+        let date = chrono::Utc::now().format("%Y-%m-%d").to_string();
+        let dir = root.path().join("drop").join(&date);
+        assert_eq!(std::fs::read_to_string(dir.join("a.csv")).unwrap(), "one");
+        assert_eq!(std::fs::read_to_string(dir.join("b.csv")).unwrap(), "two");
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 2);
+        assert_eq!(
+            first[0].metadata["delivered_path"],
+            json!(format!("/drop/{date}/a.csv"))
+        );
+        assert_eq!(server.connections(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_sftp_sink_overwrites_existing_file() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir(root.path().join("drop")).unwrap();
+        std::fs::write(root.path().join("drop/a.csv"), "stale").unwrap();
+        let server = test_server::spawn(root.path()).await;
+        let stage = SftpSinkStage::from_params(
+            &params(server.port, json!({ "path": "/drop" })),
+            resolver(&[]),
+        )
+        .unwrap();
+
+        stage
+            .process(make_item("a.csv", "fresh"), &ctx(root.path()))
+            .await
+            .unwrap();
+        assert_eq!(
+            std::fs::read_to_string(root.path().join("drop/a.csv")).unwrap(),
+            "fresh"
+        );
+        assert!(!root.path().join("drop/a.csv.part").exists());
+        assert_eq!(server.posix_renames(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_sftp_sink_overwrites_without_posix_rename() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir(root.path().join("drop")).unwrap();
+        std::fs::write(root.path().join("drop/a.csv"), "stale").unwrap();
+        std::fs::write(root.path().join("drop/a.csv.part.old"), "leftover").unwrap();
+        let server = test_server::spawn_without_posix_rename(root.path()).await;
+        let stage = SftpSinkStage::from_params(
+            &params(server.port, json!({ "path": "/drop" })),
+            resolver(&[]),
+        )
+        .unwrap();
+        let ctx = ctx(root.path());
+
+        stage
+            .process(make_item("a.csv", "fresh"), &ctx)
+            .await
+            .unwrap();
+        stage
+            .process(make_item("b.csv", "new"), &ctx)
+            .await
+            .unwrap();
+        let drop_dir = root.path().join("drop");
+        assert_eq!(
+            std::fs::read_to_string(drop_dir.join("a.csv")).unwrap(),
+            "fresh"
+        );
+        assert_eq!(
+            std::fs::read_to_string(drop_dir.join("b.csv")).unwrap(),
+            "new"
+        );
+        assert_eq!(std::fs::read_dir(&drop_dir).unwrap().count(), 2);
+        assert_eq!(server.posix_renames(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_sftp_sink_encrypted_batch() {
+        let root = tempfile::tempdir().unwrap();
+        let server = test_server::spawn(root.path()).await;
+        let partner = keygen("Partner <partner@example.com>", None);
+        let stage = SftpSinkStage::from_params(
+            &params(
+                server.port,
+                json!({
+                    "path": "/out",
+                    "mode": "batch",
+                    "file_name": "export.csv",
+                    "encrypt": { "recipient_key_secrets": ["partner"], "armor": true }
+                }),
+            ),
+            resolver(&[("partner", partner.to_public_key().to_bytes().unwrap())]),
+        )
+        .unwrap();
+
+        let items = vec![make_item("a", "id\n1\n"), make_item("b", "2\n")];
+        let result = stage.process_batch(items, &ctx(root.path())).await.unwrap();
+        assert_eq!(
+            result[0].metadata["delivered_path"],
+            json!("/out/export.csv.asc")
+        );
+        let encrypted = std::fs::read(root.path().join("out/export.csv.asc")).unwrap();
+        assert_eq!(decrypt(&encrypted, &partner), b"id\n1\n2\n");
+    }
+
+    #[tokio::test]
+    async fn test_sftp_sink_rejected_credentials_are_permanent() {
+        let root = tempfile::tempdir().unwrap();
+        let server = test_server::spawn(root.path()).await;
+        let mut params = params(server.port, json!({ "path": "/drop" }));
+        params["credentials"] = json!({ "type": "secret", "name": "wrong-password" });
+        let stage = SftpSinkStage::from_params(&params, resolver(&[])).unwrap();
+
+        let err = stage
+            .process(make_item("a.csv", "x"), &ctx(root.path()))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, StageError::Permanent { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_sftp_sink_unreachable_server_is_transient() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+        let stage =
+            SftpSinkStage::from_params(&params(port, json!({ "path": "/drop" })), resolver(&[]))
+                .unwrap();
+
+        let err = stage
+            .process(make_item("a.csv", "x"), &ctx(std::path::Path::new("/tmp")))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, StageError::Transient { .. }));
+    }
+}
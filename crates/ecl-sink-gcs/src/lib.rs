@@ -13,6 +13,7 @@
 
 use async_trait::async_trait;
 use chrono::Utc;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use tracing::debug;
 
@@ -23,7 +24,7 @@ use ecl_pipeline_topo::error::StageError;
 use ecl_pipeline_topo::{PipelineItem, Stage, StageContext};
 
 /// Configuration for the GCS sink stage, deserialized from TOML params.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct GcsSinkConfig {
     /// GCS bucket name.
     pub bucket: String,
@@ -31,6 +32,7 @@ pub struct GcsSinkConfig {
     pub prefix: String,
     /// Credential reference for GCS auth.
     #[serde(default = "default_adc")]
+    #[schemars(with = "serde_json::Value")]
     pub credentials: CredentialRef,
     /// Filter: `"all"` (default), `"valid_only"`, `"errors_only"`.
     #[serde(default = "default_filter")]
@@ -2,30 +2,14 @@
 //!
 //! Converts `serde_json::Map` records to Avro binary with the Confluent
 //! wire format header: `[0x00][4-byte schema ID big-endian][avro datum]`.
+//! Schema parsing and record conversion are shared with the `serialize`
+//! stage in `ecl-stages`.
 
 use apache_avro::Schema;
-use apache_avro::types::Value as AvroValue;
-use thiserror::Error;
 
-/// Errors during Avro serialization.
-#[derive(Debug, Error)]
-#[non_exhaustive]
-pub enum AvroError {
-    /// Schema parsing failed.
-    #[error("invalid Avro schema: {0}")]
-    InvalidSchema(String),
+pub use ecl_stages::avro::{AvroError, load_schema_json, parse_schema};
 
-    /// Value conversion failed.
-    #[error("Avro conversion error: {message}")]
-    ConversionError {
-        /// Error detail.
-        message: String,
-    },
-
-    /// Avro encoding failed.
-    #[error("Avro encoding error: {0}")]
-    EncodingError(Box<apache_avro::Error>),
-}
+use ecl_stages::avro::record_to_avro;
 
 /// Serialize a JSON record to Confluent wire format.
 ///
@@ -41,7 +25,7 @@ pub fn serialize_record_avro(
     schema_id: i32,
 ) -> Result<Vec<u8>, AvroError> {
     // Convert JSON record to Avro Value.
-    let avro_value = json_to_avro_record(record, schema)?;
+    let avro_value = record_to_avro(record, schema)?;
 
     // Encode to Avro binary datum.
     let datum = apache_avro::to_avro_datum(schema, avro_value)
@@ -56,113 +40,6 @@ pub fn serialize_record_avro(
     Ok(buf)
 }
 
-/// Parse an Avro schema from a JSON string.
-///
-/// # Errors
-///
-/// Returns `AvroError::InvalidSchema` if parsing fails.
-pub fn parse_schema(schema_json: &str) -> Result<Schema, AvroError> {
-    Schema::parse_str(schema_json).map_err(|e| AvroError::InvalidSchema(e.to_string()))
-}
-
-/// Convert a JSON record to an Avro record value, guided by the schema.
-fn json_to_avro_record(
-    record: &serde_json::Map<String, serde_json::Value>,
-    schema: &Schema,
-) -> Result<AvroValue, AvroError> {
-    match schema {
-        Schema::Record(record_schema) => {
-            let mut avro_fields = Vec::new();
-
-            for field in &record_schema.fields {
-                let json_val = record.get(&field.name);
-                let avro_val = json_value_to_avro(json_val, &field.schema)?;
-                avro_fields.push((field.name.clone(), avro_val));
-            }
-
-            Ok(AvroValue::Record(avro_fields))
-        }
-        _ => Err(AvroError::ConversionError {
-            message: "top-level schema must be a record".to_string(),
-        }),
-    }
-}
-
-/// Convert a single JSON value to an Avro value based on the target schema.
-fn json_value_to_avro(
-    value: Option<&serde_json::Value>,
-    schema: &Schema,
-) -> Result<AvroValue, AvroError> {
-    match schema {
-        Schema::Null => Ok(AvroValue::Null),
-        Schema::Boolean => match value {
-            Some(serde_json::Value::Bool(b)) => Ok(AvroValue::Boolean(*b)),
-            _ => Ok(AvroValue::Boolean(false)),
-        },
-        Schema::Int => match value {
-            Some(serde_json::Value::Number(n)) => {
-                Ok(AvroValue::Int(n.as_i64().unwrap_or(0) as i32))
-            }
-            Some(serde_json::Value::String(s)) => Ok(AvroValue::Int(s.parse::<i32>().unwrap_or(0))),
-            _ => Ok(AvroValue::Int(0)),
-        },
-        Schema::Long => match value {
-            Some(serde_json::Value::Number(n)) => Ok(AvroValue::Long(n.as_i64().unwrap_or(0))),
-            Some(serde_json::Value::String(s)) => {
-                Ok(AvroValue::Long(s.parse::<i64>().unwrap_or(0)))
-            }
-            _ => Ok(AvroValue::Long(0)),
-        },
-        Schema::Float => match value {
-            Some(serde_json::Value::Number(n)) => {
-                Ok(AvroValue::Float(n.as_f64().unwrap_or(0.0) as f32))
-            }
-            _ => Ok(AvroValue::Float(0.0)),
-        },
-        Schema::Double => match value {
-            Some(serde_json::Value::Number(n)) => Ok(AvroValue::Double(n.as_f64().unwrap_or(0.0))),
-            _ => Ok(AvroValue::Double(0.0)),
-        },
-        Schema::String => match value {
-            Some(serde_json::Value::String(s)) => Ok(AvroValue::String(s.clone())),
-            Some(v) => Ok(AvroValue::String(v.to_string())),
-            None => Ok(AvroValue::String(String::new())),
-        },
-        Schema::Union(union_schema) => {
-            // Handle ["null", "type"] union (optional fields).
-            let variants = union_schema.variants();
-            if value.is_none() || matches!(value, Some(serde_json::Value::Null)) {
-                // Find null variant.
-                for (i, variant) in variants.iter().enumerate() {
-                    if *variant == Schema::Null {
-                        return Ok(AvroValue::Union(i as u32, Box::new(AvroValue::Null)));
-                    }
-                }
-                return Err(AvroError::ConversionError {
-                    message: "union does not contain null".to_string(),
-                });
-            }
-            // Find the first non-null variant that could match.
-            for (i, variant) in variants.iter().enumerate() {
-                if *variant != Schema::Null {
-                    let converted = json_value_to_avro(value, variant)?;
-                    return Ok(AvroValue::Union(i as u32, Box::new(converted)));
-                }
-            }
-            Err(AvroError::ConversionError {
-                message: "no matching union variant".to_string(),
-            })
-        }
-        _ => {
-            // Fallback: serialize as string.
-            match value {
-                Some(v) => Ok(AvroValue::String(v.to_string())),
-                None => Ok(AvroValue::Null),
-            }
-        }
-    }
-}
-
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -259,16 +136,4 @@ mod tests {
         let bytes = serialize_record_avro(&record, &schema, 1).unwrap();
         assert!(!bytes.is_empty());
     }
-
-    #[test]
-    fn test_parse_schema_invalid() {
-        let result = parse_schema("not valid json");
-        assert!(result.is_err());
-    }
-
-    #[test]
-    fn test_avro_error_is_send_sync() {
-        fn assert_send_sync<T: Send + Sync>() {}
-        assert_send_sync::<AvroError>();
-    }
 }
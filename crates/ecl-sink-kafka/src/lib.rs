@@ -19,6 +19,7 @@ use async_trait::async_trait;
 use rdkafka::config::ClientConfig;
 use rdkafka::producer::{FutureProducer, FutureRecord};
 use regex::Regex;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use tokio::sync::OnceCell;
 use tracing::debug;
@@ -26,11 +27,11 @@ use tracing::debug;
 use ecl_pipeline_topo::error::StageError;
 use ecl_pipeline_topo::{PipelineItem, Stage, StageContext};
 
-use crate::avro::{parse_schema, serialize_record_avro};
+use crate::avro::{load_schema_json, parse_schema, serialize_record_avro};
 use crate::registry::SchemaRegistry;
 
 /// Configuration for the Kafka sink stage, deserialized from TOML params.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct KafkaSinkConfig {
     /// Kafka topic to produce to.
     pub topic: String,
@@ -139,25 +140,13 @@ impl KafkaSinkStage {
         }
 
         // Load Avro schema from inline or file.
-        let schema_json = match (&config.avro_schema, &config.avro_schema_file) {
-            (Some(inline), _) => inline.clone(),
-            (None, Some(path)) => {
-                let interpolated_path = interpolate_env(path);
-                std::fs::read_to_string(&interpolated_path).map_err(|e| StageError::Permanent {
-                    stage: "kafka_sink".to_string(),
-                    item_id: String::new(),
-                    message: format!("cannot read schema file '{interpolated_path}': {e}"),
-                })?
-            }
-            (None, None) => {
-                return Err(StageError::Permanent {
-                    stage: "kafka_sink".to_string(),
-                    item_id: String::new(),
-                    message: "either 'avro_schema' or 'avro_schema_file' must be specified"
-                        .to_string(),
-                });
-            }
-        };
+        let schema_path = config.avro_schema_file.as_deref().map(interpolate_env);
+        let schema_json = load_schema_json(config.avro_schema.as_deref(), schema_path.as_deref())
+            .map_err(|e| StageError::Permanent {
+            stage: "kafka_sink".to_string(),
+            item_id: String::new(),
+            message: e.to_string(),
+        })?;
 
         // Parse the Avro schema.
         let schema = parse_schema(&schema_json).map_err(|e| StageError::Permanent {
@@ -6,6 +6,7 @@
 use std::collections::BTreeMap;
 
 use async_trait::async_trait;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde_json::Value;
 use tracing::debug;
@@ -16,7 +17,7 @@ use ecl_pipeline_topo::{PipelineItem, Stage, StageContext};
 type Record = serde_json::Map<String, serde_json::Value>;
 
 /// Configuration for the aggregate stage, parsed from stage params.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct AggregateConfig {
     /// Fields to group by.
     pub group_by: Vec<String>,
@@ -29,7 +30,7 @@ pub struct AggregateConfig {
 }
 
 /// A single aggregate operation.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct AggregateOp {
     /// Source field to aggregate.
     pub field: String,
@@ -40,7 +41,7 @@ pub struct AggregateOp {
 }
 
 /// Collect sub-records into a JSON array.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct CollectArrayOp {
     /// Output field name (will be a JSON array).
     pub output: String,
@@ -7,6 +7,7 @@
 use std::collections::{BTreeMap, HashMap};
 
 use async_trait::async_trait;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde_json::Value;
 use tracing::debug;
@@ -15,7 +16,7 @@ use ecl_pipeline_topo::error::StageError;
 use ecl_pipeline_topo::{PipelineItem, Stage, StageContext};
 
 /// Configuration for the assemble stage, parsed from stage params.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct AssembleConfig {
     /// The primary stream (e.g., "transactions"). One output per primary record.
     pub primary_stream: String,
@@ -27,7 +28,7 @@ pub struct AssembleConfig {
 }
 
 /// A join definition for the assemble stage.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct AssembleJoin {
     /// Which stream to join from.
     pub stream: String,
@@ -0,0 +1,268 @@
+//! Avro schema loading and record conversion.
+//!
+//! Shared by the `serialize` stage (Avro container files) and the Kafka
+//! sink (Confluent wire format). Records are converted field by field,
+//! guided by the schema; missing or mistyped values fall back to the
+//! type's zero value, and unsupported schema types are written as strings.
+
+use apache_avro::Schema;
+use apache_avro::types::Value as AvroValue;
+use thiserror::Error;
+
+use ecl_pipeline_topo::Record;
+
+/// Errors during Avro schema loading and serialization.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum AvroError {
+    /// Schema parsing failed.
+    #[error("invalid Avro schema: {0}")]
+    InvalidSchema(String),
+
+    /// Neither an inline schema nor a schema file was configured.
+    #[error("either 'avro_schema' or 'avro_schema_file' must be specified")]
+    MissingSchema,
+
+    /// The schema file could not be read.
+    #[error("cannot read schema file '{path}': {message}")]
+    SchemaFile {
+        /// Path of the schema file.
+        path: String,
+        /// Error detail.
+        message: String,
+    },
+
+    /// Value conversion failed.
+    #[error("Avro conversion error: {message}")]
+    ConversionError {
+        /// Error detail.
+        message: String,
+    },
+
+    /// Avro encoding failed.
+    #[error("Avro encoding error: {0}")]
+    EncodingError(Box<apache_avro::Error>),
+}
+
+/// Parse an Avro schema from a JSON string.
+///
+/// # Errors
+///
+/// Returns `AvroError::InvalidSchema` if parsing fails.
+pub fn parse_schema(schema_json: &str) -> Result<Schema, AvroError> {
+    Schema::parse_str(schema_json).map_err(|e| AvroError::InvalidSchema(e.to_string()))
+}
+
+/// Return schema JSON from an inline string or a `.avsc` file on disk.
+/// The inline schema wins when both are given.
+///
+/// # Errors
+///
+/// Returns `AvroError::MissingSchema` if neither is given and
+/// `AvroError::SchemaFile` if the file cannot be read.
+pub fn load_schema_json(inline: Option<&str>, file: Option<&str>) -> Result<String, AvroError> {
+    match (inline, file) {
+        (Some(inline), _) => Ok(inline.to_string()),
+        (None, Some(path)) => std::fs::read_to_string(path).map_err(|e| AvroError::SchemaFile {
+            path: path.to_string(),
+            message: e.to_string(),
+        }),
+        (None, None) => Err(AvroError::MissingSchema),
+    }
+}
+
+/// Convert a JSON record to an Avro record value, guided by the schema.
+///
+/// # Errors
+///
+/// Returns `AvroError::ConversionError` if the schema is not a record or
+/// a value cannot be placed in a union.
+pub fn record_to_avro(record: &Record, schema: &Schema) -> Result<AvroValue, AvroError> {
+    match schema {
+        Schema::Record(record_schema) => {
+            let mut avro_fields = Vec::new();
+
+            for field in &record_schema.fields {
+                let json_val = record.get(&field.name);
+                let avro_val = json_value_to_avro(json_val, &field.schema)?;
+                avro_fields.push((field.name.clone(), avro_val));
+            }
+
+            Ok(AvroValue::Record(avro_fields))
+        }
+        _ => Err(AvroError::ConversionError {
+            message: "top-level schema must be a record".to_string(),
+        }),
+    }
+}
+
+/// Convert a single JSON value to an Avro value based on the target schema.
+fn json_value_to_avro(
+    value: Option<&serde_json::Value>,
+    schema: &Schema,
+) -> Result<AvroValue, AvroError> {
+    match schema {
+        Schema::Null => Ok(AvroValue::Null),
+        Schema::Boolean => match value {
+            Some(serde_json::Value::Bool(b)) => Ok(AvroValue::Boolean(*b)),
+            _ => Ok(AvroValue::Boolean(false)),
+        },
+        Schema::Int => match value {
+            Some(serde_json::Value::Number(n)) => {
+                Ok(AvroValue::Int(n.as_i64().unwrap_or(0) as i32))
+            }
+            Some(serde_json::Value::String(s)) => Ok(AvroValue::Int(s.parse::<i32>().unwrap_or(0))),
+            _ => Ok(AvroValue::Int(0)),
+        },
+        Schema::Long => match value {
+            Some(serde_json::Value::Number(n)) => Ok(AvroValue::Long(n.as_i64().unwrap_or(0))),
+            Some(serde_json::Value::String(s)) => {
+                Ok(AvroValue::Long(s.parse::<i64>().unwrap_or(0)))
+            }
+            _ => Ok(AvroValue::Long(0)),
+        },
+        Schema::Float => match value {
+            Some(serde_json::Value::Number(n)) => {
+                Ok(AvroValue::Float(n.as_f64().unwrap_or(0.0) as f32))
+            }
+            _ => Ok(AvroValue::Float(0.0)),
+        },
+        Schema::Double => match value {
+            Some(serde_json::Value::Number(n)) => Ok(AvroValue::Double(n.as_f64().unwrap_or(0.0))),
+            _ => Ok(AvroValue::Double(0.0)),
+        },
+        Schema::String => match value {
+            Some(serde_json::Value::String(s)) => Ok(AvroValue::String(s.clone())),
+            Some(v) => Ok(AvroValue::String(v.to_string())),
+            None => Ok(AvroValue::String(String::new())),
+        },
+        Schema::Union(union_schema) => {
+            // Handle ["null", "type"] union (optional fields).
+            let variants = union_schema.variants();
+            if value.is_none() || matches!(value, Some(serde_json::Value::Null)) {
+                // Find null variant.
+                for (i, variant) in variants.iter().enumerate() {
+                    if *variant == Schema::Null {
+                        return Ok(AvroValue::Union(i as u32, Box::new(AvroValue::Null)));
+                    }
+                }
+                return Err(AvroError::ConversionError {
+                    message: "union does not contain null".to_string(),
+                });
+            }
+            // Find the first non-null variant that could match.
+            for (i, variant) in variants.iter().enumerate() {
+                if *variant != Schema::Null {
+                    let converted = json_value_to_avro(value, variant)?;
+                    return Ok(AvroValue::Union(i as u32, Box::new(converted)));
+                }
+            }
+            Err(AvroError::ConversionError {
+                message: "no matching union variant".to_string(),
+            })
+        }
+        _ => {
+            // Fallback: serialize as string.
+            match value {
+                Some(v) => Ok(AvroValue::String(v.to_string())),
+                None => Ok(AvroValue::Null),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn test_schema() -> Schema {
+        parse_schema(
+            r#"{
+                "type": "record",
+                "name": "Transaction",
+                "fields": [
+                    {"name": "id", "type": "string"},
+                    {"name": "amount", "type": "double"},
+                    {"name": "count", "type": "int"},
+                    {"name": "note", "type": ["null", "string"], "default": null}
+                ]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_record_to_avro_follows_schema_order() {
+        let mut record = Record::new();
+        record.insert("note".to_string(), json!("hi"));
+        record.insert("count".to_string(), json!("7"));
+        record.insert("id".to_string(), json!("txn-001"));
+        record.insert("extra".to_string(), json!(true));
+
+        let value = record_to_avro(&record, &test_schema()).unwrap();
+        assert_eq!(
+            value,
+            AvroValue::Record(vec![
+                ("id".to_string(), AvroValue::String("txn-001".to_string())),
+                ("amount".to_string(), AvroValue::Double(0.0)),
+                ("count".to_string(), AvroValue::Int(7)),
+                (
+                    "note".to_string(),
+                    AvroValue::Union(1, Box::new(AvroValue::String("hi".to_string())))
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_record_to_avro_null_union() {
+        let value = record_to_avro(&Record::new(), &test_schema()).unwrap();
+        let AvroValue::Record(fields) = value else {
+            unreachable!("record schema yields a record");
+        };
+        assert_eq!(fields[3].1, AvroValue::Union(0, Box::new(AvroValue::Null)));
+    }
+
+    #[test]
+    fn test_record_to_avro_requires_record_schema() {
+        let schema = parse_schema(r#""string""#).unwrap();
+        let err = record_to_avro(&Record::new(), &schema).unwrap_err();
+        assert!(err.to_string().contains("must be a record"));
+    }
+
+    #[test]
+    fn test_load_schema_json() {
+        assert_eq!(
+            load_schema_json(Some("inline"), Some("/nope")).unwrap(),
+            "inline"
+        );
+        assert!(matches!(
+            load_schema_json(None, None),
+            Err(AvroError::MissingSchema)
+        ));
+        let err = load_schema_json(None, Some("/nonexistent/x.avsc")).unwrap_err();
+        assert!(err.to_string().contains("/nonexistent/x.avsc"));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("s.avsc");
+        std::fs::write(&path, r#""string""#).unwrap();
+        assert_eq!(
+            load_schema_json(None, path.to_str()).unwrap(),
+            r#""string""#
+        );
+    }
+
+    #[test]
+    fn test_parse_schema_invalid() {
+        let result = parse_schema("not valid json");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_avro_error_is_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<AvroError>();
+    }
+}
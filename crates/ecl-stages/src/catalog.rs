@@ -0,0 +1,597 @@
+//! Stage catalog: the single registry of stage adapters a runtime can build.
+//!
+//! Each [`StageEntry`] pairs an adapter name (the `adapter = "..."` in a
+//! stage's TOML) with a one-line summary, the JSON Schema of its `params`
+//! (derived from the stage's config type), and a constructor. The catalog
+//! resolves stages for the topology layer and, as a
+//! [`ParamsCatalog`], lets `PipelineSpec::validate_with` check params
+//! before anything runs.
+//!
+//! [`StageCatalog::builtin`] registers every stage in this crate; runtimes
+//! register stages from other crates (sinks, for example) on top.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use schemars::JsonSchema;
+
+use ecl_pipeline_spec::{ParamsCatalog, StageSpec};
+use ecl_pipeline_topo::error::{ResolveError, StageError};
+use ecl_pipeline_topo::{SourceAdapter, Stage};
+use ecl_secrets::SecretResolver;
+
+use crate::aggregate::{AggregateConfig, AggregateStage};
+use crate::assemble::{AssembleConfig, AssembleStage};
+use crate::csv_parse::{CsvParseConfig, CsvParseStage};
+use crate::date_parse::{DateParseConfig, DateParseStage};
+use crate::decompress::{DecompressConfig, DecompressStage};
+use crate::emit::EmitStage;
+use crate::extract::ExtractStage;
+use crate::field_map::{FieldMapConfig, FieldMapStage};
+use crate::filter::{FilterConfig, FilterStage};
+use crate::fixed_width_parse::{FixedWidthParseConfig, FixedWidthParseStage};
+use crate::join::{JoinConfig, JoinStage};
+use crate::json_parse::{JsonParseConfig, JsonParseStage};
+use crate::lookup::{LookupConfig, LookupStage};
+use crate::normalize::NormalizeStage;
+use crate::pgp_decrypt::{PgpDecryptConfig, PgpDecryptStage};
+use crate::serialize::{SerializeConfig, SerializeStage};
+use crate::timezone::{TimezoneConfig, TimezoneStage};
+use crate::validate::{ValidateConfig, ValidateStage};
+use crate::xml_parse::{XmlParseConfig, XmlParseStage};
+
+/// Params for stages that take none. Any table is accepted and ignored.
+#[derive(JsonSchema)]
+struct NoParams {}
+
+/// What a stage constructor gets to work with.
+pub struct BuildContext<'a> {
+    /// The stage's name in the pipeline spec.
+    pub stage: &'a str,
+    /// The stage's spec.
+    pub spec: &'a StageSpec,
+    /// The stage's params; an omitted `params` table is an empty object.
+    pub params: &'a serde_json::Value,
+    /// Pre-resolved pull source adapters, keyed by source name.
+    pub sources: &'a BTreeMap<String, Arc<dyn SourceAdapter>>,
+    /// Resolver for secrets named in stage params.
+    pub secrets: Arc<dyn SecretResolver>,
+}
+
+/// Constructs a stage from its spec.
+pub type BuildFn = fn(&BuildContext<'_>) -> Result<Arc<dyn Stage>, ResolveError>;
+
+/// A registered stage adapter.
+#[derive(Debug, Clone)]
+pub struct StageEntry {
+    name: &'static str,
+    summary: &'static str,
+    params_schema: serde_json::Value,
+    build: BuildFn,
+}
+
+impl StageEntry {
+    /// Describe an adapter whose params deserialize into `P`.
+    pub fn new<P: JsonSchema>(name: &'static str, summary: &'static str, build: BuildFn) -> Self {
+        Self {
+            name,
+            summary,
+            params_schema: schemars::schema_for!(P).to_value(),
+            build,
+        }
+    }
+
+    /// Describe an adapter that takes no params.
+    pub fn without_params(name: &'static str, summary: &'static str, build: BuildFn) -> Self {
+        Self::new::<NoParams>(name, summary, build)
+    }
+
+    /// The adapter name stages use to select this entry.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// One-line description of what the stage does.
+    pub fn summary(&self) -> &'static str {
+        self.summary
+    }
+
+    /// JSON Schema for the stage's `params`.
+    pub fn params_schema(&self) -> &serde_json::Value {
+        &self.params_schema
+    }
+}
+
+/// Every stage adapter a runtime can build, keyed by adapter name.
+#[derive(Debug, Clone, Default)]
+pub struct StageCatalog {
+    entries: BTreeMap<&'static str, StageEntry>,
+    secrets: Option<Arc<dyn SecretResolver>>,
+}
+
+impl StageCatalog {
+    /// An empty catalog.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolve secrets named in stage params through `resolver` instead of
+    /// the default environment-variable resolver.
+    pub fn with_secret_resolver(mut self, resolver: Arc<dyn SecretResolver>) -> Self {
+        self.secrets = Some(resolver);
+        self
+    }
+
+    /// A catalog of every stage in this crate.
+    pub fn builtin() -> Self {
+        let mut catalog = Self::new();
+        catalog
+            .register(StageEntry::without_params(
+                "extract",
+                "Fetch each item's content from the stage's `source` adapter",
+                build_extract,
+            ))
+            .register(StageEntry::without_params(
+                "normalize",
+                "Pass items through unchanged (placeholder for format conversion)",
+                |_| Ok(Arc::new(NormalizeStage::new())),
+            ))
+            .register(StageEntry::new::<FilterConfig>(
+                "filter",
+                "Keep or drop items by glob patterns on their IDs",
+                |ctx| {
+                    Ok(Arc::new(
+                        FilterStage::from_params(ctx.params).map_err(|e| invalid(ctx, e))?,
+                    ))
+                },
+            ))
+            .register(StageEntry::new::<CsvParseConfig>(
+                "csv_parse",
+                "Parse CSV content into one record per row",
+                |ctx| {
+                    Ok(Arc::new(
+                        CsvParseStage::from_params(ctx.params).map_err(|e| invalid(ctx, e))?,
+                    ))
+                },
+            ))
+            .register(StageEntry::new::<JsonParseConfig>(
+                "json_parse",
+                "Parse JSON or JSON Lines content into one record per selected value",
+                |ctx| {
+                    Ok(Arc::new(
+                        JsonParseStage::from_params(ctx.params).map_err(|e| invalid(ctx, e))?,
+                    ))
+                },
+            ))
+            .register(StageEntry::new::<XmlParseConfig>(
+                "xml_parse",
+                "Parse XML content into one record per matching element",
+                |ctx| {
+                    Ok(Arc::new(
+                        XmlParseStage::from_params(ctx.params).map_err(|e| invalid(ctx, e))?,
+                    ))
+                },
+            ))
+            .register(StageEntry::new::<FixedWidthParseConfig>(
+                "fixed_width_parse",
+                "Parse fixed-width text into one record per line",
+                |ctx| {
+                    Ok(Arc::new(
+                        FixedWidthParseStage::from_params(ctx.params)
+                            .map_err(|e| invalid(ctx, e))?,
+                    ))
+                },
+            ))
+            .register(StageEntry::new::<FieldMapConfig>(
+                "field_map",
+                "Rename, drop, set, copy, pad, extract and nest record fields",
+                |ctx| {
+                    Ok(Arc::new(
+                        FieldMapStage::from_params(ctx.params).map_err(|e| invalid(ctx, e))?,
+                    ))
+                },
+            ))
+            .register(StageEntry::new::<ValidateConfig>(
+                "validate",
+                "Check record fields against rules with hard or soft severity",
+                |ctx| {
+                    Ok(Arc::new(
+                        ValidateStage::from_params(ctx.params).map_err(|e| invalid(ctx, e))?,
+                    ))
+                },
+            ))
+            .register(StageEntry::new::<JoinConfig>(
+                "join",
+                "Join two streams by key (inner, left or full)",
+                |ctx| {
+                    Ok(Arc::new(
+                        JoinStage::from_params(ctx.params).map_err(|e| invalid(ctx, e))?,
+                    ))
+                },
+            ))
+            .register(StageEntry::new::<AggregateConfig>(
+                "aggregate",
+                "Group records and compute sum/max/min/count/avg/first/last",
+                |ctx| {
+                    Ok(Arc::new(
+                        AggregateStage::from_params(ctx.params).map_err(|e| invalid(ctx, e))?,
+                    ))
+                },
+            ))
+            .register(StageEntry::new::<LookupConfig>(
+                "lookup",
+                "Map field values through static lookup tables",
+                |ctx| {
+                    Ok(Arc::new(
+                        LookupStage::from_params(ctx.params).map_err(|e| invalid(ctx, e))?,
+                    ))
+                },
+            ))
+            .register(StageEntry::new::<DateParseConfig>(
+                "date_parse",
+                "Parse date strings into RFC 3339 timestamps",
+                |ctx| {
+                    Ok(Arc::new(
+                        DateParseStage::from_params(ctx.params).map_err(|e| invalid(ctx, e))?,
+                    ))
+                },
+            ))
+            .register(StageEntry::new::<TimezoneConfig>(
+                "timezone",
+                "Convert local datetimes to UTC using a ZIP code timezone lookup",
+                |ctx| {
+                    Ok(Arc::new(
+                        TimezoneStage::from_params(ctx.params).map_err(|e| invalid(ctx, e))?,
+                    ))
+                },
+            ))
+            .register(StageEntry::new::<DecompressConfig>(
+                "decompress",
+                "Extract ZIP or GZIP archives into one item per file",
+                |ctx| {
+                    Ok(Arc::new(
+                        DecompressStage::from_params(ctx.params).map_err(|e| invalid(ctx, e))?,
+                    ))
+                },
+            ))
+            .register(StageEntry::new::<PgpDecryptConfig>(
+                "pgp_decrypt",
+                "Decrypt OpenPGP messages with a secret-resolved private key",
+                |ctx| {
+                    Ok(Arc::new(
+                        PgpDecryptStage::from_params(ctx.params, ctx.secrets.clone())
+                            .map_err(|e| invalid(ctx, e))?,
+                    ))
+                },
+            ))
+            .register(StageEntry::new::<AssembleConfig>(
+                "assemble",
+                "Merge several streams into nested records around a primary stream",
+                |ctx| {
+                    Ok(Arc::new(
+                        AssembleStage::from_params(ctx.params).map_err(|e| invalid(ctx, e))?,
+                    ))
+                },
+            ))
+            .register(StageEntry::new::<SerializeConfig>(
+                "serialize",
+                "Write each stream's records to CSV, JSON Lines, Parquet or Avro files",
+                |ctx| {
+                    Ok(Arc::new(
+                        SerializeStage::from_params(ctx.params).map_err(|e| invalid(ctx, e))?,
+                    ))
+                },
+            ))
+            .register(StageEntry::without_params(
+                "emit",
+                "Write each item's content to the output directory",
+                |_| Ok(Arc::new(EmitStage::new())),
+            ));
+        catalog
+    }
+
+    /// Add `entry`, replacing any entry with the same name.
+    pub fn register(&mut self, entry: StageEntry) -> &mut Self {
+        self.entries.insert(entry.name, entry);
+        self
+    }
+
+    /// The entry for adapter `name`, if registered.
+    pub fn get(&self, name: &str) -> Option<&StageEntry> {
+        self.entries.get(name)
+    }
+
+    /// All entries, ordered by name.
+    pub fn entries(&self) -> impl Iterator<Item = &StageEntry> {
+        self.entries.values()
+    }
+
+    /// Build stage `stage` from its spec.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ResolveError::UnknownAdapter` if the spec's adapter is not
+    /// registered, or whatever the adapter's constructor reports.
+    pub fn build(
+        &self,
+        stage: &str,
+        spec: &StageSpec,
+        sources: &BTreeMap<String, Arc<dyn SourceAdapter>>,
+    ) -> Result<Arc<dyn Stage>, ResolveError> {
+        let entry = self
+            .get(&spec.adapter)
+            .ok_or_else(|| ResolveError::UnknownAdapter {
+                stage: stage.to_string(),
+                adapter: spec.adapter.clone(),
+            })?;
+        let params = spec.effective_params();
+        let secrets = self
+            .secrets
+            .clone()
+            .unwrap_or_else(|| Arc::from(ecl_secrets::default_resolver()));
+        (entry.build)(&BuildContext {
+            stage,
+            spec,
+            params: &params,
+            sources,
+            secrets,
+        })
+    }
+}
+
+impl ParamsCatalog for StageCatalog {
+    fn params_schema(&self, adapter: &str) -> Option<&serde_json::Value> {
+        self.get(adapter).map(StageEntry::params_schema)
+    }
+}
+
+/// Report a stage constructor's error as an invalid stage configuration.
+pub fn invalid(ctx: &BuildContext<'_>, error: StageError) -> ResolveError {
+    ResolveError::Io(std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        format!("{} stage '{}': {error}", ctx.spec.adapter, ctx.stage),
+    ))
+}
+
+fn build_extract(ctx: &BuildContext<'_>) -> Result<Arc<dyn Stage>, ResolveError> {
+    let source_name = ctx.spec.source.as_deref().ok_or_else(|| {
+        ResolveError::Io(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("extract stage '{}' has no 'source' field", ctx.stage),
+        ))
+    })?;
+    let adapter =
+        ctx.sources
+            .get(source_name)
+            .cloned()
+            .ok_or_else(|| ResolveError::UnknownAdapter {
+                stage: ctx.stage.to_string(),
+                adapter: format!("source '{source_name}' not found"),
+            })?;
+    Ok(Arc::new(ExtractStage::new(adapter, source_name)))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use ecl_pipeline_spec::{FilesystemSourceSpec, PipelineSpec, ResourceSpec, SourceSpec};
+    use std::path::PathBuf;
+
+    fn stage(adapter: &str, params: serde_json::Value) -> StageSpec {
+        StageSpec {
+            adapter: adapter.to_string(),
+            source: None,
+            resources: ResourceSpec::default(),
+            params,
+            retry: None,
+            timeout_secs: None,
+            skip_on_error: false,
+            condition: None,
+            input_streams: vec![],
+            output_stream: None,
+        }
+    }
+
+    fn spec_with(stage_spec: StageSpec) -> PipelineSpec {
+        let mut sources = BTreeMap::new();
+        sources.insert(
+            "local".to_string(),
+            SourceSpec::Filesystem(FilesystemSourceSpec {
+                root: PathBuf::from("/tmp/data"),
+                filters: vec![],
+                extensions: vec![],
+                stream: None,
+            }),
+        );
+        let mut stages = BTreeMap::new();
+        stages.insert("under-test".to_string(), stage_spec);
+        PipelineSpec {
+            name: "catalog".to_string(),
+            version: 1,
+            output_dir: PathBuf::from("./output"),
+            sources,
+            stages,
+            defaults: Default::default(),
+            lifecycle: None,
+            secrets: Default::default(),
+            triggers: None,
+            schedule: None,
+        }
+    }
+
+    #[test]
+    fn test_builtin_registers_every_stage() {
+        let names: Vec<&str> = StageCatalog::builtin()
+            .entries()
+            .map(|e| e.name())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "aggregate",
+                "assemble",
+                "csv_parse",
+                "date_parse",
+                "decompress",
+                "emit",
+                "extract",
+                "field_map",
+                "filter",
+                "fixed_width_parse",
+                "join",
+                "json_parse",
+                "lookup",
+                "normalize",
+                "pgp_decrypt",
+                "serialize",
+                "timezone",
+                "validate",
+                "xml_parse",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_schemas_are_derived_from_config_types() {
+        let catalog = StageCatalog::builtin();
+        let join = catalog.get("join").unwrap().params_schema();
+        assert_eq!(join["type"], "object");
+        let required: Vec<&str> = join["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|v| v.as_str())
+            .collect();
+        assert!(required.contains(&"left_stream"));
+        assert!(!required.contains(&"join_type"));
+        // Field doc comments become descriptions for `ecl stages describe`.
+        assert!(join["properties"]["left_key"]["description"].is_string());
+    }
+
+    #[test]
+    fn test_build_every_builtin_with_valid_params() {
+        let catalog = StageCatalog::builtin();
+        let params = [
+            ("normalize", serde_json::Value::Null),
+            ("emit", serde_json::Value::Null),
+            ("filter", serde_json::json!({ "include": ["**/*.md"] })),
+            (
+                "csv_parse",
+                serde_json::json!({ "columns": [{ "name": "id", "type": "integer" }] }),
+            ),
+            (
+                "json_parse",
+                serde_json::json!({ "records": "$.data[*]", "lines": true }),
+            ),
+            (
+                "xml_parse",
+                serde_json::json!({ "records": "orders/order", "columns": [{ "name": "id", "path": "@id" }] }),
+            ),
+            (
+                "fixed_width_parse",
+                serde_json::json!({ "columns": [{ "name": "id", "type": "integer", "start": 0, "width": 6 }] }),
+            ),
+            ("field_map", serde_json::json!({ "drop": ["tmp"] })),
+            (
+                "validate",
+                serde_json::json!({ "rules": [{ "field": "id", "check": "required" }] }),
+            ),
+            (
+                "join",
+                serde_json::json!({
+                    "left_stream": "a", "right_stream": "b",
+                    "left_key": "id", "right_key": "id"
+                }),
+            ),
+            ("aggregate", serde_json::json!({ "group_by": ["id"] })),
+            (
+                "lookup",
+                serde_json::json!({ "lookups": [{ "field": "s", "output": "t", "table": { "A": "Active" } }] }),
+            ),
+            (
+                "date_parse",
+                serde_json::json!({ "conversions": [{ "field": "d", "output": "o", "format": "%Y-%m-%d" }] }),
+            ),
+            (
+                "timezone",
+                serde_json::json!({ "datetime_field": "d", "zipcode_field": "z", "output": "o" }),
+            ),
+            ("decompress", serde_json::Value::Null),
+            (
+                "pgp_decrypt",
+                serde_json::json!({ "private_key_secret": "PARTNER_PGP_KEY" }),
+            ),
+            (
+                "assemble",
+                serde_json::json!({ "primary_stream": "p", "primary_key": "id" }),
+            ),
+            (
+                "serialize",
+                serde_json::json!({ "format": "csv", "columns": [{ "name": "id" }], "max_rows": 1000 }),
+            ),
+        ];
+        for (adapter, params) in params {
+            let spec = spec_with(stage(adapter, params));
+            spec.validate_with(&catalog)
+                .unwrap_or_else(|e| unreachable!("{adapter}: {e}"));
+            let stage_spec = &spec.stages["under-test"];
+            catalog
+                .build("under-test", stage_spec, &BTreeMap::new())
+                .unwrap_or_else(|e| unreachable!("{adapter}: {e}"));
+        }
+    }
+
+    #[test]
+    fn test_validate_with_rejects_bad_params() {
+        let catalog = StageCatalog::builtin();
+        let spec = spec_with(stage("join", serde_json::json!({ "left_stream": "a" })));
+        let err = spec.validate_with(&catalog).unwrap_err();
+        assert!(matches!(
+            err,
+            ecl_pipeline_spec::SpecError::InvalidParams { ref adapter, .. } if adapter == "join"
+        ));
+
+        let spec = spec_with(stage(
+            "csv_parse",
+            serde_json::json!({ "columns": [], "delimiter": ";;" }),
+        ));
+        let err = spec.validate_with(&catalog).unwrap_err();
+        assert!(err.to_string().contains("params.delimiter"));
+    }
+
+    #[test]
+    fn test_build_unknown_adapter_fails() {
+        let catalog = StageCatalog::builtin();
+        let err = catalog
+            .build(
+                "shape",
+                &stage("reshape", serde_json::Value::Null),
+                &BTreeMap::new(),
+            )
+            .unwrap_err();
+        assert!(matches!(err, ResolveError::UnknownAdapter { .. }));
+    }
+
+    #[test]
+    fn test_build_extract_requires_known_source() {
+        let catalog = StageCatalog::builtin();
+        let mut spec = stage("extract", serde_json::Value::Null);
+        let err = catalog.build("fetch", &spec, &BTreeMap::new()).unwrap_err();
+        assert!(err.to_string().contains("has no 'source' field"));
+
+        spec.source = Some("missing".to_string());
+        let err = catalog.build("fetch", &spec, &BTreeMap::new()).unwrap_err();
+        assert!(matches!(err, ResolveError::UnknownAdapter { .. }));
+    }
+
+    #[test]
+    fn test_register_replaces_existing_entry() {
+        let mut catalog = StageCatalog::builtin();
+        catalog.register(StageEntry::without_params("emit", "replacement", |_| {
+            Ok(Arc::new(EmitStage::new()))
+        }));
+        assert_eq!(catalog.get("emit").unwrap().summary(), "replacement");
+        assert_eq!(catalog.entries().count(), 19);
+    }
+}
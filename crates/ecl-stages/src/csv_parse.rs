@@ -8,14 +8,15 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use schemars::JsonSchema;
 use serde::Deserialize;
 
 use ecl_pipeline_topo::error::StageError;
 use ecl_pipeline_topo::{PipelineItem, Record, Stage, StageContext};
 
 /// Parsed from stage params TOML/JSON.
-#[derive(Debug, Clone, Deserialize)]
-struct CsvParseConfig {
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub(crate) struct CsvParseConfig {
     /// Column definitions in order.
     columns: Vec<ColumnDef>,
     /// Field delimiter character. Default: ','
@@ -35,14 +36,14 @@ struct CsvParseConfig {
 }
 
 /// A single column definition.
-#[derive(Debug, Clone, Deserialize)]
-struct ColumnDef {
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub(crate) struct ColumnDef {
     /// Column name (used as Record field key).
-    name: String,
+    pub(crate) name: String,
     /// Column type for conversion: "string", "integer", "float", "boolean".
     /// Default: "string"
     #[serde(default = "default_column_type")]
-    r#type: String,
+    pub(crate) r#type: String,
 }
 
 fn default_delimiter() -> char {
@@ -54,7 +55,7 @@ fn default_quote() -> char {
 fn default_has_headers() -> bool {
     true
 }
-fn default_on_error() -> String {
+pub(crate) fn default_on_error() -> String {
     "skip".to_string()
 }
 fn default_column_type() -> String {
@@ -62,7 +63,7 @@ fn default_column_type() -> String {
 }
 
 /// Convert a raw string value to the appropriate JSON type.
-fn convert_value(raw: &str, col_type: &str) -> serde_json::Value {
+pub(crate) fn convert_value(raw: &str, col_type: &str) -> serde_json::Value {
     match col_type {
         "integer" => raw
             .parse::<i64>()
@@ -81,6 +82,64 @@ fn convert_value(raw: &str, col_type: &str) -> serde_json::Value {
     }
 }
 
+/// Handle a row that failed to parse, per the stage's `on_row_error`:
+/// `"fail"` fails the whole item, anything else logs and skips the row.
+/// Shared by all parse stages.
+pub(crate) fn row_error(
+    stage: &str,
+    on_row_error: &str,
+    item: &PipelineItem,
+    location: &str,
+    error: &dyn std::fmt::Display,
+) -> Result<(), StageError> {
+    if on_row_error == "fail" {
+        return Err(StageError::Permanent {
+            stage: stage.to_string(),
+            item_id: item.id.clone(),
+            message: format!("{location} parse error: {error}"),
+        });
+    }
+    tracing::warn!(item_id = %item.id, stage, "skipping bad {location}: {error}");
+    Ok(())
+}
+
+/// Build the item for one parsed row. `position` is the metadata key and
+/// 1-based number locating the row in its parent (line or record number).
+/// Shared by all parse stages.
+pub(crate) fn row_item(
+    parent: &PipelineItem,
+    position: (&str, usize),
+    record: Record,
+    raw: Vec<u8>,
+    mime_type: &str,
+) -> PipelineItem {
+    let (key, number) = position;
+
+    // Metadata inherited from parent + row-specific fields.
+    let mut metadata = parent.metadata.clone();
+    metadata.insert(
+        "_source_file".to_string(),
+        serde_json::Value::String(parent.display_name.clone()),
+    );
+    metadata.insert(
+        key.to_string(),
+        serde_json::Value::Number(serde_json::Number::from(number)),
+    );
+
+    PipelineItem {
+        id: format!("{}:row:{number}", parent.id),
+        display_name: format!("{}:{number}", parent.display_name),
+        content: Arc::from(raw),
+        mime_type: mime_type.to_string(),
+        source_name: parent.source_name.clone(),
+        source_content_hash: parent.source_content_hash.clone(),
+        provenance: parent.provenance.clone(),
+        metadata,
+        record: Some(record),
+        stream: parent.stream.clone(),
+    }
+}
+
 /// CSV parsing stage: one file in, N record items out.
 #[derive(Debug)]
 pub struct CsvParseStage {
@@ -134,18 +193,13 @@ impl Stage for CsvParseStage {
             let csv_record = match result {
                 Ok(r) => r,
                 Err(e) => {
-                    if self.config.on_row_error == "fail" {
-                        return Err(StageError::Permanent {
-                            stage: "csv_parse".to_string(),
-                            item_id: item.id.clone(),
-                            message: format!("row {line_number} parse error: {e}"),
-                        });
-                    }
-                    tracing::warn!(
-                        item_id = %item.id,
-                        line = line_number,
-                        "skipping bad CSV row: {e}"
-                    );
+                    row_error(
+                        "csv_parse",
+                        &self.config.on_row_error,
+                        &item,
+                        &format!("row {line_number}"),
+                        &e,
+                    )?;
                     continue;
                 }
             };
@@ -157,17 +211,6 @@ impl Stage for CsvParseStage {
                 record.insert(col_def.name.clone(), convert_value(raw, &col_def.r#type));
             }
 
-            // Build metadata inherited from parent + row-specific fields.
-            let mut metadata = item.metadata.clone();
-            metadata.insert(
-                "_source_file".to_string(),
-                serde_json::Value::String(item.display_name.clone()),
-            );
-            metadata.insert(
-                "_line_number".to_string(),
-                serde_json::Value::Number(serde_json::Number::from(line_number)),
-            );
-
             // Build the raw CSV row bytes for debugging.
             let row_bytes: Vec<u8> = csv_record
                 .iter()
@@ -175,18 +218,13 @@ impl Stage for CsvParseStage {
                 .join(&(self.config.delimiter.to_string()))
                 .into_bytes();
 
-            output.push(PipelineItem {
-                id: format!("{}:row:{line_number}", item.id),
-                display_name: format!("{}:{line_number}", item.display_name),
-                content: Arc::from(row_bytes),
-                mime_type: "application/x-csv-row".to_string(),
-                source_name: item.source_name.clone(),
-                source_content_hash: item.source_content_hash.clone(),
-                provenance: item.provenance.clone(),
-                metadata,
-                record: Some(record),
-                stream: item.stream.clone(),
-            });
+            output.push(row_item(
+                &item,
+                ("_line_number", line_number),
+                record,
+                row_bytes,
+                "application/x-csv-row",
+            ));
         }
 
         Ok(output)
@@ -7,6 +7,7 @@
 use async_trait::async_trait;
 use chrono::{NaiveDate, NaiveDateTime, TimeZone};
 use chrono_tz::Tz;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde_json::Value;
 use tracing::debug;
@@ -17,14 +18,14 @@ use ecl_pipeline_topo::{PipelineItem, Stage, StageContext};
 type Record = serde_json::Map<String, serde_json::Value>;
 
 /// Configuration for the date parse stage, parsed from stage params.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct DateParseConfig {
     /// List of date conversions to apply.
     pub conversions: Vec<DateConversion>,
 }
 
 /// A single date conversion operation.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct DateConversion {
     /// Source field containing the date string.
     pub field: String,
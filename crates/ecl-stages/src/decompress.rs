@@ -7,6 +7,7 @@ use std::io::{Cursor, Read as _};
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use tracing::debug;
 
@@ -14,7 +15,7 @@ use ecl_pipeline_topo::error::StageError;
 use ecl_pipeline_topo::{PipelineItem, Stage, StageContext};
 
 /// Configuration for the decompress stage, parsed from stage params.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct DecompressConfig {
     /// Supported formats: "zip", "gzip". Default: "zip".
     #[serde(default = "default_zip")]
@@ -160,7 +161,7 @@ impl DecompressStage {
 }
 
 /// Guess MIME type from file extension.
-fn mime_from_extension(name: &str) -> String {
+pub(crate) fn mime_from_extension(name: &str) -> String {
     match std::path::Path::new(name)
         .extension()
         .and_then(|e| e.to_str())
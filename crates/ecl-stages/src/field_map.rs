@@ -9,6 +9,7 @@ use std::collections::BTreeMap;
 use async_trait::async_trait;
 use chrono::NaiveDate;
 use regex::Regex;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use tracing::debug;
 
@@ -16,8 +17,8 @@ use ecl_pipeline_topo::error::StageError;
 use ecl_pipeline_topo::{PipelineItem, Record, Stage, StageContext};
 
 /// Configuration for the field mapping stage, deserialized from stage params.
-#[derive(Debug, Clone, Deserialize)]
-struct FieldMapConfig {
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub(crate) struct FieldMapConfig {
     /// Rename fields: `{ from: "old_name", to: "new_name" }`.
     #[serde(default)]
     rename: Vec<RenameOp>,
@@ -52,7 +53,7 @@ struct FieldMapConfig {
 }
 
 /// Rename a field from one name to another.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 struct RenameOp {
     /// Source field name.
     from: String,
@@ -61,7 +62,7 @@ struct RenameOp {
 }
 
 /// Set a literal value on a field.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 struct SetOp {
     /// Field name to set.
     field: String,
@@ -70,7 +71,7 @@ struct SetOp {
 }
 
 /// Copy a field value to a new field.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 struct CopyOp {
     /// Source field name.
     from: String,
@@ -79,7 +80,7 @@ struct CopyOp {
 }
 
 /// Parse a date string into RFC 3339 format.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 struct DateParseOp {
     /// Source field containing the date string.
     field: String,
@@ -94,7 +95,7 @@ struct DateParseOp {
 }
 
 /// Pad a string field to a minimum width.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 struct PadOp {
     /// Field name to pad.
     field: String,
@@ -109,7 +110,7 @@ struct PadOp {
 }
 
 /// Extract a capture group from a regex match.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 struct RegexExtractOp {
     /// Source field to match against.
     field: String,
@@ -123,7 +124,7 @@ struct RegexExtractOp {
 }
 
 /// Group fields into a nested JSON object.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 struct NestOp {
     /// Output field name for the nested object.
     output: String,
@@ -2,11 +2,24 @@
 
 use async_trait::async_trait;
 use glob::Pattern;
+use schemars::JsonSchema;
+use serde::Deserialize;
 use tracing::debug;
 
 use ecl_pipeline_topo::error::StageError;
 use ecl_pipeline_topo::{PipelineItem, Stage, StageContext};
 
+/// Parsed from stage params TOML/JSON.
+#[derive(Debug, Clone, Default, Deserialize, JsonSchema)]
+pub(crate) struct FilterConfig {
+    /// Glob patterns an item ID must match one of (if any are given).
+    #[serde(default)]
+    include: Vec<String>,
+    /// Glob patterns that reject an item ID; these win over `include`.
+    #[serde(default)]
+    exclude: Vec<String>,
+}
+
 /// Filter stage that applies glob include/exclude rules to pipeline items.
 ///
 /// Configuration is read from `StageContext.params`:
@@ -35,14 +48,22 @@ impl FilterStage {
     /// Create a filter stage from JSON params.
     ///
     /// Expects `params` to optionally contain `"include"` and `"exclude"` arrays
-    /// of glob pattern strings.
+    /// of glob pattern strings. Null params pass everything through.
     ///
     /// # Errors
     ///
-    /// Returns `StageError::Permanent` if a glob pattern is invalid.
+    /// Returns `StageError::Permanent` if the params are malformed or a glob
+    /// pattern is invalid.
     pub fn from_params(params: &serde_json::Value) -> Result<Self, StageError> {
-        let include = compile_patterns(params, "include")?;
-        let exclude = compile_patterns(params, "exclude")?;
+        let config: Option<FilterConfig> =
+            serde_json::from_value(params.clone()).map_err(|e| StageError::Permanent {
+                stage: "filter".to_string(),
+                item_id: String::new(),
+                message: format!("invalid filter config: {e}"),
+            })?;
+        let config = config.unwrap_or_default();
+        let include = compile_patterns(&config.include)?;
+        let exclude = compile_patterns(&config.exclude)?;
         Ok(Self { include, exclude })
     }
 
@@ -70,16 +91,10 @@ impl FilterStage {
     }
 }
 
-/// Compile glob patterns from a JSON field.
-fn compile_patterns(params: &serde_json::Value, field: &str) -> Result<Vec<Pattern>, StageError> {
-    let Some(arr) = params.get(field) else {
-        return Ok(vec![]);
-    };
-    let Some(arr) = arr.as_array() else {
-        return Ok(vec![]);
-    };
-    arr.iter()
-        .filter_map(|v| v.as_str())
+/// Compile glob pattern strings.
+fn compile_patterns(patterns: &[String]) -> Result<Vec<Pattern>, StageError> {
+    patterns
+        .iter()
         .map(|s| {
             Pattern::new(s).map_err(|e| StageError::Permanent {
                 stage: "filter".to_string(),
@@ -211,6 +226,13 @@ resources = { creates = ["docs"] }
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_from_params_rejects_non_array_patterns() {
+        let params = serde_json::json!({ "include": "**/*.md" });
+        let err = FilterStage::from_params(&params).unwrap_err();
+        assert!(err.to_string().contains("invalid filter config"));
+    }
+
     #[test]
     fn test_from_params_null_value() {
         let params = serde_json::Value::Null;
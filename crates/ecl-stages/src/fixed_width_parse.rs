@@ -0,0 +1,278 @@
+//! Fixed-width parsing stage: fan-out from a fixed-width text file →
+//! individual records.
+//!
+//! Each column is cut from a line at a configured character offset and
+//! width, then typed with the same definitions as `csv_parse`. Lines that
+//! cannot be parsed are skipped or fail the item per `on_row_error`.
+
+use async_trait::async_trait;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use ecl_pipeline_topo::error::StageError;
+use ecl_pipeline_topo::{PipelineItem, Record, Stage, StageContext};
+
+use crate::csv_parse::{ColumnDef, convert_value, default_on_error, row_error, row_item};
+
+/// Parsed from stage params TOML/JSON.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub(crate) struct FixedWidthParseConfig {
+    /// Column definitions with their positions in each line.
+    columns: Vec<FixedWidthColumnDef>,
+    /// Number of leading lines to skip (headers, banners). Default: 0
+    #[serde(default)]
+    skip_lines: usize,
+    /// Trim surrounding whitespace from each field. Default: true
+    #[serde(default = "default_trim")]
+    trim: bool,
+    /// How to handle lines that cannot be parsed.
+    /// "skip" = skip them (log warning), "fail" = fail the item.
+    /// Default: "skip"
+    #[serde(default = "default_on_error")]
+    on_row_error: String,
+}
+
+/// A typed column cut from a fixed character range.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub(crate) struct FixedWidthColumnDef {
+    #[serde(flatten)]
+    column: ColumnDef,
+    /// Zero-based character offset where the field starts.
+    start: usize,
+    /// Field width in characters.
+    width: usize,
+}
+
+fn default_trim() -> bool {
+    true
+}
+
+/// Fixed-width parsing stage: one file in, N record items out.
+#[derive(Debug)]
+pub struct FixedWidthParseStage {
+    config: FixedWidthParseConfig,
+}
+
+impl FixedWidthParseStage {
+    /// Create from stage params JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StageError::Permanent` if the config is invalid.
+    pub fn from_params(params: &serde_json::Value) -> Result<Self, StageError> {
+        let config: FixedWidthParseConfig =
+            serde_json::from_value(params.clone()).map_err(|e| StageError::Permanent {
+                stage: "fixed_width_parse".to_string(),
+                item_id: String::new(),
+                message: format!("invalid fixed_width_parse config: {e}"),
+            })?;
+        if config.columns.is_empty() {
+            return Err(StageError::Permanent {
+                stage: "fixed_width_parse".to_string(),
+                item_id: String::new(),
+                message: "invalid fixed_width_parse config: at least one column is required"
+                    .to_string(),
+            });
+        }
+        Ok(Self { config })
+    }
+
+    /// Cut the configured columns out of one line. Fields past the end of
+    /// a short line are empty.
+    fn parse_line(&self, line: &str) -> Record {
+        let chars: Vec<char> = line.chars().collect();
+        self.config
+            .columns
+            .iter()
+            .map(|col| {
+                let start = col.start.min(chars.len());
+                let end = col.start.saturating_add(col.width).min(chars.len());
+                let field: String = chars[start..end].iter().collect();
+                let field = if self.config.trim {
+                    field.trim()
+                } else {
+                    &field
+                };
+                (
+                    col.column.name.clone(),
+                    convert_value(field, &col.column.r#type),
+                )
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl Stage for FixedWidthParseStage {
+    fn name(&self) -> &str {
+        "fixed_width_parse"
+    }
+
+    async fn process(
+        &self,
+        item: PipelineItem,
+        _ctx: &StageContext,
+    ) -> Result<Vec<PipelineItem>, StageError> {
+        let mut output = Vec::new();
+
+        let lines = item.content.split(|b| *b == b'\n').enumerate();
+        for (idx, raw) in lines.skip(self.config.skip_lines) {
+            let line_number = idx + 1;
+            let raw = raw.strip_suffix(b"\r").unwrap_or(raw);
+            if raw.trim_ascii().is_empty() {
+                continue;
+            }
+            let line = match std::str::from_utf8(raw) {
+                Ok(l) => l,
+                Err(e) => {
+                    row_error(
+                        "fixed_width_parse",
+                        &self.config.on_row_error,
+                        &item,
+                        &format!("line {line_number}"),
+                        &e,
+                    )?;
+                    continue;
+                }
+            };
+
+            output.push(row_item(
+                &item,
+                ("_line_number", line_number),
+                self.parse_line(line),
+                raw.to_vec(),
+                "text/plain",
+            ));
+        }
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use ecl_pipeline_spec::PipelineSpec;
+    use ecl_pipeline_state::{Blake3Hash, ItemProvenance};
+    use serde_json::json;
+    use std::collections::BTreeMap;
+    use std::sync::Arc;
+
+    fn make_item(content: &[u8]) -> PipelineItem {
+        PipelineItem {
+            id: "file-1".to_string(),
+            display_name: "ledger.txt".to_string(),
+            content: Arc::from(content),
+            mime_type: "text/plain".to_string(),
+            source_name: "test".to_string(),
+            source_content_hash: Blake3Hash::new("abc"),
+            provenance: ItemProvenance {
+                source_kind: "filesystem".to_string(),
+                metadata: BTreeMap::new(),
+                source_modified: None,
+                extracted_at: chrono::Utc::now(),
+            },
+            metadata: BTreeMap::new(),
+            record: None,
+            stream: None,
+        }
+    }
+
+    fn ctx() -> StageContext {
+        StageContext {
+            spec: Arc::new(
+                PipelineSpec::from_toml(
+                    "name = \"t\"\nversion = 1\noutput_dir = \"./o\"\n[sources.s]\nkind = \"filesystem\"\nroot = \"/tmp\"\n[stages.x]\nadapter = \"x\"\nresources = { creates = [\"y\"] }",
+                )
+                .unwrap(),
+            ),
+            output_dir: std::path::PathBuf::from("/tmp"),
+            params: serde_json::Value::Null,
+            span: tracing::Span::none(),
+        }
+    }
+
+    fn ledger_params() -> serde_json::Value {
+        json!({
+            "skip_lines": 1,
+            "columns": [
+                {"name": "account", "start": 0, "width": 6},
+                {"name": "amount", "type": "float", "start": 6, "width": 8},
+                {"name": "count", "type": "integer", "start": 14, "width": 3},
+                {"name": "memo", "start": 17, "width": 10}
+            ]
+        })
+    }
+
+    async fn parse(
+        params: serde_json::Value,
+        content: &[u8],
+    ) -> Result<Vec<PipelineItem>, StageError> {
+        FixedWidthParseStage::from_params(&params)
+            .unwrap()
+            .process(make_item(content), &ctx())
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_fixed_width_parse_basic() {
+        let content = "ACCT  AMOUNT  CNTMEMO\n\
+                       A001    12.50  3 rent\r\n\
+                       \n\
+                       B002  1000.00 12 café\n";
+        let result = parse(ledger_params(), content.as_bytes()).await.unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(
+            result[0].record.as_ref().unwrap(),
+            json!({"account": "A001", "amount": 12.5, "count": 3, "memo": "rent"})
+                .as_object()
+                .unwrap()
+        );
+        assert_eq!(result[1].record.as_ref().unwrap()["memo"], json!("café"));
+        assert_eq!(result[1].id, "file-1:row:4");
+        assert_eq!(result[1].metadata["_line_number"], json!(4));
+        assert_eq!(result[1].metadata["_source_file"], json!("ledger.txt"));
+        assert_eq!(result[0].content.as_ref(), b"A001    12.50  3 rent");
+    }
+
+    #[tokio::test]
+    async fn test_fixed_width_parse_short_line_and_no_trim() {
+        let params = json!({
+            "trim": false,
+            "columns": [
+                {"name": "a", "start": 0, "width": 3},
+                {"name": "b", "start": 3, "width": 3},
+                {"name": "c", "start": 10, "width": 2}
+            ]
+        });
+        let result = parse(params, b" x y\n").await.unwrap();
+        assert_eq!(
+            result[0].record.as_ref().unwrap(),
+            json!({"a": " x ", "b": "y", "c": ""}).as_object().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fixed_width_parse_bad_line_skip_and_fail() {
+        let content = b"HEADER\nA001    12.50  3 rent\nB\xff\xfe\nC003     1.00  1 x\n";
+        let result = parse(ledger_params(), content).await.unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[1].metadata["_line_number"], json!(4));
+
+        let mut params = ledger_params();
+        params["on_row_error"] = json!("fail");
+        let err = parse(params, content).await.unwrap_err();
+        assert!(err.to_string().contains("line 3 parse error"));
+    }
+
+    #[test]
+    fn test_fixed_width_parse_config_requires_columns() {
+        assert!(FixedWidthParseStage::from_params(&json!({})).is_err());
+        assert!(FixedWidthParseStage::from_params(&json!({"columns": []})).is_err());
+        assert!(
+            FixedWidthParseStage::from_params(&json!({"columns": [{"name": "a", "start": 0}]}))
+                .is_err()
+        );
+    }
+}
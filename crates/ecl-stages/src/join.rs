@@ -6,6 +6,7 @@
 use std::collections::{HashMap, HashSet};
 
 use async_trait::async_trait;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use tracing::debug;
 
@@ -15,7 +16,7 @@ use ecl_pipeline_topo::{PipelineItem, Stage, StageContext};
 type Record = serde_json::Map<String, serde_json::Value>;
 
 /// Configuration for the join stage, parsed from stage params.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct JoinConfig {
     /// Join type: "inner", "left", "full".
     #[serde(default = "default_join_type")]
@@ -0,0 +1,451 @@
+//! JSON parsing stage: fan-out from a JSON or JSON Lines document →
+//! individual records.
+//!
+//! Records are selected with a JSONPath-style expression (`$`, `.key`,
+//! `['key']`, `[n]`, `[*]`, `.*`); a selected array yields one record per
+//! element. Columns use the same typed definitions as `csv_parse`, and rows
+//! that cannot be parsed are skipped or fail the item per `on_row_error`.
+
+use async_trait::async_trait;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+use ecl_pipeline_topo::error::StageError;
+use ecl_pipeline_topo::{PipelineItem, Record, Stage, StageContext};
+
+use crate::csv_parse::{ColumnDef, convert_value, default_on_error, row_error, row_item};
+
+/// Parsed from stage params TOML/JSON.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub(crate) struct JsonParseConfig {
+    /// JSONPath-style selection of the records in each document.
+    /// Default: "$" (the document itself, or its elements if it is an array)
+    #[serde(default = "default_records")]
+    records: String,
+    /// Column definitions. Default: keep every field of each record as-is.
+    #[serde(default)]
+    columns: Vec<ColumnDef>,
+    /// Parse content as JSON Lines, one document per line. Default: false
+    #[serde(default)]
+    lines: bool,
+    /// How to handle records that cannot be parsed.
+    /// "skip" = skip them (log warning), "fail" = fail the item.
+    /// Default: "skip"
+    #[serde(default = "default_on_error")]
+    on_row_error: String,
+}
+
+fn default_records() -> String {
+    "$".to_string()
+}
+
+/// One step of a record path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+    Wildcard,
+}
+
+/// Parse a JSONPath-style expression into segments.
+fn parse_path(path: &str) -> Result<Vec<Segment>, String> {
+    let rest = path
+        .trim()
+        .strip_prefix('$')
+        .ok_or_else(|| format!("path '{path}' must start with '$'"))?;
+    let chars: Vec<char> = rest.chars().collect();
+    let mut segments = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '.' => {
+                let start = i + 1;
+                let mut end = start;
+                while end < chars.len() && chars[end] != '.' && chars[end] != '[' {
+                    end += 1;
+                }
+                let key: String = chars[start..end].iter().collect();
+                match key.as_str() {
+                    "" => return Err(format!("empty key in path '{path}'")),
+                    "*" => segments.push(Segment::Wildcard),
+                    _ => segments.push(Segment::Key(key)),
+                }
+                i = end;
+            }
+            '[' => {
+                let close = chars[i..]
+                    .iter()
+                    .position(|c| *c == ']')
+                    .map(|p| i + p)
+                    .ok_or_else(|| format!("unclosed '[' in path '{path}'"))?;
+                let inner: String = chars[i + 1..close].iter().collect();
+                let inner = inner.trim();
+                let quoted = inner
+                    .strip_prefix('\'')
+                    .and_then(|s| s.strip_suffix('\''))
+                    .or_else(|| inner.strip_prefix('"').and_then(|s| s.strip_suffix('"')));
+                let segment = if inner == "*" {
+                    Segment::Wildcard
+                } else if let Some(key) = quoted {
+                    Segment::Key(key.to_string())
+                } else {
+                    Segment::Index(
+                        inner
+                            .parse()
+                            .map_err(|_| format!("invalid index '{inner}' in path '{path}'"))?,
+                    )
+                };
+                segments.push(segment);
+                i = close + 1;
+            }
+            c => return Err(format!("unexpected '{c}' in path '{path}'")),
+        }
+    }
+    Ok(segments)
+}
+
+/// Select the values `segments` point at. A selected array contributes
+/// its elements.
+fn select<'a>(document: &'a Value, segments: &[Segment]) -> Vec<&'a Value> {
+    let mut current = vec![document];
+    for segment in segments {
+        current = current
+            .into_iter()
+            .flat_map(|value| -> Vec<&Value> {
+                match (segment, value) {
+                    (Segment::Key(key), Value::Object(map)) => map.get(key).into_iter().collect(),
+                    (Segment::Index(i), Value::Array(items)) => items.get(*i).into_iter().collect(),
+                    (Segment::Wildcard, Value::Object(map)) => map.values().collect(),
+                    (Segment::Wildcard, Value::Array(items)) => items.iter().collect(),
+                    _ => Vec::new(),
+                }
+            })
+            .collect();
+    }
+    current
+        .into_iter()
+        .flat_map(|value| match value {
+            Value::Array(items) => items.iter().collect(),
+            other => vec![other],
+        })
+        .collect()
+}
+
+/// Convert an already-typed JSON value to a column type. Strings go
+/// through the same conversion as CSV fields; scalars become strings for
+/// "string" columns; anything else is kept as-is.
+fn coerce(value: Option<&Value>, col_type: &str) -> Value {
+    match value {
+        None => Value::Null,
+        Some(Value::String(s)) => convert_value(s, col_type),
+        Some(v @ (Value::Number(_) | Value::Bool(_))) if col_type == "string" => {
+            Value::String(v.to_string())
+        }
+        Some(v) => v.clone(),
+    }
+}
+
+/// JSON parsing stage: one document in, N record items out.
+#[derive(Debug)]
+pub struct JsonParseStage {
+    config: JsonParseConfig,
+    path: Vec<Segment>,
+}
+
+impl JsonParseStage {
+    /// Create from stage params JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StageError::Permanent` if the config or record path is
+    /// invalid.
+    pub fn from_params(params: &serde_json::Value) -> Result<Self, StageError> {
+        let config: JsonParseConfig =
+            serde_json::from_value(params.clone()).map_err(|e| StageError::Permanent {
+                stage: "json_parse".to_string(),
+                item_id: String::new(),
+                message: format!("invalid json_parse config: {e}"),
+            })?;
+        let path = parse_path(&config.records).map_err(|e| StageError::Permanent {
+            stage: "json_parse".to_string(),
+            item_id: String::new(),
+            message: format!("invalid json_parse config: {e}"),
+        })?;
+        Ok(Self { config, path })
+    }
+
+    /// Turn the selected records of one document into items.
+    fn emit_records(
+        &self,
+        item: &PipelineItem,
+        document: &Value,
+        line_number: Option<usize>,
+        index: &mut usize,
+        output: &mut Vec<PipelineItem>,
+    ) -> Result<(), StageError> {
+        for value in select(document, &self.path) {
+            *index += 1;
+            let index = *index;
+            let Value::Object(object) = value else {
+                let location = match line_number {
+                    Some(line) => format!("line {line}"),
+                    None => format!("record {index}"),
+                };
+                row_error(
+                    "json_parse",
+                    &self.config.on_row_error,
+                    item,
+                    &location,
+                    &format!("expected an object, found {value}"),
+                )?;
+                continue;
+            };
+
+            let record: Record = if self.config.columns.is_empty() {
+                object.clone()
+            } else {
+                self.config
+                    .columns
+                    .iter()
+                    .map(|col| (col.name.clone(), coerce(object.get(&col.name), &col.r#type)))
+                    .collect()
+            };
+            let raw = serde_json::to_vec(value).unwrap_or_default();
+
+            let mut row = row_item(
+                item,
+                ("_record_index", index),
+                record,
+                raw,
+                "application/json",
+            );
+            if let Some(line) = line_number {
+                row.metadata
+                    .insert("_line_number".to_string(), Value::from(line));
+            }
+            output.push(row);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Stage for JsonParseStage {
+    fn name(&self) -> &str {
+        "json_parse"
+    }
+
+    async fn process(
+        &self,
+        item: PipelineItem,
+        _ctx: &StageContext,
+    ) -> Result<Vec<PipelineItem>, StageError> {
+        let mut output = Vec::new();
+        let mut index = 0;
+
+        if !self.config.lines {
+            let document: Value =
+                serde_json::from_slice(&item.content).map_err(|e| StageError::Permanent {
+                    stage: "json_parse".to_string(),
+                    item_id: item.id.clone(),
+                    message: format!("invalid JSON document: {e}"),
+                })?;
+            self.emit_records(&item, &document, None, &mut index, &mut output)?;
+            return Ok(output);
+        }
+
+        for (idx, line) in item.content.split(|b| *b == b'\n').enumerate() {
+            let line_number = idx + 1;
+            if line.trim_ascii().is_empty() {
+                continue;
+            }
+            match serde_json::from_slice::<Value>(line) {
+                Ok(document) => {
+                    self.emit_records(&item, &document, Some(line_number), &mut index, &mut output)?
+                }
+                Err(e) => row_error(
+                    "json_parse",
+                    &self.config.on_row_error,
+                    &item,
+                    &format!("line {line_number}"),
+                    &e,
+                )?,
+            }
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use ecl_pipeline_spec::PipelineSpec;
+    use ecl_pipeline_state::{Blake3Hash, ItemProvenance};
+    use serde_json::json;
+    use std::collections::BTreeMap;
+    use std::sync::Arc;
+
+    fn make_item(content: &str) -> PipelineItem {
+        PipelineItem {
+            id: "file-1".to_string(),
+            display_name: "feed.json".to_string(),
+            content: Arc::from(content.as_bytes()),
+            mime_type: "application/json".to_string(),
+            source_name: "test".to_string(),
+            source_content_hash: Blake3Hash::new("abc"),
+            provenance: ItemProvenance {
+                source_kind: "filesystem".to_string(),
+                metadata: BTreeMap::new(),
+                source_modified: None,
+                extracted_at: chrono::Utc::now(),
+            },
+            metadata: BTreeMap::new(),
+            record: None,
+            stream: Some("orders".to_string()),
+        }
+    }
+
+    fn ctx() -> StageContext {
+        StageContext {
+            spec: Arc::new(
+                PipelineSpec::from_toml(
+                    "name = \"t\"\nversion = 1\noutput_dir = \"./o\"\n[sources.s]\nkind = \"filesystem\"\nroot = \"/tmp\"\n[stages.x]\nadapter = \"x\"\nresources = { creates = [\"y\"] }",
+                )
+                .unwrap(),
+            ),
+            output_dir: std::path::PathBuf::from("/tmp"),
+            params: Value::Null,
+            span: tracing::Span::none(),
+        }
+    }
+
+    async fn parse(params: Value, content: &str) -> Result<Vec<PipelineItem>, StageError> {
+        JsonParseStage::from_params(&params)
+            .unwrap()
+            .process(make_item(content), &ctx())
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_json_parse_top_level_array() {
+        let result = parse(json!({}), r#"[{"id": 1}, {"id": 2}]"#).await.unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[1].record.as_ref().unwrap()["id"], json!(2));
+        assert_eq!(result[1].id, "file-1:row:2");
+        assert_eq!(result[1].metadata["_record_index"], json!(2));
+        assert_eq!(result[1].metadata["_source_file"], json!("feed.json"));
+        assert_eq!(result[1].stream.as_deref(), Some("orders"));
+        assert_eq!(result[0].content.as_ref(), br#"{"id":1}"#);
+    }
+
+    #[tokio::test]
+    async fn test_json_parse_path_selection_and_columns() {
+        let doc = r#"{
+            "meta": {"count": 2},
+            "data": {"orders": [
+                {"id": "10", "total": 5.5, "paid": "yes", "note": "x"},
+                {"id": 11, "total": "7", "paid": false}
+            ]}
+        }"#;
+        let params = json!({
+            "records": "$.data.orders[*]",
+            "columns": [
+                {"name": "id", "type": "integer"},
+                {"name": "total", "type": "float"},
+                {"name": "paid", "type": "boolean"},
+                {"name": "note"}
+            ]
+        });
+        let result = parse(params, doc).await.unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(
+            result[0].record.as_ref().unwrap(),
+            json!({"id": 10, "total": 5.5, "paid": true, "note": "x"})
+                .as_object()
+                .unwrap()
+        );
+        assert_eq!(
+            result[1].record.as_ref().unwrap(),
+            json!({"id": 11, "total": 7.0, "paid": false, "note": null})
+                .as_object()
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_json_parse_index_and_quoted_keys() {
+        let doc = r#"{"pages": [{"the rows": [{"a": 1}]}, {"the rows": [{"a": 2}]}]}"#;
+        let result = parse(json!({"records": "$.pages[1]['the rows']"}), doc)
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].record.as_ref().unwrap()["a"], json!(2));
+
+        let result = parse(json!({"records": "$.pages.*[\"the rows\"]"}), doc)
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_json_parse_lines() {
+        let content = "{\"id\": 1}\n\n{\"id\": 2}\r\n{\"id\": 3}\n";
+        let result = parse(json!({"lines": true}), content).await.unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[2].metadata["_line_number"], json!(4));
+        assert_eq!(result[2].id, "file-1:row:3");
+    }
+
+    #[tokio::test]
+    async fn test_json_parse_lines_bad_line_skip_and_fail() {
+        let content = "{\"id\": 1}\nnot json\n{\"id\": 3}\n";
+        let result = parse(json!({"lines": true}), content).await.unwrap();
+        assert_eq!(result.len(), 2);
+
+        let err = parse(json!({"lines": true, "on_row_error": "fail"}), content)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("line 2 parse error"));
+    }
+
+    #[tokio::test]
+    async fn test_json_parse_non_object_records() {
+        let result = parse(json!({}), r#"[5, {"id": 2}]"#).await.unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id, "file-1:row:2");
+
+        let err = parse(json!({"on_row_error": "fail"}), r#"[{"id": 1}, 5]"#)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("record 2 parse error"));
+    }
+
+    #[tokio::test]
+    async fn test_json_parse_invalid_document() {
+        let err = parse(json!({}), "{").await.unwrap_err();
+        assert!(matches!(err, StageError::Permanent { .. }));
+        assert!(err.to_string().contains("invalid JSON document"));
+    }
+
+    #[test]
+    fn test_parse_path() {
+        assert_eq!(parse_path("$").unwrap(), vec![]);
+        assert_eq!(
+            parse_path("$.a[0][*].*['b c']").unwrap(),
+            vec![
+                Segment::Key("a".to_string()),
+                Segment::Index(0),
+                Segment::Wildcard,
+                Segment::Wildcard,
+                Segment::Key("b c".to_string()),
+            ]
+        );
+        assert!(parse_path("a.b").is_err());
+        assert!(parse_path("$.a[").is_err());
+        assert!(parse_path("$.a[x]").is_err());
+        assert!(parse_path("$..a").is_err());
+        assert!(JsonParseStage::from_params(&json!({"records": "items"})).is_err());
+    }
+}
@@ -3,6 +3,9 @@
 //! Provides stages for extraction, transformation, and output:
 //! - [`ExtractStage`] — delegates to a `SourceAdapter` to fetch content
 //! - [`CsvParseStage`] — parses CSV content into structured records (fan-out)
+//! - [`JsonParseStage`] — parses JSON / JSON Lines into records selected by a JSONPath-style expression (fan-out)
+//! - [`XmlParseStage`] — parses XML elements at a path into records, mapping text and attributes (fan-out)
+//! - [`FixedWidthParseStage`] — parses fixed-width text into records by column offsets (fan-out)
 //! - [`NormalizeStage`] — passthrough (placeholder for future format conversion)
 //! - [`FilterStage`] — glob-based include/exclude filtering
 //! - [`FieldMapStage`] — field renaming, date parsing, padding, regex extraction
@@ -13,8 +16,17 @@
 //! - [`DateParseStage`] — date string parsing to RFC3339 format
 //! - [`TimezoneStage`] — local datetime to UTC conversion via ZIP code lookup
 //! - [`DecompressStage`] — ZIP/GZIP archive extraction (fan-out)
+//! - [`PgpDecryptStage`] — OpenPGP decryption with secret-resolved keys and signature verification
 //! - [`AssembleStage`] — batch merging of multiple streams into nested structures
+//! - [`SerializeStage`] — batch encoding of records into CSV, JSON Lines, Parquet or Avro files
 //! - [`EmitStage`] — writes pipeline items to the output directory
+//!
+//! [`StageCatalog`] registers all of them by adapter name, with a JSON
+//! Schema for each stage's params.
+//!
+//! # Features
+//!
+//! - `test-utils`: Export OpenPGP key fixtures for downstream tests
 
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
@@ -24,6 +36,8 @@
 
 pub mod aggregate;
 pub mod assemble;
+pub mod avro;
+pub mod catalog;
 pub mod csv_parse;
 pub mod date_parse;
 pub mod decompress;
@@ -31,14 +45,22 @@ pub mod emit;
 pub mod extract;
 pub mod field_map;
 pub mod filter;
+pub mod fixed_width_parse;
 pub mod join;
+pub mod json_parse;
 pub mod lookup;
 pub mod normalize;
+pub mod pgp_decrypt;
+pub mod serialize;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_keys;
 pub mod timezone;
 pub mod validate;
+pub mod xml_parse;
 
 pub use aggregate::AggregateStage;
 pub use assemble::AssembleStage;
+pub use catalog::{BuildContext, BuildFn, StageCatalog, StageEntry};
 pub use csv_parse::CsvParseStage;
 pub use date_parse::DateParseStage;
 pub use decompress::DecompressStage;
@@ -46,8 +68,13 @@ pub use emit::EmitStage;
 pub use extract::ExtractStage;
 pub use field_map::FieldMapStage;
 pub use filter::FilterStage;
+pub use fixed_width_parse::FixedWidthParseStage;
 pub use join::JoinStage;
+pub use json_parse::JsonParseStage;
 pub use lookup::LookupStage;
 pub use normalize::NormalizeStage;
+pub use pgp_decrypt::PgpDecryptStage;
+pub use serialize::SerializeStage;
 pub use timezone::TimezoneStage;
 pub use validate::ValidateStage;
+pub use xml_parse::XmlParseStage;
@@ -7,6 +7,7 @@
 use std::collections::HashMap;
 
 use async_trait::async_trait;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde_json::Value;
 use tracing::debug;
@@ -17,14 +18,14 @@ use ecl_pipeline_topo::{PipelineItem, Stage, StageContext};
 type Record = serde_json::Map<String, serde_json::Value>;
 
 /// Configuration for the lookup stage, parsed from stage params.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct LookupConfig {
     /// List of lookup operations to apply.
     pub lookups: Vec<LookupOp>,
 }
 
 /// A single lookup operation mapping values from one field to another.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct LookupOp {
     /// Source field to look up.
     pub field: String,
@@ -0,0 +1,585 @@
+//! PGP decrypt stage: decrypts OpenPGP-encrypted items (1:1).
+//!
+//! The private key, its passphrase and any signer public keys are named
+//! secrets, resolved once through the pipeline's `SecretResolver` on first
+//! use. Accepts armored and binary messages, decompresses compressed
+//! payloads, and strips the `.pgp`/`.gpg`/`.asc` suffix from the item ID and
+//! display name so the MIME type reflects the decrypted file.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use pgp::composed::{Deserializable, Message, SignedPublicKey, SignedSecretKey};
+use pgp::types::{KeyDetails, Password, VerifyingKey};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use tokio::sync::OnceCell;
+use tracing::debug;
+
+use ecl_pipeline_topo::error::StageError;
+use ecl_pipeline_topo::{PipelineItem, Stage, StageContext};
+use ecl_secrets::{SecretError, SecretResolver};
+
+use crate::decompress::mime_from_extension;
+
+/// Configuration for the PGP decrypt stage, parsed from stage params.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct PgpDecryptConfig {
+    /// Secret holding the private key (armored or binary).
+    pub private_key_secret: String,
+    /// Secret holding the private key's passphrase (omit for unprotected keys).
+    #[serde(default)]
+    pub passphrase_secret: Option<String>,
+    /// Secrets holding public keys whose signatures are accepted.
+    /// Signed messages must verify against one of them.
+    #[serde(default)]
+    pub verify_key_secrets: Vec<String>,
+    /// Reject unsigned messages when `verify_key_secrets` is set. Default: true.
+    #[serde(default = "default_true")]
+    pub require_signature: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Keys resolved from secrets on first use.
+struct Keys {
+    secret: SignedSecretKey,
+    passphrase: Password,
+    verify: Vec<SignedPublicKey>,
+}
+
+/// PGP decrypt stage that decrypts each item with a configured private key.
+pub struct PgpDecryptStage {
+    config: PgpDecryptConfig,
+    resolver: Arc<dyn SecretResolver>,
+    keys: OnceCell<Keys>,
+}
+
+impl std::fmt::Debug for PgpDecryptStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PgpDecryptStage")
+            .field("config", &self.config)
+            .field("resolver", &self.resolver)
+            .field("keys_loaded", &self.keys.initialized())
+            .finish()
+    }
+}
+
+impl PgpDecryptStage {
+    /// Create a PGP decrypt stage from JSON params, resolving keys through
+    /// `resolver`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StageError::Permanent` if params cannot be deserialized.
+    pub fn from_params(
+        params: &serde_json::Value,
+        resolver: Arc<dyn SecretResolver>,
+    ) -> Result<Self, StageError> {
+        let config: PgpDecryptConfig =
+            serde_json::from_value(params.clone()).map_err(|e| StageError::Permanent {
+                stage: "pgp_decrypt".into(),
+                item_id: String::new(),
+                message: format!("invalid pgp_decrypt config: {e}"),
+            })?;
+
+        Ok(Self {
+            config,
+            resolver,
+            keys: OnceCell::new(),
+        })
+    }
+
+    /// Resolve and parse the configured keys.
+    async fn load_keys(&self, item_id: &str) -> Result<Keys, StageError> {
+        let key_bytes = self
+            .resolve(&self.config.private_key_secret, item_id)
+            .await?;
+        let (secret, _) = SignedSecretKey::from_reader_single(key_bytes.as_slice())
+            .map_err(|e| permanent(item_id, format!("invalid private key: {e}")))?;
+
+        let passphrase = match &self.config.passphrase_secret {
+            Some(name) => Password::from(self.resolve(name, item_id).await?.as_slice()),
+            None => Password::empty(),
+        };
+
+        let mut verify = Vec::with_capacity(self.config.verify_key_secrets.len());
+        for name in &self.config.verify_key_secrets {
+            let bytes = self.resolve(name, item_id).await?;
+            let (key, _) = SignedPublicKey::from_reader_single(bytes.as_slice())
+                .map_err(|e| permanent(item_id, format!("invalid public key '{name}': {e}")))?;
+            verify.push(key);
+        }
+
+        debug!(
+            key = %secret.fingerprint(),
+            verify_keys = verify.len(),
+            "loaded PGP keys"
+        );
+
+        Ok(Keys {
+            secret,
+            passphrase,
+            verify,
+        })
+    }
+
+    async fn resolve(&self, name: &str, item_id: &str) -> Result<Vec<u8>, StageError> {
+        self.resolver.resolve(name).await.map_err(|e| match e {
+            SecretError::Provider { .. } => StageError::Transient {
+                stage: "pgp_decrypt".into(),
+                item_id: item_id.to_string(),
+                message: e.to_string(),
+            },
+            _ => permanent(item_id, e.to_string()),
+        })
+    }
+
+    /// Decrypt `item`'s content, returning the plaintext and the fingerprint
+    /// of the key that verified its signature, if any.
+    fn decrypt(
+        &self,
+        keys: &Keys,
+        item: &PipelineItem,
+    ) -> Result<(Vec<u8>, Option<String>), StageError> {
+        let content = item.content.as_ref();
+        let message = if is_armored(content) {
+            Message::from_armor(content).map(|(message, _)| message)
+        } else {
+            Message::from_bytes(content)
+        }
+        .map_err(|e| StageError::UnsupportedContent {
+            stage: "pgp_decrypt".into(),
+            item_id: item.id.clone(),
+            message: format!("not an OpenPGP message: {e}"),
+        })?;
+
+        let mut message = message
+            .decrypt(&keys.passphrase, &keys.secret)
+            .map_err(|e| permanent(&item.id, format!("decryption failed: {e}")))?;
+        if message.is_compressed() {
+            message = message
+                .decompress()
+                .map_err(|e| permanent(&item.id, format!("decompression failed: {e}")))?;
+        }
+        let plaintext = message
+            .as_data_vec()
+            .map_err(|e| permanent(&item.id, format!("failed to read plaintext: {e}")))?;
+
+        if keys.verify.is_empty() {
+            return Ok((plaintext, None));
+        }
+        if !message.is_signed() {
+            if self.config.require_signature {
+                return Err(permanent(&item.id, "message is not signed".to_string()));
+            }
+            return Ok((plaintext, None));
+        }
+
+        // Signatures may come from a primary key or a signing subkey.
+        let candidates: Vec<(&SignedPublicKey, &dyn VerifyingKey)> = keys
+            .verify
+            .iter()
+            .flat_map(|key| {
+                std::iter::once((key, key as &dyn VerifyingKey)).chain(
+                    key.public_subkeys
+                        .iter()
+                        .map(move |sub| (key, sub as &dyn VerifyingKey)),
+                )
+            })
+            .collect();
+        let verifiers: Vec<&dyn VerifyingKey> = candidates.iter().map(|(_, v)| *v).collect();
+        let results = message
+            .verify_nested(&verifiers)
+            .map_err(|e| permanent(&item.id, format!("signature check failed: {e}")))?;
+        let signer = candidates
+            .iter()
+            .zip(results)
+            .find(|(_, result)| matches!(result, pgp::composed::VerificationResult::Valid(_)))
+            .map(|((key, _), _)| key.fingerprint().to_string())
+            .ok_or_else(|| {
+                permanent(
+                    &item.id,
+                    "signature does not match any configured public key".to_string(),
+                )
+            })?;
+
+        Ok((plaintext, Some(signer)))
+    }
+}
+
+fn permanent(item_id: &str, message: String) -> StageError {
+    StageError::Permanent {
+        stage: "pgp_decrypt".into(),
+        item_id: item_id.to_string(),
+        message,
+    }
+}
+
+/// Whether `content` is an ASCII-armored OpenPGP block.
+fn is_armored(content: &[u8]) -> bool {
+    content.trim_ascii_start().starts_with(b"-----BEGIN PGP")
+}
+
+/// Strip a trailing `.pgp`, `.gpg` or `.asc` (any case) from `name`.
+fn strip_pgp_suffix(name: &str) -> &str {
+    match name.rsplit_once('.') {
+        Some((stem, ext))
+            if !stem.is_empty()
+                && ["pgp", "gpg", "asc"]
+                    .iter()
+                    .any(|s| ext.eq_ignore_ascii_case(s)) =>
+        {
+            stem
+        }
+        _ => name,
+    }
+}
+
+#[async_trait]
+impl Stage for PgpDecryptStage {
+    fn name(&self) -> &str {
+        "pgp_decrypt"
+    }
+
+    async fn process(
+        &self,
+        item: PipelineItem,
+        _ctx: &StageContext,
+    ) -> Result<Vec<PipelineItem>, StageError> {
+        let keys = self
+            .keys
+            .get_or_try_init(|| self.load_keys(&item.id))
+            .await?;
+        let (plaintext, signer) = self.decrypt(keys, &item)?;
+
+        let display_name = strip_pgp_suffix(&item.display_name).to_string();
+        let mut metadata = item.metadata;
+        if let Some(signer) = signer {
+            metadata.insert("pgp_signer".to_string(), serde_json::Value::String(signer));
+        }
+
+        debug!(
+            item_id = %item.id,
+            output_size = plaintext.len(),
+            "decrypted PGP message"
+        );
+
+        Ok(vec![PipelineItem {
+            id: strip_pgp_suffix(&item.id).to_string(),
+            mime_type: mime_from_extension(&display_name),
+            display_name,
+            content: Arc::from(plaintext),
+            source_name: item.source_name,
+            source_content_hash: item.source_content_hash,
+            provenance: item.provenance,
+            metadata,
+            record: item.record,
+            stream: item.stream,
+        }])
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::test_keys::keygen;
+    use ecl_pipeline_spec::PipelineSpec;
+    use ecl_pipeline_state::{Blake3Hash, ItemProvenance};
+    use pgp::composed::{ArmorOptions, MessageBuilder};
+    use pgp::crypto::hash::HashAlgorithm;
+    use pgp::crypto::sym::SymmetricKeyAlgorithm;
+    use pgp::ser::Serialize as _;
+    use serde_json::json;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[derive(Debug, Default)]
+    struct MockResolver(HashMap<String, Vec<u8>>);
+
+    #[async_trait]
+    impl SecretResolver for MockResolver {
+        async fn resolve(&self, name: &str) -> Result<Vec<u8>, SecretError> {
+            self.0
+                .get(name)
+                .cloned()
+                .ok_or_else(|| SecretError::NotFound {
+                    name: name.to_string(),
+                })
+        }
+    }
+
+    /// Encrypt `data` to `recipient`, optionally signing with `signer`.
+    fn encrypt(
+        data: &[u8],
+        recipient: &SignedSecretKey,
+        signer: Option<(&SignedSecretKey, &str)>,
+        armor: bool,
+    ) -> Vec<u8> {
+        let public = recipient.to_public_key();
+        let mut builder = MessageBuilder::from_bytes("", data.to_vec())
+            .seipd_v1(rand::thread_rng(), SymmetricKeyAlgorithm::AES256);
+        builder
+            .encrypt_to_key(rand::thread_rng(), &public.public_subkeys[0])
+            .unwrap();
+        if let Some((key, passphrase)) = signer {
+            builder.sign(&key.primary_key, passphrase.into(), HashAlgorithm::Sha256);
+        }
+        if armor {
+            builder
+                .to_armored_string(rand::thread_rng(), ArmorOptions::default())
+                .unwrap()
+                .into_bytes()
+        } else {
+            builder.to_vec(rand::thread_rng()).unwrap()
+        }
+    }
+
+    fn resolver(entries: &[(&str, Vec<u8>)]) -> Arc<dyn SecretResolver> {
+        Arc::new(MockResolver(
+            entries
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.clone()))
+                .collect(),
+        ))
+    }
+
+    fn public_bytes(key: &SignedSecretKey) -> Vec<u8> {
+        key.to_public_key().to_bytes().unwrap()
+    }
+
+    fn make_item(id: &str, content: &[u8]) -> PipelineItem {
+        PipelineItem {
+            id: id.to_string(),
+            display_name: id.to_string(),
+            content: Arc::from(content),
+            mime_type: "application/pgp-encrypted".to_string(),
+            source_name: "sftp".to_string(),
+            source_content_hash: Blake3Hash::new("test"),
+            provenance: ItemProvenance {
+                source_kind: "sftp".to_string(),
+                metadata: BTreeMap::new(),
+                source_modified: None,
+                extracted_at: chrono::Utc::now(),
+            },
+            metadata: BTreeMap::new(),
+            record: None,
+            stream: Some("daily".to_string()),
+        }
+    }
+
+    fn ctx() -> StageContext {
+        StageContext {
+            spec: Arc::new(PipelineSpec {
+                name: "test".to_string(),
+                version: 1,
+                output_dir: std::path::PathBuf::from("./out"),
+                sources: BTreeMap::new(),
+                stages: BTreeMap::new(),
+                defaults: ecl_pipeline_spec::DefaultsSpec::default(),
+                lifecycle: None,
+                secrets: Default::default(),
+                triggers: None,
+                schedule: None,
+            }),
+            output_dir: std::path::PathBuf::from("./out"),
+            params: serde_json::Value::Null,
+            span: tracing::Span::none(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pgp_decrypt_binary_message() {
+        let key = keygen("Us <us@example.com>", None);
+        let encrypted = encrypt(b"id,amount\n1,10\n", &key, None, false);
+        let stage = PgpDecryptStage::from_params(
+            &json!({ "private_key_secret": "key" }),
+            resolver(&[("key", key.to_bytes().unwrap())]),
+        )
+        .unwrap();
+
+        let result = stage
+            .process(make_item("drop/daily.csv.pgp", &encrypted), &ctx())
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].content.as_ref(), b"id,amount\n1,10\n");
+        assert_eq!(result[0].id, "drop/daily.csv");
+        assert_eq!(result[0].display_name, "drop/daily.csv");
+        assert_eq!(result[0].mime_type, "text/csv");
+        assert_eq!(result[0].stream.as_deref(), Some("daily"));
+        assert!(!result[0].metadata.contains_key("pgp_signer"));
+    }
+
+    #[tokio::test]
+    async fn test_pgp_decrypt_armored_message_with_passphrase() {
+        let key = keygen("Us <us@example.com>", Some("hunter2"));
+        let encrypted = encrypt(b"hello", &key, None, true);
+        let armored_key = key.to_armored_bytes(ArmorOptions::default()).unwrap();
+        let stage = PgpDecryptStage::from_params(
+            &json!({ "private_key_secret": "key", "passphrase_secret": "pass" }),
+            resolver(&[("key", armored_key), ("pass", b"hunter2".to_vec())]),
+        )
+        .unwrap();
+
+        let result = stage
+            .process(make_item("notes.txt.GPG", &encrypted), &ctx())
+            .await
+            .unwrap();
+        assert_eq!(result[0].content.as_ref(), b"hello");
+        assert_eq!(result[0].id, "notes.txt");
+        assert_eq!(result[0].mime_type, "text/plain");
+    }
+
+    #[tokio::test]
+    async fn test_pgp_decrypt_wrong_passphrase_fails() {
+        let key = keygen("Us <us@example.com>", Some("hunter2"));
+        let encrypted = encrypt(b"hello", &key, None, false);
+        let stage = PgpDecryptStage::from_params(
+            &json!({ "private_key_secret": "key", "passphrase_secret": "pass" }),
+            resolver(&[("key", key.to_bytes().unwrap()), ("pass", b"nope".to_vec())]),
+        )
+        .unwrap();
+
+        let err = stage
+            .process(make_item("a.csv.pgp", &encrypted), &ctx())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, StageError::Permanent { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_pgp_decrypt_verifies_signature() {
+        let ours = keygen("Us <us@example.com>", None);
+        let partner = keygen("Partner <partner@example.com>", None);
+        let encrypted = encrypt(b"signed", &ours, Some((&partner, "")), false);
+        let stage = PgpDecryptStage::from_params(
+            &json!({ "private_key_secret": "key", "verify_key_secrets": ["partner"] }),
+            resolver(&[
+                ("key", ours.to_bytes().unwrap()),
+                ("partner", public_bytes(&partner)),
+            ]),
+        )
+        .unwrap();
+
+        let result = stage
+            .process(make_item("a.csv.pgp", &encrypted), &ctx())
+            .await
+            .unwrap();
+        assert_eq!(result[0].content.as_ref(), b"signed");
+        assert_eq!(
+            result[0].metadata["pgp_signer"],
+            json!(partner.fingerprint().to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pgp_decrypt_rejects_unknown_signer() {
+        let ours = keygen("Us <us@example.com>", None);
+        let partner = keygen("Partner <partner@example.com>", None);
+        let stranger = keygen("Stranger <x@example.com>", None);
+        let encrypted = encrypt(b"signed", &ours, Some((&stranger, "")), false);
+        let stage = PgpDecryptStage::from_params(
+            &json!({ "private_key_secret": "key", "verify_key_secrets": ["partner"] }),
+            resolver(&[
+                ("key", ours.to_bytes().unwrap()),
+                ("partner", public_bytes(&partner)),
+            ]),
+        )
+        .unwrap();
+
+        let err = stage
+            .process(make_item("a.csv.pgp", &encrypted), &ctx())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("does not match"));
+    }
+
+    #[tokio::test]
+    async fn test_pgp_decrypt_unsigned_message_with_verify_keys() {
+        let ours = keygen("Us <us@example.com>", None);
+        let partner = keygen("Partner <partner@example.com>", None);
+        let encrypted = encrypt(b"unsigned", &ours, None, false);
+        let secrets = [
+            ("key", ours.to_bytes().unwrap()),
+            ("partner", public_bytes(&partner)),
+        ];
+
+        let strict = PgpDecryptStage::from_params(
+            &json!({ "private_key_secret": "key", "verify_key_secrets": ["partner"] }),
+            resolver(&secrets),
+        )
+        .unwrap();
+        let err = strict
+            .process(make_item("a.csv.pgp", &encrypted), &ctx())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("not signed"));
+
+        let lenient = PgpDecryptStage::from_params(
+            &json!({
+                "private_key_secret": "key",
+                "verify_key_secrets": ["partner"],
+                "require_signature": false
+            }),
+            resolver(&secrets),
+        )
+        .unwrap();
+        let result = lenient
+            .process(make_item("a.csv.pgp", &encrypted), &ctx())
+            .await
+            .unwrap();
+        assert_eq!(result[0].content.as_ref(), b"unsigned");
+    }
+
+    #[tokio::test]
+    async fn test_pgp_decrypt_non_pgp_content() {
+        let key = keygen("Us <us@example.com>", None);
+        let stage = PgpDecryptStage::from_params(
+            &json!({ "private_key_secret": "key" }),
+            resolver(&[("key", key.to_bytes().unwrap())]),
+        )
+        .unwrap();
+
+        let err = stage
+            .process(make_item("a.csv", b"id,amount\n"), &ctx())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, StageError::UnsupportedContent { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_pgp_decrypt_missing_secret() {
+        let stage =
+            PgpDecryptStage::from_params(&json!({ "private_key_secret": "key" }), resolver(&[]))
+                .unwrap();
+        let err = stage
+            .process(make_item("a.csv.pgp", b"data"), &ctx())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("secret not found: key"));
+    }
+
+    #[test]
+    fn test_pgp_decrypt_requires_private_key_secret() {
+        let err = PgpDecryptStage::from_params(&json!({}), resolver(&[])).unwrap_err();
+        assert!(err.to_string().contains("private_key_secret"));
+    }
+
+    #[test]
+    fn test_strip_pgp_suffix() {
+        assert_eq!(strip_pgp_suffix("daily.csv.pgp"), "daily.csv");
+        assert_eq!(strip_pgp_suffix("daily.csv.gpg"), "daily.csv");
+        assert_eq!(strip_pgp_suffix("daily.csv.ASC"), "daily.csv");
+        assert_eq!(strip_pgp_suffix("daily.csv"), "daily.csv");
+        assert_eq!(strip_pgp_suffix(".pgp"), ".pgp");
+    }
+
+    #[test]
+    fn test_is_armored() {
+        assert!(is_armored(b"\n  -----BEGIN PGP MESSAGE-----\n"));
+        assert!(!is_armored(&[0x85, 0x01, 0x0c]));
+    }
+}
@@ -0,0 +1,1034 @@
+//! Serialize stage: writes the records of each stream into output files.
+//!
+//! A batch stage that groups records by stream and encodes each group as
+//! CSV, JSON Lines, Parquet or an Avro container file. A group rolls over
+//! to a new file after `max_rows` records, or once the file reaches
+//! `max_bytes`. Emits one item per file, whose content is the encoded file,
+//! so `emit` or a file sink can deliver it.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use apache_avro::Schema as AvroSchema;
+use arrow_array::{ArrayRef, BooleanArray, Float64Array, Int64Array, RecordBatch, StringArray};
+use arrow_schema::{DataType, Field, Schema as ArrowSchema, SchemaRef};
+use async_trait::async_trait;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+use tracing::debug;
+
+use ecl_pipeline_topo::error::StageError;
+use ecl_pipeline_topo::{PipelineItem, Record, Stage, StageContext};
+
+use crate::avro::{load_schema_json, parse_schema, record_to_avro};
+use crate::csv_parse::{ColumnDef, convert_value};
+
+/// Parsed from stage params TOML/JSON.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub(crate) struct SerializeConfig {
+    /// Output format: "csv", "jsonl", "parquet" or "avro".
+    format: SerializeFormat,
+    /// Columns to write, in order. CSV and JSON Lines use the names;
+    /// Parquet also uses the types. Default: every field, in first-seen
+    /// order, with Parquet types inferred from the values.
+    #[serde(default)]
+    columns: Vec<ColumnDef>,
+    /// Output file name. Placeholders: `{stream}`, `{part}` (1-based, four
+    /// digits) and `{ext}`. Default: `"{stream}-{part}.{ext}"`
+    #[serde(default = "default_file_name")]
+    file_name: String,
+    /// CSV field delimiter. Default: ','
+    #[serde(default = "default_delimiter")]
+    delimiter: char,
+    /// Write a CSV header row at the top of each file. Default: true
+    #[serde(default = "default_has_headers")]
+    has_headers: bool,
+    /// Inline Avro schema JSON (required for "avro" unless
+    /// `avro_schema_file` is set).
+    #[serde(default)]
+    avro_schema: Option<String>,
+    /// Path to a `.avsc` file on disk.
+    #[serde(default)]
+    avro_schema_file: Option<String>,
+    /// Start a new file after this many records.
+    #[serde(default)]
+    max_rows: Option<usize>,
+    /// Start a new file once the current one reaches this many bytes.
+    /// Parquet sizes are checked per batch of 1024 rows.
+    #[serde(default)]
+    max_bytes: Option<u64>,
+}
+
+/// Output file format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+enum SerializeFormat {
+    Csv,
+    Jsonl,
+    Parquet,
+    Avro,
+}
+
+impl SerializeFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Jsonl => "jsonl",
+            Self::Parquet => "parquet",
+            Self::Avro => "avro",
+        }
+    }
+
+    fn mime_type(self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv",
+            Self::Jsonl => "application/x-ndjson",
+            Self::Parquet => "application/vnd.apache.parquet",
+            Self::Avro => "application/avro",
+        }
+    }
+}
+
+fn default_file_name() -> String {
+    "{stream}-{part}.{ext}".to_string()
+}
+fn default_delimiter() -> char {
+    ','
+}
+fn default_has_headers() -> bool {
+    true
+}
+
+/// Rows buffered before Parquet encodes a record batch.
+const PARQUET_BATCH_ROWS: usize = 1024;
+
+/// Serialize stage: many record items in, one item per output file out.
+///
+/// This is a batch stage (`requires_batch() -> true`).
+#[derive(Debug)]
+pub struct SerializeStage {
+    config: SerializeConfig,
+    avro_schema: Option<AvroSchema>,
+}
+
+impl SerializeStage {
+    /// Create from stage params JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StageError::Permanent` if the config is invalid or the Avro
+    /// schema cannot be loaded.
+    pub fn from_params(params: &serde_json::Value) -> Result<Self, StageError> {
+        let config: SerializeConfig = serde_json::from_value(params.clone()).map_err(invalid)?;
+
+        let rolls = config.max_rows.is_some() || config.max_bytes.is_some();
+        if rolls && !config.file_name.contains("{part}") {
+            return Err(invalid(
+                "file_name must contain {part} when max_rows or max_bytes is set",
+            ));
+        }
+        if config.max_rows == Some(0) || config.max_bytes == Some(0) {
+            return Err(invalid("max_rows and max_bytes must be positive"));
+        }
+
+        let avro_schema = if config.format == SerializeFormat::Avro {
+            let json = load_schema_json(
+                config.avro_schema.as_deref(),
+                config.avro_schema_file.as_deref(),
+            )
+            .map_err(invalid)?;
+            Some(parse_schema(&json).map_err(invalid)?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            config,
+            avro_schema,
+        })
+    }
+
+    /// Encode one stream's records into one or more files.
+    fn serialize_stream(
+        &self,
+        stream: &str,
+        items: &[(PipelineItem, Record)],
+    ) -> Result<Vec<PipelineItem>, StageError> {
+        let records: Vec<&Record> = items.iter().map(|(_, record)| record).collect();
+        let columns = if self.config.columns.is_empty() {
+            infer_columns(&records)
+        } else {
+            self.config.columns.clone()
+        };
+
+        let mut files = Vec::new();
+        let mut encoder: Option<Box<dyn Encoder + '_>> = None;
+        let mut rows = 0;
+        for (item, record) in items {
+            let current = match &mut encoder {
+                Some(current) => current,
+                None => encoder.insert(self.encoder(&columns)?),
+            };
+            current
+                .write(record)
+                .map_err(|message| permanent(&item.id, message))?;
+            rows += 1;
+
+            let full = self.config.max_rows.is_some_and(|max| rows >= max)
+                || self
+                    .config
+                    .max_bytes
+                    .is_some_and(|max| current.size() >= max);
+            if full && let Some(done) = encoder.take() {
+                files.push(self.finish(done, stream, files.len() + 1, rows, &item.id)?);
+                rows = 0;
+            }
+        }
+        if let Some(done) = encoder {
+            let last_id = items.last().map(|(item, _)| item.id.as_str()).unwrap_or("");
+            files.push(self.finish(done, stream, files.len() + 1, rows, last_id)?);
+        }
+
+        // All files of a stream inherit provenance from its first item.
+        let Some((first, _)) = items.first() else {
+            return Ok(Vec::new());
+        };
+        Ok(files
+            .into_iter()
+            .map(|(name, content, row_count)| {
+                let mut metadata = BTreeMap::new();
+                metadata.insert("row_count".to_string(), Value::from(row_count));
+                PipelineItem {
+                    id: name.clone(),
+                    display_name: name,
+                    content: Arc::from(content),
+                    mime_type: self.config.format.mime_type().to_string(),
+                    source_name: first.source_name.clone(),
+                    source_content_hash: first.source_content_hash.clone(),
+                    provenance: first.provenance.clone(),
+                    metadata,
+                    record: None,
+                    stream: first.stream.clone(),
+                }
+            })
+            .collect())
+    }
+
+    fn encoder<'a>(&'a self, columns: &[ColumnDef]) -> Result<Box<dyn Encoder + 'a>, StageError> {
+        Ok(match self.config.format {
+            SerializeFormat::Csv => Box::new(CsvEncoder::new(
+                columns,
+                self.config.delimiter,
+                self.config.has_headers,
+            )?),
+            SerializeFormat::Jsonl => Box::new(JsonlEncoder::new(columns)),
+            SerializeFormat::Parquet => Box::new(ParquetEncoder::new(columns)?),
+            SerializeFormat::Avro => {
+                let schema = self
+                    .avro_schema
+                    .as_ref()
+                    .ok_or_else(|| invalid("avro format requires an Avro schema"))?;
+                Box::new(AvroEncoder::new(schema, self.config.max_bytes.is_some()))
+            }
+        })
+    }
+
+    /// Close `encoder` and name its file.
+    fn finish(
+        &self,
+        encoder: Box<dyn Encoder + '_>,
+        stream: &str,
+        part: usize,
+        rows: usize,
+        item_id: &str,
+    ) -> Result<(String, Vec<u8>, usize), StageError> {
+        let content = encoder
+            .finish()
+            .map_err(|message| permanent(item_id, message))?;
+        let name = self
+            .config
+            .file_name
+            .replace("{stream}", stream)
+            .replace("{part}", &format!("{part:04}"))
+            .replace("{ext}", self.config.format.extension());
+        debug!(file = %name, rows, bytes = content.len(), "serialize: wrote file");
+        Ok((name, content, rows))
+    }
+}
+
+fn permanent(item_id: &str, message: String) -> StageError {
+    StageError::Permanent {
+        stage: "serialize".to_string(),
+        item_id: item_id.to_string(),
+        message,
+    }
+}
+
+fn invalid(message: impl std::fmt::Display) -> StageError {
+    permanent("", format!("invalid serialize config: {message}"))
+}
+
+/// Column names in first-seen order, typed by the values they hold.
+fn infer_columns(records: &[&Record]) -> Vec<ColumnDef> {
+    let mut columns: Vec<ColumnDef> = Vec::new();
+    let mut index: BTreeMap<String, usize> = BTreeMap::new();
+    let mut kinds: Vec<Option<&'static str>> = Vec::new();
+
+    for record in records {
+        for (name, value) in record.iter() {
+            let i = *index.entry(name.clone()).or_insert_with(|| {
+                columns.push(ColumnDef {
+                    name: name.clone(),
+                    r#type: String::new(),
+                });
+                kinds.push(None);
+                columns.len() - 1
+            });
+            let kind = match value {
+                Value::Null => continue,
+                Value::Bool(_) => "boolean",
+                Value::Number(n) if n.is_i64() => "integer",
+                Value::Number(_) => "float",
+                _ => "string",
+            };
+            kinds[i] = Some(match (kinds[i], kind) {
+                (None, kind) => kind,
+                (Some(a), b) if a == b => a,
+                (Some("integer"), "float") | (Some("float"), "integer") => "float",
+                _ => "string",
+            });
+        }
+    }
+
+    for (column, kind) in columns.iter_mut().zip(kinds) {
+        column.r#type = kind.unwrap_or("string").to_string();
+    }
+    columns
+}
+
+/// A field's value as CSV text. Nulls and missing fields are empty;
+/// nested values are written as JSON.
+fn text(value: Option<&Value>) -> Option<String> {
+    match value {
+        None | Some(Value::Null) => None,
+        Some(Value::String(s)) => Some(s.clone()),
+        Some(v) => Some(v.to_string()),
+    }
+}
+
+/// One output file being written.
+trait Encoder {
+    /// Append a record.
+    fn write(&mut self, record: &Record) -> Result<(), String>;
+    /// Bytes written so far (approximate for Parquet).
+    fn size(&self) -> u64;
+    /// Complete the file and return its bytes.
+    fn finish(self: Box<Self>) -> Result<Vec<u8>, String>;
+}
+
+struct CsvEncoder {
+    names: Vec<String>,
+    writer: csv::Writer<Vec<u8>>,
+}
+
+impl CsvEncoder {
+    fn new(columns: &[ColumnDef], delimiter: char, has_headers: bool) -> Result<Self, StageError> {
+        let names: Vec<String> = columns.iter().map(|c| c.name.clone()).collect();
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(delimiter as u8)
+            .from_writer(Vec::new());
+        if has_headers {
+            writer
+                .write_record(&names)
+                .map_err(|e| permanent("", format!("failed to write CSV header: {e}")))?;
+        }
+        Ok(Self { names, writer })
+    }
+}
+
+impl Encoder for CsvEncoder {
+    fn write(&mut self, record: &Record) -> Result<(), String> {
+        let row = self
+            .names
+            .iter()
+            .map(|name| text(record.get(name)).unwrap_or_default());
+        self.writer
+            .write_record(row)
+            .map_err(|e| format!("failed to write CSV row: {e}"))?;
+        // Flush so `size()` sees the row; the underlying Vec makes this cheap.
+        self.writer
+            .flush()
+            .map_err(|e| format!("failed to write CSV row: {e}"))
+    }
+
+    fn size(&self) -> u64 {
+        self.writer.get_ref().len() as u64
+    }
+
+    fn finish(self: Box<Self>) -> Result<Vec<u8>, String> {
+        self.writer
+            .into_inner()
+            .map_err(|e| format!("failed to finish CSV file: {e}"))
+    }
+}
+
+struct JsonlEncoder {
+    /// Fields to keep, in order (`None` = whole record).
+    names: Option<Vec<String>>,
+    buffer: Vec<u8>,
+}
+
+impl JsonlEncoder {
+    fn new(columns: &[ColumnDef]) -> Self {
+        Self {
+            names: (!columns.is_empty()).then(|| columns.iter().map(|c| c.name.clone()).collect()),
+            buffer: Vec::new(),
+        }
+    }
+}
+
+impl Encoder for JsonlEncoder {
+    fn write(&mut self, record: &Record) -> Result<(), String> {
+        let line = match &self.names {
+            Some(names) => {
+                let selected: Record = names
+                    .iter()
+                    .map(|name| {
+                        (
+                            name.clone(),
+                            record.get(name).cloned().unwrap_or(Value::Null),
+                        )
+                    })
+                    .collect();
+                serde_json::to_vec(&selected)
+            }
+            None => serde_json::to_vec(record),
+        }
+        .map_err(|e| format!("failed to encode JSON line: {e}"))?;
+        self.buffer.extend_from_slice(&line);
+        self.buffer.push(b'\n');
+        Ok(())
+    }
+
+    fn size(&self) -> u64 {
+        self.buffer.len() as u64
+    }
+
+    fn finish(self: Box<Self>) -> Result<Vec<u8>, String> {
+        Ok(self.buffer)
+    }
+}
+
+struct ParquetEncoder {
+    columns: Vec<ColumnDef>,
+    schema: SchemaRef,
+    writer: ArrowWriter<Vec<u8>>,
+    pending: Vec<Record>,
+}
+
+impl ParquetEncoder {
+    fn new(columns: &[ColumnDef]) -> Result<Self, StageError> {
+        let fields: Vec<Field> = columns
+            .iter()
+            .map(|c| {
+                let data_type = match c.r#type.as_str() {
+                    "integer" => DataType::Int64,
+                    "float" => DataType::Float64,
+                    "boolean" => DataType::Boolean,
+                    _ => DataType::Utf8,
+                };
+                Field::new(&c.name, data_type, true)
+            })
+            .collect();
+        let schema: SchemaRef = Arc::new(ArrowSchema::new(fields));
+        let props = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+        let writer = ArrowWriter::try_new(Vec::new(), Arc::clone(&schema), Some(props))
+            .map_err(|e| permanent("", format!("failed to start Parquet file: {e}")))?;
+        Ok(Self {
+            columns: columns.to_vec(),
+            schema,
+            writer,
+            pending: Vec::new(),
+        })
+    }
+
+    /// Encode buffered rows as one record batch.
+    fn flush_pending(&mut self) -> Result<(), String> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        let arrays: Vec<ArrayRef> = self
+            .columns
+            .iter()
+            .map(|column| parquet_column(column, &self.pending))
+            .collect();
+        let batch = RecordBatch::try_new(Arc::clone(&self.schema), arrays)
+            .map_err(|e| format!("failed to build record batch: {e}"))?;
+        self.writer
+            .write(&batch)
+            .map_err(|e| format!("failed to write Parquet rows: {e}"))?;
+        self.pending.clear();
+        Ok(())
+    }
+}
+
+/// Build one Arrow column, coercing values to the column's type. Values
+/// that don't convert become nulls.
+fn parquet_column(column: &ColumnDef, records: &[Record]) -> ArrayRef {
+    let typed = |record: &Record| match record.get(&column.name) {
+        Some(Value::String(s)) => convert_value(s, &column.r#type),
+        Some(v) => v.clone(),
+        None => Value::Null,
+    };
+    match column.r#type.as_str() {
+        "integer" => Arc::new(
+            records
+                .iter()
+                .map(|r| typed(r).as_i64())
+                .collect::<Int64Array>(),
+        ),
+        "float" => Arc::new(
+            records
+                .iter()
+                .map(|r| typed(r).as_f64())
+                .collect::<Float64Array>(),
+        ),
+        "boolean" => Arc::new(
+            records
+                .iter()
+                .map(|r| typed(r).as_bool())
+                .collect::<BooleanArray>(),
+        ),
+        _ => Arc::new(
+            records
+                .iter()
+                .map(|r| text(r.get(&column.name)))
+                .collect::<StringArray>(),
+        ),
+    }
+}
+
+impl Encoder for ParquetEncoder {
+    fn write(&mut self, record: &Record) -> Result<(), String> {
+        self.pending.push(record.clone());
+        if self.pending.len() >= PARQUET_BATCH_ROWS {
+            self.flush_pending()?;
+        }
+        Ok(())
+    }
+
+    fn size(&self) -> u64 {
+        (self.writer.bytes_written() + self.writer.in_progress_size()) as u64
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<Vec<u8>, String> {
+        self.flush_pending()?;
+        self.writer
+            .into_inner()
+            .map_err(|e| format!("failed to finish Parquet file: {e}"))
+    }
+}
+
+struct AvroEncoder<'a> {
+    schema: &'a AvroSchema,
+    writer: apache_avro::Writer<'a, Vec<u8>>,
+    /// Whether to track `buffered` (costs a second encode per record).
+    track_size: bool,
+    /// Encoded bytes held in the writer's current block.
+    buffered: u64,
+}
+
+impl<'a> AvroEncoder<'a> {
+    fn new(schema: &'a AvroSchema, track_size: bool) -> Self {
+        Self {
+            schema,
+            writer: apache_avro::Writer::new(schema, Vec::new()),
+            track_size,
+            buffered: 0,
+        }
+    }
+}
+
+impl Encoder for AvroEncoder<'_> {
+    fn write(&mut self, record: &Record) -> Result<(), String> {
+        let value = record_to_avro(record, self.schema).map_err(|e| e.to_string())?;
+        let datum_len = if self.track_size {
+            apache_avro::to_avro_datum(self.schema, value.clone())
+                .map_err(|e| format!("Avro encoding error: {e}"))?
+                .len() as u64
+        } else {
+            0
+        };
+        let flushed = self
+            .writer
+            .append_value_ref(&value)
+            .map_err(|e| format!("Avro encoding error: {e}"))?;
+        // A non-zero return means the block (including this record) was
+        // written out.
+        self.buffered = if flushed > 0 {
+            0
+        } else {
+            self.buffered + datum_len
+        };
+        Ok(())
+    }
+
+    fn size(&self) -> u64 {
+        self.writer.get_ref().len() as u64 + self.buffered
+    }
+
+    fn finish(self: Box<Self>) -> Result<Vec<u8>, String> {
+        self.writer
+            .into_inner()
+            .map_err(|e| format!("failed to finish Avro file: {e}"))
+    }
+}
+
+#[async_trait]
+impl Stage for SerializeStage {
+    fn name(&self) -> &str {
+        "serialize"
+    }
+
+    fn requires_batch(&self) -> bool {
+        true
+    }
+
+    async fn process(
+        &self,
+        _item: PipelineItem,
+        _ctx: &StageContext,
+    ) -> Result<Vec<PipelineItem>, StageError> {
+        Err(StageError::Permanent {
+            stage: "serialize".to_string(),
+            item_id: String::new(),
+            message: "serialize stage requires batch mode; use process_batch()".to_string(),
+        })
+    }
+
+    async fn process_batch(
+        &self,
+        items: Vec<PipelineItem>,
+        _ctx: &StageContext,
+    ) -> Result<Vec<PipelineItem>, StageError> {
+        debug!(items = items.len(), "serialize stage starting");
+
+        // Group records by stream, keeping arrival order within each.
+        let mut streams: BTreeMap<String, Vec<(PipelineItem, Record)>> = BTreeMap::new();
+        for mut item in items {
+            let record = item.record.take().ok_or_else(|| StageError::Permanent {
+                stage: "serialize".to_string(),
+                item_id: item.id.clone(),
+                message: "item has no record".to_string(),
+            })?;
+            let stream = item.stream.clone().unwrap_or_else(|| "default".to_string());
+            streams.entry(stream).or_default().push((item, record));
+        }
+        if streams.len() > 1 && !self.config.file_name.contains("{stream}") {
+            return Err(invalid(
+                "file_name must contain {stream} when records span several streams",
+            ));
+        }
+
+        let mut results = Vec::new();
+        for (stream, group) in &streams {
+            results.extend(self.serialize_stream(stream, group)?);
+        }
+
+        debug!(
+            streams = streams.len(),
+            files = results.len(),
+            "serialize stage complete"
+        );
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use arrow_array::Array;
+    use ecl_pipeline_spec::PipelineSpec;
+    use ecl_pipeline_state::{Blake3Hash, ItemProvenance};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use serde_json::json;
+
+    fn make_item(id: &str, stream: Option<&str>, record: Value) -> PipelineItem {
+        PipelineItem {
+            id: id.to_string(),
+            display_name: id.to_string(),
+            content: Arc::from(b"" as &[u8]),
+            mime_type: "application/x-csv-row".to_string(),
+            source_name: "test".to_string(),
+            source_content_hash: Blake3Hash::new("abc"),
+            provenance: ItemProvenance {
+                source_kind: "filesystem".to_string(),
+                metadata: BTreeMap::new(),
+                source_modified: None,
+                extracted_at: chrono::Utc::now(),
+            },
+            metadata: BTreeMap::new(),
+            record: record.as_object().cloned(),
+            stream: stream.map(str::to_string),
+        }
+    }
+
+    fn rows() -> Vec<PipelineItem> {
+        vec![
+            make_item(
+                "r1",
+                Some("sales"),
+                json!({"store": "001", "amount": 10.5, "qty": 2}),
+            ),
+            make_item(
+                "r2",
+                Some("sales"),
+                json!({"store": "002", "amount": 3, "qty": null}),
+            ),
+            make_item(
+                "r3",
+                Some("sales"),
+                json!({"store": "003, east", "amount": 7.25, "qty": 1}),
+            ),
+        ]
+    }
+
+    fn ctx() -> StageContext {
+        StageContext {
+            spec: Arc::new(
+                PipelineSpec::from_toml(
+                    "name = \"t\"\nversion = 1\noutput_dir = \"./o\"\n[sources.s]\nkind = \"filesystem\"\nroot = \"/tmp\"\n[stages.x]\nadapter = \"x\"\nresources = { creates = [\"y\"] }",
+                )
+                .unwrap(),
+            ),
+            output_dir: std::path::PathBuf::from("/tmp"),
+            params: Value::Null,
+            span: tracing::Span::none(),
+        }
+    }
+
+    async fn run(params: Value, items: Vec<PipelineItem>) -> Vec<PipelineItem> {
+        let stage = SerializeStage::from_params(&params).unwrap();
+        assert!(stage.requires_batch());
+        stage.process_batch(items, &ctx()).await.unwrap()
+    }
+
+    fn content(item: &PipelineItem) -> &str {
+        std::str::from_utf8(&item.content).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_serialize_csv_with_column_order() {
+        let params = json!({
+            "format": "csv",
+            "columns": [{"name": "qty"}, {"name": "store"}, {"name": "missing"}]
+        });
+        let files = run(params, rows()).await;
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].id, "sales-0001.csv");
+        assert_eq!(files[0].mime_type, "text/csv");
+        assert_eq!(files[0].stream.as_deref(), Some("sales"));
+        assert_eq!(files[0].metadata["row_count"], json!(3));
+        assert_eq!(
+            content(&files[0]),
+            "qty,store,missing\n2,001,\n,002,\n1,\"003, east\",\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_serialize_csv_without_headers_and_delimiter() {
+        let params = json!({
+            "format": "csv",
+            "columns": [{"name": "store"}, {"name": "amount"}],
+            "delimiter": "|",
+            "has_headers": false,
+            "file_name": "out.{ext}"
+        });
+        let files = run(params, rows()).await;
+        assert_eq!(files[0].id, "out.csv");
+        assert_eq!(content(&files[0]), "001|10.5\n002|3\n003, east|7.25\n");
+    }
+
+    #[tokio::test]
+    async fn test_serialize_jsonl() {
+        let files = run(json!({"format": "jsonl"}), rows()).await;
+        assert_eq!(files[0].id, "sales-0001.jsonl");
+        assert_eq!(files[0].mime_type, "application/x-ndjson");
+        let lines: Vec<Value> = content(&files[0])
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[1], json!({"store": "002", "amount": 3, "qty": null}));
+
+        let params = json!({"format": "jsonl", "columns": [{"name": "store"}]});
+        let files = run(params, rows()).await;
+        assert_eq!(
+            content(&files[0]).lines().next().unwrap(),
+            r#"{"store":"001"}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_serialize_rolls_by_row_count() {
+        let params = json!({"format": "jsonl", "max_rows": 2});
+        let files = run(params, rows()).await;
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].id, "sales-0001.jsonl");
+        assert_eq!(files[1].id, "sales-0002.jsonl");
+        assert_eq!(files[0].metadata["row_count"], json!(2));
+        assert_eq!(files[1].metadata["row_count"], json!(1));
+        assert_eq!(content(&files[1]).lines().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_serialize_rolls_by_size() {
+        let params = json!({
+            "format": "csv",
+            "columns": [{"name": "store"}],
+            "max_bytes": 10
+        });
+        let files = run(params, rows()).await;
+        // Header (6) + first row (4) reaches the limit; so does each later file.
+        assert_eq!(files.len(), 3);
+        assert_eq!(content(&files[0]), "store\n001\n");
+        assert_eq!(content(&files[1]), "store\n002\n");
+        assert_eq!(content(&files[2]), "store\n\"003, east\"\n");
+    }
+
+    #[tokio::test]
+    async fn test_serialize_one_file_set_per_stream() {
+        let mut items = rows();
+        items.push(make_item("x1", Some("returns"), json!({"store": "009"})));
+        items.push(make_item("x2", None, json!({"store": "010"})));
+        let files = run(json!({"format": "jsonl"}), items).await;
+        let ids: Vec<&str> = files.iter().map(|f| f.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            [
+                "default-0001.jsonl",
+                "returns-0001.jsonl",
+                "sales-0001.jsonl"
+            ]
+        );
+        assert_eq!(files[1].stream.as_deref(), Some("returns"));
+        assert_eq!(files[0].stream, None);
+
+        let mut items = rows();
+        items.push(make_item("x1", Some("returns"), json!({"store": "009"})));
+        let stage =
+            SerializeStage::from_params(&json!({"format": "csv", "file_name": "all.csv"})).unwrap();
+        let err = stage.process_batch(items, &ctx()).await.unwrap_err();
+        assert!(err.to_string().contains("{stream}"));
+    }
+
+    #[tokio::test]
+    async fn test_serialize_parquet_infers_types() {
+        let files = run(json!({"format": "parquet"}), rows()).await;
+        assert_eq!(files[0].id, "sales-0001.parquet");
+
+        let reader =
+            ParquetRecordBatchReaderBuilder::try_new(bytes::Bytes::from(files[0].content.to_vec()))
+                .unwrap()
+                .build()
+                .unwrap();
+        let batches: Vec<RecordBatch> = reader.map(|b| b.unwrap()).collect();
+        let batch = &batches[0];
+        assert_eq!(batch.num_rows(), 3);
+        let schema = batch.schema();
+        assert_eq!(
+            schema.field_with_name("store").unwrap().data_type(),
+            &DataType::Utf8
+        );
+        assert_eq!(
+            schema.field_with_name("amount").unwrap().data_type(),
+            &DataType::Float64
+        );
+        assert_eq!(
+            schema.field_with_name("qty").unwrap().data_type(),
+            &DataType::Int64
+        );
+
+        let qty = batch
+            .column_by_name("qty")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(qty.value(0), 2);
+        assert!(qty.is_null(1));
+        let amount = batch
+            .column_by_name("amount")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(amount.value(1), 3.0);
+    }
+
+    #[tokio::test]
+    async fn test_serialize_parquet_with_typed_columns() {
+        let items = vec![
+            make_item("r1", None, json!({"id": "12", "ok": "yes", "n": "x"})),
+            make_item("r2", None, json!({"id": 13, "ok": false, "n": 1.5})),
+        ];
+        let params = json!({
+            "format": "parquet",
+            "columns": [
+                {"name": "id", "type": "integer"},
+                {"name": "ok", "type": "boolean"},
+                {"name": "n", "type": "float"}
+            ],
+            "max_rows": 1
+        });
+        let files = run(params, items).await;
+        assert_eq!(files.len(), 2);
+
+        let batch =
+            ParquetRecordBatchReaderBuilder::try_new(bytes::Bytes::from(files[0].content.to_vec()))
+                .unwrap()
+                .build()
+                .unwrap()
+                .next()
+                .unwrap()
+                .unwrap();
+        let id = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(id.value(0), 12);
+        let ok = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<BooleanArray>()
+            .unwrap();
+        assert!(ok.value(0));
+        // "x" is not a float, so it becomes null.
+        assert!(batch.column(2).is_null(0));
+    }
+
+    const AVRO_SCHEMA: &str = r#"{
+        "type": "record",
+        "name": "Sale",
+        "fields": [
+            {"name": "store", "type": "string"},
+            {"name": "amount", "type": "double"},
+            {"name": "qty", "type": ["null", "long"], "default": null}
+        ]
+    }"#;
+
+    #[tokio::test]
+    async fn test_serialize_avro_container() {
+        let params = json!({"format": "avro", "avro_schema": AVRO_SCHEMA});
+        let files = run(params, rows()).await;
+        assert_eq!(files[0].id, "sales-0001.avro");
+        assert_eq!(files[0].mime_type, "application/avro");
+
+        let reader = apache_avro::Reader::new(files[0].content.as_ref()).unwrap();
+        let values: Vec<apache_avro::types::Value> = reader.map(|v| v.unwrap()).collect();
+        assert_eq!(values.len(), 3);
+        let apache_avro::types::Value::Record(fields) = &values[1] else {
+            unreachable!("records decode as records");
+        };
+        assert_eq!(
+            fields[0],
+            (
+                "store".to_string(),
+                apache_avro::types::Value::String("002".to_string())
+            )
+        );
+        assert_eq!(
+            fields[2].1,
+            apache_avro::types::Value::Union(0, Box::new(apache_avro::types::Value::Null))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_serialize_avro_rolls_by_size() {
+        let params = json!({
+            "format": "avro",
+            "avro_schema": AVRO_SCHEMA,
+            "max_bytes": 1
+        });
+        let files = run(params, rows()).await;
+        assert_eq!(files.len(), 3);
+        for file in &files {
+            let reader = apache_avro::Reader::new(file.content.as_ref()).unwrap();
+            assert_eq!(reader.count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_serialize_config_errors() {
+        let err = SerializeStage::from_params(&json!({"format": "xml"})).unwrap_err();
+        assert!(err.to_string().contains("invalid serialize config"));
+
+        let err = SerializeStage::from_params(&json!({"format": "avro"})).unwrap_err();
+        assert!(err.to_string().contains("avro_schema"));
+
+        let err = SerializeStage::from_params(
+            &json!({"format": "csv", "file_name": "out.csv", "max_rows": 10}),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("{part}"));
+
+        let err =
+            SerializeStage::from_params(&json!({"format": "csv", "max_rows": 0})).unwrap_err();
+        assert!(err.to_string().contains("positive"));
+    }
+
+    #[tokio::test]
+    async fn test_serialize_requires_records() {
+        let stage = SerializeStage::from_params(&json!({"format": "csv"})).unwrap();
+        let mut item = make_item("r1", None, json!({}));
+        item.record = None;
+        let err = stage.process_batch(vec![item], &ctx()).await.unwrap_err();
+        assert!(err.to_string().contains("no record"));
+
+        let err = stage
+            .process(make_item("r1", None, json!({})), &ctx())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("batch mode"));
+    }
+
+    #[tokio::test]
+    async fn test_serialize_empty_batch() {
+        assert!(run(json!({"format": "csv"}), Vec::new()).await.is_empty());
+    }
+
+    #[test]
+    fn test_infer_columns_widens_types() {
+        let a = json!({"a": 1, "b": 1, "c": true, "d": null})
+            .as_object()
+            .cloned()
+            .unwrap();
+        let b = json!({"a": 2, "b": 2.5, "c": "x", "e": [1]})
+            .as_object()
+            .cloned()
+            .unwrap();
+        let columns = infer_columns(&[&a, &b]);
+        let types: Vec<(&str, &str)> = columns
+            .iter()
+            .map(|c| (c.name.as_str(), c.r#type.as_str()))
+            .collect();
+        assert_eq!(
+            types,
+            [
+                ("a", "integer"),
+                ("b", "float"),
+                ("c", "string"),
+                ("d", "string"),
+                ("e", "string")
+            ]
+        );
+    }
+}
@@ -0,0 +1,35 @@
+//! OpenPGP key fixtures for tests.
+//!
+//! Generates throwaway keys so encryption and decryption tests, here and in
+//! downstream sink crates, need no checked-in key material.
+
+#![allow(clippy::unwrap_used)]
+
+use pgp::composed::{
+    EncryptionCaps, KeyType, SecretKeyParamsBuilder, SignedSecretKey, SubkeyParamsBuilder,
+};
+use pgp::crypto::ecc_curve::ECCCurve;
+
+/// Generate a key for `uid`: an Ed25519 primary that certifies and signs,
+/// with a Curve25519 encryption subkey. Both are locked with `passphrase`
+/// if one is given.
+pub fn keygen(uid: &str, passphrase: Option<&str>) -> SignedSecretKey {
+    let mut encrypt = SubkeyParamsBuilder::default();
+    encrypt
+        .key_type(KeyType::ECDH(ECCCurve::Curve25519))
+        .can_encrypt(EncryptionCaps::All)
+        .passphrase(passphrase.map(str::to_string));
+    let mut params = SecretKeyParamsBuilder::default();
+    params
+        .key_type(KeyType::Ed25519Legacy)
+        .can_certify(true)
+        .can_sign(true)
+        .primary_user_id(uid.into())
+        .passphrase(passphrase.map(str::to_string))
+        .subkeys(vec![encrypt.build().unwrap()]);
+    params
+        .build()
+        .unwrap()
+        .generate(rand::thread_rng())
+        .unwrap()
+}
@@ -9,6 +9,7 @@ use std::collections::{BTreeMap, HashMap};
 use async_trait::async_trait;
 use chrono::{NaiveDateTime, TimeZone};
 use chrono_tz::Tz;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde_json::Value;
 use tracing::debug;
@@ -19,7 +20,7 @@ use ecl_pipeline_topo::{PipelineItem, Stage, StageContext};
 type Record = serde_json::Map<String, serde_json::Value>;
 
 /// Configuration for the timezone stage, parsed from stage params.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct TimezoneConfig {
     /// Field containing the datetime string (already parsed, RFC3339 or local).
     pub datetime_field: String,
@@ -8,6 +8,7 @@
 use async_trait::async_trait;
 use chrono::DateTime;
 use regex::Regex;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde_json::Value;
 use tracing::debug;
@@ -16,14 +17,14 @@ use ecl_pipeline_topo::error::StageError;
 use ecl_pipeline_topo::{PipelineItem, Record, Stage, StageContext};
 
 /// Configuration for the validation stage.
-#[derive(Debug, Clone, Deserialize)]
-struct ValidateConfig {
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub(crate) struct ValidateConfig {
     /// Validation rules applied in order.
     rules: Vec<ValidationRule>,
 }
 
 /// A single validation rule.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 struct ValidationRule {
     /// Field to validate.
     field: String,
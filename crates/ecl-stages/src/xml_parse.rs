@@ -0,0 +1,382 @@
+//! XML parsing stage: fan-out from an XML document → individual records.
+//!
+//! Record elements are selected by a slash-separated element path from the
+//! document root. Columns read element text or attributes relative to each
+//! record element and use the same typed definitions as `csv_parse`; rows
+//! that cannot be mapped are skipped or fail the item per `on_row_error`.
+
+use async_trait::async_trait;
+use roxmltree::{Document, Node};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use ecl_pipeline_topo::error::StageError;
+use ecl_pipeline_topo::{PipelineItem, Record, Stage, StageContext};
+
+use crate::csv_parse::{ColumnDef, convert_value, default_on_error, row_error, row_item};
+
+/// Parsed from stage params TOML/JSON.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub(crate) struct XmlParseConfig {
+    /// Slash-separated element path from the root element to each record,
+    /// e.g. "orders/order". `*` matches any element name.
+    records: String,
+    /// Column definitions. Default: every attribute of the record element
+    /// plus the text of each child element that has no element children.
+    #[serde(default)]
+    columns: Vec<XmlColumnDef>,
+    /// How to handle records that cannot be mapped.
+    /// "skip" = skip them (log warning), "fail" = fail the item.
+    /// Default: "skip"
+    #[serde(default = "default_on_error")]
+    on_row_error: String,
+}
+
+/// A typed column read from a path relative to the record element.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub(crate) struct XmlColumnDef {
+    #[serde(flatten)]
+    column: ColumnDef,
+    /// Where to read the value: "child/grandchild" for element text,
+    /// "@attr" or "child/@attr" for an attribute, "." for the record
+    /// element's own text. Default: the column name.
+    #[serde(default)]
+    path: Option<String>,
+}
+
+fn name_matches(node: &Node<'_, '_>, step: &str) -> bool {
+    node.is_element() && (step == "*" || node.tag_name().name() == step)
+}
+
+/// The trimmed text directly inside an element.
+fn text_of(node: &Node<'_, '_>) -> String {
+    node.children()
+        .filter(Node::is_text)
+        .filter_map(|n| n.text())
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+/// Resolve a column path against a record element. A path matching
+/// nothing yields `None`; one matching several values is an error.
+fn lookup(record: Node<'_, '_>, path: &str) -> Result<Option<String>, String> {
+    let mut steps: Vec<&str> = path
+        .split('/')
+        .filter(|s| !s.is_empty() && *s != ".")
+        .collect();
+    let attribute = steps.last().copied().and_then(|s| s.strip_prefix('@'));
+    if attribute.is_some() {
+        steps.pop();
+    }
+
+    let mut nodes = vec![record];
+    for step in steps {
+        nodes = nodes
+            .into_iter()
+            .flat_map(|n| n.children().filter(|c| name_matches(c, step)))
+            .collect();
+    }
+
+    let mut values: Vec<String> = match attribute {
+        Some(name) => nodes
+            .iter()
+            .filter_map(|n| n.attribute(name))
+            .map(str::to_string)
+            .collect(),
+        None => nodes.iter().map(text_of).collect(),
+    };
+    match values.len() {
+        0 | 1 => Ok(values.pop()),
+        n => Err(format!("path '{path}' matched {n} values")),
+    }
+}
+
+/// Map a record element with no column definitions: attributes plus the
+/// text of leaf child elements, all as strings.
+fn default_record(node: Node<'_, '_>) -> Result<Record, String> {
+    let mut record = Record::new();
+    let attributes = node
+        .attributes()
+        .map(|a| (a.name().to_string(), a.value().to_string()));
+    let leaves = node
+        .children()
+        .filter(|c| c.is_element() && !c.children().any(|g| g.is_element()))
+        .map(|c| (c.tag_name().name().to_string(), text_of(&c)));
+    for (name, value) in attributes.chain(leaves) {
+        if record.contains_key(&name) {
+            return Err(format!("duplicate field '{name}'"));
+        }
+        record.insert(name, serde_json::Value::String(value));
+    }
+    Ok(record)
+}
+
+/// XML parsing stage: one document in, N record items out.
+#[derive(Debug)]
+pub struct XmlParseStage {
+    config: XmlParseConfig,
+}
+
+impl XmlParseStage {
+    /// Create from stage params JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StageError::Permanent` if the config is invalid.
+    pub fn from_params(params: &serde_json::Value) -> Result<Self, StageError> {
+        let config: XmlParseConfig =
+            serde_json::from_value(params.clone()).map_err(|e| StageError::Permanent {
+                stage: "xml_parse".to_string(),
+                item_id: String::new(),
+                message: format!("invalid xml_parse config: {e}"),
+            })?;
+        if config.records.split('/').all(str::is_empty) {
+            return Err(StageError::Permanent {
+                stage: "xml_parse".to_string(),
+                item_id: String::new(),
+                message: "invalid xml_parse config: 'records' must name at least one element"
+                    .to_string(),
+            });
+        }
+        Ok(Self { config })
+    }
+
+    fn map_record(&self, node: Node<'_, '_>) -> Result<Record, String> {
+        if self.config.columns.is_empty() {
+            return default_record(node);
+        }
+        self.config
+            .columns
+            .iter()
+            .map(|col| {
+                let path = col.path.as_deref().unwrap_or(&col.column.name);
+                let value = match lookup(node, path)? {
+                    Some(raw) => convert_value(&raw, &col.column.r#type),
+                    None => serde_json::Value::Null,
+                };
+                Ok((col.column.name.clone(), value))
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl Stage for XmlParseStage {
+    fn name(&self) -> &str {
+        "xml_parse"
+    }
+
+    async fn process(
+        &self,
+        item: PipelineItem,
+        _ctx: &StageContext,
+    ) -> Result<Vec<PipelineItem>, StageError> {
+        let text = std::str::from_utf8(&item.content).map_err(|e| StageError::Permanent {
+            stage: "xml_parse".to_string(),
+            item_id: item.id.clone(),
+            message: format!("XML document is not valid UTF-8: {e}"),
+        })?;
+        let document = Document::parse(text).map_err(|e| StageError::Permanent {
+            stage: "xml_parse".to_string(),
+            item_id: item.id.clone(),
+            message: format!("invalid XML document: {e}"),
+        })?;
+
+        let mut steps = self.config.records.split('/').filter(|s| !s.is_empty());
+        let root = document.root_element();
+        let mut records: Vec<Node<'_, '_>> = match steps.next() {
+            Some(step) if name_matches(&root, step) => vec![root],
+            _ => Vec::new(),
+        };
+        for step in steps {
+            records = records
+                .into_iter()
+                .flat_map(|n| n.children().filter(|c| name_matches(c, step)))
+                .collect();
+        }
+
+        let mut output = Vec::new();
+        for (idx, node) in records.into_iter().enumerate() {
+            let index = idx + 1;
+            let record = match self.map_record(node) {
+                Ok(r) => r,
+                Err(e) => {
+                    row_error(
+                        "xml_parse",
+                        &self.config.on_row_error,
+                        &item,
+                        &format!("record {index}"),
+                        &e,
+                    )?;
+                    continue;
+                }
+            };
+            output.push(row_item(
+                &item,
+                ("_record_index", index),
+                record,
+                text[node.range()].as_bytes().to_vec(),
+                "application/xml",
+            ));
+        }
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use ecl_pipeline_spec::PipelineSpec;
+    use ecl_pipeline_state::{Blake3Hash, ItemProvenance};
+    use serde_json::json;
+    use std::collections::BTreeMap;
+    use std::sync::Arc;
+
+    const ORDERS: &str = r#"<?xml version="1.0"?>
+<export xmlns="urn:example">
+  <orders>
+    <order id="1" status="paid">
+      <customer><name>Alice</name></customer>
+      <total currency="EUR">12.50</total>
+      <rush>true</rush>
+    </order>
+    <order id="2" status="open">
+      <customer><name>Bob</name></customer>
+      <total currency="USD">7</total>
+    </order>
+  </orders>
+</export>"#;
+
+    fn make_item(content: &str) -> PipelineItem {
+        PipelineItem {
+            id: "file-1".to_string(),
+            display_name: "orders.xml".to_string(),
+            content: Arc::from(content.as_bytes()),
+            mime_type: "application/xml".to_string(),
+            source_name: "test".to_string(),
+            source_content_hash: Blake3Hash::new("abc"),
+            provenance: ItemProvenance {
+                source_kind: "filesystem".to_string(),
+                metadata: BTreeMap::new(),
+                source_modified: None,
+                extracted_at: chrono::Utc::now(),
+            },
+            metadata: BTreeMap::new(),
+            record: None,
+            stream: None,
+        }
+    }
+
+    fn ctx() -> StageContext {
+        StageContext {
+            spec: Arc::new(
+                PipelineSpec::from_toml(
+                    "name = \"t\"\nversion = 1\noutput_dir = \"./o\"\n[sources.s]\nkind = \"filesystem\"\nroot = \"/tmp\"\n[stages.x]\nadapter = \"x\"\nresources = { creates = [\"y\"] }",
+                )
+                .unwrap(),
+            ),
+            output_dir: std::path::PathBuf::from("/tmp"),
+            params: serde_json::Value::Null,
+            span: tracing::Span::none(),
+        }
+    }
+
+    async fn parse(
+        params: serde_json::Value,
+        content: &str,
+    ) -> Result<Vec<PipelineItem>, StageError> {
+        XmlParseStage::from_params(&params)
+            .unwrap()
+            .process(make_item(content), &ctx())
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_xml_parse_columns_with_paths() {
+        let params = json!({
+            "records": "export/orders/order",
+            "columns": [
+                {"name": "id", "type": "integer", "path": "@id"},
+                {"name": "customer", "path": "customer/name"},
+                {"name": "total", "type": "float"},
+                {"name": "currency", "path": "total/@currency"},
+                {"name": "rush", "type": "boolean"}
+            ]
+        });
+        let result = parse(params, ORDERS).await.unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(
+            result[0].record.as_ref().unwrap(),
+            json!({"id": 1, "customer": "Alice", "total": 12.5, "currency": "EUR", "rush": true})
+                .as_object()
+                .unwrap()
+        );
+        assert_eq!(result[1].record.as_ref().unwrap()["rush"], json!(null));
+        assert_eq!(result[1].id, "file-1:row:2");
+        assert_eq!(result[1].metadata["_record_index"], json!(2));
+        assert_eq!(result[1].metadata["_source_file"], json!("orders.xml"));
+        let raw = std::str::from_utf8(&result[1].content).unwrap();
+        assert!(raw.starts_with("<order id=\"2\""));
+        assert!(raw.ends_with("</order>"));
+    }
+
+    #[tokio::test]
+    async fn test_xml_parse_default_mapping() {
+        let result = parse(json!({"records": "/export/*/order"}), ORDERS)
+            .await
+            .unwrap();
+        assert_eq!(result.len(), 2);
+        // `customer` has element children, so only leaves are mapped.
+        assert_eq!(
+            result[0].record.as_ref().unwrap(),
+            json!({"id": "1", "status": "paid", "total": "12.50", "rush": "true"})
+                .as_object()
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_xml_parse_ambiguous_path_skip_and_fail() {
+        let xml = "<rows><row><v>1</v></row><row><v>2</v><v>3</v></row><row><v>4</v></row></rows>";
+        let params = json!({"records": "rows/row", "columns": [{"name": "v", "type": "integer"}]});
+        let result = parse(params, xml).await.unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[1].record.as_ref().unwrap()["v"], json!(4));
+        assert_eq!(result[1].metadata["_record_index"], json!(3));
+
+        let params = json!({
+            "records": "rows/row",
+            "on_row_error": "fail",
+            "columns": [{"name": "v"}]
+        });
+        let err = parse(params, xml).await.unwrap_err();
+        assert!(err.to_string().contains("record 2 parse error"));
+        assert!(err.to_string().contains("matched 2 values"));
+    }
+
+    #[tokio::test]
+    async fn test_xml_parse_no_matching_records() {
+        let result = parse(json!({"records": "other/order"}), ORDERS)
+            .await
+            .unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_xml_parse_invalid_document() {
+        let err = parse(json!({"records": "a"}), "<a><b></a>")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, StageError::Permanent { .. }));
+        assert!(err.to_string().contains("invalid XML document"));
+    }
+
+    #[test]
+    fn test_xml_parse_config_requires_records() {
+        assert!(XmlParseStage::from_params(&json!({})).is_err());
+        assert!(XmlParseStage::from_params(&json!({"records": "/"})).is_err());
+    }
+}
@@ -0,0 +1,130 @@
+//! Caller identity for access decisions.
+//!
+//! A [`Caller`] is the ACL view of an [`AuthenticatedUser`]: its email,
+//! subject and tenant (the email domain). Requests without an
+//! authenticated user (stdio transport, dev mode) are anonymous.
+//!
+//! While a tool call runs, the caller is available through
+//! [`current_caller`], so content providers can filter what they return
+//! without threading the identity through every trait method.
+
+use std::future::Future;
+
+use fabryk_auth::AuthenticatedUser;
+
+tokio::task_local! {
+    static CURRENT: Caller;
+}
+
+/// The identity an access decision is made for.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Caller {
+    email: Option<String>,
+    subject: Option<String>,
+}
+
+impl Caller {
+    /// An unauthenticated caller.
+    pub fn anonymous() -> Self {
+        Self::default()
+    }
+
+    /// The caller for an authenticated user.
+    ///
+    /// Emails are compared case-insensitively, so the email is lowercased.
+    pub fn from_user(user: &AuthenticatedUser) -> Self {
+        Self {
+            email: Some(user.email.to_lowercase()).filter(|e| !e.is_empty()),
+            subject: Some(user.subject.clone()).filter(|s| !s.is_empty()),
+        }
+    }
+
+    /// Whether no authenticated user is present.
+    pub fn is_anonymous(&self) -> bool {
+        self.email.is_none() && self.subject.is_none()
+    }
+
+    /// The caller's email address (lowercased).
+    pub fn email(&self) -> Option<&str> {
+        self.email.as_deref()
+    }
+
+    /// The caller's subject identifier.
+    pub fn subject(&self) -> Option<&str> {
+        self.subject.as_deref()
+    }
+
+    /// The caller's tenant: the domain of their email address.
+    pub fn tenant(&self) -> Option<&str> {
+        self.email
+            .as_deref()
+            .and_then(|e| e.rsplit_once('@'))
+            .map(|(_, domain)| domain)
+            .filter(|d| !d.is_empty())
+    }
+}
+
+impl From<&AuthenticatedUser> for Caller {
+    fn from(user: &AuthenticatedUser) -> Self {
+        Self::from_user(user)
+    }
+}
+
+/// Run `future` with `caller` as the [`current_caller`].
+pub async fn with_caller<F: Future>(caller: Caller, future: F) -> F::Output {
+    CURRENT.scope(caller, future).await
+}
+
+/// The caller of the tool call currently running on this task.
+///
+/// Returns an anonymous caller outside [`with_caller`].
+pub fn current_caller() -> Caller {
+    CURRENT.try_with(Caller::clone).unwrap_or_default()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(email: &str) -> AuthenticatedUser {
+        AuthenticatedUser {
+            email: email.to_string(),
+            subject: "sub_123".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_caller_from_user() {
+        let caller = Caller::from_user(&user("Alice@Example.COM"));
+        assert_eq!(caller.email(), Some("alice@example.com"));
+        assert_eq!(caller.subject(), Some("sub_123"));
+        assert_eq!(caller.tenant(), Some("example.com"));
+        assert!(!caller.is_anonymous());
+    }
+
+    #[test]
+    fn test_anonymous_caller() {
+        let caller = Caller::anonymous();
+        assert!(caller.is_anonymous());
+        assert_eq!(caller.tenant(), None);
+    }
+
+    #[test]
+    fn test_tenant_requires_domain() {
+        assert_eq!(Caller::from_user(&user("alice")).tenant(), None);
+        assert_eq!(Caller::from_user(&user("alice@")).tenant(), None);
+    }
+
+    #[tokio::test]
+    async fn test_current_caller_scoped() {
+        assert!(current_caller().is_anonymous());
+        let caller = Caller::from_user(&user("bob@example.com"));
+        let seen = with_caller(caller.clone(), async { current_caller() }).await;
+        assert_eq!(seen, caller);
+        assert!(current_caller().is_anonymous());
+    }
+}
@@ -1,12 +1,35 @@
 //! Access control layer for Fabryk.
 //!
-//! This crate is a placeholder for v0.2/v0.3. It will provide:
+//! Provides tenant-aware access policies for MCP tools and content:
 //!
-//! - User/tenant identification
-//! - Permission checking
-//! - Resource ownership
-//! - Multi-tenancy isolation
+//! - [`Policy`] — roles granting tools, content categories and sources by
+//!   name pattern, assigned per user, per tenant, and to all authenticated
+//!   or anonymous callers; loaded from TOML, deny-by-default
+//! - [`Caller`] — the identity checked, built from
+//!   [`fabryk_auth::AuthenticatedUser`]; its tenant is the email domain
+//! - [`Permissions`] — a caller's effective grants
+//! - [`current_caller`] / [`with_caller`] — the caller of the running tool call
+//!
+//! With the `mcp` feature, [`AclToolAccess`] plugs a policy into
+//! `FabrykMcpServer` tool dispatch, and [`AclContentProvider`] /
+//! [`AclSourceProvider`] filter content by the caller's grants.
 
 #![doc = include_str!("../README.md")]
 
-// Placeholder - implementation deferred to v0.2/v0.3
+pub mod caller;
+#[cfg(feature = "mcp")]
+pub mod mcp;
+pub mod policy;
+
+// Re-exports — caller
+pub use caller::{Caller, current_caller, with_caller};
+
+// Re-exports — policy
+pub use policy::{DefaultAccess, Permissions, Policy, ResourceKind, Role};
+
+// Re-exports — MCP integration (requires `mcp` feature)
+#[cfg(feature = "mcp")]
+pub use mcp::{
+    AclContentProvider, AclResource, AclSourceProvider, AclToolAccess, caller_from_extensions,
+    permits,
+};
@@ -0,0 +1,557 @@
+//! MCP integration (requires the `mcp` feature).
+//!
+//! - [`AclToolAccess`] — a [`ToolAccess`] that hides and denies tools per
+//!   the caller's grants, and runs allowed calls with the caller set as
+//!   the [`current_caller`](crate::current_caller)
+//! - [`AclContentProvider`] / [`AclSourceProvider`] — provider wrappers
+//!   that filter items and sources by the current caller's grants
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use std::sync::Arc;
+//! use fabryk_acl::{AclContentProvider, AclToolAccess, Policy};
+//!
+//! let policy = Arc::new(Policy::load("acl.toml")?);
+//! let provider = AclContentProvider::new(FsContentItemProvider::new(path), policy.clone());
+//! let registry = CompositeRegistry::new().add(ContentTools::new(provider));
+//!
+//! let server = FabrykMcpServer::new(registry)
+//!     .with_tool_access(AclToolAccess::new(policy));
+//! ```
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use fabryk_core::{Error, Result};
+use fabryk_mcp_content::{
+    CategoryInfo, ChapterInfo, ContentItemProvider, ContentItemSummary, FilterMap, SourceProvider,
+    SourceSummary,
+};
+use fabryk_mcp_core::access::Extensions;
+use fabryk_mcp_core::{ToolAccess, ToolResult};
+
+use crate::caller::{Caller, current_caller, with_caller};
+use crate::policy::{Permissions, Policy};
+
+/// The caller of an MCP request, from the authenticated user the auth
+/// middleware stored in the HTTP request parts.
+///
+/// Requests without one (stdio transport, auth disabled) are anonymous.
+pub fn caller_from_extensions(extensions: &Extensions) -> Caller {
+    extensions
+        .get::<http::request::Parts>()
+        .and_then(fabryk_auth::user_from_parts)
+        .map(Caller::from_user)
+        .unwrap_or_default()
+}
+
+/// Tool access control backed by a [`Policy`].
+pub struct AclToolAccess {
+    policy: Arc<Policy>,
+}
+
+impl AclToolAccess {
+    /// Create a tool access check for `policy`.
+    pub fn new(policy: Arc<Policy>) -> Self {
+        Self { policy }
+    }
+}
+
+impl ToolAccess for AclToolAccess {
+    fn allows(&self, tool: &str, extensions: &Extensions) -> bool {
+        let caller = caller_from_extensions(extensions);
+        self.policy.permissions(&caller).can_use_tool(tool)
+    }
+
+    fn scope(&self, call: ToolResult, extensions: &Extensions) -> ToolResult {
+        Box::pin(with_caller(caller_from_extensions(extensions), call))
+    }
+}
+
+/// Something whose visibility depends on category and source grants.
+pub trait AclResource {
+    /// The resource's ID.
+    fn acl_id(&self) -> &str;
+
+    /// The content category the resource belongs to, if any.
+    fn acl_category(&self) -> Option<&str> {
+        None
+    }
+
+    /// The source the resource comes from, if any.
+    fn acl_source(&self) -> Option<&str> {
+        None
+    }
+}
+
+impl AclResource for ContentItemSummary {
+    fn acl_id(&self) -> &str {
+        &self.id
+    }
+
+    fn acl_category(&self) -> Option<&str> {
+        Some(&self.category)
+    }
+
+    fn acl_source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+}
+
+impl AclResource for SourceSummary {
+    fn acl_id(&self) -> &str {
+        &self.id
+    }
+
+    fn acl_source(&self) -> Option<&str> {
+        Some(&self.id)
+    }
+}
+
+/// Whether `permissions` cover `resource`'s category and source.
+pub fn permits(permissions: &Permissions, resource: &impl AclResource) -> bool {
+    resource
+        .acl_category()
+        .is_none_or(|c| permissions.can_read_category(c))
+        && resource
+            .acl_source()
+            .is_none_or(|s| permissions.can_read_source(s))
+}
+
+/// A [`ContentItemProvider`] that only exposes items the current caller
+/// may read.
+///
+/// Denied items are indistinguishable from missing ones: they are left
+/// out of listings and counts, and `get_item` reports them as not found.
+pub struct AclContentProvider<P> {
+    inner: P,
+    policy: Arc<Policy>,
+}
+
+impl<P> AclContentProvider<P> {
+    /// Wrap `inner`, filtering by `policy`.
+    pub fn new(inner: P, policy: Arc<Policy>) -> Self {
+        Self { inner, policy }
+    }
+
+    fn permissions(&self) -> Permissions {
+        self.policy.permissions(&current_caller())
+    }
+}
+
+impl<P> AclContentProvider<P>
+where
+    P: ContentItemProvider,
+    P::ItemSummary: AclResource,
+{
+    fn filter(
+        permissions: &Permissions,
+        items: Vec<P::ItemSummary>,
+        limit: Option<usize>,
+    ) -> Vec<P::ItemSummary> {
+        items
+            .into_iter()
+            .filter(|item| permits(permissions, item))
+            .take(limit.unwrap_or(usize::MAX))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<P> ContentItemProvider for AclContentProvider<P>
+where
+    P: ContentItemProvider,
+    P::ItemSummary: AclResource,
+{
+    type ItemSummary = P::ItemSummary;
+    type ItemDetail = P::ItemDetail;
+
+    async fn list_items(
+        &self,
+        category: Option<&str>,
+        limit: Option<usize>,
+    ) -> Result<Vec<Self::ItemSummary>> {
+        let permissions = self.permissions();
+        if category.is_some_and(|c| !permissions.can_read_category(c)) {
+            return Ok(Vec::new());
+        }
+        let items = self.inner.list_items(category, None).await?;
+        Ok(Self::filter(&permissions, items, limit))
+    }
+
+    async fn get_item(&self, id: &str) -> Result<Self::ItemDetail> {
+        let permissions = self.permissions();
+        let visible = self
+            .inner
+            .list_items(None, None)
+            .await?
+            .iter()
+            .any(|item| item.acl_id() == id && permits(&permissions, item));
+        if !visible {
+            return Err(Error::not_found(self.inner.content_type_name(), id));
+        }
+        self.inner.get_item(id).await
+    }
+
+    async fn list_categories(&self) -> Result<Vec<CategoryInfo>> {
+        let permissions = self.permissions();
+        let items = Self::filter(&permissions, self.inner.list_items(None, None).await?, None);
+        let categories = self.inner.list_categories().await?;
+        Ok(categories
+            .into_iter()
+            .filter(|c| permissions.can_read_category(&c.id))
+            .map(|mut c| {
+                c.count = items
+                    .iter()
+                    .filter(|item| item.acl_category() == Some(c.id.as_str()))
+                    .count();
+                c
+            })
+            .collect())
+    }
+
+    fn content_type_name(&self) -> &str {
+        self.inner.content_type_name()
+    }
+
+    fn content_type_name_plural(&self) -> &str {
+        self.inner.content_type_name_plural()
+    }
+
+    async fn list_items_filtered(
+        &self,
+        category: Option<&str>,
+        limit: Option<usize>,
+        extra_filters: &FilterMap,
+    ) -> Result<Vec<Self::ItemSummary>> {
+        let permissions = self.permissions();
+        if category.is_some_and(|c| !permissions.can_read_category(c)) {
+            return Ok(Vec::new());
+        }
+        let items = self
+            .inner
+            .list_items_filtered(category, None, extra_filters)
+            .await?;
+        Ok(Self::filter(&permissions, items, limit))
+    }
+}
+
+/// A [`SourceProvider`] that only exposes sources the current caller may
+/// read.
+pub struct AclSourceProvider<P> {
+    inner: P,
+    policy: Arc<Policy>,
+}
+
+impl<P> AclSourceProvider<P> {
+    /// Wrap `inner`, filtering by `policy`.
+    pub fn new(inner: P, policy: Arc<Policy>) -> Self {
+        Self { inner, policy }
+    }
+
+    fn check(&self, source_id: &str) -> Result<()> {
+        if self
+            .policy
+            .permissions(&current_caller())
+            .can_read_source(source_id)
+        {
+            Ok(())
+        } else {
+            Err(Error::not_found("source", source_id))
+        }
+    }
+}
+
+#[async_trait]
+impl<P> SourceProvider for AclSourceProvider<P>
+where
+    P: SourceProvider,
+    P::SourceSummary: AclResource,
+{
+    type SourceSummary = P::SourceSummary;
+
+    async fn list_sources(&self) -> Result<Vec<Self::SourceSummary>> {
+        let permissions = self.policy.permissions(&current_caller());
+        Ok(self
+            .inner
+            .list_sources()
+            .await?
+            .into_iter()
+            .filter(|source| permits(&permissions, source))
+            .collect())
+    }
+
+    async fn get_chapter(
+        &self,
+        source_id: &str,
+        chapter: &str,
+        section: Option<&str>,
+    ) -> Result<String> {
+        self.check(source_id)?;
+        self.inner.get_chapter(source_id, chapter, section).await
+    }
+
+    async fn list_chapters(&self, source_id: &str) -> Result<Vec<ChapterInfo>> {
+        self.check(source_id)?;
+        self.inner.list_chapters(source_id).await
+    }
+
+    async fn get_source_path(&self, source_id: &str) -> Result<Option<std::path::PathBuf>> {
+        self.check(source_id)?;
+        self.inner.get_source_path(source_id).await
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fabryk_auth::AuthenticatedUser;
+    use fabryk_mcp_core::model::{CallToolResult, Content};
+
+    const POLICY: &str = r#"
+        anonymous = ["public"]
+
+        [roles.public]
+        tools = ["list_items"]
+        categories = ["public"]
+        sources = ["open-*"]
+
+        [roles.staff]
+        tools = ["*"]
+        categories = ["*"]
+        sources = ["*"]
+
+        [tenants]
+        "acme.com" = ["staff"]
+    "#;
+
+    fn policy() -> Arc<Policy> {
+        Arc::new(Policy::from_toml_str(POLICY).unwrap())
+    }
+
+    fn staff() -> Caller {
+        Caller::from_user(&AuthenticatedUser {
+            email: "bob@acme.com".to_string(),
+            subject: "sub_bob".to_string(),
+        })
+    }
+
+    fn extensions_for(email: &str) -> Extensions {
+        let (mut parts, _body) = http::Request::new(()).into_parts();
+        parts.extensions.insert(AuthenticatedUser {
+            email: email.to_string(),
+            subject: "sub".to_string(),
+        });
+        let mut extensions = Extensions::new();
+        extensions.insert(parts);
+        extensions
+    }
+
+    fn item(id: &str, category: &str, source: Option<&str>) -> ContentItemSummary {
+        ContentItemSummary {
+            id: id.to_string(),
+            title: id.to_string(),
+            category: category.to_string(),
+            subcategory: None,
+            tier: None,
+            source: source.map(str::to_string),
+            chapter: None,
+            extraction_confidence: None,
+            aliases: Vec::new(),
+            chapter_number: None,
+            pdf_page: None,
+            path: format!("{category}/{id}.md"),
+            preview: None,
+        }
+    }
+
+    struct StaticProvider(Vec<ContentItemSummary>);
+
+    #[async_trait]
+    impl ContentItemProvider for StaticProvider {
+        type ItemSummary = ContentItemSummary;
+        type ItemDetail = String;
+
+        async fn list_items(
+            &self,
+            category: Option<&str>,
+            limit: Option<usize>,
+        ) -> Result<Vec<ContentItemSummary>> {
+            Ok(self
+                .0
+                .iter()
+                .filter(|i| category.is_none_or(|c| i.category == c))
+                .take(limit.unwrap_or(usize::MAX))
+                .cloned()
+                .collect())
+        }
+
+        async fn get_item(&self, id: &str) -> Result<String> {
+            Ok(format!("content of {id}"))
+        }
+
+        async fn list_categories(&self) -> Result<Vec<CategoryInfo>> {
+            Ok(["public", "internal"]
+                .into_iter()
+                .map(|id| CategoryInfo {
+                    id: id.to_string(),
+                    name: id.to_string(),
+                    count: self.0.iter().filter(|i| i.category == id).count(),
+                    description: None,
+                })
+                .collect())
+        }
+    }
+
+    fn provider() -> AclContentProvider<StaticProvider> {
+        AclContentProvider::new(
+            StaticProvider(vec![
+                item("intro", "public", None),
+                item("licensed", "public", Some("paid-book")),
+                item("roadmap", "internal", None),
+            ]),
+            policy(),
+        )
+    }
+
+    fn ids(items: &[ContentItemSummary]) -> Vec<&str> {
+        items.iter().map(|i| i.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_caller_from_extensions() {
+        let caller = caller_from_extensions(&extensions_for("bob@acme.com"));
+        assert_eq!(caller.tenant(), Some("acme.com"));
+        assert!(caller_from_extensions(&Extensions::new()).is_anonymous());
+    }
+
+    #[test]
+    fn test_tool_access_allows() {
+        let access = AclToolAccess::new(policy());
+        assert!(access.allows("list_items", &Extensions::new()));
+        assert!(!access.allows("get_item", &Extensions::new()));
+        assert!(access.allows("get_item", &extensions_for("bob@acme.com")));
+        assert!(!access.allows("get_item", &extensions_for("eve@other.org")));
+    }
+
+    #[tokio::test]
+    async fn test_tool_access_scope_sets_current_caller() {
+        let access = AclToolAccess::new(policy());
+        let call: ToolResult = Box::pin(async {
+            let email = current_caller().email().unwrap_or("anonymous").to_string();
+            Ok(CallToolResult::success(vec![Content::text(email)]))
+        });
+        let result = access
+            .scope(call, &extensions_for("bob@acme.com"))
+            .await
+            .unwrap();
+        assert!(format!("{:?}", result.content[0]).contains("bob@acme.com"));
+    }
+
+    #[tokio::test]
+    async fn test_content_provider_filters_anonymous() {
+        let provider = provider();
+        let items = provider.list_items(None, None).await.unwrap();
+        assert_eq!(ids(&items), vec!["intro"]);
+        assert!(
+            provider
+                .list_items(Some("internal"), None)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(provider.count().await.unwrap(), 1);
+
+        let categories = provider.list_categories().await.unwrap();
+        assert_eq!(categories.len(), 1);
+        assert_eq!(categories[0].id, "public");
+        assert_eq!(categories[0].count, 1);
+
+        assert!(provider.get_item("intro").await.is_ok());
+        let err = provider.get_item("roadmap").await.unwrap_err();
+        assert!(err.is_not_found());
+        assert!(provider.get_item("licensed").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_content_provider_staff_sees_everything() {
+        let provider = provider();
+        with_caller(staff(), async {
+            let items = provider.list_items(None, None).await.unwrap();
+            assert_eq!(items.len(), 3);
+            assert_eq!(provider.list_items(None, Some(2)).await.unwrap().len(), 2);
+            assert_eq!(provider.list_categories().await.unwrap().len(), 2);
+            assert_eq!(
+                provider.get_item("roadmap").await.unwrap(),
+                "content of roadmap"
+            );
+        })
+        .await;
+    }
+
+    struct StaticSources;
+
+    #[async_trait]
+    impl SourceProvider for StaticSources {
+        type SourceSummary = SourceSummary;
+
+        async fn list_sources(&self) -> Result<Vec<SourceSummary>> {
+            Ok(["open-notes", "paid-book"]
+                .into_iter()
+                .map(|id| SourceSummary {
+                    id: id.to_string(),
+                    title: id.to_string(),
+                    format: fabryk_mcp_content::SourceFormat::Markdown,
+                    path: id.to_string(),
+                    chapters: None,
+                    status: fabryk_mcp_content::SourceStatus::Converted,
+                })
+                .collect())
+        }
+
+        async fn get_chapter(
+            &self,
+            source_id: &str,
+            chapter: &str,
+            _: Option<&str>,
+        ) -> Result<String> {
+            Ok(format!("{source_id}/{chapter}"))
+        }
+
+        async fn list_chapters(&self, _source_id: &str) -> Result<Vec<ChapterInfo>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_source_path(&self, _source_id: &str) -> Result<Option<std::path::PathBuf>> {
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_source_provider_filters_by_source_grants() {
+        let provider = AclSourceProvider::new(StaticSources, policy());
+
+        let sources = provider.list_sources().await.unwrap();
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].id, "open-notes");
+        assert!(provider.get_chapter("open-notes", "1", None).await.is_ok());
+        assert!(
+            provider
+                .get_chapter("paid-book", "1", None)
+                .await
+                .unwrap_err()
+                .is_not_found()
+        );
+
+        with_caller(staff(), async {
+            assert_eq!(provider.list_sources().await.unwrap().len(), 2);
+            assert!(provider.get_chapter("paid-book", "1", None).await.is_ok());
+        })
+        .await;
+    }
+}
@@ -0,0 +1,474 @@
+//! Access policies: roles, grants and role assignments.
+//!
+//! A [`Policy`] defines named roles, each granting access to MCP tools,
+//! content categories and sources by name pattern. Roles are assigned to
+//! callers by email (or subject), by tenant, and to every authenticated or
+//! anonymous caller. A caller's effective [`Permissions`] are the union of
+//! the grants of all their roles.
+//!
+//! # TOML format
+//!
+//! ```toml
+//! default = "deny"              # or "allow"
+//! anonymous = []                # roles for unauthenticated callers
+//! authenticated = ["reader"]    # roles for every authenticated caller
+//!
+//! [roles.reader]
+//! tools = ["search", "get_*", "list_*"]
+//! categories = ["public", "{tenant}/*"]
+//! sources = ["*"]
+//!
+//! [roles.admin]
+//! tools = ["*"]
+//! categories = ["*"]
+//! sources = ["*"]
+//!
+//! [tenants]
+//! "example.com" = ["reader"]
+//!
+//! [users]
+//! "alice@example.com" = ["admin"]
+//! ```
+//!
+//! Patterns match whole names, with `*` matching any run of characters.
+//! `{tenant}` in a pattern is replaced by the caller's tenant, which keeps
+//! tenants' content apart with a single role; for callers without a tenant
+//! such patterns match nothing.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use fabryk_core::{Error, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::caller::Caller;
+
+/// Placeholder replaced by the caller's tenant in grant patterns.
+const TENANT_PLACEHOLDER: &str = "{tenant}";
+
+/// What happens to a resource kind the caller holds no grants for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DefaultAccess {
+    /// Ungranted kinds are unrestricted; roles only narrow access to the
+    /// kinds they mention.
+    Allow,
+    /// Only granted resources are accessible.
+    #[default]
+    Deny,
+}
+
+/// The kinds of resource a policy controls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceKind {
+    /// An MCP tool, by name.
+    Tool,
+    /// A content category, by ID.
+    Category,
+    /// A source, by ID.
+    Source,
+}
+
+/// A named set of grants.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Role {
+    /// Tool name patterns.
+    #[serde(default)]
+    pub tools: Vec<String>,
+    /// Content category patterns.
+    #[serde(default)]
+    pub categories: Vec<String>,
+    /// Source ID patterns.
+    #[serde(default)]
+    pub sources: Vec<String>,
+}
+
+/// An access policy.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Policy {
+    /// Behaviour for resource kinds the caller holds no grants for.
+    #[serde(default)]
+    pub default: DefaultAccess,
+    /// Roles for unauthenticated callers.
+    #[serde(default)]
+    pub anonymous: Vec<String>,
+    /// Roles for every authenticated caller.
+    #[serde(default)]
+    pub authenticated: Vec<String>,
+    /// Role definitions, by name.
+    #[serde(default)]
+    pub roles: BTreeMap<String, Role>,
+    /// Roles by tenant (email domain).
+    #[serde(default)]
+    pub tenants: BTreeMap<String, Vec<String>>,
+    /// Roles by user email or subject.
+    #[serde(default)]
+    pub users: BTreeMap<String, Vec<String>>,
+}
+
+impl Policy {
+    /// A policy that allows everything.
+    pub fn allow_all() -> Self {
+        Self {
+            default: DefaultAccess::Allow,
+            ..Self::default()
+        }
+    }
+
+    /// Parse and validate a policy from TOML.
+    pub fn from_toml_str(toml: &str) -> Result<Self> {
+        let policy: Self =
+            toml::from_str(toml).map_err(|e| Error::config(format!("invalid ACL policy: {e}")))?;
+        policy.validate()?;
+        Ok(policy)
+    }
+
+    /// Load and validate a policy from a TOML file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|e| Error::io_with_path(e, path))?;
+        Self::from_toml_str(&content).map_err(|e| Error::config(format!("{}: {e}", path.display())))
+    }
+
+    /// Check that every assigned role is defined.
+    pub fn validate(&self) -> Result<()> {
+        let assignments = [
+            ("anonymous", &self.anonymous),
+            ("authenticated", &self.authenticated),
+        ]
+        .into_iter()
+        .map(|(who, roles)| (who.to_string(), roles))
+        .chain(
+            self.tenants
+                .iter()
+                .map(|(tenant, roles)| (format!("tenant '{tenant}'"), roles)),
+        )
+        .chain(
+            self.users
+                .iter()
+                .map(|(user, roles)| (format!("user '{user}'"), roles)),
+        );
+
+        for (who, roles) in assignments {
+            if let Some(role) = roles.iter().find(|r| !self.roles.contains_key(*r)) {
+                return Err(Error::config(format!(
+                    "ACL policy assigns undefined role '{role}' to {who}"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// The names of the roles assigned to `caller`.
+    pub fn roles_for(&self, caller: &Caller) -> Vec<&str> {
+        let assigned: Vec<&Vec<String>> = if caller.is_anonymous() {
+            vec![&self.anonymous]
+        } else {
+            let by_tenant = caller.tenant().and_then(|t| self.tenants.get(t));
+            let by_user = [caller.email(), caller.subject()]
+                .into_iter()
+                .flatten()
+                .filter_map(|key| self.users.get(key));
+            std::iter::once(&self.authenticated)
+                .chain(by_tenant)
+                .chain(by_user)
+                .collect()
+        };
+
+        let mut roles: Vec<&str> = Vec::new();
+        for name in assigned.into_iter().flatten() {
+            if !roles.contains(&name.as_str()) {
+                roles.push(name);
+            }
+        }
+        roles
+    }
+
+    /// The effective permissions of `caller`.
+    pub fn permissions(&self, caller: &Caller) -> Permissions {
+        let mut permissions = Permissions {
+            default: self.default,
+            ..Permissions::default()
+        };
+        let tenant = caller.tenant();
+        let resolve = |patterns: &[String], into: &mut Vec<String>| {
+            for pattern in patterns {
+                let pattern = match (pattern.contains(TENANT_PLACEHOLDER), tenant) {
+                    (false, _) => pattern.clone(),
+                    (true, Some(tenant)) => pattern.replace(TENANT_PLACEHOLDER, tenant),
+                    (true, None) => continue,
+                };
+                if !into.contains(&pattern) {
+                    into.push(pattern);
+                }
+            }
+        };
+
+        for role in self.roles_for(caller) {
+            if let Some(role) = self.roles.get(role) {
+                resolve(&role.tools, &mut permissions.tools);
+                resolve(&role.categories, &mut permissions.categories);
+                resolve(&role.sources, &mut permissions.sources);
+            }
+        }
+        permissions
+    }
+}
+
+/// A caller's effective grants.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Permissions {
+    default: DefaultAccess,
+    tools: Vec<String>,
+    categories: Vec<String>,
+    sources: Vec<String>,
+}
+
+impl Permissions {
+    /// Whether the caller may access the resource `name` of `kind`.
+    pub fn allows(&self, kind: ResourceKind, name: &str) -> bool {
+        let patterns = match kind {
+            ResourceKind::Tool => &self.tools,
+            ResourceKind::Category => &self.categories,
+            ResourceKind::Source => &self.sources,
+        };
+        if patterns.is_empty() {
+            return self.default == DefaultAccess::Allow;
+        }
+        patterns.iter().any(|p| pattern_matches(p, name))
+    }
+
+    /// Whether the caller may list and call the tool `name`.
+    pub fn can_use_tool(&self, name: &str) -> bool {
+        self.allows(ResourceKind::Tool, name)
+    }
+
+    /// Whether the caller may read content in `category`.
+    pub fn can_read_category(&self, category: &str) -> bool {
+        self.allows(ResourceKind::Category, category)
+    }
+
+    /// Whether the caller may read the source `source_id`.
+    pub fn can_read_source(&self, source_id: &str) -> bool {
+        self.allows(ResourceKind::Source, source_id)
+    }
+}
+
+/// Match `name` against a pattern in which `*` matches any run of characters.
+fn pattern_matches(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts: Vec<&str> = parts.collect();
+    let Some(last) = parts.pop() else {
+        // No wildcard: the prefix must be the whole name.
+        return rest.is_empty();
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fabryk_auth::AuthenticatedUser;
+
+    const POLICY: &str = r#"
+        default = "deny"
+        anonymous = ["public"]
+        authenticated = ["reader"]
+
+        [roles.public]
+        tools = ["health"]
+        categories = ["public"]
+
+        [roles.reader]
+        tools = ["search", "get_*"]
+        categories = ["public", "{tenant}/*"]
+        sources = ["*"]
+
+        [roles.admin]
+        tools = ["*"]
+        categories = ["*"]
+        sources = ["*"]
+
+        [tenants]
+        "acme.com" = ["reader"]
+
+        [users]
+        "root@acme.com" = ["admin"]
+    "#;
+
+    fn caller(email: &str) -> Caller {
+        Caller::from_user(&AuthenticatedUser {
+            email: email.to_string(),
+            subject: format!("sub-{email}"),
+        })
+    }
+
+    #[test]
+    fn test_pattern_matches() {
+        assert!(pattern_matches("*", "anything"));
+        assert!(pattern_matches("*", ""));
+        assert!(pattern_matches("search", "search"));
+        assert!(!pattern_matches("search", "search_fts"));
+        assert!(pattern_matches("get_*", "get_item"));
+        assert!(!pattern_matches("get_*", "list_items"));
+        assert!(pattern_matches("*_items", "list_items"));
+        assert!(pattern_matches("a*b*c", "a-x-b-y-c"));
+        assert!(!pattern_matches("a*b*c", "a-x-c"));
+        assert!(!pattern_matches("ab*ba", "aba"));
+    }
+
+    #[test]
+    fn test_from_toml_str() {
+        let policy = Policy::from_toml_str(POLICY).unwrap();
+        assert_eq!(policy.default, DefaultAccess::Deny);
+        assert_eq!(policy.roles.len(), 3);
+        assert_eq!(policy.users["root@acme.com"], vec!["admin"]);
+    }
+
+    #[test]
+    fn test_from_toml_str_defaults_to_deny() {
+        let policy = Policy::from_toml_str("").unwrap();
+        assert_eq!(policy.default, DefaultAccess::Deny);
+        assert!(
+            !policy
+                .permissions(&Caller::anonymous())
+                .can_use_tool("health")
+        );
+    }
+
+    #[test]
+    fn test_from_toml_str_rejects_undefined_role() {
+        let err = Policy::from_toml_str("[users]\n\"a@b.com\" = [\"ghost\"]\n").unwrap_err();
+        assert!(err.to_string().contains("undefined role 'ghost'"));
+        assert!(err.to_string().contains("user 'a@b.com'"));
+    }
+
+    #[test]
+    fn test_from_toml_str_rejects_unknown_fields() {
+        assert!(Policy::from_toml_str("defualt = \"allow\"").is_err());
+        assert!(Policy::from_toml_str("[roles.x]\ntool = [\"*\"]").is_err());
+    }
+
+    #[test]
+    fn test_load_from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("acl.toml");
+        std::fs::write(&path, POLICY).unwrap();
+        let policy = Policy::load(&path).unwrap();
+        assert_eq!(policy.roles.len(), 3);
+
+        assert!(Policy::load(dir.path().join("missing.toml")).is_err());
+    }
+
+    #[test]
+    fn test_roles_for() {
+        let policy = Policy::from_toml_str(POLICY).unwrap();
+        assert_eq!(policy.roles_for(&Caller::anonymous()), vec!["public"]);
+        assert_eq!(policy.roles_for(&caller("bob@acme.com")), vec!["reader"]);
+        assert_eq!(
+            policy.roles_for(&caller("root@acme.com")),
+            vec!["reader", "admin"]
+        );
+    }
+
+    #[test]
+    fn test_roles_for_matches_subject() {
+        let mut policy = Policy::from_toml_str(POLICY).unwrap();
+        policy
+            .users
+            .insert("sub-carol@other.org".to_string(), vec!["admin".to_string()]);
+        assert!(
+            policy
+                .roles_for(&caller("carol@other.org"))
+                .contains(&"admin")
+        );
+    }
+
+    #[test]
+    fn test_permissions_deny_by_default() {
+        let policy = Policy::from_toml_str(POLICY).unwrap();
+
+        let anonymous = policy.permissions(&Caller::anonymous());
+        assert!(anonymous.can_use_tool("health"));
+        assert!(!anonymous.can_use_tool("search"));
+        assert!(anonymous.can_read_category("public"));
+        assert!(!anonymous.can_read_source("any-book"));
+
+        let reader = policy.permissions(&caller("bob@acme.com"));
+        assert!(reader.can_use_tool("search"));
+        assert!(reader.can_use_tool("get_item"));
+        assert!(!reader.can_use_tool("delete_item"));
+        assert!(reader.can_read_source("any-book"));
+
+        let admin = policy.permissions(&caller("root@acme.com"));
+        assert!(admin.can_use_tool("delete_item"));
+        assert!(admin.can_read_category("other.org/private"));
+    }
+
+    #[test]
+    fn test_permissions_tenant_isolation() {
+        let policy = Policy::from_toml_str(POLICY).unwrap();
+        let acme = policy.permissions(&caller("bob@acme.com"));
+        let other = policy.permissions(&caller("eve@other.org"));
+
+        assert!(acme.can_read_category("acme.com/roadmap"));
+        assert!(!acme.can_read_category("other.org/roadmap"));
+        assert!(other.can_read_category("other.org/roadmap"));
+        assert!(!other.can_read_category("acme.com/roadmap"));
+    }
+
+    #[test]
+    fn test_permissions_tenant_pattern_skipped_without_tenant() {
+        let policy = Policy::from_toml_str(POLICY).unwrap();
+        let no_domain = policy.permissions(&caller("localuser"));
+        assert!(no_domain.can_read_category("public"));
+        assert!(!no_domain.can_read_category("/x"));
+    }
+
+    #[test]
+    fn test_permissions_allow_mode_only_narrows_granted_kinds() {
+        let policy = Policy::from_toml_str(
+            r#"
+            default = "allow"
+            authenticated = ["limited"]
+
+            [roles.limited]
+            tools = ["search"]
+            "#,
+        )
+        .unwrap();
+
+        let limited = policy.permissions(&caller("bob@acme.com"));
+        assert!(limited.can_use_tool("search"));
+        assert!(!limited.can_use_tool("delete_item"));
+        // No category or source grants: unrestricted in allow mode.
+        assert!(limited.can_read_category("anything"));
+        assert!(limited.can_read_source("anything"));
+
+        let anonymous = policy.permissions(&Caller::anonymous());
+        assert!(anonymous.can_use_tool("delete_item"));
+    }
+
+    #[test]
+    fn test_allow_all() {
+        let permissions = Policy::allow_all().permissions(&Caller::anonymous());
+        assert!(permissions.can_use_tool("anything"));
+        assert!(permissions.can_read_category("anything"));
+    }
+}
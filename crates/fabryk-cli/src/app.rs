@@ -146,10 +146,36 @@ impl<C: ConfigProvider> FabrykCli<C> {
             }
             GraphSubcommand::Validate => graph_handlers::handle_validate(&*self.config).await,
             GraphSubcommand::Stats => graph_handlers::handle_stats(&*self.config).await,
-            GraphSubcommand::Query { id, query_type, to } => {
-                let options = graph_handlers::QueryOptions { id, query_type, to };
+            GraphSubcommand::Query {
+                id,
+                query_type,
+                to,
+                algorithm,
+            } => {
+                let options = graph_handlers::QueryOptions {
+                    id,
+                    query_type,
+                    to,
+                    algorithm,
+                };
                 graph_handlers::handle_query(&*self.config, options).await
             }
+            GraphSubcommand::Export { format, output } => {
+                let options = graph_handlers::ExportOptions { format, output };
+                graph_handlers::handle_export(&*self.config, options).await
+            }
+            GraphSubcommand::Import {
+                input,
+                format,
+                dry_run,
+            } => {
+                let options = graph_handlers::ImportOptions {
+                    input,
+                    format,
+                    dry_run,
+                };
+                graph_handlers::handle_import(&*self.config, options).await
+            }
         }
     }
 }
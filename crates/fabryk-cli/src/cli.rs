@@ -160,17 +160,46 @@ pub enum GraphSubcommand {
 
     /// Query the graph.
     Query {
-        /// Node ID to query.
+        /// Node ID to query (not needed for clusters).
         #[arg(short, long)]
-        id: String,
+        id: Option<String>,
 
-        /// Type of query: related, prerequisites, path.
+        /// Type of query: related, prerequisites, path, clusters.
         #[arg(short = 't', long, default_value = "related")]
         query_type: String,
 
         /// Target node ID (for path queries).
         #[arg(long)]
         to: Option<String>,
+
+        /// Clustering algorithm (for clusters queries): leiden, louvain, label_propagation.
+        #[arg(long)]
+        algorithm: Option<String>,
+    },
+
+    /// Export the graph to an interchange format.
+    Export {
+        /// Output format: graphml, dot, jsonld, csv, json.
+        #[arg(short, long, default_value = "graphml")]
+        format: String,
+
+        /// Output file path (a directory for csv).
+        #[arg(short, long)]
+        output: String,
+    },
+
+    /// Import a graph file and merge it into the graph.
+    Import {
+        /// File to import (a directory or edge table for csv).
+        input: String,
+
+        /// Input format: graphml, csv, json. Inferred from the path if omitted.
+        #[arg(short, long)]
+        format: Option<String>,
+
+        /// Show what would be merged without writing.
+        #[arg(long)]
+        dry_run: bool,
     },
 }
 
@@ -409,9 +438,12 @@ mod tests {
         let args = CliArgs::parse_from(["test", "graph", "query", "--id", "node-1"]);
         match args.command {
             Some(BaseCommand::Graph(GraphCommand {
-                command: GraphSubcommand::Query { id, query_type, to },
+                command:
+                    GraphSubcommand::Query {
+                        id, query_type, to, ..
+                    },
             })) => {
-                assert_eq!(id, "node-1");
+                assert_eq!(id.as_deref(), Some("node-1"));
                 assert_eq!(query_type, "related");
                 assert!(to.is_none());
             }
@@ -434,9 +466,12 @@ mod tests {
         ]);
         match args.command {
             Some(BaseCommand::Graph(GraphCommand {
-                command: GraphSubcommand::Query { id, query_type, to },
+                command:
+                    GraphSubcommand::Query {
+                        id, query_type, to, ..
+                    },
             })) => {
-                assert_eq!(id, "a");
+                assert_eq!(id.as_deref(), Some("a"));
                 assert_eq!(query_type, "path");
                 assert_eq!(to, Some("b".to_string()));
             }
@@ -444,6 +479,77 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_graph_query_clusters() {
+        let args = CliArgs::parse_from([
+            "test",
+            "graph",
+            "query",
+            "--query-type",
+            "clusters",
+            "--algorithm",
+            "louvain",
+        ]);
+        match args.command {
+            Some(BaseCommand::Graph(GraphCommand {
+                command:
+                    GraphSubcommand::Query {
+                        id,
+                        query_type,
+                        algorithm,
+                        ..
+                    },
+            })) => {
+                assert!(id.is_none());
+                assert_eq!(query_type, "clusters");
+                assert_eq!(algorithm.as_deref(), Some("louvain"));
+            }
+            _ => panic!("Expected Graph Query clusters command"),
+        }
+    }
+
+    #[test]
+    fn test_graph_export_command() {
+        let args = CliArgs::parse_from(["test", "graph", "export", "-o", "graph.graphml"]);
+        match args.command {
+            Some(BaseCommand::Graph(GraphCommand {
+                command: GraphSubcommand::Export { format, output },
+            })) => {
+                assert_eq!(format, "graphml");
+                assert_eq!(output, "graph.graphml");
+            }
+            _ => panic!("Expected Graph Export command"),
+        }
+    }
+
+    #[test]
+    fn test_graph_import_command() {
+        let args = CliArgs::parse_from([
+            "test",
+            "graph",
+            "import",
+            "curated.csv",
+            "--format",
+            "csv",
+            "--dry-run",
+        ]);
+        match args.command {
+            Some(BaseCommand::Graph(GraphCommand {
+                command:
+                    GraphSubcommand::Import {
+                        input,
+                        format,
+                        dry_run,
+                    },
+            })) => {
+                assert_eq!(input, "curated.csv");
+                assert_eq!(format.as_deref(), Some("csv"));
+                assert!(dry_run);
+            }
+            _ => panic!("Expected Graph Import command"),
+        }
+    }
+
     // ------------------------------------------------------------------------
     // Config command tests
     // ------------------------------------------------------------------------
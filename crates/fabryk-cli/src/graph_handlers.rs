@@ -1,15 +1,17 @@
 //! Handler functions for graph CLI commands.
 //!
 //! These functions implement the logic behind `graph validate`, `graph stats`,
-//! `graph query`, and `graph build` (with a domain-provided extractor).
+//! `graph query`, `graph export`, `graph import`, and `graph build` (with a
+//! domain-provided extractor).
 
 use fabryk_core::traits::ConfigProvider;
 use fabryk_core::{Error, Result};
 use fabryk_graph::{
-    GraphBuilder, GraphData, GraphExtractor, GraphMetadata, compute_stats, load_graph,
+    ClusterOptions, ExportFormat, GraphBuilder, GraphData, GraphExtractor, GraphMetadata,
+    compute_stats, detect_clusters, export_graph, import_graph, load_graph, merge_graph,
     neighborhood, prerequisites_sorted, save_graph, shortest_path, validate_graph,
 };
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 // ============================================================================
 // Option types
@@ -27,12 +29,34 @@ pub struct BuildOptions {
 /// Options for graph query operations.
 #[derive(Debug, Clone)]
 pub struct QueryOptions {
-    /// Node ID to query.
-    pub id: String,
-    /// Type of query: "related", "prerequisites", or "path".
+    /// Node ID to query (required except for "clusters").
+    pub id: Option<String>,
+    /// Type of query: "related", "prerequisites", "path", or "clusters".
     pub query_type: String,
     /// Target node for path queries.
     pub to: Option<String>,
+    /// Clustering algorithm for "clusters" queries (default leiden).
+    pub algorithm: Option<String>,
+}
+
+/// Options for graph export operations.
+#[derive(Debug, Clone)]
+pub struct ExportOptions {
+    /// Format name: "graphml", "dot", "jsonld", "csv", or "json".
+    pub format: String,
+    /// Output file path (a directory for CSV).
+    pub output: String,
+}
+
+/// Options for graph import operations.
+#[derive(Debug, Clone)]
+pub struct ImportOptions {
+    /// File (or CSV directory) to import.
+    pub input: String,
+    /// Format name; inferred from the input path when `None`.
+    pub format: Option<String>,
+    /// If true, report what would be merged without writing.
+    pub dry_run: bool,
 }
 
 // ============================================================================
@@ -205,19 +229,91 @@ pub async fn handle_query<C: ConfigProvider>(config: &C, options: QueryOptions)
     let path = graph_path(config)?;
     let graph = load_graph_or_error(&path)?;
 
+    let id = || {
+        options.id.as_deref().ok_or_else(|| {
+            Error::config(format!(
+                "--id is required for {} queries",
+                options.query_type
+            ))
+        })
+    };
+
     match options.query_type.as_str() {
-        "related" => query_related(&graph, &options.id).await,
-        "prerequisites" => query_prerequisites(&graph, &options.id).await,
+        "related" => query_related(&graph, id()?).await,
+        "prerequisites" => query_prerequisites(&graph, id()?).await,
         "path" => {
             let to = options
                 .to
+                .as_deref()
                 .ok_or_else(|| Error::config("--to is required for path queries"))?;
-            query_path(&graph, &options.id, &to).await
+            query_path(&graph, id()?, to).await
         }
+        "clusters" => query_clusters(&graph, options.algorithm.as_deref()).await,
         other => Err(Error::config(format!("Unknown query type: {other}"))),
     }
 }
 
+/// Export the graph to an interchange format.
+pub async fn handle_export<C: ConfigProvider>(config: &C, options: ExportOptions) -> Result<()> {
+    let path = graph_path(config)?;
+    let graph = load_graph_or_error(&path)?;
+    let format: ExportFormat = options.format.parse()?;
+
+    export_graph(&graph, format, &options.output)?;
+    println!(
+        "Exported {} nodes and {} edges as {format} to: {}",
+        graph.node_count(),
+        graph.edge_count(),
+        options.output
+    );
+
+    Ok(())
+}
+
+/// Import a graph file and merge it into the graph.
+///
+/// Starts from an empty graph if none has been built yet. Existing nodes
+/// are kept; new nodes and edges are added.
+pub async fn handle_import<C: ConfigProvider>(config: &C, options: ImportOptions) -> Result<()> {
+    let input = Path::new(&options.input);
+    let format = match options.format {
+        Some(ref name) => name.parse()?,
+        None => ExportFormat::from_path(input).ok_or_else(|| {
+            Error::config(format!(
+                "Cannot infer the format of {}; pass --format",
+                input.display()
+            ))
+        })?,
+    };
+    let imported = import_graph(input, format)?;
+
+    let path = graph_path(config)?;
+    let mut graph = if path.exists() {
+        load_graph(&path)?
+    } else {
+        GraphData::new()
+    };
+    let stats = merge_graph(&mut graph, &imported);
+
+    println!("Imported {} ({format}):", input.display());
+    println!("  Nodes added:     {}", stats.nodes_added);
+    println!("  Nodes existing:  {}", stats.nodes_existing);
+    println!("  Edges added:     {}", stats.edges_added);
+    println!("  Edges duplicate: {}", stats.edges_duplicate);
+
+    if options.dry_run {
+        println!("\nDry run — graph not saved.");
+    } else {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| Error::io_with_path(e, parent))?;
+        }
+        save_graph(&graph, &path, Some(GraphMetadata::default()))?;
+        println!("\nGraph saved to: {}", path.display());
+    }
+
+    Ok(())
+}
+
 // ============================================================================
 // Query implementations
 // ============================================================================
@@ -278,6 +374,44 @@ async fn query_path(graph: &GraphData, from: &str, to: &str) -> Result<()> {
     Ok(())
 }
 
+async fn query_clusters(graph: &GraphData, algorithm: Option<&str>) -> Result<()> {
+    let mut options = ClusterOptions::default();
+    if let Some(name) = algorithm {
+        options.algorithm = name.parse()?;
+    }
+    let result = detect_clusters(graph, &options)?;
+
+    println!(
+        "Clusters ({}, modularity {:.3}):",
+        result.algorithm, result.modularity
+    );
+    if result.clusters.is_empty() {
+        println!("  (no clusters)");
+    }
+    for cluster in &result.clusters {
+        let category = match (&cluster.dominant_category, cluster.categories.first()) {
+            (Some(dominant), _) => dominant.clone(),
+            (None, Some(_)) => "mixed".to_string(),
+            (None, None) => "uncategorized".to_string(),
+        };
+        println!(
+            "\n  #{} — {} node(s), {category}, conductance {:.2}",
+            cluster.id, cluster.size, cluster.conductance
+        );
+        for score in &cluster.top_nodes {
+            let title = graph
+                .get_node(&score.node_id)
+                .map_or("", |n| n.title.as_str());
+            println!("    - {} ({title})", score.node_id);
+        }
+    }
+    if !result.unclustered.is_empty() {
+        println!("\n{} unclustered node(s)", result.unclustered.len());
+    }
+
+    Ok(())
+}
+
 // ============================================================================
 // Helpers
 // ============================================================================
@@ -414,9 +548,10 @@ mod tests {
         };
 
         let options = QueryOptions {
-            id: "a".to_string(),
+            id: Some("a".to_string()),
             query_type: "related".to_string(),
             to: None,
+            algorithm: None,
         };
 
         let result = handle_query(&config, options).await;
@@ -433,9 +568,10 @@ mod tests {
         };
 
         let options = QueryOptions {
-            id: "nonexistent".to_string(),
+            id: Some("nonexistent".to_string()),
             query_type: "related".to_string(),
             to: None,
+            algorithm: None,
         };
 
         let result = handle_query(&config, options).await;
@@ -456,9 +592,10 @@ mod tests {
         };
 
         let options = QueryOptions {
-            id: "c".to_string(),
+            id: Some("c".to_string()),
             query_type: "prerequisites".to_string(),
             to: None,
+            algorithm: None,
         };
 
         let result = handle_query(&config, options).await;
@@ -479,9 +616,10 @@ mod tests {
         };
 
         let options = QueryOptions {
-            id: "a".to_string(),
+            id: Some("a".to_string()),
             query_type: "path".to_string(),
             to: Some("c".to_string()),
+            algorithm: None,
         };
 
         let result = handle_query(&config, options).await;
@@ -498,9 +636,10 @@ mod tests {
         };
 
         let options = QueryOptions {
-            id: "a".to_string(),
+            id: Some("a".to_string()),
             query_type: "path".to_string(),
             to: None,
+            algorithm: None,
         };
 
         let result = handle_query(&config, options).await;
@@ -521,9 +660,10 @@ mod tests {
         };
 
         let options = QueryOptions {
-            id: "a".to_string(),
+            id: Some("a".to_string()),
             query_type: "unknown".to_string(),
             to: None,
+            algorithm: None,
         };
 
         let result = handle_query(&config, options).await;
@@ -554,6 +694,146 @@ mod tests {
         assert_eq!(options.output.unwrap(), "/tmp/graph.json");
     }
 
+    #[tokio::test]
+    async fn test_handle_query_clusters() {
+        let dir = tempdir().unwrap();
+        setup_graph(dir.path());
+
+        let config = TestConfig {
+            base: dir.path().to_path_buf(),
+        };
+
+        let options = QueryOptions {
+            id: None,
+            query_type: "clusters".to_string(),
+            to: None,
+            algorithm: Some("louvain".to_string()),
+        };
+
+        let result = handle_query(&config, options).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_handle_query_missing_id() {
+        let dir = tempdir().unwrap();
+        setup_graph(dir.path());
+
+        let config = TestConfig {
+            base: dir.path().to_path_buf(),
+        };
+
+        let options = QueryOptions {
+            id: None,
+            query_type: "related".to_string(),
+            to: None,
+            algorithm: None,
+        };
+
+        let result = handle_query(&config, options).await;
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("--id"));
+    }
+
+    // ------------------------------------------------------------------------
+    // export / import handlers
+    // ------------------------------------------------------------------------
+
+    #[tokio::test]
+    async fn test_handle_export_graphml() {
+        let dir = tempdir().unwrap();
+        setup_graph(dir.path());
+        let config = TestConfig {
+            base: dir.path().to_path_buf(),
+        };
+        let output = dir.path().join("graph.graphml");
+
+        let options = ExportOptions {
+            format: "graphml".to_string(),
+            output: output.to_string_lossy().to_string(),
+        };
+        handle_export(&config, options).await.unwrap();
+
+        let xml = std::fs::read_to_string(&output).unwrap();
+        assert!(xml.contains("<graphml"));
+    }
+
+    #[tokio::test]
+    async fn test_handle_export_unknown_format() {
+        let dir = tempdir().unwrap();
+        setup_graph(dir.path());
+        let config = TestConfig {
+            base: dir.path().to_path_buf(),
+        };
+
+        let options = ExportOptions {
+            format: "svg".to_string(),
+            output: dir.path().join("out.svg").to_string_lossy().to_string(),
+        };
+        assert!(handle_export(&config, options).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_handle_import_merges_into_graph() {
+        let dir = tempdir().unwrap();
+        let graph_path = setup_graph(dir.path());
+        let config = TestConfig {
+            base: dir.path().to_path_buf(),
+        };
+        let input = dir.path().join("curated.csv");
+        std::fs::write(
+            &input,
+            "source,target,relationship\na,b,prerequisite\nc,d,relates_to\n",
+        )
+        .unwrap();
+
+        let options = ImportOptions {
+            input: input.to_string_lossy().to_string(),
+            format: None,
+            dry_run: false,
+        };
+        handle_import(&config, options).await.unwrap();
+
+        let graph = load_graph(&graph_path).unwrap();
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(graph.edge_count(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_handle_import_dry_run_does_not_write() {
+        let dir = tempdir().unwrap();
+        let config = TestConfig {
+            base: dir.path().to_path_buf(),
+        };
+        let input = dir.path().join("curated.csv");
+        std::fs::write(&input, "source,target,relationship\nx,y,relates_to\n").unwrap();
+
+        let options = ImportOptions {
+            input: input.to_string_lossy().to_string(),
+            format: Some("csv".to_string()),
+            dry_run: true,
+        };
+        handle_import(&config, options).await.unwrap();
+        assert!(!graph_path(&config).unwrap().exists());
+    }
+
+    #[tokio::test]
+    async fn test_handle_import_rejects_export_only_format() {
+        let dir = tempdir().unwrap();
+        let config = TestConfig {
+            base: dir.path().to_path_buf(),
+        };
+        let input = dir.path().join("graph.dot");
+        std::fs::write(&input, "digraph {}").unwrap();
+
+        let options = ImportOptions {
+            input: input.to_string_lossy().to_string(),
+            format: None,
+            dry_run: false,
+        };
+        assert!(handle_import(&config, options).await.is_err());
+    }
+
     // ------------------------------------------------------------------------
     // helper: graph_path
     // ------------------------------------------------------------------------
@@ -49,9 +49,10 @@ pub mod sources;
 // Re-export commonly used types
 pub use concept_card::ConceptCardFrontmatter;
 pub use markdown::{
-    FrontmatterResult, extract_all_list_items, extract_first_heading, extract_first_paragraph,
-    extract_frontmatter, extract_list_from_section, extract_section_content, extract_string_array,
-    extract_text_content, normalize_id, parse_comma_list, parse_keyword_list, strip_frontmatter,
+    FrontmatterResult, MarkdownSection, extract_all_list_items, extract_first_heading,
+    extract_first_paragraph, extract_frontmatter, extract_list_from_section,
+    extract_section_content, extract_string_array, extract_text_content, normalize_id,
+    parse_comma_list, parse_keyword_list, split_sections, strip_frontmatter,
 };
 
 pub use metadata::{ContentMetadata, ContentType, detect_content_type, extract_metadata};
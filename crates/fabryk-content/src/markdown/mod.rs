@@ -45,4 +45,7 @@ pub use helpers::{
     extract_all_list_items, extract_list_from_section, extract_section_content, normalize_id,
     parse_comma_list, parse_keyword_list,
 };
-pub use parser::{extract_first_heading, extract_first_paragraph, extract_text_content};
+pub use parser::{
+    MarkdownSection, extract_first_heading, extract_first_paragraph, extract_text_content,
+    split_sections,
+};
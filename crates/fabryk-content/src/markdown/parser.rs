@@ -6,6 +6,7 @@
 //! - Extract first heading (any level)
 //! - Extract first paragraph
 //! - Strip formatting to get plain text
+//! - Split content into heading sections
 //!
 //! # Example
 //!
@@ -23,6 +24,8 @@
 //! assert_eq!(paragraph, "This is the first paragraph.");
 //! ```
 
+use std::ops::Range;
+
 use pulldown_cmark::{Event, HeadingLevel, Parser, Tag, TagEnd};
 
 /// Extract the first heading from markdown content.
@@ -218,6 +221,102 @@ pub fn extract_text_content(content: &str) -> String {
     normalize_whitespace(&text_content)
 }
 
+/// A section of markdown content, starting at a heading.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownSection {
+    /// Heading text, or `None` for content before the first heading.
+    pub heading: Option<String>,
+
+    /// Heading level, or `None` for content before the first heading.
+    pub level: Option<HeadingLevel>,
+
+    /// Byte range of the section in the source, including its heading.
+    pub range: Range<usize>,
+}
+
+/// Split markdown content into sections at headings.
+///
+/// A new section starts at every heading of `max_level` or higher (e.g.
+/// with `HeadingLevel::H2`, at `#` and `##` but not `###`). Deeper headings
+/// stay inside their enclosing section. Content before the first heading
+/// becomes a section with no heading, unless it is blank.
+///
+/// The ranges are contiguous and cover the content from the first
+/// non-blank byte to the end.
+///
+/// # Example
+///
+/// ```rust
+/// use fabryk_content::markdown::parser::split_sections;
+/// use pulldown_cmark::HeadingLevel;
+///
+/// let content = "Intro\n\n# One\n\nFirst\n\n## Detail\n\nMore\n\n# Two\n\nSecond\n";
+/// let sections = split_sections(content, HeadingLevel::H1);
+/// assert_eq!(sections.len(), 3);
+/// assert_eq!(sections[0].heading, None);
+/// assert_eq!(sections[1].heading.as_deref(), Some("One"));
+/// assert!(content[sections[1].range.clone()].contains("## Detail"));
+/// assert_eq!(sections[2].heading.as_deref(), Some("Two"));
+/// ```
+pub fn split_sections(content: &str, max_level: HeadingLevel) -> Vec<MarkdownSection> {
+    let mut sections = Vec::new();
+    let mut current = MarkdownSection {
+        heading: None,
+        level: None,
+        range: 0..content.len(),
+    };
+    let mut heading_text: Option<String> = None;
+
+    for (event, offset) in Parser::new(content).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) if level <= max_level => {
+                current.range.end = offset.start;
+                let previous = std::mem::replace(
+                    &mut current,
+                    MarkdownSection {
+                        heading: None,
+                        level: Some(level),
+                        range: offset.start..content.len(),
+                    },
+                );
+                if previous.level.is_some() || !content[previous.range.clone()].trim().is_empty() {
+                    sections.push(previous);
+                }
+                heading_text = Some(String::new());
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some(text) = heading_text.take() {
+                    current.heading = Some(text.trim().to_string());
+                }
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some(ref mut heading) = heading_text {
+                    heading.push_str(&text);
+                }
+            }
+            Event::SoftBreak | Event::HardBreak => {
+                if let Some(ref mut heading) = heading_text {
+                    heading.push(' ');
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if current.level.is_some() || !content[current.range.clone()].trim().is_empty() {
+        sections.push(current);
+    }
+
+    // Leading blank lines belong to no section
+    if let Some(first) = sections.first_mut()
+        && first.level.is_none()
+    {
+        first.range.start = content.len() - content.trim_start().len();
+    }
+
+    sections
+}
+
 /// Truncate text to a maximum length, adding "..." if truncated.
 fn truncate_text(text: &str, max_chars: usize) -> String {
     if text.len() <= max_chars {
@@ -413,4 +512,59 @@ mod tests {
         let para = extract_first_paragraph(content, 100).unwrap();
         assert_eq!(para, "これは日本語のテキストです。");
     }
+
+    // ------------------------------------------------------------------------
+    // split_sections tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_split_sections_by_level() {
+        let content = "# One\n\nFirst\n\n## Detail\n\nMore\n\n# Two\n\nSecond\n";
+
+        let sections = split_sections(content, HeadingLevel::H1);
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].level, Some(HeadingLevel::H1));
+        assert_eq!(
+            &content[sections[0].range.clone()],
+            "# One\n\nFirst\n\n## Detail\n\nMore\n\n"
+        );
+        assert_eq!(&content[sections[1].range.clone()], "# Two\n\nSecond\n");
+
+        let sections = split_sections(content, HeadingLevel::H2);
+        let headings: Vec<_> = sections.iter().map(|s| s.heading.as_deref()).collect();
+        assert_eq!(headings, vec![Some("One"), Some("Detail"), Some("Two")]);
+    }
+
+    #[test]
+    fn test_split_sections_preamble() {
+        let content = "\n\nIntro text\n\n# Heading\n\nBody\n";
+        let sections = split_sections(content, HeadingLevel::H1);
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].heading, None);
+        assert_eq!(&content[sections[0].range.clone()], "Intro text\n\n");
+        assert_eq!(sections[1].range.end, content.len());
+    }
+
+    #[test]
+    fn test_split_sections_blank_preamble_dropped() {
+        let content = "\n# Only\n\nBody\n";
+        let sections = split_sections(content, HeadingLevel::H6);
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].heading.as_deref(), Some("Only"));
+    }
+
+    #[test]
+    fn test_split_sections_formatted_heading() {
+        let content = "## The `main` **function**\n\nText";
+        let sections = split_sections(content, HeadingLevel::H2);
+        assert_eq!(sections[0].heading.as_deref(), Some("The main function"));
+    }
+
+    #[test]
+    fn test_split_sections_no_headings() {
+        assert_eq!(split_sections("", HeadingLevel::H1), Vec::new());
+        let sections = split_sections("Just text", HeadingLevel::H1);
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].range, 0..9);
+    }
 }
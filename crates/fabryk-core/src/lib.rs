@@ -10,6 +10,7 @@
 //! - [`state`]: Generic application state container
 //! - [`traits`]: Core traits for domain abstraction
 //! - [`util`]: File, path, and ID utilities
+//! - [`watcher`]: Live content watching with debounced change handlers
 
 #![doc = include_str!("../README.md")]
 
@@ -22,6 +23,7 @@ pub mod slot;
 pub mod state;
 pub mod traits;
 pub mod util;
+pub mod watcher;
 
 // Re-export key types at crate root for convenience
 pub use error::{Error, Result, log_error_chain};
@@ -34,6 +36,9 @@ pub use slot::BackendSlot;
 pub use state::AppState;
 pub use traits::ConfigManager;
 pub use traits::ConfigProvider;
+pub use watcher::{
+    ChangeHandler, ChangeKind, ChangeSet, ContentSnapshot, ContentWatcher, WatcherHandle,
+};
 
 // Convenience re-exports from util
 pub use util::ids::{humanize_id, id_from_path, normalize_id};
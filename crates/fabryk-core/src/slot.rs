@@ -78,6 +78,21 @@ impl<B> BackendSlot<B> {
         Ok(())
     }
 
+    /// Atomically replace the backend value, returning the previous one.
+    ///
+    /// Readers see either the old or the new backend, never an empty slot,
+    /// so a live reload can build the replacement off to the side and swap
+    /// it in without leaving [`ServiceState::Ready`].
+    pub fn swap(&self, value: B) -> Result<Option<B>, Error> {
+        let mut guard = self.backend.write().map_err(|_| {
+            Error::operation(format!(
+                "Failed to acquire write lock for {}",
+                self.service.name()
+            ))
+        })?;
+        Ok(guard.replace(value))
+    }
+
     /// Acquire a read guard, returning an error if the service is not
     /// [`ServiceState::Ready`].
     ///
@@ -236,6 +251,18 @@ mod tests {
         assert!(debug.contains("None"));
     }
 
+    #[test]
+    fn test_swap_returns_previous_and_keeps_state() {
+        let slot: BackendSlot<String> = BackendSlot::new("swap");
+        assert_eq!(slot.swap("first".to_string()).unwrap(), None);
+        slot.service().set_state(ServiceState::Ready);
+
+        let previous = slot.swap("second".to_string()).unwrap();
+        assert_eq!(previous.as_deref(), Some("first"));
+        assert!(slot.is_ready());
+        assert_eq!(slot.require().unwrap().as_deref(), Some("second"));
+    }
+
     #[test]
     fn test_inner_returns_arc() {
         let slot: BackendSlot<String> = BackendSlot::new("inner-test");
@@ -0,0 +1,649 @@
+//! Live content watching.
+//!
+//! [`ContentWatcher`] polls a content directory for file changes, debounces
+//! bursts of edits into a single [`ChangeSet`], and hands each change set to
+//! a list of [`ChangeHandler`]s (graph, full-text and vector updaters,
+//! resource notifiers). Its [`ServiceHandle`] reports `Ready` while every
+//! handler keeps up and `Degraded` when one fails.
+//!
+//! Watching is done by comparing modification times and sizes between
+//! directory scans, so it works the same on every platform and filesystem
+//! (including network mounts) without native notification support.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use fabryk_core::ContentWatcher;
+//!
+//! let watcher = ContentWatcher::for_content(&config, "concepts")?
+//!     .with_handler(graph_updater)
+//!     .with_handler(index_updater)
+//!     .spawn()?;
+//!
+//! // ... later
+//! watcher.stop().await;
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+
+use async_trait::async_trait;
+use tokio::sync::watch;
+
+use crate::service::{ServiceHandle, ServiceState};
+use crate::traits::ConfigProvider;
+use crate::{Error, Result};
+
+/// Default interval between directory scans.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Default quiet period before a burst of changes is dispatched.
+const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(500);
+
+// ============================================================================
+// ChangeSet
+// ============================================================================
+
+/// Kind of change observed for a file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// The file appeared.
+    Created,
+    /// The file's contents changed.
+    Modified,
+    /// The file disappeared.
+    Removed,
+}
+
+/// A debounced set of file changes, at most one per path.
+///
+/// Successive changes to the same path are coalesced: a file created and
+/// then edited is `Created`, a file created and then removed is dropped,
+/// and a file removed and then recreated is `Modified`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ChangeSet {
+    changes: BTreeMap<PathBuf, ChangeKind>,
+}
+
+impl ChangeSet {
+    /// Create an empty change set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a change, coalescing it with any earlier change to the path.
+    pub fn record(&mut self, path: impl Into<PathBuf>, kind: ChangeKind) {
+        use ChangeKind::*;
+
+        let path = path.into();
+        let merged = match (self.changes.get(&path), kind) {
+            (None, kind) => Some(kind),
+            (Some(Created), Removed) => None,
+            (Some(Created), _) => Some(Created),
+            (Some(Removed), Created | Modified) => Some(Modified),
+            (Some(Modified), kind) => Some(kind),
+            (Some(Removed), Removed) => Some(Removed),
+        };
+        match merged {
+            Some(kind) => {
+                self.changes.insert(path, kind);
+            }
+            None => {
+                self.changes.remove(&path);
+            }
+        }
+    }
+
+    /// Merge a later change set into this one.
+    pub fn merge(&mut self, later: ChangeSet) {
+        for (path, kind) in later.changes {
+            self.record(path, kind);
+        }
+    }
+
+    /// Returns `true` if there are no changes.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Number of changed paths.
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Iterate over all changes in path order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Path, ChangeKind)> {
+        self.changes.iter().map(|(p, k)| (p.as_path(), *k))
+    }
+
+    /// Paths that were created or modified and should be (re)loaded.
+    pub fn upserted(&self) -> impl Iterator<Item = &Path> {
+        self.iter()
+            .filter(|(_, kind)| *kind != ChangeKind::Removed)
+            .map(|(path, _)| path)
+    }
+
+    /// Paths that were removed.
+    pub fn removed(&self) -> impl Iterator<Item = &Path> {
+        self.iter()
+            .filter(|(_, kind)| *kind == ChangeKind::Removed)
+            .map(|(path, _)| path)
+    }
+}
+
+// ============================================================================
+// ChangeHandler
+// ============================================================================
+
+/// Reacts to debounced content changes.
+///
+/// Implementations apply the change set to a backend incrementally, for
+/// example by mutating a graph or replacing index documents, and swap the
+/// result into its [`BackendSlot`](crate::BackendSlot).
+#[async_trait]
+pub trait ChangeHandler: Send + Sync {
+    /// Name used in logs and degraded-state messages.
+    fn name(&self) -> &str;
+
+    /// Apply a change set.
+    async fn apply(&self, changes: &ChangeSet) -> Result<()>;
+}
+
+// ============================================================================
+// ContentSnapshot
+// ============================================================================
+
+/// Modification time and size of every watched file under a directory.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ContentSnapshot {
+    files: BTreeMap<PathBuf, (Option<SystemTime>, u64)>,
+}
+
+impl ContentSnapshot {
+    /// Scan `root` recursively, keeping files with one of `extensions`
+    /// (all files when `extensions` is empty).
+    ///
+    /// A missing root yields an empty snapshot.
+    pub fn scan(root: &Path, extensions: &[String]) -> Result<Self> {
+        let mut snapshot = Self::default();
+        if root.exists() {
+            snapshot.scan_dir(root, extensions)?;
+        }
+        Ok(snapshot)
+    }
+
+    fn scan_dir(&mut self, dir: &Path, extensions: &[String]) -> Result<()> {
+        let entries = std::fs::read_dir(dir).map_err(|e| Error::io_with_path(e, dir))?;
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if metadata.is_dir() {
+                self.scan_dir(&path, extensions)?;
+            } else if extensions.is_empty()
+                || path
+                    .extension()
+                    .and_then(|e| e.to_str())
+                    .is_some_and(|ext| extensions.iter().any(|e| e.eq_ignore_ascii_case(ext)))
+            {
+                self.files
+                    .insert(path, (metadata.modified().ok(), metadata.len()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Number of files in the snapshot.
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Returns `true` if the snapshot has no files.
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Changes between this snapshot and a newer one.
+    pub fn diff(&self, newer: &ContentSnapshot) -> ChangeSet {
+        let mut changes = ChangeSet::new();
+        for (path, stamp) in &newer.files {
+            match self.files.get(path) {
+                None => changes.record(path.clone(), ChangeKind::Created),
+                Some(old) if old != stamp => changes.record(path.clone(), ChangeKind::Modified),
+                Some(_) => {}
+            }
+        }
+        for path in self.files.keys() {
+            if !newer.files.contains_key(path) {
+                changes.record(path.clone(), ChangeKind::Removed);
+            }
+        }
+        changes
+    }
+}
+
+// ============================================================================
+// ContentWatcher
+// ============================================================================
+
+/// Polls a content directory and dispatches debounced changes to handlers.
+///
+/// A change set that any handler fails on is kept and dispatched again on
+/// the next poll, merged with whatever changed since, so handlers should
+/// tolerate being given changes they already applied.
+pub struct ContentWatcher {
+    root: PathBuf,
+    poll_interval: Duration,
+    debounce: Duration,
+    extensions: Vec<String>,
+    handlers: Vec<Arc<dyn ChangeHandler>>,
+    service: ServiceHandle,
+}
+
+impl ContentWatcher {
+    /// Create a watcher for `root`, watching markdown files.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            debounce: DEFAULT_DEBOUNCE,
+            extensions: vec!["md".to_string()],
+            handlers: Vec::new(),
+            service: ServiceHandle::new("content-watcher"),
+        }
+    }
+
+    /// Create a watcher for [`ConfigProvider::content_path`] of `content_type`.
+    pub fn for_content<C: ConfigProvider>(config: &C, content_type: &str) -> Result<Self> {
+        Ok(Self::new(config.content_path(content_type)?))
+    }
+
+    /// Set the interval between directory scans.
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Set the quiet period to wait for after the last change before
+    /// dispatching a change set.
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Set the file extensions to watch (without dots). Empty watches all files.
+    pub fn with_extensions(mut self, extensions: Vec<&str>) -> Self {
+        self.extensions = extensions.into_iter().map(String::from).collect();
+        self
+    }
+
+    /// Add a change handler. Handlers run in registration order.
+    pub fn with_handler(mut self, handler: impl ChangeHandler + 'static) -> Self {
+        self.handlers.push(Arc::new(handler));
+        self
+    }
+
+    /// The watched directory.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// The watcher's service handle.
+    pub fn service(&self) -> &ServiceHandle {
+        &self.service
+    }
+
+    /// Scan the watched directory.
+    pub fn scan(&self) -> Result<ContentSnapshot> {
+        ContentSnapshot::scan(&self.root, &self.extensions)
+    }
+
+    /// Scan the watched directory on the blocking thread pool.
+    async fn scan_blocking(&self) -> Result<ContentSnapshot> {
+        let root = self.root.clone();
+        let extensions = self.extensions.clone();
+        tokio::task::spawn_blocking(move || ContentSnapshot::scan(&root, &extensions))
+            .await
+            .map_err(|e| Error::operation(format!("Scan task failed: {e}")))?
+    }
+
+    /// Run every handler on a change set.
+    ///
+    /// All handlers run even if one fails. The service is marked
+    /// `Degraded` naming the failed handlers, or `Ready` if all succeeded.
+    /// Returns `true` if every handler succeeded.
+    pub async fn dispatch(&self, changes: &ChangeSet) -> bool {
+        log::info!(
+            "Content changed under {}: {} file(s)",
+            self.root.display(),
+            changes.len()
+        );
+
+        let mut failures = Vec::new();
+        for handler in &self.handlers {
+            if let Err(e) = handler.apply(changes).await {
+                log::error!("Change handler '{}' failed: {e}", handler.name());
+                failures.push(format!("{}: {e}", handler.name()));
+            }
+        }
+
+        if failures.is_empty() {
+            if !self.service.state().is_ready() {
+                self.service.set_state(ServiceState::Ready);
+            }
+            true
+        } else {
+            self.service
+                .set_state(ServiceState::Degraded(failures.join("; ")));
+            false
+        }
+    }
+
+    /// Take an initial snapshot and start watching in a background task.
+    pub fn spawn(self) -> Result<WatcherHandle> {
+        self.service.set_state(ServiceState::Starting);
+        let snapshot = match self.scan() {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                self.service.set_state(ServiceState::Failed(e.to_string()));
+                return Err(e);
+            }
+        };
+        log::info!(
+            "Watching {} files under {}",
+            snapshot.len(),
+            self.root.display()
+        );
+        self.service.set_state(ServiceState::Ready);
+
+        let service = self.service.clone();
+        let (shutdown, rx) = watch::channel(false);
+        let task = tokio::spawn(self.run(snapshot, rx));
+        Ok(WatcherHandle {
+            service,
+            shutdown,
+            task,
+        })
+    }
+
+    async fn run(self, mut snapshot: ContentSnapshot, mut shutdown: watch::Receiver<bool>) {
+        let mut pending = ChangeSet::new();
+        let mut last_change = Instant::now();
+
+        loop {
+            // Poll faster while a burst is being debounced
+            let interval = if pending.is_empty() {
+                self.poll_interval
+            } else {
+                self.poll_interval.min(self.debounce)
+            };
+            tokio::select! {
+                _ = shutdown.changed() => break,
+                _ = tokio::time::sleep(interval) => {}
+            }
+
+            match self.scan_blocking().await {
+                Ok(next) => {
+                    let changes = snapshot.diff(&next);
+                    snapshot = next;
+                    if !changes.is_empty() {
+                        pending.merge(changes);
+                        last_change = Instant::now();
+                    }
+                }
+                Err(e) => log::warn!("Failed to scan {}: {e}", self.root.display()),
+            }
+
+            if !pending.is_empty() && last_change.elapsed() >= self.debounce {
+                let changes = std::mem::take(&mut pending);
+                if !self.dispatch(&changes).await {
+                    // Keep the failed changes so the next poll retries them
+                    pending = changes;
+                }
+            }
+        }
+
+        self.service.set_state(ServiceState::Stopped);
+    }
+}
+
+/// Handle to a running [`ContentWatcher`].
+pub struct WatcherHandle {
+    service: ServiceHandle,
+    shutdown: watch::Sender<bool>,
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl WatcherHandle {
+    /// The watcher's service handle.
+    pub fn service(&self) -> &ServiceHandle {
+        &self.service
+    }
+
+    /// Stop watching and wait for the background task to finish.
+    ///
+    /// A change set being dispatched is allowed to complete.
+    pub async fn stop(self) {
+        self.service.set_state(ServiceState::Stopping);
+        let _ = self.shutdown.send(true);
+        if let Err(e) = self.task.await {
+            log::warn!("Content watcher task ended abnormally: {e}");
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Clone, Default)]
+    struct Recorder {
+        seen: Arc<Mutex<Vec<ChangeSet>>>,
+        fail: bool,
+        /// Number of calls that fail before the handler recovers.
+        failures_left: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl ChangeHandler for Recorder {
+        fn name(&self) -> &str {
+            "recorder"
+        }
+
+        async fn apply(&self, changes: &ChangeSet) -> Result<()> {
+            self.seen.lock().unwrap().push(changes.clone());
+            let flaky = self.failures_left.load(Ordering::SeqCst) > 0;
+            if flaky {
+                self.failures_left.fetch_sub(1, Ordering::SeqCst);
+            }
+            if self.fail || flaky {
+                Err(Error::operation("boom"))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[test]
+    fn test_change_set_coalescing() {
+        let mut set = ChangeSet::new();
+        set.record("a.md", ChangeKind::Created);
+        set.record("a.md", ChangeKind::Modified);
+        set.record("b.md", ChangeKind::Created);
+        set.record("b.md", ChangeKind::Removed);
+        set.record("c.md", ChangeKind::Removed);
+        set.record("c.md", ChangeKind::Created);
+        set.record("d.md", ChangeKind::Modified);
+        set.record("d.md", ChangeKind::Removed);
+
+        let changes: Vec<_> = set.iter().collect();
+        assert_eq!(
+            changes,
+            vec![
+                (Path::new("a.md"), ChangeKind::Created),
+                (Path::new("c.md"), ChangeKind::Modified),
+                (Path::new("d.md"), ChangeKind::Removed),
+            ]
+        );
+        assert_eq!(set.upserted().count(), 2);
+        assert_eq!(set.removed().collect::<Vec<_>>(), vec![Path::new("d.md")]);
+    }
+
+    #[test]
+    fn test_snapshot_scan_and_diff() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("sub")).unwrap();
+        std::fs::write(dir.path().join("keep.md"), "keep").unwrap();
+        std::fs::write(dir.path().join("edit.md"), "old").unwrap();
+        std::fs::write(dir.path().join("gone.md"), "gone").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+
+        let exts = vec!["md".to_string()];
+        let before = ContentSnapshot::scan(dir.path(), &exts).unwrap();
+        assert_eq!(before.len(), 3);
+
+        std::fs::write(dir.path().join("edit.md"), "new and longer").unwrap();
+        std::fs::remove_file(dir.path().join("gone.md")).unwrap();
+        std::fs::write(dir.path().join("sub/new.md"), "new").unwrap();
+
+        let after = ContentSnapshot::scan(dir.path(), &exts).unwrap();
+        let changes: Vec<_> = before
+            .diff(&after)
+            .iter()
+            .map(|(p, k)| (p.strip_prefix(dir.path()).unwrap().to_path_buf(), k))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                (PathBuf::from("edit.md"), ChangeKind::Modified),
+                (PathBuf::from("gone.md"), ChangeKind::Removed),
+                (PathBuf::from("sub/new.md"), ChangeKind::Created),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_snapshot_missing_root_is_empty() {
+        let snapshot = ContentSnapshot::scan(Path::new("/nonexistent/content"), &[]).unwrap();
+        assert!(snapshot.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_sets_service_state() {
+        let ok = Recorder::default();
+        let failing = Recorder {
+            fail: true,
+            ..Default::default()
+        };
+        let watcher = ContentWatcher::new("/tmp")
+            .with_handler(ok.clone())
+            .with_handler(failing.clone());
+
+        let mut changes = ChangeSet::new();
+        changes.record("a.md", ChangeKind::Created);
+        assert!(!watcher.dispatch(&changes).await);
+
+        // Both handlers ran despite the failure
+        assert_eq!(ok.seen.lock().unwrap().len(), 1);
+        assert_eq!(failing.seen.lock().unwrap().len(), 1);
+        match watcher.service().state() {
+            ServiceState::Degraded(msg) => assert!(msg.contains("recorder: ")),
+            other => panic!("Expected degraded, got {other}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_spawned_watcher_debounces_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("existing.md"), "one").unwrap();
+
+        let recorder = Recorder::default();
+        let handle = ContentWatcher::new(dir.path())
+            .with_poll_interval(Duration::from_millis(20))
+            .with_debounce(Duration::from_millis(100))
+            .with_handler(recorder.clone())
+            .spawn()
+            .unwrap();
+        assert!(handle.service().state().is_ready());
+
+        // A burst of edits within the debounce window
+        std::fs::write(dir.path().join("new.md"), "draft").unwrap();
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        std::fs::write(dir.path().join("new.md"), "final version").unwrap();
+        std::fs::remove_file(dir.path().join("existing.md")).unwrap();
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while recorder.seen.lock().unwrap().is_empty() && Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+
+        let seen = recorder.seen.lock().unwrap().clone();
+        assert_eq!(seen.len(), 1, "burst should be dispatched once");
+        assert_eq!(seen[0].upserted().count(), 1);
+        assert_eq!(seen[0].removed().count(), 1);
+
+        let service = handle.service().clone();
+        handle.stop().await;
+        assert_eq!(service.state(), ServiceState::Stopped);
+    }
+
+    #[tokio::test]
+    async fn test_spawned_watcher_retries_failed_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let recorder = Recorder {
+            failures_left: Arc::new(AtomicUsize::new(1)),
+            ..Default::default()
+        };
+        let handle = ContentWatcher::new(dir.path())
+            .with_poll_interval(Duration::from_millis(20))
+            .with_debounce(Duration::from_millis(50))
+            .with_handler(recorder.clone())
+            .spawn()
+            .unwrap();
+
+        std::fs::write(dir.path().join("new.md"), "draft").unwrap();
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while (recorder.seen.lock().unwrap().len() < 2 || !handle.service().state().is_ready())
+            && Instant::now() < deadline
+        {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+
+        let seen = recorder.seen.lock().unwrap().clone();
+        assert_eq!(seen.len(), 2, "failed change set should be retried once");
+        assert_eq!(seen[0], seen[1]);
+        assert_eq!(seen[1].upserted().count(), 1);
+        assert!(handle.service().state().is_ready());
+        handle.stop().await;
+    }
+
+    #[test]
+    fn test_for_content_uses_content_path() {
+        #[derive(Clone)]
+        struct Config;
+        impl ConfigProvider for Config {
+            fn project_name(&self) -> &str {
+                "test"
+            }
+            fn base_path(&self) -> Result<PathBuf> {
+                Ok(PathBuf::from("/data"))
+            }
+            fn content_path(&self, content_type: &str) -> Result<PathBuf> {
+                Ok(PathBuf::from("/data").join(content_type))
+            }
+        }
+
+        let watcher = ContentWatcher::for_content(&Config, "concepts").unwrap();
+        assert_eq!(watcher.root(), Path::new("/data/concepts"));
+    }
+}
@@ -25,13 +25,23 @@
 //! let results = backend.search(params).await?;
 //! println!("Found {} results", results.total);
 //! ```
+//!
+//! # Paging, Sorting and Facets
+//!
+//! `offset` (or the opaque `cursor` from a previous page's `next_cursor`)
+//! skips results, `sort` orders by relevance or date, and `facets` requests
+//! per-value counts over the filterable fields. Facet counts and `total`
+//! cover every match, not just the returned page.
+
+use std::collections::BTreeMap;
 
 use async_trait::async_trait;
-use fabryk_core::Result;
+use fabryk_core::{Error, Result};
 use serde::{Deserialize, Serialize};
 
 use crate::concept_card_extractor::ConceptCardDocumentExtractor;
 use crate::document::SearchDocument;
+use crate::highlight::Passage;
 use crate::types::{QueryMode, SearchConfig};
 
 /// Parameters for a search request.
@@ -68,6 +78,89 @@ pub struct SearchParams {
     /// Search backends can use these for post-filtering or query refinement.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub extra_filters: Option<serde_json::Value>,
+
+    /// Number of results to skip.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<usize>,
+
+    /// Cursor from a previous page's `next_cursor`; takes precedence over `offset`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+
+    /// Result ordering (default: relevance).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<SortOrder>,
+
+    /// Fields to count matching results by.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub facets: Option<Vec<FacetField>>,
+}
+
+impl SearchParams {
+    /// The index of the first result to return, from `cursor` or `offset`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cursor was not produced by a search.
+    pub fn start(&self) -> Result<usize> {
+        match self.cursor {
+            Some(ref cursor) => decode_cursor(cursor),
+            None => Ok(self.offset.unwrap_or(0)),
+        }
+    }
+}
+
+/// Result ordering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    /// Highest relevance first.
+    #[default]
+    Relevance,
+    /// Newest `date` first; undated results last.
+    DateDesc,
+    /// Oldest `date` first; undated results last.
+    DateAsc,
+}
+
+/// A filterable field that results can be counted by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FacetField {
+    /// Content category.
+    Category,
+    /// Source reference.
+    Source,
+    /// Content type classification.
+    ContentType,
+}
+
+impl FacetField {
+    /// The field name, as used in `SearchResults::facets`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Category => "category",
+            Self::Source => "source",
+            Self::ContentType => "content_type",
+        }
+    }
+
+    fn value<'a>(&self, result: &'a SearchResult) -> Option<&'a str> {
+        match self {
+            Self::Category => Some(result.category.as_str()).filter(|c| !c.is_empty()),
+            Self::Source => result.source.as_deref(),
+            Self::ContentType => result.content_type.as_deref(),
+        }
+    }
+}
+
+/// The number of matching results with a given field value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FacetCount {
+    /// Field value.
+    pub value: String,
+    /// Number of matching results with this value.
+    pub count: usize,
 }
 
 /// A single search result.
@@ -94,6 +187,12 @@ pub struct SearchResult {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub snippet: Option<String>,
 
+    /// Highlighted passages with match offsets, best first.
+    ///
+    /// Only filled by backends that know which terms matched.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub passages: Vec<Passage>,
+
     /// Relevance score (0.0 to 1.0+, higher is better).
     pub relevance: f32,
 
@@ -112,6 +211,10 @@ pub struct SearchResult {
     /// Section reference.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub section: Option<String>,
+
+    /// Publication/creation date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
 }
 
 /// Collection of search results.
@@ -125,6 +228,19 @@ pub struct SearchResults {
 
     /// Backend that executed the search.
     pub backend: String,
+
+    /// Index of the first item among all matches.
+    #[serde(default)]
+    pub offset: usize,
+
+    /// Cursor for the next page, if more results remain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+
+    /// Counts of matching results per value of each requested facet field,
+    /// most frequent first.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub facets: BTreeMap<String, Vec<FacetCount>>,
 }
 
 impl SearchResults {
@@ -134,10 +250,97 @@ impl SearchResults {
             items: Vec::new(),
             total: 0,
             backend: backend.to_string(),
+            offset: 0,
+            next_cursor: None,
+            facets: BTreeMap::new(),
+        }
+    }
+
+    /// Create one page of results starting at `offset` out of `total` matches.
+    pub fn page(items: Vec<SearchResult>, total: usize, offset: usize, backend: &str) -> Self {
+        let end = offset.saturating_add(items.len());
+        Self {
+            next_cursor: (end < total).then(|| encode_cursor(end)),
+            items,
+            total,
+            backend: backend.to_string(),
+            offset,
+            facets: BTreeMap::new(),
         }
     }
 }
 
+/// Sort, count facets over and page the complete list of filtered matches.
+///
+/// `items` must hold every match, ordered by relevance. For backends that
+/// hold their matches in memory; `TantivySearch` does this in the index.
+pub fn paginate(
+    mut items: Vec<SearchResult>,
+    params: &SearchParams,
+    limit: usize,
+    backend: &str,
+) -> Result<SearchResults> {
+    let offset = params.start()?;
+
+    let mut facets = BTreeMap::new();
+    for field in params.facets.iter().flatten() {
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        for item in &items {
+            if let Some(value) = field.value(item) {
+                *counts.entry(value.to_string()).or_default() += 1;
+            }
+        }
+        facets.insert(field.as_str().to_string(), facet_counts(counts));
+    }
+
+    match params.sort.unwrap_or_default() {
+        SortOrder::Relevance => {}
+        // Stable sorts keep relevance order among equal dates
+        SortOrder::DateDesc => items.sort_by(|a, b| {
+            b.date
+                .is_some()
+                .cmp(&a.date.is_some())
+                .then(b.date.cmp(&a.date))
+        }),
+        SortOrder::DateAsc => items.sort_by(|a, b| {
+            b.date
+                .is_some()
+                .cmp(&a.date.is_some())
+                .then(a.date.cmp(&b.date))
+        }),
+    }
+
+    let total = items.len();
+    let page: Vec<SearchResult> = items.into_iter().skip(offset).take(limit).collect();
+    let mut results = SearchResults::page(page, total, offset, backend);
+    results.facets = facets;
+    Ok(results)
+}
+
+/// Order per-value counts most frequent first, ties by value.
+pub(crate) fn facet_counts(counts: BTreeMap<String, usize>) -> Vec<FacetCount> {
+    let mut counts: Vec<FacetCount> = counts
+        .into_iter()
+        .map(|(value, count)| FacetCount { value, count })
+        .collect();
+    counts.sort_by_key(|c| std::cmp::Reverse(c.count));
+    counts
+}
+
+const CURSOR_PREFIX: &str = "offset:";
+
+/// Encode the start of the next page as an opaque cursor.
+fn encode_cursor(offset: usize) -> String {
+    format!("{CURSOR_PREFIX}{offset}")
+}
+
+fn decode_cursor(cursor: &str) -> Result<usize> {
+    cursor
+        .strip_prefix(CURSOR_PREFIX)
+        .and_then(|n| n.parse().ok())
+        .ok_or_else(|| Error::parse(format!("Invalid search cursor: {cursor}")))
+}
+
 /// Abstract search backend trait.
 ///
 /// Implementations provide different search strategies:
@@ -360,11 +563,13 @@ impl SearchBackend for SimpleSearch {
                 category: doc.category.clone(),
                 source: doc.source.clone(),
                 snippet,
+                passages: Vec::new(),
                 relevance,
                 content_type: doc.content_type.clone(),
                 path: Some(doc.path.clone()),
                 chapter: doc.chapter.clone(),
                 section: doc.section.clone(),
+                date: doc.date.clone(),
             });
         }
 
@@ -375,14 +580,7 @@ impl SearchBackend for SimpleSearch {
                 .unwrap_or(std::cmp::Ordering::Equal)
         });
 
-        let total = results.len();
-        results.truncate(limit);
-
-        Ok(SearchResults {
-            items: results,
-            total,
-            backend: self.name().to_string(),
-        })
+        paginate(results, &params, limit, self.name())
     }
 
     fn name(&self) -> &str {
@@ -446,11 +644,13 @@ mod tests {
             category: "test".to_string(),
             source: None,
             snippet: Some("...test snippet...".to_string()),
+            passages: Vec::new(),
             relevance: 0.95,
             content_type: Some("concept".to_string()),
             path: None,
             chapter: None,
             section: None,
+            date: None,
         };
 
         let json = serde_json::to_string(&result).unwrap();
@@ -683,4 +883,119 @@ mod tests {
         let params: SearchParams = serde_json::from_str(json).unwrap();
         assert!(params.extra_filters.is_none());
     }
+
+    // -- Paging, sorting and facets -----------------------------------------
+
+    fn dated(id: &str, category: &str, date: Option<&str>) -> SearchResult {
+        SearchResult {
+            id: id.to_string(),
+            title: id.to_string(),
+            description: None,
+            category: category.to_string(),
+            source: None,
+            snippet: None,
+            passages: Vec::new(),
+            relevance: 1.0,
+            content_type: None,
+            path: None,
+            chapter: None,
+            section: None,
+            date: date.map(String::from),
+        }
+    }
+
+    fn sample() -> Vec<SearchResult> {
+        vec![
+            dated("a", "harmony", Some("2023-01-01")),
+            dated("b", "rhythm", None),
+            dated("c", "harmony", Some("2025-01-01")),
+        ]
+    }
+
+    fn ids(results: &SearchResults) -> Vec<&str> {
+        results.items.iter().map(|r| r.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_search_params_start() {
+        let params = SearchParams {
+            offset: Some(4),
+            ..Default::default()
+        };
+        assert_eq!(params.start().unwrap(), 4);
+
+        let params = SearchParams {
+            offset: Some(4),
+            cursor: Some(encode_cursor(7)),
+            ..Default::default()
+        };
+        assert_eq!(params.start().unwrap(), 7);
+
+        let params = SearchParams {
+            cursor: Some("page-2".to_string()),
+            ..Default::default()
+        };
+        assert!(params.start().is_err());
+    }
+
+    #[test]
+    fn test_paginate_pages_with_cursor() {
+        let params = SearchParams::default();
+        let first = paginate(sample(), &params, 2, "test").unwrap();
+        assert_eq!(ids(&first), vec!["a", "b"]);
+        assert_eq!(first.total, 3);
+
+        let params = SearchParams {
+            cursor: first.next_cursor,
+            ..Default::default()
+        };
+        let second = paginate(sample(), &params, 2, "test").unwrap();
+        assert_eq!(second.offset, 2);
+        assert_eq!(ids(&second), vec!["c"]);
+        assert!(second.next_cursor.is_none());
+    }
+
+    #[test]
+    fn test_paginate_sorts_by_date_with_undated_last() {
+        let params = SearchParams {
+            sort: Some(SortOrder::DateDesc),
+            ..Default::default()
+        };
+        let results = paginate(sample(), &params, 10, "test").unwrap();
+        assert_eq!(ids(&results), vec!["c", "a", "b"]);
+
+        let params = SearchParams {
+            sort: Some(SortOrder::DateAsc),
+            ..Default::default()
+        };
+        let results = paginate(sample(), &params, 10, "test").unwrap();
+        assert_eq!(ids(&results), vec!["a", "c", "b"]);
+    }
+
+    #[test]
+    fn test_paginate_counts_facets_over_all_matches() {
+        let params = SearchParams {
+            facets: Some(vec![FacetField::Category, FacetField::Source]),
+            ..Default::default()
+        };
+        let results = paginate(sample(), &params, 1, "test").unwrap();
+        assert_eq!(results.items.len(), 1);
+
+        let categories = &results.facets["category"];
+        assert_eq!(categories[0].value, "harmony");
+        assert_eq!(categories[0].count, 2);
+        assert_eq!(categories[1].value, "rhythm");
+        // No result has a source, so the facet is present but empty
+        assert!(results.facets["source"].is_empty());
+    }
+
+    #[test]
+    fn test_sort_order_serialization() {
+        assert_eq!(
+            serde_json::to_string(&SortOrder::DateDesc).unwrap(),
+            "\"date_desc\""
+        );
+        let field: FacetField = serde_json::from_str("\"content_type\"").unwrap();
+        assert_eq!(field.as_str(), "content_type");
+    }
 }
@@ -0,0 +1,265 @@
+//! Highlighted passages for search results.
+//!
+//! Backends that know which terms matched (e.g. `TantivySearch`, via
+//! Tantivy's `SnippetGenerator`) report them as [`Passage`]s: fragments of
+//! a stored field with the byte ranges of every match, so callers can quote
+//! the exact relevant lines or render their own markup.
+//!
+//! This module holds the backend-independent parts: splitting text into
+//! candidate blocks, applying markup and ranking passages.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// A byte range of a match within a passage's `text`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchSpan {
+    /// Start byte offset (inclusive).
+    pub start: usize,
+    /// End byte offset (exclusive).
+    pub end: usize,
+}
+
+/// A fragment of a document field containing query matches.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Passage {
+    /// Field the passage was taken from (e.g. "description", "content").
+    pub field: String,
+
+    /// Plain passage text.
+    pub text: String,
+
+    /// Passage text with each match wrapped in the configured markup.
+    pub highlighted: String,
+
+    /// Byte offset of the passage within the field's stored text.
+    pub offset: usize,
+
+    /// Byte ranges of matched terms within `text`, in order.
+    pub matches: Vec<MatchSpan>,
+}
+
+impl Passage {
+    /// Build a passage, rendering `highlighted` with `prefix`/`postfix`
+    /// around each match.
+    ///
+    /// Spans that overlap, fall outside `text` or split a character are
+    /// dropped.
+    pub fn new(
+        field: &str,
+        text: &str,
+        offset: usize,
+        matches: Vec<MatchSpan>,
+        prefix: &str,
+        postfix: &str,
+    ) -> Self {
+        let mut valid: Vec<MatchSpan> = Vec::with_capacity(matches.len());
+        for span in matches {
+            let in_bounds = span.start < span.end
+                && span.end <= text.len()
+                && text.is_char_boundary(span.start)
+                && text.is_char_boundary(span.end);
+            let after_previous = valid.last().is_none_or(|last| span.start >= last.end);
+            if in_bounds && after_previous {
+                valid.push(span);
+            }
+        }
+
+        let mut highlighted = String::with_capacity(text.len() + valid.len() * 4);
+        let mut cursor = 0;
+        for span in &valid {
+            highlighted.push_str(&text[cursor..span.start]);
+            highlighted.push_str(prefix);
+            highlighted.push_str(&text[span.start..span.end]);
+            highlighted.push_str(postfix);
+            cursor = span.end;
+        }
+        highlighted.push_str(&text[cursor..]);
+
+        Self {
+            field: field.to_string(),
+            text: text.to_string(),
+            highlighted,
+            offset,
+            matches: valid,
+        }
+    }
+
+    /// Number of distinct (case-insensitive) matched terms.
+    pub fn distinct_matches(&self) -> usize {
+        self.matches
+            .iter()
+            .map(|span| self.text[span.start..span.end].to_lowercase())
+            .collect::<HashSet<_>>()
+            .len()
+    }
+}
+
+/// Split `text` into candidate blocks for passage extraction.
+///
+/// Blocks are paragraphs (separated by blank lines); paragraphs longer than
+/// `2 * max_len` bytes are further split at line breaks or whitespace.
+/// Returns each block with its byte offset in `text`.
+pub fn passage_blocks(text: &str, max_len: usize) -> Vec<(usize, &str)> {
+    let window = max_len.max(1) * 2;
+    let mut blocks = Vec::new();
+
+    let mut start = 0;
+    for (pos, _) in text.match_indices("\n\n").chain([(text.len(), "")]) {
+        if pos < start {
+            continue;
+        }
+        split_block(text, start, pos, window, &mut blocks);
+        start = pos + 2;
+    }
+    blocks
+}
+
+fn split_block<'a>(
+    text: &'a str,
+    mut start: usize,
+    end: usize,
+    window: usize,
+    blocks: &mut Vec<(usize, &'a str)>,
+) {
+    while start < end {
+        let mut stop = end;
+        if end - start > window {
+            let limit = text.floor_char_boundary(start + window);
+            let slice = &text[start..limit];
+            stop = slice
+                .rfind('\n')
+                .or_else(|| slice.rfind(char::is_whitespace))
+                .filter(|p| *p > 0)
+                .map_or(limit, |p| start + p);
+            if stop <= start {
+                stop = text.ceil_char_boundary(start + 1);
+            }
+        }
+        let block = &text[start..stop];
+        let trimmed = block.trim_start();
+        let lead = block.len() - trimmed.len();
+        let trimmed = trimmed.trim_end();
+        if !trimmed.is_empty() {
+            blocks.push((start + lead, trimmed));
+        }
+        start = stop;
+    }
+}
+
+/// Order passages best first and keep at most `max`.
+///
+/// Passages with more distinct matched terms rank higher; ties keep their
+/// input order (so earlier fields and earlier positions win).
+pub fn rank_passages(mut passages: Vec<Passage>, max: usize) -> Vec<Passage> {
+    passages.sort_by_key(|p| std::cmp::Reverse(p.distinct_matches()));
+    passages.truncate(max);
+    passages
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(start: usize, end: usize) -> MatchSpan {
+        MatchSpan { start, end }
+    }
+
+    #[test]
+    fn test_passage_highlighting() {
+        let passage = Passage::new(
+            "content",
+            "Harmonics shape harmony",
+            10,
+            vec![span(0, 9), span(16, 23)],
+            "<b>",
+            "</b>",
+        );
+        assert_eq!(passage.highlighted, "<b>Harmonics</b> shape <b>harmony</b>");
+        assert_eq!(passage.offset, 10);
+        assert_eq!(passage.matches.len(), 2);
+    }
+
+    #[test]
+    fn test_passage_drops_invalid_spans() {
+        let passage = Passage::new(
+            "content",
+            "café au lait",
+            0,
+            vec![span(0, 4), span(2, 5), span(3, 6), span(8, 99), span(9, 13)],
+            "*",
+            "*",
+        );
+        // (0, 4) splits 'é', (3, 6) overlaps (2, 5), (8, 99) is out of bounds
+        assert_eq!(passage.matches, vec![span(2, 5), span(9, 13)]);
+        assert_eq!(passage.highlighted, "ca*fé* au *lait*");
+    }
+
+    #[test]
+    fn test_passage_distinct_matches() {
+        let passage = Passage::new(
+            "content",
+            "Fifth and fifth and third",
+            0,
+            vec![span(0, 5), span(10, 15), span(20, 25)],
+            "",
+            "",
+        );
+        assert_eq!(passage.distinct_matches(), 2);
+    }
+
+    #[test]
+    fn test_passage_blocks_paragraphs() {
+        let text = "First para.\n\n  Second para.  \n\n\nThird.";
+        let blocks = passage_blocks(text, 100);
+        assert_eq!(
+            blocks,
+            vec![(0, "First para."), (15, "Second para."), (32, "Third.")]
+        );
+        for (offset, block) in blocks {
+            assert_eq!(&text[offset..offset + block.len()], block);
+        }
+    }
+
+    #[test]
+    fn test_passage_blocks_split_long_paragraphs() {
+        let text = "alpha beta gamma delta epsilon zeta eta theta";
+        let blocks = passage_blocks(text, 8);
+        assert!(blocks.len() > 1);
+        for (offset, block) in &blocks {
+            assert!(block.len() <= 16);
+            assert_eq!(&text[*offset..offset + block.len()], *block);
+        }
+        let joined: Vec<&str> = blocks.iter().map(|(_, b)| *b).collect();
+        assert_eq!(joined.join(" "), text);
+    }
+
+    #[test]
+    fn test_passage_blocks_empty() {
+        assert!(passage_blocks("", 10).is_empty());
+        assert!(passage_blocks("\n\n\n\n", 10).is_empty());
+    }
+
+    #[test]
+    fn test_rank_passages() {
+        let one = Passage::new("content", "fifth", 0, vec![span(0, 5)], "", "");
+        let two = Passage::new(
+            "content",
+            "fifth third",
+            20,
+            vec![span(0, 5), span(6, 11)],
+            "",
+            "",
+        );
+        let also_one = Passage::new("content", "third", 40, vec![span(0, 5)], "", "");
+
+        let ranked = rank_passages(vec![one, two, also_one], 2);
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(ranked[0].offset, 20);
+        assert_eq!(ranked[1].offset, 0);
+    }
+}
@@ -28,7 +28,7 @@ use std::path::Path;
 
 use fabryk_core::{Error, Result};
 use tantivy::schema::Field;
-use tantivy::{Index, IndexWriter, TantivyDocument};
+use tantivy::{Index, IndexWriter, TantivyDocument, Term};
 
 use crate::document::SearchDocument;
 use crate::schema::SearchSchema;
@@ -107,6 +107,15 @@ impl Indexer {
         Ok(())
     }
 
+    /// Delete the document with the given ID.
+    ///
+    /// Like additions, the deletion takes effect on the next `commit()`.
+    /// Deleting and then re-adding a document replaces it.
+    pub fn delete_document(&mut self, id: &str) {
+        self.writer
+            .delete_term(Term::from_field_text(self.schema.id, id));
+    }
+
     /// Commit staged changes to make them searchable.
     pub fn commit(&mut self) -> Result<()> {
         self.writer
@@ -241,6 +250,22 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_indexer_delete_document() {
+        let schema = SearchSchema::build();
+        let mut indexer = Indexer::new_in_memory(&schema).unwrap();
+
+        indexer.add_document(&create_test_doc("doc-1")).unwrap();
+        indexer.add_document(&create_test_doc("doc-2")).unwrap();
+        indexer.commit().unwrap();
+
+        indexer.delete_document("doc-1");
+        indexer.commit().unwrap();
+
+        let reader = indexer.index().reader().unwrap();
+        assert_eq!(reader.searcher().num_docs(), 1);
+    }
+
     #[test]
     fn test_indexer_clear() {
         let schema = SearchSchema::build();
@@ -21,6 +21,7 @@
 //! │  SearchSchema (default 14-field schema)                     │
 //! │  SearchDocument (indexed document representation)           │
 //! │  QueryBuilder (weighted multi-field queries)                │
+//! │  Passage highlighting (stemmed/phrase-aware match offsets)  │
 //! ├─────────────────────────────────────────────────────────────┤
 //! │  Indexer (Tantivy index writer)                            │
 //! │  IndexBuilder (batch indexing orchestration)               │
@@ -79,6 +80,7 @@
 pub mod backend;
 pub mod concept_card_extractor;
 pub mod document;
+pub mod highlight;
 pub mod probe;
 pub mod types;
 
@@ -104,12 +106,17 @@ pub mod stopwords;
 #[cfg(feature = "fts-tantivy")]
 pub mod tantivy_search;
 
+#[cfg(feature = "fts-tantivy")]
+pub mod updater;
+
 // Re-exports
 pub use backend::{
-    SearchBackend, SearchParams, SearchResult, SearchResults, SimpleDocumentExtractor, SimpleSearch,
+    FacetCount, FacetField, SearchBackend, SearchParams, SearchResult, SearchResults,
+    SimpleDocumentExtractor, SimpleSearch, SortOrder, paginate,
 };
 pub use concept_card_extractor::ConceptCardDocumentExtractor;
 pub use document::SearchDocument;
+pub use highlight::{MatchSpan, Passage};
 pub use probe::{SearchProbe, search_probe};
 pub use types::{LanceDbConfig, QueryMode, SearchConfig};
 
@@ -134,6 +141,9 @@ pub use stopwords::StopwordFilter;
 #[cfg(feature = "fts-tantivy")]
 pub use tantivy_search::TantivySearch;
 
+#[cfg(feature = "fts-tantivy")]
+pub use updater::IndexUpdater;
+
 /// Create a search backend based on configuration.
 ///
 /// Returns `TantivySearch` if:
@@ -26,7 +26,7 @@
 //! - `chapter`: Chapter reference
 //! - `part`: Part/section within source
 //! - `author`: Content author
-//! - `date`: Publication/creation date
+//! - `date`: Publication/creation date (STRING | FAST | STORED, sortable)
 //! - `content_type`: Type classification (STRING | FAST | STORED)
 //! - `section`: Specific section reference
 //!
@@ -46,7 +46,7 @@ use tantivy::tokenizer::{LowerCaser, SimpleTokenizer, Stemmer, TextAnalyzer};
 /// Schema version for cache invalidation.
 ///
 /// Increment this when schema fields change to force index rebuilds.
-pub const SCHEMA_VERSION: u32 = 3;
+pub const SCHEMA_VERSION: u32 = 4;
 
 /// Search schema holding field references and the Tantivy schema.
 ///
@@ -127,7 +127,8 @@ impl SearchSchema {
         let chapter = builder.add_text_field("chapter", STORED);
         let part = builder.add_text_field("part", STORED);
         let author = builder.add_text_field("author", STORED);
-        let date = builder.add_text_field("date", STORED);
+        // Fast so results can be sorted by date
+        let date = builder.add_text_field("date", STRING | FAST | STORED);
 
         // v0.3.0 additions
         let content_type = builder.add_text_field("content_type", STRING | FAST | STORED);
@@ -287,7 +288,7 @@ mod tests {
 
     #[test]
     fn test_schema_version() {
-        assert_eq!(SCHEMA_VERSION, 3);
+        assert_eq!(SCHEMA_VERSION, 4);
     }
 
     #[test]
@@ -315,5 +316,9 @@ mod tests {
         // Check category is FAST
         let category_entry = tantivy_schema.get_field_entry(schema.category);
         assert!(category_entry.is_fast());
+
+        // Check date is FAST for sorting
+        let date_entry = tantivy_schema.get_field_entry(schema.date);
+        assert!(date_entry.is_fast());
     }
 }
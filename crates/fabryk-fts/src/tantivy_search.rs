@@ -5,7 +5,10 @@
 //! - BM25 scoring
 //! - Multi-field weighted search
 //! - Category/source/content_type filtering
-//! - Snippet generation
+//! - Paging, date sorting and facet counts
+//! - Query-aware highlighting: passages come from Tantivy's
+//!   `SnippetGenerator`, so stemmed and phrase matches are highlighted
+//!   with their byte offsets
 //!
 //! # Usage
 //!
@@ -25,17 +28,26 @@
 //! }).await?;
 //! ```
 
+use std::collections::BTreeMap;
 use std::path::Path;
 
 use async_trait::async_trait;
 use fabryk_core::{Error, Result};
-use tantivy::collector::TopDocs;
+use tantivy::collector::{Collector, Count, SegmentCollector, TopDocs};
+use tantivy::columnar::StrColumn;
 use tantivy::query::Query;
-use tantivy::{Index, IndexReader, ReloadPolicy};
+use tantivy::snippet::SnippetGenerator;
+use tantivy::{
+    DocAddress, DocId, Index, IndexReader, ReloadPolicy, Score, Searcher, SegmentOrdinal,
+    SegmentReader,
+};
 
 use tantivy::schema::Value;
 
-use crate::backend::{SearchBackend, SearchParams, SearchResult, SearchResults};
+use crate::backend::{
+    FacetField, SearchBackend, SearchParams, SearchResult, SearchResults, SortOrder, facet_counts,
+};
+use crate::highlight::{MatchSpan, Passage, passage_blocks, rank_passages};
 use crate::query::QueryBuilder;
 use crate::schema::SearchSchema;
 use crate::types::SearchConfig;
@@ -50,6 +62,12 @@ pub struct TantivySearch {
     config: SearchConfig,
 }
 
+/// Snippet generators for the highlighted fields of one query.
+struct Highlighter {
+    description: SnippetGenerator,
+    content: SnippetGenerator,
+}
+
 impl TantivySearch {
     /// Create a new Tantivy search backend.
     ///
@@ -85,18 +103,22 @@ impl TantivySearch {
         })
     }
 
-    /// Execute a query and return scored document addresses.
+    /// Execute a query and return the top scored document addresses,
+    /// plus the total number of matches.
     fn execute_query(
         &self,
         query: &dyn Query,
         limit: usize,
-    ) -> Result<Vec<(f32, tantivy::DocAddress)>> {
+    ) -> Result<(Vec<(f32, tantivy::DocAddress)>, usize)> {
         let searcher = self.reader.searcher();
-        let top_docs = searcher
-            .search(query, &TopDocs::with_limit(limit).order_by_score())
+        // TopDocs requires a non-zero limit, and never needs more than the corpus
+        let limit = limit.min(searcher.num_docs() as usize).max(1);
+        let collector = (TopDocs::with_limit(limit).order_by_score(), Count);
+        let (top_docs, total) = searcher
+            .search(query, &collector)
             .map_err(|e| Error::operation(format!("Search failed: {e}")))?;
 
-        Ok(top_docs)
+        Ok((top_docs, total))
     }
 
     /// Convert Tantivy documents to SearchResults.
@@ -104,8 +126,10 @@ impl TantivySearch {
         &self,
         docs: Vec<(f32, tantivy::DocAddress)>,
         query_str: &str,
+        query: &dyn Query,
     ) -> Result<Vec<SearchResult>> {
         let searcher = self.reader.searcher();
+        let highlighter = self.highlighter(&searcher, query);
         let mut results = Vec::with_capacity(docs.len());
 
         for (score, doc_address) in docs {
@@ -123,9 +147,18 @@ impl TantivySearch {
             let content_type = get_text_field(&doc, self.schema.content_type);
             let chapter = get_text_field(&doc, self.schema.chapter);
             let section = get_text_field(&doc, self.schema.section);
-
-            // Generate snippet
-            let snippet = self.generate_snippet(query_str, &description, &content);
+            let date = get_text_field(&doc, self.schema.date);
+
+            // Highlight matches; fall back to a substring scan when the
+            // query has no highlightable terms (e.g. fuzzy or wildcard)
+            let passages = highlighter
+                .as_ref()
+                .map(|h| self.extract_passages(h, description.as_deref(), &content))
+                .unwrap_or_default();
+            let snippet = match passages.first() {
+                Some(best) => Some(passage_snippet(best, description.as_deref(), &content)),
+                None => self.generate_snippet(query_str, &description, &content),
+            };
 
             results.push(SearchResult {
                 id,
@@ -134,17 +167,90 @@ impl TantivySearch {
                 category,
                 source,
                 snippet,
+                passages,
                 relevance: score,
                 content_type,
                 path,
                 chapter,
                 section,
+                date,
             });
         }
 
         Ok(results)
     }
 
+    /// Create snippet generators for `query`.
+    ///
+    /// Highlighting is best-effort: on failure the search still succeeds
+    /// with substring-based snippets.
+    fn highlighter(&self, searcher: &Searcher, query: &dyn Query) -> Option<Highlighter> {
+        let create = |field| {
+            let mut generator = SnippetGenerator::create(searcher, query, field)?;
+            generator.set_max_num_chars(self.config.snippet_length);
+            Ok::<_, tantivy::TantivyError>(generator)
+        };
+        match (create(self.schema.description), create(self.schema.content)) {
+            (Ok(description), Ok(content)) => Some(Highlighter {
+                description,
+                content,
+            }),
+            (Err(e), _) | (_, Err(e)) => {
+                log::warn!("Failed to create snippet generator: {e}");
+                None
+            }
+        }
+    }
+
+    /// Extract the best highlighted passages from description and content.
+    fn extract_passages(
+        &self,
+        highlighter: &Highlighter,
+        description: Option<&str>,
+        content: &str,
+    ) -> Vec<Passage> {
+        if self.config.max_passages == 0 {
+            return Vec::new();
+        }
+
+        let fields = [
+            (
+                "description",
+                &highlighter.description,
+                description.unwrap_or_default(),
+            ),
+            ("content", &highlighter.content, content),
+        ];
+        let mut passages = Vec::new();
+        for (name, generator, text) in fields {
+            for (block_offset, block) in passage_blocks(text, self.config.snippet_length) {
+                let snippet = generator.snippet(block);
+                if snippet.highlighted().is_empty() {
+                    continue;
+                }
+                let fragment = snippet.fragment();
+                let offset = block_offset + block.find(fragment).unwrap_or(0);
+                let matches = snippet
+                    .highlighted()
+                    .iter()
+                    .map(|range| MatchSpan {
+                        start: range.start,
+                        end: range.end,
+                    })
+                    .collect();
+                passages.push(Passage::new(
+                    name,
+                    fragment,
+                    offset,
+                    matches,
+                    &self.config.highlight_prefix,
+                    &self.config.highlight_postfix,
+                ));
+            }
+        }
+        rank_passages(passages, self.config.max_passages)
+    }
+
     /// Generate a search snippet from description or content.
     fn generate_snippet(
         &self,
@@ -184,46 +290,259 @@ impl TantivySearch {
 impl SearchBackend for TantivySearch {
     async fn search(&self, params: SearchParams) -> Result<SearchResults> {
         let limit = params.limit.unwrap_or(self.config.default_limit);
+        // Offsets come from tool args or cursors; past the corpus nothing matches
+        let offset = params
+            .start()?
+            .min(self.reader.searcher().num_docs() as usize);
 
         // Build query
         let builder = QueryBuilder::new(&self.schema, &self.config);
         let query = builder.build_query(&params.query)?;
 
-        // Execute
-        let docs = self.execute_query(query.as_ref(), limit)?;
-        let total = docs.len();
+        // Without filters, facets or date sorting, only the requested page
+        // needs to be retrieved; the rest are just counted
+        let sort = params.sort.unwrap_or_default();
+        let collector = MatchCollector::new(&params);
+        if !collector.filters() && collector.facets.is_empty() && sort == SortOrder::Relevance {
+            let (docs, total) = self.execute_query(query.as_ref(), offset.saturating_add(limit))?;
+            let page: Vec<_> = docs.into_iter().skip(offset).take(limit).collect();
+            let items = self.convert_results(page, &params.query, query.as_ref())?;
+            return Ok(SearchResults::page(items, total, offset, self.name()));
+        }
 
-        // Convert to results
-        let mut items = self.convert_results(docs, &params.query)?;
+        // Filter, count and sort every match on its fast fields, so only
+        // the requested page has to be loaded and highlighted
+        let searcher = self.reader.searcher();
+        let fruit = searcher
+            .search(query.as_ref(), &collector)
+            .map_err(|e| Error::operation(format!("Search failed: {e}")))?;
 
-        // Apply filters
-        if let Some(ref category) = params.category {
-            items.retain(|r| r.category.eq_ignore_ascii_case(category));
+        let mut matches = fruit.matches;
+        matches.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.address.cmp(&b.address)));
+        match sort {
+            SortOrder::Relevance => {}
+            // Stable sorts keep relevance order among equal dates
+            SortOrder::DateDesc => matches.sort_by(|a, b| {
+                b.date
+                    .is_some()
+                    .cmp(&a.date.is_some())
+                    .then(b.date.cmp(&a.date))
+            }),
+            SortOrder::DateAsc => matches.sort_by(|a, b| {
+                b.date
+                    .is_some()
+                    .cmp(&a.date.is_some())
+                    .then(a.date.cmp(&b.date))
+            }),
         }
-        if let Some(ref source) = params.source {
-            items.retain(|r| {
-                r.source
-                    .as_ref()
-                    .is_some_and(|s| s.eq_ignore_ascii_case(source))
-            });
+
+        let total = matches.len();
+        let page: Vec<_> = matches
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .map(|m| (m.score, m.address))
+            .collect();
+        let items = self.convert_results(page, &params.query, query.as_ref())?;
+
+        let mut facets = fruit.facets;
+        let mut results = SearchResults::page(items, total, offset, self.name());
+        for field in &collector.facets {
+            let counts = facets.remove(field).unwrap_or_default();
+            results
+                .facets
+                .insert(field.as_str().to_string(), facet_counts(counts));
         }
-        if let Some(ref content_types) = params.content_types {
-            items.retain(|r| {
-                r.content_type
-                    .as_ref()
-                    .is_some_and(|ct| content_types.iter().any(|t| ct.eq_ignore_ascii_case(t)))
-            });
+        Ok(results)
+    }
+
+    fn name(&self) -> &str {
+        "tantivy"
+    }
+}
+
+/// A match that passed the filters, with what is needed to sort it.
+struct Match {
+    score: Score,
+    address: DocAddress,
+    date: Option<String>,
+}
+
+/// Matches and facet counts collected from one or more segments.
+#[derive(Default)]
+struct MatchFruit {
+    matches: Vec<Match>,
+    facets: BTreeMap<FacetField, BTreeMap<String, usize>>,
+}
+
+/// Collects every match that passes the category, source and content type
+/// filters, reading field values from fast columns rather than stored
+/// documents.
+///
+/// Filters compare case-insensitively, as the values are indexed raw.
+#[derive(Clone)]
+struct MatchCollector {
+    category: Option<String>,
+    source: Option<String>,
+    content_types: Option<Vec<String>>,
+    facets: Vec<FacetField>,
+    by_date: bool,
+}
+
+impl MatchCollector {
+    fn new(params: &SearchParams) -> Self {
+        let mut facets = params.facets.clone().unwrap_or_default();
+        facets.sort();
+        facets.dedup();
+        Self {
+            category: params.category.clone(),
+            source: params.source.clone(),
+            content_types: params.content_types.clone(),
+            facets,
+            by_date: params.sort.unwrap_or_default() != SortOrder::Relevance,
         }
+    }
+
+    /// Whether any filter is set.
+    fn filters(&self) -> bool {
+        self.category.is_some() || self.source.is_some() || self.content_types.is_some()
+    }
+
+    /// Whether values of `field` must be read for each match.
+    fn reads(&self, field: FacetField) -> bool {
+        let filtered = match field {
+            FacetField::Category => self.category.is_some(),
+            FacetField::Source => self.source.is_some(),
+            FacetField::ContentType => self.content_types.is_some(),
+        };
+        filtered || self.facets.contains(&field)
+    }
+
+    /// Whether a match with these field values passes the filters.
+    fn accepts(&self, values: &[Option<String>; 3]) -> bool {
+        let [category, source, content_type] = values;
+        let eq = |value: &Option<String>, wanted: &str| {
+            value
+                .as_deref()
+                .is_some_and(|v| v.eq_ignore_ascii_case(wanted))
+        };
+        self.category.as_deref().is_none_or(|c| eq(category, c))
+            && self.source.as_deref().is_none_or(|s| eq(source, s))
+            && self
+                .content_types
+                .as_ref()
+                .is_none_or(|types| types.iter().any(|t| eq(content_type, t)))
+    }
+}
+
+/// Fields read into `MatchSegmentCollector::columns`, in order.
+const FACET_FIELDS: [FacetField; 3] = [
+    FacetField::Category,
+    FacetField::Source,
+    FacetField::ContentType,
+];
+
+impl Collector for MatchCollector {
+    type Fruit = MatchFruit;
+    type Child = MatchSegmentCollector;
 
-        Ok(SearchResults {
-            items,
-            total,
-            backend: self.name().to_string(),
+    fn for_segment(
+        &self,
+        segment_ord: SegmentOrdinal,
+        segment: &SegmentReader,
+    ) -> tantivy::Result<MatchSegmentCollector> {
+        let fast_fields = segment.fast_fields();
+        let open = |used: bool, name: &str| {
+            if used {
+                fast_fields.str(name)
+            } else {
+                Ok(None)
+            }
+        };
+        let mut columns: [Option<StrColumn>; 3] = Default::default();
+        for (column, field) in columns.iter_mut().zip(FACET_FIELDS) {
+            *column = open(self.reads(field), field.as_str())?;
+        }
+        Ok(MatchSegmentCollector {
+            segment_ord,
+            collector: self.clone(),
+            columns,
+            date: open(self.by_date, "date")?,
+            fruit: MatchFruit::default(),
         })
     }
 
-    fn name(&self) -> &str {
-        "tantivy"
+    fn requires_scoring(&self) -> bool {
+        true
+    }
+
+    fn merge_fruits(&self, segment_fruits: Vec<MatchFruit>) -> tantivy::Result<MatchFruit> {
+        let mut merged = MatchFruit::default();
+        for fruit in segment_fruits {
+            merged.matches.extend(fruit.matches);
+            for (field, counts) in fruit.facets {
+                let merged_counts = merged.facets.entry(field).or_default();
+                for (value, count) in counts {
+                    *merged_counts.entry(value).or_default() += count;
+                }
+            }
+        }
+        Ok(merged)
+    }
+}
+
+/// Per-segment half of [`MatchCollector`].
+struct MatchSegmentCollector {
+    segment_ord: SegmentOrdinal,
+    collector: MatchCollector,
+    columns: [Option<StrColumn>; 3],
+    date: Option<StrColumn>,
+    fruit: MatchFruit,
+}
+
+impl SegmentCollector for MatchSegmentCollector {
+    type Fruit = MatchFruit;
+
+    fn collect(&mut self, doc: DocId, score: Score) {
+        let values = self.columns.each_ref().map(|c| column_str(c.as_ref(), doc));
+        if !self.collector.accepts(&values) {
+            return;
+        }
+
+        for (field, value) in FACET_FIELDS.into_iter().zip(values) {
+            if let Some(value) = value
+                && self.collector.facets.contains(&field)
+            {
+                *self
+                    .fruit
+                    .facets
+                    .entry(field)
+                    .or_default()
+                    .entry(value)
+                    .or_default() += 1;
+            }
+        }
+
+        self.fruit.matches.push(Match {
+            score,
+            address: DocAddress::new(self.segment_ord, doc),
+            date: column_str(self.date.as_ref(), doc),
+        });
+    }
+
+    fn harvest(self) -> MatchFruit {
+        self.fruit
+    }
+}
+
+/// Read the first non-empty value of a document from a fast string column.
+fn column_str(column: Option<&StrColumn>, doc: DocId) -> Option<String> {
+    let column = column?;
+    let ord = column.term_ords(doc).next()?;
+    let mut value = String::new();
+    match column.ord_to_str(ord, &mut value) {
+        Ok(true) if !value.is_empty() => Some(value),
+        _ => None,
     }
 }
 
@@ -234,6 +553,23 @@ fn get_text_field(doc: &tantivy::TantivyDocument, field: tantivy::schema::Field)
         .map(String::from)
 }
 
+/// Render a passage as a snippet, with ellipses where it is cut from its field.
+fn passage_snippet(passage: &Passage, description: Option<&str>, content: &str) -> String {
+    let field_len = match passage.field.as_str() {
+        "description" => description.map_or(0, str::len),
+        _ => content.len(),
+    };
+    let mut snippet = String::new();
+    if passage.offset > 0 {
+        snippet.push_str("...");
+    }
+    snippet.push_str(&passage.highlighted);
+    if passage.offset + passage.text.len() < field_len {
+        snippet.push_str("...");
+    }
+    snippet
+}
+
 /// Find a snippet of text containing the query.
 fn find_snippet_in_text(text: &str, query: &str, max_len: usize) -> Option<String> {
     if query.is_empty() || query == "*" {
@@ -495,6 +831,176 @@ mod tests {
         assert!(results.items.len() <= 1);
     }
 
+    #[tokio::test]
+    async fn test_tantivy_search_total_counts_all_matches() {
+        let (_temp, config) = create_test_index();
+        let backend = TantivySearch::new(&config).unwrap();
+
+        let results = backend
+            .search(SearchParams {
+                query: "*".to_string(),
+                limit: Some(1),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(results.items.len(), 1);
+        assert_eq!(results.total, 3);
+        assert!(results.next_cursor.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_tantivy_search_clamps_out_of_range_offsets() {
+        let (_temp, config) = create_test_index();
+        let backend = TantivySearch::new(&config).unwrap();
+
+        let results = backend
+            .search(SearchParams {
+                query: "*".to_string(),
+                limit: Some(usize::MAX),
+                offset: Some(usize::MAX),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert!(results.items.is_empty());
+        assert_eq!(results.total, 3);
+        assert_eq!(results.offset, 3);
+        assert!(results.next_cursor.is_none());
+
+        let results = backend
+            .search(SearchParams {
+                query: "*".to_string(),
+                limit: Some(usize::MAX),
+                cursor: Some(format!("offset:{}", usize::MAX)),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert!(results.items.is_empty());
+
+        let results = backend
+            .search(SearchParams {
+                query: "*".to_string(),
+                limit: Some(usize::MAX),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(results.items.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_tantivy_search_pages_with_cursor() {
+        let (_temp, config) = create_test_index();
+        let backend = TantivySearch::new(&config).unwrap();
+
+        let mut seen = Vec::new();
+        let mut cursor = None;
+        loop {
+            let results = backend
+                .search(SearchParams {
+                    query: "*".to_string(),
+                    limit: Some(2),
+                    cursor: cursor.clone(),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+            seen.extend(results.items.into_iter().map(|r| r.id));
+            cursor = results.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        seen.sort();
+        assert_eq!(seen, vec!["test-1", "test-2", "test-3"]);
+    }
+
+    #[tokio::test]
+    async fn test_tantivy_search_filtered_paging_and_facets() {
+        let (_temp, config) = create_test_index();
+        let backend = TantivySearch::new(&config).unwrap();
+
+        let results = backend
+            .search(SearchParams {
+                query: "*".to_string(),
+                category: Some("harmony".to_string()),
+                limit: Some(1),
+                offset: Some(1),
+                facets: Some(vec![FacetField::Category, FacetField::ContentType]),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(results.total, 2);
+        assert_eq!(results.offset, 1);
+        assert_eq!(results.items.len(), 1);
+        assert!(results.next_cursor.is_none());
+        assert_eq!(results.facets["category"][0].value, "harmony");
+        assert_eq!(results.facets["category"][0].count, 2);
+        assert_eq!(results.facets["content_type"].len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_tantivy_search_sorts_by_date() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let index_path = temp_dir.path().join("index");
+        let schema = SearchSchema::build();
+        let mut indexer = Indexer::new(&index_path, &schema).unwrap();
+        for (id, date) in [
+            ("old", Some("2020-01-01")),
+            ("undated", None),
+            ("new", Some("2025-06-01")),
+        ] {
+            let mut builder = SearchDocument::builder()
+                .id(id)
+                .title("Modulation")
+                .content("Modulation moves the tonal centre")
+                .category("harmony");
+            if let Some(date) = date {
+                builder = builder.date(date);
+            }
+            indexer.add_document(&builder.build()).unwrap();
+        }
+        indexer.commit().unwrap();
+
+        let config = SearchConfig {
+            index_path: Some(index_path.to_string_lossy().to_string()),
+            ..Default::default()
+        };
+        let backend = TantivySearch::new(&config).unwrap();
+        let ids = |results: SearchResults| -> Vec<String> {
+            results.items.into_iter().map(|r| r.id).collect()
+        };
+
+        let desc = backend
+            .search(SearchParams {
+                query: "modulation".to_string(),
+                sort: Some(SortOrder::DateDesc),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(ids(desc), vec!["new", "old", "undated"]);
+
+        let asc = backend
+            .search(SearchParams {
+                query: "modulation".to_string(),
+                sort: Some(SortOrder::DateAsc),
+                limit: Some(1),
+                offset: Some(1),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(asc.total, 3);
+        assert_eq!(ids(asc), vec!["new"]);
+    }
+
     #[tokio::test]
     async fn test_tantivy_search_relevance_ordering() {
         let (_temp, config) = create_test_index();
@@ -548,6 +1054,131 @@ mod tests {
         assert!(item.description.is_some());
     }
 
+    #[tokio::test]
+    async fn test_tantivy_search_highlights_stemmed_matches() {
+        let (_temp, config) = create_test_index();
+        let backend = TantivySearch::new(&config).unwrap();
+
+        let results = backend
+            .search(SearchParams {
+                query: "cadence".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let item = results.items.iter().find(|r| r.id == "test-3").unwrap();
+        // "cadence" also matches the stemmed "cadences" in the description
+        let description = &item.passages[0];
+        assert_eq!(description.field, "description");
+        let span = description.matches[0];
+        assert_eq!(&description.text[span.start..span.end], "cadences");
+        assert!(description.highlighted.contains("**cadences**"));
+        assert!(item.snippet.as_ref().unwrap().contains("**cadences**"));
+
+        let content = item.passages.iter().find(|p| p.field == "content").unwrap();
+        let span = content.matches[0];
+        assert_eq!(&content.text[span.start..span.end], "cadence");
+    }
+
+    #[tokio::test]
+    async fn test_tantivy_search_passage_offsets_point_into_field() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let index_path = temp_dir.path().join("index");
+        let schema = SearchSchema::build();
+        let mut indexer = Indexer::new(&index_path, &schema).unwrap();
+        let content = "Scales are the raw material.\n\n\
+                       Modulation moves the tonal centre.\n\n\
+                       A pivot chord enables modulation between keys.";
+        indexer
+            .add_document(
+                &SearchDocument::builder()
+                    .id("doc")
+                    .title("Keys")
+                    .content(content)
+                    .category("harmony")
+                    .build(),
+            )
+            .unwrap();
+        indexer.commit().unwrap();
+
+        let config = SearchConfig {
+            index_path: Some(index_path.to_string_lossy().to_string()),
+            highlight_prefix: "<em>".to_string(),
+            highlight_postfix: "</em>".to_string(),
+            ..Default::default()
+        };
+        let backend = TantivySearch::new(&config).unwrap();
+        let results = backend
+            .search(SearchParams {
+                query: "modulation".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let passages = &results.items[0].passages;
+        assert_eq!(passages.len(), 2);
+        for passage in passages {
+            assert_eq!(
+                &content[passage.offset..passage.offset + passage.text.len()],
+                passage.text
+            );
+            assert!(passage.highlighted.contains("<em>"));
+            for span in &passage.matches {
+                let start = passage.offset + span.start;
+                let end = passage.offset + span.end;
+                assert!(content[start..end].eq_ignore_ascii_case("modulation"));
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tantivy_search_max_passages() {
+        let (_temp, mut config) = create_test_index();
+        config.max_passages = 1;
+        let backend = TantivySearch::new(&config).unwrap();
+
+        let results = backend
+            .search(SearchParams {
+                query: "harmony".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert!(results.items.iter().all(|r| r.passages.len() <= 1));
+
+        config.max_passages = 0;
+        let backend = TantivySearch::new(&config).unwrap();
+        let results = backend
+            .search(SearchParams {
+                query: "harmony".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert!(results.items.iter().all(|r| r.passages.is_empty()));
+        // Snippets still come from the substring fallback
+        assert!(results.items.iter().all(|r| r.snippet.is_some()));
+    }
+
+    #[test]
+    fn test_passage_snippet_ellipses() {
+        let content = "one two three";
+        let passage = Passage::new(
+            "content",
+            "two",
+            4,
+            vec![MatchSpan { start: 0, end: 3 }],
+            "*",
+            "*",
+        );
+        assert_eq!(passage_snippet(&passage, None, content), "...*two*...");
+
+        let passage = Passage::new("content", content, 0, Vec::new(), "*", "*");
+        assert_eq!(passage_snippet(&passage, None, content), content);
+    }
+
     #[test]
     fn test_find_snippet_in_text_basic() {
         let text = "This is a test of harmony in music theory";
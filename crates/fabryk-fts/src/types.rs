@@ -71,6 +71,18 @@ pub struct SearchConfig {
     #[serde(default = "default_snippet_length", alias = "snippet_size")]
     pub snippet_length: usize,
 
+    /// Maximum highlighted passages per result.
+    #[serde(default = "default_max_passages")]
+    pub max_passages: usize,
+
+    /// Markup inserted before each highlighted match.
+    #[serde(default = "default_highlight_prefix")]
+    pub highlight_prefix: String,
+
+    /// Markup inserted after each highlighted match.
+    #[serde(default = "default_highlight_postfix")]
+    pub highlight_postfix: String,
+
     /// Rebuild index on startup.
     #[serde(default)]
     pub rebuild_on_startup: bool,
@@ -112,6 +124,18 @@ fn default_snippet_length() -> usize {
     200
 }
 
+fn default_max_passages() -> usize {
+    3
+}
+
+fn default_highlight_prefix() -> String {
+    "**".to_string()
+}
+
+fn default_highlight_postfix() -> String {
+    "**".to_string()
+}
+
 fn default_minimum_match() -> f32 {
     0.6
 }
@@ -142,6 +166,9 @@ impl Default for SearchConfig {
             allowlist: Vec::new(),
             default_limit: default_limit(),
             snippet_length: default_snippet_length(),
+            max_passages: default_max_passages(),
+            highlight_prefix: default_highlight_prefix(),
+            highlight_postfix: default_highlight_postfix(),
             rebuild_on_startup: false,
             minimum_match_percent: default_minimum_match(),
             field_boost_title: default_field_boost_title(),
@@ -232,6 +259,9 @@ mod tests {
         assert!(config.stopwords_enabled);
         assert_eq!(config.default_limit, 10);
         assert_eq!(config.snippet_length, 200);
+        assert_eq!(config.max_passages, 3);
+        assert_eq!(config.highlight_prefix, "**");
+        assert_eq!(config.highlight_postfix, "**");
     }
 
     #[test]
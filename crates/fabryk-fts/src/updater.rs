@@ -0,0 +1,203 @@
+//! Incremental index updates for live content watching.
+//!
+//! [`IndexUpdater`] is a [`ChangeHandler`] that replaces the documents of
+//! changed files in an on-disk Tantivy index, then opens a fresh
+//! [`TantivySearch`] and swaps it into a [`BackendSlot`]. In-flight searches
+//! keep using the previous reader until they complete.
+//!
+//! The index freshness metadata is left untouched, so the next startup
+//! still verifies the index against the content directory.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! let updater = IndexUpdater::new(Box::new(MyExtractor), config, fts_slot.clone());
+//! let watcher = ContentWatcher::for_content(&app_config, "concepts")?
+//!     .with_handler(updater)
+//!     .spawn()?;
+//! ```
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use fabryk_core::{BackendSlot, ChangeHandler, ChangeSet, Error, Result};
+
+use crate::builder::DocumentExtractor;
+use crate::indexer::Indexer;
+use crate::schema::SearchSchema;
+use crate::tantivy_search::TantivySearch;
+use crate::types::SearchConfig;
+
+/// Replaces index documents for changed content files.
+pub struct IndexUpdater {
+    extractor: Box<dyn DocumentExtractor>,
+    config: SearchConfig,
+    slot: BackendSlot<Arc<TantivySearch>>,
+    /// Document ID last extracted from each file, so removals and renames
+    /// delete the right document.
+    ids: Mutex<HashMap<PathBuf, String>>,
+}
+
+impl IndexUpdater {
+    /// Create an updater for the index at `config.index_path`, publishing
+    /// reopened backends to `slot`.
+    pub fn new(
+        extractor: Box<dyn DocumentExtractor>,
+        config: SearchConfig,
+        slot: BackendSlot<Arc<TantivySearch>>,
+    ) -> Self {
+        Self {
+            extractor,
+            config,
+            slot,
+            ids: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Document ID for a file the updater has not seen extracted: the
+    /// file stem, as used by the default extractor.
+    fn fallback_id(path: &Path) -> Option<String> {
+        path.file_stem().map(|s| s.to_string_lossy().to_string())
+    }
+
+    /// Stage deletions and replacements, returning the files that failed.
+    fn stage(&self, indexer: &mut Indexer, changes: &ChangeSet) -> Vec<String> {
+        let mut ids = self
+            .ids
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut failures = Vec::new();
+
+        for path in changes.removed() {
+            if let Some(id) = ids.remove(path).or_else(|| Self::fallback_id(path)) {
+                indexer.delete_document(&id);
+            }
+        }
+
+        for path in changes.upserted() {
+            let content = match std::fs::read_to_string(path) {
+                Ok(content) => content,
+                Err(e) => {
+                    failures.push(format!("{}: {e}", path.display()));
+                    continue;
+                }
+            };
+            let old_id = ids.remove(path);
+            if let Some(ref id) = old_id {
+                indexer.delete_document(id);
+            }
+
+            let Some(doc) = self.extractor.extract(path, &content) else {
+                log::debug!("Skipped {:?} (extraction returned None)", path);
+                continue;
+            };
+            if old_id.as_deref() != Some(doc.id.as_str()) {
+                indexer.delete_document(&doc.id);
+            }
+            match indexer.add_document(&doc) {
+                Ok(()) => {
+                    ids.insert(path.to_path_buf(), doc.id);
+                }
+                Err(e) => failures.push(format!("{}: {e}", path.display())),
+            }
+        }
+
+        failures
+    }
+}
+
+#[async_trait]
+impl ChangeHandler for IndexUpdater {
+    fn name(&self) -> &str {
+        "fts"
+    }
+
+    async fn apply(&self, changes: &ChangeSet) -> Result<()> {
+        let index_path = self
+            .config
+            .index_path
+            .as_ref()
+            .ok_or_else(|| Error::config("index_path is required for IndexUpdater"))?;
+
+        let schema = SearchSchema::build();
+        // The writer is dropped before reopening so its lock is released
+        let failures = {
+            let mut indexer = Indexer::new(Path::new(index_path), &schema)?;
+            let failures = self.stage(&mut indexer, changes);
+            indexer.commit()?;
+            failures
+        };
+
+        let search = TantivySearch::new(&self.config)?;
+        self.slot.swap(Arc::new(search))?;
+        log::info!("Search index updated for {} file(s)", changes.len());
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::operation(format!(
+                "{} file(s) could not be indexed: {}",
+                failures.len(),
+                failures.join("; ")
+            )))
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{SearchBackend, SearchParams};
+    use crate::builder::{DefaultExtractor, IndexBuilder};
+    use fabryk_core::{ChangeKind, ServiceState};
+
+    fn params(query: &str) -> SearchParams {
+        SearchParams {
+            query: query.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_apply_replaces_documents_and_swaps_backend() {
+        let dir = tempfile::tempdir().unwrap();
+        let content = dir.path().join("content");
+        let index = dir.path().join("index");
+        std::fs::create_dir(&content).unwrap();
+        std::fs::write(content.join("alpha.md"), "harmonic minor scale").unwrap();
+        std::fs::write(content.join("beta.md"), "circle of fifths").unwrap();
+        IndexBuilder::new().build(&content, &index).await.unwrap();
+
+        let config = SearchConfig {
+            index_path: Some(index.to_string_lossy().to_string()),
+            ..Default::default()
+        };
+        let slot: BackendSlot<Arc<TantivySearch>> = BackendSlot::new("fts");
+        slot.set(Arc::new(TantivySearch::new(&config).unwrap()))
+            .unwrap();
+        slot.service().set_state(ServiceState::Ready);
+
+        std::fs::write(content.join("alpha.md"), "melodic minor scale").unwrap();
+        std::fs::remove_file(content.join("beta.md")).unwrap();
+        let mut changes = ChangeSet::new();
+        changes.record(content.join("alpha.md"), ChangeKind::Modified);
+        changes.record(content.join("beta.md"), ChangeKind::Removed);
+
+        let updater =
+            IndexUpdater::new(Box::new(DefaultExtractor::default()), config, slot.clone());
+        updater.apply(&changes).await.unwrap();
+
+        let backend = slot.require().unwrap().clone().unwrap();
+        let melodic = backend.search(&params("melodic")).await.unwrap();
+        assert_eq!(melodic.total, 1);
+        assert_eq!(backend.search(&params("harmonic")).await.unwrap().total, 0);
+        assert_eq!(backend.search(&params("fifths")).await.unwrap().total, 0);
+        assert!(slot.is_ready());
+    }
+}
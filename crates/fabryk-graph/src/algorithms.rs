@@ -7,6 +7,9 @@
 //! - Centrality analysis (identifying important nodes)
 //! - Bridge detection (nodes connecting different clusters)
 //!
+//! Weighted centrality measures and exact cut vertices/edges live in
+//! [`crate::centrality`].
+//!
 //! All algorithms are generic and operate on `GraphData`.
 
 use crate::{Edge, GraphData, Node, NodeSummary, Relationship};
@@ -15,8 +15,12 @@
 //!   in `BuildStats::dangling_refs` instead of silently dropped.
 //! - **Bidirectional edge deduplication**: Prevents duplicate edges when both
 //!   sides of a relationship declare each other.
+//!
+//! An optional final pass infers edges from embedding similarity (see
+//! [`crate::semantic`]).
 
 use crate::persistence::{self, GraphMetadata};
+use crate::semantic::{SemanticEdgeInferrer, SemanticReport};
 use crate::{Edge, EdgeOrigin, GraphData, GraphExtractor, Relationship};
 use fabryk_content::markdown::extract_frontmatter;
 use fabryk_core::{Error, Result};
@@ -73,6 +77,8 @@ pub struct BuildStats {
     pub deduped_edges: usize,
     /// Whether the result was loaded from cache.
     pub from_cache: bool,
+    /// Report from the semantic edge pass, if one ran.
+    pub semantic_report: Option<SemanticReport>,
 }
 
 // ============================================================================
@@ -95,6 +101,7 @@ pub struct GraphBuilder<E: GraphExtractor> {
     error_handling: ErrorHandling,
     cache_path: Option<PathBuf>,
     skip_cache: bool,
+    semantic: Option<SemanticEdgeInferrer>,
 }
 
 impl<E: GraphExtractor> GraphBuilder<E> {
@@ -107,6 +114,7 @@ impl<E: GraphExtractor> GraphBuilder<E> {
             error_handling: ErrorHandling::default(),
             cache_path: None,
             skip_cache: false,
+            semantic: None,
         }
     }
 
@@ -145,6 +153,14 @@ impl<E: GraphExtractor> GraphBuilder<E> {
         self
     }
 
+    /// Runs a semantic edge inference pass after all other edges are added.
+    ///
+    /// The pass's report is returned in [`BuildStats::semantic_report`].
+    pub fn with_semantic_edges(mut self, inferrer: SemanticEdgeInferrer) -> Self {
+        self.semantic = Some(inferrer);
+        self
+    }
+
     /// Builds the graph.
     ///
     /// Uses a two-phase approach (adapted from Taproot):
@@ -180,6 +196,7 @@ impl<E: GraphExtractor> GraphBuilder<E> {
                     dangling_refs: Vec::new(),
                     deduped_edges: 0,
                     from_cache: true,
+                    semantic_report: None,
                 };
                 return Ok((graph, stats));
             }
@@ -198,6 +215,7 @@ impl<E: GraphExtractor> GraphBuilder<E> {
             dangling_refs: Vec::new(),
             deduped_edges: 0,
             from_cache: false,
+            semantic_report: None,
         };
 
         let mut graph = GraphData::new();
@@ -282,6 +300,15 @@ impl<E: GraphExtractor> GraphBuilder<E> {
                 load_manual_edges(manual_path, &mut graph, &mut seen_edges, &mut stats)?;
         }
 
+        // ================================================================
+        // Phase 4: Infer edges from embedding similarity
+        // ================================================================
+        if let Some(ref inferrer) = self.semantic {
+            let report = inferrer.apply(&mut graph).await?;
+            stats.edges_created += report.suggestions.len();
+            stats.semantic_report = Some(report);
+        }
+
         // Save to cache after successful build
         if let Some(ref cache_path) = self.cache_path {
             let content_hash = compute_content_hash(&content_path)?;
@@ -310,24 +337,7 @@ impl<E: GraphExtractor> GraphBuilder<E> {
         base_path: &Path,
         file_path: &Path,
     ) -> Result<(E::NodeData, Option<E::EdgeData>)> {
-        let content =
-            std::fs::read_to_string(file_path).map_err(|e| Error::io_with_path(e, file_path))?;
-
-        let fm_result = extract_frontmatter(&content)?;
-
-        let frontmatter = fm_result
-            .value()
-            .cloned()
-            .unwrap_or(yaml_serde::Value::Null);
-        let body = fm_result.body();
-
-        let node_data = self
-            .extractor
-            .extract_node(base_path, file_path, &frontmatter, body)?;
-
-        let edge_data = self.extractor.extract_edges(&frontmatter, body)?;
-
-        Ok((node_data, edge_data))
+        extract_file(&self.extractor, base_path, file_path)
     }
 }
 
@@ -335,6 +345,30 @@ impl<E: GraphExtractor> GraphBuilder<E> {
 // Helper functions
 // ============================================================================
 
+/// Read a content file and run the extractor over its frontmatter and body.
+pub(crate) fn extract_file<E: GraphExtractor>(
+    extractor: &E,
+    base_path: &Path,
+    file_path: &Path,
+) -> Result<(E::NodeData, Option<E::EdgeData>)> {
+    let content =
+        std::fs::read_to_string(file_path).map_err(|e| Error::io_with_path(e, file_path))?;
+
+    let fm_result = extract_frontmatter(&content)?;
+
+    let frontmatter = fm_result
+        .value()
+        .cloned()
+        .unwrap_or(yaml_serde::Value::Null);
+    let body = fm_result.body();
+
+    let node_data = extractor.extract_node(base_path, file_path, &frontmatter, body)?;
+
+    let edge_data = extractor.extract_edges(&frontmatter, body)?;
+
+    Ok((node_data, edge_data))
+}
+
 /// Parse a relationship string to Relationship enum.
 fn parse_relationship(s: &str) -> Relationship {
     match s.to_lowercase().as_str() {
@@ -623,6 +657,50 @@ mod tests {
         assert_eq!(stats.edges_created, 2);
     }
 
+    #[tokio::test]
+    async fn test_builder_semantic_edges() {
+        use crate::semantic::SemanticEdgeOptions;
+        use fabryk_vector::{MockEmbeddingProvider, SimpleVectorBackend};
+        use std::sync::Arc;
+
+        let (_dir, content_dir) = setup_test_files().await;
+        std::fs::write(
+            content_dir.join("concept-c.md"),
+            "---\ntitle: \"Concept C\"\n---\n\nMore content.\n",
+        )
+        .unwrap();
+
+        let provider = Arc::new(MockEmbeddingProvider::new(8));
+        let inferrer = SemanticEdgeInferrer::new(
+            Arc::new(SimpleVectorBackend::new(provider.clone())),
+            provider,
+        )
+        // Every pair counts as similar
+        .with_options(SemanticEdgeOptions::default().with_threshold(-1.0));
+
+        let (graph, stats) = GraphBuilder::new(MockExtractor)
+            .with_content_path(&content_dir)
+            .with_semantic_edges(inferrer)
+            .build()
+            .await
+            .unwrap();
+
+        let report = stats.semantic_report.unwrap();
+        assert_eq!(report.nodes_compared, 3);
+        // concept-a -> concept-b is already a prerequisite
+        assert_eq!(report.already_linked, 1);
+        assert_eq!(report.suggestions.len(), 2);
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(stats.edges_created, 3);
+        assert!(
+            graph
+                .edges
+                .iter()
+                .filter(|e| e.origin == EdgeOrigin::Semantic)
+                .all(|e| e.from == "concept-c" || e.to == "concept-c")
+        );
+    }
+
     #[tokio::test]
     async fn test_builder_empty_directory() {
         let dir = tempdir().unwrap();
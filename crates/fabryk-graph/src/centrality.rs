@@ -0,0 +1,917 @@
+//! Weighted centrality and connectivity analysis.
+//!
+//! Provides:
+//! - PageRank (optionally personalized to a set of seed nodes)
+//! - Betweenness, closeness, and eigenvector centrality
+//! - Weighted degree centrality
+//! - Articulation points and bridge edges (Tarjan's algorithm)
+//!
+//! Every algorithm can be restricted to a set of relationship types and
+//! uses `Edge.weight` as link strength: PageRank follows stronger links more
+//! often, eigenvector and degree sum weights, and the distance-based measures
+//! treat an edge as `1 / weight` long (as [`shortest_path`] does). Edges with
+//! a non-positive weight are ignored.
+//!
+//! PageRank follows edge direction. Betweenness, closeness, and eigenvector
+//! centrality treat edges as undirected: curriculum graphs are mostly
+//! acyclic, where directed eigenvector scores vanish and most directed
+//! distances are undefined.
+//!
+//! [`shortest_path`]: crate::shortest_path
+
+use crate::{Edge, GraphData, Node, Relationship};
+use fabryk_core::{Error, Result};
+use petgraph::visit::EdgeRef;
+use serde::{Deserialize, Serialize};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+
+/// Relative tolerance when comparing path lengths.
+const DISTANCE_EPSILON: f64 = 1e-9;
+
+// ============================================================================
+// Options and result types
+// ============================================================================
+
+/// A centrality measure.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CentralityAlgorithm {
+    /// Sum of incident edge weights.
+    #[default]
+    Degree,
+    /// Stationary distribution of a random walk along edges.
+    PageRank,
+    /// Share of shortest paths passing through a node.
+    Betweenness,
+    /// Inverse mean distance to reachable nodes.
+    Closeness,
+    /// Principal eigenvector of the adjacency matrix.
+    Eigenvector,
+}
+
+impl CentralityAlgorithm {
+    /// All algorithms.
+    pub const ALL: [CentralityAlgorithm; 5] = [
+        Self::Degree,
+        Self::PageRank,
+        Self::Betweenness,
+        Self::Closeness,
+        Self::Eigenvector,
+    ];
+
+    /// Returns the algorithm name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Degree => "degree",
+            Self::PageRank => "pagerank",
+            Self::Betweenness => "betweenness",
+            Self::Closeness => "closeness",
+            Self::Eigenvector => "eigenvector",
+        }
+    }
+}
+
+impl fmt::Display for CentralityAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for CentralityAlgorithm {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().replace(['-', '_'], "").as_str() {
+            "degree" => Ok(Self::Degree),
+            "pagerank" => Ok(Self::PageRank),
+            "betweenness" => Ok(Self::Betweenness),
+            "closeness" => Ok(Self::Closeness),
+            "eigenvector" => Ok(Self::Eigenvector),
+            _ => Err(Error::config(format!(
+                "Unknown centrality algorithm '{s}' (expected one of: {})",
+                Self::ALL.map(|a| a.name()).join(", ")
+            ))),
+        }
+    }
+}
+
+/// Options for [`compute_centrality`].
+#[derive(Clone, Debug)]
+pub struct CentralityOptions {
+    /// Algorithm to run.
+    pub algorithm: CentralityAlgorithm,
+    /// Only consider edges of these relationship types (all if `None`).
+    pub relationships: Option<Vec<Relationship>>,
+    /// Use `Edge.weight`; if false, every edge counts as 1.0.
+    pub weighted: bool,
+    /// PageRank seed nodes; the random walk restarts only at these.
+    pub personalization: Vec<String>,
+    /// PageRank damping factor.
+    pub damping: f64,
+    /// Iteration cap for PageRank and eigenvector centrality.
+    pub max_iterations: usize,
+    /// Convergence threshold (L1 change between iterations).
+    pub tolerance: f64,
+}
+
+impl Default for CentralityOptions {
+    fn default() -> Self {
+        Self {
+            algorithm: CentralityAlgorithm::default(),
+            relationships: None,
+            weighted: true,
+            personalization: Vec::new(),
+            damping: 0.85,
+            max_iterations: 100,
+            tolerance: 1e-6,
+        }
+    }
+}
+
+impl CentralityOptions {
+    /// Options for the given algorithm with default parameters.
+    pub fn new(algorithm: CentralityAlgorithm) -> Self {
+        Self {
+            algorithm,
+            ..Default::default()
+        }
+    }
+
+    /// Restrict to the given relationship types.
+    pub fn with_relationships(mut self, relationships: Vec<Relationship>) -> Self {
+        self.relationships = Some(relationships);
+        self
+    }
+
+    /// Personalize PageRank to the given seed nodes.
+    pub fn with_personalization(
+        mut self,
+        ids: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.personalization = ids.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the PageRank damping factor.
+    pub fn with_damping(mut self, damping: f64) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Treat every edge as weight 1.0.
+    pub fn unweighted(mut self) -> Self {
+        self.weighted = false;
+        self
+    }
+}
+
+/// A node's score under one centrality measure.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NodeCentrality {
+    /// Node ID.
+    pub node_id: String,
+    /// Score; scale depends on the algorithm.
+    pub score: f32,
+}
+
+// ============================================================================
+// Weighted view
+// ============================================================================
+
+/// Compact adjacency lists over the edges that pass a relationship filter.
+///
+/// Positions are petgraph node indices.
+pub(crate) struct WeightedView {
+    /// Outgoing `(target, weight)` per node, parallel edges summed.
+    pub(crate) out: Vec<Vec<(usize, f64)>>,
+    /// Undirected `(neighbor, weight)` per node, both directions summed.
+    pub(crate) undirected: Vec<Vec<(usize, f64)>>,
+}
+
+impl WeightedView {
+    pub(crate) fn new(
+        graph: &GraphData,
+        relationships: Option<&[Relationship]>,
+        weighted: bool,
+    ) -> Self {
+        let n = graph.node_count();
+        let mut out: Vec<HashMap<usize, f64>> = vec![HashMap::new(); n];
+        let mut undirected: Vec<HashMap<usize, f64>> = vec![HashMap::new(); n];
+
+        for edge_ref in graph.graph.edge_references() {
+            let Some(weight) = edge_weight(edge_ref.weight(), relationships, weighted) else {
+                continue;
+            };
+            let (from, to) = (edge_ref.source().index(), edge_ref.target().index());
+            if from == to {
+                continue;
+            }
+            *out[from].entry(to).or_default() += weight;
+            *undirected[from].entry(to).or_default() += weight;
+            *undirected[to].entry(from).or_default() += weight;
+        }
+
+        let sorted = |lists: Vec<HashMap<usize, f64>>| {
+            lists
+                .into_iter()
+                .map(|list| {
+                    let mut list: Vec<(usize, f64)> = list.into_iter().collect();
+                    list.sort_by_key(|&(idx, _)| idx);
+                    list
+                })
+                .collect()
+        };
+        Self {
+            out: sorted(out),
+            undirected: sorted(undirected),
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.out.len()
+    }
+}
+
+/// Weight of an edge under the filter, or `None` if it is excluded.
+pub(crate) fn edge_weight(
+    edge: &Edge,
+    relationships: Option<&[Relationship]>,
+    weighted: bool,
+) -> Option<f64> {
+    if relationships.is_some_and(|rels| !rels.contains(&edge.relationship)) {
+        return None;
+    }
+    if !weighted {
+        return Some(1.0);
+    }
+    let weight = f64::from(edge.weight);
+    (weight > 0.0).then_some(weight)
+}
+
+/// Priority queue entry for Dijkstra.
+#[derive(PartialEq)]
+struct Visit(f64, usize);
+
+impl Eq for Visit {}
+
+impl PartialOrd for Visit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Visit {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0).then(self.1.cmp(&other.1))
+    }
+}
+
+/// Single-source shortest paths over the undirected view, with edge
+/// length `1 / weight`.
+///
+/// Returns distances (`None` if unreachable), shortest-path counts,
+/// predecessor lists, and nodes in order of non-decreasing distance.
+struct ShortestPaths {
+    dist: Vec<Option<f64>>,
+    sigma: Vec<f64>,
+    preds: Vec<Vec<usize>>,
+    order: Vec<usize>,
+}
+
+fn shortest_paths(view: &WeightedView, source: usize) -> ShortestPaths {
+    let n = view.len();
+    let mut dist: Vec<Option<f64>> = vec![None; n];
+    let mut sigma = vec![0.0; n];
+    let mut preds = vec![Vec::new(); n];
+    let mut order = Vec::new();
+    let mut settled = vec![false; n];
+    let mut heap = BinaryHeap::new();
+
+    dist[source] = Some(0.0);
+    sigma[source] = 1.0;
+    heap.push(Reverse(Visit(0.0, source)));
+
+    while let Some(Reverse(Visit(d, u))) = heap.pop() {
+        if settled[u] {
+            continue;
+        }
+        settled[u] = true;
+        order.push(u);
+
+        for &(v, weight) in &view.undirected[u] {
+            let candidate = d + 1.0 / weight;
+            match dist[v] {
+                Some(current) if (candidate - current).abs() <= DISTANCE_EPSILON * current => {
+                    if !settled[v] {
+                        sigma[v] += sigma[u];
+                        preds[v].push(u);
+                    }
+                }
+                Some(current) if candidate >= current => {}
+                _ => {
+                    dist[v] = Some(candidate);
+                    sigma[v] = sigma[u];
+                    preds[v] = vec![u];
+                    heap.push(Reverse(Visit(candidate, v)));
+                }
+            }
+        }
+    }
+
+    ShortestPaths {
+        dist,
+        sigma,
+        preds,
+        order,
+    }
+}
+
+// ============================================================================
+// Centrality
+// ============================================================================
+
+/// Compute centrality scores for all nodes.
+///
+/// Returns scores sorted descending (ties by node ID). Score scales:
+/// - `Degree`: weighted degree divided by `2 * (n - 1)`
+/// - `PageRank`: probabilities summing to 1.0
+/// - `Betweenness`: normalized by `(n - 1) * (n - 2) / 2`
+/// - `Closeness`: 0.0 to 1.0, scaled by the reachable fraction
+/// - `Eigenvector`: unit L2 norm
+///
+/// # Errors
+///
+/// Returns `NotFound` if a personalization seed is not in the graph.
+pub fn compute_centrality(
+    graph: &GraphData,
+    options: &CentralityOptions,
+) -> Result<Vec<NodeCentrality>> {
+    let view = WeightedView::new(graph, options.relationships.as_deref(), options.weighted);
+
+    let scores = match options.algorithm {
+        CentralityAlgorithm::Degree => degree(&view),
+        CentralityAlgorithm::PageRank => {
+            let seeds = options
+                .personalization
+                .iter()
+                .map(|id| {
+                    graph
+                        .get_index(id)
+                        .map(|idx| idx.index())
+                        .ok_or_else(|| Error::not_found("node", id))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            pagerank(&view, &seeds, options)
+        }
+        CentralityAlgorithm::Betweenness => betweenness(&view),
+        CentralityAlgorithm::Closeness => closeness(&view),
+        CentralityAlgorithm::Eigenvector => eigenvector(&view, options),
+    };
+
+    let mut ranked: Vec<NodeCentrality> = graph
+        .graph
+        .node_indices()
+        .map(|idx| NodeCentrality {
+            node_id: graph.graph[idx].id.clone(),
+            score: scores[idx.index()] as f32,
+        })
+        .collect();
+    ranked.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.node_id.cmp(&b.node_id))
+    });
+    Ok(ranked)
+}
+
+fn degree(view: &WeightedView) -> Vec<f64> {
+    let n = view.len();
+    if n < 2 {
+        return vec![0.0; n];
+    }
+    let scale = 2.0 * (n as f64 - 1.0);
+    view.undirected
+        .iter()
+        .map(|list| list.iter().map(|&(_, w)| w).sum::<f64>() / scale)
+        .collect()
+}
+
+fn pagerank(view: &WeightedView, seeds: &[usize], options: &CentralityOptions) -> Vec<f64> {
+    let n = view.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    // Restart distribution: uniform over the seeds, or over all nodes
+    let mut restart = vec![0.0; n];
+    let unique: HashSet<usize> = seeds.iter().copied().collect();
+    if unique.is_empty() {
+        restart.fill(1.0 / n as f64);
+    } else {
+        for &seed in &unique {
+            restart[seed] = 1.0 / unique.len() as f64;
+        }
+    }
+
+    let out_weight: Vec<f64> = view
+        .out
+        .iter()
+        .map(|list| list.iter().map(|&(_, w)| w).sum())
+        .collect();
+    let damping = options.damping;
+    let mut rank = restart.clone();
+
+    for _ in 0..options.max_iterations {
+        // Dangling nodes hand their rank back through the restart distribution
+        let dangling: f64 = (0..n)
+            .filter(|&u| out_weight[u] == 0.0)
+            .map(|u| rank[u])
+            .sum();
+        let mut next: Vec<f64> = restart
+            .iter()
+            .map(|&r| (1.0 - damping + damping * dangling) * r)
+            .collect();
+        for (u, list) in view.out.iter().enumerate() {
+            if out_weight[u] == 0.0 {
+                continue;
+            }
+            let share = damping * rank[u] / out_weight[u];
+            for &(v, w) in list {
+                next[v] += share * w;
+            }
+        }
+
+        let change: f64 = next.iter().zip(&rank).map(|(a, b)| (a - b).abs()).sum();
+        rank = next;
+        if change < options.tolerance {
+            break;
+        }
+    }
+    rank
+}
+
+/// Brandes' algorithm over weighted shortest paths.
+fn betweenness(view: &WeightedView) -> Vec<f64> {
+    let n = view.len();
+    let mut centrality = vec![0.0; n];
+
+    for source in 0..n {
+        let paths = shortest_paths(view, source);
+        let mut delta = vec![0.0; n];
+        for &w in paths.order.iter().rev() {
+            for &v in &paths.preds[w] {
+                delta[v] += paths.sigma[v] / paths.sigma[w] * (1.0 + delta[w]);
+            }
+            if w != source {
+                centrality[w] += delta[w];
+            }
+        }
+    }
+
+    // Each undirected pair was counted from both ends
+    if n > 2 {
+        let scale = (n as f64 - 1.0) * (n as f64 - 2.0);
+        for value in &mut centrality {
+            *value /= scale;
+        }
+    }
+    centrality
+}
+
+/// Wasserman–Faust closeness, which stays comparable across components.
+fn closeness(view: &WeightedView) -> Vec<f64> {
+    let n = view.len();
+    if n < 2 {
+        return vec![0.0; n];
+    }
+
+    (0..n)
+        .map(|source| {
+            let paths = shortest_paths(view, source);
+            let reached: Vec<f64> = paths.dist.iter().flatten().copied().collect();
+            let others = reached.len() as f64 - 1.0;
+            let total: f64 = reached.iter().sum();
+            if total > 0.0 {
+                (others / total) * (others / (n as f64 - 1.0))
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+/// Power iteration on `A + I`, which converges on bipartite graphs too.
+fn eigenvector(view: &WeightedView, options: &CentralityOptions) -> Vec<f64> {
+    let n = view.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut x = vec![1.0 / n as f64; n];
+    for _ in 0..options.max_iterations {
+        let mut next = x.clone();
+        for (u, list) in view.undirected.iter().enumerate() {
+            for &(v, w) in list {
+                next[v] += x[u] * w;
+            }
+        }
+        let norm = next.iter().map(|v| v * v).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            return vec![0.0; n];
+        }
+        for value in &mut next {
+            *value /= norm;
+        }
+
+        let change: f64 = next.iter().zip(&x).map(|(a, b)| (a - b).abs()).sum();
+        x = next;
+        if change < options.tolerance {
+            break;
+        }
+    }
+
+    // A + I gives isolated nodes a self-weight; they are not central
+    for (value, list) in x.iter_mut().zip(&view.undirected) {
+        if list.is_empty() {
+            *value = 0.0;
+        }
+    }
+    let norm = x.iter().map(|v| v * v).sum::<f64>().sqrt();
+    if norm > 0.0 {
+        for value in &mut x {
+            *value /= norm;
+        }
+    }
+    x
+}
+
+// ============================================================================
+// Articulation points and bridges
+// ============================================================================
+
+/// Cut vertices and cut edges of the undirected graph.
+struct Connectivity {
+    articulation: Vec<bool>,
+    bridges: Vec<petgraph::graph::EdgeIndex>,
+}
+
+/// Iterative Tarjan DFS; parallel edges are told apart by edge index so
+/// a doubled link is never a bridge.
+fn connectivity(graph: &GraphData, relationships: Option<&[Relationship]>) -> Connectivity {
+    const UNVISITED: usize = usize::MAX;
+
+    let n = graph.node_count();
+    let mut adjacency: Vec<Vec<(usize, petgraph::graph::EdgeIndex)>> = vec![Vec::new(); n];
+    for edge_ref in graph.graph.edge_references() {
+        if edge_weight(edge_ref.weight(), relationships, true).is_none() {
+            continue;
+        }
+        let (from, to) = (edge_ref.source().index(), edge_ref.target().index());
+        if from != to {
+            adjacency[from].push((to, edge_ref.id()));
+            adjacency[to].push((from, edge_ref.id()));
+        }
+    }
+
+    let mut disc = vec![UNVISITED; n];
+    let mut low = vec![UNVISITED; n];
+    let mut articulation = vec![false; n];
+    let mut bridges = Vec::new();
+    let mut timer = 0;
+
+    for root in 0..n {
+        if disc[root] != UNVISITED {
+            continue;
+        }
+        disc[root] = timer;
+        low[root] = timer;
+        timer += 1;
+        let mut root_children = 0;
+        // (node, edge used to reach it, next adjacency position)
+        let mut stack = vec![(root, None, 0)];
+
+        while let Some(&(u, via, next)) = stack.last() {
+            if let Some(&(v, edge)) = adjacency[u].get(next) {
+                if let Some(top) = stack.last_mut() {
+                    top.2 += 1;
+                }
+                if via == Some(edge) {
+                    continue;
+                }
+                if disc[v] == UNVISITED {
+                    disc[v] = timer;
+                    low[v] = timer;
+                    timer += 1;
+                    if u == root {
+                        root_children += 1;
+                    }
+                    stack.push((v, Some(edge), 0));
+                } else {
+                    low[u] = low[u].min(disc[v]);
+                }
+                continue;
+            }
+
+            stack.pop();
+            if let (Some(&(parent, _, _)), Some(edge)) = (stack.last(), via) {
+                low[parent] = low[parent].min(low[u]);
+                if low[u] > disc[parent] {
+                    bridges.push(edge);
+                }
+                if parent != root && low[u] >= disc[parent] {
+                    articulation[parent] = true;
+                }
+            }
+        }
+
+        if root_children > 1 {
+            articulation[root] = true;
+        }
+    }
+
+    Connectivity {
+        articulation,
+        bridges,
+    }
+}
+
+/// Find articulation points: nodes whose removal disconnects the graph.
+///
+/// Edges are treated as undirected. Returns nodes sorted by ID.
+pub fn articulation_points(graph: &GraphData, relationships: Option<&[Relationship]>) -> Vec<Node> {
+    let result = connectivity(graph, relationships);
+    let mut nodes: Vec<Node> = graph
+        .graph
+        .node_indices()
+        .filter(|idx| result.articulation[idx.index()])
+        .map(|idx| graph.graph[idx].clone())
+        .collect();
+    nodes.sort_by(|a, b| a.id.cmp(&b.id));
+    nodes
+}
+
+/// Find bridge edges: edges whose removal disconnects the graph.
+///
+/// Edges are treated as undirected, so a pair linked in both directions
+/// is never a bridge. Returns edges sorted by endpoints.
+pub fn bridge_edges(graph: &GraphData, relationships: Option<&[Relationship]>) -> Vec<Edge> {
+    let mut edges: Vec<Edge> = connectivity(graph, relationships)
+        .bridges
+        .into_iter()
+        .map(|idx| graph.graph[idx].clone())
+        .collect();
+    edges.sort_by(|a, b| (&a.from, &a.to).cmp(&(&b.from, &b.to)));
+    edges
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph_with(edges: &[(&str, &str, Relationship)]) -> GraphData {
+        let mut graph = GraphData::new();
+        for (from, to, _) in edges {
+            for id in [from, to] {
+                if !graph.contains_node(id) {
+                    graph.add_node(Node::new(*id, *id));
+                }
+            }
+        }
+        for (from, to, rel) in edges {
+            graph.add_edge(Edge::new(*from, *to, rel.clone())).unwrap();
+        }
+        graph
+    }
+
+    /// Two triangles joined through the edge c - d.
+    fn barbell() -> GraphData {
+        use Relationship::RelatesTo as R;
+        graph_with(&[
+            ("a", "b", R),
+            ("b", "c", R),
+            ("c", "a", R),
+            ("c", "d", R),
+            ("d", "e", R),
+            ("e", "f", R),
+            ("f", "d", R),
+        ])
+    }
+
+    fn score(scores: &[NodeCentrality], id: &str) -> f32 {
+        scores.iter().find(|s| s.node_id == id).unwrap().score
+    }
+
+    #[test]
+    fn test_algorithm_from_str() {
+        for algorithm in CentralityAlgorithm::ALL {
+            assert_eq!(
+                algorithm.name().parse::<CentralityAlgorithm>().unwrap(),
+                algorithm
+            );
+        }
+        assert_eq!(
+            "Page_Rank".parse::<CentralityAlgorithm>().unwrap(),
+            CentralityAlgorithm::PageRank
+        );
+        assert!("katz".parse::<CentralityAlgorithm>().is_err());
+    }
+
+    #[test]
+    fn test_pagerank_sums_to_one_and_favors_sinks() {
+        use Relationship::Prerequisite as P;
+        let graph = graph_with(&[("a", "c", P), ("b", "c", P), ("c", "d", P)]);
+        let scores = compute_centrality(
+            &graph,
+            &CentralityOptions::new(CentralityAlgorithm::PageRank),
+        )
+        .unwrap();
+
+        let total: f32 = scores.iter().map(|s| s.score).sum();
+        assert!((total - 1.0).abs() < 1e-4);
+        assert_eq!(scores[0].node_id, "d");
+        assert!(score(&scores, "c") > score(&scores, "a"));
+    }
+
+    #[test]
+    fn test_pagerank_uses_edge_weights() {
+        let mut graph = graph_with(&[]);
+        for id in ["a", "b", "c"] {
+            graph.add_node(Node::new(id, id));
+        }
+        graph
+            .add_edge(Edge::new("a", "b", Relationship::RelatesTo).with_weight(0.9))
+            .unwrap();
+        graph
+            .add_edge(Edge::new("a", "c", Relationship::RelatesTo).with_weight(0.1))
+            .unwrap();
+
+        let options = CentralityOptions::new(CentralityAlgorithm::PageRank);
+        let weighted = compute_centrality(&graph, &options).unwrap();
+        assert!(score(&weighted, "b") > score(&weighted, "c"));
+
+        let unweighted = compute_centrality(&graph, &options.unweighted()).unwrap();
+        assert!((score(&unweighted, "b") - score(&unweighted, "c")).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_personalized_pagerank() {
+        let graph = barbell();
+        let options =
+            CentralityOptions::new(CentralityAlgorithm::PageRank).with_personalization(["f"]);
+        let scores = compute_centrality(&graph, &options).unwrap();
+        assert!(score(&scores, "e") > score(&scores, "b"));
+
+        let missing =
+            CentralityOptions::new(CentralityAlgorithm::PageRank).with_personalization(["nope"]);
+        assert!(compute_centrality(&graph, &missing).is_err());
+    }
+
+    #[test]
+    fn test_betweenness_barbell() {
+        let scores = compute_centrality(
+            &barbell(),
+            &CentralityOptions::new(CentralityAlgorithm::Betweenness),
+        )
+        .unwrap();
+
+        // c and d lie on the paths between the triangles: 6 of 10 pairs
+        let top: HashSet<&str> = scores[..2].iter().map(|s| s.node_id.as_str()).collect();
+        assert_eq!(top, HashSet::from(["c", "d"]));
+        assert!((score(&scores, "c") - 0.6).abs() < 1e-4);
+        assert_eq!(score(&scores, "a"), 0.0);
+    }
+
+    #[test]
+    fn test_betweenness_prefers_strong_links() {
+        // a - b - d is stronger (shorter) than a - c - d
+        let mut graph = graph_with(&[]);
+        for id in ["a", "b", "c", "d"] {
+            graph.add_node(Node::new(id, id));
+        }
+        for (from, to, weight) in [
+            ("a", "b", 1.0),
+            ("b", "d", 1.0),
+            ("a", "c", 0.2),
+            ("c", "d", 0.2),
+        ] {
+            graph
+                .add_edge(Edge::new(from, to, Relationship::RelatesTo).with_weight(weight))
+                .unwrap();
+        }
+        let scores = compute_centrality(
+            &graph,
+            &CentralityOptions::new(CentralityAlgorithm::Betweenness),
+        )
+        .unwrap();
+        assert!(score(&scores, "b") > score(&scores, "c"));
+    }
+
+    #[test]
+    fn test_closeness_center_of_path() {
+        // Prerequisite edges have weight 1.0, so each hop has length 1
+        use Relationship::Prerequisite as R;
+        let graph = graph_with(&[("a", "b", R), ("b", "c", R)]);
+        let scores = compute_centrality(
+            &graph,
+            &CentralityOptions::new(CentralityAlgorithm::Closeness),
+        )
+        .unwrap();
+        assert_eq!(scores[0].node_id, "b");
+        assert!((score(&scores, "b") - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_eigenvector_hub() {
+        use Relationship::RelatesTo as R;
+        let mut graph = graph_with(&[("hub", "a", R), ("hub", "b", R), ("hub", "c", R)]);
+        graph.add_node(Node::new("isolated", "Isolated"));
+        let scores = compute_centrality(
+            &graph,
+            &CentralityOptions::new(CentralityAlgorithm::Eigenvector),
+        )
+        .unwrap();
+
+        assert_eq!(scores[0].node_id, "hub");
+        assert_eq!(score(&scores, "isolated"), 0.0);
+        let norm: f32 = scores.iter().map(|s| s.score * s.score).sum();
+        assert!((norm - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_relationship_filter() {
+        let graph = graph_with(&[
+            ("a", "b", Relationship::Prerequisite),
+            ("c", "b", Relationship::Prerequisite),
+            ("b", "d", Relationship::RelatesTo),
+            ("e", "d", Relationship::RelatesTo),
+            ("f", "d", Relationship::RelatesTo),
+        ]);
+        let options = CentralityOptions::new(CentralityAlgorithm::Degree)
+            .with_relationships(vec![Relationship::Prerequisite]);
+        let scores = compute_centrality(&graph, &options).unwrap();
+        assert_eq!(scores[0].node_id, "b");
+        assert_eq!(score(&scores, "d"), 0.0);
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let graph = GraphData::new();
+        for algorithm in CentralityAlgorithm::ALL {
+            let scores = compute_centrality(&graph, &CentralityOptions::new(algorithm)).unwrap();
+            assert!(scores.is_empty());
+        }
+        assert!(articulation_points(&graph, None).is_empty());
+        assert!(bridge_edges(&graph, None).is_empty());
+    }
+
+    #[test]
+    fn test_articulation_points_and_bridges() {
+        let graph = barbell();
+        let points: Vec<String> = articulation_points(&graph, None)
+            .into_iter()
+            .map(|n| n.id)
+            .collect();
+        assert_eq!(points, vec!["c", "d"]);
+
+        let bridges = bridge_edges(&graph, None);
+        assert_eq!(bridges.len(), 1);
+        assert_eq!(
+            (bridges[0].from.as_str(), bridges[0].to.as_str()),
+            ("c", "d")
+        );
+    }
+
+    #[test]
+    fn test_bridges_ignore_parallel_edges() {
+        use Relationship::RelatesTo as R;
+        let graph = graph_with(&[("a", "b", R), ("b", "a", R), ("b", "c", R)]);
+        let bridges = bridge_edges(&graph, None);
+        assert_eq!(bridges.len(), 1);
+        assert_eq!(bridges[0].to, "c");
+
+        let points: Vec<String> = articulation_points(&graph, None)
+            .into_iter()
+            .map(|n| n.id)
+            .collect();
+        assert_eq!(points, vec!["b"]);
+    }
+
+    #[test]
+    fn test_bridges_with_relationship_filter() {
+        let graph = graph_with(&[
+            ("a", "b", Relationship::Prerequisite),
+            ("b", "c", Relationship::Prerequisite),
+            ("a", "c", Relationship::RelatesTo),
+        ]);
+        assert!(bridge_edges(&graph, None).is_empty());
+        let filtered = bridge_edges(&graph, Some(&[Relationship::Prerequisite]));
+        assert_eq!(filtered.len(), 2);
+    }
+}
@@ -0,0 +1,848 @@
+//! Community detection for knowledge graphs.
+//!
+//! Provides three clustering algorithms over the undirected, weighted view
+//! of a [`GraphData`]:
+//! - Louvain modularity optimization
+//! - Leiden, which refines Louvain so every cluster is connected
+//! - Label propagation (fast, no objective function)
+//!
+//! Each cluster comes with a summary: its most central nodes, its category
+//! mix, and how strongly it is tied to the rest of the graph. Clusters
+//! without a dominant category or with high conductance usually point at
+//! topic areas that need curation.
+//!
+//! All algorithms visit nodes in index order, so results are deterministic
+//! for a given graph.
+
+use crate::centrality::{WeightedView, edge_weight};
+use crate::{
+    CategoryCount, CentralityAlgorithm, CentralityOptions, GraphData, NodeCentrality, Relationship,
+    compute_centrality,
+};
+use fabryk_core::{Error, Result};
+use petgraph::visit::EdgeRef;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+
+/// Minimum modularity gain for a node to change community.
+const GAIN_EPSILON: f64 = 1e-12;
+
+// ============================================================================
+// Options and result types
+// ============================================================================
+
+/// A community detection algorithm.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClusterAlgorithm {
+    /// Louvain modularity optimization.
+    Louvain,
+    /// Leiden: Louvain with a refinement step guaranteeing connected clusters.
+    #[default]
+    Leiden,
+    /// Label propagation.
+    LabelPropagation,
+}
+
+impl ClusterAlgorithm {
+    /// All algorithms.
+    pub const ALL: [ClusterAlgorithm; 3] = [Self::Louvain, Self::Leiden, Self::LabelPropagation];
+
+    /// Returns the algorithm name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Louvain => "louvain",
+            Self::Leiden => "leiden",
+            Self::LabelPropagation => "label_propagation",
+        }
+    }
+}
+
+impl fmt::Display for ClusterAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for ClusterAlgorithm {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().replace(['-', '_'], "").as_str() {
+            "louvain" => Ok(Self::Louvain),
+            "leiden" => Ok(Self::Leiden),
+            "labelpropagation" | "lpa" => Ok(Self::LabelPropagation),
+            _ => Err(Error::config(format!(
+                "Unknown clustering algorithm '{s}' (expected one of: {})",
+                Self::ALL.map(|a| a.name()).join(", ")
+            ))),
+        }
+    }
+}
+
+/// Options for [`detect_clusters`].
+#[derive(Clone, Debug)]
+pub struct ClusterOptions {
+    /// Algorithm to run.
+    pub algorithm: ClusterAlgorithm,
+    /// Only consider edges of these relationship types (all if `None`).
+    pub relationships: Option<Vec<Relationship>>,
+    /// Use `Edge.weight`; if false, every edge counts as 1.0.
+    pub weighted: bool,
+    /// Modularity resolution; higher values yield smaller clusters.
+    pub resolution: f64,
+    /// Clusters smaller than this are reported as unclustered nodes.
+    pub min_size: usize,
+    /// Number of top nodes to list per cluster.
+    pub top_nodes: usize,
+    /// Centrality measure used to rank nodes within a cluster. Defaults
+    /// to eigenvector centrality, undirected like the clustering itself.
+    pub ranking: CentralityAlgorithm,
+    /// Iteration cap (passes for label propagation, levels otherwise).
+    pub max_iterations: usize,
+}
+
+impl Default for ClusterOptions {
+    fn default() -> Self {
+        Self {
+            algorithm: ClusterAlgorithm::default(),
+            relationships: None,
+            weighted: true,
+            resolution: 1.0,
+            min_size: 2,
+            top_nodes: 5,
+            ranking: CentralityAlgorithm::Eigenvector,
+            max_iterations: 50,
+        }
+    }
+}
+
+impl ClusterOptions {
+    /// Options for the given algorithm with default parameters.
+    pub fn new(algorithm: ClusterAlgorithm) -> Self {
+        Self {
+            algorithm,
+            ..Default::default()
+        }
+    }
+
+    /// Restrict to the given relationship types.
+    pub fn with_relationships(mut self, relationships: Vec<Relationship>) -> Self {
+        self.relationships = Some(relationships);
+        self
+    }
+
+    /// Set the modularity resolution.
+    pub fn with_resolution(mut self, resolution: f64) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// Set the minimum reported cluster size.
+    pub fn with_min_size(mut self, min_size: usize) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Set the number of top nodes listed per cluster.
+    pub fn with_top_nodes(mut self, top_nodes: usize) -> Self {
+        self.top_nodes = top_nodes;
+        self
+    }
+
+    /// Treat every edge as weight 1.0.
+    pub fn unweighted(mut self) -> Self {
+        self.weighted = false;
+        self
+    }
+}
+
+/// Summary of one detected cluster.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Cluster {
+    /// Cluster number (0 is the largest).
+    pub id: usize,
+    /// Number of member nodes.
+    pub size: usize,
+    /// Member node IDs, most central first.
+    pub nodes: Vec<String>,
+    /// The most central members.
+    pub top_nodes: Vec<NodeCentrality>,
+    /// Member categories, most common first.
+    pub categories: Vec<CategoryCount>,
+    /// Category held by more than half of the members, if any.
+    pub dominant_category: Option<String>,
+    /// Members without a category.
+    pub uncategorized: usize,
+    /// Total weight of edges inside the cluster.
+    pub internal_weight: f32,
+    /// Total weight of edges leaving the cluster.
+    pub external_weight: f32,
+    /// Share of the cluster's edge weight that leaves it (0.0 to 1.0).
+    pub conductance: f32,
+}
+
+/// Result of [`detect_clusters`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ClusteringResult {
+    /// Algorithm that produced the clusters.
+    pub algorithm: ClusterAlgorithm,
+    /// Modularity of the full partition.
+    pub modularity: f32,
+    /// Clusters of at least `min_size` nodes, largest first.
+    pub clusters: Vec<Cluster>,
+    /// Nodes in clusters below `min_size`, sorted by ID.
+    pub unclustered: Vec<String>,
+}
+
+// ============================================================================
+// Aggregated graph
+// ============================================================================
+
+/// Undirected weighted graph at one aggregation level.
+struct Level {
+    /// `(neighbor, weight)` per node, without self-loops.
+    adjacency: Vec<Vec<(usize, f64)>>,
+    /// Weight of edges folded into each node (each edge counted once).
+    self_loops: Vec<f64>,
+}
+
+impl Level {
+    fn from_view(view: &WeightedView) -> Self {
+        Self {
+            adjacency: view.undirected.clone(),
+            self_loops: vec![0.0; view.len()],
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// Weighted degree of every node.
+    fn strengths(&self) -> Vec<f64> {
+        self.adjacency
+            .iter()
+            .zip(&self.self_loops)
+            .map(|(list, self_loop)| list.iter().map(|&(_, w)| w).sum::<f64>() + 2.0 * self_loop)
+            .collect()
+    }
+
+    /// Collapse each community into a single node.
+    fn aggregate(&self, membership: &[usize], count: usize) -> Self {
+        let mut adjacency: Vec<BTreeMap<usize, f64>> = vec![BTreeMap::new(); count];
+        let mut self_loops = vec![0.0; count];
+
+        for (i, list) in self.adjacency.iter().enumerate() {
+            let ci = membership[i];
+            self_loops[ci] += self.self_loops[i];
+            for &(j, w) in list {
+                let cj = membership[j];
+                if ci == cj {
+                    // Seen once from each end
+                    self_loops[ci] += w / 2.0;
+                } else {
+                    *adjacency[ci].entry(cj).or_default() += w;
+                }
+            }
+        }
+
+        Self {
+            adjacency: adjacency
+                .into_iter()
+                .map(|list| list.into_iter().collect())
+                .collect(),
+            self_loops,
+        }
+    }
+}
+
+/// Relabel communities as `0..count` in order of first appearance.
+fn renumber(communities: &[usize]) -> (Vec<usize>, usize) {
+    let mut ids: HashMap<usize, usize> = HashMap::new();
+    let renumbered = communities
+        .iter()
+        .map(|&c| {
+            let next = ids.len();
+            *ids.entry(c).or_insert(next)
+        })
+        .collect();
+    (renumbered, ids.len())
+}
+
+/// Weight from `node` to each neighboring community, sorted by community.
+fn community_links(level: &Level, node: usize, communities: &[usize]) -> Vec<(usize, f64)> {
+    let mut links: BTreeMap<usize, f64> = BTreeMap::new();
+    for &(j, w) in &level.adjacency[node] {
+        *links.entry(communities[j]).or_default() += w;
+    }
+    links.into_iter().collect()
+}
+
+// ============================================================================
+// Louvain / Leiden
+// ============================================================================
+
+/// Move nodes between communities while modularity improves.
+///
+/// Returns whether any node moved.
+fn local_moving(
+    level: &Level,
+    communities: &mut [usize],
+    resolution: f64,
+    max_passes: usize,
+) -> bool {
+    let strengths = level.strengths();
+    let total: f64 = strengths.iter().sum();
+    if total == 0.0 {
+        return false;
+    }
+
+    let mut community_totals = vec![0.0; level.len()];
+    for (i, &c) in communities.iter().enumerate() {
+        community_totals[c] += strengths[i];
+    }
+
+    let mut moved_any = false;
+    for _ in 0..max_passes {
+        let mut moved = false;
+        for i in 0..level.len() {
+            let current = communities[i];
+            let k = strengths[i];
+            community_totals[current] -= k;
+
+            let links = community_links(level, i, communities);
+            let gain = |c: usize, w: f64| w - resolution * community_totals[c] * k / total;
+            let own = links
+                .iter()
+                .find(|&&(c, _)| c == current)
+                .map_or(0.0, |&(_, w)| w);
+
+            let mut best = current;
+            let mut best_gain = gain(current, own);
+            for &(c, w) in &links {
+                let g = gain(c, w);
+                if g > best_gain + GAIN_EPSILON {
+                    best = c;
+                    best_gain = g;
+                }
+            }
+
+            community_totals[best] += k;
+            if best != current {
+                communities[i] = best;
+                moved = true;
+            }
+        }
+        if !moved {
+            break;
+        }
+        moved_any = true;
+    }
+    moved_any
+}
+
+/// Leiden refinement: split each community into well-connected
+/// subcommunities, merging singletons greedily.
+fn refine(level: &Level, communities: &[usize], resolution: f64) -> Vec<usize> {
+    let n = level.len();
+    let strengths = level.strengths();
+    let total: f64 = strengths.iter().sum();
+    let mut refined: Vec<usize> = (0..n).collect();
+    if total == 0.0 {
+        return refined;
+    }
+
+    let mut community_totals = vec![0.0; n];
+    for (i, &c) in communities.iter().enumerate() {
+        community_totals[c] += strengths[i];
+    }
+    // Subcommunity totals and weight to the rest of the parent community,
+    // indexed by the subcommunity's founding node
+    let mut sub_totals = strengths.clone();
+    let mut sub_external: Vec<f64> = (0..n)
+        .map(|i| {
+            level.adjacency[i]
+                .iter()
+                .filter(|&&(j, _)| communities[j] == communities[i])
+                .map(|&(_, w)| w)
+                .sum()
+        })
+        .collect();
+    let mut singleton = vec![true; n];
+
+    let well_connected = |external: f64, inner: f64, parent_total: f64| {
+        external >= resolution * inner * (parent_total - inner) / total
+    };
+
+    for v in 0..n {
+        let parent = communities[v];
+        if !singleton[v] || !well_connected(sub_external[v], strengths[v], community_totals[parent])
+        {
+            continue;
+        }
+
+        let mut best = None;
+        let mut best_gain = 0.0;
+        for (s, w) in community_links(level, v, &refined) {
+            if s == v
+                || communities[s] != parent
+                || !well_connected(sub_external[s], sub_totals[s], community_totals[parent])
+            {
+                continue;
+            }
+            let g = w - resolution * strengths[v] * sub_totals[s] / total;
+            if g >= best_gain {
+                best = Some((s, w));
+                best_gain = g;
+            }
+        }
+
+        if let Some((s, w)) = best {
+            sub_external[s] += sub_external[v] - 2.0 * w;
+            sub_totals[s] += strengths[v];
+            refined[v] = s;
+            singleton[v] = false;
+            singleton[s] = false;
+        }
+    }
+    refined
+}
+
+/// Louvain: alternate local moving and aggregation until stable.
+fn louvain(view: &WeightedView, options: &ClusterOptions) -> Vec<usize> {
+    let mut level = Level::from_view(view);
+    let mut membership: Vec<usize> = (0..view.len()).collect();
+
+    for _ in 0..options.max_iterations {
+        let mut communities: Vec<usize> = (0..level.len()).collect();
+        if !local_moving(
+            &level,
+            &mut communities,
+            options.resolution,
+            options.max_iterations,
+        ) {
+            break;
+        }
+        let (communities, count) = renumber(&communities);
+        for m in &mut membership {
+            *m = communities[*m];
+        }
+        level = level.aggregate(&communities, count);
+    }
+    membership
+}
+
+/// Leiden: local moving, refinement, then aggregation of the refined
+/// partition seeded with the unrefined one.
+fn leiden(view: &WeightedView, options: &ClusterOptions) -> Vec<usize> {
+    let mut level = Level::from_view(view);
+    let mut membership: Vec<usize> = (0..view.len()).collect();
+    let mut communities: Vec<usize> = (0..level.len()).collect();
+
+    for _ in 0..options.max_iterations {
+        local_moving(
+            &level,
+            &mut communities,
+            options.resolution,
+            options.max_iterations,
+        );
+        communities = renumber(&communities).0;
+
+        let (refined, count) = renumber(&refine(&level, &communities, options.resolution));
+        if count == level.len() {
+            break;
+        }
+        let mut parents = vec![0; count];
+        for (i, &r) in refined.iter().enumerate() {
+            parents[r] = communities[i];
+        }
+        for m in &mut membership {
+            *m = refined[*m];
+        }
+        level = level.aggregate(&refined, count);
+        communities = parents;
+    }
+
+    let assignment: Vec<usize> = membership.iter().map(|&m| communities[m]).collect();
+    split_disconnected(view, &assignment)
+}
+
+/// Split every community into its connected components.
+fn split_disconnected(view: &WeightedView, communities: &[usize]) -> Vec<usize> {
+    const UNASSIGNED: usize = usize::MAX;
+    let mut components = vec![UNASSIGNED; view.len()];
+    let mut next = 0;
+
+    for start in 0..view.len() {
+        if components[start] != UNASSIGNED {
+            continue;
+        }
+        components[start] = next;
+        let mut stack = vec![start];
+        while let Some(u) = stack.pop() {
+            for &(v, _) in &view.undirected[u] {
+                if components[v] == UNASSIGNED && communities[v] == communities[u] {
+                    components[v] = next;
+                    stack.push(v);
+                }
+            }
+        }
+        next += 1;
+    }
+    components
+}
+
+// ============================================================================
+// Label propagation
+// ============================================================================
+
+/// Each node repeatedly adopts the label with the most edge weight among
+/// its neighbors; ties keep the current label, else take the smallest.
+fn label_propagation(view: &WeightedView, options: &ClusterOptions) -> Vec<usize> {
+    let mut labels: Vec<usize> = (0..view.len()).collect();
+
+    for _ in 0..options.max_iterations {
+        let mut changed = false;
+        for i in 0..view.len() {
+            let mut weights: BTreeMap<usize, f64> = BTreeMap::new();
+            for &(j, w) in &view.undirected[i] {
+                *weights.entry(labels[j]).or_default() += w;
+            }
+            let Some(max) = weights.values().copied().reduce(f64::max) else {
+                continue;
+            };
+            let current = weights.get(&labels[i]).copied().unwrap_or(0.0);
+            if current >= max - GAIN_EPSILON {
+                continue;
+            }
+            if let Some((&label, _)) = weights.iter().find(|&(_, &w)| w >= max - GAIN_EPSILON) {
+                labels[i] = label;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    labels
+}
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Detect clusters of closely linked nodes.
+///
+/// Edges are treated as undirected. Nodes without edges (under the
+/// relationship filter) form singleton clusters and are reported in
+/// `unclustered` unless `min_size` is 1.
+pub fn detect_clusters(graph: &GraphData, options: &ClusterOptions) -> Result<ClusteringResult> {
+    let view = WeightedView::new(graph, options.relationships.as_deref(), options.weighted);
+    let assignment = match options.algorithm {
+        ClusterAlgorithm::Louvain => louvain(&view, options),
+        ClusterAlgorithm::Leiden => leiden(&view, options),
+        ClusterAlgorithm::LabelPropagation => label_propagation(&view, options),
+    };
+    let (assignment, count) = renumber(&assignment);
+    let modularity = modularity(&view, &assignment, count, options.resolution);
+
+    let mut centrality_options = CentralityOptions::new(options.ranking);
+    centrality_options.relationships = options.relationships.clone();
+    centrality_options.weighted = options.weighted;
+    let scores = compute_centrality(graph, &centrality_options)?;
+
+    // Members in descending centrality order
+    let mut members: Vec<Vec<NodeCentrality>> = vec![Vec::new(); count];
+    for score in scores {
+        if let Some(idx) = graph.get_index(&score.node_id) {
+            members[assignment[idx.index()]].push(score);
+        }
+    }
+
+    let mut internal = vec![0.0; count];
+    let mut external = vec![0.0; count];
+    for edge_ref in graph.graph.edge_references() {
+        let Some(w) = edge_weight(
+            edge_ref.weight(),
+            options.relationships.as_deref(),
+            options.weighted,
+        ) else {
+            continue;
+        };
+        let (a, b) = (
+            assignment[edge_ref.source().index()],
+            assignment[edge_ref.target().index()],
+        );
+        if a == b {
+            internal[a] += w;
+        } else {
+            external[a] += w;
+            external[b] += w;
+        }
+    }
+
+    let mut unclustered = Vec::new();
+    let mut clusters = Vec::new();
+    for (c, nodes) in members.into_iter().enumerate() {
+        if nodes.len() < options.min_size.max(1) {
+            unclustered.extend(nodes.into_iter().map(|s| s.node_id));
+            continue;
+        }
+        clusters.push(summarize(
+            graph,
+            nodes,
+            internal[c],
+            external[c],
+            options.top_nodes,
+        ));
+    }
+    clusters.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.nodes.cmp(&b.nodes)));
+    for (id, cluster) in clusters.iter_mut().enumerate() {
+        cluster.id = id;
+    }
+    unclustered.sort();
+
+    Ok(ClusteringResult {
+        algorithm: options.algorithm,
+        modularity: modularity as f32,
+        clusters,
+        unclustered,
+    })
+}
+
+/// Modularity of a partition of the level-0 graph.
+fn modularity(view: &WeightedView, assignment: &[usize], count: usize, resolution: f64) -> f64 {
+    let mut inner = vec![0.0; count];
+    let mut totals = vec![0.0; count];
+    let mut total = 0.0;
+    for (i, list) in view.undirected.iter().enumerate() {
+        for &(j, w) in list {
+            totals[assignment[i]] += w;
+            total += w;
+            if assignment[i] == assignment[j] {
+                inner[assignment[i]] += w;
+            }
+        }
+    }
+    if total == 0.0 {
+        return 0.0;
+    }
+    inner
+        .iter()
+        .zip(&totals)
+        .map(|(inner, tot)| inner / total - resolution * (tot / total).powi(2))
+        .sum()
+}
+
+fn summarize(
+    graph: &GraphData,
+    members: Vec<NodeCentrality>,
+    internal: f64,
+    external: f64,
+    top_nodes: usize,
+) -> Cluster {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    let mut uncategorized = 0;
+    for member in &members {
+        match graph
+            .get_node(&member.node_id)
+            .and_then(|n| n.category.clone())
+        {
+            Some(category) => *counts.entry(category).or_default() += 1,
+            None => uncategorized += 1,
+        }
+    }
+    let mut categories: Vec<CategoryCount> = counts
+        .into_iter()
+        .map(|(category, count)| CategoryCount { category, count })
+        .collect();
+    categories.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then_with(|| a.category.cmp(&b.category))
+    });
+
+    let size = members.len();
+    let dominant_category = categories
+        .first()
+        .filter(|c| c.count * 2 > size)
+        .map(|c| c.category.clone());
+    let volume = 2.0 * internal + external;
+
+    Cluster {
+        id: 0,
+        size,
+        nodes: members.iter().map(|m| m.node_id.clone()).collect(),
+        top_nodes: members.into_iter().take(top_nodes).collect(),
+        categories,
+        dominant_category,
+        uncategorized,
+        internal_weight: internal as f32,
+        external_weight: external as f32,
+        conductance: if volume > 0.0 {
+            (external / volume) as f32
+        } else {
+            0.0
+        },
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Edge, Node};
+
+    /// Two 4-cliques ("x" and "y" categories) joined by one edge, plus an
+    /// isolated node.
+    fn two_communities() -> GraphData {
+        let mut graph = GraphData::new();
+        for (prefix, category) in [("x", "harmony"), ("y", "rhythm")] {
+            for i in 0..4 {
+                graph.add_node(Node::new(format!("{prefix}{i}"), "").with_category(category));
+            }
+            for i in 0..4 {
+                for j in (i + 1)..4 {
+                    graph
+                        .add_edge(Edge::new(
+                            format!("{prefix}{i}"),
+                            format!("{prefix}{j}"),
+                            Relationship::RelatesTo,
+                        ))
+                        .unwrap();
+                }
+            }
+        }
+        graph
+            .add_edge(Edge::new("x0", "y0", Relationship::Prerequisite))
+            .unwrap();
+        graph.add_node(Node::new("lonely", "Lonely"));
+        graph
+    }
+
+    fn members(result: &ClusteringResult) -> Vec<Vec<String>> {
+        result
+            .clusters
+            .iter()
+            .map(|c| {
+                let mut nodes = c.nodes.clone();
+                nodes.sort();
+                nodes
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_algorithm_from_str() {
+        for algorithm in ClusterAlgorithm::ALL {
+            assert_eq!(
+                algorithm.name().parse::<ClusterAlgorithm>().unwrap(),
+                algorithm
+            );
+        }
+        assert_eq!(
+            "label-propagation".parse::<ClusterAlgorithm>().unwrap(),
+            ClusterAlgorithm::LabelPropagation
+        );
+        assert!("spectral".parse::<ClusterAlgorithm>().is_err());
+    }
+
+    #[test]
+    fn test_all_algorithms_find_both_cliques() {
+        let graph = two_communities();
+        for algorithm in ClusterAlgorithm::ALL {
+            let result = detect_clusters(&graph, &ClusterOptions::new(algorithm)).unwrap();
+            assert_eq!(
+                members(&result),
+                vec![vec!["x0", "x1", "x2", "x3"], vec!["y0", "y1", "y2", "y3"]],
+                "{algorithm}"
+            );
+            assert_eq!(result.unclustered, vec!["lonely"]);
+            assert!(
+                result.modularity > 0.3,
+                "{algorithm}: {}",
+                result.modularity
+            );
+        }
+    }
+
+    #[test]
+    fn test_cluster_summary() {
+        let result = detect_clusters(&two_communities(), &ClusterOptions::default()).unwrap();
+        let x = result
+            .clusters
+            .iter()
+            .find(|c| c.nodes.contains(&"x1".to_string()))
+            .unwrap();
+
+        assert_eq!(x.size, 4);
+        assert_eq!(x.dominant_category.as_deref(), Some("harmony"));
+        assert_eq!(x.uncategorized, 0);
+        // The bridging node is the most central member
+        assert_eq!(x.top_nodes[0].node_id, "x0");
+        assert!((x.internal_weight - 6.0 * 0.7).abs() < 1e-4);
+        assert!((x.external_weight - 1.0).abs() < 1e-4);
+        assert!(x.conductance > 0.0 && x.conductance < 0.2);
+    }
+
+    #[test]
+    fn test_relationship_filter_and_min_size() {
+        let graph = two_communities();
+        let options = ClusterOptions::new(ClusterAlgorithm::Louvain)
+            .with_relationships(vec![Relationship::Prerequisite])
+            .with_min_size(1);
+        let result = detect_clusters(&graph, &options).unwrap();
+
+        assert_eq!(result.clusters[0].nodes.len(), 2);
+        assert!(result.unclustered.is_empty());
+        assert_eq!(result.clusters.len(), 8);
+    }
+
+    #[test]
+    fn test_resolution_controls_cluster_size() {
+        let graph = two_communities();
+        let coarse = detect_clusters(
+            &graph,
+            &ClusterOptions::new(ClusterAlgorithm::Louvain).with_resolution(0.01),
+        )
+        .unwrap();
+        assert_eq!(coarse.clusters.len(), 1);
+        assert_eq!(coarse.clusters[0].size, 8);
+        assert!(coarse.clusters[0].dominant_category.is_none());
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        for algorithm in ClusterAlgorithm::ALL {
+            let result =
+                detect_clusters(&GraphData::new(), &ClusterOptions::new(algorithm)).unwrap();
+            assert!(result.clusters.is_empty());
+            assert_eq!(result.modularity, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_split_disconnected() {
+        let mut graph = GraphData::new();
+        for id in ["a", "b", "c", "d"] {
+            graph.add_node(Node::new(id, id));
+        }
+        graph
+            .add_edge(Edge::new("a", "b", Relationship::RelatesTo))
+            .unwrap();
+        graph
+            .add_edge(Edge::new("c", "d", Relationship::RelatesTo))
+            .unwrap();
+        let view = WeightedView::new(&graph, None, true);
+
+        let (split, count) = renumber(&split_disconnected(&view, &[0, 0, 0, 0]));
+        assert_eq!(count, 2);
+        assert_eq!(split, vec![0, 0, 1, 1]);
+    }
+}
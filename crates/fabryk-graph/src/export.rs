@@ -0,0 +1,719 @@
+//! Graph export to standard interchange formats.
+//!
+//! Writes a [`GraphData`] as:
+//!
+//! - **GraphML** — opens in Gephi, yEd and Cytoscape
+//! - **DOT** — Graphviz
+//! - **JSON-LD** — linked-data tooling, with edges reified so their
+//!   weight and origin survive
+//! - **CSV** — a node table and an edge table (Gephi's spreadsheet import)
+//!
+//! Every format carries the edge relationship, weight and origin, and the
+//! node fields and metadata. GraphML and CSV can be read back with
+//! [`crate::import`].
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use fabryk_graph::{ExportFormat, export_graph};
+//!
+//! export_graph(&graph, ExportFormat::GraphMl, "graph.graphml")?;
+//! export_graph(&graph, ExportFormat::Csv, "graph-csv/")?;
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::Path;
+use std::str::FromStr;
+
+use fabryk_core::{Error, Result};
+
+use crate::{EdgeOrigin, GraphData, Node, NodeType};
+
+/// File name of the node table in a CSV export directory.
+pub const CSV_NODES_FILE: &str = "nodes.csv";
+
+/// File name of the edge table in a CSV export directory.
+pub const CSV_EDGES_FILE: &str = "edges.csv";
+
+/// GraphML key description marking values stored as JSON text.
+pub(crate) const GRAPHML_JSON_DESC: &str = "json";
+
+// ============================================================================
+// ExportFormat
+// ============================================================================
+
+/// Interchange formats for graph export and import.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Fabryk's native `SerializableGraph` JSON.
+    Json,
+    /// GraphML XML.
+    GraphMl,
+    /// Graphviz DOT.
+    Dot,
+    /// JSON-LD.
+    JsonLd,
+    /// A directory holding `nodes.csv` and `edges.csv`.
+    Csv,
+}
+
+impl ExportFormat {
+    /// All formats, in the order they are listed to users.
+    pub const ALL: [ExportFormat; 5] = [
+        Self::GraphMl,
+        Self::Dot,
+        Self::JsonLd,
+        Self::Csv,
+        Self::Json,
+    ];
+
+    /// Canonical format name, as accepted by [`FromStr`].
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::GraphMl => "graphml",
+            Self::Dot => "dot",
+            Self::JsonLd => "jsonld",
+            Self::Csv => "csv",
+        }
+    }
+
+    /// Whether graphs in this format can be imported.
+    pub fn can_import(&self) -> bool {
+        matches!(self, Self::Json | Self::GraphMl | Self::Csv)
+    }
+
+    /// Infer the format from a path: a directory is CSV, otherwise the
+    /// file extension decides.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        if path.is_dir() {
+            return Some(Self::Csv);
+        }
+        match path.extension()?.to_str()?.to_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            "graphml" | "xml" => Some(Self::GraphMl),
+            "dot" | "gv" => Some(Self::Dot),
+            "jsonld" => Some(Self::JsonLd),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().replace('-', "").as_str() {
+            "json" => Ok(Self::Json),
+            "graphml" => Ok(Self::GraphMl),
+            "dot" | "graphviz" => Ok(Self::Dot),
+            "jsonld" => Ok(Self::JsonLd),
+            "csv" => Ok(Self::Csv),
+            other => Err(Error::config(format!(
+                "Unknown graph format '{other}' (expected one of: {})",
+                Self::ALL.map(|f| f.name()).join(", ")
+            ))),
+        }
+    }
+}
+
+// ============================================================================
+// Shared helpers
+// ============================================================================
+
+/// Serialized name of an edge origin (matches its serde representation).
+pub(crate) fn origin_name(origin: &EdgeOrigin) -> &'static str {
+    match origin {
+        EdgeOrigin::Frontmatter => "Frontmatter",
+        EdgeOrigin::ContentBody => "ContentBody",
+        EdgeOrigin::Manual => "Manual",
+        EdgeOrigin::Inferred => "Inferred",
+        EdgeOrigin::Semantic => "Semantic",
+    }
+}
+
+/// Nodes sorted by ID, so exports are deterministic.
+fn sorted_nodes(graph: &GraphData) -> Vec<&Node> {
+    let mut nodes: Vec<&Node> = graph.nodes.values().collect();
+    nodes.sort_by(|a, b| a.id.cmp(&b.id));
+    nodes
+}
+
+/// Render a metadata value as plain text: strings unquoted, the rest as JSON.
+fn value_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+// ============================================================================
+// GraphML
+// ============================================================================
+
+/// GraphML attribute type for a metadata key, inferred from its values.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum AttrType {
+    Boolean,
+    Long,
+    Double,
+    String,
+    /// Arrays, objects and mixed types, stored as JSON text.
+    Json,
+}
+
+impl AttrType {
+    fn of(value: &serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Bool(_) => Self::Boolean,
+            serde_json::Value::Number(n) if n.is_i64() || n.is_u64() => Self::Long,
+            serde_json::Value::Number(_) => Self::Double,
+            serde_json::Value::String(_) => Self::String,
+            _ => Self::Json,
+        }
+    }
+
+    fn unify(self, other: Self) -> Self {
+        match (self, other) {
+            (a, b) if a == b => a,
+            (Self::Long, Self::Double) | (Self::Double, Self::Long) => Self::Double,
+            _ => Self::Json,
+        }
+    }
+
+    fn graphml_name(self) -> &'static str {
+        match self {
+            Self::Boolean => "boolean",
+            Self::Long => "long",
+            Self::Double => "double",
+            Self::String | Self::Json => "string",
+        }
+    }
+}
+
+/// Escape text for XML content and attribute values.
+pub(crate) fn xml_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Render a graph as GraphML.
+///
+/// Node fields and edge relationship, weight and origin become typed
+/// `<key>`s. Each metadata key gets its own typed key named after it;
+/// values that are arrays, objects or of mixed types are written as JSON
+/// text, marked with `<desc>json</desc>` so they import losslessly.
+pub fn to_graphml(graph: &GraphData) -> String {
+    let nodes = sorted_nodes(graph);
+
+    // Metadata keys and their unified types
+    let mut meta_types: BTreeMap<&str, AttrType> = BTreeMap::new();
+    for node in &nodes {
+        for (key, value) in &node.metadata {
+            let ty = AttrType::of(value);
+            meta_types
+                .entry(key.as_str())
+                .and_modify(|t| *t = t.unify(ty))
+                .or_insert(ty);
+        }
+    }
+
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str(
+        "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\" \
+         xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" \
+         xsi:schemaLocation=\"http://graphml.graphdrawing.org/xmlns \
+         http://graphml.graphdrawing.org/xmlns/1.0/graphml.xsd\">\n",
+    );
+
+    let fixed_keys = [
+        ("title", "node", "title", "string"),
+        ("category", "node", "category", "string"),
+        ("source_id", "node", "source_id", "string"),
+        ("is_canonical", "node", "is_canonical", "boolean"),
+        ("canonical_id", "node", "canonical_id", "string"),
+        ("node_type", "node", "node_type", "string"),
+        ("relationship", "edge", "relationship", "string"),
+        ("weight", "edge", "weight", "double"),
+        ("origin", "edge", "origin", "string"),
+    ];
+    for (id, domain, name, ty) in fixed_keys {
+        let _ = writeln!(
+            out,
+            "  <key id=\"{id}\" for=\"{domain}\" attr.name=\"{name}\" attr.type=\"{ty}\"/>"
+        );
+    }
+    let meta_ids: BTreeMap<&str, String> = meta_types
+        .keys()
+        .enumerate()
+        .map(|(i, key)| (*key, format!("m{i}")))
+        .collect();
+    for (key, ty) in &meta_types {
+        let id = &meta_ids[key];
+        let name = xml_escape(key);
+        let graphml_type = ty.graphml_name();
+        if *ty == AttrType::Json {
+            let _ = writeln!(
+                out,
+                "  <key id=\"{id}\" for=\"node\" attr.name=\"{name}\" attr.type=\"{graphml_type}\">\
+                 <desc>{GRAPHML_JSON_DESC}</desc></key>"
+            );
+        } else {
+            let _ = writeln!(
+                out,
+                "  <key id=\"{id}\" for=\"node\" attr.name=\"{name}\" attr.type=\"{graphml_type}\"/>"
+            );
+        }
+    }
+
+    out.push_str("  <graph id=\"G\" edgedefault=\"directed\">\n");
+    for node in &nodes {
+        let _ = writeln!(out, "    <node id=\"{}\">", xml_escape(&node.id));
+        let mut data = |key: &str, value: &str| {
+            let _ = writeln!(
+                out,
+                "      <data key=\"{key}\">{}</data>",
+                xml_escape(value)
+            );
+        };
+        data("title", &node.title);
+        if let Some(ref category) = node.category {
+            data("category", category);
+        }
+        if let Some(ref source_id) = node.source_id {
+            data("source_id", source_id);
+        }
+        data(
+            "is_canonical",
+            if node.is_canonical { "true" } else { "false" },
+        );
+        if let Some(ref canonical_id) = node.canonical_id {
+            data("canonical_id", canonical_id);
+        }
+        data("node_type", &node.node_type.to_string());
+
+        let metadata: BTreeMap<_, _> = node.metadata.iter().collect();
+        for (key, value) in metadata {
+            let text = if meta_types[key.as_str()] == AttrType::Json {
+                value.to_string()
+            } else {
+                value_text(value)
+            };
+            data(&meta_ids[key.as_str()], &text);
+        }
+        out.push_str("    </node>\n");
+    }
+
+    for (i, edge) in graph.edges.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "    <edge id=\"e{i}\" source=\"{}\" target=\"{}\">",
+            xml_escape(&edge.from),
+            xml_escape(&edge.to)
+        );
+        let _ = writeln!(
+            out,
+            "      <data key=\"relationship\">{}</data>",
+            xml_escape(edge.relationship.name())
+        );
+        let _ = writeln!(out, "      <data key=\"weight\">{}</data>", edge.weight);
+        let _ = writeln!(
+            out,
+            "      <data key=\"origin\">{}</data>",
+            origin_name(&edge.origin)
+        );
+        out.push_str("    </edge>\n");
+    }
+    out.push_str("  </graph>\n</graphml>\n");
+    out
+}
+
+// ============================================================================
+// DOT
+// ============================================================================
+
+/// Quote a string as a DOT identifier.
+fn dot_quote(s: &str) -> String {
+    let escaped = s
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{escaped}\"")
+}
+
+/// Render a graph as Graphviz DOT.
+///
+/// Nodes are labelled with their title and edges with their relationship.
+/// All other node fields, metadata, and the edge weight and origin are
+/// written as extra attributes, which Graphviz ignores but other DOT
+/// readers keep.
+pub fn to_dot(graph: &GraphData) -> String {
+    let mut out = String::from("digraph G {\n");
+    for node in sorted_nodes(graph) {
+        let mut attrs = vec![("label".to_string(), node.title.clone())];
+        if let Some(ref category) = node.category {
+            attrs.push(("category".to_string(), category.clone()));
+        }
+        if let Some(ref source_id) = node.source_id {
+            attrs.push(("source_id".to_string(), source_id.clone()));
+        }
+        attrs.push(("is_canonical".to_string(), node.is_canonical.to_string()));
+        if let Some(ref canonical_id) = node.canonical_id {
+            attrs.push(("canonical_id".to_string(), canonical_id.clone()));
+        }
+        attrs.push(("node_type".to_string(), node.node_type.to_string()));
+        let metadata: BTreeMap<_, _> = node.metadata.iter().collect();
+        for (key, value) in metadata {
+            attrs.push((key.clone(), value_text(value)));
+        }
+
+        let attrs: Vec<String> = attrs
+            .iter()
+            .map(|(k, v)| format!("{}={}", dot_quote(k), dot_quote(v)))
+            .collect();
+        let _ = writeln!(out, "  {} [{}];", dot_quote(&node.id), attrs.join(", "));
+    }
+    for edge in &graph.edges {
+        let _ = writeln!(
+            out,
+            "  {} -> {} [label={}, weight={}, origin={}];",
+            dot_quote(&edge.from),
+            dot_quote(&edge.to),
+            dot_quote(edge.relationship.name()),
+            edge.weight,
+            dot_quote(origin_name(&edge.origin))
+        );
+    }
+    out.push_str("}\n");
+    out
+}
+
+// ============================================================================
+// JSON-LD
+// ============================================================================
+
+/// Vocabulary IRI for Fabryk graph terms in JSON-LD output.
+pub const JSONLD_VOCAB: &str = "urn:fabryk:graph#";
+
+/// Render a graph as a JSON-LD document.
+///
+/// Nodes are typed `Node` resources keyed by their ID. Edges are reified
+/// as `Edge` resources with `from`/`to` references, so the relationship,
+/// weight and origin are kept. Node metadata is nested under `metadata`
+/// as a JSON literal.
+pub fn to_json_ld(graph: &GraphData) -> Result<String> {
+    use serde_json::{Value, json};
+
+    let mut items: Vec<Value> = Vec::new();
+    for node in sorted_nodes(graph) {
+        let mut obj = serde_json::Map::new();
+        obj.insert("@id".into(), json!(node.id));
+        obj.insert("@type".into(), json!("Node"));
+        obj.insert("title".into(), json!(node.title));
+        obj.insert("isCanonical".into(), json!(node.is_canonical));
+        obj.insert("nodeType".into(), json!(node.node_type.to_string()));
+        if let Some(ref category) = node.category {
+            obj.insert("category".into(), json!(category));
+        }
+        if let Some(ref source_id) = node.source_id {
+            obj.insert("sourceId".into(), json!(source_id));
+        }
+        if let Some(ref canonical_id) = node.canonical_id {
+            obj.insert("canonical".into(), json!({ "@id": canonical_id }));
+        }
+        if !node.metadata.is_empty() {
+            let metadata: BTreeMap<_, _> = node.metadata.iter().collect();
+            obj.insert("metadata".into(), json!(metadata));
+        }
+        items.push(Value::Object(obj));
+    }
+    for (i, edge) in graph.edges.iter().enumerate() {
+        items.push(json!({
+            "@id": format!("_:e{i}"),
+            "@type": "Edge",
+            "from": { "@id": edge.from },
+            "to": { "@id": edge.to },
+            "relationship": edge.relationship.name(),
+            "weight": edge.weight,
+            "origin": origin_name(&edge.origin),
+        }));
+    }
+
+    let doc = json!({
+        "@context": {
+            "@vocab": JSONLD_VOCAB,
+            "title": "http://schema.org/name",
+            "weight": { "@type": "http://www.w3.org/2001/XMLSchema#double" },
+            "metadata": { "@type": "@json" },
+        },
+        "@graph": items,
+    });
+    serde_json::to_string_pretty(&doc)
+        .map_err(|e| Error::operation(format!("Failed to serialize JSON-LD: {e}")))
+}
+
+// ============================================================================
+// CSV
+// ============================================================================
+
+/// Column headers of the node table.
+pub(crate) const CSV_NODE_HEADERS: [&str; 8] = [
+    "id",
+    "label",
+    "category",
+    "source_id",
+    "is_canonical",
+    "canonical_id",
+    "node_type",
+    "metadata",
+];
+
+/// Column headers of the edge table.
+pub(crate) const CSV_EDGE_HEADERS: [&str; 5] =
+    ["source", "target", "relationship", "weight", "origin"];
+
+fn csv_error(e: impl std::fmt::Display) -> Error {
+    Error::operation(format!("Failed to write CSV: {e}"))
+}
+
+/// Render a graph as a node table and an edge table, in that order.
+///
+/// The `id`/`label` and `source`/`target` headers match what Gephi's
+/// spreadsheet import expects. Node metadata is a JSON object column.
+pub fn to_csv(graph: &GraphData) -> Result<(String, String)> {
+    let mut nodes = csv::Writer::from_writer(Vec::new());
+    nodes.write_record(CSV_NODE_HEADERS).map_err(csv_error)?;
+    for node in sorted_nodes(graph) {
+        let metadata = if node.metadata.is_empty() {
+            String::new()
+        } else {
+            let sorted: BTreeMap<_, _> = node.metadata.iter().collect();
+            serde_json::to_string(&sorted).map_err(csv_error)?
+        };
+        nodes
+            .write_record([
+                node.id.as_str(),
+                node.title.as_str(),
+                node.category.as_deref().unwrap_or_default(),
+                node.source_id.as_deref().unwrap_or_default(),
+                if node.is_canonical { "true" } else { "false" },
+                node.canonical_id.as_deref().unwrap_or_default(),
+                &node.node_type.to_string(),
+                &metadata,
+            ])
+            .map_err(csv_error)?;
+    }
+
+    let mut edges = csv::Writer::from_writer(Vec::new());
+    edges.write_record(CSV_EDGE_HEADERS).map_err(csv_error)?;
+    for edge in &graph.edges {
+        edges
+            .write_record([
+                edge.from.as_str(),
+                edge.to.as_str(),
+                edge.relationship.name(),
+                &edge.weight.to_string(),
+                origin_name(&edge.origin),
+            ])
+            .map_err(csv_error)?;
+    }
+
+    let finish = |writer: csv::Writer<Vec<u8>>| -> Result<String> {
+        let bytes = writer.into_inner().map_err(csv_error)?;
+        String::from_utf8(bytes).map_err(csv_error)
+    };
+    Ok((finish(nodes)?, finish(edges)?))
+}
+
+// ============================================================================
+// File output
+// ============================================================================
+
+/// Write a graph to `path` in the given format.
+///
+/// For [`ExportFormat::Csv`], `path` is a directory that receives
+/// `nodes.csv` and `edges.csv`; it is created if missing.
+pub fn export_graph(graph: &GraphData, format: ExportFormat, path: impl AsRef<Path>) -> Result<()> {
+    let path = path.as_ref();
+    let write = |path: &Path, contents: &str| {
+        std::fs::write(path, contents).map_err(|e| Error::io_with_path(e, path))
+    };
+
+    match format {
+        ExportFormat::Json => crate::persistence::save_graph(graph, path, None),
+        ExportFormat::GraphMl => write(path, &to_graphml(graph)),
+        ExportFormat::Dot => write(path, &to_dot(graph)),
+        ExportFormat::JsonLd => write(path, &to_json_ld(graph)?),
+        ExportFormat::Csv => {
+            std::fs::create_dir_all(path).map_err(|e| Error::io_with_path(e, path))?;
+            let (nodes, edges) = to_csv(graph)?;
+            write(&path.join(CSV_NODES_FILE), &nodes)?;
+            write(&path.join(CSV_EDGES_FILE), &edges)
+        }
+    }
+}
+
+/// Parse a node type name as written by the exporters.
+pub(crate) fn parse_node_type(s: &str) -> NodeType {
+    match s {
+        "" | "domain" => NodeType::Domain,
+        "user_query" => NodeType::UserQuery,
+        other => NodeType::Custom(other.to_string()),
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Edge, Relationship};
+
+    fn sample_graph() -> GraphData {
+        let mut graph = GraphData::new();
+        graph.add_node(
+            Node::new("scale", "Major Scale")
+                .with_category("harmony")
+                .with_metadata("tier", "foundation")
+                .with_metadata("chapter", 3)
+                .with_metadata("aliases", serde_json::json!(["ionian"])),
+        );
+        graph.add_node(
+            Node::new("mode", "Modes & \"Scales\"")
+                .with_source("tymoczko")
+                .as_variant_of("scale")
+                .with_node_type(NodeType::Custom("theorem".into()))
+                .with_metadata("chapter", 4.5),
+        );
+        graph
+            .add_edge(Edge::new("scale", "mode", Relationship::LeadsTo).with_weight(0.25))
+            .unwrap();
+        graph
+            .add_edge(
+                Edge::new("mode", "scale", Relationship::Custom("derived_from".into()))
+                    .with_origin(EdgeOrigin::Manual),
+            )
+            .unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_format_parse_and_names() {
+        assert_eq!(
+            "GraphML".parse::<ExportFormat>().unwrap(),
+            ExportFormat::GraphMl
+        );
+        assert_eq!(
+            "json-ld".parse::<ExportFormat>().unwrap(),
+            ExportFormat::JsonLd
+        );
+        assert_eq!(
+            "graphviz".parse::<ExportFormat>().unwrap(),
+            ExportFormat::Dot
+        );
+        let err = "gexf".parse::<ExportFormat>().unwrap_err();
+        assert!(err.to_string().contains("graphml, dot, jsonld, csv, json"));
+        for format in ExportFormat::ALL {
+            assert_eq!(format.name().parse::<ExportFormat>().unwrap(), format);
+        }
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(
+            ExportFormat::from_path(Path::new("g.graphml")),
+            Some(ExportFormat::GraphMl)
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("g.gv")),
+            Some(ExportFormat::Dot)
+        );
+        assert_eq!(ExportFormat::from_path(Path::new("g.bin")), None);
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(ExportFormat::from_path(dir.path()), Some(ExportFormat::Csv));
+    }
+
+    #[test]
+    fn test_to_graphml() {
+        let xml = to_graphml(&sample_graph());
+        assert!(xml.contains("attr.name=\"relationship\""));
+        assert!(xml.contains("attr.name=\"chapter\" attr.type=\"double\""));
+        assert!(xml.contains("attr.name=\"tier\" attr.type=\"string\""));
+        assert!(xml.contains("<desc>json</desc>"));
+        assert!(xml.contains("<data key=\"title\">Modes &amp; &quot;Scales&quot;</data>"));
+        assert!(xml.contains("<edge id=\"e0\" source=\"scale\" target=\"mode\">"));
+        assert!(xml.contains("<data key=\"weight\">0.25</data>"));
+        assert!(xml.contains("<data key=\"origin\">Manual</data>"));
+        assert!(xml.contains("<data key=\"relationship\">derived_from</data>"));
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let dot = to_dot(&sample_graph());
+        assert!(dot.starts_with("digraph G {"));
+        assert!(dot.contains("\"mode\" [\"label\"=\"Modes & \\\"Scales\\\"\""));
+        assert!(dot.contains("\"node_type\"=\"theorem\""));
+        assert!(dot.contains(
+            "\"scale\" -> \"mode\" [label=\"leads_to\", weight=0.25, origin=\"Frontmatter\"];"
+        ));
+    }
+
+    #[test]
+    fn test_to_json_ld() {
+        let doc: serde_json::Value =
+            serde_json::from_str(&to_json_ld(&sample_graph()).unwrap()).unwrap();
+        assert_eq!(doc["@context"]["@vocab"], JSONLD_VOCAB);
+        let items = doc["@graph"].as_array().unwrap();
+        assert_eq!(items.len(), 4);
+        let mode = items.iter().find(|i| i["@id"] == "mode").unwrap();
+        assert_eq!(mode["canonical"]["@id"], "scale");
+        assert_eq!(mode["isCanonical"], false);
+        let edge = items.iter().find(|i| i["@type"] == "Edge").unwrap();
+        assert_eq!(edge["from"]["@id"], "scale");
+        assert_eq!(edge["weight"], 0.25);
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let (nodes, edges) = to_csv(&sample_graph()).unwrap();
+        let mut lines = nodes.lines();
+        assert_eq!(
+            lines.next().unwrap(),
+            "id,label,category,source_id,is_canonical,canonical_id,node_type,metadata"
+        );
+        assert!(nodes.contains("\"Modes & \"\"Scales\"\"\""));
+        assert!(nodes.contains("\"{\"\"aliases\"\":[\"\"ionian\"\"],\"\"chapter\"\":3,\"\"tier\"\":\"\"foundation\"\"}\""));
+        assert_eq!(edges.lines().count(), 3);
+        assert!(edges.contains("scale,mode,leads_to,0.25,Frontmatter"));
+    }
+
+    #[test]
+    fn test_export_graph_csv_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("export");
+        export_graph(&sample_graph(), ExportFormat::Csv, &out).unwrap();
+        assert!(out.join(CSV_NODES_FILE).exists());
+        assert!(out.join(CSV_EDGES_FILE).exists());
+    }
+}
@@ -0,0 +1,732 @@
+//! Graph import from GraphML and CSV, and merging into an existing graph.
+//!
+//! Importers read files written by [`crate::export`] losslessly, and are
+//! lenient with files from other tools (Gephi, yEd, spreadsheets):
+//!
+//! - `label` is accepted for a node's title and an edge's relationship
+//! - unknown node attributes and CSV columns become node metadata
+//! - edges without a weight get the relationship's default weight, and
+//!   edges without an origin are treated as [`EdgeOrigin::Manual`]
+//! - nodes referenced only by edges are created with their ID as title
+//!
+//! [`merge_graph`] adds an imported graph to an existing one.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use fabryk_graph::{ExportFormat, import_graph, merge_graph};
+//!
+//! let curated = import_graph("curated.graphml", ExportFormat::GraphMl)?;
+//! let stats = merge_graph(&mut graph, &curated);
+//! println!("{} edges added", stats.edges_added);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use fabryk_core::{Error, Result};
+
+use crate::export::{
+    CSV_EDGES_FILE, CSV_NODES_FILE, ExportFormat, GRAPHML_JSON_DESC, parse_node_type,
+};
+use crate::{Edge, EdgeOrigin, GraphData, Node, Relationship};
+
+// ============================================================================
+// Graph assembly
+// ============================================================================
+
+/// Typed value of an imported attribute.
+fn typed_value(text: &str, ty: &str, json: bool) -> serde_json::Value {
+    use serde_json::Value;
+
+    if json {
+        return serde_json::from_str(text).unwrap_or_else(|_| Value::String(text.to_string()));
+    }
+    match ty {
+        "boolean" => text
+            .trim()
+            .parse::<bool>()
+            .map(Value::Bool)
+            .unwrap_or_else(|_| Value::String(text.to_string())),
+        "int" | "long" => text
+            .trim()
+            .parse::<i64>()
+            .map(Value::from)
+            .unwrap_or_else(|_| Value::String(text.to_string())),
+        "float" | "double" => text
+            .trim()
+            .parse::<f64>()
+            .map(Value::from)
+            .unwrap_or_else(|_| Value::String(text.to_string())),
+        _ => Value::String(text.to_string()),
+    }
+}
+
+/// Parse an edge origin from its serialized name (any case).
+fn parse_origin(s: &str) -> Result<EdgeOrigin> {
+    serde_json::from_value(serde_json::Value::String(s.to_string()))
+        .or_else(|_| {
+            let lowered = s.to_lowercase();
+            serde_json::from_value(serde_json::Value::String(lowered))
+        })
+        .map_err(|_| Error::parse(format!("Unknown edge origin '{s}'")))
+}
+
+/// Parse an edge weight, falling back to the relationship default.
+fn parse_weight(s: Option<&str>, relationship: &Relationship) -> Result<f32> {
+    match s.map(str::trim).filter(|s| !s.is_empty()) {
+        Some(s) => s
+            .parse()
+            .map_err(|_| Error::parse(format!("Invalid edge weight '{s}'"))),
+        None => Ok(relationship.default_weight()),
+    }
+}
+
+/// Set a node field from a named attribute, or store it as metadata.
+fn set_node_attr(node: &mut Node, name: &str, value: serde_json::Value) {
+    let text = || match &value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+    let optional = |s: String| if s.is_empty() { None } else { Some(s) };
+    match name {
+        "title" | "label" => node.title = text(),
+        "category" => node.category = optional(text()),
+        "source_id" => node.source_id = optional(text()),
+        "is_canonical" => {
+            node.is_canonical = value.as_bool().unwrap_or_else(|| text() != "false");
+        }
+        "canonical_id" => node.canonical_id = optional(text()),
+        "node_type" => node.node_type = parse_node_type(&text()),
+        _ => {
+            node.metadata.insert(name.to_string(), value);
+        }
+    }
+}
+
+/// Build a graph from imported nodes and edges, creating missing endpoints.
+fn assemble(nodes: Vec<Node>, edges: Vec<Edge>) -> Result<GraphData> {
+    let mut graph = GraphData::new();
+    for node in nodes {
+        graph.add_node(node);
+    }
+    for edge in edges {
+        for id in [&edge.from, &edge.to] {
+            if !graph.contains_node(id) {
+                graph.add_node(Node::new(id.clone(), id.clone()));
+            }
+        }
+        graph.add_edge(edge)?;
+    }
+    Ok(graph)
+}
+
+// ============================================================================
+// GraphML
+// ============================================================================
+
+/// A GraphML `<key>` declaration.
+#[derive(Default)]
+struct KeyDef {
+    /// `node`, `edge`, `all`, ...
+    domain: String,
+    name: String,
+    ty: String,
+    json: bool,
+    default: Option<String>,
+    /// yEd graphics payloads, which carry no graph data.
+    ignored: bool,
+}
+
+impl KeyDef {
+    fn applies_to(&self, domain: &str) -> bool {
+        !self.ignored && (self.domain == domain || self.domain == "all" || self.domain.is_empty())
+    }
+}
+
+/// Concatenated text of an element and its descendants.
+fn element_text(element: roxmltree::Node<'_, '_>) -> String {
+    element
+        .descendants()
+        .filter(|n| n.is_text())
+        .filter_map(|n| n.text())
+        .collect()
+}
+
+/// Child elements with the given local name.
+fn child_elements<'a, 'input>(
+    element: roxmltree::Node<'a, 'input>,
+    name: &'static str,
+) -> impl Iterator<Item = roxmltree::Node<'a, 'input>> {
+    element
+        .children()
+        .filter(move |n| n.is_element() && n.tag_name().name() == name)
+}
+
+/// Parse a `<key>` declaration.
+fn parse_key(element: roxmltree::Node<'_, '_>) -> (String, KeyDef) {
+    let id = element.attribute("id").unwrap_or_default().to_string();
+    let def = KeyDef {
+        domain: element.attribute("for").unwrap_or_default().to_string(),
+        name: element.attribute("attr.name").unwrap_or(&id).to_string(),
+        ty: element
+            .attribute("attr.type")
+            .unwrap_or_default()
+            .to_string(),
+        json: child_elements(element, "desc").any(|d| element_text(d).trim() == GRAPHML_JSON_DESC),
+        default: child_elements(element, "default").next().map(element_text),
+        ignored: element.attribute("yfiles.type").is_some(),
+    };
+    (id, def)
+}
+
+/// Collect the nodes and edges of a `<graph>`, flattening graphs nested
+/// in its nodes.
+fn collect_graph(
+    graph: roxmltree::Node<'_, '_>,
+    keys: &HashMap<String, KeyDef>,
+    nodes: &mut Vec<Node>,
+    edges: &mut Vec<Edge>,
+) -> Result<()> {
+    for element in graph.children().filter(|n| n.is_element()) {
+        match element.tag_name().name() {
+            "node" => {
+                let id = element
+                    .attribute("id")
+                    .ok_or_else(|| Error::parse("GraphML <node> without an id"))?;
+                let mut node = Node::new(id, id);
+                for data in child_elements(element, "data") {
+                    let key = data.attribute("key").unwrap_or_default();
+                    if let Some(def) = keys.get(key).filter(|d| d.applies_to("node")) {
+                        set_node_attr(
+                            &mut node,
+                            &def.name,
+                            typed_value(&element_text(data), &def.ty, def.json),
+                        );
+                    }
+                }
+                apply_node_defaults(&mut node, keys);
+                nodes.push(node);
+                for nested in child_elements(element, "graph") {
+                    collect_graph(nested, keys, nodes, edges)?;
+                }
+            }
+            "edge" => {
+                let endpoint = |attr: &str| {
+                    element
+                        .attribute(attr)
+                        .map(str::to_string)
+                        .ok_or_else(|| Error::parse(format!("GraphML <edge> without a {attr}")))
+                };
+                let (from, to) = (endpoint("source")?, endpoint("target")?);
+                let data = child_elements(element, "data")
+                    .map(|d| {
+                        (
+                            d.attribute("key").unwrap_or_default().to_string(),
+                            element_text(d),
+                        )
+                    })
+                    .collect();
+                edges.push(finish_edge(from, to, &data, keys)?);
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Parse a GraphML document into a graph.
+///
+/// Nested graphs are flattened. Data whose key is not declared is
+/// ignored, as are yEd graphics keys. Element names are matched by local
+/// name, so namespaced GraphML parses the same.
+pub fn from_graphml(xml: &str) -> Result<GraphData> {
+    let options = roxmltree::ParsingOptions {
+        allow_dtd: true,
+        ..Default::default()
+    };
+    let doc = roxmltree::Document::parse_with_options(xml, options)
+        .map_err(|e| Error::parse(format!("Invalid GraphML: {e}")))?;
+    let root = doc.root_element();
+    if root.tag_name().name() != "graphml" {
+        return Err(Error::parse("Not a GraphML document: missing <graphml>"));
+    }
+
+    let keys: HashMap<String, KeyDef> = child_elements(root, "key").map(parse_key).collect();
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+    for graph in child_elements(root, "graph") {
+        collect_graph(graph, &keys, &mut nodes, &mut edges)?;
+    }
+    assemble(nodes, edges)
+}
+
+/// Fill in declared node-key defaults the node has no data for.
+fn apply_node_defaults(node: &mut Node, keys: &HashMap<String, KeyDef>) {
+    for def in keys.values().filter(|d| d.applies_to("node")) {
+        let Some(ref default) = def.default else {
+            continue;
+        };
+        if node.metadata.contains_key(&def.name) {
+            continue;
+        }
+        let is_field = matches!(
+            def.name.as_str(),
+            "title"
+                | "label"
+                | "category"
+                | "source_id"
+                | "is_canonical"
+                | "canonical_id"
+                | "node_type"
+        );
+        if !is_field {
+            set_node_attr(node, &def.name, typed_value(default, &def.ty, def.json));
+        }
+    }
+}
+
+/// Turn an edge's `<data>` into an [`Edge`], by key name.
+fn finish_edge(
+    from: String,
+    to: String,
+    data: &HashMap<String, String>,
+    keys: &HashMap<String, KeyDef>,
+) -> Result<Edge> {
+    let mut named: HashMap<&str, &str> = HashMap::new();
+    for def in keys.values().filter(|d| d.applies_to("edge")) {
+        if let Some(ref default) = def.default {
+            named.insert(def.name.as_str(), default.as_str());
+        }
+    }
+    for (key, text) in data {
+        if let Some(def) = keys.get(key).filter(|d| d.applies_to("edge")) {
+            named.insert(def.name.as_str(), text.as_str());
+        }
+    }
+
+    edge_from_fields(
+        from,
+        to,
+        named
+            .get("relationship")
+            .or_else(|| named.get("label"))
+            .copied(),
+        named.get("weight").copied(),
+        named.get("origin").copied(),
+    )
+}
+
+/// Build an edge from its textual fields, applying import defaults.
+fn edge_from_fields(
+    from: String,
+    to: String,
+    relationship: Option<&str>,
+    weight: Option<&str>,
+    origin: Option<&str>,
+) -> Result<Edge> {
+    let relationship = relationship
+        .map(str::trim)
+        .filter(|r| !r.is_empty())
+        .map(|r| r.parse::<Relationship>().unwrap_or_else(|e| match e {}))
+        .unwrap_or_default();
+    let weight = parse_weight(weight, &relationship)?;
+    let origin = match origin.map(str::trim).filter(|o| !o.is_empty()) {
+        Some(o) => parse_origin(o)?,
+        None => EdgeOrigin::Manual,
+    };
+    Ok(Edge {
+        from,
+        to,
+        relationship,
+        weight,
+        origin,
+    })
+}
+
+// ============================================================================
+// CSV
+// ============================================================================
+
+fn csv_error(e: impl std::fmt::Display) -> Error {
+    Error::parse(format!("Failed to read CSV: {e}"))
+}
+
+/// Read a CSV table into rows keyed by lower-cased header.
+fn read_table(csv_text: &str) -> Result<Vec<HashMap<String, String>>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::Headers)
+        .from_reader(csv_text.as_bytes());
+    let headers: Vec<String> = reader
+        .headers()
+        .map_err(csv_error)?
+        .iter()
+        .map(|h| h.to_lowercase())
+        .collect();
+    reader
+        .records()
+        .map(|record| {
+            let record = record.map_err(csv_error)?;
+            Ok(headers
+                .iter()
+                .cloned()
+                .zip(record.iter().map(String::from))
+                .collect())
+        })
+        .collect()
+}
+
+/// Parse a node table and an edge table into a graph.
+///
+/// The node table is optional; without it every node is created from the
+/// edges. Node columns `id`, `label`/`title`, `category`, `source_id`,
+/// `is_canonical`, `canonical_id`, `node_type` and `metadata` (a JSON
+/// object) map to node fields, and any other non-empty column becomes a
+/// string metadata entry. Edge columns are `source`/`from`,
+/// `target`/`to`, `relationship`/`label`, `weight` and `origin`.
+pub fn from_csv(nodes_csv: Option<&str>, edges_csv: &str) -> Result<GraphData> {
+    let mut nodes = Vec::new();
+    if let Some(nodes_csv) = nodes_csv {
+        for (i, row) in read_table(nodes_csv)?.into_iter().enumerate() {
+            let id = row
+                .get("id")
+                .filter(|id| !id.is_empty())
+                .cloned()
+                .ok_or_else(|| Error::parse(format!("Node row {} has no id", i + 1)))?;
+            let mut node = Node::new(id.clone(), id);
+            for (column, value) in row {
+                if value.is_empty() || column == "id" {
+                    continue;
+                }
+                if column == "metadata" {
+                    let metadata: HashMap<String, serde_json::Value> = serde_json::from_str(&value)
+                        .map_err(|e| {
+                            Error::parse(format!("Invalid metadata for node '{}': {e}", node.id))
+                        })?;
+                    node.metadata.extend(metadata);
+                } else {
+                    let value = match column.as_str() {
+                        "is_canonical" => typed_value(&value, "boolean", false),
+                        _ => serde_json::Value::String(value),
+                    };
+                    set_node_attr(&mut node, &column, value);
+                }
+            }
+            nodes.push(node);
+        }
+    }
+
+    let mut edges = Vec::new();
+    for (i, row) in read_table(edges_csv)?.into_iter().enumerate() {
+        let field = |names: &[&str]| {
+            names
+                .iter()
+                .find_map(|n| row.get(*n))
+                .map(String::as_str)
+                .filter(|v| !v.is_empty())
+        };
+        let endpoint = |names: &[&str]| {
+            field(names)
+                .map(String::from)
+                .ok_or_else(|| Error::parse(format!("Edge row {} has no {}", i + 1, names[0])))
+        };
+        edges.push(edge_from_fields(
+            endpoint(&["source", "from"])?,
+            endpoint(&["target", "to"])?,
+            field(&["relationship", "label"]),
+            field(&["weight"]),
+            field(&["origin"]),
+        )?);
+    }
+
+    assemble(nodes, edges)
+}
+
+// ============================================================================
+// File input and merging
+// ============================================================================
+
+/// Read a graph from `path` in the given format.
+///
+/// For [`ExportFormat::Csv`], `path` is either a directory holding
+/// `edges.csv` (and optionally `nodes.csv`) or a single edge-table file.
+/// DOT and JSON-LD are export-only.
+pub fn import_graph(path: impl AsRef<Path>, format: ExportFormat) -> Result<GraphData> {
+    let path = path.as_ref();
+    let read =
+        |path: &Path| std::fs::read_to_string(path).map_err(|e| Error::io_with_path(e, path));
+
+    match format {
+        ExportFormat::Json => crate::persistence::load_graph(path),
+        ExportFormat::GraphMl => from_graphml(&read(path)?),
+        ExportFormat::Csv if path.is_dir() => {
+            let nodes_path = path.join(CSV_NODES_FILE);
+            let nodes = if nodes_path.exists() {
+                Some(read(&nodes_path)?)
+            } else {
+                None
+            };
+            from_csv(nodes.as_deref(), &read(&path.join(CSV_EDGES_FILE))?)
+        }
+        ExportFormat::Csv => from_csv(None, &read(path)?),
+        ExportFormat::Dot | ExportFormat::JsonLd => Err(Error::config(format!(
+            "Importing {format} is not supported (supported: graphml, csv, json)"
+        ))),
+    }
+}
+
+/// Counts from a [`merge_graph`] call.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MergeStats {
+    /// Nodes added to the target graph.
+    pub nodes_added: usize,
+    /// Imported nodes already present in the target (left unchanged).
+    pub nodes_existing: usize,
+    /// Edges added to the target graph.
+    pub edges_added: usize,
+    /// Imported edges already present (same endpoints and relationship).
+    pub edges_duplicate: usize,
+}
+
+/// Merge `source` into `target`.
+///
+/// Nodes are added when their ID is new; existing nodes keep their data.
+/// Edges are added unless the target already has an edge with the same
+/// endpoints and relationship.
+pub fn merge_graph(target: &mut GraphData, source: &GraphData) -> MergeStats {
+    let mut stats = MergeStats::default();
+
+    let mut ids: Vec<&String> = source.nodes.keys().collect();
+    ids.sort();
+    for id in ids {
+        if target.contains_node(id) {
+            stats.nodes_existing += 1;
+        } else {
+            target.add_node(source.nodes[id].clone());
+            stats.nodes_added += 1;
+        }
+    }
+
+    let mut seen: HashSet<(String, String, String)> = target
+        .edges
+        .iter()
+        .map(|e| {
+            (
+                e.from.clone(),
+                e.to.clone(),
+                e.relationship.name().to_string(),
+            )
+        })
+        .collect();
+    for edge in &source.edges {
+        let key = (
+            edge.from.clone(),
+            edge.to.clone(),
+            edge.relationship.name().to_string(),
+        );
+        if seen.insert(key) && target.add_edge(edge.clone()).is_ok() {
+            stats.edges_added += 1;
+        } else {
+            stats.edges_duplicate += 1;
+        }
+    }
+
+    stats
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NodeType;
+    use crate::export::{export_graph, to_csv, to_graphml};
+
+    fn sample_graph() -> GraphData {
+        let mut graph = GraphData::new();
+        graph.add_node(
+            Node::new("scale", "Major <Scale>")
+                .with_category("harmony")
+                .with_metadata("tier", "foundation")
+                .with_metadata("chapter", 3)
+                .with_metadata("draft", true)
+                .with_metadata("aliases", serde_json::json!(["ionian"])),
+        );
+        graph.add_node(
+            Node::new("mode", "Modes & Scales")
+                .with_source("tymoczko")
+                .as_variant_of("scale")
+                .with_node_type(NodeType::Custom("theorem".into()))
+                .with_metadata("weight_class", 4.5),
+        );
+        graph
+            .add_edge(Edge::new("scale", "mode", Relationship::LeadsTo).with_weight(0.25))
+            .unwrap();
+        graph
+            .add_edge(
+                Edge::new("mode", "scale", Relationship::Custom("derived_from".into()))
+                    .with_origin(EdgeOrigin::Inferred),
+            )
+            .unwrap();
+        graph
+    }
+
+    fn assert_same_graph(a: &GraphData, b: &GraphData) {
+        assert_eq!(a.node_count(), b.node_count());
+        for (id, node) in &a.nodes {
+            assert_eq!(Some(node), b.get_node(id), "node {id}");
+        }
+        assert_eq!(a.edges, b.edges);
+    }
+
+    #[test]
+    fn test_graphml_round_trip() {
+        let graph = sample_graph();
+        let imported = from_graphml(&to_graphml(&graph)).unwrap();
+        assert_same_graph(&graph, &imported);
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let graph = sample_graph();
+        let (nodes, edges) = to_csv(&graph).unwrap();
+        let imported = from_csv(Some(&nodes), &edges).unwrap();
+        assert_same_graph(&graph, &imported);
+    }
+
+    #[test]
+    fn test_graphml_from_other_tools() {
+        // yEd-style document: prefixed graphics data, labels, no weights
+        let xml = r#"<?xml version="1.0"?>
+<!-- exported by an editor -->
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns" xmlns:y="http://www.yworks.com/xml/graphml">
+  <key id="d0" for="node" yfiles.type="nodegraphics"/>
+  <key id="d1" for="node" attr.name="label" attr.type="string"/>
+  <key id="d2" for="node" attr.name="difficulty" attr.type="int">
+    <default>1</default>
+  </key>
+  <key id="d3" for="edge" attr.name="label" attr.type="string"/>
+  <graph edgedefault="directed">
+    <node id="a">
+      <data key="d0"><y:ShapeNode><y:NodeLabel>ignored</y:NodeLabel></y:ShapeNode></data>
+      <data key="d1">Alpha &#x26; Co</data>
+      <data key="d2">3</data>
+    </node>
+    <node id="b"><data key="d1"><![CDATA[Beta <b>]]></data></node>
+    <edge source="a" target="b"><data key="d3">prerequisite</data></edge>
+    <edge source="b" target="c"/>
+  </graph>
+</graphml>"#;
+        let graph = from_graphml(xml).unwrap();
+        assert_eq!(graph.node_count(), 3);
+        let a = graph.get_node("a").unwrap();
+        assert_eq!(a.title, "Alpha & Co");
+        assert_eq!(a.metadata_u64("difficulty"), Some(3));
+        let b = graph.get_node("b").unwrap();
+        assert_eq!(b.title, "Beta <b>");
+        assert_eq!(b.metadata_u64("difficulty"), Some(1));
+        assert_eq!(graph.get_node("c").unwrap().title, "c");
+
+        let edge = &graph.edges[0];
+        assert_eq!(edge.relationship, Relationship::Prerequisite);
+        assert_eq!(edge.weight, Relationship::Prerequisite.default_weight());
+        assert_eq!(edge.origin, EdgeOrigin::Manual);
+        assert_eq!(graph.edges[1].relationship, Relationship::RelatesTo);
+    }
+
+    #[test]
+    fn test_graphml_with_markup_in_values() {
+        let xml = r#"<?xml version="1.0"?>
+<!DOCTYPE graphml [
+  <!ENTITY tool "Editor > 2.0">
+]>
+<graphml>
+  <key id="label" for="node" attr.name="label" attr.type="string"/>
+  <key id="note" for="node" attr.name="note" attr.type="string"/>
+  <graph>
+    <node id="a>b"><data key="label">&tool;</data></node>
+    <node id="c"><data key="note"><![CDATA[x > y]]> &amp; z</data></node>
+    <edge source="a>b" target="c"/>
+  </graph>
+</graphml>"#;
+        let graph = from_graphml(xml).unwrap();
+        assert_eq!(graph.node_count(), 2);
+        assert_eq!(graph.get_node("a>b").unwrap().title, "Editor > 2.0");
+        assert_eq!(
+            graph.get_node("c").unwrap().metadata["note"],
+            serde_json::json!("x > y & z")
+        );
+        assert_eq!(graph.edges[0].from, "a>b");
+    }
+
+    #[test]
+    fn test_graphml_rejects_other_xml() {
+        assert!(from_graphml("<gexf><nodes/></gexf>").is_err());
+        assert!(from_graphml("<graphml><graph><node/></graph></graphml>").is_err());
+    }
+
+    #[test]
+    fn test_csv_from_spreadsheet() {
+        let nodes = "Id,Label,Difficulty\na,Alpha,hard\nb,Beta,\n";
+        let edges = "Source,Target,Type,Weight\na,b,Directed,2.5\nb,c,Directed,\n";
+        let graph = from_csv(Some(nodes), edges).unwrap();
+        assert_eq!(graph.node_count(), 3);
+        let a = graph.get_node("a").unwrap();
+        assert_eq!(a.title, "Alpha");
+        assert_eq!(a.metadata_str("difficulty"), Some("hard"));
+        assert!(graph.get_node("b").unwrap().metadata.is_empty());
+        assert_eq!(graph.edges[0].weight, 2.5);
+        assert_eq!(graph.edges[0].origin, EdgeOrigin::Manual);
+
+        let err = from_csv(None, "source,target,origin\na,b,Guessed\n").unwrap_err();
+        assert!(err.to_string().contains("Guessed"));
+    }
+
+    #[test]
+    fn test_import_graph_formats() {
+        let dir = tempfile::tempdir().unwrap();
+        let graph = sample_graph();
+
+        let csv_dir = dir.path().join("csv");
+        export_graph(&graph, ExportFormat::Csv, &csv_dir).unwrap();
+        assert_same_graph(&graph, &import_graph(&csv_dir, ExportFormat::Csv).unwrap());
+
+        let edges_only = import_graph(csv_dir.join(CSV_EDGES_FILE), ExportFormat::Csv).unwrap();
+        assert_eq!(edges_only.edge_count(), 2);
+
+        let dot = dir.path().join("g.dot");
+        export_graph(&graph, ExportFormat::Dot, &dot).unwrap();
+        assert!(import_graph(&dot, ExportFormat::Dot).is_err());
+    }
+
+    #[test]
+    fn test_merge_graph() {
+        let mut target = GraphData::new();
+        target.add_node(Node::new("scale", "Existing Title"));
+        target.add_node(Node::new("mode", "Mode"));
+        target
+            .add_edge(Edge::new("scale", "mode", Relationship::LeadsTo))
+            .unwrap();
+
+        let mut source = sample_graph();
+        source.add_node(Node::new("chord", "Chord"));
+        source
+            .add_edge(Edge::new("chord", "scale", Relationship::Extends))
+            .unwrap();
+
+        let stats = merge_graph(&mut target, &source);
+        assert_eq!(
+            stats,
+            MergeStats {
+                nodes_added: 1,
+                nodes_existing: 2,
+                edges_added: 2,
+                edges_duplicate: 1,
+            }
+        );
+        assert_eq!(target.get_node("scale").unwrap().title, "Existing Title");
+        assert_eq!(target.edge_count(), 3);
+    }
+}
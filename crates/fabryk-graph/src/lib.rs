@@ -20,12 +20,19 @@
 
 pub mod algorithms;
 pub mod builder;
+pub mod centrality;
+pub mod clustering;
 pub mod concept_card_extractor;
+pub mod export;
 pub mod extractor;
+pub mod import;
+pub mod pattern;
 pub mod persistence;
 pub mod query;
+pub mod semantic;
 pub mod stats;
 pub mod types;
+pub mod updater;
 pub mod validation;
 
 // Re-exports — algorithms
@@ -39,6 +46,23 @@ pub use algorithms::{
 // Re-exports — builder
 pub use builder::{BuildError, BuildStats, ErrorHandling, GraphBuilder, ManualEdge};
 
+// Re-exports — centrality
+pub use centrality::{
+    CentralityAlgorithm, CentralityOptions, NodeCentrality, articulation_points, bridge_edges,
+    compute_centrality,
+};
+
+// Re-exports — clustering
+pub use clustering::{
+    Cluster, ClusterAlgorithm, ClusterOptions, ClusteringResult, detect_clusters,
+};
+
+// Re-exports — pattern queries
+pub use pattern::{PatternQuery, PatternResult, QueryLimits, execute_pattern};
+
+// Re-exports — semantic edge inference
+pub use semantic::{SemanticEdgeInferrer, SemanticEdgeOptions, SemanticReport, SuggestedEdge};
+
 // Re-exports — concept card extractor
 pub use concept_card_extractor::{
     ConceptCardEdgeData, ConceptCardGraphExtractor, ConceptCardNodeData,
@@ -47,6 +71,10 @@ pub use concept_card_extractor::{
 // Re-exports — extractor
 pub use extractor::GraphExtractor;
 
+// Re-exports — export / import
+pub use export::{ExportFormat, export_graph, to_csv, to_dot, to_graphml, to_json_ld};
+pub use import::{MergeStats, from_csv, from_graphml, import_graph, merge_graph};
+
 // Re-exports — persistence
 pub use persistence::{
     GraphMetadata, SerializableGraph, is_cache_fresh, load_graph, load_graph_from_str, save_graph,
@@ -65,6 +93,9 @@ pub use stats::{DegreeDirection, GraphStats, compute_stats, quick_summary, top_n
 // Re-exports — types
 pub use types::{Edge, EdgeOrigin, GraphData, LoadedGraph, Node, NodeType, Relationship};
 
+// Re-exports — updater
+pub use updater::GraphUpdater;
+
 // Re-exports — validation
 pub use validation::{ValidationIssue, ValidationResult, is_valid, validate_graph};
 
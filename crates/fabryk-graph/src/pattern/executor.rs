@@ -0,0 +1,625 @@
+//! Backtracking matcher and projection for parsed pattern queries.
+
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+use fabryk_core::{Error, Result};
+use petgraph::Direction;
+use petgraph::graph::{EdgeIndex, NodeIndex};
+use petgraph::visit::EdgeRef;
+use serde_json::{Value, json};
+
+use super::parser::{
+    Ast, CmpOp, Expr, NodePattern, OrderKey, Projection, RelDirection, RelPattern,
+};
+use super::{PatternResult, QueryLimits};
+use crate::export::origin_name;
+use crate::{GraphData, Node, NodeSummary};
+
+/// A value bound to a pattern variable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Bound {
+    Node(NodeIndex),
+    Edge(EdgeIndex),
+}
+
+type Bindings = HashMap<String, Bound>;
+
+/// A matched row before aggregation, ordering and paging.
+struct Row {
+    values: Vec<Value>,
+    /// Values of `OrderKey::Expr` items, evaluated at match time.
+    order_values: Vec<Value>,
+}
+
+pub(crate) struct Executor<'a> {
+    graph: &'a GraphData,
+    ast: &'a Ast,
+    limits: &'a QueryLimits,
+    steps: usize,
+    rows: Vec<Row>,
+    /// Stop matching once this many rows exist (`None` to match all).
+    stop_at: Option<usize>,
+    /// Edges used by the current match; a match never reuses an edge.
+    used_edges: Vec<EdgeIndex>,
+}
+
+impl<'a> Executor<'a> {
+    pub(crate) fn new(graph: &'a GraphData, ast: &'a Ast, limits: &'a QueryLimits) -> Self {
+        let aggregating = ast
+            .returns
+            .iter()
+            .any(|item| matches!(item.projection, Projection::Count(_)));
+        // Without sorting, deduplication or counting, the first rows are final
+        let stop_at = (ast.order_by.is_empty() && !ast.distinct && !aggregating).then(|| {
+            ast.skip
+                + match ast.limit {
+                    Some(limit) if limit <= limits.max_rows => limit,
+                    _ => limits.max_rows + 1,
+                }
+        });
+        Self {
+            graph,
+            ast,
+            limits,
+            steps: 0,
+            rows: Vec::new(),
+            stop_at,
+            used_edges: Vec::new(),
+        }
+    }
+
+    pub(crate) fn run(mut self) -> Result<PatternResult> {
+        let mut bindings = Bindings::new();
+        self.match_pattern(0, &mut bindings)?;
+
+        let columns: Vec<String> = self.ast.returns.iter().map(|r| r.alias.clone()).collect();
+        let steps = self.steps;
+        let mut rows = self.aggregate();
+
+        if self.ast.distinct {
+            let mut seen = HashSet::new();
+            rows.retain(|row| seen.insert(Value::Array(row.values.clone()).to_string()));
+        }
+        if !self.ast.order_by.is_empty() {
+            rows.sort_by(|a, b| self.compare_rows(a, b));
+        }
+
+        let limit = self.ast.limit.unwrap_or(usize::MAX);
+        let mut rows: Vec<Vec<Value>> = rows
+            .into_iter()
+            .skip(self.ast.skip)
+            .take(limit)
+            .map(|row| row.values)
+            .collect();
+        let truncated = rows.len() > self.limits.max_rows;
+        rows.truncate(self.limits.max_rows);
+
+        Ok(PatternResult {
+            columns,
+            rows,
+            truncated,
+            steps,
+        })
+    }
+
+    fn step(&mut self) -> Result<()> {
+        self.steps += 1;
+        if self.steps > self.limits.max_steps {
+            return Err(Error::operation(format!(
+                "Query exceeded the cost limit of {} steps; add labels, \
+                 property filters or tighter hop ranges",
+                self.limits.max_steps
+            )));
+        }
+        Ok(())
+    }
+
+    fn done(&self) -> bool {
+        self.stop_at.is_some_and(|stop| self.rows.len() >= stop)
+    }
+
+    // -- Matching ------------------------------------------------------------
+
+    fn match_pattern(&mut self, index: usize, bindings: &mut Bindings) -> Result<()> {
+        let ast = self.ast;
+        let Some(pattern) = ast.patterns.get(index) else {
+            return self.emit(bindings);
+        };
+
+        for start in self.start_candidates(&pattern.start, bindings)? {
+            if self.done() {
+                break;
+            }
+            self.step()?;
+            if !self.node_matches(&pattern.start, start, bindings) {
+                continue;
+            }
+            let bound = bind(bindings, &pattern.start.var, Bound::Node(start));
+            self.match_hops(index, 0, start, bindings)?;
+            unbind(bindings, &pattern.start.var, bound);
+        }
+        Ok(())
+    }
+
+    fn match_hops(
+        &mut self,
+        index: usize,
+        hop: usize,
+        current: NodeIndex,
+        bindings: &mut Bindings,
+    ) -> Result<()> {
+        let ast = self.ast;
+        let pattern = &ast.patterns[index];
+        let Some((rel, node)) = pattern.hops.get(hop) else {
+            return self.match_pattern(index + 1, bindings);
+        };
+
+        for (end, edges) in self.expand(current, rel)? {
+            if self.done() {
+                break;
+            }
+            if !self.node_matches(node, end, bindings) {
+                continue;
+            }
+            if let (Some(var), [edge]) = (&rel.var, edges.as_slice())
+                && bindings
+                    .get(var)
+                    .is_some_and(|bound| *bound != Bound::Edge(*edge))
+            {
+                continue;
+            }
+
+            let rel_bound = match edges.as_slice() {
+                [edge] => bind(bindings, &rel.var, Bound::Edge(*edge)),
+                _ => false,
+            };
+            let node_bound = bind(bindings, &node.var, Bound::Node(end));
+            let used = self.used_edges.len();
+            self.used_edges.extend(&edges);
+
+            self.match_hops(index, hop + 1, end, bindings)?;
+
+            self.used_edges.truncate(used);
+            unbind(bindings, &node.var, node_bound);
+            unbind(bindings, &rel.var, rel_bound);
+        }
+        Ok(())
+    }
+
+    /// Nodes that could start a pattern.
+    fn start_candidates(
+        &self,
+        pattern: &NodePattern,
+        bindings: &Bindings,
+    ) -> Result<Vec<NodeIndex>> {
+        if let Some(var) = &pattern.var
+            && let Some(bound) = bindings.get(var)
+        {
+            return match bound {
+                Bound::Node(idx) => Ok(vec![*idx]),
+                Bound::Edge(_) => Err(Error::parse(format!(
+                    "Variable '{var}' is a relationship, not a node"
+                ))),
+            };
+        }
+        let id = pattern
+            .props
+            .iter()
+            .find_map(|(key, value)| (key == "id").then_some(value).and_then(Value::as_str));
+        Ok(match id {
+            Some(id) => self.graph.get_index(id).into_iter().collect(),
+            None => self.graph.graph.node_indices().collect(),
+        })
+    }
+
+    /// End nodes reachable through `rel`, with the edges taken.
+    fn expand(
+        &mut self,
+        from: NodeIndex,
+        rel: &RelPattern,
+    ) -> Result<Vec<(NodeIndex, Vec<EdgeIndex>)>> {
+        let max_hops = rel
+            .max_hops
+            .unwrap_or(self.limits.max_hops)
+            .min(self.limits.max_hops);
+        let mut results = Vec::new();
+        if rel.min_hops == 0 {
+            results.push((from, Vec::new()));
+        }
+
+        // Depth-first over paths that do not reuse an edge
+        let mut stack: Vec<(NodeIndex, Vec<EdgeIndex>)> = vec![(from, Vec::new())];
+        while let Some((node, path)) = stack.pop() {
+            if path.len() >= max_hops {
+                continue;
+            }
+            for (edge, next) in self.edges_from(node, rel.direction) {
+                if path.contains(&edge) || self.used_edges.contains(&edge) {
+                    continue;
+                }
+                if !self.type_matches(rel, edge) {
+                    continue;
+                }
+                self.step()?;
+                let mut next_path = path.clone();
+                next_path.push(edge);
+                if next_path.len() >= rel.min_hops {
+                    results.push((next, next_path.clone()));
+                }
+                stack.push((next, next_path));
+            }
+        }
+        Ok(results)
+    }
+
+    fn edges_from(&self, node: NodeIndex, direction: RelDirection) -> Vec<(EdgeIndex, NodeIndex)> {
+        let graph = &self.graph.graph;
+        let outgoing = || {
+            graph
+                .edges_directed(node, Direction::Outgoing)
+                .map(|e| (e.id(), e.target()))
+        };
+        let incoming = || {
+            graph
+                .edges_directed(node, Direction::Incoming)
+                .map(|e| (e.id(), e.source()))
+        };
+        match direction {
+            RelDirection::Outgoing => outgoing().collect(),
+            RelDirection::Incoming => incoming().collect(),
+            RelDirection::Either => outgoing().chain(incoming()).collect(),
+        }
+    }
+
+    fn type_matches(&self, rel: &RelPattern, edge: EdgeIndex) -> bool {
+        if rel.types.is_empty() {
+            return true;
+        }
+        let name = self.graph.graph[edge].relationship.name();
+        rel.types.iter().any(|t| t.eq_ignore_ascii_case(name))
+    }
+
+    fn node_matches(&self, pattern: &NodePattern, idx: NodeIndex, bindings: &Bindings) -> bool {
+        if let Some(var) = &pattern.var
+            && let Some(bound) = bindings.get(var)
+            && *bound != Bound::Node(idx)
+        {
+            return false;
+        }
+        let node = &self.graph.graph[idx];
+        pattern
+            .labels
+            .iter()
+            .all(|label| node.category.as_deref() == Some(label.as_str()))
+            && pattern.props.iter().all(|(key, expected)| {
+                values_equal(&node_property(node, key), expected) == Some(true)
+            })
+    }
+
+    // -- Rows ----------------------------------------------------------------
+
+    fn emit(&mut self, bindings: &Bindings) -> Result<()> {
+        if let Some(filter) = &self.ast.filter
+            && self.eval_bool(filter, bindings)? != Some(true)
+        {
+            return Ok(());
+        }
+
+        let values = self
+            .ast
+            .returns
+            .iter()
+            .map(|item| match &item.projection {
+                Projection::Value(expr) => self.eval(expr, bindings),
+                // Non-null marker; counted in `aggregate`
+                Projection::Count(None) => Ok(Value::Bool(true)),
+                Projection::Count(Some(expr)) => {
+                    Ok(Value::Bool(!self.eval(expr, bindings)?.is_null()))
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let order_values = self
+            .ast
+            .order_by
+            .iter()
+            .filter_map(|item| match &item.key {
+                OrderKey::Expr(expr) => Some(self.eval(expr, bindings)),
+                OrderKey::Column(_) => None,
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.rows.push(Row {
+            values,
+            order_values,
+        });
+        Ok(())
+    }
+
+    /// Group rows on their non-count columns and fill in the counts.
+    fn aggregate(&mut self) -> Vec<Row> {
+        let rows = std::mem::take(&mut self.rows);
+        let counted: Vec<bool> = self
+            .ast
+            .returns
+            .iter()
+            .map(|item| matches!(item.projection, Projection::Count(_)))
+            .collect();
+        if !counted.contains(&true) {
+            return rows;
+        }
+
+        let mut groups: Vec<Row> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        for row in rows {
+            let key: Vec<&Value> = row
+                .values
+                .iter()
+                .zip(&counted)
+                .filter(|(_, counted)| !**counted)
+                .map(|(value, _)| value)
+                .collect();
+            let key = serde_json::to_string(&key).unwrap_or_default();
+            let group = *index.entry(key).or_insert_with(|| {
+                groups.push(Row {
+                    values: row
+                        .values
+                        .iter()
+                        .zip(&counted)
+                        .map(|(value, counted)| if *counted { json!(0) } else { value.clone() })
+                        .collect(),
+                    order_values: Vec::new(),
+                });
+                groups.len() - 1
+            });
+            for (col, counted) in counted.iter().enumerate() {
+                if *counted && row.values[col] == Value::Bool(true) {
+                    let total = groups[group].values[col].as_u64().unwrap_or(0);
+                    groups[group].values[col] = json!(total + 1);
+                }
+            }
+        }
+
+        // A count over no matches is still one row
+        if groups.is_empty() && counted.iter().all(|c| *c) {
+            groups.push(Row {
+                values: vec![json!(0); counted.len()],
+                order_values: Vec::new(),
+            });
+        }
+        groups
+    }
+
+    fn compare_rows(&self, a: &Row, b: &Row) -> Ordering {
+        let mut expr_idx = 0;
+        for item in &self.ast.order_by {
+            let (left, right) = match item.key {
+                OrderKey::Column(col) => (&a.values[col], &b.values[col]),
+                OrderKey::Expr(_) => {
+                    expr_idx += 1;
+                    (&a.order_values[expr_idx - 1], &b.order_values[expr_idx - 1])
+                }
+            };
+            let ordering = sort_order(left, right);
+            let ordering = if item.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        Ordering::Equal
+    }
+
+    // -- Expressions ---------------------------------------------------------
+
+    fn eval(&self, expr: &Expr, bindings: &Bindings) -> Result<Value> {
+        Ok(match expr {
+            Expr::Literal(value) => value.clone(),
+            Expr::List(items) => Value::Array(
+                items
+                    .iter()
+                    .map(|item| self.eval(item, bindings))
+                    .collect::<Result<_>>()?,
+            ),
+            Expr::Variable(var) => match self.lookup(var, bindings)? {
+                Bound::Node(idx) => serde_json::to_value(NodeSummary::from(&self.graph.graph[idx]))
+                    .unwrap_or(Value::Null),
+                Bound::Edge(idx) => {
+                    let edge = &self.graph.graph[idx];
+                    json!({
+                        "from": edge.from,
+                        "to": edge.to,
+                        "relationship": edge.relationship.name(),
+                        "weight": edge.weight,
+                    })
+                }
+            },
+            Expr::Property(var, key) => match self.lookup(var, bindings)? {
+                Bound::Node(idx) => node_property(&self.graph.graph[idx], key),
+                Bound::Edge(idx) => {
+                    let edge = &self.graph.graph[idx];
+                    match key.as_str() {
+                        "type" | "relationship" => json!(edge.relationship.name()),
+                        "weight" => json!(edge.weight),
+                        "origin" => json!(origin_name(&edge.origin)),
+                        "from" => json!(edge.from),
+                        "to" => json!(edge.to),
+                        _ => Value::Null,
+                    }
+                }
+            },
+            Expr::Not(_) | Expr::And(..) | Expr::Or(..) | Expr::Compare(..) | Expr::IsNull(..) => {
+                self.eval_bool(expr, bindings)?
+                    .map_or(Value::Null, Value::Bool)
+            }
+        })
+    }
+
+    /// Three-valued logic: `None` is null (unknown).
+    fn eval_bool(&self, expr: &Expr, bindings: &Bindings) -> Result<Option<bool>> {
+        Ok(match expr {
+            Expr::Not(inner) => self.eval_bool(inner, bindings)?.map(|b| !b),
+            Expr::And(left, right) => {
+                match (
+                    self.eval_bool(left, bindings)?,
+                    self.eval_bool(right, bindings)?,
+                ) {
+                    (Some(false), _) | (_, Some(false)) => Some(false),
+                    (Some(true), Some(true)) => Some(true),
+                    _ => None,
+                }
+            }
+            Expr::Or(left, right) => {
+                match (
+                    self.eval_bool(left, bindings)?,
+                    self.eval_bool(right, bindings)?,
+                ) {
+                    (Some(true), _) | (_, Some(true)) => Some(true),
+                    (Some(false), Some(false)) => Some(false),
+                    _ => None,
+                }
+            }
+            Expr::IsNull(inner, negated) => Some(self.eval(inner, bindings)?.is_null() != *negated),
+            Expr::Compare(left, op, right) => compare(
+                &self.eval(left, bindings)?,
+                *op,
+                &self.eval(right, bindings)?,
+            ),
+            other => match self.eval(other, bindings)? {
+                Value::Bool(b) => Some(b),
+                Value::Null => None,
+                value => {
+                    return Err(Error::parse(format!(
+                        "Expected a boolean condition, found {value}"
+                    )));
+                }
+            },
+        })
+    }
+
+    fn lookup(&self, var: &str, bindings: &Bindings) -> Result<Bound> {
+        bindings
+            .get(var)
+            .copied()
+            .ok_or_else(|| Error::parse(format!("Unknown variable '{var}' (not bound in MATCH)")))
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Bind `var` if it is named and unbound; returns whether it was bound here.
+fn bind(bindings: &mut Bindings, var: &Option<String>, value: Bound) -> bool {
+    match var {
+        Some(var) if !bindings.contains_key(var) => {
+            bindings.insert(var.clone(), value);
+            true
+        }
+        _ => false,
+    }
+}
+
+fn unbind(bindings: &mut Bindings, var: &Option<String>, bound: bool) {
+    if bound && let Some(var) = var {
+        bindings.remove(var);
+    }
+}
+
+/// A node property: built-in fields first, then metadata.
+fn node_property(node: &Node, key: &str) -> Value {
+    match key {
+        "id" => json!(node.id),
+        "title" => json!(node.title),
+        "category" => json!(node.category),
+        "source_id" => json!(node.source_id),
+        "is_canonical" => json!(node.is_canonical),
+        "canonical_id" => json!(node.canonical_id),
+        "node_type" => serde_json::to_value(&node.node_type).unwrap_or(Value::Null),
+        _ => node.metadata.get(key).cloned().unwrap_or(Value::Null),
+    }
+}
+
+/// Equality with numbers compared by value; `None` if either side is null.
+fn values_equal(a: &Value, b: &Value) -> Option<bool> {
+    match (a, b) {
+        (Value::Null, _) | (_, Value::Null) => None,
+        (Value::Number(x), Value::Number(y)) => Some(x.as_f64() == y.as_f64()),
+        (Value::Array(xs), Value::Array(ys)) => Some(
+            xs.len() == ys.len()
+                && xs
+                    .iter()
+                    .zip(ys)
+                    .all(|(x, y)| values_equal(x, y) == Some(true)),
+        ),
+        _ => Some(a == b),
+    }
+}
+
+fn compare(left: &Value, op: CmpOp, right: &Value) -> Option<bool> {
+    let ordering = || match (left, right) {
+        (Value::Number(x), Value::Number(y)) => x.as_f64()?.partial_cmp(&y.as_f64()?),
+        (Value::String(x), Value::String(y)) => Some(x.cmp(y)),
+        (Value::Bool(x), Value::Bool(y)) => Some(x.cmp(y)),
+        _ => None,
+    };
+    let strings = || match (left, right) {
+        (Value::String(x), Value::String(y)) => Some((x.as_str(), y.as_str())),
+        _ => None,
+    };
+
+    match op {
+        CmpOp::Eq => values_equal(left, right),
+        CmpOp::Ne => values_equal(left, right).map(|eq| !eq),
+        CmpOp::Lt => ordering().map(Ordering::is_lt),
+        CmpOp::Le => ordering().map(Ordering::is_le),
+        CmpOp::Gt => ordering().map(Ordering::is_gt),
+        CmpOp::Ge => ordering().map(Ordering::is_ge),
+        CmpOp::StartsWith => strings().map(|(x, y)| x.starts_with(y)),
+        CmpOp::EndsWith => strings().map(|(x, y)| x.ends_with(y)),
+        CmpOp::Contains => match left {
+            // Lists (e.g. metadata tags) contain elements
+            Value::Array(items) => Some(
+                items
+                    .iter()
+                    .any(|item| values_equal(item, right) == Some(true)),
+            ),
+            _ => strings().map(|(x, y)| x.contains(y)),
+        },
+        CmpOp::In => match right {
+            Value::Array(items) if !left.is_null() => Some(
+                items
+                    .iter()
+                    .any(|item| values_equal(left, item) == Some(true)),
+            ),
+            _ => None,
+        },
+    }
+}
+
+/// Total order for sorting: booleans, numbers, strings, then other values,
+/// with nulls last.
+fn sort_order(a: &Value, b: &Value) -> Ordering {
+    fn rank(value: &Value) -> u8 {
+        match value {
+            Value::Bool(_) => 0,
+            Value::Number(_) => 1,
+            Value::String(_) => 2,
+            Value::Array(_) | Value::Object(_) => 3,
+            Value::Null => 4,
+        }
+    }
+    match (a, b) {
+        (Value::Bool(x), Value::Bool(y)) => x.cmp(y),
+        (Value::Number(x), Value::Number(y)) => x
+            .as_f64()
+            .unwrap_or(0.0)
+            .total_cmp(&y.as_f64().unwrap_or(0.0)),
+        (Value::String(x), Value::String(y)) => x.cmp(y),
+        _ if rank(a) == rank(b) => a.to_string().cmp(&b.to_string()),
+        _ => rank(a).cmp(&rank(b)),
+    }
+}
@@ -0,0 +1,359 @@
+//! Declarative pattern queries over a [`GraphData`].
+//!
+//! A small Cypher-like language for asking ad-hoc questions that the
+//! fixed graph operations don't cover:
+//!
+//! ```text
+//! MATCH (c:harmony)-[:extends]->(p {tier: "advanced"})
+//! WHERE c.title CONTAINS "chord"
+//! RETURN c.id, c.title, p.id AS extends
+//! ORDER BY c.title
+//! LIMIT 20
+//! ```
+//!
+//! # Grammar
+//!
+//! - Nodes: `(var:category {key: value, ...})`. The label matches the
+//!   node's category; properties match built-in fields (`id`, `title`,
+//!   `category`, `source_id`, `is_canonical`, `canonical_id`, `node_type`)
+//!   or metadata keys.
+//! - Relationships: `-[var:type|type]->`, `<-[...]-` or `-[...]-` (either
+//!   direction). Types match relationship names case-insensitively.
+//!   Variable-length hops use `*`, `*2`, `*1..3`, `*..4` or `*2..`, and
+//!   cannot be bound to a variable.
+//! - Several comma-separated patterns join on shared variables.
+//! - `WHERE` supports `=`, `<>`, `<`, `<=`, `>`, `>=`, `CONTAINS`,
+//!   `STARTS WITH`, `ENDS WITH`, `IN [...]`, `IS [NOT] NULL`, `AND`, `OR`,
+//!   `NOT` and parentheses, with SQL-style null semantics.
+//! - `RETURN [DISTINCT]` takes variables, properties, literals, `count(*)`
+//!   and `count(expr)`, each with an optional `AS alias`; `RETURN *`
+//!   returns every named variable. Counts group by the other columns.
+//! - `ORDER BY expr [ASC|DESC], ...`, `SKIP n` and `LIMIT n`.
+//!
+//! Keywords are case-insensitive. Edges are never reused within one match.
+//!
+//! # Cost limits
+//!
+//! Execution is bounded by [`QueryLimits`]: the number of candidate nodes
+//! and edge expansions examined, the maximum hops for variable-length
+//! relationships, and the number of rows returned. Exceeding the step
+//! budget is an error; exceeding the row budget truncates the result.
+
+mod executor;
+mod parser;
+
+use crate::GraphData;
+use fabryk_core::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Cost limits for executing a pattern query.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueryLimits {
+    /// Maximum candidate nodes plus edge expansions examined.
+    pub max_steps: usize,
+    /// Maximum rows returned; further rows set [`PatternResult::truncated`].
+    pub max_rows: usize,
+    /// Upper bound for variable-length relationships (also applies to `*`).
+    pub max_hops: usize,
+}
+
+impl Default for QueryLimits {
+    fn default() -> Self {
+        Self {
+            max_steps: 100_000,
+            max_rows: 1_000,
+            max_hops: 10,
+        }
+    }
+}
+
+impl QueryLimits {
+    /// Sets the maximum number of rows returned.
+    pub fn with_max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = max_rows;
+        self
+    }
+
+    /// Sets the step budget.
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// Sets the hop bound for variable-length relationships.
+    pub fn with_max_hops(mut self, max_hops: usize) -> Self {
+        self.max_hops = max_hops;
+        self
+    }
+}
+
+/// Rows produced by a pattern query.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PatternResult {
+    /// Column names, from `AS` aliases or the projected expression.
+    pub columns: Vec<String>,
+    /// One value per column per row. Node variables project to node
+    /// summaries, relationship variables to `{from, to, relationship, weight}`.
+    pub rows: Vec<Vec<Value>>,
+    /// Whether rows were dropped to respect [`QueryLimits::max_rows`].
+    pub truncated: bool,
+    /// Steps spent matching (see [`QueryLimits::max_steps`]).
+    pub steps: usize,
+}
+
+/// A parsed pattern query, reusable across graphs.
+#[derive(Clone, Debug)]
+pub struct PatternQuery {
+    ast: parser::Ast,
+}
+
+impl PatternQuery {
+    /// Parses a query, returning a parse error with the failing position.
+    pub fn parse(query: &str) -> Result<Self> {
+        Ok(Self {
+            ast: parser::parse(query)?,
+        })
+    }
+
+    /// Column names the query will return.
+    pub fn columns(&self) -> Vec<String> {
+        self.ast.returns.iter().map(|r| r.alias.clone()).collect()
+    }
+
+    /// Runs the query against `graph` within `limits`.
+    pub fn execute(&self, graph: &GraphData, limits: &QueryLimits) -> Result<PatternResult> {
+        executor::Executor::new(graph, &self.ast, limits).run()
+    }
+}
+
+/// Parses and runs a pattern query in one call.
+pub fn execute_pattern(
+    graph: &GraphData,
+    query: &str,
+    limits: &QueryLimits,
+) -> Result<PatternResult> {
+    PatternQuery::parse(query)?.execute(graph, limits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Edge, Node, Relationship};
+    use serde_json::json;
+
+    /// Harmony concepts extending each other, plus a rhythm chain.
+    fn music_graph() -> GraphData {
+        let mut graph = GraphData::new();
+        let nodes = [
+            ("triads", "Triads", "harmony", "basic"),
+            ("sevenths", "Seventh Chords", "harmony", "intermediate"),
+            ("extended", "Extended Chords", "harmony", "advanced"),
+            ("altered", "Altered Chords", "harmony", "advanced"),
+            ("meter", "Meter", "rhythm", "basic"),
+            ("polymeter", "Polymeter", "rhythm", "advanced"),
+        ];
+        for (id, title, category, tier) in nodes {
+            graph.add_node(
+                Node::new(id, title)
+                    .with_category(category)
+                    .with_metadata("tier", tier),
+            );
+        }
+        for (from, to, rel) in [
+            ("sevenths", "triads", Relationship::Extends),
+            ("extended", "sevenths", Relationship::Extends),
+            ("altered", "extended", Relationship::Extends),
+            ("triads", "sevenths", Relationship::Prerequisite),
+            ("polymeter", "meter", Relationship::Extends),
+            ("altered", "polymeter", Relationship::RelatesTo),
+        ] {
+            graph.add_edge(Edge::new(from, to, rel)).unwrap();
+        }
+        graph
+    }
+
+    fn run(query: &str) -> PatternResult {
+        execute_pattern(&music_graph(), query, &QueryLimits::default()).unwrap()
+    }
+
+    fn column(result: &PatternResult, col: usize) -> Vec<Value> {
+        result.rows.iter().map(|row| row[col].clone()).collect()
+    }
+
+    #[test]
+    fn test_match_with_label_and_property_predicates() {
+        let result = run(r#"MATCH (c:harmony)-[:extends]->(p {tier: "advanced"})
+               RETURN c.id, p.id AS extends"#);
+        assert_eq!(result.columns, vec!["c.id", "extends"]);
+        assert_eq!(result.rows, vec![vec![json!("altered"), json!("extended")]]);
+        assert!(!result.truncated);
+    }
+
+    #[test]
+    fn test_where_and_order_by() {
+        let result = run(
+            r#"MATCH (n) WHERE n.tier IN ["advanced", "intermediate"] AND NOT n.category = "rhythm"
+               RETURN n.id ORDER BY n.id DESC"#,
+        );
+        assert_eq!(
+            column(&result, 0),
+            vec![json!("sevenths"), json!("extended"), json!("altered")]
+        );
+    }
+
+    #[test]
+    fn test_incoming_and_either_direction() {
+        let result = run(r#"MATCH (n {id: "triads"})<-[:extends]-(m) RETURN m.id"#);
+        assert_eq!(column(&result, 0), vec![json!("sevenths")]);
+
+        let result = run(r#"MATCH (n {id: "triads"})-[r]-(m) RETURN m.id, r.type ORDER BY r.type"#);
+        assert_eq!(
+            result.rows,
+            vec![
+                vec![json!("sevenths"), json!("extends")],
+                vec![json!("sevenths"), json!("prerequisite")],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_variable_length_hops() {
+        let result =
+            run(r#"MATCH (a {id: "altered"})-[:extends*1..3]->(b) RETURN b.id ORDER BY b.id"#);
+        assert_eq!(
+            column(&result, 0),
+            vec![json!("extended"), json!("sevenths"), json!("triads")]
+        );
+
+        let result = run(r#"MATCH (a {id: "altered"})-[:extends*2]->(b) RETURN b.id"#);
+        assert_eq!(column(&result, 0), vec![json!("sevenths")]);
+
+        // Zero hops includes the start node
+        let result = run(r#"MATCH (a {id: "meter"})<-[*0..1]-(b) RETURN b.id ORDER BY b.id"#);
+        assert_eq!(column(&result, 0), vec![json!("meter"), json!("polymeter")]);
+    }
+
+    #[test]
+    fn test_relationship_variable_and_properties() {
+        let result = run(r#"MATCH (a)-[r:prerequisite]->(b) RETURN a.id, r.type, r.weight, r"#);
+        assert_eq!(result.rows.len(), 1);
+        assert_eq!(result.rows[0][0], json!("triads"));
+        assert_eq!(result.rows[0][1], json!("prerequisite"));
+        assert_eq!(result.rows[0][2], json!(1.0));
+        assert_eq!(result.rows[0][3]["to"], json!("sevenths"));
+    }
+
+    #[test]
+    fn test_multiple_patterns_join_on_shared_variables() {
+        let result =
+            run(r#"MATCH (a)-[:extends]->(b), (b)-[:prerequisite]->(c) RETURN a.id, c.id"#);
+        assert_eq!(
+            result.rows,
+            vec![vec![json!("sevenths"), json!("sevenths")]]
+        );
+    }
+
+    #[test]
+    fn test_count_groups_by_other_columns() {
+        let result =
+            run("MATCH (n) RETURN n.category AS category, count(*) AS total ORDER BY total DESC");
+        assert_eq!(
+            result.rows,
+            vec![
+                vec![json!("harmony"), json!(4)],
+                vec![json!("rhythm"), json!(2)],
+            ]
+        );
+
+        let result = run(r#"MATCH (n:melody) RETURN count(*)"#);
+        assert_eq!(result.rows, vec![vec![json!(0)]]);
+    }
+
+    #[test]
+    fn test_distinct_skip_and_limit() {
+        let result = run("MATCH (n) RETURN DISTINCT n.tier ORDER BY n.tier");
+        assert_eq!(
+            column(&result, 0),
+            vec![json!("advanced"), json!("basic"), json!("intermediate")]
+        );
+
+        let result = run("MATCH (n) RETURN n.id ORDER BY n.id SKIP 1 LIMIT 2");
+        assert_eq!(column(&result, 0), vec![json!("extended"), json!("meter")]);
+    }
+
+    #[test]
+    fn test_node_projection_is_summary() {
+        let result = run(r#"MATCH (n {id: "meter"}) RETURN n"#);
+        assert_eq!(result.rows[0][0]["id"], json!("meter"));
+        assert_eq!(result.rows[0][0]["title"], json!("Meter"));
+    }
+
+    #[test]
+    fn test_null_semantics() {
+        // Missing metadata is null, and comparisons with null never match
+        let result = run(r#"MATCH (n) WHERE n.missing = "x" OR n.missing <> "x" RETURN n.id"#);
+        assert!(result.rows.is_empty());
+
+        let result = run("MATCH (n) WHERE n.missing IS NULL RETURN count(*)");
+        assert_eq!(result.rows, vec![vec![json!(6)]]);
+    }
+
+    #[test]
+    fn test_row_limit_truncates() {
+        let limits = QueryLimits::default().with_max_rows(2);
+        let result = execute_pattern(&music_graph(), "MATCH (n) RETURN n.id", &limits).unwrap();
+        assert_eq!(result.rows.len(), 2);
+        assert!(result.truncated);
+
+        let result =
+            execute_pattern(&music_graph(), "MATCH (n) RETURN n.id LIMIT 2", &limits).unwrap();
+        assert!(!result.truncated);
+    }
+
+    #[test]
+    fn test_step_limit_errors() {
+        let limits = QueryLimits::default().with_max_steps(10);
+        let err = execute_pattern(
+            &music_graph(),
+            "MATCH (a)-[*]-(b) RETURN a.id, b.id",
+            &limits,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("cost limit"));
+    }
+
+    #[test]
+    fn test_max_hops_caps_unbounded_expansion() {
+        let limits = QueryLimits::default().with_max_hops(1);
+        let result = execute_pattern(
+            &music_graph(),
+            r#"MATCH (a {id: "altered"})-[:extends*]->(b) RETURN b.id"#,
+            &limits,
+        )
+        .unwrap();
+        assert_eq!(column(&result, 0), vec![json!("extended")]);
+    }
+
+    #[test]
+    fn test_unknown_variable_errors() {
+        let err = execute_pattern(
+            &music_graph(),
+            "MATCH (n) RETURN m.id",
+            &QueryLimits::default(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Unknown variable 'm'"));
+    }
+
+    #[test]
+    fn test_query_is_reusable() {
+        let query = PatternQuery::parse("MATCH (n:rhythm) RETURN n.id AS id").unwrap();
+        assert_eq!(query.columns(), vec!["id"]);
+        let graph = music_graph();
+        let first = query.execute(&graph, &QueryLimits::default()).unwrap();
+        let second = query.execute(&graph, &QueryLimits::default()).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(first.rows.len(), 2);
+    }
+}
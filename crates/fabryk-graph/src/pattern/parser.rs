@@ -0,0 +1,844 @@
+//! Lexer and recursive-descent parser for the pattern query language.
+
+use fabryk_core::{Error, Result};
+use serde_json::Value;
+
+// ============================================================================
+// AST
+// ============================================================================
+
+/// A parsed query.
+#[derive(Clone, Debug)]
+pub(crate) struct Ast {
+    pub(crate) patterns: Vec<PathPattern>,
+    pub(crate) filter: Option<Expr>,
+    pub(crate) distinct: bool,
+    pub(crate) returns: Vec<ReturnItem>,
+    pub(crate) order_by: Vec<OrderItem>,
+    pub(crate) skip: usize,
+    pub(crate) limit: Option<usize>,
+}
+
+/// A chain of node and relationship patterns.
+#[derive(Clone, Debug)]
+pub(crate) struct PathPattern {
+    pub(crate) start: NodePattern,
+    pub(crate) hops: Vec<(RelPattern, NodePattern)>,
+}
+
+/// `(var:label {prop: value})`
+#[derive(Clone, Debug, Default)]
+pub(crate) struct NodePattern {
+    pub(crate) var: Option<String>,
+    pub(crate) labels: Vec<String>,
+    pub(crate) props: Vec<(String, Value)>,
+}
+
+/// Direction of a relationship pattern.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum RelDirection {
+    Outgoing,
+    Incoming,
+    Either,
+}
+
+/// `-[var:type|type*min..max]->`
+#[derive(Clone, Debug)]
+pub(crate) struct RelPattern {
+    pub(crate) var: Option<String>,
+    pub(crate) types: Vec<String>,
+    pub(crate) direction: RelDirection,
+    pub(crate) min_hops: usize,
+    /// `None` means unbounded (capped by the query limits).
+    pub(crate) max_hops: Option<usize>,
+}
+
+/// Comparison operators.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum CmpOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Contains,
+    StartsWith,
+    EndsWith,
+    In,
+}
+
+/// An expression in `WHERE`, `RETURN` or `ORDER BY`.
+#[derive(Clone, Debug)]
+pub(crate) enum Expr {
+    Literal(Value),
+    List(Vec<Expr>),
+    Variable(String),
+    Property(String, String),
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Compare(Box<Expr>, CmpOp, Box<Expr>),
+    IsNull(Box<Expr>, bool),
+}
+
+/// A projected column.
+#[derive(Clone, Debug)]
+pub(crate) enum Projection {
+    Value(Expr),
+    /// `count(*)` (`None`) or `count(expr)`.
+    Count(Option<Expr>),
+}
+
+/// A `RETURN` item with its column name.
+#[derive(Clone, Debug)]
+pub(crate) struct ReturnItem {
+    pub(crate) projection: Projection,
+    pub(crate) alias: String,
+}
+
+/// What an `ORDER BY` item sorts on.
+#[derive(Clone, Debug)]
+pub(crate) enum OrderKey {
+    /// A returned column (by alias).
+    Column(usize),
+    /// An expression over the match bindings.
+    Expr(Expr),
+}
+
+/// An `ORDER BY` item.
+#[derive(Clone, Debug)]
+pub(crate) struct OrderItem {
+    pub(crate) key: OrderKey,
+    pub(crate) descending: bool,
+}
+
+// ============================================================================
+// Lexer
+// ============================================================================
+
+#[derive(Clone, Debug, PartialEq)]
+enum Tok {
+    Ident(String),
+    /// Backtick-quoted identifier; never a keyword.
+    Quoted(String),
+    Str(String),
+    Num(f64),
+    Sym(&'static str),
+    Eof,
+}
+
+#[derive(Clone, Debug)]
+struct Token {
+    tok: Tok,
+    start: usize,
+    end: usize,
+}
+
+const SYMBOLS: [&str; 21] = [
+    "..", "<>", "!=", "<=", ">=", "(", ")", "[", "]", "{", "}", ":", ",", ".", "-", ">", "<", "=",
+    "*", "|", ";",
+];
+
+fn tokenize(src: &str) -> Result<Vec<Token>> {
+    let bytes = src.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+
+        let tok = if c == b'\'' || c == b'"' {
+            let (s, next) = lex_string(src, i)?;
+            i = next;
+            Tok::Str(s)
+        } else if c == b'`' {
+            let close = src[i + 1..]
+                .find('`')
+                .ok_or_else(|| syntax_error(src, i, "unterminated `identifier`"))?;
+            let name = src[i + 1..i + 1 + close].to_string();
+            i += close + 2;
+            Tok::Quoted(name)
+        } else if c.is_ascii_digit() {
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            // A single dot followed by a digit is a fraction; `1..3` is a range
+            if i + 1 < bytes.len() && bytes[i] == b'.' && bytes[i + 1].is_ascii_digit() {
+                i += 1;
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+            }
+            let n = src[start..i]
+                .parse()
+                .map_err(|_| syntax_error(src, start, "invalid number"))?;
+            Tok::Num(n)
+        } else if c.is_ascii_alphabetic() || c == b'_' {
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                i += 1;
+            }
+            Tok::Ident(src[start..i].to_string())
+        } else {
+            let sym = SYMBOLS
+                .iter()
+                .find(|s| src[i..].starts_with(**s))
+                .ok_or_else(|| {
+                    syntax_error(
+                        src,
+                        i,
+                        &format!(
+                            "unexpected character '{}'",
+                            src[i..].chars().next().unwrap_or(' ')
+                        ),
+                    )
+                })?;
+            i += sym.len();
+            Tok::Sym(sym)
+        };
+        tokens.push(Token { tok, start, end: i });
+    }
+
+    tokens.push(Token {
+        tok: Tok::Eof,
+        start: src.len(),
+        end: src.len(),
+    });
+    Ok(tokens)
+}
+
+/// Lex a quoted string starting at `start`, returning it and the index
+/// after the closing quote.
+fn lex_string(src: &str, start: usize) -> Result<(String, usize)> {
+    let mut chars = src[start..].char_indices();
+    let quote = chars.next().map(|(_, c)| c).unwrap_or('"');
+    let mut out = String::new();
+
+    while let Some((offset, c)) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some((_, 'n')) => out.push('\n'),
+                Some((_, 't')) => out.push('\t'),
+                Some((_, other)) => out.push(other),
+                None => break,
+            },
+            c if c == quote => return Ok((out, start + offset + c.len_utf8())),
+            c => out.push(c),
+        }
+    }
+    Err(syntax_error(src, start, "unterminated string"))
+}
+
+fn syntax_error(src: &str, pos: usize, message: &str) -> Error {
+    let snippet: String = src[pos.min(src.len())..].chars().take(20).collect();
+    if snippet.is_empty() {
+        Error::parse(format!("Query syntax error at end of input: {message}"))
+    } else {
+        Error::parse(format!(
+            "Query syntax error at position {pos} (near '{snippet}'): {message}"
+        ))
+    }
+}
+
+// ============================================================================
+// Parser
+// ============================================================================
+
+const KEYWORDS: [&str; 21] = [
+    "MATCH", "WHERE", "RETURN", "AND", "OR", "NOT", "IN", "IS", "NULL", "TRUE", "FALSE",
+    "CONTAINS", "STARTS", "ENDS", "WITH", "AS", "ORDER", "BY", "SKIP", "LIMIT", "DISTINCT",
+];
+
+/// Parse a query string.
+pub(crate) fn parse(src: &str) -> Result<Ast> {
+    let mut parser = Parser {
+        src,
+        tokens: tokenize(src)?,
+        pos: 0,
+    };
+    parser.query()
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> &Tok {
+        &self.tokens[self.pos].tok
+    }
+
+    fn peek_at(&self, offset: usize) -> &Tok {
+        let idx = (self.pos + offset).min(self.tokens.len() - 1);
+        &self.tokens[idx].tok
+    }
+
+    fn advance(&mut self) -> Tok {
+        let tok = self.tokens[self.pos].tok.clone();
+        if self.pos < self.tokens.len() - 1 {
+            self.pos += 1;
+        }
+        tok
+    }
+
+    fn error(&self, message: &str) -> Error {
+        syntax_error(self.src, self.tokens[self.pos].start, message)
+    }
+
+    fn is_sym(&self, sym: &str) -> bool {
+        matches!(self.peek(), Tok::Sym(s) if *s == sym)
+    }
+
+    fn eat_sym(&mut self, sym: &str) -> bool {
+        if self.is_sym(sym) {
+            self.advance();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_sym(&mut self, sym: &str) -> Result<()> {
+        if self.eat_sym(sym) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{sym}'")))
+        }
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Tok::Ident(s) if s.eq_ignore_ascii_case(keyword))
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        if self.is_keyword(keyword) {
+            self.advance();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<()> {
+        if self.eat_keyword(keyword) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected {keyword}")))
+        }
+    }
+
+    /// A non-keyword identifier, if one is next.
+    fn name(&mut self) -> Option<String> {
+        match self.peek() {
+            Tok::Ident(s) if !KEYWORDS.iter().any(|k| s.eq_ignore_ascii_case(k)) => {
+                let s = s.clone();
+                self.advance();
+                Some(s)
+            }
+            Tok::Quoted(s) => {
+                let s = s.clone();
+                self.advance();
+                Some(s)
+            }
+            _ => None,
+        }
+    }
+
+    fn expect_name(&mut self, what: &str) -> Result<String> {
+        self.name()
+            .ok_or_else(|| self.error(&format!("expected {what}")))
+    }
+
+    /// Any identifier, keywords included (property keys, relationship types).
+    fn any_name(&mut self, what: &str) -> Result<String> {
+        match self.peek() {
+            Tok::Ident(s) | Tok::Quoted(s) => {
+                let s = s.clone();
+                self.advance();
+                Ok(s)
+            }
+            _ => Err(self.error(&format!("expected {what}"))),
+        }
+    }
+
+    fn integer(&mut self, what: &str) -> Result<usize> {
+        match self.peek() {
+            Tok::Num(n) if n.fract() == 0.0 && *n >= 0.0 => {
+                let n = *n as usize;
+                self.advance();
+                Ok(n)
+            }
+            _ => Err(self.error(&format!("expected a non-negative integer {what}"))),
+        }
+    }
+
+    // -- Clauses -------------------------------------------------------------
+
+    fn query(&mut self) -> Result<Ast> {
+        self.expect_keyword("MATCH")?;
+        let mut patterns = vec![self.path_pattern()?];
+        while self.eat_sym(",") {
+            patterns.push(self.path_pattern()?);
+        }
+
+        let filter = if self.eat_keyword("WHERE") {
+            Some(self.expr()?)
+        } else {
+            None
+        };
+
+        self.expect_keyword("RETURN")?;
+        let distinct = self.eat_keyword("DISTINCT");
+        let returns = if self.eat_sym("*") {
+            star_items(&patterns)?
+        } else {
+            let mut items = vec![self.return_item()?];
+            while self.eat_sym(",") {
+                items.push(self.return_item()?);
+            }
+            items
+        };
+
+        let mut order_by = Vec::new();
+        if self.eat_keyword("ORDER") {
+            self.expect_keyword("BY")?;
+            loop {
+                order_by.push(self.order_item(&returns)?);
+                if !self.eat_sym(",") {
+                    break;
+                }
+            }
+        }
+
+        let skip = if self.eat_keyword("SKIP") {
+            self.integer("after SKIP")?
+        } else {
+            0
+        };
+        let limit = if self.eat_keyword("LIMIT") {
+            Some(self.integer("after LIMIT")?)
+        } else {
+            None
+        };
+
+        self.eat_sym(";");
+        if *self.peek() != Tok::Eof {
+            return Err(self.error("unexpected input after query"));
+        }
+
+        Ok(Ast {
+            patterns,
+            filter,
+            distinct,
+            returns,
+            order_by,
+            skip,
+            limit,
+        })
+    }
+
+    fn path_pattern(&mut self) -> Result<PathPattern> {
+        let start = self.node_pattern()?;
+        let mut hops = Vec::new();
+        while self.is_sym("-") || self.is_sym("<") {
+            let rel = self.rel_pattern()?;
+            let node = self.node_pattern()?;
+            hops.push((rel, node));
+        }
+        Ok(PathPattern { start, hops })
+    }
+
+    fn node_pattern(&mut self) -> Result<NodePattern> {
+        self.expect_sym("(")?;
+        let mut node = NodePattern {
+            var: self.name(),
+            ..Default::default()
+        };
+        while self.eat_sym(":") {
+            node.labels.push(self.any_name("a label")?);
+        }
+        if self.is_sym("{") {
+            node.props = self.property_map()?;
+        }
+        self.expect_sym(")")?;
+        Ok(node)
+    }
+
+    fn property_map(&mut self) -> Result<Vec<(String, Value)>> {
+        self.expect_sym("{")?;
+        let mut props = Vec::new();
+        if !self.is_sym("}") {
+            loop {
+                let key = self.any_name("a property name")?;
+                self.expect_sym(":")?;
+                let value = match self.primary()? {
+                    Expr::Literal(value) => value,
+                    Expr::List(items) => Value::Array(
+                        items
+                            .into_iter()
+                            .map(|item| match item {
+                                Expr::Literal(value) => Ok(value),
+                                _ => Err(self.error("property lists must contain literals")),
+                            })
+                            .collect::<Result<_>>()?,
+                    ),
+                    _ => return Err(self.error("property values must be literals")),
+                };
+                props.push((key, value));
+                if !self.eat_sym(",") {
+                    break;
+                }
+            }
+        }
+        self.expect_sym("}")?;
+        Ok(props)
+    }
+
+    fn rel_pattern(&mut self) -> Result<RelPattern> {
+        let incoming = self.eat_sym("<");
+        self.expect_sym("-")?;
+
+        let mut rel = RelPattern {
+            var: None,
+            types: Vec::new(),
+            direction: RelDirection::Either,
+            min_hops: 1,
+            max_hops: Some(1),
+        };
+        if self.eat_sym("[") {
+            rel.var = self.name();
+            if self.eat_sym(":") {
+                rel.types.push(self.any_name("a relationship type")?);
+                while self.eat_sym("|") {
+                    self.eat_sym(":");
+                    rel.types.push(self.any_name("a relationship type")?);
+                }
+            }
+            if self.eat_sym("*") {
+                rel.max_hops = None;
+                if matches!(self.peek(), Tok::Num(_)) {
+                    rel.min_hops = self.integer("hop count")?;
+                    if !self.is_sym("..") {
+                        rel.max_hops = Some(rel.min_hops);
+                    }
+                }
+                if self.eat_sym("..") && matches!(self.peek(), Tok::Num(_)) {
+                    rel.max_hops = Some(self.integer("hop count")?);
+                }
+                if rel.max_hops.is_some_and(|max| max < rel.min_hops) {
+                    return Err(self.error("hop range maximum is below its minimum"));
+                }
+                if rel.var.is_some() {
+                    return Err(
+                        self.error("variable-length relationships cannot be bound to a variable")
+                    );
+                }
+            }
+            self.expect_sym("]")?;
+        }
+
+        self.expect_sym("-")?;
+        let outgoing = self.eat_sym(">");
+        rel.direction = match (incoming, outgoing) {
+            (false, true) => RelDirection::Outgoing,
+            (true, false) => RelDirection::Incoming,
+            (false, false) => RelDirection::Either,
+            (true, true) => return Err(self.error("a relationship cannot point both ways")),
+        };
+        Ok(rel)
+    }
+
+    fn return_item(&mut self) -> Result<ReturnItem> {
+        let start = self.tokens[self.pos].start;
+        let projection = if self.is_keyword("count") && self.peek_at(1) == &Tok::Sym("(") {
+            self.advance();
+            self.advance();
+            let inner = if self.eat_sym("*") {
+                None
+            } else {
+                Some(self.expr()?)
+            };
+            self.expect_sym(")")?;
+            Projection::Count(inner)
+        } else {
+            Projection::Value(self.expr()?)
+        };
+        let end = self.tokens[self.pos.saturating_sub(1)].end;
+
+        let alias = if self.eat_keyword("AS") {
+            self.expect_name("a column name")?
+        } else {
+            self.src[start..end].trim().to_string()
+        };
+        Ok(ReturnItem { projection, alias })
+    }
+
+    fn order_item(&mut self, returns: &[ReturnItem]) -> Result<OrderItem> {
+        let start = self.tokens[self.pos].start;
+        let expr = self.expr()?;
+        let end = self.tokens[self.pos.saturating_sub(1)].end;
+        let text = self.src[start..end].trim();
+
+        let column = returns.iter().position(|item| item.alias == text);
+        let key = match column {
+            Some(idx) => OrderKey::Column(idx),
+            None if returns
+                .iter()
+                .any(|item| matches!(item.projection, Projection::Count(_))) =>
+            {
+                return Err(self.error("ORDER BY must use returned columns when counting"));
+            }
+            None => OrderKey::Expr(expr),
+        };
+
+        let descending = self.eat_keyword("DESC") || self.eat_keyword("DESCENDING");
+        if !descending && !self.eat_keyword("ASC") {
+            self.eat_keyword("ASCENDING");
+        }
+        Ok(OrderItem { key, descending })
+    }
+
+    // -- Expressions ---------------------------------------------------------
+
+    fn expr(&mut self) -> Result<Expr> {
+        let mut left = self.and_expr()?;
+        while self.eat_keyword("OR") {
+            let right = self.and_expr()?;
+            left = Expr::Or(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn and_expr(&mut self) -> Result<Expr> {
+        let mut left = self.not_expr()?;
+        while self.eat_keyword("AND") {
+            let right = self.not_expr()?;
+            left = Expr::And(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn not_expr(&mut self) -> Result<Expr> {
+        if self.eat_keyword("NOT") {
+            Ok(Expr::Not(Box::new(self.not_expr()?)))
+        } else {
+            self.comparison()
+        }
+    }
+
+    fn comparison(&mut self) -> Result<Expr> {
+        let left = self.primary()?;
+
+        if self.eat_keyword("IS") {
+            let negated = self.eat_keyword("NOT");
+            self.expect_keyword("NULL")?;
+            return Ok(Expr::IsNull(Box::new(left), negated));
+        }
+
+        let op = match self.peek() {
+            Tok::Sym("=") => CmpOp::Eq,
+            Tok::Sym("<>") | Tok::Sym("!=") => CmpOp::Ne,
+            Tok::Sym("<") => CmpOp::Lt,
+            Tok::Sym("<=") => CmpOp::Le,
+            Tok::Sym(">") => CmpOp::Gt,
+            Tok::Sym(">=") => CmpOp::Ge,
+            _ if self.is_keyword("CONTAINS") => CmpOp::Contains,
+            _ if self.is_keyword("IN") => CmpOp::In,
+            _ if self.is_keyword("STARTS") => CmpOp::StartsWith,
+            _ if self.is_keyword("ENDS") => CmpOp::EndsWith,
+            _ => return Ok(left),
+        };
+        self.advance();
+        if matches!(op, CmpOp::StartsWith | CmpOp::EndsWith) {
+            self.expect_keyword("WITH")?;
+        }
+
+        let right = self.primary()?;
+        Ok(Expr::Compare(Box::new(left), op, Box::new(right)))
+    }
+
+    fn primary(&mut self) -> Result<Expr> {
+        match self.peek().clone() {
+            Tok::Str(s) => {
+                self.advance();
+                Ok(Expr::Literal(Value::String(s)))
+            }
+            Tok::Num(n) => {
+                self.advance();
+                Ok(Expr::Literal(number(n)))
+            }
+            Tok::Sym("-") if matches!(self.peek_at(1), Tok::Num(_)) => {
+                self.advance();
+                match self.advance() {
+                    Tok::Num(n) => Ok(Expr::Literal(number(-n))),
+                    _ => Err(self.error("expected a number")),
+                }
+            }
+            Tok::Sym("[") => {
+                self.advance();
+                let mut items = Vec::new();
+                if !self.is_sym("]") {
+                    loop {
+                        items.push(self.expr()?);
+                        if !self.eat_sym(",") {
+                            break;
+                        }
+                    }
+                }
+                self.expect_sym("]")?;
+                Ok(Expr::List(items))
+            }
+            Tok::Sym("(") => {
+                self.advance();
+                let inner = self.expr()?;
+                self.expect_sym(")")?;
+                Ok(inner)
+            }
+            _ if self.eat_keyword("TRUE") => Ok(Expr::Literal(Value::Bool(true))),
+            _ if self.eat_keyword("FALSE") => Ok(Expr::Literal(Value::Bool(false))),
+            _ if self.eat_keyword("NULL") => Ok(Expr::Literal(Value::Null)),
+            _ => {
+                if self.is_keyword("count") && self.peek_at(1) == &Tok::Sym("(") {
+                    return Err(self.error("count() is only allowed in RETURN"));
+                }
+                let var = self.expect_name("an expression")?;
+                if self.eat_sym(".") {
+                    let prop = self.any_name("a property name")?;
+                    Ok(Expr::Property(var, prop))
+                } else {
+                    Ok(Expr::Variable(var))
+                }
+            }
+        }
+    }
+}
+
+/// `RETURN *`: every named variable, in order of appearance.
+fn star_items(patterns: &[PathPattern]) -> Result<Vec<ReturnItem>> {
+    let mut names: Vec<String> = Vec::new();
+    let mut push = |var: &Option<String>| {
+        if let Some(var) = var
+            && !names.contains(var)
+        {
+            names.push(var.clone());
+        }
+    };
+    for pattern in patterns {
+        push(&pattern.start.var);
+        for (rel, node) in &pattern.hops {
+            push(&rel.var);
+            push(&node.var);
+        }
+    }
+    if names.is_empty() {
+        return Err(Error::parse(
+            "Query syntax error: RETURN * needs at least one named variable",
+        ));
+    }
+    Ok(names
+        .into_iter()
+        .map(|name| ReturnItem {
+            projection: Projection::Value(Expr::Variable(name.clone())),
+            alias: name,
+        })
+        .collect())
+}
+
+/// JSON number, as an integer when it has no fractional part.
+pub(crate) fn number(n: f64) -> Value {
+    if n.fract() == 0.0 && n.abs() < i64::MAX as f64 {
+        Value::from(n as i64)
+    } else {
+        serde_json::Number::from_f64(n).map_or(Value::Null, Value::Number)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_full_query() {
+        let ast = parse(
+            "MATCH (c:harmony {tier: 'core'})-[:extends|prerequisite*1..3]->(p), (p)<--(q) \
+             WHERE p.tier = \"advanced\" AND NOT c.id IN ['a', 'b'] \
+             RETURN DISTINCT c.id, p.title AS parent ORDER BY parent DESC SKIP 1 LIMIT 5",
+        )
+        .unwrap();
+
+        assert_eq!(ast.patterns.len(), 2);
+        let first = &ast.patterns[0];
+        assert_eq!(first.start.var.as_deref(), Some("c"));
+        assert_eq!(first.start.labels, vec!["harmony"]);
+        assert_eq!(first.start.props[0].0, "tier");
+        let (rel, end) = &first.hops[0];
+        assert_eq!(rel.types, vec!["extends", "prerequisite"]);
+        assert_eq!(rel.direction, RelDirection::Outgoing);
+        assert_eq!((rel.min_hops, rel.max_hops), (1, Some(3)));
+        assert_eq!(end.var.as_deref(), Some("p"));
+        assert_eq!(ast.patterns[1].hops[0].0.direction, RelDirection::Incoming);
+
+        assert!(ast.filter.is_some());
+        assert!(ast.distinct);
+        assert_eq!(ast.returns[0].alias, "c.id");
+        assert_eq!(ast.returns[1].alias, "parent");
+        assert!(matches!(ast.order_by[0].key, OrderKey::Column(1)));
+        assert!(ast.order_by[0].descending);
+        assert_eq!((ast.skip, ast.limit), (1, Some(5)));
+    }
+
+    #[test]
+    fn test_parse_hop_ranges() {
+        let hops = |q: &str| {
+            let ast = parse(q).unwrap();
+            let rel = &ast.patterns[0].hops[0].0;
+            (rel.min_hops, rel.max_hops)
+        };
+        assert_eq!(hops("MATCH (a)-->(b) RETURN a"), (1, Some(1)));
+        assert_eq!(hops("MATCH (a)-[*]-(b) RETURN a"), (1, None));
+        assert_eq!(hops("MATCH (a)-[*2]->(b) RETURN a"), (2, Some(2)));
+        assert_eq!(hops("MATCH (a)-[*..4]->(b) RETURN a"), (1, Some(4)));
+        assert_eq!(hops("MATCH (a)-[*0..]->(b) RETURN a"), (0, None));
+    }
+
+    #[test]
+    fn test_parse_count_and_star() {
+        let ast = parse("MATCH (a)-[r]->(b) RETURN *").unwrap();
+        let aliases: Vec<&str> = ast.returns.iter().map(|r| r.alias.as_str()).collect();
+        assert_eq!(aliases, vec!["a", "r", "b"]);
+
+        let ast = parse("MATCH (a) RETURN a.category, count(*) AS n ORDER BY n DESC").unwrap();
+        assert!(matches!(ast.returns[1].projection, Projection::Count(None)));
+        assert!(matches!(ast.order_by[0].key, OrderKey::Column(1)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for query in [
+            "RETURN a",
+            "MATCH (a RETURN a",
+            "MATCH (a)<-[r]->(b) RETURN a",
+            "MATCH (a)-[r*2]->(b) RETURN r",
+            "MATCH (a)-[*3..1]->(b) RETURN a",
+            "MATCH (a) WHERE count(a) > 1 RETURN a",
+            "MATCH (a) RETURN a, count(*) ORDER BY a.title",
+            "MATCH (a) RETURN a LIMIT -1",
+            "MATCH (a) WHERE a.title = 'x RETURN a",
+            "MATCH (a) RETURN a extra",
+        ] {
+            let err = parse(query).unwrap_err();
+            assert!(err.to_string().contains("syntax error"), "{query}: {err}");
+        }
+    }
+}
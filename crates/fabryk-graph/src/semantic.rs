@@ -0,0 +1,550 @@
+//! Semantic-similarity edge inference.
+//!
+//! Frontmatter only records the links authors thought to write down. This
+//! pass compares node embeddings and proposes `RelatesTo` edges (or `SameAs`
+//! above a stricter threshold) between nodes whose content is similar but
+//! which are not linked yet.
+//!
+//! Embeddings are read from the vector index when a node's document is
+//! indexed under the node ID, and computed with the [`EmbeddingProvider`]
+//! otherwise (from the node title plus its `description` or `summary`
+//! metadata).
+//!
+//! Suggested edges carry [`EdgeOrigin::Semantic`], so they can be told apart
+//! from curated edges and are replaced on the next run. The
+//! [`SemanticReport`] lists them most similar first: a prioritized list of
+//! links curators may want to add to frontmatter.
+
+use crate::{Edge, EdgeOrigin, GraphData, Node, Relationship};
+use fabryk_core::{Error, Result};
+use fabryk_vector::{EmbeddingProvider, VectorBackend};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+// ============================================================================
+// Options and report types
+// ============================================================================
+
+/// Options for [`SemanticEdgeInferrer`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SemanticEdgeOptions {
+    /// Minimum cosine similarity for a `RelatesTo` suggestion.
+    pub threshold: f32,
+    /// Minimum cosine similarity for a `SameAs` suggestion (`None` to never
+    /// suggest `SameAs`).
+    pub same_as_threshold: Option<f32>,
+    /// Maximum suggestions touching any one node.
+    pub max_per_node: usize,
+    /// Only pair nodes that share a category.
+    pub same_category_only: bool,
+    /// Texts embedded per provider call.
+    pub batch_size: usize,
+}
+
+impl Default for SemanticEdgeOptions {
+    fn default() -> Self {
+        Self {
+            threshold: 0.80,
+            same_as_threshold: Some(0.95),
+            max_per_node: 3,
+            same_category_only: false,
+            batch_size: 32,
+        }
+    }
+}
+
+impl SemanticEdgeOptions {
+    /// Sets the `RelatesTo` similarity threshold.
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Sets the `SameAs` similarity threshold, or disables `SameAs` with `None`.
+    pub fn with_same_as_threshold(mut self, threshold: Option<f32>) -> Self {
+        self.same_as_threshold = threshold;
+        self
+    }
+
+    /// Sets the maximum number of suggestions per node.
+    pub fn with_max_per_node(mut self, max: usize) -> Self {
+        self.max_per_node = max;
+        self
+    }
+
+    /// Only pairs nodes within the same category.
+    pub fn same_category_only(mut self) -> Self {
+        self.same_category_only = true;
+        self
+    }
+
+    /// Sets the number of texts embedded per provider call.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+}
+
+/// An edge proposed from embedding similarity.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SuggestedEdge {
+    /// Source node ID (the lexically smaller ID; both relationships are
+    /// symmetric).
+    pub from: String,
+    /// Target node ID.
+    pub to: String,
+    /// `RelatesTo`, or `SameAs` for near-duplicates.
+    pub relationship: Relationship,
+    /// Cosine similarity of the two node embeddings.
+    pub similarity: f32,
+}
+
+impl SuggestedEdge {
+    /// The graph edge for this suggestion, weighted by similarity.
+    pub fn to_edge(&self) -> Edge {
+        let weight = self.relationship.default_weight() * self.similarity;
+        Edge::new(&self.from, &self.to, self.relationship.clone())
+            .with_weight(weight)
+            .with_origin(EdgeOrigin::Semantic)
+    }
+}
+
+/// Review report from a semantic inference run.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SemanticReport {
+    /// Domain nodes compared.
+    pub nodes_compared: usize,
+    /// Embeddings read from the vector index.
+    pub embeddings_indexed: usize,
+    /// Embeddings computed with the embedding provider.
+    pub embeddings_computed: usize,
+    /// Similar pairs already linked by a non-semantic edge.
+    pub already_linked: usize,
+    /// Similar pairs dropped by the per-node cap.
+    pub capped: usize,
+    /// Suggested edges, most similar first.
+    pub suggestions: Vec<SuggestedEdge>,
+}
+
+// ============================================================================
+// SemanticEdgeInferrer
+// ============================================================================
+
+/// Proposes edges between nodes with similar embeddings.
+///
+/// Can run standalone via [`suggest`](Self::suggest) and
+/// [`apply`](Self::apply), or as a builder pass via
+/// [`GraphBuilder::with_semantic_edges`](crate::GraphBuilder::with_semantic_edges).
+///
+/// Similarity is computed pairwise over all domain nodes, so a run is
+/// quadratic in the node count.
+pub struct SemanticEdgeInferrer {
+    backend: Arc<dyn VectorBackend>,
+    provider: Arc<dyn EmbeddingProvider>,
+    options: SemanticEdgeOptions,
+}
+
+impl SemanticEdgeInferrer {
+    /// Creates an inferrer reading indexed embeddings from `backend` and
+    /// embedding the remaining nodes with `provider`.
+    pub fn new(backend: Arc<dyn VectorBackend>, provider: Arc<dyn EmbeddingProvider>) -> Self {
+        Self {
+            backend,
+            provider,
+            options: SemanticEdgeOptions::default(),
+        }
+    }
+
+    /// Sets the inference options.
+    pub fn with_options(mut self, options: SemanticEdgeOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Returns the inference options.
+    pub fn options(&self) -> &SemanticEdgeOptions {
+        &self.options
+    }
+
+    /// Computes suggestions without modifying the graph.
+    pub async fn suggest(&self, graph: &GraphData) -> Result<SemanticReport> {
+        let mut nodes: Vec<&Node> = graph.iter_nodes().filter(|n| n.is_domain()).collect();
+        nodes.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut report = SemanticReport {
+            nodes_compared: nodes.len(),
+            ..Default::default()
+        };
+        let embeddings = self.embeddings(&nodes, &mut report).await?;
+
+        // Pairs linked by curated or extracted edges, in either direction
+        let linked: HashSet<(&str, &str)> = graph
+            .iter_edges()
+            .filter(|e| e.origin != EdgeOrigin::Semantic)
+            .map(|e| ordered(&e.from, &e.to))
+            .collect();
+
+        let mut candidates = Vec::new();
+        for i in 0..nodes.len() {
+            for j in (i + 1)..nodes.len() {
+                let (a, b) = (nodes[i], nodes[j]);
+                if self.options.same_category_only && a.category != b.category {
+                    continue;
+                }
+                let similarity = dot(&embeddings[i], &embeddings[j]);
+                if similarity < self.options.threshold {
+                    continue;
+                }
+                if linked.contains(&ordered(&a.id, &b.id)) {
+                    report.already_linked += 1;
+                    continue;
+                }
+                candidates.push((similarity, i, j));
+            }
+        }
+        // Most similar first; node order breaks ties deterministically
+        candidates.sort_by(|a, b| b.0.total_cmp(&a.0).then((a.1, a.2).cmp(&(b.1, b.2))));
+
+        let mut per_node = vec![0usize; nodes.len()];
+        for (similarity, i, j) in candidates {
+            if per_node[i] >= self.options.max_per_node || per_node[j] >= self.options.max_per_node
+            {
+                report.capped += 1;
+                continue;
+            }
+            per_node[i] += 1;
+            per_node[j] += 1;
+
+            let relationship = match self.options.same_as_threshold {
+                Some(t) if similarity >= t => Relationship::SameAs,
+                _ => Relationship::RelatesTo,
+            };
+            report.suggestions.push(SuggestedEdge {
+                from: nodes[i].id.clone(),
+                to: nodes[j].id.clone(),
+                relationship,
+                similarity,
+            });
+        }
+
+        Ok(report)
+    }
+
+    /// Replaces the graph's semantic edges with fresh suggestions.
+    pub async fn apply(&self, graph: &mut GraphData) -> Result<SemanticReport> {
+        let removed = graph.remove_edges_with_origin(&EdgeOrigin::Semantic);
+        if removed > 0 {
+            log::debug!("Removed {removed} previously inferred semantic edges");
+        }
+
+        let report = self.suggest(graph).await?;
+        for suggestion in &report.suggestions {
+            graph.add_edge(suggestion.to_edge())?;
+        }
+        log::info!(
+            "Inferred {} semantic edges ({} pairs already linked, {} capped)",
+            report.suggestions.len(),
+            report.already_linked,
+            report.capped
+        );
+        Ok(report)
+    }
+
+    /// Unit-length embeddings for `nodes`, in the same order.
+    async fn embeddings(
+        &self,
+        nodes: &[&Node],
+        report: &mut SemanticReport,
+    ) -> Result<Vec<Vec<f32>>> {
+        let mut embeddings: Vec<Option<Vec<f32>>> = Vec::with_capacity(nodes.len());
+        let mut use_index = true;
+        for node in nodes {
+            let indexed = if use_index {
+                match self.backend.get(&node.id).await {
+                    Ok(doc) => doc.map(|d| d.embedding),
+                    Err(e) => {
+                        log::debug!(
+                            "Vector backend '{}' cannot look up embeddings, computing them: {e}",
+                            self.backend.name()
+                        );
+                        use_index = false;
+                        None
+                    }
+                }
+            } else {
+                None
+            };
+            if indexed.is_some() {
+                report.embeddings_indexed += 1;
+            }
+            embeddings.push(indexed);
+        }
+
+        let missing: Vec<usize> = (0..nodes.len())
+            .filter(|i| embeddings[*i].is_none())
+            .collect();
+        for batch in missing.chunks(self.options.batch_size.max(1)) {
+            let texts: Vec<String> = batch.iter().map(|i| node_text(nodes[*i])).collect();
+            let refs: Vec<&str> = texts.iter().map(String::as_str).collect();
+            let vectors = self.provider.embed_batch(&refs).await?;
+            if vectors.len() != batch.len() {
+                return Err(Error::operation(format!(
+                    "Embedding provider '{}' returned {} embeddings for {} texts",
+                    self.provider.name(),
+                    vectors.len(),
+                    batch.len()
+                )));
+            }
+            for (i, vector) in batch.iter().zip(vectors) {
+                embeddings[*i] = Some(vector);
+            }
+            report.embeddings_computed += batch.len();
+        }
+
+        let embeddings: Vec<Vec<f32>> = embeddings
+            .into_iter()
+            .map(|e| normalize(e.unwrap_or_default()))
+            .collect();
+        if let Some(first) = embeddings.iter().find(|e| !e.is_empty())
+            && let Some(other) = embeddings
+                .iter()
+                .find(|e| !e.is_empty() && e.len() != first.len())
+        {
+            return Err(Error::config(format!(
+                "Embedding dimensions differ ({} vs {}); the vector index and \
+                 embedding provider must use the same model",
+                first.len(),
+                other.len()
+            )));
+        }
+        Ok(embeddings)
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Text embedded for a node without an indexed document.
+fn node_text(node: &Node) -> String {
+    match node
+        .metadata_str("description")
+        .or_else(|| node.metadata_str("summary"))
+    {
+        Some(description) => format!("{}\n\n{}", node.title, description),
+        None => node.title.clone(),
+    }
+}
+
+/// Scale to unit length; zero vectors become empty (and never match).
+fn normalize(mut vector: Vec<f32>) -> Vec<f32> {
+    let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm == 0.0 {
+        return Vec::new();
+    }
+    for x in &mut vector {
+        *x /= norm;
+    }
+    vector
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn ordered<'a>(a: &'a str, b: &'a str) -> (&'a str, &'a str) {
+    if a <= b { (a, b) } else { (b, a) }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use fabryk_vector::{EmbeddedDocument, SimpleVectorBackend, VectorDocument};
+    use std::collections::HashMap;
+
+    /// Maps known texts to fixed vectors; anything else is orthogonal noise.
+    struct TableProvider {
+        table: HashMap<&'static str, Vec<f32>>,
+    }
+
+    #[async_trait]
+    impl EmbeddingProvider for TableProvider {
+        async fn embed(&self, text: &str) -> Result<Vec<f32>> {
+            Ok(self
+                .table
+                .get(text)
+                .cloned()
+                .unwrap_or_else(|| vec![0.0, 0.0, 0.0, 1.0]))
+        }
+
+        fn dimension(&self) -> usize {
+            4
+        }
+
+        fn name(&self) -> &str {
+            "table"
+        }
+    }
+
+    fn provider() -> Arc<TableProvider> {
+        Arc::new(TableProvider {
+            table: HashMap::from([
+                ("Triads", vec![1.0, 0.0, 0.0, 0.0]),
+                ("Seventh Chords", vec![0.85, 0.5268, 0.0, 0.0]),
+                ("Chords", vec![1.0, 0.01, 0.0, 0.0]),
+                ("Meter", vec![0.0, 1.0, 0.0, 0.0]),
+            ]),
+        })
+    }
+
+    fn graph() -> GraphData {
+        let mut graph = GraphData::new();
+        graph.add_node(Node::new("triads", "Triads").with_category("harmony"));
+        graph.add_node(Node::new("sevenths", "Seventh Chords").with_category("harmony"));
+        graph.add_node(Node::new("chords", "Chords").with_category("reference"));
+        graph.add_node(Node::new("meter", "Meter").with_category("rhythm"));
+        graph
+    }
+
+    fn inferrer(backend: SimpleVectorBackend) -> SemanticEdgeInferrer {
+        SemanticEdgeInferrer::new(Arc::new(backend), provider())
+    }
+
+    fn empty_backend() -> SimpleVectorBackend {
+        SimpleVectorBackend::new(provider())
+    }
+
+    fn pairs(report: &SemanticReport) -> Vec<(&str, &str, Relationship)> {
+        report
+            .suggestions
+            .iter()
+            .map(|s| (s.from.as_str(), s.to.as_str(), s.relationship.clone()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_suggest_ranks_by_similarity() {
+        let report = inferrer(empty_backend()).suggest(&graph()).await.unwrap();
+        assert_eq!(report.nodes_compared, 4);
+        assert_eq!(report.embeddings_computed, 4);
+        assert_eq!(report.embeddings_indexed, 0);
+        assert_eq!(
+            pairs(&report),
+            vec![
+                ("chords", "triads", Relationship::SameAs),
+                ("chords", "sevenths", Relationship::RelatesTo),
+                ("sevenths", "triads", Relationship::RelatesTo),
+            ]
+        );
+        assert!(report.suggestions[0].similarity > report.suggestions[1].similarity);
+    }
+
+    #[tokio::test]
+    async fn test_linked_pairs_are_not_suggested() {
+        let mut graph = graph();
+        graph
+            .add_edge(Edge::new("triads", "sevenths", Relationship::Prerequisite))
+            .unwrap();
+        let report = inferrer(empty_backend()).suggest(&graph).await.unwrap();
+        assert_eq!(report.already_linked, 1);
+        assert!(
+            !pairs(&report)
+                .iter()
+                .any(|(from, to, _)| (*from, *to) == ("sevenths", "triads"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_per_node_cap_and_category_filter() {
+        let options = SemanticEdgeOptions::default().with_max_per_node(1);
+        let report = inferrer(empty_backend())
+            .with_options(options)
+            .suggest(&graph())
+            .await
+            .unwrap();
+        // chords–triads takes both nodes' only slot
+        assert_eq!(
+            pairs(&report),
+            vec![("chords", "triads", Relationship::SameAs)]
+        );
+        assert_eq!(report.capped, 2);
+
+        let options = SemanticEdgeOptions::default()
+            .same_category_only()
+            .with_same_as_threshold(None);
+        let report = inferrer(empty_backend())
+            .with_options(options)
+            .suggest(&graph())
+            .await
+            .unwrap();
+        assert_eq!(
+            pairs(&report),
+            vec![("sevenths", "triads", Relationship::RelatesTo)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_indexed_embeddings_are_preferred() {
+        let mut backend = empty_backend();
+        // Indexed embedding makes "meter" identical to "triads"
+        backend.add_documents(vec![EmbeddedDocument::new(
+            VectorDocument::new("meter", "Meter"),
+            vec![1.0, 0.0, 0.0, 0.0],
+        )]);
+        let report = inferrer(backend).suggest(&graph()).await.unwrap();
+        assert_eq!(report.embeddings_indexed, 1);
+        assert_eq!(report.embeddings_computed, 3);
+        assert!(
+            pairs(&report)
+                .iter()
+                .any(|(from, to, _)| (*from, *to) == ("meter", "triads"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dimension_mismatch_errors() {
+        let mut backend = empty_backend();
+        backend.add_documents(vec![EmbeddedDocument::new(
+            VectorDocument::new("meter", "Meter"),
+            vec![1.0, 0.0],
+        )]);
+        let err = inferrer(backend).suggest(&graph()).await.unwrap_err();
+        assert!(err.to_string().contains("dimensions differ"));
+    }
+
+    #[tokio::test]
+    async fn test_apply_replaces_semantic_edges() {
+        let mut graph = graph();
+        graph
+            .add_edge(
+                Edge::new("meter", "triads", Relationship::RelatesTo)
+                    .with_origin(EdgeOrigin::Semantic),
+            )
+            .unwrap();
+
+        let report = inferrer(empty_backend()).apply(&mut graph).await.unwrap();
+        assert_eq!(graph.edge_count(), report.suggestions.len());
+        assert!(graph.edges.iter().all(|e| e.origin == EdgeOrigin::Semantic));
+        assert!(!graph.edges.iter().any(|e| e.from == "meter"));
+
+        let same_as = graph
+            .edges
+            .iter()
+            .find(|e| e.relationship == Relationship::SameAs)
+            .unwrap();
+        assert!((same_as.weight - Relationship::SameAs.default_weight()).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_node_text_uses_description() {
+        let node = Node::new("a", "Triads").with_metadata("description", "Three-note chords");
+        assert_eq!(node_text(&node), "Triads\n\nThree-note chords");
+        assert_eq!(node_text(&Node::new("b", "Meter")), "Meter");
+    }
+}
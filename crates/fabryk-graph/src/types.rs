@@ -5,6 +5,7 @@
 //! supporting domain-specific extensions via the `Custom` variant.
 
 use petgraph::graph::{DiGraph, NodeIndex};
+use petgraph::visit::EdgeRef;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
@@ -191,6 +192,9 @@ pub enum EdgeOrigin {
     /// Inferred by an algorithm (e.g., transitive closure).
     #[serde(alias = "inferred")]
     Inferred,
+    /// Inferred from embedding similarity (see [`crate::semantic`]).
+    #[serde(alias = "semantic")]
+    Semantic,
 }
 
 // ============================================================================
@@ -512,6 +516,62 @@ impl GraphData {
 
         Some(node)
     }
+
+    /// Add a node, or replace the data of an existing node with the same ID.
+    ///
+    /// Unlike [`add_node`](Self::add_node), an existing node's title,
+    /// category and metadata are overwritten. Its edges are kept.
+    pub fn upsert_node(&mut self, node: Node) -> NodeIndex {
+        match self.node_indices.get(&node.id).copied() {
+            Some(idx) => {
+                self.graph[idx] = node.clone();
+                self.nodes.insert(node.id.clone(), node);
+                idx
+            }
+            None => self.add_node(node),
+        }
+    }
+
+    /// Remove the outgoing edges of a node that were extracted from its
+    /// content (frontmatter or body), keeping manual and inferred edges.
+    ///
+    /// Used when a content file changes and its relationships are
+    /// re-extracted. Returns the number of edges removed.
+    pub fn remove_extracted_edges_from(&mut self, id: &str) -> usize {
+        let extracted = |e: &Edge| {
+            e.from == id && matches!(e.origin, EdgeOrigin::Frontmatter | EdgeOrigin::ContentBody)
+        };
+
+        let Some(idx) = self.node_indices.get(id).copied() else {
+            return 0;
+        };
+        let mut doomed: Vec<_> = self
+            .graph
+            .edges_directed(idx, petgraph::Direction::Outgoing)
+            .filter(|e| extracted(e.weight()))
+            .map(|e| e.id())
+            .collect();
+        // Removing an edge moves the last edge into its slot, so remove
+        // from the highest index down to keep the rest valid
+        doomed.sort_unstable_by_key(|e| std::cmp::Reverse(*e));
+        for edge in &doomed {
+            self.graph.remove_edge(*edge);
+        }
+
+        self.edges.retain(|e| !extracted(e));
+        doomed.len()
+    }
+
+    /// Remove every edge with the given origin.
+    ///
+    /// Used to replace the output of an inference pass when it is re-run.
+    /// Returns the number of edges removed.
+    pub fn remove_edges_with_origin(&mut self, origin: &EdgeOrigin) -> usize {
+        let before = self.edges.len();
+        self.graph.retain_edges(|g, e| g[e].origin != *origin);
+        self.edges.retain(|e| e.origin != *origin);
+        before - self.edges.len()
+    }
 }
 
 impl Default for GraphData {
@@ -643,6 +703,7 @@ mod tests {
             EdgeOrigin::ContentBody,
             EdgeOrigin::Manual,
             EdgeOrigin::Inferred,
+            EdgeOrigin::Semantic,
         ];
 
         for origin in origins {
@@ -873,6 +934,85 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_graph_data_upsert_node() {
+        let mut graph = GraphData::new();
+        graph.add_node(Node::new("a", "Node A"));
+        graph.add_node(Node::new("b", "Node B"));
+        graph
+            .add_edge(Edge::new("a", "b", Relationship::Prerequisite))
+            .unwrap();
+
+        let idx = graph.upsert_node(Node::new("a", "Renamed").with_category("new"));
+        assert_eq!(graph.get_index("a"), Some(idx));
+        assert_eq!(graph.get_node("a").unwrap().title, "Renamed");
+        assert_eq!(graph.graph[idx].title, "Renamed");
+        assert_eq!(graph.edge_count(), 1);
+
+        graph.upsert_node(Node::new("c", "Node C"));
+        assert_eq!(graph.node_count(), 3);
+    }
+
+    #[test]
+    fn test_graph_data_remove_extracted_edges_from() {
+        let mut graph = GraphData::new();
+        for id in ["a", "b", "c", "d"] {
+            graph.add_node(Node::new(id, id));
+        }
+        graph
+            .add_edge(Edge::new("a", "b", Relationship::Prerequisite))
+            .unwrap();
+        graph
+            .add_edge(Edge::new("a", "c", Relationship::RelatesTo).with_origin(EdgeOrigin::Manual))
+            .unwrap();
+        graph
+            .add_edge(
+                Edge::new("a", "d", Relationship::LeadsTo).with_origin(EdgeOrigin::ContentBody),
+            )
+            .unwrap();
+        graph
+            .add_edge(Edge::new("b", "a", Relationship::Prerequisite))
+            .unwrap();
+
+        assert_eq!(graph.remove_extracted_edges_from("a"), 2);
+        assert_eq!(graph.edge_count(), 2);
+        assert_eq!(graph.edges.len(), 2);
+        // Manual edge and incoming edge survive, in both representations
+        let remaining: Vec<(&str, &str)> = graph
+            .graph
+            .edge_weights()
+            .map(|e| (e.from.as_str(), e.to.as_str()))
+            .collect();
+        assert!(remaining.contains(&("a", "c")));
+        assert!(remaining.contains(&("b", "a")));
+        assert_eq!(graph.remove_extracted_edges_from("missing"), 0);
+    }
+
+    #[test]
+    fn test_graph_data_remove_edges_with_origin() {
+        let mut graph = GraphData::new();
+        for id in ["a", "b", "c"] {
+            graph.add_node(Node::new(id, id));
+        }
+        graph
+            .add_edge(Edge::new("a", "b", Relationship::Prerequisite))
+            .unwrap();
+        graph
+            .add_edge(
+                Edge::new("a", "c", Relationship::RelatesTo).with_origin(EdgeOrigin::Semantic),
+            )
+            .unwrap();
+        graph
+            .add_edge(Edge::new("b", "c", Relationship::SameAs).with_origin(EdgeOrigin::Semantic))
+            .unwrap();
+
+        assert_eq!(graph.remove_edges_with_origin(&EdgeOrigin::Semantic), 2);
+        assert_eq!(graph.edge_count(), 1);
+        assert_eq!(graph.edges.len(), 1);
+        assert_eq!(graph.edges[0].to, "b");
+        assert_eq!(graph.remove_edges_with_origin(&EdgeOrigin::Semantic), 0);
+    }
+
     #[test]
     fn test_graph_data_remove_node() {
         let mut graph = GraphData::new();
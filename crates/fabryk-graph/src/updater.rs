@@ -0,0 +1,262 @@
+//! Incremental graph updates for live content watching.
+//!
+//! [`GraphUpdater`] is a [`ChangeHandler`] that applies a debounced
+//! [`ChangeSet`] to a shared [`GraphData`] without a full rebuild: removed
+//! files drop their nodes, changed files have their node replaced and their
+//! extracted edges re-derived. Manual and inferred edges are preserved.
+//!
+//! The update is built on a copy of the graph and swapped in under the write
+//! lock, so queries never observe a half-applied change set.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! let graph = Arc::new(RwLock::new(graph));
+//! let tools = GraphTools::with_shared(graph.clone());
+//!
+//! let watcher = ContentWatcher::for_content(&config, "concepts")?
+//!     .with_handler(GraphUpdater::new(MyExtractor, content_path, graph))
+//!     .spawn()?;
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use fabryk_core::util::ids::id_from_path;
+use fabryk_core::{ChangeHandler, ChangeSet, Error, Result};
+use tokio::sync::RwLock;
+
+use crate::builder::extract_file;
+use crate::{GraphData, GraphExtractor};
+
+/// Applies content changes to a shared graph incrementally.
+pub struct GraphUpdater<E: GraphExtractor> {
+    extractor: E,
+    content_path: PathBuf,
+    graph: Arc<RwLock<GraphData>>,
+    /// Node ID last extracted from each file, so removals and renames
+    /// drop the right node.
+    ids: Mutex<HashMap<PathBuf, String>>,
+}
+
+impl<E: GraphExtractor> GraphUpdater<E> {
+    /// Create an updater for the graph built from `content_path`.
+    pub fn new(
+        extractor: E,
+        content_path: impl Into<PathBuf>,
+        graph: Arc<RwLock<GraphData>>,
+    ) -> Self {
+        Self {
+            extractor,
+            content_path: content_path.into(),
+            graph,
+            ids: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The shared graph this updater writes to.
+    pub fn graph(&self) -> &Arc<RwLock<GraphData>> {
+        &self.graph
+    }
+
+    /// Node ID for a removed file: the one last extracted from it, or
+    /// the ID derived from its file name.
+    fn removed_id(ids: &mut HashMap<PathBuf, String>, path: &Path) -> Option<String> {
+        ids.remove(path).or_else(|| id_from_path(path))
+    }
+
+    /// Apply a change set to `graph`, returning the files that failed.
+    fn update(&self, graph: &mut GraphData, changes: &ChangeSet) -> Vec<String> {
+        let mut ids = self
+            .ids
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut failures = Vec::new();
+
+        for path in changes.removed() {
+            if let Some(id) = Self::removed_id(&mut ids, path) {
+                graph.remove_node(&id);
+            }
+        }
+
+        let mut pending_edges = Vec::new();
+        for path in changes.upserted() {
+            match extract_file(&self.extractor, &self.content_path, path) {
+                Ok((node_data, edge_data)) => {
+                    let node = self.extractor.to_graph_node(&node_data);
+                    if let Some(old_id) = ids.insert(path.to_path_buf(), node.id.clone())
+                        && old_id != node.id
+                    {
+                        graph.remove_node(&old_id);
+                    }
+                    graph.remove_extracted_edges_from(&node.id);
+                    let id = node.id.clone();
+                    graph.upsert_node(node);
+                    if let Some(edges) = edge_data {
+                        pending_edges.push((id, edges));
+                    }
+                }
+                Err(e) => failures.push(format!("{}: {e}", path.display())),
+            }
+        }
+
+        let mut seen: HashSet<(String, String, String)> = graph
+            .edges
+            .iter()
+            .map(|e| {
+                (
+                    e.from.clone(),
+                    e.to.clone(),
+                    e.relationship.name().to_string(),
+                )
+            })
+            .collect();
+        for (from_id, edge_data) in &pending_edges {
+            for edge in self.extractor.to_graph_edges(from_id, edge_data) {
+                if !graph.contains_node(&edge.to) {
+                    log::debug!("Skipping dangling edge {} -> {}", edge.from, edge.to);
+                    continue;
+                }
+                let key = (
+                    edge.from.clone(),
+                    edge.to.clone(),
+                    edge.relationship.name().to_string(),
+                );
+                if seen.insert(key) {
+                    let _ = graph.add_edge(edge);
+                }
+            }
+        }
+
+        failures
+    }
+}
+
+#[async_trait]
+impl<E: GraphExtractor> ChangeHandler for GraphUpdater<E> {
+    fn name(&self) -> &str {
+        "graph"
+    }
+
+    async fn apply(&self, changes: &ChangeSet) -> Result<()> {
+        let mut graph = self.graph.read().await.clone();
+        let failures = self.update(&mut graph, changes);
+
+        log::info!(
+            "Graph updated: {} nodes, {} edges",
+            graph.node_count(),
+            graph.edge_count()
+        );
+        *self.graph.write().await = graph;
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::operation(format!(
+                "{} file(s) could not be extracted: {}",
+                failures.len(),
+                failures.join("; ")
+            )))
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extractor::mock::MockExtractor;
+    use crate::{Edge, EdgeOrigin, GraphBuilder, Relationship};
+    use fabryk_core::ChangeKind;
+
+    async fn setup() -> (tempfile::TempDir, PathBuf, Arc<RwLock<GraphData>>) {
+        let dir = tempfile::tempdir().unwrap();
+        let content = dir.path().to_path_buf();
+        std::fs::write(
+            content.join("a.md"),
+            "---\ntitle: A\nprerequisites:\n  - b\n---\nBody",
+        )
+        .unwrap();
+        std::fs::write(content.join("b.md"), "---\ntitle: B\n---\nBody").unwrap();
+
+        let (graph, _) = GraphBuilder::new(MockExtractor)
+            .with_content_path(&content)
+            .build()
+            .await
+            .unwrap();
+        (dir, content, Arc::new(RwLock::new(graph)))
+    }
+
+    #[tokio::test]
+    async fn test_modified_file_replaces_node_and_edges() {
+        let (_dir, content, graph) = setup().await;
+        graph
+            .write()
+            .await
+            .add_edge(Edge::new("a", "b", Relationship::RelatesTo).with_origin(EdgeOrigin::Manual))
+            .unwrap();
+
+        std::fs::write(content.join("c.md"), "---\ntitle: C\n---\nBody").unwrap();
+        std::fs::write(
+            content.join("a.md"),
+            "---\ntitle: A Revised\nprerequisites:\n  - c\n---\nBody",
+        )
+        .unwrap();
+
+        let mut changes = ChangeSet::new();
+        changes.record(content.join("c.md"), ChangeKind::Created);
+        changes.record(content.join("a.md"), ChangeKind::Modified);
+
+        let updater = GraphUpdater::new(MockExtractor, &content, graph.clone());
+        updater.apply(&changes).await.unwrap();
+
+        let graph = graph.read().await;
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.get_node("a").unwrap().title, "A Revised");
+        let edges: Vec<(&str, &str, &str)> = graph
+            .edges
+            .iter()
+            .map(|e| (e.from.as_str(), e.to.as_str(), e.relationship.name()))
+            .collect();
+        assert_eq!(edges.len(), 2);
+        assert!(edges.contains(&("a", "c", "prerequisite")));
+        // Manual edge survives re-extraction
+        assert!(edges.contains(&("a", "b", "relates_to")));
+    }
+
+    #[tokio::test]
+    async fn test_removed_file_drops_node() {
+        let (_dir, content, graph) = setup().await;
+        std::fs::remove_file(content.join("b.md")).unwrap();
+
+        let mut changes = ChangeSet::new();
+        changes.record(content.join("b.md"), ChangeKind::Removed);
+
+        let updater = GraphUpdater::new(MockExtractor, &content, graph.clone());
+        updater.apply(&changes).await.unwrap();
+
+        let graph = graph.read().await;
+        assert!(!graph.contains_node("b"));
+        assert_eq!(graph.edge_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_failed_file_reports_error_but_applies_rest() {
+        let (_dir, content, graph) = setup().await;
+        std::fs::write(content.join("c.md"), "---\ntitle: C\n---\nBody").unwrap();
+
+        let mut changes = ChangeSet::new();
+        changes.record(content.join("c.md"), ChangeKind::Created);
+        changes.record(content.join("missing.md"), ChangeKind::Created);
+
+        let updater = GraphUpdater::new(MockExtractor, &content, graph.clone());
+        let err = updater.apply(&changes).await.unwrap_err();
+        assert!(err.to_string().contains("missing.md"));
+        assert!(graph.read().await.contains_node("c"));
+    }
+}
@@ -0,0 +1,83 @@
+//! Per-caller tool access control.
+//!
+//! A [`ToolAccess`] policy decides, for each MCP request, which tools the
+//! caller may use. When installed with
+//! [`FabrykMcpServer::with_tool_access`](crate::FabrykMcpServer::with_tool_access),
+//! denied tools are hidden from `tools/list` and calls to them are refused
+//! before they reach the registry.
+//!
+//! The caller is identified from the request [`Extensions`]. Over HTTP,
+//! rmcp stores the request's `http::request::Parts` there, which in turn
+//! carry whatever the auth middleware inserted (e.g. an authenticated user).
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! struct ReadOnly;
+//!
+//! impl ToolAccess for ReadOnly {
+//!     fn allows(&self, tool: &str, _extensions: &Extensions) -> bool {
+//!         !tool.starts_with("delete_")
+//!     }
+//! }
+//!
+//! let server = FabrykMcpServer::new(registry).with_tool_access(ReadOnly);
+//! ```
+
+use crate::registry::ToolResult;
+
+pub use rmcp::model::Extensions;
+
+/// Decides which tools the caller of a request may list and call.
+pub trait ToolAccess: Send + Sync {
+    /// Whether the caller of the request carrying `extensions` may use `tool`.
+    fn allows(&self, tool: &str, extensions: &Extensions) -> bool;
+
+    /// Wrap an allowed tool call before it runs.
+    ///
+    /// Implementations can use this to make the caller's identity
+    /// available to tool handlers (e.g. through a task-local). The default
+    /// runs the call unchanged.
+    fn scope(&self, call: ToolResult, _extensions: &Extensions) -> ToolResult {
+        call
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rmcp::model::{CallToolResult, Content};
+
+    struct PrefixAccess(&'static str);
+
+    impl ToolAccess for PrefixAccess {
+        fn allows(&self, tool: &str, _extensions: &Extensions) -> bool {
+            tool.starts_with(self.0)
+        }
+    }
+
+    #[test]
+    fn test_allows() {
+        let access = PrefixAccess("search_");
+        assert!(access.allows("search_fts", &Extensions::new()));
+        assert!(!access.allows("delete_item", &Extensions::new()));
+    }
+
+    #[tokio::test]
+    async fn test_default_scope_runs_call_unchanged() {
+        let access = PrefixAccess("");
+        let call: ToolResult =
+            Box::pin(async { Ok(CallToolResult::success(vec![Content::text("ok")])) });
+        let result = access.scope(call, &Extensions::new()).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+    }
+
+    #[test]
+    fn test_trait_object_safety() {
+        fn _assert_object_safe(_: &dyn ToolAccess) {}
+    }
+}
@@ -1,9 +1,10 @@
 //! Server builder for Fabryk MCP applications.
 //!
 //! Provides [`ServerBuilder`] for composing a [`FabrykMcpServer`] from
-//! a [`CompositeRegistry`] and optional resources. Handles the common
-//! pattern of assembling tool registries, health diagnostics, and
-//! static resources into a server ready for stdio or HTTP transport.
+//! a [`CompositeRegistry`] and optional resources and prompts. Handles the
+//! common pattern of assembling tool registries, health diagnostics,
+//! static resources, and prompt templates into a server ready for stdio or
+//! HTTP transport.
 //!
 //! # Example
 //!
@@ -21,9 +22,11 @@
 //!     .add(my_domain_tools)
 //!     .with_resource(conventions_resource)
 //!     .with_resource(scope_resource)
+//!     .with_prompts(FilePrompts::load("prompts")?)
 //!     .build();
 //! ```
 
+use crate::prompt::{CompositePromptRegistry, PromptRegistry};
 use crate::registry::{CompositeRegistry, ToolRegistry};
 use crate::server::FabrykMcpServer;
 use crate::static_resources::{StaticResourceDef, StaticResources};
@@ -31,8 +34,8 @@ use std::path::PathBuf;
 
 /// Fluent builder for assembling a [`FabrykMcpServer`].
 ///
-/// Collects tool registries, server metadata, and optional static resources,
-/// then produces a fully configured server ready to run.
+/// Collects tool registries, server metadata, and optional static resources
+/// and prompts, then produces a fully configured server ready to run.
 pub struct ServerBuilder {
     name: String,
     version: String,
@@ -40,6 +43,7 @@ pub struct ServerBuilder {
     registry: CompositeRegistry,
     resources_path: PathBuf,
     resource_defs: Vec<StaticResourceDef>,
+    prompts: CompositePromptRegistry,
     services: Vec<fabryk_core::service::ServiceHandle>,
 }
 
@@ -53,6 +57,7 @@ impl ServerBuilder {
             registry: CompositeRegistry::new(),
             resources_path: PathBuf::from("."),
             resource_defs: Vec::new(),
+            prompts: CompositePromptRegistry::new(),
             services: Vec::new(),
         }
     }
@@ -111,6 +116,15 @@ impl ServerBuilder {
         self
     }
 
+    /// Add a prompt registry.
+    ///
+    /// Prompts are exposed via the MCP protocol's `prompts/list` and
+    /// `prompts/get`. Multiple registries are combined in order.
+    pub fn with_prompts(mut self, registry: impl PromptRegistry + 'static) -> Self {
+        self.prompts = self.prompts.add(registry);
+        self
+    }
+
     /// Consume the builder and return the composed registry for further
     /// wrapping (e.g., with [`DiscoverableRegistry`]).
     ///
@@ -125,6 +139,7 @@ impl ServerBuilder {
                 description: self.description,
                 resources_path: self.resources_path,
                 resource_defs: self.resource_defs,
+                prompts: self.prompts,
                 services: self.services,
             },
         )
@@ -152,6 +167,10 @@ impl ServerBuilder {
             server = server.with_resources(resources);
         }
 
+        if !parts.prompts.is_empty() {
+            server = server.with_prompts(parts.prompts);
+        }
+
         server
     }
 
@@ -177,6 +196,10 @@ impl ServerBuilder {
             server = server.with_resources(resources);
         }
 
+        if !self.prompts.is_empty() {
+            server = server.with_prompts(self.prompts);
+        }
+
         server
     }
 }
@@ -193,6 +216,8 @@ pub struct ServerBuilderParts {
     pub resources_path: PathBuf,
     /// Static resource definitions.
     pub resource_defs: Vec<StaticResourceDef>,
+    /// Prompt registries.
+    pub prompts: CompositePromptRegistry,
     /// Service handles for health tracking.
     pub services: Vec<fabryk_core::service::ServiceHandle>,
 }
@@ -243,6 +268,33 @@ mod tests {
         assert!(builder.resource_defs.is_empty());
     }
 
+    #[test]
+    fn test_builder_with_prompts() {
+        use crate::file_prompts::{FilePrompts, PromptTemplate};
+        use rmcp::ServerHandler;
+
+        let server = ServerBuilder::new()
+            .name("test")
+            .version("1.0")
+            .with_prompts(FilePrompts::new().with_prompt(PromptTemplate {
+                name: "study_plan".into(),
+                template: "Build a study plan".into(),
+                ..Default::default()
+            }))
+            .build();
+
+        assert!(server.get_info().capabilities.prompts.is_some());
+        assert_eq!(server.list_prompts_inner().prompts.len(), 1);
+    }
+
+    #[test]
+    fn test_builder_without_prompts() {
+        use rmcp::ServerHandler;
+
+        let server = ServerBuilder::new().name("test").build();
+        assert!(server.get_info().capabilities.prompts.is_none());
+    }
+
     #[test]
     fn test_builder_with_resource() {
         let server = ServerBuilder::new()
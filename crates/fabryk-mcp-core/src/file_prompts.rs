@@ -0,0 +1,434 @@
+//! File-backed prompt registry for MCP servers.
+//!
+//! Provides [`FilePrompts`], a [`PromptRegistry`] that serves templated
+//! prompts loaded from a directory of markdown files. Each file declares
+//! its metadata and arguments in YAML frontmatter; the body is the
+//! template, with `{{argument}}` placeholders substituted at render time.
+//!
+//! # File Format
+//!
+//! ```markdown
+//! ---
+//! name: explain_concept
+//! description: Explain a concept at a given tier
+//! arguments:
+//!   - name: concept
+//!     description: Concept to explain
+//!     required: true
+//!   - name: tier
+//!     description: beginner, intermediate or advanced
+//!     default: beginner
+//! ---
+//! Explain {{concept}} for a {{tier}} student.
+//! ```
+//!
+//! `name` defaults to the file stem. Placeholders that don't match a
+//! declared argument are left untouched.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use fabryk_content::markdown::extract_frontmatter;
+use fabryk_core::{Error, Result};
+use rmcp::model::{
+    ErrorData, GetPromptResult, JsonObject, Prompt, PromptArgument, PromptMessage,
+    PromptMessageRole,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::prompt::{PromptFuture, PromptRegistry};
+
+/// Definition of a single prompt argument.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PromptArgumentDef {
+    /// Argument name, used as the `{{name}}` placeholder.
+    pub name: String,
+    /// Argument description shown to clients.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether the caller must supply a value.
+    #[serde(default)]
+    pub required: bool,
+    /// Value used when an optional argument is omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+}
+
+/// A templated prompt.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PromptTemplate {
+    /// MCP prompt name (e.g., `"explain_concept"`).
+    #[serde(default)]
+    pub name: String,
+    /// Optional human-readable title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Prompt description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Declared arguments.
+    #[serde(default)]
+    pub arguments: Vec<PromptArgumentDef>,
+    /// Template text with `{{argument}}` placeholders.
+    #[serde(default)]
+    pub template: String,
+}
+
+impl PromptTemplate {
+    /// Parse a prompt template from markdown with YAML frontmatter.
+    ///
+    /// `default_name` is used when the frontmatter has no `name`.
+    pub fn parse(content: &str, default_name: &str) -> Result<Self> {
+        let fm = extract_frontmatter(content)?;
+        let mut template: PromptTemplate = fm.deserialize()?.unwrap_or_default();
+        if template.name.is_empty() {
+            template.name = default_name.to_string();
+        }
+        template.template = fm.body().trim().to_string();
+        Ok(template)
+    }
+
+    /// Convert to the MCP prompt listing entry.
+    pub fn to_prompt(&self) -> Prompt {
+        let arguments = (!self.arguments.is_empty()).then(|| {
+            self.arguments
+                .iter()
+                .map(|arg| {
+                    let mut out = PromptArgument::new(&arg.name).with_required(arg.required);
+                    if let Some(desc) = &arg.description {
+                        out = out.with_description(desc);
+                    }
+                    out
+                })
+                .collect()
+        });
+        let mut prompt = Prompt::new(&self.name, self.description.as_deref(), arguments);
+        prompt.title = self.title.clone();
+        prompt
+    }
+
+    /// Substitute argument values into the template.
+    ///
+    /// The template is scanned once, so placeholders inside substituted
+    /// values are left as they are rather than filled from other arguments.
+    ///
+    /// Returns an `invalid_params` error if a required argument is missing.
+    pub fn render(&self, arguments: &JsonObject) -> std::result::Result<String, ErrorData> {
+        let mut values = HashMap::new();
+        for arg in &self.arguments {
+            let value = match arguments.get(&arg.name) {
+                Some(Value::String(s)) => s.clone(),
+                Some(Value::Null) | None => match (&arg.default, arg.required) {
+                    (Some(default), _) => default.clone(),
+                    (None, false) => String::new(),
+                    (None, true) => {
+                        return Err(ErrorData::invalid_params(
+                            format!("prompt '{}' requires argument '{}'", self.name, arg.name),
+                            None,
+                        ));
+                    }
+                },
+                Some(other) => other.to_string(),
+            };
+            values.insert(arg.name.as_str(), value);
+        }
+
+        let mut text = String::with_capacity(self.template.len());
+        let mut rest = self.template.as_str();
+        while let Some(start) = rest.find("{{") {
+            let after = &rest[start + 2..];
+            let placeholder = after
+                .find("}}")
+                .and_then(|end| Some((end, values.get(&after[..end])?)));
+            match placeholder {
+                Some((end, value)) => {
+                    text.push_str(&rest[..start]);
+                    text.push_str(value);
+                    rest = &after[end + 2..];
+                }
+                // Not a declared argument: keep the first brace and rescan
+                None => {
+                    text.push_str(&rest[..start + 1]);
+                    rest = &rest[start + 1..];
+                }
+            }
+        }
+        text.push_str(rest);
+        Ok(text)
+    }
+}
+
+/// A [`PromptRegistry`] that serves templated prompts loaded from files.
+///
+/// Each rendered prompt is a single user message containing the
+/// substituted template.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use fabryk_mcp_core::FilePrompts;
+///
+/// let prompts = FilePrompts::load("/path/to/prompts")?;
+/// let server = ServerBuilder::new()
+///     .name("music-theory")
+///     .with_prompts(prompts)
+///     .build();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FilePrompts {
+    templates: Vec<PromptTemplate>,
+}
+
+impl FilePrompts {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load every `*.md` file in `dir` as a prompt template.
+    ///
+    /// Files are loaded in name order. A missing directory yields an
+    /// empty registry.
+    pub fn load(dir: impl AsRef<Path>) -> Result<Self> {
+        let dir = dir.as_ref();
+        if !dir.exists() {
+            log::warn!("Prompt directory not found: {}", dir.display());
+            return Ok(Self::new());
+        }
+
+        let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+            .map_err(|e| Error::io_with_path(e, dir))?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+            .collect();
+        paths.sort();
+
+        let mut prompts = Self::new();
+        for path in paths {
+            let content =
+                std::fs::read_to_string(&path).map_err(|e| Error::io_with_path(e, &path))?;
+            let stem = path
+                .file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            prompts = prompts.with_prompt(PromptTemplate::parse(&content, &stem)?);
+        }
+        log::debug!(
+            "Loaded {} prompts from {}",
+            prompts.templates.len(),
+            dir.display()
+        );
+        Ok(prompts)
+    }
+
+    /// Add a prompt template. Returns self for chaining.
+    ///
+    /// A template with the same name as an existing one replaces it.
+    pub fn with_prompt(mut self, template: PromptTemplate) -> Self {
+        self.templates.retain(|t| t.name != template.name);
+        self.templates.push(template);
+        self
+    }
+}
+
+impl PromptRegistry for FilePrompts {
+    fn prompts(&self) -> Vec<Prompt> {
+        self.templates
+            .iter()
+            .map(PromptTemplate::to_prompt)
+            .collect()
+    }
+
+    fn get(&self, name: &str, arguments: JsonObject) -> Option<PromptFuture> {
+        let template = self.templates.iter().find(|t| t.name == name)?;
+        let rendered = template.render(&arguments);
+        let description = template.description.clone();
+
+        Some(Box::pin(async move {
+            let message = PromptMessage::new_text(PromptMessageRole::User, rendered?);
+            let mut result = GetPromptResult::new(vec![message]);
+            if let Some(desc) = description {
+                result = result.with_description(desc);
+            }
+            Ok(result)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rmcp::model::{ErrorCode, PromptMessageContent};
+
+    const EXPLAIN: &str = "---
+name: explain_concept
+title: Explain a concept
+description: Explain a concept at a given tier
+arguments:
+  - name: concept
+    description: Concept to explain
+    required: true
+  - name: tier
+    default: beginner
+---
+
+Explain {{concept}} for a {{tier}} student.
+";
+
+    fn args(pairs: &[(&str, &str)]) -> JsonObject {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), Value::String(v.to_string())))
+            .collect()
+    }
+
+    fn text(result: &GetPromptResult) -> &str {
+        match &result.messages[0].content {
+            PromptMessageContent::Text { text } => text,
+            _ => panic!("Expected text content"),
+        }
+    }
+
+    #[test]
+    fn test_parse_template() {
+        let t = PromptTemplate::parse(EXPLAIN, "fallback").unwrap();
+        assert_eq!(t.name, "explain_concept");
+        assert_eq!(t.title.as_deref(), Some("Explain a concept"));
+        assert_eq!(t.arguments.len(), 2);
+        assert!(t.arguments[0].required);
+        assert_eq!(t.arguments[1].default.as_deref(), Some("beginner"));
+        assert_eq!(t.template, "Explain {{concept}} for a {{tier}} student.");
+    }
+
+    #[test]
+    fn test_parse_without_frontmatter_uses_default_name() {
+        let t = PromptTemplate::parse("Build a study plan.", "study_plan").unwrap();
+        assert_eq!(t.name, "study_plan");
+        assert!(t.arguments.is_empty());
+        assert_eq!(t.template, "Build a study plan.");
+    }
+
+    #[test]
+    fn test_to_prompt_arguments() {
+        let prompt = PromptTemplate::parse(EXPLAIN, "x").unwrap().to_prompt();
+        assert_eq!(prompt.name, "explain_concept");
+        let arguments = prompt.arguments.unwrap();
+        assert_eq!(arguments[0].name, "concept");
+        assert_eq!(arguments[0].required, Some(true));
+        assert_eq!(
+            arguments[0].description.as_deref(),
+            Some("Concept to explain")
+        );
+        assert_eq!(arguments[1].required, Some(false));
+    }
+
+    #[test]
+    fn test_render_with_default_and_missing_required() {
+        let t = PromptTemplate::parse(EXPLAIN, "x").unwrap();
+        assert_eq!(
+            t.render(&args(&[("concept", "voice leading")])).unwrap(),
+            "Explain voice leading for a beginner student."
+        );
+        assert_eq!(
+            t.render(&args(&[("concept", "cadences"), ("tier", "advanced")]))
+                .unwrap(),
+            "Explain cadences for a advanced student."
+        );
+        let err = t.render(&JsonObject::new()).unwrap_err();
+        assert_eq!(err.code, ErrorCode::INVALID_PARAMS);
+        assert!(err.message.contains("concept"));
+    }
+
+    #[test]
+    fn test_render_leaves_undeclared_placeholders() {
+        let t = PromptTemplate {
+            name: "raw".into(),
+            template: "Keep {{this}} as is".into(),
+            ..Default::default()
+        };
+        assert_eq!(t.render(&JsonObject::new()).unwrap(), "Keep {{this}} as is");
+    }
+
+    #[test]
+    fn test_render_does_not_substitute_into_values() {
+        let t = PromptTemplate::parse(EXPLAIN, "x").unwrap();
+        assert_eq!(
+            t.render(&args(&[("concept", "{{tier}}"), ("tier", "advanced")]))
+                .unwrap(),
+            "Explain {{tier}} for a advanced student."
+        );
+        assert_eq!(
+            t.render(&args(&[("concept", "modes"), ("tier", "{{concept}}")]))
+                .unwrap(),
+            "Explain modes for a {{concept}} student."
+        );
+
+        let braced = PromptTemplate {
+            template: "{{{concept}}}".into(),
+            ..t
+        };
+        assert_eq!(
+            braced.render(&args(&[("concept", "modes")])).unwrap(),
+            "{modes}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("explain.md"), EXPLAIN).unwrap();
+        std::fs::write(dir.path().join("study_plan.md"), "Plan a week of study.").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+
+        let prompts = FilePrompts::load(dir.path()).unwrap();
+        let names: Vec<String> = prompts.prompts().into_iter().map(|p| p.name).collect();
+        assert_eq!(names, vec!["explain_concept", "study_plan"]);
+
+        let result = prompts
+            .get("explain_concept", args(&[("concept", "modes")]))
+            .unwrap()
+            .await
+            .unwrap();
+        assert_eq!(
+            result.description.as_deref(),
+            Some("Explain a concept at a given tier")
+        );
+        assert_eq!(text(&result), "Explain modes for a beginner student.");
+        assert_eq!(result.messages[0].role, PromptMessageRole::User);
+    }
+
+    #[test]
+    fn test_load_missing_directory_is_empty() {
+        let prompts = FilePrompts::load("/nonexistent/prompts").unwrap();
+        assert!(prompts.prompts().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_missing_required_argument_errors() {
+        let prompts = FilePrompts::new().with_prompt(PromptTemplate::parse(EXPLAIN, "x").unwrap());
+        let result = prompts
+            .get("explain_concept", JsonObject::new())
+            .unwrap()
+            .await;
+        assert!(result.is_err());
+        assert!(prompts.get("unknown", JsonObject::new()).is_none());
+    }
+
+    #[test]
+    fn test_with_prompt_replaces_same_name() {
+        let prompts = FilePrompts::new()
+            .with_prompt(PromptTemplate {
+                name: "a".into(),
+                template: "one".into(),
+                ..Default::default()
+            })
+            .with_prompt(PromptTemplate {
+                name: "a".into(),
+                template: "two".into(),
+                ..Default::default()
+            });
+        assert_eq!(prompts.prompts().len(), 1);
+        assert_eq!(prompts.templates[0].template, "two");
+    }
+}
@@ -14,7 +14,8 @@
 //!     .context("Galactic copilot for No Man's Sky")
 //!     .workflow("Call where_am_i to establish location")
 //!     .convention("Distances are in light-years")
-//!     .subscribe("nms://player/location", "Live warp tracking");
+//!     .subscribe("nms://player/location", "Live warp tracking")
+//!     .prompt("plan_route", "When the player asks how to reach a system");
 //! ```
 
 use crate::discoverable::{ExternalConnector, ToolMeta};
@@ -41,6 +42,8 @@ pub struct ServerGuidance {
     pub data_freshness: HashMap<String, String>,
     /// Recommended resource subscriptions: `(uri, reason)`.
     pub recommended_subscriptions: Vec<(String, String)>,
+    /// Prompts worth offering to the user: `(name, when_to_use)`.
+    pub recommended_prompts: Vec<(String, String)>,
 }
 
 impl ServerGuidance {
@@ -65,6 +68,13 @@ impl ServerGuidance {
         self
     }
 
+    /// Add a recommended prompt.
+    pub fn prompt(mut self, name: impl Into<String>, when_to_use: impl Into<String>) -> Self {
+        self.recommended_prompts
+            .push((name.into(), when_to_use.into()));
+        self
+    }
+
     /// Add a workflow step.
     pub fn workflow(mut self, step: impl Into<String>) -> Self {
         self.workflow.push(step.into());
@@ -111,8 +121,8 @@ impl ServerGuidance {
 
     /// Generate the `ServerInfo.instructions` text.
     ///
-    /// Includes the directory-first directive, context, subscription
-    /// recommendations, and conventions.
+    /// Includes the directory-first directive, context, subscription and
+    /// prompt recommendations, and conventions.
     pub fn to_instructions(&self) -> String {
         let mut parts = Vec::new();
 
@@ -138,6 +148,16 @@ impl ServerGuidance {
             parts.push(format!("Recommended subscriptions:\n{}", subs.join("\n")));
         }
 
+        // Prompt recommendations
+        if !self.recommended_prompts.is_empty() {
+            let prompts: Vec<String> = self
+                .recommended_prompts
+                .iter()
+                .map(|(name, when)| format!("- {name}: {when}"))
+                .collect();
+            parts.push(format!("Available prompts:\n{}", prompts.join("\n")));
+        }
+
         // Conventions
         if !self.conventions.is_empty() {
             let convs: Vec<String> = self.conventions.iter().map(|c| format!("- {c}")).collect();
@@ -216,6 +236,14 @@ mod tests {
         assert!(text.contains("nms://player: Live tracking"));
     }
 
+    #[test]
+    fn test_to_instructions_includes_prompts() {
+        let g = ServerGuidance::for_domain("nms").prompt("plan_route", "Planning a journey");
+        let text = g.to_instructions();
+        assert!(text.contains("Available prompts:"));
+        assert!(text.contains("plan_route: Planning a journey"));
+    }
+
     #[test]
     fn test_to_instructions_includes_conventions() {
         let g = ServerGuidance::for_domain("nms").convention("Distances in light-years");
@@ -237,6 +265,7 @@ mod tests {
         let g = ServerGuidance::for_domain("nms");
         let text = g.to_instructions();
         assert!(!text.contains("Recommended subscriptions:"));
+        assert!(!text.contains("Available prompts:"));
         assert!(!text.contains("Conventions:"));
         assert!(!text.contains("Constraints:"));
     }
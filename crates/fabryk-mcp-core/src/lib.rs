@@ -11,6 +11,8 @@
 //! ├─────────────────────────────────────────────────────────────┤
 //! │  ToolRegistry trait — tool registration and dispatch        │
 //! │  CompositeRegistry — combine multiple tool sources          │
+//! │  ToolAccess trait — per-caller tool visibility              │
+//! │  PromptRegistry trait — templated prompts (prompts/get)     │
 //! ├─────────────────────────────────────────────────────────────┤
 //! │  FabrykMcpServer — generic server (implements ServerHandler)│
 //! │  ServerConfig — server metadata (name, version, description)│
@@ -44,14 +46,17 @@
 //!     .await?;
 //! ```
 
+pub mod access;
 pub mod builder;
 pub mod discoverable;
 pub mod error;
+pub mod file_prompts;
 pub mod guidance;
 #[cfg(feature = "http")]
 pub mod health_router;
 pub mod helpers;
 pub mod notifier;
+pub mod prompt;
 pub mod registry;
 pub mod resource;
 pub mod search_fallback;
@@ -61,6 +66,9 @@ pub mod static_resources;
 pub mod tools;
 pub mod validate;
 
+// Re-exports — access control
+pub use access::ToolAccess;
+
 // Re-exports — registry
 pub use registry::{CompositeRegistry, ToolRegistry, ToolResult};
 
@@ -71,7 +79,7 @@ pub use service_registry::ServiceAwareRegistry;
 pub use server::{FabrykMcpServer, ServerConfig};
 
 // Re-exports — notifier
-pub use notifier::Notifier;
+pub use notifier::{Notifier, ResourceNotifier, ResourceUriMapper};
 
 // Re-exports — builder
 pub use builder::{ServerBuilder, ServerBuilderParts};
@@ -100,6 +108,12 @@ pub use resource::{ResourceFuture, ResourceRegistry};
 // Re-exports — static resources
 pub use static_resources::{StaticResourceDef, StaticResources};
 
+// Re-exports — prompt registry
+pub use prompt::{CompositePromptRegistry, PromptFuture, PromptRegistry};
+
+// Re-exports — file-backed prompts
+pub use file_prompts::{FilePrompts, PromptArgumentDef, PromptTemplate};
+
 // Re-exports — built-in tools
 pub use tools::{
     BackendInfo, BackendsInfo, DiagnosticTools, FieldBoosts, HealthResponse, HealthTools,
@@ -113,8 +127,9 @@ pub use validate::{assert_tools_valid, validate_tools, warn_on_invalid_tools};
 pub mod model {
     //! Re-exported rmcp model types.
     pub use rmcp::model::{
-        AnnotateAble, Annotated, CallToolResult, Content, ErrorCode, ErrorData, LoggingLevel,
-        RawResource, Resource, ResourceContents, Tool,
+        AnnotateAble, Annotated, CallToolResult, Content, ErrorCode, ErrorData, Extensions,
+        GetPromptResult, JsonObject, LoggingLevel, Prompt, PromptArgument, PromptMessage,
+        PromptMessageContent, PromptMessageRole, RawResource, Resource, ResourceContents, Tool,
     };
 }
 
@@ -3,10 +3,17 @@
 //! The [`Notifier`] collects `Peer<RoleServer>` handles from connected MCP
 //! clients and broadcasts notifications to all of them. Dead peers are
 //! automatically pruned on each broadcast.
+//!
+//! [`ResourceNotifier`] plugs the notifier into a content watcher so
+//! clients hear about content edits as soon as they are live.
 
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Arc;
 
+use async_trait::async_trait;
+use fabryk_core::{ChangeHandler, ChangeKind, ChangeSet};
+
 use rmcp::RoleServer;
 use rmcp::model::{
     LoggingLevel, LoggingMessageNotificationParam, ResourceUpdatedNotificationParam,
@@ -177,6 +184,91 @@ impl Notifier {
     }
 }
 
+/// Maps a changed content file to the resource URI that exposes it.
+pub type ResourceUriMapper = Box<dyn Fn(&Path) -> Option<String> + Send + Sync>;
+
+/// A [`ChangeHandler`] that tells clients when watched content changed.
+///
+/// Register it after the backend updaters on a
+/// [`ContentWatcher`](fabryk_core::ContentWatcher) so clients re-read
+/// resources only once the new data is live. Each change set sends
+/// `resources/updated` for the configured URIs and for every changed file
+/// the mapper recognizes, and `resources/list_changed` when files were
+/// added or removed.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let notifier = ResourceNotifier::new(server.notifier())
+///     .with_resource("music-theory://graph")
+///     .with_uri_mapper(|path| {
+///         id_from_path(path).map(|id| format!("music-theory://concepts/{id}"))
+///     });
+/// ```
+pub struct ResourceNotifier {
+    notifier: Notifier,
+    uris: Vec<String>,
+    mapper: Option<ResourceUriMapper>,
+}
+
+impl ResourceNotifier {
+    /// Create a handler broadcasting through `notifier`.
+    pub fn new(notifier: Notifier) -> Self {
+        Self {
+            notifier,
+            uris: Vec::new(),
+            mapper: None,
+        }
+    }
+
+    /// Notify this resource URI on every change set.
+    pub fn with_resource(mut self, uri: impl Into<String>) -> Self {
+        self.uris.push(uri.into());
+        self
+    }
+
+    /// Derive a resource URI for each changed file.
+    pub fn with_uri_mapper(
+        mut self,
+        mapper: impl Fn(&Path) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.mapper = Some(Box::new(mapper));
+        self
+    }
+
+    /// Resource URIs affected by a change set, without duplicates.
+    fn updated_uris(&self, changes: &ChangeSet) -> Vec<String> {
+        let mut uris = self.uris.clone();
+        if let Some(ref mapper) = self.mapper {
+            for (path, _) in changes.iter() {
+                if let Some(uri) = mapper(path)
+                    && !uris.contains(&uri)
+                {
+                    uris.push(uri);
+                }
+            }
+        }
+        uris
+    }
+}
+
+#[async_trait]
+impl ChangeHandler for ResourceNotifier {
+    fn name(&self) -> &str {
+        "resource-notifier"
+    }
+
+    async fn apply(&self, changes: &ChangeSet) -> fabryk_core::Result<()> {
+        for uri in self.updated_uris(changes) {
+            self.notifier.resource_updated(uri).await;
+        }
+        if changes.iter().any(|(_, kind)| kind != ChangeKind::Modified) {
+            self.notifier.resource_list_changed().await;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -235,6 +327,34 @@ mod tests {
         n2.unsubscribe_resource("test://uri").await;
     }
 
+    #[test]
+    fn test_resource_notifier_updated_uris() {
+        let handler = ResourceNotifier::new(Notifier::new())
+            .with_resource("test://graph")
+            .with_uri_mapper(|path| {
+                path.file_stem()
+                    .map(|s| format!("test://concepts/{}", s.to_string_lossy()))
+            });
+
+        let mut changes = ChangeSet::new();
+        changes.record("/content/a.md", ChangeKind::Modified);
+        changes.record("/content/b.md", ChangeKind::Removed);
+        changes.record("/other/a.md", ChangeKind::Created);
+
+        assert_eq!(
+            handler.updated_uris(&changes),
+            vec!["test://graph", "test://concepts/a", "test://concepts/b"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resource_notifier_apply_with_no_peers() {
+        let handler = ResourceNotifier::new(Notifier::new()).with_resource("test://graph");
+        let mut changes = ChangeSet::new();
+        changes.record("a.md", ChangeKind::Created);
+        assert!(handler.apply(&changes).await.is_ok());
+    }
+
     #[tokio::test]
     async fn test_unsubscribe_resource_removes_uri() {
         let notifier = Notifier::new();
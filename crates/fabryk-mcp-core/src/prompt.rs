@@ -0,0 +1,194 @@
+//! Prompt registry trait for MCP servers.
+//!
+//! The [`PromptRegistry`] trait abstracts over prompt listing and rendering,
+//! allowing domains to ship reusable prompt templates (e.g., "explain
+//! concept X at tier Y") alongside their tools.
+//!
+//! The [`CompositePromptRegistry`] combines multiple prompt sources into one.
+
+use rmcp::model::{ErrorData, GetPromptResult, JsonObject, Prompt};
+use std::future::Future;
+use std::pin::Pin;
+
+/// Type alias for async prompt rendering results.
+pub type PromptFuture = Pin<Box<dyn Future<Output = Result<GetPromptResult, ErrorData>> + Send>>;
+
+/// Trait for registering and rendering MCP prompts.
+///
+/// Each prompt advertises its arguments (name, description, whether it is
+/// required) via [`Prompt::arguments`], which clients use to collect
+/// values before calling `prompts/get`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// struct MyPrompts;
+///
+/// impl PromptRegistry for MyPrompts {
+///     fn prompts(&self) -> Vec<Prompt> {
+///         vec![/* prompt definitions */]
+///     }
+///
+///     fn get(&self, name: &str, arguments: JsonObject) -> Option<PromptFuture> {
+///         match name {
+///             "study_plan" => Some(Box::pin(async move { /* ... */ })),
+///             _ => None,
+///         }
+///     }
+/// }
+/// ```
+pub trait PromptRegistry: Send + Sync {
+    /// Returns all available prompts.
+    fn prompts(&self) -> Vec<Prompt>;
+
+    /// Render a prompt by name with the given arguments.
+    ///
+    /// Returns `None` if the prompt is not recognized by this registry.
+    fn get(&self, name: &str, arguments: JsonObject) -> Option<PromptFuture>;
+
+    /// Returns the number of registered prompts.
+    fn prompt_count(&self) -> usize {
+        self.prompts().len()
+    }
+
+    /// Check if a prompt exists by name.
+    fn has_prompt(&self, name: &str) -> bool {
+        self.prompts().iter().any(|p| p.name == name)
+    }
+}
+
+/// A prompt registry that combines multiple sub-registries.
+///
+/// Prompts are listed in registration order, and `get` is dispatched to
+/// the first registry that recognizes the name.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let prompts = CompositePromptRegistry::new()
+///     .add(FilePrompts::load("prompts")?)
+///     .add(my_dynamic_prompts);
+/// ```
+#[derive(Default)]
+pub struct CompositePromptRegistry {
+    registries: Vec<Box<dyn PromptRegistry>>,
+}
+
+impl CompositePromptRegistry {
+    /// Create a new empty composite prompt registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a sub-registry.
+    #[allow(clippy::should_implement_trait)]
+    pub fn add<R: PromptRegistry + 'static>(mut self, registry: R) -> Self {
+        self.registries.push(Box::new(registry));
+        self
+    }
+
+    /// Returns `true` if no sub-registries have been added.
+    pub fn is_empty(&self) -> bool {
+        self.registries.is_empty()
+    }
+}
+
+impl PromptRegistry for CompositePromptRegistry {
+    fn prompts(&self) -> Vec<Prompt> {
+        self.registries.iter().flat_map(|r| r.prompts()).collect()
+    }
+
+    fn get(&self, name: &str, arguments: JsonObject) -> Option<PromptFuture> {
+        self.registries
+            .iter()
+            .find_map(|r| r.get(name, arguments.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rmcp::model::{PromptArgument, PromptMessage, PromptMessageRole};
+
+    /// Verify `PromptRegistry` is object-safe.
+    #[test]
+    fn test_trait_object_safety() {
+        fn _assert_object_safe(_: &dyn PromptRegistry) {}
+    }
+
+    struct TestPrompts {
+        name: &'static str,
+    }
+
+    impl PromptRegistry for TestPrompts {
+        fn prompts(&self) -> Vec<Prompt> {
+            vec![Prompt::new(
+                self.name,
+                Some("A test prompt"),
+                Some(vec![PromptArgument::new("topic").with_required(true)]),
+            )]
+        }
+
+        fn get(&self, name: &str, arguments: JsonObject) -> Option<PromptFuture> {
+            if name != self.name {
+                return None;
+            }
+            let topic = arguments
+                .get("topic")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string();
+            Some(Box::pin(async move {
+                Ok(GetPromptResult::new(vec![PromptMessage::new_text(
+                    PromptMessageRole::User,
+                    format!("Tell me about {topic}"),
+                )]))
+            }))
+        }
+    }
+
+    #[test]
+    fn test_prompt_count_and_has_prompt() {
+        let registry = TestPrompts { name: "explain" };
+        assert_eq!(registry.prompt_count(), 1);
+        assert!(registry.has_prompt("explain"));
+        assert!(!registry.has_prompt("unknown"));
+    }
+
+    #[test]
+    fn test_composite_empty() {
+        let registry = CompositePromptRegistry::new();
+        assert!(registry.is_empty());
+        assert!(registry.prompts().is_empty());
+        assert!(registry.get("explain", JsonObject::new()).is_none());
+    }
+
+    #[test]
+    fn test_composite_lists_all_prompts() {
+        let registry = CompositePromptRegistry::new()
+            .add(TestPrompts { name: "explain" })
+            .add(TestPrompts { name: "study_plan" });
+        assert!(!registry.is_empty());
+        let names: Vec<String> = registry.prompts().into_iter().map(|p| p.name).collect();
+        assert_eq!(names, vec!["explain", "study_plan"]);
+    }
+
+    #[tokio::test]
+    async fn test_composite_dispatches_to_owner() {
+        let registry = CompositePromptRegistry::new()
+            .add(TestPrompts { name: "explain" })
+            .add(TestPrompts { name: "study_plan" });
+
+        let mut args = JsonObject::new();
+        args.insert("topic".into(), "cadences".into());
+        let result = registry.get("study_plan", args).unwrap().await.unwrap();
+        assert_eq!(result.messages.len(), 1);
+        assert_eq!(
+            result.messages[0].content,
+            rmcp::model::PromptMessageContent::Text {
+                text: "Tell me about cadences".to_string()
+            }
+        );
+        assert!(registry.get("unknown", JsonObject::new()).is_none());
+    }
+}
@@ -4,12 +4,15 @@
 //! tool dispatch. The server implements rmcp's `ServerHandler` trait,
 //! delegating tool listing and dispatch to the registry.
 
+use crate::access::{Extensions, ToolAccess};
 use crate::notifier::Notifier;
+use crate::prompt::PromptRegistry;
 use crate::registry::ToolRegistry;
 use crate::resource::ResourceRegistry;
 use fabryk_core::service::{ServiceHandle, ServiceState};
 use rmcp::model::{
-    CallToolResult, Content, ErrorData, Implementation, ListResourcesResult, ProtocolVersion,
+    CallToolResult, Content, ErrorData, GetPromptRequestParams, GetPromptResult, Implementation,
+    JsonObject, ListPromptsResult, ListResourcesResult, PromptsCapability, ProtocolVersion,
     ReadResourceRequestParams, ReadResourceResult, ServerCapabilities, ServerInfo,
     SubscribeRequestParams, UnsubscribeRequestParams,
 };
@@ -78,6 +81,8 @@ pub struct FabrykMcpServer {
     services: Vec<ServiceHandle>,
     notifier: Notifier,
     resource_registry: Option<Arc<dyn ResourceRegistry>>,
+    prompt_registry: Option<Arc<dyn PromptRegistry>>,
+    tool_access: Option<Arc<dyn ToolAccess>>,
 }
 
 impl FabrykMcpServer {
@@ -89,6 +94,8 @@ impl FabrykMcpServer {
             services: Vec::new(),
             notifier: Notifier::new(),
             resource_registry: None,
+            prompt_registry: None,
+            tool_access: None,
         }
     }
 
@@ -177,6 +184,23 @@ impl FabrykMcpServer {
         self
     }
 
+    /// Register a prompt registry for MCP prompt support.
+    ///
+    /// Enables `prompts/list` and `prompts/get` in the server capabilities.
+    pub fn with_prompts<P: PromptRegistry + 'static>(mut self, registry: P) -> Self {
+        self.prompt_registry = Some(Arc::new(registry));
+        self
+    }
+
+    /// Install a per-caller tool access policy.
+    ///
+    /// Tools the caller may not use are hidden from `tools/list`, and
+    /// calls to them return an error result without reaching the registry.
+    pub fn with_tool_access<A: ToolAccess + 'static>(mut self, access: A) -> Self {
+        self.tool_access = Some(Arc::new(access));
+        self
+    }
+
     /// Get the server configuration.
     pub fn config(&self) -> &ServerConfig {
         &self.config
@@ -292,9 +316,16 @@ impl FabrykMcpServer {
 // Helper methods extracted for testability (ServerHandler methods need
 // RequestContext which is difficult to construct in tests).
 impl FabrykMcpServer {
-    /// List all tools from the registry.
-    pub(crate) fn list_tools_inner(&self) -> Vec<rmcp::model::Tool> {
-        self.registry.tools()
+    /// List the tools from the registry that the caller may use.
+    pub(crate) fn list_tools_inner(&self, extensions: &Extensions) -> Vec<rmcp::model::Tool> {
+        let tools = self.registry.tools();
+        match &self.tool_access {
+            Some(access) => tools
+                .into_iter()
+                .filter(|t| access.allows(&t.name, extensions))
+                .collect(),
+            None => tools,
+        }
     }
 
     /// Call a tool by name with the given arguments.
@@ -302,9 +333,21 @@ impl FabrykMcpServer {
         &self,
         name: &str,
         args: serde_json::Value,
+        extensions: &Extensions,
     ) -> Result<CallToolResult, ErrorData> {
+        if let Some(access) = &self.tool_access
+            && !access.allows(name, extensions)
+        {
+            log::warn!("Denied call to tool '{name}'");
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "Access denied: tool '{name}' is not available to this caller"
+            ))]));
+        }
         match self.registry.call(name, args) {
-            Some(future) => future.await,
+            Some(future) => match &self.tool_access {
+                Some(access) => access.scope(future, extensions).await,
+                None => future.await,
+            },
             None => Ok(CallToolResult::error(vec![Content::text(format!(
                 "Unknown tool: {name}"
             ))])),
@@ -340,11 +383,39 @@ impl FabrykMcpServer {
             )),
         }
     }
+
+    /// List all prompts from the prompt registry.
+    pub(crate) fn list_prompts_inner(&self) -> ListPromptsResult {
+        match &self.prompt_registry {
+            Some(registry) => ListPromptsResult::with_all_items(registry.prompts()),
+            None => ListPromptsResult::default(),
+        }
+    }
+
+    /// Render a prompt by name.
+    pub(crate) async fn get_prompt_inner(
+        &self,
+        name: &str,
+        arguments: JsonObject,
+    ) -> Result<GetPromptResult, ErrorData> {
+        let registry = self
+            .prompt_registry
+            .as_ref()
+            .ok_or_else(|| ErrorData::invalid_params("Prompts not enabled", None))?;
+
+        match registry.get(name, arguments) {
+            Some(future) => future.await,
+            None => Err(ErrorData::invalid_params(
+                format!("Unknown prompt: {name}"),
+                None,
+            )),
+        }
+    }
 }
 
 impl ServerHandler for FabrykMcpServer {
     fn get_info(&self) -> ServerInfo {
-        let capabilities = if self.resource_registry.is_some() {
+        let mut capabilities = if self.resource_registry.is_some() {
             ServerCapabilities::builder()
                 .enable_tools()
                 .enable_logging()
@@ -358,6 +429,9 @@ impl ServerHandler for FabrykMcpServer {
                 .enable_logging()
                 .build()
         };
+        if self.prompt_registry.is_some() {
+            capabilities.prompts = Some(PromptsCapability::default());
+        }
 
         let mut info = ServerInfo::new(capabilities)
             .with_protocol_version(ProtocolVersion::LATEST)
@@ -388,10 +462,10 @@ impl ServerHandler for FabrykMcpServer {
     fn list_tools(
         &self,
         _request: Option<rmcp::model::PaginatedRequestParams>,
-        _context: RequestContext<RoleServer>,
+        context: RequestContext<RoleServer>,
     ) -> impl std::future::Future<Output = Result<rmcp::model::ListToolsResult, ErrorData>> + Send + '_
     {
-        let tools = self.list_tools_inner();
+        let tools = self.list_tools_inner(&context.extensions);
         async move {
             Ok(rmcp::model::ListToolsResult {
                 tools,
@@ -404,7 +478,7 @@ impl ServerHandler for FabrykMcpServer {
     fn call_tool(
         &self,
         request: rmcp::model::CallToolRequestParams,
-        _context: RequestContext<RoleServer>,
+        context: RequestContext<RoleServer>,
     ) -> impl std::future::Future<Output = Result<CallToolResult, ErrorData>> + Send + '_ {
         let name = request.name.to_string();
         let args = request
@@ -412,7 +486,7 @@ impl ServerHandler for FabrykMcpServer {
             .map(serde_json::Value::Object)
             .unwrap_or(serde_json::Value::Null);
 
-        async move { self.call_tool_inner(&name, args).await }
+        async move { self.call_tool_inner(&name, args, &context.extensions).await }
     }
 
     #[allow(clippy::manual_async_fn)]
@@ -433,6 +507,27 @@ impl ServerHandler for FabrykMcpServer {
         async move { self.read_resource_inner(&request.uri).await }
     }
 
+    #[allow(clippy::manual_async_fn)]
+    fn list_prompts(
+        &self,
+        _request: Option<rmcp::model::PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> impl std::future::Future<Output = Result<ListPromptsResult, ErrorData>> + Send + '_ {
+        async move { Ok(self.list_prompts_inner()) }
+    }
+
+    #[allow(clippy::manual_async_fn)]
+    fn get_prompt(
+        &self,
+        request: GetPromptRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> impl std::future::Future<Output = Result<GetPromptResult, ErrorData>> + Send + '_ {
+        async move {
+            self.get_prompt_inner(&request.name, request.arguments.unwrap_or_default())
+                .await
+        }
+    }
+
     #[allow(clippy::manual_async_fn)]
     fn subscribe(
         &self,
@@ -748,7 +843,7 @@ mod tests {
     #[test]
     fn test_list_tools_inner_returns_tools() {
         let server = FabrykMcpServer::new(MockRegistry);
-        let tools = server.list_tools_inner();
+        let tools = server.list_tools_inner(&Extensions::new());
         assert_eq!(tools.len(), 1);
         assert_eq!(tools[0].name.to_string(), "test_tool");
     }
@@ -756,7 +851,7 @@ mod tests {
     #[test]
     fn test_list_tools_inner_empty_registry() {
         let server = FabrykMcpServer::new(CompositeRegistry::new());
-        let tools = server.list_tools_inner();
+        let tools = server.list_tools_inner(&Extensions::new());
         assert!(tools.is_empty());
     }
 
@@ -764,7 +859,7 @@ mod tests {
     async fn test_call_tool_inner_known_tool() {
         let server = FabrykMcpServer::new(MockRegistry);
         let result = server
-            .call_tool_inner("test_tool", serde_json::Value::Null)
+            .call_tool_inner("test_tool", serde_json::Value::Null, &Extensions::new())
             .await
             .unwrap();
         assert!(!result.is_error.unwrap_or(false));
@@ -774,7 +869,7 @@ mod tests {
     async fn test_call_tool_inner_unknown_tool() {
         let server = FabrykMcpServer::new(MockRegistry);
         let result = server
-            .call_tool_inner("nonexistent", serde_json::Value::Null)
+            .call_tool_inner("nonexistent", serde_json::Value::Null, &Extensions::new())
             .await
             .unwrap();
         // Unknown tools return an error result (not an Err)
@@ -782,6 +877,48 @@ mod tests {
         assert!(format!("{text:?}").contains("Unknown tool"));
     }
 
+    /// Allows tools only when the request carries a `Caller("admin")`.
+    struct AdminOnly;
+
+    #[derive(Clone)]
+    struct Caller(&'static str);
+
+    impl ToolAccess for AdminOnly {
+        fn allows(&self, _tool: &str, extensions: &Extensions) -> bool {
+            extensions.get::<Caller>().is_some_and(|c| c.0 == "admin")
+        }
+    }
+
+    fn admin_extensions() -> Extensions {
+        let mut extensions = Extensions::new();
+        extensions.insert(Caller("admin"));
+        extensions
+    }
+
+    #[test]
+    fn test_list_tools_inner_hides_denied_tools() {
+        let server = FabrykMcpServer::new(MockRegistry).with_tool_access(AdminOnly);
+        assert!(server.list_tools_inner(&Extensions::new()).is_empty());
+        assert_eq!(server.list_tools_inner(&admin_extensions()).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_call_tool_inner_denies_without_access() {
+        let server = FabrykMcpServer::new(MockRegistry).with_tool_access(AdminOnly);
+        let denied = server
+            .call_tool_inner("test_tool", serde_json::Value::Null, &Extensions::new())
+            .await
+            .unwrap();
+        assert_eq!(denied.is_error, Some(true));
+        assert!(format!("{:?}", denied.content[0]).contains("Access denied"));
+
+        let allowed = server
+            .call_tool_inner("test_tool", serde_json::Value::Null, &admin_extensions())
+            .await
+            .unwrap();
+        assert_eq!(allowed.is_error, Some(false));
+    }
+
     #[test]
     fn test_list_resources_inner_no_registry() {
         let server = FabrykMcpServer::new(CompositeRegistry::new());
@@ -881,6 +1018,66 @@ mod tests {
         assert_eq!(errors.len(), 1);
         assert!(errors[0].contains("not ready after"));
     }
+
+    // -- Prompts ------------------------------------------------------------
+
+    fn explain_prompts() -> crate::file_prompts::FilePrompts {
+        crate::file_prompts::FilePrompts::new().with_prompt(crate::file_prompts::PromptTemplate {
+            name: "explain".into(),
+            description: Some("Explain a topic".into()),
+            arguments: vec![crate::file_prompts::PromptArgumentDef {
+                name: "topic".into(),
+                required: true,
+                ..Default::default()
+            }],
+            template: "Explain {{topic}}".into(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_get_info_without_prompts() {
+        let server = FabrykMcpServer::new(MockRegistry);
+        assert!(server.get_info().capabilities.prompts.is_none());
+        assert!(server.list_prompts_inner().prompts.is_empty());
+    }
+
+    #[test]
+    fn test_get_info_with_prompts() {
+        let server = FabrykMcpServer::new(MockRegistry).with_prompts(explain_prompts());
+        let info = server.get_info();
+        assert!(info.capabilities.prompts.is_some());
+        assert!(info.capabilities.tools.is_some());
+        assert_eq!(server.list_prompts_inner().prompts[0].name, "explain");
+    }
+
+    #[tokio::test]
+    async fn test_get_prompt_inner() {
+        let server = FabrykMcpServer::new(MockRegistry).with_prompts(explain_prompts());
+
+        let mut args = JsonObject::new();
+        args.insert("topic".into(), "modulation".into());
+        let result = server.get_prompt_inner("explain", args).await.unwrap();
+        assert_eq!(
+            result.messages[0].content,
+            rmcp::model::PromptMessageContent::Text {
+                text: "Explain modulation".to_string()
+            }
+        );
+
+        let err = server
+            .get_prompt_inner("missing", JsonObject::new())
+            .await
+            .unwrap_err();
+        assert!(err.message.contains("Unknown prompt: missing"));
+    }
+
+    #[tokio::test]
+    async fn test_get_prompt_inner_no_registry() {
+        let server = FabrykMcpServer::new(MockRegistry);
+        let result = server.get_prompt_inner("explain", JsonObject::new()).await;
+        assert!(result.is_err());
+    }
 }
 
 #[cfg(all(test, feature = "http"))]
@@ -4,7 +4,8 @@
 //!
 //! # Tools
 //!
-//! - `search` — full-text search with category/source filtering
+//! - `search` — full-text search with category/source filtering and
+//!   optional reranking
 //! - `search_status` — search backend availability
 //!
 //! # Example
@@ -8,10 +8,11 @@ use fabryk_mcp_core::helpers::{make_tool, serialize_response};
 use fabryk_mcp_core::model::{ErrorData, Tool};
 use fabryk_mcp_core::registry::{ToolRegistry, ToolResult};
 
-use fabryk_fts::{SearchBackend, SearchParams};
+use fabryk_fts::{FacetCount, FacetField, Passage, SearchBackend, SearchParams, SortOrder};
+use fabryk_vector::{DEFAULT_RERANK_TOP_K, Reranker, SearchTimings, rerank_top_k};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -32,6 +33,16 @@ pub struct SearchArgs {
     pub limit: Option<usize>,
     /// Optional content type filter.
     pub content_type: Option<String>,
+    /// Number of results to skip.
+    pub offset: Option<usize>,
+    /// Opaque cursor from a previous response's `next_cursor`.
+    pub cursor: Option<String>,
+    /// Result ordering (default relevance).
+    pub sort: Option<SortOrder>,
+    /// Fields to count facet values for.
+    pub facets: Option<Vec<FacetField>>,
+    /// Rerank the page with the configured reranker (default per server).
+    pub rerank: Option<bool>,
 }
 
 // ---------------------------------------------------------------------------
@@ -53,10 +64,19 @@ pub struct SearchResultResponse {
     pub source: Option<String>,
     /// Content snippet.
     pub snippet: Option<String>,
+    /// Highlighted passages with match offsets, best first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub passages: Vec<Passage>,
     /// Relevance score.
     pub relevance: f32,
     /// Content type.
     pub content_type: Option<String>,
+    /// Publication/creation date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    /// Reranker score, when the result was reranked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rerank_score: Option<f32>,
 }
 
 /// Response from search tool.
@@ -68,8 +88,23 @@ pub struct SearchResponse {
     pub total: usize,
     /// Results (may be limited).
     pub results: Vec<SearchResultResponse>,
+    /// Number of results skipped before this page.
+    #[serde(default)]
+    pub offset: usize,
+    /// Cursor for the next page, if more results remain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    /// Facet counts keyed by field name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub facets: BTreeMap<String, Vec<FacetCount>>,
     /// Search duration in milliseconds.
     pub duration_ms: u64,
+    /// Per-stage timings.
+    #[serde(default)]
+    pub timings: SearchTimings,
+    /// Name of the reranker applied, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reranker: Option<String>,
     /// Backend used.
     pub backend: String,
 }
@@ -93,6 +128,11 @@ pub struct SearchStatusResponse {
 /// - `search` — full-text search with filtering
 /// - `search_status` — search backend status
 ///
+/// With a [`Reranker`] configured, relevance-ordered pages can be reranked:
+/// the first `rerank_top_k` results of the page are re-scored and
+/// re-ordered. Callers opt in per request with the `rerank` argument;
+/// `with_rerank_by_default` sets the server default.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -107,6 +147,9 @@ pub struct FtsTools {
     custom_names: HashMap<String, String>,
     custom_descriptions: HashMap<String, String>,
     extra_search_schema: Option<serde_json::Value>,
+    reranker: Option<Arc<dyn Reranker>>,
+    rerank_by_default: bool,
+    rerank_top_k: usize,
 }
 
 impl FtsTools {
@@ -122,6 +165,9 @@ impl FtsTools {
             custom_names: HashMap::new(),
             custom_descriptions: HashMap::new(),
             extra_search_schema: None,
+            reranker: None,
+            rerank_by_default: false,
+            rerank_top_k: DEFAULT_RERANK_TOP_K,
         }
     }
 
@@ -132,6 +178,9 @@ impl FtsTools {
             custom_names: HashMap::new(),
             custom_descriptions: HashMap::new(),
             extra_search_schema: None,
+            reranker: None,
+            rerank_by_default: false,
+            rerank_top_k: DEFAULT_RERANK_TOP_K,
         }
     }
 
@@ -142,6 +191,9 @@ impl FtsTools {
             custom_names: HashMap::new(),
             custom_descriptions: HashMap::new(),
             extra_search_schema: None,
+            reranker: None,
+            rerank_by_default: false,
+            rerank_top_k: DEFAULT_RERANK_TOP_K,
         }
     }
 
@@ -173,6 +225,26 @@ impl FtsTools {
         self
     }
 
+    /// Set the reranker used for relevance-ordered results.
+    pub fn with_reranker(mut self, reranker: Arc<dyn Reranker>) -> Self {
+        self.reranker = Some(reranker);
+        self
+    }
+
+    /// Rerank results unless a request sets `rerank: false`.
+    ///
+    /// Has no effect without a reranker.
+    pub fn with_rerank_by_default(mut self, enabled: bool) -> Self {
+        self.rerank_by_default = enabled;
+        self
+    }
+
+    /// Number of results per page to rerank (default 20).
+    pub fn with_rerank_top_k(mut self, top_k: usize) -> Self {
+        self.rerank_top_k = top_k.max(1);
+        self
+    }
+
     fn tool_name(&self, slot: &str) -> String {
         self.custom_names
             .get(slot)
@@ -212,11 +284,45 @@ impl ToolRegistry for FtsTools {
                 "content_type": {
                     "type": "string",
                     "description": "Filter by content type"
+                },
+                "offset": {
+                    "type": "integer",
+                    "description": "Number of results to skip (default 0)"
+                },
+                "cursor": {
+                    "type": "string",
+                    "description": "Cursor from a previous response's next_cursor; overrides offset"
+                },
+                "sort": {
+                    "type": "string",
+                    "enum": ["relevance", "date_desc", "date_asc"],
+                    "description": "Result ordering (default relevance)"
+                },
+                "facets": {
+                    "type": "array",
+                    "items": {
+                        "type": "string",
+                        "enum": ["category", "source", "content_type"]
+                    },
+                    "description": "Fields to return facet counts for"
                 }
             },
             "required": ["query"]
         });
 
+        if self.reranker.is_some()
+            && let Some(props) = search_schema.pointer_mut("/properties")
+        {
+            props["rerank"] = serde_json::json!({
+                "type": "boolean",
+                "description": format!(
+                    "Rerank relevance-ordered results with a cross-encoder for better \
+                     precision at extra cost (default: {})",
+                    self.rerank_by_default
+                )
+            });
+        }
+
         // Merge extra schema properties into the search tool's properties.
         if let Some(extra) = &self.extra_search_schema
             && let (Some(props), Some(extra_props)) =
@@ -249,6 +355,9 @@ impl ToolRegistry for FtsTools {
         let backend = Arc::clone(&self.backend);
 
         if name == self.tool_name(Self::SLOT_SEARCH) {
+            let configured_reranker = self.reranker.clone();
+            let rerank_by_default = self.rerank_by_default;
+            let top_k = self.rerank_top_k;
             return Some(Box::pin(async move {
                 let mut obj = match args {
                     Value::Object(map) => map,
@@ -278,6 +387,55 @@ impl ToolRegistry for FtsTools {
                     .remove("content_type")
                     .and_then(|v| v.as_str().map(String::from));
 
+                let offset = obj
+                    .remove("offset")
+                    .and_then(|v| v.as_u64().map(|n| n as usize));
+                let cursor = obj
+                    .remove("cursor")
+                    .and_then(|v| v.as_str().map(String::from));
+                let sort = obj
+                    .remove("sort")
+                    .map(serde_json::from_value::<SortOrder>)
+                    .transpose()
+                    .map_err(|e| ErrorData::invalid_params(format!("invalid `sort`: {e}"), None))?;
+                let facets = obj
+                    .remove("facets")
+                    .map(serde_json::from_value::<Vec<FacetField>>)
+                    .transpose()
+                    .map_err(|e| {
+                        ErrorData::invalid_params(format!("invalid `facets`: {e}"), None)
+                    })?;
+                let rerank = obj
+                    .remove("rerank")
+                    .map(serde_json::from_value::<bool>)
+                    .transpose()
+                    .map_err(|e| {
+                        ErrorData::invalid_params(format!("invalid `rerank`: {e}"), None)
+                    })?;
+
+                // Reranking only makes sense for relevance ordering
+                let by_relevance = sort.unwrap_or_default() == SortOrder::Relevance;
+                let reranker = match (rerank, configured_reranker) {
+                    (Some(true), None) => {
+                        return Err(ErrorData::invalid_params(
+                            "Reranking is not configured on this server".to_string(),
+                            None,
+                        ));
+                    }
+                    (Some(true), Some(_)) if !by_relevance => {
+                        return Err(ErrorData::invalid_params(
+                            "`rerank` requires relevance sort order".to_string(),
+                            None,
+                        ));
+                    }
+                    (requested, Some(reranker))
+                        if by_relevance && requested.unwrap_or(rerank_by_default) =>
+                    {
+                        Some(reranker)
+                    }
+                    _ => None,
+                };
+
                 let content_types = content_type.map(|ct| vec![ct]);
 
                 // Remaining fields become extra filters.
@@ -298,11 +456,17 @@ impl ToolRegistry for FtsTools {
                     query_mode: None,
                     snippet_length: None,
                     extra_filters,
+                    offset,
+                    cursor,
+                    sort,
+                    facets,
                 };
 
+                let mut timings = SearchTimings::default();
                 let search_results = backend.search(params).await.map_err(|e| e.to_mcp_error())?;
+                timings.keyword_ms = Some(SearchTimings::since(start));
 
-                let results: Vec<SearchResultResponse> = search_results
+                let mut results: Vec<SearchResultResponse> = search_results
                     .items
                     .into_iter()
                     .map(|hit| SearchResultResponse {
@@ -312,16 +476,44 @@ impl ToolRegistry for FtsTools {
                         category: hit.category,
                         source: hit.source,
                         snippet: hit.snippet,
+                        passages: hit.passages,
                         relevance: hit.relevance,
                         content_type: hit.content_type,
+                        date: hit.date,
+                        rerank_score: None,
                     })
                     .collect();
 
+                let reranker_name = match reranker {
+                    Some(reranker) => {
+                        let stage = Instant::now();
+                        results =
+                            rerank_top_k(reranker.as_ref(), &query, results, top_k, rerank_text)
+                                .await
+                                .map_err(|e| e.to_mcp_error())?
+                                .into_iter()
+                                .map(|(mut result, score)| {
+                                    result.rerank_score = score;
+                                    result
+                                })
+                                .collect();
+                        timings.rerank_ms = Some(SearchTimings::since(stage));
+                        Some(reranker.name().to_string())
+                    }
+                    None => None,
+                };
+                timings.total_ms = SearchTimings::since(start);
+
                 let response = SearchResponse {
                     query,
                     total: search_results.total,
                     results,
+                    offset: search_results.offset,
+                    next_cursor: search_results.next_cursor,
+                    facets: search_results.facets,
                     duration_ms: start.elapsed().as_millis() as u64,
+                    timings,
+                    reranker: reranker_name,
                     backend: search_results.backend,
                 };
 
@@ -343,6 +535,20 @@ impl ToolRegistry for FtsTools {
     }
 }
 
+/// Text handed to the reranker for a search result.
+fn rerank_text(result: &SearchResultResponse) -> String {
+    [
+        Some(result.title.as_str()),
+        result.description.as_deref(),
+        result.snippet.as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|part| !part.is_empty())
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -351,7 +557,8 @@ impl ToolRegistry for FtsTools {
 mod tests {
     use super::*;
     use async_trait::async_trait;
-    use fabryk_fts::{SearchResult, SearchResults};
+    use fabryk_fts::{SearchResult, SearchResults, paginate};
+    use fabryk_vector::MockReranker;
 
     // -- Mock backend -------------------------------------------------------
 
@@ -370,11 +577,13 @@ mod tests {
                         category: "test".to_string(),
                         source: None,
                         snippet: Some("...matching text...".to_string()),
+                        passages: Vec::new(),
                         relevance: 0.95,
                         content_type: Some("concept".to_string()),
                         path: None,
                         chapter: None,
                         section: None,
+                        date: Some("2024-01-01".to_string()),
                     },
                     SearchResult {
                         id: "result-2".to_string(),
@@ -383,11 +592,13 @@ mod tests {
                         category: "test".to_string(),
                         source: Some("book-1".to_string()),
                         snippet: None,
+                        passages: Vec::new(),
                         relevance: 0.8,
                         content_type: None,
                         path: None,
                         chapter: None,
                         section: None,
+                        date: Some("2025-06-01".to_string()),
                     },
                 ],
             }
@@ -402,16 +613,8 @@ mod tests {
             if let Some(ref cat) = params.category {
                 items.retain(|r| r.category == *cat);
             }
-            if let Some(limit) = params.limit {
-                items.truncate(limit);
-            }
-
-            let total = items.len();
-            Ok(SearchResults {
-                items,
-                total,
-                backend: "mock".to_string(),
-            })
+            let limit = params.limit.unwrap_or(10);
+            paginate(items, &params, limit, "mock")
         }
 
         fn name(&self) -> &str {
@@ -481,6 +684,87 @@ mod tests {
         assert_eq!(result.is_error, Some(false));
     }
 
+    async fn search_json(tools: &FtsTools, args: Value) -> Value {
+        let result = tools.call("search", args).unwrap().await.unwrap();
+        let text = &result.content[0].as_text().unwrap().text;
+        serde_json::from_str(text).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_fts_search_paging() {
+        let tools = FtsTools::new(MockSearchBackend::new());
+
+        let first = search_json(&tools, serde_json::json!({"query": "test", "limit": 1})).await;
+        assert_eq!(first["total"], 2);
+        assert_eq!(first["results"][0]["id"], "result-1");
+        let cursor = first["next_cursor"].as_str().unwrap().to_string();
+
+        let second = search_json(
+            &tools,
+            serde_json::json!({"query": "test", "limit": 1, "cursor": cursor}),
+        )
+        .await;
+        assert_eq!(second["offset"], 1);
+        assert_eq!(second["results"][0]["id"], "result-2");
+        assert!(second.get("next_cursor").is_none());
+
+        let by_offset = search_json(
+            &tools,
+            serde_json::json!({"query": "test", "limit": 1, "offset": 1}),
+        )
+        .await;
+        assert_eq!(by_offset["results"][0]["id"], "result-2");
+    }
+
+    #[tokio::test]
+    async fn test_fts_search_sort_by_date() {
+        let tools = FtsTools::new(MockSearchBackend::new());
+        let json = search_json(
+            &tools,
+            serde_json::json!({"query": "test", "sort": "date_desc"}),
+        )
+        .await;
+        assert_eq!(json["results"][0]["id"], "result-2");
+        assert_eq!(json["results"][0]["date"], "2025-06-01");
+    }
+
+    #[tokio::test]
+    async fn test_fts_search_facets() {
+        let tools = FtsTools::new(MockSearchBackend::new());
+        let json = search_json(
+            &tools,
+            serde_json::json!({"query": "test", "facets": ["category", "content_type"]}),
+        )
+        .await;
+        assert_eq!(json["facets"]["category"][0]["value"], "test");
+        assert_eq!(json["facets"]["category"][0]["count"], 2);
+        assert_eq!(json["facets"]["content_type"][0]["value"], "concept");
+    }
+
+    #[tokio::test]
+    async fn test_fts_search_invalid_sort() {
+        let tools = FtsTools::new(MockSearchBackend::new());
+        let future = tools
+            .call(
+                "search",
+                serde_json::json!({"query": "test", "sort": "random"}),
+            )
+            .unwrap();
+        assert!(future.await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fts_search_invalid_cursor() {
+        let tools = FtsTools::new(MockSearchBackend::new());
+        let future = tools
+            .call(
+                "search",
+                serde_json::json!({"query": "test", "cursor": "bogus"}),
+            )
+            .unwrap();
+        assert!(future.await.is_err());
+    }
+
     #[tokio::test]
     async fn test_fts_search_missing_query() {
         let tools = FtsTools::new(MockSearchBackend::new());
@@ -522,10 +806,18 @@ mod tests {
                 category: "test".to_string(),
                 source: None,
                 snippet: None,
+                passages: Vec::new(),
                 relevance: 0.9,
                 content_type: None,
+                date: None,
+                rerank_score: None,
             }],
+            offset: 0,
+            next_cursor: None,
+            facets: BTreeMap::new(),
             duration_ms: 5,
+            timings: SearchTimings::default(),
+            reranker: None,
             backend: "mock".to_string(),
         };
 
@@ -675,4 +967,93 @@ mod tests {
         let result = future.await.unwrap();
         assert_eq!(result.is_error, Some(false));
     }
+
+    // -- Rerank tests -------------------------------------------------------
+
+    fn rerank_tools() -> FtsTools {
+        FtsTools::new(MockSearchBackend::new()).with_reranker(Arc::new(MockReranker))
+    }
+
+    #[tokio::test]
+    async fn test_fts_search_rerank() {
+        let json = search_json(
+            &rerank_tools(),
+            serde_json::json!({"query": "second", "rerank": true}),
+        )
+        .await;
+        assert_eq!(json["results"][0]["id"], "result-2");
+        assert_eq!(json["results"][0]["rerank_score"], 1.0);
+        assert_eq!(json["results"][1]["rerank_score"], 0.0);
+        assert_eq!(json["reranker"], "mock");
+        assert!(json["timings"]["keyword_ms"].is_number());
+        assert!(json["timings"]["rerank_ms"].is_number());
+    }
+
+    #[tokio::test]
+    async fn test_fts_search_rerank_server_default() {
+        let tools = rerank_tools().with_rerank_by_default(true);
+        let json = search_json(&tools, serde_json::json!({"query": "second"})).await;
+        assert_eq!(json["results"][0]["id"], "result-2");
+
+        let json = search_json(
+            &tools,
+            serde_json::json!({"query": "second", "rerank": false}),
+        )
+        .await;
+        assert_eq!(json["results"][0]["id"], "result-1");
+        assert!(json.get("reranker").is_none());
+        assert!(json["timings"].get("rerank_ms").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_fts_search_rerank_top_k() {
+        let tools = rerank_tools().with_rerank_top_k(1);
+        let json = search_json(
+            &tools,
+            serde_json::json!({"query": "second", "rerank": true}),
+        )
+        .await;
+        // Only the first result is re-scored, so the order is unchanged
+        assert_eq!(json["results"][0]["id"], "result-1");
+        assert!(json["results"][1].get("rerank_score").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_fts_search_rerank_errors() {
+        let plain = FtsTools::new(MockSearchBackend::new());
+        let result = plain
+            .call("search", serde_json::json!({"query": "x", "rerank": true}))
+            .unwrap()
+            .await;
+        assert!(result.is_err());
+
+        let result = rerank_tools()
+            .call(
+                "search",
+                serde_json::json!({"query": "x", "rerank": true, "sort": "date_desc"}),
+            )
+            .unwrap()
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fts_search_rerank_not_forwarded_as_filter() {
+        let json = search_json(
+            &rerank_tools(),
+            serde_json::json!({"query": "second", "rerank": false}),
+        )
+        .await;
+        assert_eq!(json["total"], 2);
+    }
+
+    #[test]
+    fn test_rerank_schema_only_with_reranker() {
+        let plain = FtsTools::new(MockSearchBackend::new());
+        let schema = serde_json::to_value(&plain.tools()[0].input_schema).unwrap();
+        assert!(schema["properties"].get("rerank").is_none());
+
+        let schema = serde_json::to_value(&rerank_tools().tools()[0].input_schema).unwrap();
+        assert_eq!(schema["properties"]["rerank"]["type"], "boolean");
+    }
 }
@@ -21,6 +21,8 @@
 //! - `graph_source_coverage` — find concepts that a source introduces or covers
 //! - `graph_learning_path` — step-numbered learning path to a target concept
 //! - `graph_bridge_categories` — find nodes connecting two specific categories
+//! - `graph_clusters` — topic clusters with per-cluster summaries
+//! - `graph_query` — declarative pattern queries (MATCH/WHERE/RETURN)
 //!
 //! # Example
 //!
@@ -9,10 +9,12 @@ use fabryk_mcp_core::model::{ErrorData, Tool};
 use fabryk_mcp_core::registry::{ToolRegistry, ToolResult};
 
 use fabryk_graph::{
-    EdgeInfo, GraphData, NeighborInfo, Node, NodeSummary, PathStep, Relationship,
-    bridge_between_categories, calculate_centrality, compute_stats, concept_sources,
-    concept_variants, dependents, find_bridges, get_node_detail, get_node_edges, learning_path,
-    neighborhood, prerequisites_sorted, shortest_path, source_coverage, validate_graph,
+    CentralityAlgorithm, CentralityOptions, ClusterOptions, EdgeInfo, GraphData, NeighborInfo,
+    Node, NodeSummary, PathStep, PatternQuery, QueryLimits, Relationship,
+    bridge_between_categories, calculate_centrality, compute_centrality, compute_stats,
+    concept_sources, concept_variants, dependents, detect_clusters, find_bridges, get_node_detail,
+    get_node_edges, learning_path, neighborhood, prerequisites_sorted, shortest_path,
+    source_coverage, validate_graph,
 };
 use serde::Deserialize;
 use serde_json::{Value, json};
@@ -123,6 +125,64 @@ pub struct NeighborhoodArgs {
     pub relationship: Option<String>,
 }
 
+/// Arguments for graph_centrality tool.
+#[derive(Debug, Deserialize)]
+pub struct CentralityArgs {
+    /// Algorithm: "pagerank", "degree", "betweenness", "closeness", or
+    /// "eigenvector". When omitted, returns unweighted degree, in-degree
+    /// and out-degree per node.
+    #[serde(default)]
+    pub algorithm: Option<String>,
+    /// Only consider edges of these relationship types.
+    #[serde(default)]
+    pub relationships: Option<Vec<String>>,
+    /// Seed node IDs for personalized PageRank.
+    #[serde(default)]
+    pub personalize: Option<Vec<String>>,
+    /// Use edge weights (default true).
+    #[serde(default)]
+    pub weighted: Option<bool>,
+    /// Maximum results (default 10).
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// Arguments for graph_clusters tool.
+#[derive(Debug, Deserialize)]
+pub struct ClustersArgs {
+    /// Algorithm: "leiden" (default), "louvain", or "label_propagation".
+    #[serde(default)]
+    pub algorithm: Option<String>,
+    /// Only consider edges of these relationship types.
+    #[serde(default)]
+    pub relationships: Option<Vec<String>>,
+    /// Modularity resolution (default 1.0).
+    #[serde(default)]
+    pub resolution: Option<f64>,
+    /// Smallest cluster to report (default 2).
+    #[serde(default)]
+    pub min_size: Option<usize>,
+    /// Top nodes listed per cluster (default 5).
+    #[serde(default)]
+    pub top: Option<usize>,
+    /// Use edge weights (default true).
+    #[serde(default)]
+    pub weighted: Option<bool>,
+    /// Maximum clusters returned (default 20).
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// Arguments for graph_query tool.
+#[derive(Debug, Deserialize)]
+pub struct QueryArgs {
+    /// Pattern query, e.g. `MATCH (c:harmony)-[:extends]->(p) RETURN c.id, p.id`.
+    pub query: String,
+    /// Maximum rows returned (default 100).
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
 /// Arguments for graph_get_node tool.
 #[derive(Debug, Deserialize)]
 pub struct GetNodeArgs {
@@ -183,7 +243,7 @@ pub struct BridgeCategoriesArgs {
 
 /// MCP tools for graph queries.
 ///
-/// Generates seventeen tools:
+/// Generates eighteen tools:
 /// - `graph_related` — find related nodes
 /// - `graph_path` — shortest path between nodes
 /// - `graph_prerequisites` — learning order prerequisites
@@ -201,6 +261,8 @@ pub struct BridgeCategoriesArgs {
 /// - `graph_source_coverage` — find concepts that a source introduces or covers
 /// - `graph_learning_path` — step-numbered learning path to a target concept
 /// - `graph_bridge_categories` — find nodes connecting two specific categories
+/// - `graph_clusters` — topic clusters with per-cluster summaries
+/// - `graph_query` — declarative pattern queries (MATCH/WHERE/RETURN)
 ///
 /// # Example
 ///
@@ -254,6 +316,10 @@ impl GraphTools {
     pub const SLOT_LEARNING_PATH: &str = "graph_learning_path";
     /// Slot key for the bridge categories tool.
     pub const SLOT_BRIDGE_CATEGORIES: &str = "graph_bridge_categories";
+    /// Slot key for the clusters tool.
+    pub const SLOT_CLUSTERS: &str = "graph_clusters";
+    /// Slot key for the pattern query tool.
+    pub const SLOT_QUERY: &str = "graph_query";
 
     /// Create new graph tools with owned graph data.
     pub fn new(graph: GraphData) -> Self {
@@ -467,6 +533,25 @@ impl ToolRegistry for GraphTools {
                 self.merge_extra_schema(Self::SLOT_CENTRALITY, json!({
                     "type": "object",
                     "properties": {
+                        "algorithm": {
+                            "type": "string",
+                            "enum": ["pagerank", "degree", "betweenness", "closeness", "eigenvector"],
+                            "description": "Centrality measure; when omitted, returns degree, in_degree and out_degree per node"
+                        },
+                        "relationships": {
+                            "type": "array",
+                            "items": { "type": "string" },
+                            "description": "Only follow these relationship types (e.g., prerequisite); requires algorithm"
+                        },
+                        "personalize": {
+                            "type": "array",
+                            "items": { "type": "string" },
+                            "description": "Node IDs to personalize PageRank around; requires algorithm"
+                        },
+                        "weighted": {
+                            "type": "boolean",
+                            "description": "Use edge weights (default true); requires algorithm"
+                        },
                         "limit": {
                             "type": "integer",
                             "description": "Number of results (default 10)"
@@ -654,6 +739,71 @@ impl ToolRegistry for GraphTools {
                     "required": ["category_a", "category_b"]
                 })),
             ),
+            make_tool(
+                &self.tool_name(Self::SLOT_CLUSTERS),
+                &self.tool_description(
+                    Self::SLOT_CLUSTERS,
+                    "Detect topic clusters with their top nodes, category mix, and connectivity",
+                ),
+                self.merge_extra_schema(Self::SLOT_CLUSTERS, json!({
+                    "type": "object",
+                    "properties": {
+                        "algorithm": {
+                            "type": "string",
+                            "enum": ["leiden", "louvain", "label_propagation"],
+                            "description": "Clustering algorithm (default leiden)"
+                        },
+                        "relationships": {
+                            "type": "array",
+                            "items": { "type": "string" },
+                            "description": "Only follow these relationship types (e.g., prerequisite)"
+                        },
+                        "resolution": {
+                            "type": "number",
+                            "description": "Higher values give smaller clusters (default 1.0)"
+                        },
+                        "min_size": {
+                            "type": "integer",
+                            "description": "Smallest cluster to report (default 2)"
+                        },
+                        "top": {
+                            "type": "integer",
+                            "description": "Top nodes listed per cluster (default 5)"
+                        },
+                        "weighted": {
+                            "type": "boolean",
+                            "description": "Use edge weights (default true)"
+                        },
+                        "limit": {
+                            "type": "integer",
+                            "description": "Maximum clusters returned (default 20)"
+                        }
+                    }
+                })),
+            ),
+            make_tool(
+                &self.tool_name(Self::SLOT_QUERY),
+                &self.tool_description(
+                    Self::SLOT_QUERY,
+                    "Run a Cypher-like pattern query: MATCH (a:category {key: value})-[:relationship*1..3]->(b) \
+                     WHERE a.title CONTAINS 'x' RETURN a.id, b.title ORDER BY a.id LIMIT 10. \
+                     Labels match categories; properties match node fields or metadata",
+                ),
+                self.merge_extra_schema(Self::SLOT_QUERY, json!({
+                    "type": "object",
+                    "properties": {
+                        "query": {
+                            "type": "string",
+                            "description": "Pattern query (MATCH ... [WHERE ...] RETURN ... [ORDER BY ...] [SKIP n] [LIMIT n])"
+                        },
+                        "limit": {
+                            "type": "integer",
+                            "description": "Maximum rows returned (default 100)"
+                        }
+                    },
+                    "required": ["query"]
+                })),
+            ),
         ]
     }
 
@@ -824,29 +974,82 @@ impl ToolRegistry for GraphTools {
             let raw_args = args.clone();
             let node_filter = node_filter.clone();
             return Some(Box::pin(async move {
-                let limit = args
-                    .get("limit")
-                    .and_then(|v| v.as_u64())
-                    .map(|n| n as usize)
-                    .unwrap_or(10);
+                let args: CentralityArgs = serde_json::from_value(args)
+                    .map_err(|e| ErrorData::invalid_params(e.to_string(), None))?;
+                let limit = args.limit.unwrap_or(10);
 
-                let graph = graph.read().await;
-                let scores = calculate_centrality(&graph);
-
-                let filtered: Vec<_> = match &node_filter {
-                    Some(filter) => scores
-                        .into_iter()
-                        .filter(|s| {
-                            graph
-                                .get_node(&s.node_id)
-                                .map(|n| filter.matches(n, &raw_args))
-                                .unwrap_or(false)
-                        })
-                        .take(limit)
-                        .collect(),
-                    None => scores.into_iter().take(limit).collect(),
+                let Some(algorithm) = args.algorithm.as_deref() else {
+                    if args.relationships.is_some()
+                        || args.personalize.is_some()
+                        || args.weighted.is_some()
+                    {
+                        return Err(ErrorData::invalid_params(
+                            "relationships, personalize and weighted require an algorithm",
+                            None,
+                        ));
+                    }
+
+                    let graph = graph.read().await;
+                    let scores = calculate_centrality(&graph);
+
+                    let filtered: Vec<_> = match &node_filter {
+                        Some(filter) => scores
+                            .into_iter()
+                            .filter(|s| {
+                                graph
+                                    .get_node(&s.node_id)
+                                    .map(|n| filter.matches(n, &raw_args))
+                                    .unwrap_or(false)
+                            })
+                            .take(limit)
+                            .collect(),
+                        None => scores.into_iter().take(limit).collect(),
+                    };
+                    return serialize_response(&filtered);
                 };
-                serialize_response(&filtered)
+                let algorithm: CentralityAlgorithm =
+                    algorithm.parse().map_err(|e: fabryk_core::Error| {
+                        ErrorData::invalid_params(e.to_string(), None)
+                    })?;
+                let mut options = CentralityOptions::new(algorithm)
+                    .with_personalization(args.personalize.unwrap_or_default());
+                if let Some(relationships) = args.relationships {
+                    options = options.with_relationships(
+                        relationships
+                            .iter()
+                            .map(|r| parse_relationship(r))
+                            .collect(),
+                    );
+                }
+                if args.weighted == Some(false) {
+                    options = options.unweighted();
+                }
+
+                let graph = graph.read().await;
+                let scores = compute_centrality(&graph, &options).map_err(|e| e.to_mcp_error())?;
+
+                let ranked: Vec<Value> = scores
+                    .into_iter()
+                    .filter_map(|s| {
+                        let node = graph.get_node(&s.node_id)?;
+                        match &node_filter {
+                            Some(filter) if !filter.matches(node, &raw_args) => None,
+                            _ => Some(json!({
+                                "node_id": s.node_id,
+                                "title": node.title,
+                                "score": s.score
+                            })),
+                        }
+                    })
+                    .take(limit)
+                    .collect();
+                let count = ranked.len();
+                let response = json!({
+                    "algorithm": algorithm.name(),
+                    "scores": ranked,
+                    "count": count
+                });
+                serialize_response(&response)
             }));
         }
 
@@ -1047,6 +1250,99 @@ impl ToolRegistry for GraphTools {
             }));
         }
 
+        if name == self.tool_name(Self::SLOT_CLUSTERS) {
+            return Some(Box::pin(async move {
+                let args: ClustersArgs = serde_json::from_value(args)
+                    .map_err(|e| ErrorData::invalid_params(e.to_string(), None))?;
+
+                let mut options = ClusterOptions::default();
+                if let Some(name) = args.algorithm.as_deref() {
+                    options.algorithm = name.parse().map_err(|e: fabryk_core::Error| {
+                        ErrorData::invalid_params(e.to_string(), None)
+                    })?;
+                }
+                if let Some(relationships) = args.relationships {
+                    options = options.with_relationships(
+                        relationships
+                            .iter()
+                            .map(|r| parse_relationship(r))
+                            .collect(),
+                    );
+                }
+                if let Some(resolution) = args.resolution {
+                    options = options.with_resolution(resolution);
+                }
+                if let Some(min_size) = args.min_size {
+                    options = options.with_min_size(min_size);
+                }
+                if let Some(top) = args.top {
+                    options = options.with_top_nodes(top);
+                }
+                if args.weighted == Some(false) {
+                    options = options.unweighted();
+                }
+
+                let graph = graph.read().await;
+                let result = detect_clusters(&graph, &options).map_err(|e| e.to_mcp_error())?;
+
+                let cluster_count = result.clusters.len();
+                let clusters: Vec<Value> = result
+                    .clusters
+                    .iter()
+                    .take(args.limit.unwrap_or(20))
+                    .map(|cluster| {
+                        let top_nodes: Vec<Value> = cluster
+                            .top_nodes
+                            .iter()
+                            .map(|s| {
+                                json!({
+                                    "node_id": s.node_id,
+                                    "title": graph.get_node(&s.node_id).map(|n| n.title.as_str()),
+                                    "score": s.score
+                                })
+                            })
+                            .collect();
+                        json!({
+                            "id": cluster.id,
+                            "size": cluster.size,
+                            "top_nodes": top_nodes,
+                            "categories": cluster.categories,
+                            "dominant_category": cluster.dominant_category,
+                            "uncategorized": cluster.uncategorized,
+                            "internal_weight": cluster.internal_weight,
+                            "external_weight": cluster.external_weight,
+                            "conductance": cluster.conductance
+                        })
+                    })
+                    .collect();
+                let response = json!({
+                    "algorithm": result.algorithm.name(),
+                    "modularity": result.modularity,
+                    "cluster_count": cluster_count,
+                    "clusters": clusters,
+                    "unclustered": result.unclustered
+                });
+                serialize_response(&response)
+            }));
+        }
+
+        if name == self.tool_name(Self::SLOT_QUERY) {
+            return Some(Box::pin(async move {
+                let args: QueryArgs = serde_json::from_value(args)
+                    .map_err(|e| ErrorData::invalid_params(e.to_string(), None))?;
+
+                let query = PatternQuery::parse(&args.query)
+                    .map_err(|e| ErrorData::invalid_params(e.to_string(), None))?;
+                let limits = QueryLimits::default().with_max_rows(args.limit.unwrap_or(100));
+
+                let graph = graph.read().await;
+                let result = query
+                    .execute(&graph, &limits)
+                    .map_err(|e| e.to_mcp_error())?;
+                serialize_response(&result)
+            }));
+        }
+
         None
     }
 }
@@ -1104,7 +1400,7 @@ mod tests {
     #[test]
     fn test_graph_tools_creation() {
         let tools = GraphTools::new(GraphData::new());
-        assert_eq!(tools.tool_count(), 17);
+        assert_eq!(tools.tool_count(), 19);
     }
 
     #[test]
@@ -1129,6 +1425,7 @@ mod tests {
         assert!(names.contains(&"graph_source_coverage"));
         assert!(names.contains(&"graph_learning_path"));
         assert!(names.contains(&"graph_bridge_categories"));
+        assert!(names.contains(&"graph_clusters"));
     }
 
     #[test]
@@ -1281,6 +1578,47 @@ mod tests {
         let future = tools.call("graph_centrality", json!({"limit": 5})).unwrap();
         let result = future.await.unwrap();
         assert_eq!(result.is_error, Some(false));
+        let text = &result.content[0].as_text().unwrap().text;
+        let response: Value = serde_json::from_str(text).unwrap();
+        let first = &response.as_array().unwrap()[0];
+        assert!(first["degree"].is_number());
+        assert!(first["in_degree"].is_number());
+        assert!(first["out_degree"].is_number());
+    }
+
+    #[tokio::test]
+    async fn test_graph_centrality_options_require_algorithm() {
+        let tools = GraphTools::new(make_test_graph());
+        let future = tools
+            .call("graph_centrality", json!({"weighted": false}))
+            .unwrap();
+        assert!(future.await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_graph_centrality_algorithm_and_relationships() {
+        let tools = GraphTools::new(make_test_graph());
+        let future = tools
+            .call(
+                "graph_centrality",
+                json!({"algorithm": "betweenness", "relationships": ["prerequisite", "relates_to"]}),
+            )
+            .unwrap();
+        let result = future.await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let text = &result.content[0].as_text().unwrap().text;
+        let response: Value = serde_json::from_str(text).unwrap();
+        assert_eq!(response["algorithm"], "betweenness");
+        assert_eq!(response["scores"][0]["node_id"], "node-b");
+    }
+
+    #[tokio::test]
+    async fn test_graph_centrality_unknown_algorithm() {
+        let tools = GraphTools::new(make_test_graph());
+        let future = tools
+            .call("graph_centrality", json!({"algorithm": "katz"}))
+            .unwrap();
+        assert!(future.await.is_err());
     }
 
     // -- graph_bridges tests ------------------------------------------------
@@ -1293,6 +1631,86 @@ mod tests {
         assert_eq!(result.is_error, Some(false));
     }
 
+    // -- graph_clusters tests -----------------------------------------------
+
+    #[tokio::test]
+    async fn test_graph_clusters() {
+        let tools = GraphTools::new(make_test_graph());
+        let future = tools
+            .call("graph_clusters", json!({"algorithm": "louvain", "top": 1}))
+            .unwrap();
+        let result = future.await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let text = &result.content[0].as_text().unwrap().text;
+        let response: Value = serde_json::from_str(text).unwrap();
+        assert_eq!(response["algorithm"], "louvain");
+        assert_eq!(response["cluster_count"], 1);
+        assert_eq!(response["clusters"][0]["size"], 3);
+        assert_eq!(response["clusters"][0]["dominant_category"], "alpha");
+        assert_eq!(
+            response["clusters"][0]["top_nodes"]
+                .as_array()
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_graph_clusters_unknown_algorithm() {
+        let tools = GraphTools::new(make_test_graph());
+        let future = tools
+            .call("graph_clusters", json!({"algorithm": "spectral"}))
+            .unwrap();
+        assert!(future.await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_graph_query() {
+        let tools = GraphTools::new(make_test_graph());
+        let future = tools
+            .call(
+                "graph_query",
+                json!({
+                    "query": "MATCH (a:alpha)-[:prerequisite]->(b {tier: 'advanced'}) RETURN a.id, b.title AS title"
+                }),
+            )
+            .unwrap();
+        let result = future.await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let text = &result.content[0].as_text().unwrap().text;
+        let response: Value = serde_json::from_str(text).unwrap();
+        assert_eq!(response["columns"], json!(["a.id", "title"]));
+        assert_eq!(response["rows"], json!([["node-a", "Node B"]]));
+        assert_eq!(response["truncated"], false);
+    }
+
+    #[tokio::test]
+    async fn test_graph_query_limit_truncates() {
+        let tools = GraphTools::new(make_test_graph());
+        let future = tools
+            .call(
+                "graph_query",
+                json!({"query": "MATCH (n) RETURN n.id", "limit": 1}),
+            )
+            .unwrap();
+        let result = future.await.unwrap();
+        let text = &result.content[0].as_text().unwrap().text;
+        let response: Value = serde_json::from_str(text).unwrap();
+        assert_eq!(response["rows"].as_array().unwrap().len(), 1);
+        assert_eq!(response["truncated"], true);
+    }
+
+    #[tokio::test]
+    async fn test_graph_query_syntax_error() {
+        let tools = GraphTools::new(make_test_graph());
+        let future = tools
+            .call("graph_query", json!({"query": "MATCH (n RETURN n"}))
+            .unwrap();
+        let err = future.await.unwrap_err();
+        assert!(err.message.contains("syntax error"));
+    }
+
     // -- Shared state tests -------------------------------------------------
 
     #[tokio::test]
@@ -1927,7 +2345,7 @@ mod tests {
         assert!(tools.node_filter.is_none());
         assert!(tools.extra_schemas.is_empty());
         // Still has the correct tool count.
-        assert_eq!(tools.tool_count(), 17);
+        assert_eq!(tools.tool_count(), 19);
     }
 
     #[tokio::test]
@@ -2041,7 +2459,7 @@ mod tests {
 
         let tool_list = tools.tools();
         // All existing tools should be unaffected.
-        assert_eq!(tool_list.len(), 17);
+        assert_eq!(tool_list.len(), 19);
     }
 
     #[tokio::test]
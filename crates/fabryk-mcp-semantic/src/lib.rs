@@ -5,7 +5,8 @@
 //!
 //! # Tools
 //!
-//! - `semantic_search` — search using keyword, vector, or hybrid (RRF) mode
+//! - `semantic_search` — search using keyword, vector, or hybrid (RRF) mode,
+//!   optionally reranking hybrid results
 //!
 //! # Example
 //!
@@ -21,4 +22,6 @@
 pub mod tools;
 
 // Re-exports
-pub use tools::{HybridResult, SemanticSearchArgs, SemanticSearchTools, VectorSlot};
+pub use tools::{
+    HybridResponse, HybridResult, SemanticSearchArgs, SemanticSearchTools, VectorSlot,
+};
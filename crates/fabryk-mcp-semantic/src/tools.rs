@@ -5,13 +5,15 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 
 use fabryk_fts::{SearchBackend, SearchParams};
 use fabryk_mcp_core::helpers::{make_tool, serialize_response};
 use fabryk_mcp_core::model::{ErrorData, Tool};
 use fabryk_mcp_core::registry::{ToolRegistry, ToolResult};
 use fabryk_vector::{
-    FtsResult, HybridSearchResult, VectorBackend, VectorSearchParams, reciprocal_rank_fusion,
+    DEFAULT_RERANK_TOP_K, FtsResult, HybridSearchResult, Reranker, SearchTimings, VectorBackend,
+    VectorSearchParams, fold_chunks, reciprocal_rank_fusion, rerank_hybrid,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -33,6 +35,8 @@ pub struct SemanticSearchArgs {
     pub source: Option<String>,
     /// Maximum results to return (default 10, max 50).
     pub limit: Option<usize>,
+    /// Rerank hybrid results with the configured reranker (default per server).
+    pub rerank: Option<bool>,
 }
 
 // ---------------------------------------------------------------------------
@@ -53,6 +57,12 @@ pub struct HybridResult {
     /// Metadata snapshot.
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub metadata: HashMap<String, String>,
+    /// Best-matching passage, when the vector index holds chunks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub passage: Option<String>,
+    /// Reranker score, when the result was reranked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rerank_score: Option<f32>,
 }
 
 impl From<HybridSearchResult> for HybridResult {
@@ -62,10 +72,28 @@ impl From<HybridSearchResult> for HybridResult {
             rrf_score: r.score,
             source: r.source,
             metadata: r.metadata,
+            passage: r.passage,
+            rerank_score: r.rerank_score,
         }
     }
 }
 
+/// Response of a hybrid search.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HybridResponse {
+    /// Merged results, best first.
+    pub results: Vec<HybridResult>,
+    /// Name of the reranker applied, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reranker: Option<String>,
+    /// Per-stage timings.
+    pub timings: SearchTimings,
+}
+
+/// How many vector hits to request per result, leaving room for several
+/// chunks of the same document to fold into one.
+const CHUNK_OVERFETCH: usize = 3;
+
 // ---------------------------------------------------------------------------
 // FTS → FtsResult adapter
 // ---------------------------------------------------------------------------
@@ -84,6 +112,49 @@ fn to_fts_results(results: &fabryk_fts::SearchResults) -> Vec<FtsResult> {
         .collect()
 }
 
+/// Text handed to the reranker for each FTS result, keyed by document ID.
+fn fts_rerank_texts(results: &fabryk_fts::SearchResults) -> HashMap<String, String> {
+    results
+        .items
+        .iter()
+        .map(|item| {
+            let text = [
+                Some(item.title.as_str()),
+                item.description.as_deref(),
+                item.snippet.as_deref(),
+            ]
+            .into_iter()
+            .flatten()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n");
+            (item.id.clone(), text)
+        })
+        .collect()
+}
+
+/// Text handed to the reranker for a fused result.
+///
+/// Prefers the FTS title, description and snippet, then the vector passage,
+/// then metadata title and description, then the document ID.
+fn hybrid_rerank_text(result: &HybridSearchResult, fts_texts: &HashMap<String, String>) -> String {
+    if let Some(text) = fts_texts.get(&result.id).filter(|t| !t.is_empty()) {
+        return text.clone();
+    }
+    if let Some(ref passage) = result.passage {
+        return passage.clone();
+    }
+    let from_metadata: Vec<&str> = ["title", "description"]
+        .iter()
+        .filter_map(|key| result.metadata.get(*key).map(String::as_str))
+        .collect();
+    if from_metadata.is_empty() {
+        result.id.clone()
+    } else {
+        from_metadata.join("\n")
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Type aliases
 // ---------------------------------------------------------------------------
@@ -105,6 +176,11 @@ pub type VectorSlot = Arc<tokio::sync::RwLock<Option<Arc<dyn VectorBackend>>>>;
 ///
 /// When no vector backend is available, hybrid mode falls back to keyword-only.
 ///
+/// With a [`Reranker`] configured, hybrid results can additionally be
+/// reranked: the top `rerank_top_k` fused candidates are re-scored and
+/// re-ordered before the limit is applied. Callers opt in per request with
+/// the `rerank` argument; `with_rerank_by_default` sets the server default.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -116,6 +192,9 @@ pub struct SemanticSearchTools {
     fts: Arc<dyn SearchBackend>,
     vector: Option<Arc<dyn VectorBackend>>,
     vector_slot: Option<VectorSlot>,
+    reranker: Option<Arc<dyn Reranker>>,
+    rerank_by_default: bool,
+    rerank_top_k: usize,
     custom_names: HashMap<String, String>,
     custom_descriptions: HashMap<String, String>,
 }
@@ -130,6 +209,9 @@ impl SemanticSearchTools {
             fts,
             vector,
             vector_slot: None,
+            reranker: None,
+            rerank_by_default: false,
+            rerank_top_k: DEFAULT_RERANK_TOP_K,
             custom_names: HashMap::new(),
             custom_descriptions: HashMap::new(),
         }
@@ -141,6 +223,9 @@ impl SemanticSearchTools {
             fts: Arc::from(fts),
             vector: vector.map(Arc::from),
             vector_slot: None,
+            reranker: None,
+            rerank_by_default: false,
+            rerank_top_k: DEFAULT_RERANK_TOP_K,
             custom_names: HashMap::new(),
             custom_descriptions: HashMap::new(),
         }
@@ -163,11 +248,34 @@ impl SemanticSearchTools {
             fts,
             vector: None,
             vector_slot: Some(vector_slot),
+            reranker: None,
+            rerank_by_default: false,
+            rerank_top_k: DEFAULT_RERANK_TOP_K,
             custom_names: HashMap::new(),
             custom_descriptions: HashMap::new(),
         }
     }
 
+    /// Set the reranker used for hybrid results.
+    pub fn with_reranker(mut self, reranker: Arc<dyn Reranker>) -> Self {
+        self.reranker = Some(reranker);
+        self
+    }
+
+    /// Rerank hybrid results unless a request sets `rerank: false`.
+    ///
+    /// Has no effect without a reranker.
+    pub fn with_rerank_by_default(mut self, enabled: bool) -> Self {
+        self.rerank_by_default = enabled;
+        self
+    }
+
+    /// Number of fused candidates to rerank (default 20).
+    pub fn with_rerank_top_k(mut self, top_k: usize) -> Self {
+        self.rerank_top_k = top_k.max(1);
+        self
+    }
+
     /// Override tool names by slot key.
     pub fn with_names(mut self, names: HashMap<String, String>) -> Self {
         self.custom_names = names;
@@ -210,6 +318,44 @@ impl SemanticSearchTools {
 
 impl ToolRegistry for SemanticSearchTools {
     fn tools(&self) -> Vec<Tool> {
+        let mut schema = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "query": {
+                    "type": "string",
+                    "description": "Natural language search query"
+                },
+                "mode": {
+                    "type": "string",
+                    "description": "Search mode: 'vector', 'keyword', or 'hybrid' (default)",
+                    "enum": ["vector", "keyword", "hybrid"]
+                },
+                "category": {
+                    "type": "string",
+                    "description": "Filter by category"
+                },
+                "source": {
+                    "type": "string",
+                    "description": "Filter by source"
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "Maximum results (default: 10)"
+                }
+            },
+            "required": ["query"]
+        });
+        if self.reranker.is_some() {
+            schema["properties"]["rerank"] = serde_json::json!({
+                "type": "boolean",
+                "description": format!(
+                    "Rerank hybrid results with a cross-encoder for better precision at \
+                     extra cost (default: {})",
+                    self.rerank_by_default
+                )
+            });
+        }
+
         vec![make_tool(
             &self.tool_name(Self::SLOT_SEMANTIC_SEARCH),
             &self.tool_description(
@@ -217,33 +363,7 @@ impl ToolRegistry for SemanticSearchTools {
                 "Search concepts using semantic similarity. Supports 'vector' (embedding-based), \
                  'keyword' (FTS), or 'hybrid' (both via RRF, default).",
             ),
-            serde_json::json!({
-                "type": "object",
-                "properties": {
-                    "query": {
-                        "type": "string",
-                        "description": "Natural language search query"
-                    },
-                    "mode": {
-                        "type": "string",
-                        "description": "Search mode: 'vector', 'keyword', or 'hybrid' (default)",
-                        "enum": ["vector", "keyword", "hybrid"]
-                    },
-                    "category": {
-                        "type": "string",
-                        "description": "Filter by category"
-                    },
-                    "source": {
-                        "type": "string",
-                        "description": "Filter by source"
-                    },
-                    "limit": {
-                        "type": "integer",
-                        "description": "Maximum results (default: 10)"
-                    }
-                },
-                "required": ["query"]
-            }),
+            schema,
         )]
     }
 
@@ -254,6 +374,9 @@ impl ToolRegistry for SemanticSearchTools {
 
         let fts = self.fts.clone();
         let vector = self.resolve_vector();
+        let configured_reranker = self.reranker.clone();
+        let rerank_by_default = self.rerank_by_default;
+        let rerank_top_k = self.rerank_top_k;
 
         Some(Box::pin(async move {
             let args: SemanticSearchArgs = serde_json::from_value(args)
@@ -261,6 +384,18 @@ impl ToolRegistry for SemanticSearchTools {
 
             let mode = args.mode.as_deref().unwrap_or("hybrid");
             let limit = args.limit.unwrap_or(10).min(50);
+            let reranker = match (args.rerank, configured_reranker) {
+                (Some(true), None) => {
+                    return Err(ErrorData::invalid_params(
+                        "Reranking is not configured on this server".to_string(),
+                        None,
+                    ));
+                }
+                (requested, Some(reranker)) if requested.unwrap_or(rerank_by_default) => {
+                    Some(reranker)
+                }
+                _ => None,
+            };
 
             match mode {
                 "vector" => {
@@ -270,12 +405,13 @@ impl ToolRegistry for SemanticSearchTools {
                             None,
                         )
                     })?;
-                    let params = VectorSearchParams::new(&args.query).with_limit(limit);
+                    let params =
+                        VectorSearchParams::new(&args.query).with_limit(limit * CHUNK_OVERFETCH);
                     let results = backend
                         .search(params)
                         .await
                         .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
-                    serialize_response(&results)
+                    serialize_response(&fold_chunks(results, limit))
                 }
                 "keyword" => {
                     let params = SearchParams {
@@ -292,36 +428,82 @@ impl ToolRegistry for SemanticSearchTools {
                     serialize_response(&results)
                 }
                 _ => {
-                    // Hybrid: run both and merge via reciprocal rank fusion
+                    // Hybrid: run both and merge via reciprocal rank fusion.
+                    // When reranking, fuse enough candidates to fill the rerank window.
+                    let started = Instant::now();
+                    let mut timings = SearchTimings::default();
+                    let candidates = if reranker.is_some() {
+                        limit.max(rerank_top_k)
+                    } else {
+                        limit
+                    };
+                    let fetch = (limit * 2).max(candidates);
+
                     let fts_params = SearchParams {
                         query: args.query.clone(),
-                        limit: Some(limit * 2),
+                        limit: Some(fetch),
                         category: args.category.clone(),
                         source: args.source.clone(),
                         ..Default::default()
                     };
 
+                    let stage = Instant::now();
                     let fts_results = fts
                         .search(fts_params)
                         .await
                         .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+                    timings.keyword_ms = Some(SearchTimings::since(stage));
 
                     // If vector is available, do hybrid; otherwise fall back to FTS only
                     if let Some(ref backend) = vector {
-                        let vector_params =
-                            VectorSearchParams::new(&args.query).with_limit(limit * 2);
+                        let vector_params = VectorSearchParams::new(&args.query)
+                            .with_limit(fetch * CHUNK_OVERFETCH);
+                        let stage = Instant::now();
                         let vector_results = backend
                             .search(vector_params)
                             .await
                             .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+                        // Fold chunk hits so they match FTS document IDs
+                        let vector_results = fold_chunks(vector_results, fetch);
+                        timings.vector_ms = Some(SearchTimings::since(stage));
 
                         // Convert FTS results to the adapter type and run RRF
+                        let stage = Instant::now();
                         let fts_adapted = to_fts_results(&fts_results);
-                        let merged =
-                            reciprocal_rank_fusion(&vector_results.items, &fts_adapted, limit, 60);
-                        let results: Vec<HybridResult> =
-                            merged.into_iter().map(HybridResult::from).collect();
-                        serialize_response(&results)
+                        let mut merged = reciprocal_rank_fusion(
+                            &vector_results.items,
+                            &fts_adapted,
+                            candidates,
+                            60,
+                        );
+                        timings.fusion_ms = Some(SearchTimings::since(stage));
+
+                        let reranker_name = match reranker {
+                            Some(ref reranker) => {
+                                let stage = Instant::now();
+                                let texts = fts_rerank_texts(&fts_results);
+                                merged = rerank_hybrid(
+                                    reranker.as_ref(),
+                                    &args.query,
+                                    merged,
+                                    rerank_top_k,
+                                    |r| hybrid_rerank_text(r, &texts),
+                                )
+                                .await
+                                .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+                                timings.rerank_ms = Some(SearchTimings::since(stage));
+                                Some(reranker.name().to_string())
+                            }
+                            None => None,
+                        };
+                        merged.truncate(limit);
+                        timings.total_ms = SearchTimings::since(started);
+
+                        serialize_response(&HybridResponse {
+                            results: merged.into_iter().map(HybridResult::from).collect(),
+                            reranker: reranker_name,
+                            timings,
+                        })
                     } else {
                         // No vector backend — return FTS results only
                         serialize_response(&fts_results)
@@ -340,6 +522,12 @@ impl ToolRegistry for SemanticSearchTools {
 mod tests {
     use super::*;
     use fabryk_fts::{SearchResult, SearchResults};
+    use fabryk_mcp_core::model::CallToolResult;
+    use fabryk_vector::MockReranker;
+
+    fn response_json(result: &CallToolResult) -> Value {
+        serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap()
+    }
 
     // -- Test helpers -------------------------------------------------------
 
@@ -351,21 +539,19 @@ mod tests {
             category: String::new(),
             source: None,
             snippet: None,
+            passages: Vec::new(),
             relevance,
             content_type: None,
             path: None,
             chapter: None,
             section: None,
+            date: None,
         }
     }
 
     fn make_fts_results(items: Vec<SearchResult>) -> SearchResults {
         let total = items.len();
-        SearchResults {
-            items,
-            total,
-            backend: "test".to_string(),
-        }
+        SearchResults::page(items, total, 0, "test")
     }
 
     // -- Mock backends ------------------------------------------------------
@@ -421,6 +607,7 @@ mod tests {
                     score: 1.0 - i as f32 * 0.1,
                     distance: i as f32 * 0.1,
                     metadata: HashMap::new(),
+                    passage: None,
                 })
                 .collect();
             Self::new(items)
@@ -563,6 +750,43 @@ mod tests {
         assert!(!result.is_error.unwrap_or(false));
     }
 
+    #[tokio::test]
+    async fn test_vector_mode_folds_chunks() {
+        let chunk = |id: &str, parent: &str, score: f32| fabryk_vector::VectorSearchResult {
+            id: id.to_string(),
+            score,
+            distance: 1.0 - score,
+            metadata: HashMap::from([("parent_id".to_string(), parent.to_string())]),
+            passage: Some(format!("passage {id}")),
+        };
+        let vector = MockVector::new(vec![
+            chunk("book#3", "book", 0.9),
+            chunk("book#1", "book", 0.8),
+            chunk("essay#0", "essay", 0.7),
+        ]);
+        let tools = SemanticSearchTools::new(Arc::new(MockFts::empty()), Some(Arc::new(vector)));
+
+        let result = tools
+            .call(
+                "semantic_search",
+                serde_json::json!({"query": "test", "mode": "vector"}),
+            )
+            .unwrap()
+            .await
+            .unwrap();
+
+        let text = &result.content[0].as_text().unwrap().text;
+        let json: Value = serde_json::from_str(text).unwrap();
+        let ids: Vec<&str> = json["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|r| r["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["book", "essay"]);
+        assert_eq!(json["items"][0]["passage"], "passage book#3");
+    }
+
     // -- Hybrid mode tests ------------------------------------------------
 
     #[tokio::test]
@@ -598,6 +822,109 @@ mod tests {
 
         // Default mode is hybrid
         assert!(!result.is_error.unwrap_or(false));
+        let json = response_json(&result);
+        assert_eq!(json["results"][0]["id"], "both");
+        assert_eq!(json["results"][0]["source"], "hybrid");
+        assert!(json["timings"]["keyword_ms"].is_number());
+        assert!(json["timings"]["fusion_ms"].is_number());
+        assert!(json["timings"].get("rerank_ms").is_none());
+        assert!(json.get("reranker").is_none());
+    }
+
+    // -- Rerank tests -----------------------------------------------------
+
+    fn make_rerank_tools() -> SemanticSearchTools {
+        let mut relevant = make_fts_result("relevant", 0.2);
+        relevant.title = "Deceptive cadence".to_string();
+        relevant.snippet = Some("The deceptive cadence resolves V to vi".to_string());
+        let mut popular = make_fts_result("popular", 0.9);
+        popular.title = "Scales".to_string();
+        make_tools_with_vector(vec![popular, relevant], &["popular", "other"])
+            .with_reranker(Arc::new(MockReranker))
+    }
+
+    #[tokio::test]
+    async fn test_hybrid_rerank_reorders_results() {
+        let tools = make_rerank_tools();
+        let result = tools
+            .call(
+                "semantic_search",
+                serde_json::json!({"query": "deceptive cadence", "rerank": true}),
+            )
+            .unwrap()
+            .await
+            .unwrap();
+
+        let json = response_json(&result);
+        assert_eq!(json["results"][0]["id"], "relevant");
+        assert_eq!(json["results"][0]["rerank_score"], 1.0);
+        assert_eq!(json["reranker"], "mock");
+        assert!(json["timings"]["rerank_ms"].is_number());
+    }
+
+    #[tokio::test]
+    async fn test_hybrid_rerank_respects_server_default() {
+        let tools = make_rerank_tools().with_rerank_by_default(true);
+        let query = serde_json::json!({"query": "deceptive cadence"});
+        let json = response_json(&tools.call("semantic_search", query).unwrap().await.unwrap());
+        assert_eq!(json["results"][0]["id"], "relevant");
+
+        let opt_out = serde_json::json!({"query": "deceptive cadence", "rerank": false});
+        let json = response_json(
+            &tools
+                .call("semantic_search", opt_out)
+                .unwrap()
+                .await
+                .unwrap(),
+        );
+        assert_eq!(json["results"][0]["id"], "popular");
+        assert!(json.get("reranker").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_hybrid_rerank_without_reranker_is_invalid() {
+        let tools = make_tools_with_vector(vec![], &["a"]);
+        let result = tools
+            .call(
+                "semantic_search",
+                serde_json::json!({"query": "test", "rerank": true}),
+            )
+            .unwrap()
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_rerank_schema_only_with_reranker() {
+        let plain = make_tools_fts_only();
+        let schema = serde_json::to_value(&plain.tools()[0].input_schema).unwrap();
+        assert!(schema["properties"].get("rerank").is_none());
+
+        let tools = make_rerank_tools();
+        let schema = serde_json::to_value(&tools.tools()[0].input_schema).unwrap();
+        assert_eq!(schema["properties"]["rerank"]["type"], "boolean");
+    }
+
+    #[test]
+    fn test_hybrid_rerank_text_fallbacks() {
+        let mut fts_item = make_fts_result("a", 0.5);
+        fts_item.description = Some("desc".to_string());
+        let texts = fts_rerank_texts(&make_fts_results(vec![fts_item]));
+        let mut result = HybridSearchResult {
+            id: "a".to_string(),
+            score: 0.1,
+            source: "hybrid".to_string(),
+            metadata: HashMap::from([("title".to_string(), "Meta title".to_string())]),
+            passage: Some("passage".to_string()),
+            rerank_score: None,
+        };
+        assert_eq!(hybrid_rerank_text(&result, &texts), "a\ndesc");
+
+        result.id = "b".to_string();
+        assert_eq!(hybrid_rerank_text(&result, &texts), "passage");
+
+        result.passage = None;
+        assert_eq!(hybrid_rerank_text(&result, &texts), "Meta title");
     }
 
     // -- Argument validation -----------------------------------------------
@@ -742,11 +1069,14 @@ mod tests {
             score: 0.5,
             source: "hybrid".to_string(),
             metadata: HashMap::new(),
+            passage: None,
+            rerank_score: Some(0.25),
         };
         let result = HybridResult::from(search_result);
         assert_eq!(result.id, "doc-1");
         assert!((result.rrf_score - 0.5).abs() < f32::EPSILON);
         assert_eq!(result.source, "hybrid");
+        assert_eq!(result.rerank_score, Some(0.25));
     }
 
     #[test]
@@ -756,12 +1086,15 @@ mod tests {
             rrf_score: 0.5,
             source: "keyword".to_string(),
             metadata: HashMap::new(),
+            passage: None,
+            rerank_score: None,
         };
         let json = serde_json::to_string(&result).unwrap();
         assert!(json.contains("test-id"));
         assert!(json.contains("rrf_score"));
         // Empty metadata should be omitted
         assert!(!json.contains("metadata"));
+        assert!(!json.contains("rerank_score"));
     }
 
     #[test]
@@ -771,6 +1104,8 @@ mod tests {
             rrf_score: 1.0,
             source: "vector".to_string(),
             metadata: HashMap::new(),
+            passage: None,
+            rerank_score: None,
         };
         let cloned = result.clone();
         assert_eq!(cloned.id, "x");
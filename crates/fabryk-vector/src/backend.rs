@@ -13,12 +13,14 @@ use async_trait::async_trait;
 use fabryk_core::{Error, Result};
 use serde::{Deserialize, Serialize};
 
+use crate::chunking::is_chunk;
 use crate::embedding::EmbeddingProvider;
 use crate::types::{
     EmbeddedDocument, VectorConfig, VectorSearchParams, VectorSearchResult, VectorSearchResults,
 };
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 /// Abstract vector search backend trait.
 ///
@@ -30,6 +32,12 @@ use std::sync::Arc;
 ///
 /// The `search` method is async to support I/O-bound operations (embedding
 /// generation, index access) without blocking.
+///
+/// # Incremental updates
+///
+/// `upsert`, `delete` and `get` let callers change individual documents
+/// without rebuilding the index. Read-only backends keep the default
+/// implementations, which return an error.
 #[async_trait]
 pub trait VectorBackend: Send + Sync {
     /// Execute a vector similarity search.
@@ -49,6 +57,35 @@ pub trait VectorBackend: Send + Sync {
 
     /// Get the number of indexed documents.
     fn document_count(&self) -> Result<usize>;
+
+    /// Insert documents, replacing any already indexed with the same ID.
+    async fn upsert(&self, documents: Vec<EmbeddedDocument>) -> Result<()> {
+        let _ = documents;
+        Err(Error::operation(format!(
+            "{} vector backend does not support upsert",
+            self.name()
+        )))
+    }
+
+    /// Remove documents by ID, returning how many were removed.
+    ///
+    /// Unknown IDs are ignored.
+    async fn delete(&self, ids: &[String]) -> Result<usize> {
+        let _ = ids;
+        Err(Error::operation(format!(
+            "{} vector backend does not support delete",
+            self.name()
+        )))
+    }
+
+    /// Get an indexed document by ID.
+    async fn get(&self, id: &str) -> Result<Option<EmbeddedDocument>> {
+        let _ = id;
+        Err(Error::operation(format!(
+            "{} vector backend does not support get",
+            self.name()
+        )))
+    }
 }
 
 /// Create a vector backend based on configuration.
@@ -86,6 +123,12 @@ pub fn create_vector_backend(
 #[derive(Serialize, Deserialize)]
 struct VectorCache {
     content_hash: String,
+    #[serde(default)]
+    provider: String,
+    #[serde(default)]
+    model: String,
+    #[serde(default)]
+    dimension: usize,
     documents: Vec<EmbeddedDocument>,
 }
 
@@ -93,6 +136,19 @@ struct VectorCache {
 #[derive(Deserialize)]
 struct VectorCacheHeader {
     content_hash: String,
+    #[serde(default)]
+    provider: String,
+    #[serde(default)]
+    model: String,
+    #[serde(default)]
+    dimension: usize,
+}
+
+impl VectorCacheHeader {
+    fn read(path: &Path) -> Option<Self> {
+        let json = std::fs::read_to_string(path).ok()?;
+        serde_json::from_str(&json).ok()
+    }
 }
 
 /// Brute-force vector search backend.
@@ -104,7 +160,9 @@ struct VectorCacheHeader {
 ///
 /// Supports cache persistence via [`save_cache`](Self::save_cache) and
 /// [`load_cache`](Self::load_cache). Use [`is_cache_fresh`](Self::is_cache_fresh)
-/// to check if a cached index is still valid.
+/// to check if a cached index is still valid, and
+/// [`is_cache_compatible`](Self::is_cache_compatible) to check that it was
+/// embedded by the same provider, model and dimension.
 ///
 /// # Limitations
 ///
@@ -112,7 +170,7 @@ struct VectorCacheHeader {
 /// - All documents must fit in memory
 pub struct SimpleVectorBackend {
     provider: Arc<dyn EmbeddingProvider>,
-    documents: Vec<EmbeddedDocument>,
+    documents: RwLock<Vec<EmbeddedDocument>>,
 }
 
 impl SimpleVectorBackend {
@@ -120,23 +178,56 @@ impl SimpleVectorBackend {
     pub fn new(provider: Arc<dyn EmbeddingProvider>) -> Self {
         Self {
             provider,
-            documents: Vec::new(),
+            documents: RwLock::new(Vec::new()),
         }
     }
 
     /// Add documents to the backend.
+    ///
+    /// Unlike [`upsert`](VectorBackend::upsert), this does not check for
+    /// existing documents with the same ID.
     pub fn add_documents(&mut self, documents: Vec<EmbeddedDocument>) {
-        self.documents.extend(documents);
+        self.documents
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .extend(documents);
+    }
+
+    /// IDs of all indexed documents, in index order.
+    pub fn ids(&self) -> Result<Vec<String>> {
+        Ok(self
+            .read_documents()?
+            .iter()
+            .map(|d| d.document.id.clone())
+            .collect())
+    }
+
+    fn read_documents(&self) -> Result<RwLockReadGuard<'_, Vec<EmbeddedDocument>>> {
+        self.documents
+            .read()
+            .map_err(|_| Error::operation("Failed to acquire read lock for vector documents"))
+    }
+
+    fn write_documents(&self) -> Result<RwLockWriteGuard<'_, Vec<EmbeddedDocument>>> {
+        self.documents
+            .write()
+            .map_err(|_| Error::operation("Failed to acquire write lock for vector documents"))
     }
 
     /// Save the backend's documents to a cache file.
     ///
-    /// Stores documents and a content hash for freshness checking.
+    /// Stores documents, a content hash for freshness checking, and the
+    /// provider name, model and dimension the documents were embedded with.
     /// Uses JSON format for simplicity and debuggability.
     pub fn save_cache(&self, path: &Path, content_hash: &str) -> Result<()> {
+        let documents = self.read_documents()?.clone();
+        let document_count = documents.len();
         let cache = VectorCache {
             content_hash: content_hash.to_string(),
-            documents: self.documents.clone(),
+            provider: self.provider.name().to_string(),
+            model: self.provider.model().to_string(),
+            dimension: self.provider.dimension(),
+            documents,
         };
 
         // Ensure parent directory exists
@@ -153,7 +244,7 @@ impl SimpleVectorBackend {
 
         log::info!(
             "Saved vector cache: {} documents to {}",
-            self.documents.len(),
+            document_count,
             path.display()
         );
 
@@ -174,12 +265,13 @@ impl SimpleVectorBackend {
         let cache: VectorCache = serde_json::from_str(&json)
             .map_err(|e| Error::parse(format!("Failed to parse vector cache: {e}")))?;
 
+        let document_count = cache.documents.len();
         let mut backend = Self::new(provider);
-        backend.documents = cache.documents;
+        backend.add_documents(cache.documents);
 
         log::info!(
             "Loaded vector cache: {} documents from {}",
-            backend.documents.len(),
+            document_count,
             path.display()
         );
 
@@ -192,14 +284,19 @@ impl SimpleVectorBackend {
             return false;
         }
 
-        // Read only the content_hash field without deserializing the full document array
-        if let Ok(json) = std::fs::read_to_string(path)
-            && let Ok(cache) = serde_json::from_str::<VectorCacheHeader>(&json)
-        {
-            return cache.content_hash == content_hash;
-        }
+        // Read only the header fields without deserializing the full document array
+        VectorCacheHeader::read(path).is_some_and(|cache| cache.content_hash == content_hash)
+    }
 
-        false
+    /// Check if the cache was embedded by `provider`: same provider name,
+    /// model and dimension. Caches written before these were recorded are
+    /// never compatible.
+    pub fn is_cache_compatible(path: &Path, provider: &dyn EmbeddingProvider) -> bool {
+        VectorCacheHeader::read(path).is_some_and(|cache| {
+            cache.provider == provider.name()
+                && cache.model == provider.model()
+                && cache.dimension == provider.dimension()
+        })
     }
 
     /// Compute cosine similarity between two vectors.
@@ -223,7 +320,7 @@ impl SimpleVectorBackend {
 #[async_trait]
 impl VectorBackend for SimpleVectorBackend {
     async fn search(&self, params: VectorSearchParams) -> Result<VectorSearchResults> {
-        if self.documents.is_empty() {
+        if self.read_documents()?.is_empty() {
             return Ok(VectorSearchResults::empty(self.name()));
         }
 
@@ -231,8 +328,8 @@ impl VectorBackend for SimpleVectorBackend {
         let limit = params.limit.unwrap_or(10);
         let threshold = params.similarity_threshold.unwrap_or(0.0);
 
-        let mut scored: Vec<(usize, f32)> = self
-            .documents
+        let documents = self.read_documents()?;
+        let mut scored: Vec<(usize, f32)> = documents
             .iter()
             .enumerate()
             .map(|(i, doc)| {
@@ -245,14 +342,14 @@ impl VectorBackend for SimpleVectorBackend {
         // Filter by category if specified
         if let Some(ref category) = params.category {
             scored.retain(|(i, _)| {
-                self.documents[*i].document.category.as_deref() == Some(category.as_str())
+                documents[*i].document.category.as_deref() == Some(category.as_str())
             });
         }
 
         // Filter by metadata
         for (key, value) in &params.metadata_filters {
             scored.retain(|(i, _)| {
-                self.documents[*i]
+                documents[*i]
                     .document
                     .metadata
                     .get(key)
@@ -269,13 +366,14 @@ impl VectorBackend for SimpleVectorBackend {
         let items: Vec<VectorSearchResult> = scored
             .into_iter()
             .map(|(i, score)| {
-                let doc = &self.documents[i];
+                let doc = &documents[i];
                 let distance = 1.0 - score; // cosine distance
                 VectorSearchResult {
                     id: doc.document.id.clone(),
                     score,
                     distance,
                     metadata: doc.document.metadata.clone(),
+                    passage: is_chunk(&doc.document).then(|| doc.document.text.clone()),
                 }
             })
             .collect();
@@ -292,14 +390,49 @@ impl VectorBackend for SimpleVectorBackend {
     }
 
     fn document_count(&self) -> Result<usize> {
-        Ok(self.documents.len())
+        Ok(self.read_documents()?.len())
+    }
+
+    async fn upsert(&self, documents: Vec<EmbeddedDocument>) -> Result<()> {
+        let mut indexed = self.write_documents()?;
+        let mut positions: HashMap<String, usize> = indexed
+            .iter()
+            .enumerate()
+            .map(|(i, d)| (d.document.id.clone(), i))
+            .collect();
+        for document in documents {
+            match positions.get(&document.document.id) {
+                Some(&i) => indexed[i] = document,
+                None => {
+                    positions.insert(document.document.id.clone(), indexed.len());
+                    indexed.push(document);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, ids: &[String]) -> Result<usize> {
+        let ids: HashSet<&str> = ids.iter().map(String::as_str).collect();
+        let mut indexed = self.write_documents()?;
+        let before = indexed.len();
+        indexed.retain(|d| !ids.contains(d.document.id.as_str()));
+        Ok(before - indexed.len())
+    }
+
+    async fn get(&self, id: &str) -> Result<Option<EmbeddedDocument>> {
+        Ok(self
+            .read_documents()?
+            .iter()
+            .find(|d| d.document.id == id)
+            .cloned())
     }
 }
 
 impl std::fmt::Debug for SimpleVectorBackend {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("SimpleVectorBackend")
-            .field("documents", &self.documents.len())
+            .field("documents", &self.document_count().unwrap_or(0))
             .finish()
     }
 }
@@ -550,6 +683,96 @@ mod tests {
         fn _assert_object_safe(_: &dyn VectorBackend) {}
     }
 
+    #[tokio::test]
+    async fn test_simple_backend_upsert_replaces_by_id() {
+        let backend = SimpleVectorBackend::new(mock_provider());
+        backend
+            .upsert(vec![
+                EmbeddedDocument::new(VectorDocument::new("doc-1", "old"), vec![1.0; 8]),
+                EmbeddedDocument::new(VectorDocument::new("doc-2", "two"), vec![1.0; 8]),
+            ])
+            .await
+            .unwrap();
+        backend
+            .upsert(vec![
+                EmbeddedDocument::new(VectorDocument::new("doc-1", "new"), vec![0.5; 8]),
+                EmbeddedDocument::new(VectorDocument::new("doc-3", "three"), vec![1.0; 8]),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(backend.document_count().unwrap(), 3);
+        assert_eq!(backend.ids().unwrap(), vec!["doc-1", "doc-2", "doc-3"]);
+        let doc = backend.get("doc-1").await.unwrap().unwrap();
+        assert_eq!(doc.document.text, "new");
+        assert_eq!(doc.embedding, vec![0.5; 8]);
+    }
+
+    #[tokio::test]
+    async fn test_simple_backend_delete() {
+        let backend = SimpleVectorBackend::new(mock_provider());
+        backend
+            .upsert(vec![
+                EmbeddedDocument::new(VectorDocument::new("doc-1", "one"), vec![1.0; 8]),
+                EmbeddedDocument::new(VectorDocument::new("doc-2", "two"), vec![1.0; 8]),
+            ])
+            .await
+            .unwrap();
+
+        let removed = backend
+            .delete(&["doc-1".to_string(), "missing".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(removed, 1);
+        assert_eq!(backend.ids().unwrap(), vec!["doc-2"]);
+        assert!(backend.get("doc-1").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_simple_backend_search_returns_chunk_passage() {
+        let mut backend = SimpleVectorBackend::new(mock_provider());
+        let chunk = VectorDocument::new("book#0", "chapter text")
+            .with_metadata(crate::chunking::PARENT_ID_KEY, "book");
+        backend.add_documents(vec![
+            EmbeddedDocument::new(chunk, vec![1.0; 8]),
+            EmbeddedDocument::new(VectorDocument::new("note", "note text"), vec![1.0; 8]),
+        ]);
+
+        let results = backend
+            .search(VectorSearchParams::new("query"))
+            .await
+            .unwrap();
+        for item in &results.items {
+            match item.id.as_str() {
+                "book#0" => assert_eq!(item.passage.as_deref(), Some("chapter text")),
+                _ => assert_eq!(item.passage, None),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_default_incremental_methods_unsupported() {
+        struct ReadOnly;
+
+        #[async_trait]
+        impl VectorBackend for ReadOnly {
+            async fn search(&self, _params: VectorSearchParams) -> Result<VectorSearchResults> {
+                Ok(VectorSearchResults::empty(self.name()))
+            }
+            fn name(&self) -> &str {
+                "read-only"
+            }
+            fn document_count(&self) -> Result<usize> {
+                Ok(0)
+            }
+        }
+
+        let err = ReadOnly.upsert(Vec::new()).await.unwrap_err();
+        assert!(err.to_string().contains("does not support upsert"));
+        assert!(ReadOnly.delete(&[]).await.is_err());
+        assert!(ReadOnly.get("doc-1").await.is_err());
+    }
+
     #[test]
     fn test_simple_backend_debug() {
         let backend = SimpleVectorBackend::new(mock_provider());
@@ -614,6 +837,32 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_cache_compatibility() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("test-cache.json");
+
+        let backend = SimpleVectorBackend::new(mock_provider());
+        backend.save_cache(&cache_path, "hash123").unwrap();
+
+        assert!(SimpleVectorBackend::is_cache_compatible(
+            &cache_path,
+            mock_provider().as_ref()
+        ));
+        assert!(!SimpleVectorBackend::is_cache_compatible(
+            &cache_path,
+            &MockEmbeddingProvider::new(4)
+        ));
+
+        // Caches without provider details predate the check.
+        std::fs::write(&cache_path, r#"{"content_hash":"hash123","documents":[]}"#).unwrap();
+        assert!(SimpleVectorBackend::is_cache_fresh(&cache_path, "hash123"));
+        assert!(!SimpleVectorBackend::is_cache_compatible(
+            &cache_path,
+            mock_provider().as_ref()
+        ));
+    }
+
     #[test]
     fn test_load_cache_nonexistent() {
         let result = SimpleVectorBackend::load_cache(
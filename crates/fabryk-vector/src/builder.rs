@@ -6,20 +6,26 @@
 //! 1. Discover content files using glob patterns
 //! 2. Parse frontmatter and content
 //! 3. Call VectorExtractor to produce VectorDocuments
-//! 4. Batch embed documents via EmbeddingProvider
-//! 5. Insert into VectorBackend
+//! 4. Optionally split documents into chunks via a `Chunker`
+//! 5. Batch embed documents via EmbeddingProvider
+//! 6. Insert into VectorBackend
 //!
 //! # Two-Phase Build
 //!
 //! - Phase 1: Discover + extract all documents (sync, CPU-bound)
 //! - Phase 2: Batch embed + insert (async, may be I/O-bound)
+//!
+//! Embedded documents carry a content hash, so rebuilds from a stale cache
+//! and [`VectorIndexBuilder::sync`] only re-embed documents that changed.
 
 use crate::backend::{SimpleVectorBackend, VectorBackend};
+use crate::chunking::Chunker;
 use crate::embedding::EmbeddingProvider;
 use crate::extractor::VectorExtractor;
 use crate::types::{BuildError, EmbeddedDocument, VectorDocument, VectorIndexStats};
 use fabryk_content::markdown::extract_frontmatter;
 use fabryk_core::{Error, Result};
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Instant;
@@ -59,6 +65,7 @@ pub struct VectorIndexBuilder<E: VectorExtractor> {
     batch_size: usize,
     cache_path: Option<PathBuf>,
     skip_cache: bool,
+    chunker: Option<Chunker>,
 }
 
 impl<E: VectorExtractor> VectorIndexBuilder<E> {
@@ -72,6 +79,7 @@ impl<E: VectorExtractor> VectorIndexBuilder<E> {
             batch_size: 64,
             cache_path: None,
             skip_cache: false,
+            chunker: None,
         }
     }
 
@@ -110,6 +118,16 @@ impl<E: VectorExtractor> VectorIndexBuilder<E> {
         self
     }
 
+    /// Splits each extracted document into chunks before embedding.
+    ///
+    /// Chunks are indexed instead of whole documents; use
+    /// [`fold_chunks`](crate::chunking::fold_chunks) on search results to
+    /// map hits back to their documents. Index statistics then count chunks.
+    pub fn with_chunker(mut self, chunker: Chunker) -> Self {
+        self.chunker = Some(chunker);
+        self
+    }
+
     /// Forces a rebuild even if the cache is fresh.
     pub fn skip_cache(mut self) -> Self {
         self.skip_cache = true;
@@ -127,123 +145,124 @@ impl<E: VectorExtractor> VectorIndexBuilder<E> {
     ///    call extractor to produce `VectorDocument`s.
     /// 2. **Batch Embed + Insert**: Embed documents in batches via the
     ///    provider, then insert into the backend.
+    ///
+    /// # Incremental rebuilds
+    ///
+    /// When a cache path is set and the cache is stale, the previous index
+    /// is loaded and only documents whose content hash changed are
+    /// re-embedded. Documents whose files were removed are deleted.
+    /// A cache embedded by a different provider, model or dimension is
+    /// discarded, and `skip_cache()` forces every document to be re-embedded.
     pub async fn build(self) -> Result<(SimpleVectorBackend, VectorIndexStats)> {
         let start = Instant::now();
-
-        let content_path = self
-            .content_path
-            .as_ref()
-            .ok_or_else(|| Error::config("Content path not set. Use with_content_path() first."))?
-            .clone();
-
-        let provider = self
-            .provider
-            .as_ref()
-            .ok_or_else(|| {
-                Error::config("Embedding provider not set. Use with_embedding_provider() first.")
-            })?
-            .clone();
+        let (content_path, provider) = self.require_inputs()?;
 
         // Check cache freshness (if cache configured and not skipped)
+        let mut previous = None;
         if let Some(ref cache_path) = self.cache_path
             && !self.skip_cache
         {
             let content_hash = compute_content_hash(&content_path).await?;
-            if SimpleVectorBackend::is_cache_fresh(cache_path, &content_hash)
-                && let Ok(Some(backend)) =
-                    SimpleVectorBackend::load_cache(cache_path, provider.clone())
-            {
-                let doc_count = backend.document_count().unwrap_or(0);
-                log::info!(
-                    "Vector cache is fresh, loaded {} documents from {}",
-                    doc_count,
-                    cache_path.display()
-                );
-                let stats = VectorIndexStats {
-                    documents_indexed: doc_count,
-                    files_processed: 0,
-                    files_skipped: 0,
-                    embedding_dimension: provider.dimension(),
-                    content_hash,
-                    build_duration_ms: start.elapsed().as_millis() as u64,
-                    errors: Vec::new(),
-                    from_cache: true,
-                };
-                return Ok((backend, stats));
+            let cached = match SimpleVectorBackend::load_cache(cache_path, provider.clone()) {
+                Ok(cached) => cached,
+                Err(e) => {
+                    log::warn!("Ignoring unreadable vector cache: {e}");
+                    None
+                }
+            };
+            let cached = cached.filter(|_| {
+                let compatible =
+                    SimpleVectorBackend::is_cache_compatible(cache_path, provider.as_ref());
+                if !compatible {
+                    log::info!(
+                        "Vector cache at {} was built with a different embedding provider, \
+                         model or dimension; re-embedding everything",
+                        cache_path.display()
+                    );
+                }
+                compatible
+            });
+            if let Some(backend) = cached {
+                if SimpleVectorBackend::is_cache_fresh(cache_path, &content_hash) {
+                    let doc_count = backend.document_count().unwrap_or(0);
+                    log::info!(
+                        "Vector cache is fresh, loaded {} documents from {}",
+                        doc_count,
+                        cache_path.display()
+                    );
+                    let stats = VectorIndexStats {
+                        documents_indexed: doc_count,
+                        documents_embedded: 0,
+                        documents_removed: 0,
+                        files_processed: 0,
+                        files_skipped: 0,
+                        embedding_dimension: provider.dimension(),
+                        content_hash,
+                        build_duration_ms: start.elapsed().as_millis() as u64,
+                        errors: Vec::new(),
+                        from_cache: true,
+                    };
+                    return Ok((backend, stats));
+                }
+                previous = Some(backend);
             }
         }
 
-        // Discover files
-        let files = discover_files(&content_path).await?;
-
-        let mut errors: Vec<BuildError> = Vec::new();
-        let mut documents: Vec<VectorDocument> = Vec::new();
-        let mut files_processed = 0usize;
-        let mut files_skipped = 0usize;
-
         // ================================================================
         // Phase 1: Discover + Extract documents
         // ================================================================
-        for file_path in &files {
-            match self.extract_file(&content_path, file_path) {
-                Ok(doc) => {
-                    documents.push(doc);
-                }
-                Err(e) => {
-                    let build_error = BuildError {
-                        file: file_path.clone(),
-                        message: e.to_string(),
-                    };
-
-                    match self.error_handling {
-                        ErrorHandling::FailFast => return Err(e),
-                        ErrorHandling::Collect => {
-                            files_skipped += 1;
-                            errors.push(build_error);
-                        }
-                        ErrorHandling::Skip => {
-                            files_skipped += 1;
-                            log::warn!("Skipping {}: {}", file_path.display(), build_error.message);
-                            errors.push(build_error);
-                        }
-                    }
-                }
-            }
-            files_processed += 1;
-        }
+        let extracted = self.extract_documents(&content_path).await?;
+        let documents_indexed = extracted.documents.len();
 
         // ================================================================
         // Phase 2: Batch embed + insert
         // ================================================================
-        let mut embedded_documents: Vec<EmbeddedDocument> = Vec::with_capacity(documents.len());
+        let (backend, documents_embedded, documents_removed) = match previous {
+            Some(backend) => {
+                let current: HashSet<&str> =
+                    extracted.documents.iter().map(|d| d.id.as_str()).collect();
+                let removed: Vec<String> = backend
+                    .ids()?
+                    .into_iter()
+                    .filter(|id| !current.contains(id.as_str()))
+                    .collect();
+                let documents_removed = backend.delete(&removed).await?;
+
+                let changed = self
+                    .embed_changed(&provider, &extracted.documents, &backend)
+                    .await?;
+                let documents_embedded = changed.len();
+                backend.upsert(changed).await?;
 
-        for chunk in documents.chunks(self.batch_size) {
-            let texts: Vec<&str> = chunk.iter().map(|d| d.text.as_str()).collect();
-            let embeddings = provider.embed_batch(&texts).await?;
-
-            for (doc, embedding) in chunk.iter().zip(embeddings.into_iter()) {
-                embedded_documents.push(EmbeddedDocument::new(doc.clone(), embedding));
+                log::info!(
+                    "Vector cache is stale: re-embedded {} of {} documents, removed {}",
+                    documents_embedded,
+                    documents_indexed,
+                    documents_removed,
+                );
+                (backend, documents_embedded, documents_removed)
             }
-        }
-
-        let documents_indexed = embedded_documents.len();
-        let embedding_dimension = provider.dimension();
+            None => {
+                let embedded = self.embed(&provider, &extracted.documents).await?;
+                let mut backend = SimpleVectorBackend::new(provider.clone());
+                backend.add_documents(embedded);
+                (backend, documents_indexed, 0)
+            }
+        };
 
         // Compute content hash
         let content_hash = compute_content_hash(&content_path).await?;
 
-        // Build the backend
-        let mut backend = SimpleVectorBackend::new(provider);
-        backend.add_documents(embedded_documents);
-
         let stats = VectorIndexStats {
             documents_indexed,
-            files_processed,
-            files_skipped,
-            embedding_dimension,
+            documents_embedded,
+            documents_removed,
+            files_processed: extracted.files_processed,
+            files_skipped: extracted.files_skipped,
+            embedding_dimension: provider.dimension(),
             content_hash: content_hash.clone(),
             build_duration_ms: start.elapsed().as_millis() as u64,
-            errors,
+            errors: extracted.errors,
             from_cache: false,
         };
 
@@ -257,50 +276,60 @@ impl<E: VectorExtractor> VectorIndexBuilder<E> {
         Ok((backend, stats))
     }
 
-    /// Extract a single file to a VectorDocument.
-    fn extract_file(&self, base_path: &Path, file_path: &Path) -> Result<VectorDocument> {
-        let content =
-            std::fs::read_to_string(file_path).map_err(|e| Error::io_with_path(e, file_path))?;
+    /// Bring an existing backend up to date with the content path.
+    ///
+    /// Each extracted document is looked up with
+    /// [`VectorBackend::get`]; only documents that are missing or whose
+    /// content hash changed are embedded and written with
+    /// [`VectorBackend::upsert`]. Works with any backend that supports
+    /// incremental updates, including persistent ones.
+    ///
+    /// Documents whose source files were removed, and chunks left over when
+    /// a chunked document shrank, are not detected here, since the backend
+    /// may hold documents from other content paths. Remove them with
+    /// [`VectorBackend::delete`].
+    pub async fn sync(self, backend: &dyn VectorBackend) -> Result<VectorIndexStats> {
+        let start = Instant::now();
+        let (content_path, provider) = self.require_inputs()?;
 
-        let fm_result = extract_frontmatter(&content)?;
+        let extracted = self.extract_documents(&content_path).await?;
+        let changed = self
+            .embed_changed(&provider, &extracted.documents, backend)
+            .await?;
+        let documents_embedded = changed.len();
+        backend.upsert(changed).await?;
 
-        let frontmatter = fm_result
-            .value()
-            .cloned()
-            .unwrap_or(yaml_serde::Value::Null);
-        let body = fm_result.body();
+        let stats = VectorIndexStats {
+            documents_indexed: extracted.documents.len(),
+            documents_embedded,
+            documents_removed: 0,
+            files_processed: extracted.files_processed,
+            files_skipped: extracted.files_skipped,
+            embedding_dimension: provider.dimension(),
+            content_hash: compute_content_hash(&content_path).await?,
+            build_duration_ms: start.elapsed().as_millis() as u64,
+            errors: extracted.errors,
+            from_cache: false,
+        };
+
+        log::info!(
+            "Synced {} vector documents from {} ({} re-embedded, {} errors)",
+            stats.documents_indexed,
+            content_path.display(),
+            documents_embedded,
+            stats.errors.len(),
+        );
 
-        self.extractor
-            .extract_document(base_path, file_path, &frontmatter, body)
+        Ok(stats)
     }
 
-    /// Append documents from a content path into an existing backend.
-    ///
-    /// Unlike `build()`, this does not create a new backend — it adds
-    /// embedded documents to the provided one. Use this to index multiple
-    /// content directories (potentially with different extractors) into
-    /// a single vector search backend.
-    ///
-    /// # Example
-    ///
-    /// ```rust,ignore
-    /// // Build initial index from concept cards
-    /// let (mut backend, stats1) = VectorIndexBuilder::new(card_extractor)
-    ///     .with_content_path(&cards_path)
-    ///     .with_embedding_provider(provider.clone())
-    ///     .build()
-    ///     .await?;
-    ///
-    /// // Append source documents with a different extractor
-    /// let stats2 = VectorIndexBuilder::new(source_extractor)
-    ///     .with_content_path(&sources_path)
-    ///     .with_embedding_provider(provider)
-    ///     .build_append(&mut backend)
-    ///     .await?;
-    /// ```
-    pub async fn build_append(self, backend: &mut SimpleVectorBackend) -> Result<VectorIndexStats> {
-        let start = Instant::now();
+    /// Extract a single file to a VectorDocument.
+    fn extract_file(&self, base_path: &Path, file_path: &Path) -> Result<VectorDocument> {
+        extract_file(&self.extractor, base_path, file_path)
+    }
 
+    /// The content path and embedding provider, which every build needs.
+    fn require_inputs(&self) -> Result<(PathBuf, Arc<dyn EmbeddingProvider>)> {
         let content_path = self
             .content_path
             .as_ref()
@@ -315,18 +344,18 @@ impl<E: VectorExtractor> VectorIndexBuilder<E> {
             })?
             .clone();
 
-        let files = discover_files(&content_path).await?;
+        Ok((content_path, provider))
+    }
 
-        let mut errors: Vec<BuildError> = Vec::new();
-        let mut documents: Vec<VectorDocument> = Vec::new();
-        let mut files_processed = 0usize;
-        let mut files_skipped = 0usize;
+    /// Discover content files and extract a document from each.
+    async fn extract_documents(&self, content_path: &Path) -> Result<Extracted> {
+        let files = discover_files(content_path).await?;
 
-        // Phase 1: Discover + Extract
+        let mut extracted = Extracted::default();
         for file_path in &files {
-            match self.extract_file(&content_path, file_path) {
+            match self.extract_file(content_path, file_path) {
                 Ok(doc) => {
-                    documents.push(doc);
+                    extracted.documents.push(doc);
                 }
                 Err(e) => {
                     let build_error = BuildError {
@@ -337,32 +366,106 @@ impl<E: VectorExtractor> VectorIndexBuilder<E> {
                     match self.error_handling {
                         ErrorHandling::FailFast => return Err(e),
                         ErrorHandling::Collect => {
-                            files_skipped += 1;
-                            errors.push(build_error);
+                            extracted.files_skipped += 1;
+                            extracted.errors.push(build_error);
                         }
                         ErrorHandling::Skip => {
-                            files_skipped += 1;
+                            extracted.files_skipped += 1;
                             log::warn!("Skipping {}: {}", file_path.display(), build_error.message);
-                            errors.push(build_error);
+                            extracted.errors.push(build_error);
                         }
                     }
                 }
             }
-            files_processed += 1;
+            extracted.files_processed += 1;
         }
 
-        // Phase 2: Batch embed + insert into existing backend
+        if let Some(ref chunker) = self.chunker {
+            extracted.documents = extracted
+                .documents
+                .iter()
+                .flat_map(|doc| chunker.chunk(doc))
+                .collect();
+        }
+
+        Ok(extracted)
+    }
+
+    /// Embed documents in batches, recording each document's content hash.
+    async fn embed(
+        &self,
+        provider: &Arc<dyn EmbeddingProvider>,
+        documents: &[VectorDocument],
+    ) -> Result<Vec<EmbeddedDocument>> {
         let mut embedded_documents: Vec<EmbeddedDocument> = Vec::with_capacity(documents.len());
 
-        for chunk in documents.chunks(self.batch_size) {
+        for chunk in documents.chunks(self.batch_size.max(1)) {
             let texts: Vec<&str> = chunk.iter().map(|d| d.text.as_str()).collect();
             let embeddings = provider.embed_batch(&texts).await?;
 
-            for (doc, embedding) in chunk.iter().zip(embeddings.into_iter()) {
-                embedded_documents.push(EmbeddedDocument::new(doc.clone(), embedding));
+            for (doc, embedding) in chunk.iter().zip(embeddings) {
+                embedded_documents.push(EmbeddedDocument::hashed(doc.clone(), embedding));
             }
         }
 
+        Ok(embedded_documents)
+    }
+
+    /// Embed the documents that `backend` is missing or holds an outdated
+    /// copy of. A stored embedding whose length differs from the provider's
+    /// dimension counts as outdated.
+    async fn embed_changed(
+        &self,
+        provider: &Arc<dyn EmbeddingProvider>,
+        documents: &[VectorDocument],
+        backend: &dyn VectorBackend,
+    ) -> Result<Vec<EmbeddedDocument>> {
+        let mut changed = Vec::new();
+        for doc in documents {
+            let current = backend.get(&doc.id).await?.is_some_and(|existing| {
+                existing.is_current(doc) && existing.dimension() == provider.dimension()
+            });
+            if !current {
+                changed.push(doc.clone());
+            }
+        }
+        self.embed(provider, &changed).await
+    }
+
+    /// Append documents from a content path into an existing backend.
+    ///
+    /// Unlike `build()`, this does not create a new backend — it adds
+    /// embedded documents to the provided one. Use this to index multiple
+    /// content directories (potentially with different extractors) into
+    /// a single vector search backend.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Build initial index from concept cards
+    /// let (mut backend, stats1) = VectorIndexBuilder::new(card_extractor)
+    ///     .with_content_path(&cards_path)
+    ///     .with_embedding_provider(provider.clone())
+    ///     .build()
+    ///     .await?;
+    ///
+    /// // Append source documents with a different extractor
+    /// let stats2 = VectorIndexBuilder::new(source_extractor)
+    ///     .with_content_path(&sources_path)
+    ///     .with_embedding_provider(provider)
+    ///     .build_append(&mut backend)
+    ///     .await?;
+    /// ```
+    pub async fn build_append(self, backend: &mut SimpleVectorBackend) -> Result<VectorIndexStats> {
+        let start = Instant::now();
+        let (content_path, provider) = self.require_inputs()?;
+
+        // Phase 1: Discover + Extract
+        let extracted = self.extract_documents(&content_path).await?;
+
+        // Phase 2: Batch embed + insert into existing backend
+        let embedded_documents = self.embed(&provider, &extracted.documents).await?;
+
         let documents_indexed = embedded_documents.len();
         let embedding_dimension = provider.dimension();
         let content_hash = compute_content_hash(&content_path).await?;
@@ -371,12 +474,14 @@ impl<E: VectorExtractor> VectorIndexBuilder<E> {
 
         let stats = VectorIndexStats {
             documents_indexed,
-            files_processed,
-            files_skipped,
+            documents_embedded: documents_indexed,
+            documents_removed: 0,
+            files_processed: extracted.files_processed,
+            files_skipped: extracted.files_skipped,
             embedding_dimension,
             content_hash,
             build_duration_ms: start.elapsed().as_millis() as u64,
-            errors,
+            errors: extracted.errors,
             from_cache: false,
         };
 
@@ -391,10 +496,39 @@ impl<E: VectorExtractor> VectorIndexBuilder<E> {
     }
 }
 
+/// Documents extracted in phase 1, with per-file bookkeeping.
+#[derive(Default)]
+struct Extracted {
+    documents: Vec<VectorDocument>,
+    files_processed: usize,
+    files_skipped: usize,
+    errors: Vec<BuildError>,
+}
+
 // ============================================================================
 // Helper functions
 // ============================================================================
 
+/// Read a content file and run the extractor over its frontmatter and body.
+pub(crate) fn extract_file<E: VectorExtractor>(
+    extractor: &E,
+    base_path: &Path,
+    file_path: &Path,
+) -> Result<VectorDocument> {
+    let content =
+        std::fs::read_to_string(file_path).map_err(|e| Error::io_with_path(e, file_path))?;
+
+    let fm_result = extract_frontmatter(&content)?;
+
+    let frontmatter = fm_result
+        .value()
+        .cloned()
+        .unwrap_or(yaml_serde::Value::Null);
+    let body = fm_result.body();
+
+    extractor.extract_document(base_path, file_path, &frontmatter, body)
+}
+
 /// Discover content files in a directory.
 async fn discover_files(base_path: &Path) -> Result<Vec<PathBuf>> {
     use fabryk_core::util::files::{FindOptions, find_all_files};
@@ -737,6 +871,244 @@ mod tests {
         assert_eq!(stats.files_processed, 2);
     }
 
+    #[tokio::test]
+    async fn test_builder_stale_cache_reembeds_changed_only() {
+        let (_dir, content_dir) = setup_test_files().await;
+        let cache_path = content_dir.parent().unwrap().join("vector-cache.json");
+        let provider = Arc::new(MockEmbeddingProvider::new(8));
+
+        let (_, stats1) = VectorIndexBuilder::new(MockVectorExtractor)
+            .with_content_path(&content_dir)
+            .with_embedding_provider(provider.clone())
+            .with_cache_path(&cache_path)
+            .build()
+            .await
+            .unwrap();
+        assert_eq!(stats1.documents_embedded, 2);
+
+        // Edit one file, remove the other, add a new one
+        std::fs::write(
+            content_dir.join("concept-a.md"),
+            "---\ntitle: \"Concept A\"\ncategory: \"basics\"\n---\n\nRevised content.\n",
+        )
+        .unwrap();
+        std::fs::remove_file(content_dir.join("concept-b.md")).unwrap();
+        std::fs::write(
+            content_dir.join("concept-c.md"),
+            "---\ntitle: \"Concept C\"\n---\n\nConcept C content.\n",
+        )
+        .unwrap();
+
+        let (backend, stats2) = VectorIndexBuilder::new(MockVectorExtractor)
+            .with_content_path(&content_dir)
+            .with_embedding_provider(provider.clone())
+            .with_cache_path(&cache_path)
+            .build()
+            .await
+            .unwrap();
+        assert!(!stats2.from_cache);
+        assert_eq!(stats2.documents_indexed, 2);
+        assert_eq!(stats2.documents_embedded, 2);
+        assert_eq!(stats2.documents_removed, 1);
+        assert_eq!(backend.document_count().unwrap(), 2);
+        assert!(backend.get("concept-b").await.unwrap().is_none());
+
+        // Touch nothing but one file's text: only that document is embedded
+        std::fs::write(
+            content_dir.join("concept-c.md"),
+            "---\ntitle: \"Concept C\"\n---\n\nConcept C, edited.\n",
+        )
+        .unwrap();
+        let (_, stats3) = VectorIndexBuilder::new(MockVectorExtractor)
+            .with_content_path(&content_dir)
+            .with_embedding_provider(provider)
+            .with_cache_path(&cache_path)
+            .build()
+            .await
+            .unwrap();
+        assert_eq!(stats3.documents_embedded, 1);
+        assert_eq!(stats3.documents_removed, 0);
+    }
+
+    #[tokio::test]
+    async fn test_builder_skip_cache_reembeds_everything() {
+        let (_dir, content_dir) = setup_test_files().await;
+        let cache_path = content_dir.parent().unwrap().join("vector-cache.json");
+        let provider = Arc::new(MockEmbeddingProvider::new(8));
+
+        VectorIndexBuilder::new(MockVectorExtractor)
+            .with_content_path(&content_dir)
+            .with_embedding_provider(provider.clone())
+            .with_cache_path(&cache_path)
+            .build()
+            .await
+            .unwrap();
+        std::fs::write(
+            content_dir.join("concept-a.md"),
+            "---\ntitle: \"Concept A\"\n---\n\nRevised content.\n",
+        )
+        .unwrap();
+
+        let (_, stats) = VectorIndexBuilder::new(MockVectorExtractor)
+            .with_content_path(&content_dir)
+            .with_embedding_provider(provider)
+            .with_cache_path(&cache_path)
+            .skip_cache()
+            .build()
+            .await
+            .unwrap();
+        assert_eq!(stats.documents_embedded, 2);
+    }
+
+    /// Same provider name and dimension as the mock, different model.
+    struct OtherModelProvider(MockEmbeddingProvider);
+
+    #[async_trait::async_trait]
+    impl EmbeddingProvider for OtherModelProvider {
+        async fn embed(&self, text: &str) -> Result<Vec<f32>> {
+            self.0.embed(text).await
+        }
+        fn dimension(&self) -> usize {
+            self.0.dimension()
+        }
+        fn name(&self) -> &str {
+            self.0.name()
+        }
+        fn model(&self) -> &str {
+            "mock-v2"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_builder_provider_change_reembeds_everything() {
+        let (_dir, content_dir) = setup_test_files().await;
+        let cache_path = content_dir.parent().unwrap().join("vector-cache.json");
+
+        VectorIndexBuilder::new(MockVectorExtractor)
+            .with_content_path(&content_dir)
+            .with_embedding_provider(Arc::new(MockEmbeddingProvider::new(8)))
+            .with_cache_path(&cache_path)
+            .build()
+            .await
+            .unwrap();
+
+        // Content is unchanged, but the dimension is not.
+        let (backend, stats) = VectorIndexBuilder::new(MockVectorExtractor)
+            .with_content_path(&content_dir)
+            .with_embedding_provider(Arc::new(MockEmbeddingProvider::new(16)))
+            .with_cache_path(&cache_path)
+            .build()
+            .await
+            .unwrap();
+        assert!(!stats.from_cache);
+        assert_eq!(stats.documents_embedded, 2);
+        let doc = backend.get("concept-a").await.unwrap().unwrap();
+        assert_eq!(doc.dimension(), 16);
+
+        // Same dimension, different model.
+        let (_, stats) = VectorIndexBuilder::new(MockVectorExtractor)
+            .with_content_path(&content_dir)
+            .with_embedding_provider(Arc::new(OtherModelProvider(MockEmbeddingProvider::new(16))))
+            .with_cache_path(&cache_path)
+            .build()
+            .await
+            .unwrap();
+        assert!(!stats.from_cache);
+        assert_eq!(stats.documents_embedded, 2);
+    }
+
+    #[tokio::test]
+    async fn test_builder_sync_reembeds_wrong_dimension() {
+        let (_dir, content_dir) = setup_test_files().await;
+        let backend = SimpleVectorBackend::new(Arc::new(MockEmbeddingProvider::new(8)));
+
+        VectorIndexBuilder::new(MockVectorExtractor)
+            .with_content_path(&content_dir)
+            .with_embedding_provider(Arc::new(MockEmbeddingProvider::new(8)))
+            .sync(&backend)
+            .await
+            .unwrap();
+        let stats = VectorIndexBuilder::new(MockVectorExtractor)
+            .with_content_path(&content_dir)
+            .with_embedding_provider(Arc::new(MockEmbeddingProvider::new(16)))
+            .sync(&backend)
+            .await
+            .unwrap();
+        assert_eq!(stats.documents_embedded, 2);
+    }
+
+    #[tokio::test]
+    async fn test_builder_sync() {
+        let (_dir, content_dir) = setup_test_files().await;
+        let provider: Arc<dyn EmbeddingProvider> = Arc::new(MockEmbeddingProvider::new(8));
+        let backend = SimpleVectorBackend::new(provider.clone());
+
+        let stats1 = VectorIndexBuilder::new(MockVectorExtractor)
+            .with_content_path(&content_dir)
+            .with_embedding_provider(provider.clone())
+            .sync(&backend)
+            .await
+            .unwrap();
+        assert_eq!(stats1.documents_embedded, 2);
+        assert_eq!(backend.document_count().unwrap(), 2);
+
+        std::fs::write(
+            content_dir.join("concept-b.md"),
+            "---\ntitle: \"Concept B\"\n---\n\nRevised content.\n",
+        )
+        .unwrap();
+
+        let stats2 = VectorIndexBuilder::new(MockVectorExtractor)
+            .with_content_path(&content_dir)
+            .with_embedding_provider(provider)
+            .sync(&backend)
+            .await
+            .unwrap();
+        assert_eq!(stats2.documents_indexed, 2);
+        assert_eq!(stats2.documents_embedded, 1);
+        assert_eq!(backend.document_count().unwrap(), 2);
+        let doc = backend.get("concept-b").await.unwrap().unwrap();
+        assert!(doc.document.text.contains("Revised content"));
+    }
+
+    #[tokio::test]
+    async fn test_builder_with_chunker() {
+        let dir = tempdir().unwrap();
+        let content_dir = dir.path().join("content");
+        std::fs::create_dir(&content_dir).unwrap();
+        std::fs::write(
+            content_dir.join("book.md"),
+            "---\ntitle: Book\n---\n\n# One\n\nFirst chapter.\n\n# Two\n\nSecond chapter.\n",
+        )
+        .unwrap();
+        std::fs::write(
+            content_dir.join("note.md"),
+            "---\ntitle: Note\n---\n\nShort.\n",
+        )
+        .unwrap();
+
+        let provider = Arc::new(MockEmbeddingProvider::new(8));
+        let (backend, stats) = VectorIndexBuilder::new(MockVectorExtractor)
+            .with_content_path(&content_dir)
+            .with_embedding_provider(provider)
+            .with_chunker(Chunker::sections(1))
+            .build()
+            .await
+            .unwrap();
+
+        assert_eq!(stats.files_processed, 2);
+        // The mock extractor prefixes "Book | " to the first heading, so it
+        // joins the preamble: two book chunks, plus the unchunked note
+        assert_eq!(stats.documents_indexed, 3);
+        let mut ids = backend.ids().unwrap();
+        ids.sort();
+        assert_eq!(ids, vec!["book#0", "book#1", "note"]);
+
+        let chunk = backend.get("book#1").await.unwrap().unwrap();
+        assert_eq!(chunk.document.text, "# Two\n\nSecond chapter.");
+        assert_eq!(chunk.document.metadata["section"], "Two");
+    }
+
     #[tokio::test]
     async fn test_builder_no_cache_path() {
         let (_dir, content_dir) = setup_test_files().await;
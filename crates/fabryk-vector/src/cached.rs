@@ -0,0 +1,240 @@
+//! Disk-backed embedding cache.
+//!
+//! [`CachedEmbeddingProvider`] wraps any [`EmbeddingProvider`] and stores
+//! each embedding on disk under the blake3 hash of its text, so rebuilding
+//! an index over mostly unchanged content only embeds the texts that
+//! changed.
+//!
+//! # Layout
+//!
+//! ```text
+//! <cache_dir>/<provider-name>-<dimension>/<hash[0..2]>/<hash>.f32
+//! ```
+//!
+//! Entries are namespaced by provider name and dimension so switching
+//! models never returns stale vectors. Each file holds the embedding as
+//! little-endian `f32` values. Unreadable or malformed entries are treated
+//! as misses and overwritten.
+
+use crate::embedding::EmbeddingProvider;
+use async_trait::async_trait;
+use fabryk_core::{Error, Result};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Embedding provider that memoizes another provider's output on disk.
+pub struct CachedEmbeddingProvider {
+    inner: Arc<dyn EmbeddingProvider>,
+    dir: PathBuf,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+impl CachedEmbeddingProvider {
+    /// Wrap `inner`, storing embeddings under `cache_dir`.
+    pub fn new(inner: Arc<dyn EmbeddingProvider>, cache_dir: impl AsRef<Path>) -> Self {
+        let namespace: String = format!("{}-{}", inner.name(), inner.dimension())
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        Self {
+            dir: cache_dir.as_ref().join(namespace),
+            inner,
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+        }
+    }
+
+    /// Directory holding this provider's entries.
+    pub fn cache_dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Embeddings served from the cache so far.
+    pub fn hits(&self) -> usize {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Embeddings computed by the wrapped provider so far.
+    pub fn misses(&self) -> usize {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    fn entry_path(&self, text: &str) -> PathBuf {
+        let hash = blake3::hash(text.as_bytes()).to_hex();
+        self.dir.join(&hash[..2]).join(format!("{hash}.f32"))
+    }
+
+    async fn load(&self, text: &str) -> Option<Vec<f32>> {
+        let bytes = tokio::fs::read(self.entry_path(text)).await.ok()?;
+        if bytes.is_empty() || bytes.len() % 4 != 0 {
+            return None;
+        }
+        let embedding: Vec<f32> = bytes
+            .as_chunks::<4>()
+            .0
+            .iter()
+            .map(|b| f32::from_le_bytes(*b))
+            .collect();
+        let dimension = self.inner.dimension();
+        (dimension == 0 || embedding.len() == dimension).then_some(embedding)
+    }
+
+    async fn store(&self, text: &str, embedding: &[f32]) -> Result<()> {
+        let path = self.entry_path(text);
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| Error::io_with_path(e, parent))?;
+        }
+        let bytes: Vec<u8> = embedding.iter().flat_map(|x| x.to_le_bytes()).collect();
+        // Write then rename, so a concurrent reader never sees half an entry
+        let tmp = path.with_extension("tmp");
+        tokio::fs::write(&tmp, bytes)
+            .await
+            .map_err(|e| Error::io_with_path(e, &tmp))?;
+        tokio::fs::rename(&tmp, &path)
+            .await
+            .map_err(|e| Error::io_with_path(e, &path))
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for CachedEmbeddingProvider {
+    async fn embed(&self, text: &str) -> Result<Vec<f32>> {
+        Ok(self
+            .embed_batch(&[text])
+            .await?
+            .into_iter()
+            .next()
+            .unwrap_or_default())
+    }
+
+    async fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        let mut results: Vec<Option<Vec<f32>>> = Vec::with_capacity(texts.len());
+        for text in texts {
+            results.push(self.load(text).await);
+        }
+
+        let missing: Vec<usize> = (0..texts.len()).filter(|i| results[*i].is_none()).collect();
+        self.hits
+            .fetch_add(texts.len() - missing.len(), Ordering::Relaxed);
+        if !missing.is_empty() {
+            let batch: Vec<&str> = missing.iter().map(|i| texts[*i]).collect();
+            let computed = self.inner.embed_batch(&batch).await?;
+            if computed.len() != batch.len() {
+                return Err(Error::operation(format!(
+                    "Embedding provider '{}' returned {} embeddings for {} texts",
+                    self.inner.name(),
+                    computed.len(),
+                    batch.len()
+                )));
+            }
+            self.misses.fetch_add(batch.len(), Ordering::Relaxed);
+            for (i, embedding) in missing.into_iter().zip(computed) {
+                if let Err(e) = self.store(texts[i], &embedding).await {
+                    log::warn!("Failed to cache embedding: {e}");
+                }
+                results[i] = Some(embedding);
+            }
+        }
+
+        Ok(results.into_iter().map(Option::unwrap_or_default).collect())
+    }
+
+    fn dimension(&self) -> usize {
+        self.inner.dimension()
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn model(&self) -> &str {
+        self.inner.model()
+    }
+}
+
+impl std::fmt::Debug for CachedEmbeddingProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CachedEmbeddingProvider")
+            .field("inner", &self.inner.name())
+            .field("dir", &self.dir)
+            .finish()
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::embedding::MockEmbeddingProvider;
+
+    fn cached(dir: &Path) -> CachedEmbeddingProvider {
+        CachedEmbeddingProvider::new(Arc::new(MockEmbeddingProvider::new(8)), dir)
+    }
+
+    #[tokio::test]
+    async fn test_repeated_texts_hit_the_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let provider = cached(dir.path());
+
+        let first = provider.embed_batch(&["alpha", "beta"]).await.unwrap();
+        assert_eq!(provider.misses(), 2);
+        assert_eq!(provider.hits(), 0);
+
+        let second = provider
+            .embed_batch(&["beta", "gamma", "alpha"])
+            .await
+            .unwrap();
+        assert_eq!(provider.misses(), 3);
+        assert_eq!(provider.hits(), 2);
+        assert_eq!(second[0], first[1]);
+        assert_eq!(second[2], first[0]);
+        assert_eq!(second[1].len(), 8);
+    }
+
+    #[tokio::test]
+    async fn test_cache_persists_across_instances() {
+        let dir = tempfile::tempdir().unwrap();
+        let expected = cached(dir.path()).embed("persisted").await.unwrap();
+
+        let provider = cached(dir.path());
+        assert_eq!(provider.embed("persisted").await.unwrap(), expected);
+        assert_eq!(provider.hits(), 1);
+        assert_eq!(provider.misses(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_namespace_separates_dimensions() {
+        let dir = tempfile::tempdir().unwrap();
+        cached(dir.path()).embed("text").await.unwrap();
+
+        let other =
+            CachedEmbeddingProvider::new(Arc::new(MockEmbeddingProvider::new(4)), dir.path());
+        assert_eq!(other.embed("text").await.unwrap().len(), 4);
+        assert_eq!(other.misses(), 1);
+        assert!(other.cache_dir().ends_with("mock-4"));
+    }
+
+    #[tokio::test]
+    async fn test_corrupt_entries_are_recomputed() {
+        let dir = tempfile::tempdir().unwrap();
+        let provider = cached(dir.path());
+        let expected = provider.embed("text").await.unwrap();
+
+        std::fs::write(provider.entry_path("text"), b"bad").unwrap();
+        assert_eq!(provider.embed("text").await.unwrap(), expected);
+        assert_eq!(provider.misses(), 2);
+    }
+}
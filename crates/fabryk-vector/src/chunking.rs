@@ -0,0 +1,460 @@
+//! Document chunking for vector indexing.
+//!
+//! A single embedding for a book-length document is dominated by whatever
+//! the embedding model sees first, and long texts are truncated. A
+//! [`Chunker`] splits each `VectorDocument` into smaller chunk documents
+//! that are embedded separately:
+//!
+//! - [`Chunker::Sections`] splits at markdown headings (via
+//!   `fabryk_content::markdown::split_sections`), optionally windowing
+//!   sections that are still too long.
+//! - [`Chunker::TokenWindow`] splits into fixed-size windows of
+//!   whitespace-separated tokens, with overlap.
+//!
+//! Each chunk keeps the parent's category and metadata, and records where it
+//! came from in the metadata keys [`PARENT_ID_KEY`], [`CHUNK_INDEX_KEY`],
+//! [`CHUNK_START_KEY`] and [`CHUNK_END_KEY`] (byte offsets into the parent
+//! text), plus [`SECTION_KEY`] for section chunks.
+//!
+//! At query time, [`fold_chunks`] collapses chunk hits back to their parent
+//! documents, keeping the best-matching chunk as the result's passage.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! let (backend, stats) = VectorIndexBuilder::new(extractor)
+//!     .with_content_path("/data/books")
+//!     .with_embedding_provider(provider)
+//!     .with_chunker(Chunker::sections(2))
+//!     .build()
+//!     .await?;
+//!
+//! let hits = backend.search(VectorSearchParams::new("cadences").with_limit(30)).await?;
+//! let books = fold_chunks(hits, 10);
+//! ```
+
+use std::collections::HashSet;
+use std::ops::Range;
+
+use fabryk_content::HeadingLevel;
+use fabryk_content::markdown::split_sections;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{VectorDocument, VectorSearchResults};
+
+/// Metadata key holding the ID of the document a chunk was cut from.
+pub const PARENT_ID_KEY: &str = "parent_id";
+
+/// Metadata key holding the chunk's 0-based position within its parent.
+pub const CHUNK_INDEX_KEY: &str = "chunk_index";
+
+/// Metadata key holding the byte offset where the chunk starts in the parent text.
+pub const CHUNK_START_KEY: &str = "chunk_start";
+
+/// Metadata key holding the byte offset where the chunk ends in the parent text.
+pub const CHUNK_END_KEY: &str = "chunk_end";
+
+/// Metadata key holding the heading of the section a chunk belongs to.
+pub const SECTION_KEY: &str = "section";
+
+/// How documents are split into chunks before embedding.
+///
+/// Deserializes from a tagged table, e.g.
+/// `{ strategy = "sections", max_level = 2 }` or
+/// `{ strategy = "token_window", size = 256, overlap = 32 }`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "strategy", rename_all = "snake_case")]
+pub enum Chunker {
+    /// One chunk per markdown heading section.
+    Sections {
+        /// Deepest heading level (1–6) that starts a new chunk.
+        #[serde(default = "default_max_level")]
+        max_level: u8,
+
+        /// Sections with more tokens than this are split further into
+        /// windows of this size.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_tokens: Option<usize>,
+    },
+
+    /// Fixed-size windows of whitespace-separated tokens.
+    TokenWindow {
+        /// Tokens per chunk.
+        size: usize,
+
+        /// Tokens shared by consecutive chunks.
+        ///
+        /// Clamped so that every window advances by at least one token.
+        #[serde(default)]
+        overlap: usize,
+    },
+}
+
+fn default_max_level() -> u8 {
+    2
+}
+
+/// A chunk's location in its parent text.
+struct Span {
+    range: Range<usize>,
+    section: Option<String>,
+}
+
+impl Chunker {
+    /// Split at headings of `max_level` (1–6) or higher.
+    pub fn sections(max_level: u8) -> Self {
+        Self::Sections {
+            max_level,
+            max_tokens: None,
+        }
+    }
+
+    /// Split into windows of `size` tokens, consecutive windows sharing `overlap` tokens.
+    pub fn token_window(size: usize, overlap: usize) -> Self {
+        Self::TokenWindow { size, overlap }
+    }
+
+    /// Limit section chunks to `max_tokens`, windowing longer sections.
+    ///
+    /// Has no effect on token-window chunkers.
+    pub fn with_max_tokens(mut self, limit: usize) -> Self {
+        if let Self::Sections { max_tokens, .. } = &mut self {
+            *max_tokens = Some(limit);
+        }
+        self
+    }
+
+    /// Split a document into chunk documents.
+    ///
+    /// Documents that yield a single chunk are returned unchanged, so short
+    /// documents are indexed exactly as without chunking.
+    pub fn chunk(&self, document: &VectorDocument) -> Vec<VectorDocument> {
+        let spans = self.spans(&document.text);
+        if spans.len() <= 1 {
+            return vec![document.clone()];
+        }
+
+        spans
+            .into_iter()
+            .enumerate()
+            .map(|(index, span)| {
+                let mut chunk = VectorDocument::new(
+                    format!("{}#{index}", document.id),
+                    &document.text[span.range.clone()],
+                );
+                chunk.category = document.category.clone();
+                chunk.metadata = document.metadata.clone();
+                chunk
+                    .metadata
+                    .insert(PARENT_ID_KEY.to_string(), document.id.clone());
+                chunk
+                    .metadata
+                    .insert(CHUNK_INDEX_KEY.to_string(), index.to_string());
+                chunk
+                    .metadata
+                    .insert(CHUNK_START_KEY.to_string(), span.range.start.to_string());
+                chunk
+                    .metadata
+                    .insert(CHUNK_END_KEY.to_string(), span.range.end.to_string());
+                if let Some(section) = span.section {
+                    chunk.metadata.insert(SECTION_KEY.to_string(), section);
+                }
+                chunk
+            })
+            .collect()
+    }
+
+    fn spans(&self, text: &str) -> Vec<Span> {
+        match *self {
+            Self::Sections {
+                max_level,
+                max_tokens,
+            } => {
+                let mut spans = Vec::new();
+                for section in split_sections(text, heading_level(max_level)) {
+                    let ranges = match max_tokens {
+                        Some(size) => token_windows(text, section.range, size, 0),
+                        None => trim(text, section.range).into_iter().collect(),
+                    };
+                    spans.extend(ranges.into_iter().map(|range| Span {
+                        range,
+                        section: section.heading.clone(),
+                    }));
+                }
+                spans
+            }
+            Self::TokenWindow { size, overlap } => {
+                token_windows(text, 0..text.len(), size, overlap)
+                    .into_iter()
+                    .map(|range| Span {
+                        range,
+                        section: None,
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Whether a document was produced by a [`Chunker`].
+pub fn is_chunk(document: &VectorDocument) -> bool {
+    document.metadata.contains_key(PARENT_ID_KEY)
+}
+
+/// The document ID a search hit belongs to: its parent for chunks, else its own.
+pub fn parent_id(metadata: &std::collections::HashMap<String, String>, id: &str) -> String {
+    metadata
+        .get(PARENT_ID_KEY)
+        .cloned()
+        .unwrap_or_else(|| id.to_string())
+}
+
+/// Collapse chunk hits into one result per parent document.
+///
+/// Each parent keeps the score, metadata and passage of its best-scoring
+/// chunk, and takes the parent's ID. Results that are not chunks pass
+/// through unchanged. At most `limit` results are returned, so callers
+/// should search with a larger limit to leave room for folding.
+pub fn fold_chunks(results: VectorSearchResults, limit: usize) -> VectorSearchResults {
+    let mut items = results.items;
+    items.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let mut seen = HashSet::new();
+    let mut folded = Vec::new();
+    for mut item in items {
+        let parent = parent_id(&item.metadata, &item.id);
+        if seen.insert(parent.clone()) {
+            item.id = parent;
+            folded.push(item);
+        }
+    }
+    folded.truncate(limit);
+
+    VectorSearchResults {
+        total: folded.len(),
+        items: folded,
+        backend: results.backend,
+    }
+}
+
+/// Map a 1–6 heading level, clamping out-of-range values.
+fn heading_level(level: u8) -> HeadingLevel {
+    match level {
+        0 | 1 => HeadingLevel::H1,
+        2 => HeadingLevel::H2,
+        3 => HeadingLevel::H3,
+        4 => HeadingLevel::H4,
+        5 => HeadingLevel::H5,
+        _ => HeadingLevel::H6,
+    }
+}
+
+/// Shrink a range to exclude surrounding whitespace, or `None` if blank.
+fn trim(text: &str, range: Range<usize>) -> Option<Range<usize>> {
+    let slice = &text[range.clone()];
+    let trimmed = slice.trim_start();
+    let start = range.start + (slice.len() - trimmed.len());
+    let end = start + trimmed.trim_end().len();
+    (start < end).then_some(start..end)
+}
+
+/// Split a range of text into windows of `size` whitespace-separated tokens.
+fn token_windows(
+    text: &str,
+    range: Range<usize>,
+    size: usize,
+    overlap: usize,
+) -> Vec<Range<usize>> {
+    let mut tokens: Vec<Range<usize>> = Vec::new();
+    let mut token_start = None;
+    for (i, c) in text[range.clone()].char_indices() {
+        let i = range.start + i;
+        match (c.is_whitespace(), token_start) {
+            (true, Some(start)) => {
+                tokens.push(start..i);
+                token_start = None;
+            }
+            (false, None) => token_start = Some(i),
+            _ => {}
+        }
+    }
+    if let Some(start) = token_start {
+        tokens.push(start..range.end);
+    }
+
+    let size = size.max(1);
+    let step = size.saturating_sub(overlap).max(1);
+    let mut windows = Vec::new();
+    let mut first = 0;
+    while first < tokens.len() {
+        let last = (first + size).min(tokens.len()) - 1;
+        windows.push(tokens[first].start..tokens[last].end);
+        if last == tokens.len() - 1 {
+            break;
+        }
+        first += step;
+    }
+    windows
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::VectorSearchResult;
+    use std::collections::HashMap;
+
+    const BOOK: &str = "Preface text.\n\n# Chapter One\n\nIntervals and scales.\n\n\
+                        ## Details\n\nMore on scales.\n\n# Chapter Two\n\nCadences.\n";
+
+    fn book() -> VectorDocument {
+        VectorDocument::new("book", BOOK)
+            .with_category("theory")
+            .with_metadata("tier", "advanced")
+    }
+
+    #[test]
+    fn test_sections_chunking() {
+        let chunks = Chunker::sections(1).chunk(&book());
+        assert_eq!(chunks.len(), 3);
+
+        assert_eq!(chunks[0].id, "book#0");
+        assert_eq!(chunks[0].text, "Preface text.");
+        assert!(!chunks[0].metadata.contains_key(SECTION_KEY));
+
+        assert_eq!(chunks[1].metadata[SECTION_KEY], "Chapter One");
+        assert!(chunks[1].text.starts_with("# Chapter One"));
+        assert!(chunks[1].text.ends_with("More on scales."));
+
+        assert_eq!(chunks[2].metadata[SECTION_KEY], "Chapter Two");
+        assert_eq!(chunks[2].metadata[CHUNK_INDEX_KEY], "2");
+    }
+
+    #[test]
+    fn test_chunks_inherit_and_locate() {
+        let parent = book();
+        for chunk in Chunker::sections(2).chunk(&parent) {
+            assert_eq!(chunk.category.as_deref(), Some("theory"));
+            assert_eq!(chunk.metadata["tier"], "advanced");
+            assert_eq!(chunk.metadata[PARENT_ID_KEY], "book");
+
+            let start: usize = chunk.metadata[CHUNK_START_KEY].parse().unwrap();
+            let end: usize = chunk.metadata[CHUNK_END_KEY].parse().unwrap();
+            assert_eq!(&parent.text[start..end], chunk.text);
+        }
+    }
+
+    #[test]
+    fn test_sections_max_tokens() {
+        let chunks = Chunker::sections(1).with_max_tokens(3).chunk(&book());
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec![
+                "Preface text.",
+                "# Chapter One",
+                "Intervals and scales.",
+                "## Details\n\nMore",
+                "on scales.",
+                "# Chapter Two",
+                "Cadences.",
+            ]
+        );
+        assert_eq!(chunks[4].metadata[SECTION_KEY], "Chapter One");
+    }
+
+    #[test]
+    fn test_token_window_with_overlap() {
+        let doc = VectorDocument::new("doc", "a b c d e f g");
+        let chunks = Chunker::token_window(3, 1).chunk(&doc);
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["a b c", "c d e", "e f g"]);
+        assert_eq!(chunks[1].metadata[CHUNK_START_KEY], "4");
+        assert_eq!(chunks[1].metadata[CHUNK_END_KEY], "9");
+    }
+
+    #[test]
+    fn test_token_window_overlap_clamped() {
+        let doc = VectorDocument::new("doc", "a b c");
+        let chunks = Chunker::token_window(2, 5).chunk(&doc);
+        let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["a b", "b c"]);
+    }
+
+    #[test]
+    fn test_short_document_unchanged() {
+        let doc = VectorDocument::new("doc", "just a few words");
+        let chunks = Chunker::token_window(100, 10).chunk(&doc);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].id, "doc");
+        assert!(chunks[0].metadata.is_empty());
+
+        let empty = VectorDocument::new("empty", "");
+        assert_eq!(Chunker::sections(2).chunk(&empty)[0].id, "empty");
+    }
+
+    #[test]
+    fn test_chunker_deserialize() {
+        let chunker: Chunker =
+            serde_json::from_str(r#"{"strategy": "token_window", "size": 256, "overlap": 32}"#)
+                .unwrap();
+        assert_eq!(chunker, Chunker::token_window(256, 32));
+
+        let chunker: Chunker = serde_json::from_str(r#"{"strategy": "sections"}"#).unwrap();
+        assert_eq!(chunker, Chunker::sections(2));
+    }
+
+    fn hit(id: &str, score: f32, parent: Option<&str>) -> VectorSearchResult {
+        let mut metadata = HashMap::new();
+        if let Some(parent) = parent {
+            metadata.insert(PARENT_ID_KEY.to_string(), parent.to_string());
+        }
+        VectorSearchResult {
+            id: id.to_string(),
+            score,
+            distance: 1.0 - score,
+            metadata,
+            passage: parent.map(|_| format!("text of {id}")),
+        }
+    }
+
+    #[test]
+    fn test_fold_chunks() {
+        let results = VectorSearchResults {
+            items: vec![
+                hit("book#2", 0.6, Some("book")),
+                hit("book#0", 0.9, Some("book")),
+                hit("note", 0.8, None),
+                hit("other#1", 0.7, Some("other")),
+            ],
+            total: 4,
+            backend: "simple".to_string(),
+        };
+
+        let folded = fold_chunks(results, 10);
+        let ids: Vec<&str> = folded.items.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["book", "note", "other"]);
+        assert_eq!(folded.total, 3);
+        assert_eq!(folded.items[0].score, 0.9);
+        assert_eq!(folded.items[0].passage.as_deref(), Some("text of book#0"));
+        assert_eq!(folded.items[1].passage, None);
+        assert_eq!(folded.backend, "simple");
+    }
+
+    #[test]
+    fn test_fold_chunks_limit() {
+        let results = VectorSearchResults {
+            items: vec![hit("a#0", 0.9, Some("a")), hit("b#0", 0.8, Some("b"))],
+            total: 2,
+            backend: "simple".to_string(),
+        };
+        assert_eq!(fold_chunks(results, 1).items.len(), 1);
+    }
+}
@@ -7,9 +7,19 @@
 //!
 //! - `MockEmbeddingProvider`: Deterministic fixed-dimension vectors for testing
 //! - `FastEmbedProvider`: Local embedding via fastembed (requires `vector-fastembed` feature)
+//! - `OpenAiEmbeddingProvider`: OpenAI-compatible HTTP API, including Ollama
+//!   and vLLM (requires `vector-openai` feature)
+//! - `OnnxEmbeddingProvider`: Local ONNX model from a directory (requires
+//!   `vector-onnx` feature)
+//! - `CachedEmbeddingProvider`: Disk cache around any other provider
+//!
+//! Use [`create_embedding_provider`] to select one from [`VectorConfig`].
 
+use crate::cached::CachedEmbeddingProvider;
+use crate::types::VectorConfig;
 use async_trait::async_trait;
-use fabryk_core::Result;
+use fabryk_core::{Error, Result};
+use std::sync::Arc;
 
 /// Trait for generating text embeddings.
 ///
@@ -43,6 +53,14 @@ pub trait EmbeddingProvider: Send + Sync {
 
     /// The provider name for diagnostics.
     fn name(&self) -> &str;
+
+    /// The model that produces the embeddings. Defaults to [`name`](Self::name).
+    ///
+    /// Recorded in vector caches so embeddings from a different model are
+    /// never mixed into the same index.
+    fn model(&self) -> &str {
+        self.name()
+    }
 }
 
 /// A mock embedding provider for testing.
@@ -110,6 +128,75 @@ impl EmbeddingProvider for MockEmbeddingProvider {
     }
 }
 
+// ============================================================================
+// Factory
+// ============================================================================
+
+/// Create an embedding provider based on configuration.
+///
+/// Selection by `config.provider`:
+/// - `"mock"` → [`MockEmbeddingProvider`] (dimension from config, default 384)
+/// - `"fastembed"` → `FastEmbedProvider` (requires `vector-fastembed`)
+/// - `"openai"` → `OpenAiEmbeddingProvider`, built so its dimension is
+///   known (requires `vector-openai`)
+/// - `"onnx"` → `OnnxEmbeddingProvider` loaded from `config.model_path`
+///   (requires `vector-onnx`)
+///
+/// When `config.embedding_cache_path` is set, the provider is wrapped in a
+/// [`CachedEmbeddingProvider`].
+pub async fn create_embedding_provider(
+    config: &VectorConfig,
+) -> Result<Arc<dyn EmbeddingProvider>> {
+    let provider: Arc<dyn EmbeddingProvider> = match config.provider.as_str() {
+        "mock" => Arc::new(MockEmbeddingProvider::new(if config.dimension > 0 {
+            config.dimension
+        } else {
+            384
+        })),
+        #[cfg(feature = "vector-fastembed")]
+        "fastembed" => Arc::new(crate::fastembed::FastEmbedProvider::new(
+            &config.model,
+            config.cache_path.as_deref(),
+        )?),
+        #[cfg(feature = "vector-openai")]
+        "openai" => Arc::new(
+            crate::openai::OpenAiEmbeddingProvider::from_config(config)
+                .build()
+                .await?,
+        ),
+        #[cfg(feature = "vector-onnx")]
+        "onnx" => Arc::new(crate::onnx::OnnxEmbeddingProvider::from_config(config)?),
+        #[cfg(not(feature = "vector-fastembed"))]
+        "fastembed" => {
+            return Err(Error::config(
+                "Embedding provider 'fastembed' requires the vector-fastembed feature",
+            ));
+        }
+        #[cfg(not(feature = "vector-openai"))]
+        "openai" => {
+            return Err(Error::config(
+                "Embedding provider 'openai' requires the vector-openai feature",
+            ));
+        }
+        #[cfg(not(feature = "vector-onnx"))]
+        "onnx" => {
+            return Err(Error::config(
+                "Embedding provider 'onnx' requires the vector-onnx feature",
+            ));
+        }
+        other => {
+            return Err(Error::config(format!(
+                "Unknown embedding provider: '{other}'. Supported: fastembed, openai, onnx, mock"
+            )));
+        }
+    };
+
+    Ok(match config.embedding_cache_path.as_deref() {
+        Some(path) => Arc::new(CachedEmbeddingProvider::new(provider, path)),
+        None => provider,
+    })
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -118,6 +205,52 @@ impl EmbeddingProvider for MockEmbeddingProvider {
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_create_embedding_provider_mock() {
+        let config = VectorConfig {
+            provider: "mock".to_string(),
+            dimension: 16,
+            ..Default::default()
+        };
+        let provider = create_embedding_provider(&config).await.unwrap();
+        assert_eq!(provider.name(), "mock");
+        assert_eq!(provider.dimension(), 16);
+    }
+
+    #[tokio::test]
+    async fn test_create_embedding_provider_cached() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = VectorConfig {
+            provider: "mock".to_string(),
+            embedding_cache_path: Some(dir.path().to_string_lossy().into_owned()),
+            ..Default::default()
+        };
+        let provider = create_embedding_provider(&config).await.unwrap();
+        provider.embed("cached text").await.unwrap();
+        assert!(dir.path().join("mock-384").is_dir());
+    }
+
+    #[tokio::test]
+    async fn test_create_embedding_provider_unknown() {
+        let config = VectorConfig {
+            provider: "word2vec".to_string(),
+            ..Default::default()
+        };
+        let err = create_embedding_provider(&config).await.err().unwrap();
+        assert!(err.to_string().contains("Unknown embedding provider"));
+    }
+
+    #[cfg(not(feature = "vector-onnx"))]
+    #[tokio::test]
+    async fn test_create_embedding_provider_onnx_requires_feature() {
+        let config = VectorConfig {
+            provider: "onnx".to_string(),
+            ..Default::default()
+        };
+        let err = create_embedding_provider(&config).await.err().unwrap();
+        assert!(err.to_string().contains("vector-onnx"));
+    }
+
     #[test]
     fn test_mock_provider_creation() {
         let provider = MockEmbeddingProvider::new(384);
@@ -1,18 +1,21 @@
-//! FastEmbed embedding provider.
+//! FastEmbed embedding provider and reranker.
 //!
 //! Wraps the `fastembed` crate to provide local embedding generation
-//! via pre-trained models (e.g., BGE-small, AllMiniLM).
+//! via pre-trained models (e.g., BGE-small, AllMiniLM), and local
+//! cross-encoder reranking (e.g., BGE-reranker).
 //!
 //! # Thread Safety
 //!
-//! `fastembed::TextEmbedding` is not `Send + Sync`, so we wrap it in
-//! `Arc<Mutex<>>` and use `tokio::task::spawn_blocking` for embedding calls.
+//! `fastembed::TextEmbedding` and `fastembed::TextRerank` are not
+//! `Send + Sync`, so we wrap them in `Arc<Mutex<>>` and use
+//! `tokio::task::spawn_blocking` for model calls.
 //!
 //! # Feature Gate
 //!
 //! This module requires the `vector-fastembed` feature.
 
 use crate::embedding::EmbeddingProvider;
+use crate::rerank::Reranker;
 use async_trait::async_trait;
 use fabryk_core::{Error, Result};
 use std::sync::{Arc, Mutex};
@@ -30,6 +33,23 @@ fn resolve_model(name: &str) -> Result<fastembed::EmbeddingModel> {
     }
 }
 
+/// Map a reranker name string to a fastembed `RerankerModel` enum variant.
+fn resolve_reranker_model(name: &str) -> Result<fastembed::RerankerModel> {
+    match name {
+        "bge-reranker-base" | "BGERerankerBase" => Ok(fastembed::RerankerModel::BGERerankerBase),
+        "bge-reranker-v2-m3" | "BGERerankerV2M3" => Ok(fastembed::RerankerModel::BGERerankerV2M3),
+        "jina-reranker-v1-turbo-en" | "JINARerankerV1TurboEn" => {
+            Ok(fastembed::RerankerModel::JINARerankerV1TurboEn)
+        }
+        "jina-reranker-v2-base-multilingual" | "JINARerankerV2BaseMultiligual" => {
+            Ok(fastembed::RerankerModel::JINARerankerV2BaseMultiligual)
+        }
+        other => Err(Error::config(format!(
+            "Unknown reranker model: '{other}'. Supported: bge-reranker-base, bge-reranker-v2-m3, jina-reranker-v1-turbo-en, jina-reranker-v2-base-multilingual"
+        ))),
+    }
+}
+
 /// FastEmbed-based embedding provider.
 ///
 /// Uses locally-downloaded transformer models for embedding generation.
@@ -144,6 +164,98 @@ impl std::fmt::Debug for FastEmbedProvider {
     }
 }
 
+/// FastEmbed-based cross-encoder reranker.
+///
+/// Scores query–document pairs with a locally-downloaded cross-encoder.
+/// The model is loaded once and reused for all subsequent calls.
+///
+/// # Supported Models
+///
+/// | Name | Languages | Size |
+/// |------|-----------|------|
+/// | `bge-reranker-base` | English, Chinese | ~280MB |
+/// | `bge-reranker-v2-m3` | Multilingual | ~570MB |
+/// | `jina-reranker-v1-turbo-en` | English | ~150MB |
+/// | `jina-reranker-v2-base-multilingual` | Multilingual | ~280MB |
+pub struct FastEmbedReranker {
+    model: Arc<Mutex<fastembed::TextRerank>>,
+    model_name: String,
+}
+
+impl FastEmbedReranker {
+    /// Default reranker model.
+    pub const DEFAULT_MODEL: &'static str = "bge-reranker-base";
+
+    /// Create a new FastEmbed reranker with the given model name.
+    ///
+    /// Downloads the model if not cached locally.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_name` - Model identifier (e.g., "bge-reranker-base")
+    /// * `cache_path` - Optional directory for model file caching
+    pub fn new(model_name: &str, cache_path: Option<&str>) -> Result<Self> {
+        let model_enum = resolve_reranker_model(model_name)?;
+
+        let mut init = fastembed::RerankInitOptions::new(model_enum);
+        if let Some(path) = cache_path {
+            init = init.with_cache_dir(std::path::PathBuf::from(path));
+        }
+
+        let text_rerank = fastembed::TextRerank::try_new(init)
+            .map_err(|e| Error::operation(format!("Failed to initialize reranker model: {e}")))?;
+
+        Ok(Self {
+            model: Arc::new(Mutex::new(text_rerank)),
+            model_name: model_name.to_string(),
+        })
+    }
+}
+
+#[async_trait]
+impl Reranker for FastEmbedReranker {
+    async fn score(&self, query: &str, documents: &[&str]) -> Result<Vec<f32>> {
+        if documents.is_empty() {
+            return Ok(Vec::new());
+        }
+        let model = self.model.clone();
+        let query = query.to_string();
+        let documents: Vec<String> = documents.iter().map(|d| d.to_string()).collect();
+
+        tokio::task::spawn_blocking(move || {
+            let mut model = model
+                .lock()
+                .map_err(|e| Error::operation(format!("Mutex poisoned: {e}")))?;
+            let results = model
+                .rerank(query, &documents, false, None)
+                .map_err(|e| Error::operation(format!("Reranking failed: {e}")))?;
+
+            // fastembed returns results sorted by score; restore input order
+            let mut scores = vec![f32::MIN; documents.len()];
+            for result in results {
+                if let Some(slot) = scores.get_mut(result.index) {
+                    *slot = result.score;
+                }
+            }
+            Ok(scores)
+        })
+        .await
+        .map_err(|e| Error::operation(format!("spawn_blocking failed: {e}")))?
+    }
+
+    fn name(&self) -> &str {
+        &self.model_name
+    }
+}
+
+impl std::fmt::Debug for FastEmbedReranker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FastEmbedReranker")
+            .field("model", &self.model_name)
+            .finish()
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -172,6 +284,16 @@ mod tests {
         assert!(err.to_string().contains("Unknown embedding model"));
     }
 
+    #[test]
+    fn test_resolve_reranker_model() {
+        assert!(resolve_reranker_model("bge-reranker-base").is_ok());
+        assert!(resolve_reranker_model("bge-reranker-v2-m3").is_ok());
+        assert!(resolve_reranker_model("jina-reranker-v1-turbo-en").is_ok());
+        assert!(resolve_reranker_model("JINARerankerV2BaseMultiligual").is_ok());
+        let err = resolve_reranker_model("bge-small-en-v1.5").unwrap_err();
+        assert!(err.to_string().contains("Unknown reranker model"));
+    }
+
     // Integration tests requiring model download are gated with #[ignore]
     #[tokio::test]
     #[ignore = "requires model download (~50MB)"]
@@ -214,4 +336,22 @@ mod tests {
         let e2 = provider.embed("same text").await.unwrap();
         assert_eq!(e1, e2);
     }
+
+    #[tokio::test]
+    #[ignore = "requires model download (~280MB)"]
+    async fn test_fastembed_reranker_orders_relevant_first() {
+        let reranker = FastEmbedReranker::new(FastEmbedReranker::DEFAULT_MODEL, None).unwrap();
+        let scores = reranker
+            .score(
+                "what is a cadence",
+                &[
+                    "Pandas eat bamboo.",
+                    "A cadence is a harmonic progression ending a phrase.",
+                ],
+            )
+            .await
+            .unwrap();
+        assert_eq!(scores.len(), 2);
+        assert!(scores[1] > scores[0]);
+    }
 }
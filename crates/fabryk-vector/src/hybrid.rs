@@ -10,10 +10,19 @@
 //! Where `rank_i(d)` is the 1-based rank of `d` in result list `i`, and `k`
 //! is a constant (default 60) that controls how much weight is given to
 //! lower-ranked items.
+//!
+//! # Reranking
+//!
+//! After fusion, [`rerank_hybrid`] can re-score the top candidates with a
+//! [`Reranker`]. [`SearchTimings`] records how long each stage took so the
+//! cost of reranking is visible to callers.
 
+use fabryk_core::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::time::Instant;
 
+use crate::rerank::{Reranker, rerank_top_k};
 use crate::types::VectorSearchResult;
 
 /// A hybrid search result combining vector and keyword search.
@@ -31,6 +40,46 @@ pub struct HybridSearchResult {
     /// Metadata snapshot.
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub metadata: HashMap<String, String>,
+
+    /// Best-matching passage from the vector results, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub passage: Option<String>,
+
+    /// Reranker score, if this result was re-scored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rerank_score: Option<f32>,
+}
+
+/// Per-stage wall-clock timings of a search, in milliseconds.
+///
+/// Stages that did not run are `None` and omitted from serialized output.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SearchTimings {
+    /// Full-text keyword search.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keyword_ms: Option<f64>,
+
+    /// Vector similarity search.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vector_ms: Option<f64>,
+
+    /// Reciprocal Rank Fusion.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fusion_ms: Option<f64>,
+
+    /// Reranking of the top candidates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rerank_ms: Option<f64>,
+
+    /// Whole search, including stages not listed above.
+    pub total_ms: f64,
+}
+
+impl SearchTimings {
+    /// Milliseconds elapsed since `start`.
+    pub fn since(start: Instant) -> f64 {
+        start.elapsed().as_secs_f64() * 1000.0
+    }
 }
 
 /// An FTS result suitable for RRF merging.
@@ -72,6 +121,7 @@ pub fn reciprocal_rank_fusion(
     let mut scores: HashMap<String, f32> = HashMap::new();
     let mut metadata: HashMap<String, HashMap<String, String>> = HashMap::new();
     let mut sources: HashMap<String, (bool, bool)> = HashMap::new(); // (has_vector, has_fts)
+    let mut passages: HashMap<String, String> = HashMap::new();
 
     // Score vector results
     for (rank, result) in vector_results.iter().enumerate() {
@@ -81,6 +131,11 @@ pub fn reciprocal_rank_fusion(
             .entry(result.id.clone())
             .or_insert_with(|| result.metadata.clone());
         sources.entry(result.id.clone()).or_insert((false, false)).0 = true;
+        if let Some(ref passage) = result.passage {
+            passages
+                .entry(result.id.clone())
+                .or_insert_with(|| passage.clone());
+        }
     }
 
     // Score FTS results
@@ -111,6 +166,8 @@ pub fn reciprocal_rank_fusion(
                 score,
                 source,
                 metadata: metadata.remove(&id).unwrap_or_default(),
+                passage: passages.remove(&id),
+                rerank_score: None,
             }
         })
         .collect();
@@ -124,6 +181,31 @@ pub fn reciprocal_rank_fusion(
     results
 }
 
+/// Rerank the top `top_k` fused results.
+///
+/// `text` supplies the document text the reranker scores for each result.
+/// Re-scored results carry their `rerank_score` and are ordered by it;
+/// the remaining results keep their RRF order after them.
+pub async fn rerank_hybrid<F>(
+    reranker: &dyn Reranker,
+    query: &str,
+    results: Vec<HybridSearchResult>,
+    top_k: usize,
+    text: F,
+) -> Result<Vec<HybridSearchResult>>
+where
+    F: Fn(&HybridSearchResult) -> String,
+{
+    let ranked = rerank_top_k(reranker, query, results, top_k, text).await?;
+    Ok(ranked
+        .into_iter()
+        .map(|(mut result, score)| {
+            result.rerank_score = score;
+            result
+        })
+        .collect())
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -140,6 +222,7 @@ mod tests {
                 score: 1.0 - (i as f32 * 0.1),
                 distance: i as f32 * 0.1,
                 metadata: HashMap::new(),
+                passage: None,
             })
             .collect()
     }
@@ -272,11 +355,13 @@ mod tests {
             score: 0.9,
             distance: 0.1,
             metadata: HashMap::from([("category".to_string(), "harmony".to_string())]),
+            passage: Some("Harmony basics".to_string()),
         }];
 
         let results = reciprocal_rank_fusion(&vector, &[], 10, 60);
 
         assert_eq!(results[0].metadata.get("category").unwrap(), "harmony");
+        assert_eq!(results[0].passage.as_deref(), Some("Harmony basics"));
     }
 
     #[test]
@@ -286,6 +371,8 @@ mod tests {
             score: 0.5,
             source: "hybrid".to_string(),
             metadata: HashMap::new(),
+            passage: None,
+            rerank_score: None,
         };
 
         let json = serde_json::to_string(&result).unwrap();
@@ -293,5 +380,48 @@ mod tests {
         assert!(json.contains("hybrid"));
         // Empty metadata should be omitted
         assert!(!json.contains("metadata"));
+        assert!(!json.contains("rerank_score"));
+    }
+
+    #[tokio::test]
+    async fn test_rerank_hybrid_rescores_top_k() {
+        let vector = make_vector_results(&["a", "b", "c"]);
+        let fused = reciprocal_rank_fusion(&vector, &[], 10, 60);
+        let texts = HashMap::from([
+            ("a", "unrelated text"),
+            ("b", "chord progression"),
+            ("c", "chord"),
+        ]);
+
+        let reranked = rerank_hybrid(
+            &crate::rerank::MockReranker,
+            "chord progression",
+            fused,
+            2,
+            |r| texts[r.id.as_str()].to_string(),
+        )
+        .await
+        .unwrap();
+
+        let ids: Vec<&str> = reranked.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["b", "a", "c"]);
+        assert_eq!(reranked[0].rerank_score, Some(1.0));
+        assert_eq!(reranked[1].rerank_score, Some(0.0));
+        assert_eq!(reranked[2].rerank_score, None);
+        // RRF scores are kept alongside the rerank score
+        assert!(reranked[0].score < reranked[1].score);
+    }
+
+    #[test]
+    fn test_search_timings_serialization_omits_skipped_stages() {
+        let timings = SearchTimings {
+            keyword_ms: Some(1.5),
+            total_ms: 2.0,
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&timings).unwrap();
+        assert_eq!(json["keyword_ms"], 1.5);
+        assert!(json.get("rerank_ms").is_none());
+        assert_eq!(json["total_ms"], 2.0);
     }
 }
@@ -14,14 +14,24 @@
 //! | `category` | Utf8 (nullable) | Category for filtering |
 //! | `metadata` | Utf8 | JSON-serialized metadata |
 //! | `vector` | FixedSizeList<Float32> | Embedding vector |
+//! | `content_hash` | Utf8 (nullable) | Hash of the document when embedded |
+//!
+//! # Incremental Updates
+//!
+//! [`VectorBackend::upsert`] merges documents into the table on `id`, and
+//! [`VectorBackend::delete`] removes rows by `id`, so an index can be kept
+//! up to date without rebuilding it.
 //!
 //! # Feature Gate
 //!
 //! This module requires the `vector-lancedb` feature.
 
 use crate::backend::VectorBackend;
+use crate::chunking::PARENT_ID_KEY;
 use crate::embedding::EmbeddingProvider;
-use crate::types::{EmbeddedDocument, VectorSearchParams, VectorSearchResult, VectorSearchResults};
+use crate::types::{
+    EmbeddedDocument, VectorDocument, VectorSearchParams, VectorSearchResult, VectorSearchResults,
+};
 use arrow_array::{
     Array, FixedSizeListArray, Float32Array, RecordBatch, RecordBatchIterator, RecordBatchReader,
     StringArray,
@@ -32,6 +42,7 @@ use fabryk_core::{Error, Result};
 use futures::TryStreamExt;
 use lancedb::query::{ExecutableQuery, QueryBase};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 /// LanceDB-backed vector search backend.
 ///
@@ -41,7 +52,7 @@ pub struct LancedbBackend {
     connection: lancedb::Connection,
     table_name: String,
     provider: Arc<dyn EmbeddingProvider>,
-    document_count: usize,
+    document_count: AtomicUsize,
 }
 
 impl LancedbBackend {
@@ -91,15 +102,46 @@ impl LancedbBackend {
             connection,
             table_name: table_name.to_string(),
             provider,
-            document_count: doc_count,
+            document_count: AtomicUsize::new(doc_count),
         })
     }
+
+    /// Open the vector table, or `None` if it has not been created yet.
+    async fn open_table(&self) -> Result<Option<lancedb::Table>> {
+        let names = self
+            .connection
+            .table_names()
+            .execute()
+            .await
+            .map_err(|e| Error::operation(format!("Failed to list LanceDB tables: {e}")))?;
+        if !names.contains(&self.table_name) {
+            return Ok(None);
+        }
+
+        let table = self
+            .connection
+            .open_table(&self.table_name)
+            .execute()
+            .await
+            .map_err(|e| Error::operation(format!("Failed to open table: {e}")))?;
+        Ok(Some(table))
+    }
+
+    /// Re-read the row count after the table changed.
+    async fn refresh_count(&self, table: &lancedb::Table) -> Result<usize> {
+        let count = table
+            .count_rows(None)
+            .await
+            .map_err(|e| Error::operation(format!("Failed to count rows: {e}")))?;
+        self.document_count.store(count, Ordering::Relaxed);
+        Ok(count)
+    }
 }
 
 #[async_trait]
 impl VectorBackend for LancedbBackend {
     async fn search(&self, params: VectorSearchParams) -> Result<VectorSearchResults> {
-        if self.document_count == 0 {
+        if self.document_count.load(Ordering::Relaxed) == 0 {
             return Ok(VectorSearchResults::empty(self.name()));
         }
 
@@ -171,7 +213,91 @@ impl VectorBackend for LancedbBackend {
     }
 
     fn document_count(&self) -> Result<usize> {
-        Ok(self.document_count)
+        Ok(self.document_count.load(Ordering::Relaxed))
+    }
+
+    async fn upsert(&self, documents: Vec<EmbeddedDocument>) -> Result<()> {
+        if documents.is_empty() {
+            return Ok(());
+        }
+
+        let batch = build_record_batch(&documents, self.provider.dimension() as i32)?;
+        let schema = batch.schema();
+        let batches = RecordBatchIterator::new(vec![Ok(batch)], schema);
+
+        let table = match self.open_table().await? {
+            Some(table) => {
+                let mut merge = table.merge_insert(&["id"]);
+                merge
+                    .when_matched_update_all(None)
+                    .when_not_matched_insert_all();
+                merge
+                    .execute(Box::new(batches))
+                    .await
+                    .map_err(|e| Error::operation(format!("Failed to upsert documents: {e}")))?;
+                table
+            }
+            None => self
+                .connection
+                .create_table(
+                    &self.table_name,
+                    Box::new(batches) as Box<dyn RecordBatchReader + Send>,
+                )
+                .execute()
+                .await
+                .map_err(|e| Error::operation(format!("Failed to create LanceDB table: {e}")))?,
+        };
+
+        self.refresh_count(&table).await?;
+        Ok(())
+    }
+
+    async fn delete(&self, ids: &[String]) -> Result<usize> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+        let Some(table) = self.open_table().await? else {
+            return Ok(0);
+        };
+
+        let before = self.refresh_count(&table).await?;
+        let quoted: Vec<String> = ids
+            .iter()
+            .map(|id| format!("'{}'", id.replace('\'', "''")))
+            .collect();
+        table
+            .delete(&format!("id IN ({})", quoted.join(", ")))
+            .await
+            .map_err(|e| Error::operation(format!("Failed to delete documents: {e}")))?;
+        let after = self.refresh_count(&table).await?;
+
+        Ok(before.saturating_sub(after))
+    }
+
+    async fn get(&self, id: &str) -> Result<Option<EmbeddedDocument>> {
+        let Some(table) = self.open_table().await? else {
+            return Ok(None);
+        };
+
+        let results = table
+            .query()
+            .only_if(format!("id = '{}'", id.replace('\'', "''")))
+            .limit(1)
+            .execute()
+            .await
+            .map_err(|e| Error::operation(format!("Document lookup failed: {e}")))?;
+
+        let batches: Vec<RecordBatch> = results
+            .try_collect()
+            .await
+            .map_err(|e| Error::operation(format!("Failed to collect results: {e}")))?;
+
+        for batch in &batches {
+            if let Some(document) = parse_documents(batch)?.into_iter().next() {
+                return Ok(Some(document));
+            }
+        }
+        Ok(None)
     }
 }
 
@@ -179,7 +305,7 @@ impl std::fmt::Debug for LancedbBackend {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("LancedbBackend")
             .field("table", &self.table_name)
-            .field("documents", &self.document_count)
+            .field("documents", &self.document_count.load(Ordering::Relaxed))
             .finish()
     }
 }
@@ -203,6 +329,7 @@ fn make_schema(dimension: i32) -> Arc<Schema> {
             ),
             false,
         ),
+        Field::new("content_hash", DataType::Utf8, true),
     ]))
 }
 
@@ -221,6 +348,10 @@ fn build_record_batch(documents: &[EmbeddedDocument], dimension: i32) -> Result<
         .map(|d| serde_json::to_string(&d.document.metadata).unwrap_or_else(|_| "{}".to_string()))
         .collect();
     let metadata_refs: Vec<&str> = metadata_strings.iter().map(|s| s.as_str()).collect();
+    let content_hashes: Vec<Option<&str>> = documents
+        .iter()
+        .map(|d| d.content_hash.as_deref())
+        .collect();
 
     // Flatten embeddings into a single Vec<f32>
     let all_values: Vec<f32> = documents
@@ -245,11 +376,69 @@ fn build_record_batch(documents: &[EmbeddedDocument], dimension: i32) -> Result<
             Arc::new(StringArray::from(categories)),
             Arc::new(StringArray::from(metadata_refs)),
             Arc::new(vector_array),
+            Arc::new(StringArray::from(content_hashes)),
         ],
     )
     .map_err(|e| Error::operation(format!("Failed to create RecordBatch: {e}")))
 }
 
+/// Get a string column from a RecordBatch.
+fn string_column<'a>(batch: &'a RecordBatch, name: &str) -> Result<&'a StringArray> {
+    batch
+        .column_by_name(name)
+        .ok_or_else(|| Error::operation(format!("Missing '{name}' column in results")))?
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .ok_or_else(|| Error::operation(format!("'{name}' column is not StringArray")))
+}
+
+/// Parse stored documents, with their embeddings, from a RecordBatch.
+///
+/// Tables written before the `content_hash` column existed parse with no
+/// hash, so their documents are treated as changed.
+fn parse_documents(batch: &RecordBatch) -> Result<Vec<EmbeddedDocument>> {
+    let id_col = string_column(batch, "id")?;
+    let text_col = string_column(batch, "text")?;
+    let category_col = string_column(batch, "category")?;
+    let metadata_col = string_column(batch, "metadata")?;
+    let hash_col = batch
+        .column_by_name("content_hash")
+        .and_then(|c| c.as_any().downcast_ref::<StringArray>());
+    let vector_col = batch
+        .column_by_name("vector")
+        .ok_or_else(|| Error::operation("Missing 'vector' column in results"))?
+        .as_any()
+        .downcast_ref::<FixedSizeListArray>()
+        .ok_or_else(|| Error::operation("'vector' column is not FixedSizeListArray"))?;
+
+    let mut documents = Vec::with_capacity(batch.num_rows());
+    for i in 0..batch.num_rows() {
+        let mut document = VectorDocument::new(id_col.value(i), text_col.value(i));
+        if !category_col.is_null(i) {
+            document.category = Some(category_col.value(i).to_string());
+        }
+        document.metadata = serde_json::from_str(metadata_col.value(i)).unwrap_or_default();
+
+        let values = vector_col.value(i);
+        let embedding = values
+            .as_any()
+            .downcast_ref::<Float32Array>()
+            .ok_or_else(|| Error::operation("'vector' items are not Float32Array"))?
+            .values()
+            .to_vec();
+
+        documents.push(EmbeddedDocument {
+            document,
+            embedding,
+            content_hash: hash_col
+                .filter(|c| !c.is_null(i))
+                .map(|c| c.value(i).to_string()),
+        });
+    }
+
+    Ok(documents)
+}
+
 /// Parse search results from a RecordBatch.
 fn parse_search_results(batch: &RecordBatch) -> Result<Vec<VectorSearchResult>> {
     let id_col = batch
@@ -270,6 +459,10 @@ fn parse_search_results(batch: &RecordBatch) -> Result<Vec<VectorSearchResult>>
         .column_by_name("_distance")
         .and_then(|c| c.as_any().downcast_ref::<Float32Array>());
 
+    let text_col = batch
+        .column_by_name("text")
+        .and_then(|c| c.as_any().downcast_ref::<StringArray>());
+
     let mut results = Vec::new();
     for i in 0..batch.num_rows() {
         let id = id_col.value(i).to_string();
@@ -285,11 +478,17 @@ fn parse_search_results(batch: &RecordBatch) -> Result<Vec<VectorSearchResult>>
         // Distance-to-score normalization: 1/(1 + distance)
         let score = 1.0 / (1.0 + distance);
 
+        // Chunks carry their text as the matching passage
+        let passage = text_col
+            .filter(|_| metadata.contains_key(PARENT_ID_KEY))
+            .map(|c| c.value(i).to_string());
+
         results.push(VectorSearchResult {
             id,
             score,
             distance,
             metadata,
+            passage,
         });
     }
 
@@ -329,7 +528,7 @@ mod tests {
     #[test]
     fn test_make_schema() {
         let schema = make_schema(384);
-        assert_eq!(schema.fields().len(), 5);
+        assert_eq!(schema.fields().len(), 6);
         assert_eq!(schema.field(0).name(), "id");
         assert_eq!(schema.field(4).name(), "vector");
 
@@ -345,7 +544,7 @@ mod tests {
         let batch = build_record_batch(&docs, 8).unwrap();
 
         assert_eq!(batch.num_rows(), 3);
-        assert_eq!(batch.num_columns(), 5);
+        assert_eq!(batch.num_columns(), 6);
     }
 
     #[test]
@@ -409,6 +608,22 @@ mod tests {
         assert_eq!(results[0].score, 1.0);
     }
 
+    #[test]
+    fn test_parse_documents_round_trip() {
+        let mut docs = make_test_documents(4);
+        docs[0] = EmbeddedDocument::hashed(docs[0].document.clone(), vec![0.1; 4]);
+        let batch = build_record_batch(&docs, 4).unwrap();
+
+        let parsed = parse_documents(&batch).unwrap();
+        assert_eq!(parsed.len(), 3);
+        assert_eq!(parsed[0].document.id, "doc-1");
+        assert_eq!(parsed[0].document.category.as_deref(), Some("harmony"));
+        assert_eq!(parsed[0].document.metadata["tier"], "beginner");
+        assert_eq!(parsed[0].embedding, vec![0.1; 4]);
+        assert!(parsed[0].is_current(&docs[0].document));
+        assert_eq!(parsed[2].content_hash, None);
+    }
+
     #[test]
     fn test_distance_to_score_normalization() {
         // score = 1/(1 + distance)
@@ -521,10 +736,49 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_lancedb_backend_upsert_delete_get() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("upsert_db");
+
+        let provider = Arc::new(crate::embedding::MockEmbeddingProvider::new(4));
+        let backend = LancedbBackend::build(
+            db_path.to_str().unwrap(),
+            "upsert_table",
+            provider,
+            Vec::new(),
+        )
+        .await
+        .unwrap();
+        assert!(backend.get("doc-1").await.unwrap().is_none());
+
+        backend.upsert(make_test_documents(4)).await.unwrap();
+        assert_eq!(backend.document_count().unwrap(), 3);
+
+        backend
+            .upsert(vec![EmbeddedDocument::new(
+                VectorDocument::new("doc-1", "revised harmony"),
+                vec![0.4; 4],
+            )])
+            .await
+            .unwrap();
+        assert_eq!(backend.document_count().unwrap(), 3);
+        let doc = backend.get("doc-1").await.unwrap().unwrap();
+        assert_eq!(doc.document.text, "revised harmony");
+
+        let removed = backend
+            .delete(&["doc-2".to_string(), "missing".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(removed, 1);
+        assert_eq!(backend.document_count().unwrap(), 2);
+        assert!(backend.get("doc-2").await.unwrap().is_none());
+    }
+
     #[test]
     fn test_lancedb_debug() {
         // Can't easily construct without async, so just test schema/batch helpers
         let schema = make_schema(4);
-        assert_eq!(schema.fields().len(), 5);
+        assert_eq!(schema.fields().len(), 6);
     }
 }
@@ -8,6 +8,8 @@
 //!
 //! - `vector-lancedb`: Enable LanceDB-based vector storage and ANN search
 //! - `vector-fastembed`: Enable local embedding generation via fastembed
+//! - `vector-openai`: Enable embeddings from OpenAI-compatible HTTP APIs
+//! - `vector-onnx`: Enable embeddings from a local ONNX model directory
 //!
 //! # Architecture
 //!
@@ -17,17 +19,26 @@
 //! ├─────────────────────────────────────────────────────────────┤
 //! │  EmbeddingProvider trait                                    │
 //! │  ├── MockEmbeddingProvider (always available)               │
-//! │  └── FastEmbedProvider (feature: vector-fastembed)          │
+//! │  ├── CachedEmbeddingProvider (disk cache wrapper)           │
+//! │  ├── FastEmbedProvider (feature: vector-fastembed)          │
+//! │  ├── OpenAiEmbeddingProvider (feature: vector-openai)       │
+//! │  └── OnnxEmbeddingProvider (feature: vector-onnx)           │
+//! ├─────────────────────────────────────────────────────────────┤
+//! │  Reranker trait                                             │
+//! │  ├── MockReranker (always available)                        │
+//! │  └── FastEmbedReranker (feature: vector-fastembed)          │
 //! ├─────────────────────────────────────────────────────────────┤
 //! │  VectorBackend trait                                        │
 //! │  ├── SimpleVectorBackend (in-memory fallback)               │
 //! │  └── LancedbBackend (feature: vector-lancedb)              │
 //! ├─────────────────────────────────────────────────────────────┤
 //! │  VectorExtractor trait (domain text composition)            │
+//! │  Chunker (section / token-window splitting, hit folding)    │
 //! │  VectorIndexBuilder (batch embed + index orchestration)     │
 //! ├─────────────────────────────────────────────────────────────┤
-//! │  Hybrid search (RRF merge with FTS results)                │
+//! │  Hybrid search (RRF merge with FTS results, reranking)      │
 //! │  Persistence (content hash freshness checking)              │
+//! │  Incremental updates (per-document hashes, upsert/delete)   │
 //! └─────────────────────────────────────────────────────────────┘
 //! ```
 //!
@@ -55,14 +66,18 @@
 
 // Core modules (always available)
 pub mod backend;
+pub mod cached;
 pub mod concept_card_extractor;
 pub mod embedding;
 pub mod probe;
+pub mod rerank;
 pub mod types;
 
 // Builder and extractor modules (always available)
 pub mod builder;
+pub mod chunking;
 pub mod extractor;
+pub mod updater;
 
 // Hybrid search and persistence (always available)
 pub mod hybrid;
@@ -75,6 +90,12 @@ pub mod fastembed;
 #[cfg(feature = "vector-lancedb")]
 pub mod lancedb;
 
+#[cfg(feature = "vector-openai")]
+pub mod openai;
+
+#[cfg(feature = "vector-onnx")]
+pub mod onnx;
+
 // Re-exports — core types
 pub use types::{
     BuildError, EmbeddedDocument, VectorConfig, VectorDocument, VectorIndexStats,
@@ -83,16 +104,22 @@ pub use types::{
 
 // Re-exports — traits
 pub use backend::{SimpleVectorBackend, VectorBackend};
+pub use cached::CachedEmbeddingProvider;
 pub use concept_card_extractor::ConceptCardVectorExtractor;
-pub use embedding::{EmbeddingProvider, MockEmbeddingProvider};
+pub use embedding::{EmbeddingProvider, MockEmbeddingProvider, create_embedding_provider};
 pub use extractor::VectorExtractor;
 pub use probe::{VectorProbe, vector_probe};
+pub use rerank::{DEFAULT_RERANK_TOP_K, MockReranker, Reranker, rerank_top_k};
 
 // Re-exports — builder
 pub use builder::VectorIndexBuilder;
+pub use chunking::{Chunker, fold_chunks};
+pub use updater::VectorUpdater;
 
 // Re-exports — hybrid search
-pub use hybrid::{FtsResult, HybridSearchResult, reciprocal_rank_fusion};
+pub use hybrid::{
+    FtsResult, HybridSearchResult, SearchTimings, reciprocal_rank_fusion, rerank_hybrid,
+};
 
 // Re-exports — persistence
 pub use persistence::is_index_fresh;
@@ -102,7 +129,13 @@ pub use backend::create_vector_backend;
 
 // Feature-gated re-exports
 #[cfg(feature = "vector-fastembed")]
-pub use fastembed::FastEmbedProvider;
+pub use fastembed::{FastEmbedProvider, FastEmbedReranker};
 
 #[cfg(feature = "vector-lancedb")]
 pub use lancedb::LancedbBackend;
+
+#[cfg(feature = "vector-openai")]
+pub use openai::OpenAiEmbeddingProvider;
+
+#[cfg(feature = "vector-onnx")]
+pub use onnx::OnnxEmbeddingProvider;
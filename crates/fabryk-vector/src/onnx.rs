@@ -0,0 +1,215 @@
+//! Local ONNX embedding provider.
+//!
+//! Runs a sentence-embedding model exported to ONNX from a local directory,
+//! with no download step. This covers fine-tuned or private models that the
+//! fastembed catalogue does not ship.
+//!
+//! # Model Directory
+//!
+//! The directory must hold the standard Hugging Face export files:
+//!
+//! | File | Contents |
+//! |------|----------|
+//! | `model.onnx` | The ONNX graph |
+//! | `tokenizer.json` | Tokenizer definition |
+//! | `config.json` | Model configuration |
+//! | `special_tokens_map.json` | Special token mapping |
+//! | `tokenizer_config.json` | Tokenizer settings (including `model_max_length`) |
+//!
+//! # Feature Gate
+//!
+//! This module requires the `vector-onnx` feature. Inference runs on ONNX
+//! Runtime through fastembed's user-defined model support.
+
+use crate::embedding::EmbeddingProvider;
+use crate::types::VectorConfig;
+use async_trait::async_trait;
+use fabryk_core::{Error, Result};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// File name of the ONNX graph inside the model directory.
+pub const MODEL_FILE: &str = "model.onnx";
+
+/// Map a pooling name to a fastembed `Pooling` strategy.
+fn resolve_pooling(name: &str) -> Result<fastembed::Pooling> {
+    match name {
+        "mean" => Ok(fastembed::Pooling::Mean),
+        "cls" => Ok(fastembed::Pooling::Cls),
+        other => Err(Error::config(format!(
+            "Unknown pooling: '{other}'. Supported: mean, cls"
+        ))),
+    }
+}
+
+/// Read one file from the model directory.
+fn read_model_file(dir: &Path, name: &str) -> Result<Vec<u8>> {
+    let path = dir.join(name);
+    std::fs::read(&path).map_err(|e| Error::io_with_path(e, &path))
+}
+
+/// Embedding provider for a local ONNX sentence-embedding model.
+///
+/// The model is loaded once and reused for all subsequent calls.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use fabryk_vector::OnnxEmbeddingProvider;
+///
+/// let provider = OnnxEmbeddingProvider::new("/models/my-minilm", "mean")?;
+/// println!("dimension: {}", provider.dimension());
+/// ```
+pub struct OnnxEmbeddingProvider {
+    model: Arc<Mutex<fastembed::TextEmbedding>>,
+    dimension: usize,
+    model_name: String,
+}
+
+impl OnnxEmbeddingProvider {
+    /// Load the model in `model_dir`, pooling token embeddings with
+    /// `pooling` ("mean" or "cls").
+    pub fn new(model_dir: impl Into<PathBuf>, pooling: &str) -> Result<Self> {
+        let model_dir = model_dir.into();
+        let pooling = resolve_pooling(pooling)?;
+
+        let tokenizer_files = fastembed::TokenizerFiles {
+            tokenizer_file: read_model_file(&model_dir, "tokenizer.json")?,
+            config_file: read_model_file(&model_dir, "config.json")?,
+            special_tokens_map_file: read_model_file(&model_dir, "special_tokens_map.json")?,
+            tokenizer_config_file: read_model_file(&model_dir, "tokenizer_config.json")?,
+        };
+        let model = fastembed::UserDefinedEmbeddingModel::new(
+            read_model_file(&model_dir, MODEL_FILE)?,
+            tokenizer_files,
+        )
+        .with_pooling(pooling);
+
+        let mut text_embedding = fastembed::TextEmbedding::try_new_from_user_defined(
+            model,
+            fastembed::InitOptionsUserDefined::default(),
+        )
+        .map_err(|e| Error::operation(format!("Failed to load ONNX model: {e}")))?;
+
+        // Probe dimension via a test embedding
+        let probe = text_embedding
+            .embed(vec!["dimension probe"], None)
+            .map_err(|e| Error::operation(format!("Failed to probe embedding dimension: {e}")))?;
+
+        let dimension = probe
+            .first()
+            .map(|v| v.len())
+            .ok_or_else(|| Error::operation("Empty probe embedding"))?;
+
+        Ok(Self {
+            model: Arc::new(Mutex::new(text_embedding)),
+            dimension,
+            model_name: model_dir.to_string_lossy().into_owned(),
+        })
+    }
+
+    /// Create a provider from vector configuration.
+    ///
+    /// Uses `model_path` (required) and `pooling` (default "mean").
+    pub fn from_config(config: &VectorConfig) -> Result<Self> {
+        let model_dir = config
+            .model_path
+            .as_deref()
+            .ok_or_else(|| Error::config("model_path is required for the onnx provider"))?;
+        Self::new(model_dir, config.pooling.as_deref().unwrap_or("mean"))
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for OnnxEmbeddingProvider {
+    async fn embed(&self, text: &str) -> Result<Vec<f32>> {
+        let mut results = self.embed_batch(&[text]).await?;
+        results
+            .pop()
+            .ok_or_else(|| Error::operation("No embedding returned"))
+    }
+
+    async fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        let model = self.model.clone();
+        let texts: Vec<String> = texts.iter().map(|t| t.to_string()).collect();
+
+        tokio::task::spawn_blocking(move || {
+            let mut model = model
+                .lock()
+                .map_err(|e| Error::operation(format!("Mutex poisoned: {e}")))?;
+            model
+                .embed(texts, None)
+                .map_err(|e| Error::operation(format!("Batch embedding failed: {e}")))
+        })
+        .await
+        .map_err(|e| Error::operation(format!("spawn_blocking failed: {e}")))?
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn name(&self) -> &str {
+        &self.model_name
+    }
+}
+
+impl std::fmt::Debug for OnnxEmbeddingProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OnnxEmbeddingProvider")
+            .field("model", &self.model_name)
+            .field("dimension", &self.dimension)
+            .finish()
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_pooling() {
+        assert!(matches!(
+            resolve_pooling("mean").unwrap(),
+            fastembed::Pooling::Mean
+        ));
+        assert!(matches!(
+            resolve_pooling("cls").unwrap(),
+            fastembed::Pooling::Cls
+        ));
+        let err = resolve_pooling("max").unwrap_err();
+        assert!(err.to_string().contains("Unknown pooling"));
+    }
+
+    #[test]
+    fn test_onnx_provider_missing_model_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = OnnxEmbeddingProvider::new(dir.path().join("absent"), "mean").unwrap_err();
+        assert!(err.to_string().contains("tokenizer.json"));
+    }
+
+    #[test]
+    fn test_onnx_provider_from_config_requires_model_path() {
+        let err = OnnxEmbeddingProvider::from_config(&VectorConfig {
+            provider: "onnx".to_string(),
+            ..Default::default()
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("model_path"));
+    }
+
+    // Needs an exported model, e.g. sentence-transformers/all-MiniLM-L6-v2
+    #[tokio::test]
+    #[ignore = "requires an ONNX model in FABRYK_ONNX_MODEL_DIR"]
+    async fn test_onnx_provider_embeds() {
+        let dir = std::env::var("FABRYK_ONNX_MODEL_DIR").unwrap();
+        let provider = OnnxEmbeddingProvider::new(dir, "mean").unwrap();
+        let embeddings = provider.embed_batch(&["Hello", "World"]).await.unwrap();
+        assert_eq!(embeddings.len(), 2);
+        assert_eq!(embeddings[0].len(), provider.dimension());
+    }
+}
@@ -0,0 +1,495 @@
+//! OpenAI-compatible HTTP embedding provider.
+//!
+//! Calls a `/embeddings` endpoint in the OpenAI wire format. Besides OpenAI
+//! itself, this works with servers exposing the same API, such as Ollama
+//! (`http://localhost:11434/v1`) and vLLM.
+//!
+//! # Behavior
+//!
+//! - Texts are sent in batches of [`with_batch_size`](OpenAiEmbeddingProvider::with_batch_size).
+//! - Rate limits (429), server errors (5xx) and connection failures are
+//!   retried with exponential backoff; a `Retry-After` header takes
+//!   precedence over the computed delay.
+//! - When no dimension is configured, [`build`](OpenAiEmbeddingProvider::build)
+//!   discovers it by embedding a probe text.
+//!
+//! # Feature Gate
+//!
+//! This module requires the `vector-openai` feature.
+
+use crate::embedding::EmbeddingProvider;
+use crate::types::VectorConfig;
+use async_trait::async_trait;
+use fabryk_core::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Default API base URL.
+pub const OPENAI_API_BASE: &str = "https://api.openai.com/v1";
+
+/// Environment variable holding the API key when none is configured.
+pub const DEFAULT_API_KEY_ENV: &str = "OPENAI_API_KEY";
+
+/// How many times a failed request is retried before giving up.
+const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Delay before the first retry; doubled on each further retry.
+const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Upper bound for any single retry delay.
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Default request timeout.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Serialize)]
+struct EmbeddingRequest<'a> {
+    model: &'a str,
+    input: &'a [&'a str],
+    encoding_format: &'static str,
+}
+
+#[derive(Deserialize)]
+struct EmbeddingResponse {
+    data: Vec<EmbeddingData>,
+}
+
+#[derive(Deserialize)]
+struct EmbeddingData {
+    embedding: Vec<f32>,
+    #[serde(default)]
+    index: Option<usize>,
+}
+
+/// Embedding provider for OpenAI-compatible `/embeddings` APIs.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use fabryk_vector::OpenAiEmbeddingProvider;
+///
+/// // A local Ollama server needs no API key
+/// let provider = OpenAiEmbeddingProvider::new("http://localhost:11434/v1", "nomic-embed-text")
+///     .build()
+///     .await?;
+/// println!("dimension: {}", provider.dimension());
+/// ```
+#[derive(Debug)]
+pub struct OpenAiEmbeddingProvider {
+    client: reqwest::Client,
+    base_url: String,
+    model: String,
+    api_key: Option<String>,
+    dimension: usize,
+    batch_size: usize,
+    max_retries: u32,
+    initial_backoff: Duration,
+}
+
+impl OpenAiEmbeddingProvider {
+    /// Create a provider for `model` at `base_url` (e.g.
+    /// `https://api.openai.com/v1`).
+    ///
+    /// The dimension is unknown until set with
+    /// [`with_dimension`](Self::with_dimension) or discovered by
+    /// [`build`](Self::build).
+    pub fn new(base_url: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .unwrap_or_default(),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            model: model.into(),
+            api_key: None,
+            dimension: 0,
+            batch_size: 64,
+            max_retries: DEFAULT_MAX_RETRIES,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+        }
+    }
+
+    /// Create a provider from vector configuration.
+    ///
+    /// Uses `api_base` (default [`OPENAI_API_BASE`]), `model`, `dimension`
+    /// and `batch_size`. The API key is read from the environment variable
+    /// named by `api_key_env` (default [`DEFAULT_API_KEY_ENV`]); local
+    /// servers usually need none.
+    pub fn from_config(config: &VectorConfig) -> Self {
+        let base_url = config.api_base.as_deref().unwrap_or(OPENAI_API_BASE);
+        let key_env = config.api_key_env.as_deref().unwrap_or(DEFAULT_API_KEY_ENV);
+
+        let mut provider = Self::new(base_url, &config.model)
+            .with_dimension(config.dimension)
+            .with_batch_size(config.batch_size);
+        if let Ok(key) = std::env::var(key_env)
+            && !key.trim().is_empty()
+        {
+            provider = provider.with_api_key(key.trim());
+        }
+        provider
+    }
+
+    /// Set the bearer token sent with each request.
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Set the embedding dimension (0 to discover it in [`build`](Self::build)).
+    pub fn with_dimension(mut self, dimension: usize) -> Self {
+        self.dimension = dimension;
+        self
+    }
+
+    /// Set the maximum number of texts per request.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Set how many times a failed request is retried.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Set the delay before the first retry (doubled on each further retry).
+    pub fn with_initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Finish construction, discovering the dimension if it is not set.
+    pub async fn build(mut self) -> Result<Self> {
+        if self.dimension == 0 {
+            let probe = self.request(&["dimension probe"]).await?;
+            self.dimension = probe
+                .first()
+                .map(Vec::len)
+                .filter(|d| *d > 0)
+                .ok_or_else(|| Error::operation("Empty probe embedding"))?;
+            log::info!(
+                "Discovered embedding dimension {} for model '{}'",
+                self.dimension,
+                self.model
+            );
+        }
+        Ok(self)
+    }
+
+    /// Embed one batch, retrying transient failures.
+    async fn request(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        let url = format!("{}/embeddings", self.base_url);
+        let body = EmbeddingRequest {
+            model: &self.model,
+            input: texts,
+            encoding_format: "float",
+        };
+
+        let mut attempt = 0;
+        loop {
+            let mut request = self.client.post(&url).json(&body);
+            if let Some(ref key) = self.api_key {
+                request = request.bearer_auth(key);
+            }
+
+            let (retry_after, failure) = match request.send().await {
+                Ok(response) => {
+                    let status = response.status();
+                    if status.is_success() {
+                        return self.parse_response(response, texts.len()).await;
+                    }
+                    let retry_after = response
+                        .headers()
+                        .get("retry-after")
+                        .and_then(|v| v.to_str().ok())
+                        .and_then(|v| v.trim().parse::<u64>().ok())
+                        .map(Duration::from_secs);
+                    let message = response.text().await.unwrap_or_default();
+                    let failure = format!("HTTP {}: {}", status.as_u16(), message.trim());
+                    if status.as_u16() != 429 && !status.is_server_error() {
+                        return Err(Error::operation(format!(
+                            "Embedding request to {url} failed with {failure}"
+                        )));
+                    }
+                    (retry_after, failure)
+                }
+                Err(e) => (None, e.to_string()),
+            };
+
+            if attempt >= self.max_retries {
+                return Err(Error::operation(format!(
+                    "Embedding request to {url} failed after {} attempts: {failure}",
+                    attempt + 1
+                )));
+            }
+            let delay = retry_after
+                .unwrap_or_else(|| self.initial_backoff.saturating_mul(1 << attempt.min(16)))
+                .min(MAX_BACKOFF);
+            attempt += 1;
+            log::debug!(
+                "Embedding request failed ({failure}); retry {attempt}/{} in {delay:?}",
+                self.max_retries
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    async fn parse_response(
+        &self,
+        response: reqwest::Response,
+        expected: usize,
+    ) -> Result<Vec<Vec<f32>>> {
+        let body: EmbeddingResponse = response
+            .json()
+            .await
+            .map_err(|e| Error::parse(format!("Invalid embedding response: {e}")))?;
+        if body.data.len() != expected {
+            return Err(Error::operation(format!(
+                "Embedding response has {} embeddings for {expected} inputs",
+                body.data.len()
+            )));
+        }
+
+        // Entries carry their input position; order by it when present
+        let mut data: Vec<(usize, Vec<f32>)> = body
+            .data
+            .into_iter()
+            .enumerate()
+            .map(|(pos, d)| (d.index.unwrap_or(pos), d.embedding))
+            .collect();
+        data.sort_by_key(|(index, _)| *index);
+
+        let embeddings: Vec<Vec<f32>> = data.into_iter().map(|(_, e)| e).collect();
+        if self.dimension > 0
+            && let Some(bad) = embeddings.iter().find(|e| e.len() != self.dimension)
+        {
+            return Err(Error::operation(format!(
+                "Model '{}' returned a {}-dimensional embedding, expected {}",
+                self.model,
+                bad.len(),
+                self.dimension
+            )));
+        }
+        Ok(embeddings)
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for OpenAiEmbeddingProvider {
+    async fn embed(&self, text: &str) -> Result<Vec<f32>> {
+        self.request(&[text])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::operation("No embedding returned"))
+    }
+
+    async fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        let mut results = Vec::with_capacity(texts.len());
+        for batch in texts.chunks(self.batch_size) {
+            results.extend(self.request(batch).await?);
+        }
+        Ok(results)
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn name(&self) -> &str {
+        &self.model
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{Value, json};
+    use wiremock::matchers::{body_partial_json, header, method, path};
+    use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
+
+    /// Embeds each input as `[len, index, 1.0]`.
+    struct EchoEmbeddings;
+
+    impl Respond for EchoEmbeddings {
+        fn respond(&self, request: &Request) -> ResponseTemplate {
+            let body: Value = serde_json::from_slice(&request.body).unwrap();
+            let data: Vec<Value> = body["input"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .enumerate()
+                .map(|(i, text)| {
+                    let len = text.as_str().unwrap().len() as f32;
+                    json!({"object": "embedding", "index": i, "embedding": [len, i as f32, 1.0]})
+                })
+                .collect();
+            ResponseTemplate::new(200).set_body_json(json!({"object": "list", "data": data}))
+        }
+    }
+
+    fn provider(server: &MockServer) -> OpenAiEmbeddingProvider {
+        OpenAiEmbeddingProvider::new(format!("{}/v1", server.uri()), "test-model")
+            .with_initial_backoff(Duration::from_millis(1))
+    }
+
+    #[tokio::test]
+    async fn test_build_discovers_dimension() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/embeddings"))
+            .and(body_partial_json(json!({"model": "test-model"})))
+            .respond_with(EchoEmbeddings)
+            .mount(&server)
+            .await;
+
+        let provider = provider(&server).build().await.unwrap();
+        assert_eq!(provider.dimension(), 3);
+        assert_eq!(provider.name(), "test-model");
+
+        let embedding = provider.embed("hello").await.unwrap();
+        assert_eq!(embedding, vec![5.0, 0.0, 1.0]);
+    }
+
+    #[tokio::test]
+    async fn test_embed_batch_splits_requests() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/embeddings"))
+            .respond_with(EchoEmbeddings)
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let provider = provider(&server).with_dimension(3).with_batch_size(2);
+        let embeddings = provider.embed_batch(&["a", "bb", "ccc"]).await.unwrap();
+        assert_eq!(embeddings.len(), 3);
+        // Third text is the first input of the second request
+        assert_eq!(embeddings[2], vec![3.0, 0.0, 1.0]);
+        assert_eq!(embeddings[1], vec![2.0, 1.0, 1.0]);
+    }
+
+    #[tokio::test]
+    async fn test_response_is_ordered_by_index() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": [
+                    {"index": 1, "embedding": [2.0]},
+                    {"index": 0, "embedding": [1.0]}
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let embeddings = provider(&server).embed_batch(&["a", "b"]).await.unwrap();
+        assert_eq!(embeddings, vec![vec![1.0], vec![2.0]]);
+    }
+
+    #[tokio::test]
+    async fn test_retries_rate_limits_and_server_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(EchoEmbeddings)
+            .mount(&server)
+            .await;
+
+        let embedding = provider(&server).embed("abc").await.unwrap();
+        assert_eq!(embedding, vec![3.0, 0.0, 1.0]);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_retries() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(500).set_body_string("overloaded"))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let err = provider(&server)
+            .with_max_retries(2)
+            .embed("abc")
+            .await
+            .unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("after 3 attempts"));
+        assert!(message.contains("overloaded"));
+    }
+
+    #[tokio::test]
+    async fn test_client_errors_are_not_retried() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(401).set_body_string("bad key"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let err = provider(&server).embed("abc").await.unwrap_err();
+        assert!(err.to_string().contains("HTTP 401: bad key"));
+    }
+
+    #[tokio::test]
+    async fn test_api_key_is_sent_as_bearer_token() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(header("authorization", "Bearer secret"))
+            .respond_with(EchoEmbeddings)
+            .mount(&server)
+            .await;
+
+        let provider = provider(&server).with_api_key("secret");
+        assert!(provider.embed("abc").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_dimension_mismatch_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(EchoEmbeddings)
+            .mount(&server)
+            .await;
+
+        let err = provider(&server)
+            .with_dimension(4)
+            .embed("abc")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("expected 4"));
+    }
+
+    #[test]
+    fn test_from_config() {
+        let config = VectorConfig {
+            provider: "openai".to_string(),
+            model: "text-embedding-3-small".to_string(),
+            api_base: Some("http://localhost:11434/v1/".to_string()),
+            api_key_env: Some("FABRYK_TEST_UNSET_EMBEDDING_KEY".to_string()),
+            dimension: 1536,
+            batch_size: 16,
+            ..Default::default()
+        };
+        let provider = OpenAiEmbeddingProvider::from_config(&config);
+        assert_eq!(provider.base_url, "http://localhost:11434/v1");
+        assert_eq!(provider.dimension(), 1536);
+        assert_eq!(provider.batch_size, 16);
+        assert!(provider.api_key.is_none());
+    }
+}
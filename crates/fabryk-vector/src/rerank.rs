@@ -0,0 +1,185 @@
+//! Reranking of first-stage search results.
+//!
+//! Keyword search, vector search and RRF fusion score the query and each
+//! document independently. A reranker (typically a cross-encoder) scores
+//! each query–document pair jointly, which is more accurate but much
+//! slower, so it is applied only to the top candidates of a result list.
+//!
+//! # Rerankers
+//!
+//! - `MockReranker`: Deterministic query-term overlap, for testing
+//! - `FastEmbedReranker`: Local cross-encoder models via fastembed (requires
+//!   `vector-fastembed` feature)
+
+use async_trait::async_trait;
+use fabryk_core::{Error, Result};
+use std::collections::HashSet;
+
+/// Default number of candidates re-scored by [`rerank_top_k`].
+pub const DEFAULT_RERANK_TOP_K: usize = 20;
+
+/// Trait for scoring documents against a query.
+///
+/// Scores are only comparable within one call; higher is more relevant.
+#[async_trait]
+pub trait Reranker: Send + Sync {
+    /// Score each document's relevance to `query`, in input order.
+    async fn score(&self, query: &str, documents: &[&str]) -> Result<Vec<f32>>;
+
+    /// The reranker name for diagnostics.
+    fn name(&self) -> &str;
+}
+
+/// A deterministic reranker for testing.
+///
+/// Scores a document by the fraction of distinct query terms it contains
+/// (case-insensitive), so results are predictable without a model.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MockReranker;
+
+impl MockReranker {
+    /// Create a new mock reranker.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+fn terms(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+#[async_trait]
+impl Reranker for MockReranker {
+    async fn score(&self, query: &str, documents: &[&str]) -> Result<Vec<f32>> {
+        let query_terms = terms(query);
+        if query_terms.is_empty() {
+            return Ok(vec![0.0; documents.len()]);
+        }
+        Ok(documents
+            .iter()
+            .map(|doc| {
+                let doc_terms = terms(doc);
+                let matched = query_terms.intersection(&doc_terms).count();
+                matched as f32 / query_terms.len() as f32
+            })
+            .collect())
+    }
+
+    fn name(&self) -> &str {
+        "mock"
+    }
+}
+
+/// Rerank the first `top_k` items of a ranked list.
+///
+/// The top candidates are re-scored with `reranker` (using `text` to get
+/// each item's document text) and stably re-sorted by that score; items
+/// past `top_k` keep their order after them. Each item is paired with its
+/// rerank score, or `None` if it was not re-scored.
+pub async fn rerank_top_k<T, F>(
+    reranker: &dyn Reranker,
+    query: &str,
+    items: Vec<T>,
+    top_k: usize,
+    text: F,
+) -> Result<Vec<(T, Option<f32>)>>
+where
+    F: Fn(&T) -> String,
+{
+    let cut = top_k.min(items.len());
+    let mut items = items;
+    let rest = items.split_off(cut);
+
+    let texts: Vec<String> = items.iter().map(&text).collect();
+    let refs: Vec<&str> = texts.iter().map(String::as_str).collect();
+    let scores = if refs.is_empty() {
+        Vec::new()
+    } else {
+        reranker.score(query, &refs).await?
+    };
+    if scores.len() != items.len() {
+        return Err(Error::operation(format!(
+            "Reranker '{}' returned {} scores for {} documents",
+            reranker.name(),
+            scores.len(),
+            items.len()
+        )));
+    }
+
+    let mut head: Vec<(T, Option<f32>)> = items
+        .into_iter()
+        .zip(scores)
+        .map(|(item, score)| (item, Some(score)))
+        .collect();
+    // Stable: equal scores keep their first-stage order
+    head.sort_by(|a, b| {
+        let (a, b) = (a.1.unwrap_or(f32::MIN), b.1.unwrap_or(f32::MIN));
+        b.total_cmp(&a)
+    });
+    head.extend(rest.into_iter().map(|item| (item, None)));
+    Ok(head)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_mock_reranker_scores_term_overlap() {
+        let scores = MockReranker
+            .score(
+                "Picardy third",
+                &["The Picardy third ends minor pieces", "third", "unrelated"],
+            )
+            .await
+            .unwrap();
+        assert_eq!(scores, vec![1.0, 0.5, 0.0]);
+    }
+
+    #[tokio::test]
+    async fn test_mock_reranker_empty_query() {
+        let scores = MockReranker.score("  ", &["a", "b"]).await.unwrap();
+        assert_eq!(scores, vec![0.0, 0.0]);
+    }
+
+    #[tokio::test]
+    async fn test_rerank_top_k_reorders_only_the_head() {
+        let items = vec!["minor keys", "picardy third", "picardy", "third picardy"];
+        let ranked = rerank_top_k(&MockReranker, "picardy third", items, 3, |s| s.to_string())
+            .await
+            .unwrap();
+        let order: Vec<&str> = ranked.iter().map(|(s, _)| *s).collect();
+        // "third picardy" is past top_k, so it stays last despite matching
+        assert_eq!(
+            order,
+            vec!["picardy third", "picardy", "minor keys", "third picardy"]
+        );
+        assert_eq!(ranked[0].1, Some(1.0));
+        assert_eq!(ranked[3].1, None);
+    }
+
+    #[tokio::test]
+    async fn test_rerank_top_k_is_stable_for_ties() {
+        let items = vec!["b", "a", "c"];
+        let ranked = rerank_top_k(&MockReranker, "zzz", items, 10, |s| s.to_string())
+            .await
+            .unwrap();
+        let order: Vec<&str> = ranked.iter().map(|(s, _)| *s).collect();
+        assert_eq!(order, vec!["b", "a", "c"]);
+    }
+
+    #[tokio::test]
+    async fn test_rerank_top_k_empty() {
+        let ranked = rerank_top_k(&MockReranker, "q", Vec::<String>::new(), 5, Clone::clone)
+            .await
+            .unwrap();
+        assert!(ranked.is_empty());
+    }
+}
@@ -20,7 +20,7 @@ pub struct VectorConfig {
     #[serde(default = "default_backend")]
     pub backend: String,
 
-    /// Embedding provider: "fastembed" or "mock".
+    /// Embedding provider: "fastembed", "openai", "onnx" or "mock".
     #[serde(default = "default_provider")]
     pub provider: String,
 
@@ -56,6 +56,29 @@ pub struct VectorConfig {
     /// Batch size for embedding operations.
     #[serde(default = "default_batch_size")]
     pub batch_size: usize,
+
+    /// Base URL of an OpenAI-compatible embeddings API (e.g.,
+    /// "http://localhost:11434/v1" for Ollama). Defaults to OpenAI.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_base: Option<String>,
+
+    /// Environment variable holding the embeddings API key
+    /// (default "OPENAI_API_KEY").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key_env: Option<String>,
+
+    /// Directory for the on-disk embedding cache (disabled if unset).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embedding_cache_path: Option<String>,
+
+    /// Directory holding a local ONNX model and its tokenizer files, for
+    /// the "onnx" provider.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_path: Option<String>,
+
+    /// Token pooling for the "onnx" provider: "mean" (default) or "cls".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pooling: Option<String>,
 }
 
 fn default_backend() -> String {
@@ -100,6 +123,11 @@ impl Default for VectorConfig {
             default_limit: default_limit(),
             similarity_threshold: default_threshold(),
             batch_size: default_batch_size(),
+            api_base: None,
+            api_key_env: None,
+            embedding_cache_path: None,
+            model_path: None,
+            pooling: None,
         }
     }
 }
@@ -151,6 +179,27 @@ impl VectorDocument {
         self.metadata.insert(key.into(), value.into());
         self
     }
+
+    /// Compute a blake3 hash of the document's ID, text, category and metadata.
+    ///
+    /// Used to detect which documents changed since they were last embedded.
+    pub fn content_hash(&self) -> String {
+        let mut hasher = blake3::Hasher::new();
+        for field in [self.id.as_str(), self.text.as_str()] {
+            hasher.update(&(field.len() as u64).to_le_bytes());
+            hasher.update(field.as_bytes());
+        }
+        hasher.update(self.category.as_deref().unwrap_or_default().as_bytes());
+        let mut metadata: Vec<_> = self.metadata.iter().collect();
+        metadata.sort();
+        for (key, value) in metadata {
+            hasher.update(&(key.len() as u64).to_le_bytes());
+            hasher.update(key.as_bytes());
+            hasher.update(&(value.len() as u64).to_le_bytes());
+            hasher.update(value.as_bytes());
+        }
+        hasher.finalize().to_hex().to_string()
+    }
 }
 
 /// A document with its computed embedding vector.
@@ -161,6 +210,11 @@ pub struct EmbeddedDocument {
 
     /// The embedding vector.
     pub embedding: Vec<f32>,
+
+    /// Hash of the document when it was embedded (see
+    /// [`VectorDocument::content_hash`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
 }
 
 impl EmbeddedDocument {
@@ -169,9 +223,25 @@ impl EmbeddedDocument {
         Self {
             document,
             embedding,
+            content_hash: None,
+        }
+    }
+
+    /// Create an embedded document, recording the document's content hash.
+    pub fn hashed(document: VectorDocument, embedding: Vec<f32>) -> Self {
+        let content_hash = Some(document.content_hash());
+        Self {
+            document,
+            embedding,
+            content_hash,
         }
     }
 
+    /// Whether this embedding was computed from `document` as it is now.
+    pub fn is_current(&self, document: &VectorDocument) -> bool {
+        self.content_hash.as_deref() == Some(document.content_hash().as_str())
+    }
+
     /// The embedding dimension.
     pub fn dimension(&self) -> usize {
         self.embedding.len()
@@ -254,6 +324,11 @@ pub struct VectorSearchResult {
     /// Metadata snapshot from the indexed document.
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub metadata: HashMap<String, String>,
+
+    /// Text of the matching chunk, when the indexed document is a chunk
+    /// (see [`crate::chunking`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub passage: Option<String>,
 }
 
 /// Collection of vector search results.
@@ -289,6 +364,13 @@ impl VectorSearchResults {
 pub struct VectorIndexStats {
     /// Number of documents indexed.
     pub documents_indexed: usize,
+    /// Number of documents embedded by this build; the rest were unchanged
+    /// and kept their previous embeddings.
+    #[serde(default)]
+    pub documents_embedded: usize,
+    /// Number of previously indexed documents removed by this build.
+    #[serde(default)]
+    pub documents_removed: usize,
 
     /// Number of files processed.
     pub files_processed: usize,
@@ -349,6 +431,8 @@ mod tests {
         assert_eq!(config.default_limit, 10);
         assert_eq!(config.similarity_threshold, 0.0);
         assert_eq!(config.batch_size, 64);
+        assert!(config.model_path.is_none());
+        assert!(config.pooling.is_none());
     }
 
     #[test]
@@ -445,6 +529,38 @@ mod tests {
         assert_eq!(embedded.document.id, "doc-1");
         assert_eq!(embedded.embedding.len(), 3);
         assert_eq!(embedded.dimension(), 3);
+        assert!(embedded.content_hash.is_none());
+    }
+
+    #[test]
+    fn test_vector_document_content_hash() {
+        let doc = VectorDocument::new("doc-1", "text")
+            .with_category("harmony")
+            .with_metadata("a", "1")
+            .with_metadata("b", "2");
+        assert_eq!(doc.content_hash(), doc.clone().content_hash());
+        assert_ne!(
+            doc.content_hash(),
+            doc.clone().with_metadata("a", "changed").content_hash()
+        );
+        assert_ne!(
+            doc.content_hash(),
+            VectorDocument::new("doc-1", "other text").content_hash()
+        );
+        // Field boundaries are part of the hash.
+        assert_ne!(
+            VectorDocument::new("ab", "c").content_hash(),
+            VectorDocument::new("a", "bc").content_hash()
+        );
+    }
+
+    #[test]
+    fn test_embedded_document_is_current() {
+        let doc = VectorDocument::new("doc-1", "text");
+        let embedded = EmbeddedDocument::hashed(doc.clone(), vec![0.1]);
+        assert!(embedded.is_current(&doc));
+        assert!(!embedded.is_current(&VectorDocument::new("doc-1", "edited")));
+        assert!(!EmbeddedDocument::new(doc.clone(), vec![0.1]).is_current(&doc));
     }
 
     // ------------------------------------------------------------------------
@@ -502,6 +618,7 @@ mod tests {
             score: 0.85,
             distance: 0.176,
             metadata: HashMap::from([("category".to_string(), "harmony".to_string())]),
+            passage: None,
         };
 
         let json = serde_json::to_string(&result).unwrap();
@@ -516,10 +633,12 @@ mod tests {
             score: 0.5,
             distance: 1.0,
             metadata: HashMap::new(),
+            passage: None,
         };
 
         let json = serde_json::to_string(&result).unwrap();
         assert!(!json.contains("metadata"));
+        assert!(!json.contains("passage"));
     }
 
     // ------------------------------------------------------------------------
@@ -542,6 +661,8 @@ mod tests {
     fn test_index_stats_serialization() {
         let stats = VectorIndexStats {
             documents_indexed: 100,
+            documents_embedded: 100,
+            documents_removed: 0,
             files_processed: 50,
             files_skipped: 2,
             embedding_dimension: 384,
@@ -564,6 +685,8 @@ mod tests {
     fn test_index_stats_with_errors() {
         let stats = VectorIndexStats {
             documents_indexed: 10,
+            documents_embedded: 10,
+            documents_removed: 0,
             files_processed: 12,
             files_skipped: 2,
             embedding_dimension: 384,
@@ -0,0 +1,238 @@
+//! Incremental vector index updates for live content watching.
+//!
+//! [`VectorUpdater`] is a [`ChangeHandler`] that re-extracts changed files,
+//! embeds only their documents (or chunks), and writes them to a
+//! [`VectorBackend`] with [`upsert`](VectorBackend::upsert). Documents of
+//! removed files, and chunks left over when a document shrinks, are
+//! removed with [`delete`](VectorBackend::delete).
+//!
+//! The backend must support incremental updates, as
+//! [`SimpleVectorBackend`](crate::SimpleVectorBackend) does.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! let updater = VectorUpdater::new(MyExtractor, content_path, provider, backend.clone())
+//!     .with_chunker(Chunker::sections(2));
+//! let watcher = ContentWatcher::for_content(&config, "concepts")?
+//!     .with_handler(updater)
+//!     .spawn()?;
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use fabryk_core::util::ids::id_from_path;
+use fabryk_core::{ChangeHandler, ChangeSet, Error, Result};
+
+use crate::backend::VectorBackend;
+use crate::builder::extract_file;
+use crate::chunking::Chunker;
+use crate::embedding::EmbeddingProvider;
+use crate::extractor::VectorExtractor;
+use crate::types::{EmbeddedDocument, VectorDocument};
+
+/// Embeds and upserts documents for changed content files.
+pub struct VectorUpdater<E: VectorExtractor> {
+    extractor: E,
+    content_path: PathBuf,
+    provider: Arc<dyn EmbeddingProvider>,
+    backend: Arc<dyn VectorBackend>,
+    chunker: Option<Chunker>,
+    /// Document IDs last written for each file (several when chunked).
+    ids: Mutex<HashMap<PathBuf, Vec<String>>>,
+}
+
+impl<E: VectorExtractor> VectorUpdater<E> {
+    /// Create an updater writing to `backend`.
+    pub fn new(
+        extractor: E,
+        content_path: impl Into<PathBuf>,
+        provider: Arc<dyn EmbeddingProvider>,
+        backend: Arc<dyn VectorBackend>,
+    ) -> Self {
+        Self {
+            extractor,
+            content_path: content_path.into(),
+            provider,
+            backend,
+            chunker: None,
+            ids: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Split documents into chunks, matching the chunker the index was
+    /// built with.
+    pub fn with_chunker(mut self, chunker: Chunker) -> Self {
+        self.chunker = Some(chunker);
+        self
+    }
+
+    fn lock_ids(&self) -> std::sync::MutexGuard<'_, HashMap<PathBuf, Vec<String>>> {
+        self.ids
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Documents for one file, chunked if a chunker is configured.
+    fn extract(&self, path: &Path) -> Result<Vec<VectorDocument>> {
+        let doc = extract_file(&self.extractor, &self.content_path, path)?;
+        Ok(match self.chunker {
+            Some(ref chunker) => chunker.chunk(&doc),
+            None => vec![doc],
+        })
+    }
+}
+
+#[async_trait]
+impl<E: VectorExtractor> ChangeHandler for VectorUpdater<E> {
+    fn name(&self) -> &str {
+        "vector"
+    }
+
+    async fn apply(&self, changes: &ChangeSet) -> Result<()> {
+        let mut stale = Vec::new();
+        let mut documents = Vec::new();
+        let mut failures = Vec::new();
+        {
+            let mut ids = self.lock_ids();
+
+            // Files never seen by this updater fall back to the
+            // path-derived ID; their chunks are not known here
+            for path in changes.removed() {
+                match ids.remove(path) {
+                    Some(old) => stale.extend(old),
+                    None => stale.extend(id_from_path(path)),
+                }
+            }
+
+            for path in changes.upserted() {
+                match self.extract(path) {
+                    Ok(docs) => {
+                        let new_ids: HashSet<&str> = docs.iter().map(|d| d.id.as_str()).collect();
+                        if let Some(old) = ids.get(path) {
+                            stale.extend(
+                                old.iter()
+                                    .filter(|id| !new_ids.contains(id.as_str()))
+                                    .cloned(),
+                            );
+                        }
+                        ids.insert(
+                            path.to_path_buf(),
+                            docs.iter().map(|d| d.id.clone()).collect(),
+                        );
+                        documents.extend(docs);
+                    }
+                    Err(e) => failures.push(format!("{}: {e}", path.display())),
+                }
+            }
+        }
+
+        if !stale.is_empty() {
+            self.backend.delete(&stale).await?;
+        }
+        if !documents.is_empty() {
+            let texts: Vec<&str> = documents.iter().map(|d| d.text.as_str()).collect();
+            let embeddings = self.provider.embed_batch(&texts).await?;
+            let embedded: Vec<EmbeddedDocument> = documents
+                .iter()
+                .zip(embeddings)
+                .map(|(doc, embedding)| EmbeddedDocument::hashed(doc.clone(), embedding))
+                .collect();
+            self.backend.upsert(embedded).await?;
+        }
+        log::info!(
+            "Vector index updated: {} document(s) embedded, {} removed",
+            documents.len(),
+            stale.len()
+        );
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::operation(format!(
+                "{} file(s) could not be extracted: {}",
+                failures.len(),
+                failures.join("; ")
+            )))
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::SimpleVectorBackend;
+    use crate::embedding::MockEmbeddingProvider;
+    use crate::extractor::MockVectorExtractor;
+    use fabryk_core::ChangeKind;
+
+    fn setup() -> (
+        tempfile::TempDir,
+        Arc<dyn EmbeddingProvider>,
+        Arc<SimpleVectorBackend>,
+    ) {
+        let dir = tempfile::tempdir().unwrap();
+        let provider: Arc<dyn EmbeddingProvider> = Arc::new(MockEmbeddingProvider::new(8));
+        let backend = Arc::new(SimpleVectorBackend::new(provider.clone()));
+        (dir, provider, backend)
+    }
+
+    #[tokio::test]
+    async fn test_apply_upserts_and_deletes() {
+        let (dir, provider, backend) = setup();
+        let content = dir.path();
+        std::fs::write(content.join("a.md"), "---\ntitle: A\n---\nFirst").unwrap();
+        std::fs::write(content.join("b.md"), "---\ntitle: B\n---\nSecond").unwrap();
+
+        let updater = VectorUpdater::new(MockVectorExtractor, content, provider, backend.clone());
+        let mut changes = ChangeSet::new();
+        changes.record(content.join("a.md"), ChangeKind::Created);
+        changes.record(content.join("b.md"), ChangeKind::Created);
+        updater.apply(&changes).await.unwrap();
+        assert_eq!(backend.document_count().unwrap(), 2);
+
+        std::fs::write(content.join("a.md"), "---\ntitle: A\n---\nRevised").unwrap();
+        std::fs::remove_file(content.join("b.md")).unwrap();
+        let mut changes = ChangeSet::new();
+        changes.record(content.join("a.md"), ChangeKind::Modified);
+        changes.record(content.join("b.md"), ChangeKind::Removed);
+        updater.apply(&changes).await.unwrap();
+
+        assert_eq!(backend.document_count().unwrap(), 1);
+        let a = backend.get("a").await.unwrap().unwrap();
+        assert_eq!(a.document.text, "A | Revised");
+    }
+
+    #[tokio::test]
+    async fn test_apply_removes_stale_chunks() {
+        let (dir, provider, backend) = setup();
+        let content = dir.path();
+        let path = content.join("doc.md");
+        std::fs::write(
+            &path,
+            "# One\n\nFirst\n\n# Two\n\nSecond\n\n# Three\n\nThird",
+        )
+        .unwrap();
+
+        let updater = VectorUpdater::new(MockVectorExtractor, content, provider, backend.clone())
+            .with_chunker(Chunker::sections(1));
+        let mut changes = ChangeSet::new();
+        changes.record(&path, ChangeKind::Created);
+        updater.apply(&changes).await.unwrap();
+        let before = backend.document_count().unwrap();
+        assert!(before > 1);
+
+        std::fs::write(&path, "# One\n\nOnly section").unwrap();
+        let mut changes = ChangeSet::new();
+        changes.record(&path, ChangeKind::Modified);
+        updater.apply(&changes).await.unwrap();
+        assert!(backend.document_count().unwrap() < before);
+    }
+}